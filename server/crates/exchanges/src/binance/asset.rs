@@ -6,6 +6,8 @@ use interface::{ExchangeId, FutureAsset, SpotAsset};
 
 use super::super::{AssetExchange, ExchangeError};
 use super::{generate_signature, get_timestamp, BinanceClient, BASE_URL};
+use crate::status::status_registry;
+use crate::weight::TrackedSend;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -28,6 +30,20 @@ impl AssetExchange for BinanceClient {
     }
 
     async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
+        status_registry().track(ExchangeId::Binance, "asset", self.fetch_spot_assets().await)
+    }
+
+    async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
+        status_registry().track(
+            ExchangeId::Binance,
+            "asset",
+            self.fetch_future_assets().await,
+        )
+    }
+}
+
+impl BinanceClient {
+    async fn fetch_spot_assets(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
             ExchangeError::Other(
                 "API key not set. Use BinanceClient::with_credentials()".to_string(),
@@ -55,7 +71,7 @@ impl AssetExchange for BinanceClient {
             .http
             .get(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await?;
 
         let status = response.status();
@@ -101,7 +117,7 @@ impl AssetExchange for BinanceClient {
         Ok(assets)
     }
 
-    async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
+    async fn fetch_future_assets(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
             ExchangeError::Other(
                 "API key not set. Use BinanceClient::with_credentials()".to_string(),
@@ -128,7 +144,7 @@ impl AssetExchange for BinanceClient {
             .http
             .get(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await?;
 
         let status = response.status();
@@ -6,11 +6,12 @@ use chrono::Utc;
 use serde::Deserialize;
 use tokio::sync::RwLock;
 
-use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, MarketType};
+use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, MarketType, TransferStatus};
 
 use super::super::FeeExchange;
 // mod.rs의 BinanceClient를 import하여 FeeExchange trait 구현
 use super::{generate_signature, get_api_credentials, get_timestamp, BinanceClient, SAPI_BASE_URL};
+use crate::weight::TrackedSend;
 
 /// 입출금 수수료 캐시
 static FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<String, DepositWithdrawalFee>>>> =
@@ -71,11 +72,35 @@ struct BinanceNetwork {
     max_withdraw_amount: Option<String>,
 }
 
+/// 네트워크 목록이 있으면 하나라도 열린 경우 가능, 없으면 코인 단위 플래그 사용
+fn transfer_status_of(currency: String, info: &BinanceCoinInfo) -> TransferStatus {
+    let (deposit_enabled, withdraw_enabled) = if info.network_list.is_empty() {
+        (
+            info.deposit_all_enable.unwrap_or(false),
+            info.withdraw_all_enable.unwrap_or(false),
+        )
+    } else {
+        (
+            info.network_list
+                .iter()
+                .any(|n| n.deposit_enable.unwrap_or(false)),
+            info.network_list
+                .iter()
+                .any(|n| n.withdraw_enable.unwrap_or(false)),
+        )
+    };
+    TransferStatus {
+        currency,
+        deposit_enabled,
+        withdraw_enabled,
+    }
+}
+
 impl BinanceClient {
-    /// 입출금 수수료 캐시 초기화 및 업데이트
-    pub async fn refresh_deposit_withdrawal_fees(
+    /// 코인별 입출금 설정 조회 (네트워크 목록 포함)
+    async fn fetch_coin_configs(
         &self,
-    ) -> Result<HashMap<String, DepositWithdrawalFee>, super::super::ExchangeError> {
+    ) -> Result<Vec<BinanceCoinInfo>, super::super::ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
             super::super::ExchangeError::Other(
                 "API key not set. Use BinanceClient::with_credentials()".to_string(),
@@ -102,7 +127,7 @@ impl BinanceClient {
             .http
             .get(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await?;
 
         // response.text()는 한 번만 호출 (바디를 소비하므로)
@@ -127,6 +152,29 @@ impl BinanceClient {
                 ))
             })?;
 
+        Ok(coin_infos)
+    }
+
+    /// 코인별 입출금 가능 여부 (`/sapi/v1/capital/config/getall`)
+    pub async fn fetch_transfer_statuses(
+        &self,
+    ) -> Result<HashMap<String, TransferStatus>, super::super::ExchangeError> {
+        let coin_infos = self.fetch_coin_configs().await?;
+        Ok(coin_infos
+            .iter()
+            .map(|info| {
+                let currency = info.coin.to_uppercase();
+                (currency.clone(), transfer_status_of(currency, info))
+            })
+            .collect())
+    }
+
+    /// 입출금 수수료 캐시 초기화 및 업데이트
+    pub async fn refresh_deposit_withdrawal_fees(
+        &self,
+    ) -> Result<HashMap<String, DepositWithdrawalFee>, super::super::ExchangeError> {
+        let coin_infos = self.fetch_coin_configs().await?;
+
         let mut fees = HashMap::new();
         let now = Utc::now();
 
@@ -242,7 +290,7 @@ impl BinanceClient {
             .http
             .get(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await?;
 
         if !response.status().is_success() {
@@ -374,6 +422,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_transfer_status_any_network_enabled() {
+        let infos: Vec<BinanceCoinInfo> = serde_json::from_str(
+            r#"[
+                {"coin": "usdt", "depositAllEnable": true, "withdrawAllEnable": true,
+                 "networkList": [
+                    {"network": "TRX", "depositEnable": false, "withdrawEnable": false, "withdrawFee": "1"},
+                    {"network": "ETH", "depositEnable": true, "withdrawEnable": false, "withdrawFee": "5"}
+                 ]},
+                {"coin": "XYZ", "depositAllEnable": false, "withdrawAllEnable": true}
+            ]"#,
+        )
+        .unwrap();
+
+        let usdt = transfer_status_of("USDT".to_string(), &infos[0]);
+        assert!(usdt.deposit_enabled);
+        assert!(!usdt.withdraw_enabled);
+        assert!(usdt.is_suspended());
+
+        let xyz = transfer_status_of("XYZ".to_string(), &infos[1]);
+        assert!(!xyz.deposit_enabled && xyz.withdraw_enabled);
+    }
+
     #[tokio::test]
     async fn test_refresh_deposit_withdrawal_fees() {
         skip_if_no_credentials();
@@ -27,7 +27,7 @@ impl BinanceClient {
     /// 공개 API만 사용하는 경우 (Orderbook 등)
     pub fn new() -> Self {
         Self {
-            http: reqwest::Client::new(),
+            http: crate::http::http_client(),
             api_key: None,
             api_secret: None,
         }
@@ -37,7 +37,18 @@ impl BinanceClient {
     pub fn with_credentials() -> Result<Self, ExchangeError> {
         let (api_key, api_secret) = get_api_credentials()?;
         Ok(Self {
-            http: reqwest::Client::new(),
+            http: crate::http::http_client(),
+            api_key: Some(api_key),
+            api_secret: Some(api_secret),
+        })
+    }
+
+    /// 서브 계정 등 이름이 붙은 계정의 인증 정보를 사용하는 경우
+    /// account가 "SUB1"이면 BINANCE_API_KEY_SUB1 / BINANCE_API_SECRET_SUB1 을 읽는다
+    pub fn with_account_credentials(account: &str) -> Result<Self, ExchangeError> {
+        let (api_key, api_secret) = get_account_api_credentials(account)?;
+        Ok(Self {
+            http: crate::http::http_client(),
             api_key: Some(api_key),
             api_secret: Some(api_secret),
         })
@@ -73,6 +84,18 @@ pub fn get_api_credentials() -> Result<(String, String), ExchangeError> {
     Ok((api_key, api_secret))
 }
 
+/// 이름이 붙은 계정의 API 인증 정보 가져오기 (BINANCE_API_KEY_{ACCOUNT}, BINANCE_API_SECRET_{ACCOUNT})
+pub fn get_account_api_credentials(account: &str) -> Result<(String, String), ExchangeError> {
+    let suffix = account.to_uppercase();
+    let key_var = format!("BINANCE_API_KEY_{}", suffix);
+    let secret_var = format!("BINANCE_API_SECRET_{}", suffix);
+    let api_key = env::var(&key_var)
+        .map_err(|e| ExchangeError::Other(format!("{} not found: {}", key_var, e)))?;
+    let api_secret = env::var(&secret_var)
+        .map_err(|e| ExchangeError::Other(format!("{} not found: {}", secret_var, e)))?;
+    Ok((api_key, api_secret))
+}
+
 /// 환경변수가 설정되어 있는지 확인
 pub fn has_api_credentials() -> bool {
     env::var("BINANCE_API_KEY").is_ok() && env::var("BINANCE_API_SECRET").is_ok()
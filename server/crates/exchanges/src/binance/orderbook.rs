@@ -6,6 +6,7 @@ use interface::{ExchangeId, OrderBook, OrderBookEntry};
 
 use super::super::{ExchangeError, OrderBookExchange};
 use super::{BinanceClient, BASE_URL};
+use crate::weight::TrackedSend;
 
 impl BinanceClient {
     /// 심볼을 Binance 형식으로 변환
@@ -34,7 +35,7 @@ impl OrderBookExchange for BinanceClient {
             BASE_URL, normalized_symbol
         );
 
-        let response = self.http.get(&url).send().await?;
+        let response = self.http.get(&url).send_tracked().await?;
 
         if !response.status().is_success() {
             let status = response.status();
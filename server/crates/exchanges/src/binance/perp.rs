@@ -1,19 +1,35 @@
 use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use serde::Deserialize;
 
+use crate::status::status_registry;
+use crate::weight::TrackedSend;
 use crate::{BinanceClient, ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
+use interface::{utc_from_epoch, Currency, ExchangeId, PayloadParser, PerpSnapshot, Price};
 
 const BASE_URL: &str = "https://fapi.binance.com";
 
+/// 기본 펀딩 주기 (fundingInfo에 없는 심볼)
+const DEFAULT_FUNDING_INTERVAL_HOURS: f64 = 8.0;
+/// fundingInfo를 다시 조회하는 주기 (IP당 5분 500회 제한을 공유하므로 틱마다 조회하지 않음)
+const FUNDING_INFO_TTL: Duration = Duration::from_secs(3600);
+
+/// 조회 시각, 주기가 조정된 심볼의 펀딩 주기 (symbol -> 시간)
+type FundingIntervalCache = Option<(Instant, HashMap<String, f64>)>;
+
+static FUNDING_INTERVALS: OnceLock<Mutex<FundingIntervalCache>> = OnceLock::new();
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BinancePremiumIndex {
     symbol: String,
     mark_price: String,
+    #[serde(default)]
+    index_price: String,
     last_funding_rate: String,
     next_funding_time: i64,
 }
@@ -27,6 +43,15 @@ struct BinanceTicker24h {
     open_interest: String,
 }
 
+/// `/fapi/v1/fundingInfo` 항목 (펀딩 주기/상하한이 조정된 심볼만 내려온다)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceFundingInfo {
+    symbol: String,
+    #[serde(default)]
+    funding_interval_hours: Option<f64>,
+}
+
 #[async_trait]
 impl PerpExchange for BinanceClient {
     fn id(&self) -> ExchangeId {
@@ -34,11 +59,21 @@ impl PerpExchange for BinanceClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
+        status_registry().track(
+            ExchangeId::Binance,
+            "perp",
+            self.fetch_perp_snapshots().await,
+        )
+    }
+}
+
+impl BinanceClient {
+    async fn fetch_perp_snapshots(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
         // 1) funding / mark price info
         let premium: Vec<BinancePremiumIndex> = self
             .http
             .get(format!("{BASE_URL}/fapi/v1/premiumIndex"))
-            .send()
+            .send_tracked()
             .await?
             .json()
             .await?;
@@ -47,7 +82,7 @@ impl PerpExchange for BinanceClient {
         let tickers: Vec<BinanceTicker24h> = self
             .http
             .get(format!("{BASE_URL}/fapi/v1/ticker/24hr"))
-            .send()
+            .send_tracked()
             .await?
             .json()
             .await?;
@@ -57,8 +92,10 @@ impl PerpExchange for BinanceClient {
             ticker_map.insert(t.symbol.clone(), t);
         }
 
+        let funding_intervals = self.funding_intervals().await;
         let now = Utc::now();
 
+        let parser = PayloadParser::new(ExchangeId::Binance);
         let mut out = Vec::new();
 
         for p in premium {
@@ -71,37 +108,93 @@ impl PerpExchange for BinanceClient {
                 None => continue,
             };
 
-            let mark_price: f64 = match p.mark_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(mark_price) = parser.required("mark_price", &p.mark_price) else {
+                continue;
             };
 
-            let funding_rate: f64 = p.last_funding_rate.parse().unwrap_or(0.0);
+            let Ok(index_price) = parser.optional("index_price", &p.index_price) else {
+                continue;
+            };
 
-            let oi_contracts: f64 = t.open_interest.parse().unwrap_or(0.0);
-            let oi_usd = oi_contracts * mark_price;
+            let Ok(funding_rate) = parser.optional("funding_rate", &p.last_funding_rate) else {
+                continue;
+            };
 
-            let vol_24h_usd: f64 = t.quote_volume.parse().unwrap_or(0.0);
+            let Ok(oi_contracts) = parser.optional("oi_contracts", &t.open_interest) else {
+                continue;
+            };
+            let oi_usd = oi_contracts * mark_price;
 
-            let next_funding_time: Option<DateTime<Utc>> = if p.next_funding_time > 0 {
-                DateTime::from_timestamp_millis(p.next_funding_time)
-            } else {
-                None
+            let Ok(vol_24h_usd) = parser.optional("vol_24h_usd", &t.quote_volume) else {
+                continue;
             };
 
+            let next_funding_time = utc_from_epoch(p.next_funding_time);
+
+            let funding_interval_hours = funding_intervals.as_ref().map(|m| {
+                m.get(&p.symbol)
+                    .copied()
+                    .unwrap_or(DEFAULT_FUNDING_INTERVAL_HOURS)
+            });
+
             out.push(PerpSnapshot {
                 exchange: ExchangeId::Binance,
                 symbol: p.symbol,
                 currency: Currency::USDT,
-                mark_price,
+                mark_price: Price::new(mark_price),
+                index_price: (index_price > 0.0).then_some(Price::new(index_price)),
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
                 updated_at: now,
+                funding_interval_hours,
             });
         }
 
         Ok(out)
     }
+
+    /// 주기가 조정된 심볼의 펀딩 주기 (캐시 만료 시 다시 조회)
+    /// 한 번도 조회하지 못했으면 None (기본 주기로 단정하지 않음)
+    async fn funding_intervals(&self) -> Option<HashMap<String, f64>> {
+        let cache = FUNDING_INTERVALS.get_or_init(|| Mutex::new(None));
+        if let Some((fetched_at, intervals)) = cache.lock().unwrap().as_ref() {
+            if fetched_at.elapsed() < FUNDING_INFO_TTL {
+                return Some(intervals.clone());
+            }
+        }
+
+        match self.fetch_funding_intervals().await {
+            Ok(intervals) => {
+                *cache.lock().unwrap() = Some((Instant::now(), intervals.clone()));
+                Some(intervals)
+            }
+            Err(e) => {
+                tracing::warn!("Binance fundingInfo 조회 실패: {}", e);
+                cache
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .map(|(_, intervals)| intervals.clone())
+            }
+        }
+    }
+
+    async fn fetch_funding_intervals(&self) -> Result<HashMap<String, f64>, ExchangeError> {
+        let infos: Vec<BinanceFundingInfo> = self
+            .http
+            .get(format!("{BASE_URL}/fapi/v1/fundingInfo"))
+            .send_tracked()
+            .await?
+            .json()
+            .await?;
+        Ok(infos
+            .into_iter()
+            .filter_map(|info| {
+                let hours = info.funding_interval_hours.filter(|h| *h > 0.0)?;
+                Some((info.symbol, hours))
+            })
+            .collect())
+    }
 }
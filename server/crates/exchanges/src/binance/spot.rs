@@ -2,8 +2,10 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
+use crate::status::status_registry;
+use crate::weight::TrackedSend;
 use crate::{BinanceClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
+use interface::{Currency, ExchangeId, PayloadParser, Price, SpotSnapshot};
 
 const SPOT_BASE_URL: &str = "https://api.binance.com";
 
@@ -22,15 +24,26 @@ impl SpotExchange for BinanceClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
+        status_registry().track(
+            ExchangeId::Binance,
+            "spot",
+            self.fetch_spot_snapshots().await,
+        )
+    }
+}
+
+impl BinanceClient {
+    async fn fetch_spot_snapshots(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
         let tickers: Vec<BinanceSpotTicker24h> = self
             .http
             .get(format!("{SPOT_BASE_URL}/api/v3/ticker/24hr"))
-            .send()
+            .send_tracked()
             .await?
             .json()
             .await?;
 
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Binance);
         let mut out = Vec::new();
 
         for ticker in tickers {
@@ -38,9 +51,8 @@ impl SpotExchange for BinanceClient {
                 continue; // USDT 페어만
             }
 
-            let price: f64 = match ticker.last_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(price) = parser.required("price", &ticker.last_price) else {
+                continue;
             };
 
             // price가 0보다 큰 경우만 추가
@@ -48,13 +60,15 @@ impl SpotExchange for BinanceClient {
                 continue;
             }
 
-            let vol_24h_usd: f64 = ticker.quote_volume.parse().unwrap_or(0.0);
+            let Ok(vol_24h_usd) = parser.optional("vol_24h_usd", &ticker.quote_volume) else {
+                continue;
+            };
 
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Binance,
                 symbol: ticker.symbol,
                 currency: Currency::USDT,
-                price,
+                price: Price::new(price),
                 vol_24h_usd,
                 updated_at: now,
             });
@@ -89,7 +103,7 @@ mod tests {
                     println!("Binance spot snapshot: {:?}", snapshot);
                     assert_eq!(snapshot.exchange, ExchangeId::Binance);
                     assert!(snapshot.symbol.ends_with("USDT"));
-                    assert!(snapshot.price > 0.0);
+                    assert!(snapshot.price.value() > 0.0);
                     assert!(snapshot.vol_24h_usd >= 0.0);
                 }
 
@@ -1,13 +1,18 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use futures::stream::{self, StreamExt};
 use serde::Deserialize;
 use tracing;
 
+use crate::status::status_registry;
+use crate::volume::{volume_24h_usd, VolumeUnit};
 use crate::{ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
+use interface::{
+    default_funding_interval_hours, next_funding_after, Currency, ExchangeId, PayloadParser,
+    PerpSnapshot, Price,
+};
 
 const BASE_URL: &str = "https://api.bitget.com";
 
@@ -19,46 +24,11 @@ pub struct BitgetClient {
 impl BitgetClient {
     pub fn new() -> Self {
         Self {
-            http: reqwest::Client::new(),
+            http: crate::http::http_client(),
         }
     }
 }
 
-/// Bitget의 다음 펀딩 시간 계산
-/// Bitget은 UTC 00:00, 04:00, 08:00, 12:00, 16:00, 20:00에 펀딩이 발생 (4시간 주기)
-fn next_bitget_funding_time(now: DateTime<Utc>) -> DateTime<Utc> {
-    use chrono::Timelike;
-
-    // Bitget funding schedule: 00:00, 04:00, 08:00, 12:00, 16:00, 20:00 UTC
-    let hours = now.hour();
-
-    // 다음 펀딩 시각의 hour 결정
-    let next_hour = if hours < 4 {
-        4
-    } else if hours < 8 {
-        8
-    } else if hours < 12 {
-        12
-    } else if hours < 16 {
-        16
-    } else if hours < 20 {
-        20
-    } else {
-        24 // 내일 00:00
-    };
-
-    let date = now.date_naive();
-    let next_date = if next_hour == 24 {
-        date.succ_opt().unwrap()
-    } else {
-        date
-    };
-
-    let hour = if next_hour == 24 { 0 } else { next_hour };
-
-    DateTime::<Utc>::from_naive_utc_and_offset(next_date.and_hms_opt(hour, 0, 0).unwrap(), Utc)
-}
-
 #[derive(Debug, Deserialize)]
 struct BitgetResponse<T> {
     code: String,
@@ -76,6 +46,8 @@ struct BitgetTicker {
     #[serde(default)]
     usdt_volume: String, // 24h volume in USDT
     #[serde(default)]
+    base_volume: String, // 24h volume in base currency
+    #[serde(default)]
     index_price: String, // mark price
     #[serde(default)]
     funding_rate: String,
@@ -103,6 +75,16 @@ impl PerpExchange for BitgetClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
+        status_registry().track(
+            ExchangeId::Bitget,
+            "perp",
+            self.fetch_perp_snapshots().await,
+        )
+    }
+}
+
+impl BitgetClient {
+    async fn fetch_perp_snapshots(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
         // 1) 티커 정보 (24h 거래량, 마크 가격, 펀딩 레이트)
         let tickers_url = format!("{BASE_URL}/api/mix/v1/market/tickers?productType=umcbl");
         let tickers_response: BitgetResponse<Vec<BitgetTicker>> =
@@ -205,6 +187,7 @@ impl PerpExchange for BitgetClient {
         }
 
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Bitget);
         let mut out = Vec::new();
 
         for ticker in tickers_response.data {
@@ -216,37 +199,56 @@ impl PerpExchange for BitgetClient {
             // 심볼 변환: "BTCUSDT_UMCBL" -> "BTCUSDT"
             let symbol = ticker.symbol.replace("_UMCBL", "");
 
-            let mark_price: f64 = match ticker.index_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(mark_price) = parser.required("mark_price", &ticker.index_price) else {
+                continue;
             };
 
-            let funding_rate: f64 = ticker.funding_rate.parse().unwrap_or(0.0);
+            let Ok(funding_rate) = parser.optional("funding_rate", &ticker.funding_rate) else {
+                continue;
+            };
 
             // 오픈 이너스트: open-interest 엔드포인트의 amount (계약 수) * mark_price
             // v1 amount가 음수일 수 있어서 절대값 사용
             let oi_contracts: f64 = match oi_map.get(&ticker.symbol) {
-                Some(oi_data) => oi_data.amount.parse::<f64>().unwrap_or(0.0).abs(),
+                Some(oi_data) => {
+                    let Ok(amount) = parser.optional("oi_contracts", &oi_data.amount) else {
+                        continue;
+                    };
+                    amount.abs()
+                }
                 None => 0.0,
             };
             let oi_usd = oi_contracts * mark_price;
 
-            // 24h 거래량은 usdtVolume (USDT 기준)
-            let vol_24h_usd: f64 = ticker.usdt_volume.parse().unwrap_or(0.0);
+            // 24h 거래량은 usdtVolume (USDT 기준), 없으면 baseVolume × 마크 가격
+            let Ok(vol_24h_usd) = volume_24h_usd(
+                &parser,
+                &[
+                    (&ticker.usdt_volume, VolumeUnit::Quote),
+                    (&ticker.base_volume, VolumeUnit::Base),
+                ],
+                mark_price,
+            ) else {
+                continue;
+            };
 
-            // 다음 펀딩 시간 계산 (UTC 00:00, 08:00, 16:00)
-            let next_funding_time = Some(next_bitget_funding_time(now));
+            // Bitget은 다음 펀딩 시각을 주지 않으므로 기본 주기(UTC 00:00부터 4시간)로 계산
+            let funding_interval_hours = default_funding_interval_hours(ExchangeId::Bitget);
+            let next_funding_time =
+                funding_interval_hours.and_then(|hours| next_funding_after(now, hours));
 
             out.push(PerpSnapshot {
                 exchange: ExchangeId::Bitget,
                 symbol,
                 currency: Currency::USDT,
-                mark_price,
+                mark_price: Price::new(mark_price),
+                index_price: None,
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
                 updated_at: now,
+                funding_interval_hours,
             });
         }
 
@@ -279,7 +281,7 @@ mod tests {
                     println!("Bitget snapshot: {:?}", snapshot);
                     assert_eq!(snapshot.exchange, ExchangeId::Bitget);
                     assert!(snapshot.symbol.ends_with("USDT"));
-                    assert!(snapshot.mark_price > 0.0);
+                    assert!(snapshot.mark_price.value() > 0.0);
                     assert!(snapshot.oi_usd >= 0.0);
                     assert!(snapshot.vol_24h_usd >= 0.0);
                 }
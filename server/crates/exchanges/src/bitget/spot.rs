@@ -2,8 +2,10 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
+use crate::status::status_registry;
+use crate::volume::{volume_24h_usd, VolumeUnit};
 use crate::{BitgetClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
+use interface::{Currency, ExchangeId, PayloadParser, Price, SpotSnapshot};
 
 const BASE_URL: &str = "https://api.bitget.com";
 
@@ -21,7 +23,11 @@ struct BitgetSpotTicker {
     #[serde(default)]
     close: String, // last price
     #[serde(default)]
-    usdt_volume: String, // 24h volume in USDT
+    usdt_vol: String, // 24h volume in USDT
+    #[serde(default)]
+    quote_vol: String, // 24h volume in quote currency
+    #[serde(default)]
+    base_vol: String, // 24h volume in base currency
 }
 
 #[async_trait]
@@ -31,6 +37,16 @@ impl SpotExchange for BitgetClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
+        status_registry().track(
+            ExchangeId::Bitget,
+            "spot",
+            self.fetch_spot_snapshots().await,
+        )
+    }
+}
+
+impl BitgetClient {
+    async fn fetch_spot_snapshots(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
         let tickers_url = format!("{BASE_URL}/api/spot/v1/market/tickers");
         let tickers_response: BitgetResponse<Vec<BitgetSpotTicker>> =
             self.http.get(&tickers_url).send().await?.json().await?;
@@ -43,6 +59,7 @@ impl SpotExchange for BitgetClient {
         }
 
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Bitget);
         let mut out = Vec::new();
 
         for ticker in tickers_response.data {
@@ -50,9 +67,8 @@ impl SpotExchange for BitgetClient {
                 continue; // USDT 페어만
             }
 
-            let price: f64 = match ticker.close.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(price) = parser.required("price", &ticker.close) else {
+                continue;
             };
 
             // price가 0보다 큰 경우만 추가
@@ -60,13 +76,24 @@ impl SpotExchange for BitgetClient {
                 continue;
             }
 
-            let vol_24h_usd: f64 = ticker.usdt_volume.parse().unwrap_or(0.0);
+            // 현물 v1 티커는 usdtVol/quoteVol/baseVol (usdtVolume은 선물 티커 필드)
+            let Ok(vol_24h_usd) = volume_24h_usd(
+                &parser,
+                &[
+                    (&ticker.usdt_vol, VolumeUnit::Quote),
+                    (&ticker.quote_vol, VolumeUnit::Quote),
+                    (&ticker.base_vol, VolumeUnit::Base),
+                ],
+                price,
+            ) else {
+                continue;
+            };
 
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Bitget,
                 symbol: ticker.symbol,
                 currency: Currency::USDT,
-                price,
+                price: Price::new(price),
                 vol_24h_usd,
                 updated_at: now,
             });
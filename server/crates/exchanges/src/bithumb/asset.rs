@@ -6,6 +6,7 @@ use interface::{ExchangeId, FutureAsset, SpotAsset};
 
 use super::super::{AssetExchange, ExchangeError};
 use super::{generate_jwt_token, BithumbClient, BASE_URL};
+use crate::status::status_registry;
 
 #[derive(Debug, Deserialize)]
 struct BithumbAccount {
@@ -30,6 +31,20 @@ impl AssetExchange for BithumbClient {
     }
 
     async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
+        status_registry().track(ExchangeId::Bithumb, "asset", self.fetch_spot_assets().await)
+    }
+
+    async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
+        status_registry().track(
+            ExchangeId::Bithumb,
+            "asset",
+            self.fetch_future_assets().await,
+        )
+    }
+}
+
+impl BithumbClient {
+    async fn fetch_spot_assets(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
             ExchangeError::Other(
                 "API key not set. Use BithumbClient::with_credentials()".to_string(),
@@ -102,7 +117,7 @@ impl AssetExchange for BithumbClient {
         Ok(assets)
     }
 
-    async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
+    async fn fetch_future_assets(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
         // Bithumb은 선물 거래를 지원하지 않으므로 빈 벡터 반환
         Ok(Vec::new())
     }
@@ -6,12 +6,20 @@ use chrono::Utc;
 use serde::Deserialize;
 use tokio::sync::RwLock;
 
-use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, MarketType};
+use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, MarketType, TransferStatus};
 
 use super::super::FeeExchange;
 use super::{BithumbClient, BASE_URL};
 
 const FEE_API_URL: &str = "/v2/fee/inout/ALL";
+const ASSET_STATUS_URL: &str = "/public/assetsstatus/ALL";
+
+/// 원화 출금 수수료 (KRW, 건당 고정)
+pub const KRW_WITHDRAWAL_FEE: f64 = 1_000.0;
+/// 원화 최소 출금 금액 (KRW)
+pub const KRW_MIN_WITHDRAWAL: f64 = 5_000.0;
+/// 원화 입금 네트워크 이름 (연동 은행 계좌)
+const KRW_NETWORK: &str = "KRW";
 
 /// API 응답 구조체
 #[derive(Debug, Deserialize)]
@@ -35,10 +43,98 @@ struct NetworkFee {
     withdraw_minimum_quantity: String,
 }
 
+/// 네트워크별 입출금 수수료와 최소 수량 (코인 단위, 원화는 KRW)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BithumbNetworkFee {
+    pub network: String,
+    pub deposit_fee: f64,
+    pub withdrawal_fee: f64,
+    pub min_deposit: f64,
+    pub min_withdrawal: f64,
+}
+
+impl From<&NetworkFee> for BithumbNetworkFee {
+    fn from(network: &NetworkFee) -> Self {
+        let parse = |v: &str| v.parse::<f64>().unwrap_or(0.0);
+        Self {
+            network: network.net_name.clone(),
+            deposit_fee: parse(&network.deposit_fee_quantity),
+            withdrawal_fee: parse(&network.withdraw_fee_quantity),
+            min_deposit: parse(&network.deposit_minimum_quantity),
+            min_withdrawal: parse(&network.withdraw_minimum_quantity),
+        }
+    }
+}
+
+/// 원화 입출금 (은행 계좌, 입금 무료 / 출금 건당 고정 수수료)
+fn krw_network_fee() -> BithumbNetworkFee {
+    BithumbNetworkFee {
+        network: KRW_NETWORK.to_string(),
+        deposit_fee: 0.0,
+        withdrawal_fee: KRW_WITHDRAWAL_FEE,
+        min_deposit: 0.0,
+        min_withdrawal: KRW_MIN_WITHDRAWAL,
+    }
+}
+
+/// 출금 수수료가 가장 싼 네트워크 (리밸런싱 비용 추정 기준)
+fn cheapest_network(networks: &[BithumbNetworkFee]) -> Option<&BithumbNetworkFee> {
+    networks
+        .iter()
+        .min_by(|a, b| a.withdrawal_fee.total_cmp(&b.withdrawal_fee))
+}
+
+/// 코인별 네트워크 목록으로 변환 (응답에 원화가 없으면 고정 수수료로 추가)
+fn network_fees_by_currency(
+    api_responses: &[FeeApiResponse],
+) -> HashMap<String, Vec<BithumbNetworkFee>> {
+    let mut networks: HashMap<String, Vec<BithumbNetworkFee>> = api_responses
+        .iter()
+        .filter(|r| !r.networks.is_empty())
+        .map(|r| {
+            (
+                r.currency.to_uppercase(),
+                r.networks.iter().map(BithumbNetworkFee::from).collect(),
+            )
+        })
+        .collect();
+    networks
+        .entry("KRW".to_string())
+        .or_insert_with(|| vec![krw_network_fee()]);
+    networks
+}
+
+/// 입출금 상태 API 응답 (`status`가 "0000"이면 성공, 상태값 1 = 가능, 0 = 중단)
+#[derive(Debug, Deserialize)]
+struct AssetStatusResponse {
+    status: String,
+    #[serde(default)]
+    data: HashMap<String, AssetStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetStatus {
+    deposit_status: i64,
+    withdrawal_status: i64,
+}
+
 /// 입출금 수수료 캐시
 static FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<String, DepositWithdrawalFee>>>> =
     tokio::sync::OnceCell::const_new();
 
+/// 네트워크별 입출금 수수료 캐시 (currency -> 네트워크 목록)
+type NetworkFeeCache = Arc<RwLock<HashMap<String, Vec<BithumbNetworkFee>>>>;
+
+static NETWORK_FEE_CACHE: tokio::sync::OnceCell<NetworkFeeCache> =
+    tokio::sync::OnceCell::const_new();
+
+async fn init_network_fee_cache() -> NetworkFeeCache {
+    NETWORK_FEE_CACHE
+        .get_or_init(|| async { Arc::new(RwLock::new(HashMap::new())) })
+        .await
+        .clone()
+}
+
 /// 캐시 초기화 (한 번만 실행)
 async fn init_fee_cache() -> Arc<RwLock<HashMap<String, DepositWithdrawalFee>>> {
     FEE_CACHE
@@ -48,13 +144,57 @@ async fn init_fee_cache() -> Arc<RwLock<HashMap<String, DepositWithdrawalFee>>>
 }
 
 impl BithumbClient {
+    /// 코인별 입출금 가능 여부 (공개 API)
+    pub async fn fetch_transfer_statuses(
+        &self,
+    ) -> Result<HashMap<String, TransferStatus>, super::super::ExchangeError> {
+        let url = format!("{BASE_URL}{ASSET_STATUS_URL}");
+        let response = self.http.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(super::super::ExchangeError::Other(format!(
+                "Failed to fetch asset status API: status {}",
+                response.status()
+            )));
+        }
+
+        let response_text = response.text().await?;
+        let parsed: AssetStatusResponse = serde_json::from_str(&response_text).map_err(|e| {
+            super::super::ExchangeError::Other(format!(
+                "Failed to parse asset status response: {}, response: {}",
+                e,
+                response_text.chars().take(200).collect::<String>()
+            ))
+        })?;
+        if parsed.status != "0000" {
+            return Err(super::super::ExchangeError::Other(format!(
+                "Asset status API error: status {}",
+                parsed.status
+            )));
+        }
+
+        Ok(parsed
+            .data
+            .into_iter()
+            .map(|(currency, status)| {
+                let currency = currency.to_uppercase();
+                (
+                    currency.clone(),
+                    TransferStatus {
+                        currency,
+                        deposit_enabled: status.deposit_status == 1,
+                        withdraw_enabled: status.withdrawal_status == 1,
+                    },
+                )
+            })
+            .collect())
+    }
+
     /// 입출금 수수료 캐시 초기화 및 업데이트
     pub async fn refresh_deposit_withdrawal_fees(
         &self,
     ) -> Result<HashMap<String, DepositWithdrawalFee>, super::super::ExchangeError> {
         let url = format!("{BASE_URL}{FEE_API_URL}");
-        let http = reqwest::Client::new();
-        let response = http.get(&url).send().await?;
+        let response = self.http.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(super::super::ExchangeError::Other(format!(
@@ -73,32 +213,30 @@ impl BithumbClient {
                 ))
             })?;
 
-        let mut fees = HashMap::new();
+        let networks = network_fees_by_currency(&api_responses);
         let now = Utc::now();
 
-        for api_response in api_responses {
-            let currency = api_response.currency.to_uppercase();
-
-            // 여러 네트워크가 있는 경우, 첫 번째 네트워크 사용 (또는 평균 계산 가능)
-            // 일단 첫 번째 네트워크의 수수료 사용
-            if let Some(network) = api_response.networks.first() {
-                let deposit_fee = network.deposit_fee_quantity.parse::<f64>().unwrap_or(0.0);
-                let withdrawal_fee = network.withdraw_fee_quantity.parse::<f64>().unwrap_or(0.0);
-
-                fees.insert(
+        // 여러 네트워크가 있는 경우, 출금 수수료가 가장 싼 네트워크 기준
+        let fees: HashMap<String, DepositWithdrawalFee> = networks
+            .iter()
+            .filter_map(|(currency, list)| {
+                let network = cheapest_network(list)?;
+                Some((
                     currency.clone(),
                     DepositWithdrawalFee {
-                        currency,
-                        deposit_fee,
-                        withdrawal_fee,
+                        currency: currency.clone(),
+                        deposit_fee: network.deposit_fee,
+                        withdrawal_fee: network.withdrawal_fee,
                         updated_at: now,
                     },
-                );
-            }
-        }
+                ))
+            })
+            .collect();
 
         tracing::info!("Parsed {} deposit/withdrawal fees from API", fees.len());
 
+        *init_network_fee_cache().await.write().await = networks;
+
         // 캐시 업데이트
         let cache = init_fee_cache().await;
         *cache.write().await = fees.clone();
@@ -120,6 +258,26 @@ impl BithumbClient {
 
         Ok(cache)
     }
+
+    /// 코인별 네트워크 수수료와 최소 입출금 수량 (원화는 `KRW`)
+    pub async fn get_network_fees(
+        &self,
+        currency: &str,
+    ) -> Result<Vec<BithumbNetworkFee>, super::super::ExchangeError> {
+        let cache = init_network_fee_cache().await;
+        if cache.read().await.is_empty() {
+            self.refresh_deposit_withdrawal_fees().await?;
+        }
+
+        let currency_upper = currency.to_uppercase();
+        let cache_guard = cache.read().await;
+        cache_guard.get(&currency_upper).cloned().ok_or_else(|| {
+            super::super::ExchangeError::Other(format!(
+                "Network fee information not found for currency: {}",
+                currency
+            ))
+        })
+    }
 }
 
 #[async_trait]
@@ -158,6 +316,34 @@ impl FeeExchange for BithumbClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_network_fees_by_currency() {
+        let api_responses: Vec<FeeApiResponse> = serde_json::from_str(
+            r#"[
+                {"name": "테더", "currency": "usdt", "networks": [
+                    {"net_name": "ERC20", "deposit_fee_quantity": "0", "deposit_minimum_quantity": "1",
+                     "withdraw_fee_quantity": "10", "withdraw_minimum_quantity": "20"},
+                    {"net_name": "TRC20", "deposit_fee_quantity": "0", "deposit_minimum_quantity": "1",
+                     "withdraw_fee_quantity": "1.5", "withdraw_minimum_quantity": "5"}
+                ]},
+                {"name": "없음", "currency": "XYZ", "networks": []}
+            ]"#,
+        )
+        .unwrap();
+
+        let networks = network_fees_by_currency(&api_responses);
+        assert!(!networks.contains_key("XYZ"));
+
+        let usdt = cheapest_network(&networks["USDT"]).unwrap();
+        assert_eq!(usdt.network, "TRC20");
+        assert_eq!(usdt.withdrawal_fee, 1.5);
+        assert_eq!(usdt.min_withdrawal, 5.0);
+
+        // 응답에 원화가 없으면 고정 수수료로 추가
+        assert_eq!(networks["KRW"], vec![krw_network_fee()]);
+        assert_eq!(networks["KRW"][0].withdrawal_fee, KRW_WITHDRAWAL_FEE);
+    }
+
     #[tokio::test]
     async fn test_refresh_deposit_withdrawal_fees() {
         let client = BithumbClient::new();
@@ -6,11 +6,13 @@ use serde::Serialize;
 use uuid::Uuid;
 
 use super::ExchangeError;
+use stream::BithumbSpotStream;
 
 pub mod asset;
 pub mod fee;
 pub mod orderbook;
 pub mod spot;
+pub mod stream;
 
 pub const BASE_URL: &str = "https://api.bithumb.com";
 
@@ -57,25 +59,40 @@ pub struct BithumbClient {
     pub(crate) http: reqwest::Client,
     pub(crate) api_key: Option<String>,
     pub(crate) api_secret: Option<String>,
+    /// WebSocket 현물 가격 (설정 시 REST 현물 스냅샷 가격을 최신 체결가로 덮어씀)
+    pub(crate) spot_stream: Option<BithumbSpotStream>,
 }
 
 impl BithumbClient {
     /// 공개 API만 사용하는 경우 (Orderbook 등)
     pub fn new() -> Self {
         Self {
-            http: reqwest::Client::new(),
+            http: crate::http::http_client(),
             api_key: None,
             api_secret: None,
+            spot_stream: None,
         }
     }
 
+    /// 공개 API + WebSocket 현물 가격 스트림 (oracle 현물 수집용)
+    pub fn with_spot_stream() -> Self {
+        let mut client = Self::new();
+        client.spot_stream = Some(BithumbSpotStream::start(client.http.clone()));
+        client
+    }
+
+    pub fn spot_stream(&self) -> Option<&BithumbSpotStream> {
+        self.spot_stream.as_ref()
+    }
+
     /// 인증이 필요한 API를 사용하는 경우 (Asset, Fee 등)
     pub fn with_credentials() -> Result<Self, ExchangeError> {
         let (api_key, api_secret) = get_api_credentials()?;
         Ok(Self {
-            http: reqwest::Client::new(),
+            http: crate::http::http_client(),
             api_key: Some(api_key),
             api_secret: Some(api_secret),
+            spot_stream: None,
         })
     }
 }
@@ -4,8 +4,10 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
+use crate::bithumb::stream::{apply_live_prices, LIVE_PRICE_MAX_AGE};
+use crate::status::status_registry;
 use crate::{bithumb::BithumbClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
+use interface::{Currency, ExchangeId, PayloadParser, Price, SpotSnapshot};
 
 const BASE_URL: &str = "https://api.bithumb.com";
 
@@ -30,6 +32,16 @@ impl SpotExchange for BithumbClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
+        status_registry().track(
+            ExchangeId::Bithumb,
+            "spot",
+            self.fetch_spot_snapshots().await,
+        )
+    }
+}
+
+impl BithumbClient {
+    async fn fetch_spot_snapshots(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
         // 빗썸은 원화(KRW) 거래쌍을 제공
         let url = format!("{BASE_URL}/public/ticker/ALL_KRW");
         let response: BithumbResponse = self.http.get(&url).send().await?.json().await?;
@@ -42,6 +54,7 @@ impl SpotExchange for BithumbClient {
         }
 
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Bithumb);
         let mut out = Vec::new();
 
         for (symbol, value) in response.data {
@@ -60,9 +73,8 @@ impl SpotExchange for BithumbClient {
             // 빗썸은 원화 거래쌍이지만, 통일성을 위해 USDT 형식으로 변환
             let symbol_usdt = format!("{}USDT", symbol);
 
-            let price: f64 = match ticker.closing_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(price) = parser.required("price", &ticker.closing_price) else {
+                continue;
             };
 
             // price가 0보다 큰 경우만 추가
@@ -73,7 +85,10 @@ impl SpotExchange for BithumbClient {
             // 빗썸은 원화 기준 거래량이므로, USD로 변환 필요
             // 하지만 정확한 환율 정보가 없으므로 일단 원화 거래량을 그대로 사용
             // 또는 0으로 설정하고 나중에 환율 정보를 추가할 수 있음
-            let vol_24h_krw: f64 = ticker.acc_trade_value_24h.parse().unwrap_or(0.0);
+            let Ok(vol_24h_krw) = parser.optional("vol_24h_krw", &ticker.acc_trade_value_24h)
+            else {
+                continue;
+            };
             // 원화를 USD로 변환 (대략 1 USD = 1300 KRW 가정, 실제로는 환율 API 필요)
             let vol_24h_usd = vol_24h_krw / 1300.0;
 
@@ -81,12 +96,17 @@ impl SpotExchange for BithumbClient {
                 exchange: ExchangeId::Bithumb,
                 symbol: symbol_usdt,
                 currency: Currency::KRW, // 빗썸은 원화 거래쌍
-                price,
+                price: Price::new(price),
                 vol_24h_usd,
                 updated_at: now,
             });
         }
 
+        // WebSocket 체결가가 더 최신이면 덮어씀
+        if let Some(stream) = &self.spot_stream {
+            apply_live_prices(&mut out, &stream.fresh_prices(LIVE_PRICE_MAX_AGE).await);
+        }
+
         Ok(out)
     }
 }
@@ -115,7 +135,7 @@ mod tests {
                 for snapshot in &snapshots {
                     assert_eq!(snapshot.exchange, ExchangeId::Bithumb);
                     assert!(snapshot.symbol.ends_with("USDT"));
-                    assert!(snapshot.price > 0.0);
+                    assert!(snapshot.price.value() > 0.0);
                     assert!(snapshot.vol_24h_usd >= 0.0);
                 }
 
@@ -0,0 +1,270 @@
+//! 빗썸 공개 WebSocket 현물 가격 스트림
+//!
+//! ticker(24H)와 transaction 채널을 구독해 원화 마켓 심볼별 최신 체결가를 보관한다.
+//! REST 수집 주기(10초) 사이에도 김프 계산에 쓰는 원화 가격이 1초 이내로 유지되도록
+//! oracle 현물 스냅샷에 덮어쓴다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::RwLock;
+
+use crate::supervisor::{task_supervisor, WS_STALE_AFTER};
+use crate::ws::{Heartbeat, PingMessage, ReconnectConfig, ReconnectingClient, WsHandler};
+use interface::{ExchangeId, PayloadParser, Price, SpotSnapshot};
+
+const WS_URL: &str = "wss://pubwss.bithumb.com/pub/ws";
+
+/// 이보다 오래된 WebSocket 가격은 사용하지 않음 (REST 값 유지)
+pub const LIVE_PRICE_MAX_AGE: Duration = Duration::from_secs(5);
+
+/// WebSocket으로 받은 최신 가격
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LivePrice {
+    /// 원화 가격
+    pub price: Price,
+    /// 수신 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 심볼("BTCUSDT" 형식, 현물 스냅샷과 동일)별 최신 가격
+#[derive(Clone, Default)]
+pub struct BithumbSpotStream {
+    prices: Arc<RwLock<HashMap<String, LivePrice>>>,
+}
+
+impl BithumbSpotStream {
+    /// 백그라운드에서 WebSocket 연결 시작
+    pub fn start(http: reqwest::Client) -> Self {
+        let stream = Self::default();
+        let client = ReconnectingClient::new("Bithumb spot", WS_URL)
+            .with_config(ReconnectConfig {
+                heartbeat: Some(Heartbeat {
+                    interval: Duration::from_secs(20),
+                    timeout: Duration::from_secs(60),
+                    message: PingMessage::Frame,
+                }),
+                ..Default::default()
+            })
+            .with_status(ExchangeId::Bithumb, "spot_ws");
+        let prices = stream.prices.clone();
+        task_supervisor().spawn("bithumb_spot_ws", Some(WS_STALE_AFTER), move || {
+            let client = client.clone();
+            let mut handler = BithumbSpotHandler {
+                http: http.clone(),
+                prices: prices.clone(),
+            };
+            async move {
+                client.run(&mut handler).await;
+            }
+        });
+        stream
+    }
+
+    /// `max_age` 이내에 갱신된 가격
+    pub async fn fresh_prices(&self, max_age: Duration) -> HashMap<String, LivePrice> {
+        let max_age = chrono::Duration::from_std(max_age).unwrap_or(chrono::Duration::zero());
+        let cutoff = Utc::now() - max_age;
+        self.prices
+            .read()
+            .await
+            .iter()
+            .filter(|(_, p)| p.updated_at >= cutoff)
+            .map(|(symbol, p)| (symbol.clone(), *p))
+            .collect()
+    }
+}
+
+/// 빗썸 현물 스냅샷 가격을 WebSocket 가격으로 덮어쓴다. 갱신 개수 반환
+/// updated_at은 WebSocket 수신 시각으로 바뀐다 (REST 조회 시각이 아닌 실제 가격 시각)
+pub fn apply_live_prices(
+    snapshots: &mut [SpotSnapshot],
+    live: &HashMap<String, LivePrice>,
+) -> usize {
+    let mut updated = 0;
+    for snapshot in snapshots
+        .iter_mut()
+        .filter(|s| s.exchange == ExchangeId::Bithumb)
+    {
+        if let Some(live) = live.get(&snapshot.symbol) {
+            snapshot.price = live.price;
+            snapshot.updated_at = live.updated_at;
+            updated += 1;
+        }
+    }
+    updated
+}
+
+/// "BTC_KRW" → "BTCUSDT" (REST 현물 스냅샷과 같은 심볼 형식)
+fn normalize_symbol(market: &str) -> Option<String> {
+    market
+        .strip_suffix("_KRW")
+        .map(|base| format!("{}USDT", base))
+}
+
+#[derive(Debug, Deserialize)]
+struct WsMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    content: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TickerContent {
+    symbol: String,
+    close_price: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransactionContent {
+    list: Vec<TransactionItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionItem {
+    symbol: String,
+    cont_price: String,
+}
+
+/// ticker/transaction 메시지에서 (심볼, 가격) 추출. 구독 응답 등은 빈 목록
+fn parse_prices(text: &str) -> Vec<(String, f64)> {
+    let Ok(message) = serde_json::from_str::<WsMessage>(text) else {
+        return Vec::new();
+    };
+    let parser = PayloadParser::new(ExchangeId::Bithumb);
+    let raw: Vec<(String, String)> = match message.kind.as_str() {
+        "ticker" => serde_json::from_value::<TickerContent>(message.content)
+            .map(|t| vec![(t.symbol, t.close_price)])
+            .unwrap_or_default(),
+        // 한 메시지에 여러 체결이 오면 마지막 체결가가 최신
+        "transaction" => serde_json::from_value::<TransactionContent>(message.content)
+            .map(|t| {
+                t.list
+                    .into_iter()
+                    .map(|i| (i.symbol, i.cont_price))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    raw.into_iter()
+        .filter_map(|(market, price)| {
+            let symbol = normalize_symbol(&market)?;
+            let price = parser.required("price", &price).ok()?;
+            (price > 0.0).then_some((symbol, price))
+        })
+        .collect()
+}
+
+/// ReconnectingClient 용 ticker/transaction 핸들러
+struct BithumbSpotHandler {
+    http: reqwest::Client,
+    prices: Arc<RwLock<HashMap<String, LivePrice>>>,
+}
+
+#[async_trait]
+impl WsHandler for BithumbSpotHandler {
+    /// 연결(재연결 포함)마다 원화 마켓 목록을 새로 가져와 구독
+    async fn subscriptions(&mut self) -> eyre::Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct AllTickerResponse {
+            status: String,
+            data: HashMap<String, serde_json::Value>,
+        }
+
+        let url = format!("{}/public/ticker/ALL_KRW", super::BASE_URL);
+        let response: AllTickerResponse = self.http.get(&url).send().await?.json().await?;
+        if response.status != "0000" {
+            return Err(eyre::eyre!("Bithumb API error: status {}", response.status));
+        }
+
+        let mut markets: Vec<String> = response
+            .data
+            .keys()
+            .filter(|k| k.as_str() != "date")
+            .map(|k| format!("{}_KRW", k))
+            .collect();
+        markets.sort();
+        tracing::info!("Bithumb 현물 WebSocket 구독 시작: {}개 심볼", markets.len());
+
+        Ok(vec![
+            json!({ "type": "ticker", "symbols": markets, "tickTypes": ["24H"] }).to_string(),
+            json!({ "type": "transaction", "symbols": markets }).to_string(),
+        ])
+    }
+
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+        let prices = parse_prices(text);
+        if prices.is_empty() {
+            return Ok(());
+        }
+        let now = Utc::now();
+        let mut guard = self.prices.write().await;
+        for (symbol, price) in prices {
+            guard.insert(
+                symbol,
+                LivePrice {
+                    price: Price::new(price),
+                    updated_at: now,
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Currency;
+
+    #[test]
+    fn test_parse_prices_and_apply() {
+        let ticker = r#"{"type":"ticker","content":{"symbol":"BTC_KRW","tickType":"24H","date":"20240101","time":"121844","openPrice":"2302","closePrice":"2317","lowPrice":"2272","highPrice":"2344","value":"2831915078.07065789","volume":"1222314.51355788","prevClosePrice":"2302","chgRate":"0.65","chgAmt":"15","volumePower":"60.00"}}"#;
+        assert_eq!(parse_prices(ticker), vec![("BTCUSDT".to_string(), 2317.0)]);
+
+        let transaction = r#"{"type":"transaction","content":{"list":[{"symbol":"ETH_KRW","buySellGb":"1","contPrice":"10579000","contQty":"0.01","contAmt":"105790.00","contDtm":"2024-01-01 12:24:18.830039","updn":"dn"},{"symbol":"ETH_KRW","buySellGb":"2","contPrice":"10580000","contQty":"0.01","contAmt":"105800.00","contDtm":"2024-01-01 12:24:18.831000","updn":"up"}]}}"#;
+        let prices = parse_prices(transaction);
+        assert_eq!(prices.last(), Some(&("ETHUSDT".to_string(), 10_580_000.0)));
+
+        assert!(parse_prices(r#"{"status":"0000","resmsg":"Connected Successfully"}"#).is_empty());
+
+        let old = Utc::now() - chrono::Duration::seconds(10);
+        let snapshot = SpotSnapshot {
+            exchange: ExchangeId::Bithumb,
+            symbol: "BTCUSDT".to_string(),
+            currency: Currency::KRW,
+            price: Price::new(2300.0),
+            vol_24h_usd: 1.0,
+            updated_at: old,
+        };
+        let mut snapshots = vec![
+            snapshot.clone(),
+            SpotSnapshot {
+                exchange: ExchangeId::Binance,
+                currency: Currency::USDT,
+                ..snapshot
+            },
+        ];
+        let live = HashMap::from([(
+            "BTCUSDT".to_string(),
+            LivePrice {
+                price: Price::new(2317.0),
+                updated_at: Utc::now(),
+            },
+        )]);
+        assert_eq!(apply_live_prices(&mut snapshots, &live), 1);
+        assert_eq!(snapshots[0].price, Price::new(2317.0));
+        assert!(snapshots[0].updated_at > old);
+        // 다른 거래소 스냅샷은 그대로
+        assert_eq!(snapshots[1].price, Price::new(2300.0));
+    }
+}
@@ -1,22 +1,224 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::RwLock;
 
+use crate::status::status_registry;
+use crate::supervisor::{task_supervisor, WS_STALE_AFTER};
+use crate::ws::{
+    diff_symbols, ConnectionState, Heartbeat, PingMessage, ReconnectConfig, ReconnectingClient,
+    WsHandler,
+};
 use crate::{ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
+use interface::{utc_from_epoch_str, Currency, ExchangeId, PayloadParser, PerpSnapshot, Price};
 
 const BASE_URL: &str = "https://api.bybit.com";
+const WS_URL: &str = "wss://stream.bybit.com/v5/public/linear";
+/// 연결을 유지한 채 USDT 무기한 심볼 목록을 다시 확인하는 주기 (신규 상장/상장 폐지 반영)
+const SYMBOL_REFRESH_INTERVAL: Duration = Duration::from_secs(600);
+/// 구독 메시지 하나에 담는 최대 토픽 수
+const SUBSCRIBE_BATCH: usize = 10;
+
+/// 심볼별 펀딩 주기를 다시 조회하는 주기 (상장/주기 변경이 드물어 틱마다 조회하지 않음)
+const FUNDING_INTERVAL_TTL: Duration = Duration::from_secs(3600);
+
+/// 조회 시각, 심볼별 펀딩 주기 (symbol -> 시간)
+type FundingIntervalCache = Option<(Instant, HashMap<String, f64>)>;
+
+static FUNDING_INTERVALS: OnceLock<Mutex<FundingIntervalCache>> = OnceLock::new();
+
+/// 심볼별 최신 티커 (WebSocket tickers 채널 또는 REST로 채움)
+type TickerCache = Arc<RwLock<HashMap<String, BybitTicker>>>;
 
 #[derive(Clone)]
 pub struct BybitClient {
     pub(crate) http: reqwest::Client,
+    /// 펀딩비/다음 정산 시각을 포함한 티커 캐시
+    ticker_cache: TickerCache,
+    /// tickers 채널 연결 및 구독 완료 여부 (끊겨 있으면 REST로 조회)
+    ws_connected: Arc<AtomicBool>,
 }
 
 impl BybitClient {
     pub fn new() -> Self {
-        Self {
-            http: reqwest::Client::new(),
+        let client = Self {
+            http: crate::http::http_client(),
+            ticker_cache: Arc::new(RwLock::new(HashMap::new())),
+            ws_connected: Arc::new(AtomicBool::new(false)),
+        };
+
+        // WebSocket 연결을 감시 태스크로 시작 (종료되거나 멈추면 재시작)
+        let cache = client.ticker_cache.clone();
+        let connected = client.ws_connected.clone();
+        task_supervisor().spawn("bybit_funding_ws", Some(WS_STALE_AFTER), move || {
+            Self::start_websocket(cache.clone(), connected.clone())
+        });
+
+        client
+    }
+
+    async fn start_websocket(cache: TickerCache, connected: Arc<AtomicBool>) {
+        // Bybit은 20초마다 ping을 보내도록 권장
+        let client = ReconnectingClient::new("Bybit tickers", WS_URL)
+            .with_config(ReconnectConfig {
+                initial_backoff: Duration::from_secs(1),
+                max_backoff: Duration::from_secs(60),
+                multiplier: 2.0,
+                subscribe_interval: Duration::from_millis(100),
+                heartbeat: Some(Heartbeat {
+                    interval: Duration::from_secs(20),
+                    timeout: Duration::from_secs(60),
+                    message: PingMessage::Text(r#"{"op":"ping"}"#.to_string()),
+                }),
+                resubscribe_interval: Some(SYMBOL_REFRESH_INTERVAL),
+            })
+            .with_status(ExchangeId::Bybit, "funding_ws");
+
+        let mut handler = BybitTickerHandler {
+            http: crate::http::http_client(),
+            cache,
+            connected,
+            subscribed: HashSet::new(),
+        };
+        client.run(&mut handler).await;
+    }
+
+    /// REST로 선형 티커 전체 조회
+    async fn fetch_tickers(http: &reqwest::Client) -> Result<Vec<BybitTicker>, ExchangeError> {
+        let url = format!("{BASE_URL}/v5/market/tickers?category=linear");
+        let response: BybitTickerResponse = http.get(&url).send().await?.json().await?;
+
+        if response.ret_code != 0 {
+            return Err(ExchangeError::Other(format!(
+                "Bybit API error: {} - {}",
+                response.ret_code, response.ret_msg
+            )));
+        }
+        Ok(response.result.list)
+    }
+
+    /// USDT 무기한 심볼 목록
+    async fn fetch_usdt_symbols(http: &reqwest::Client) -> eyre::Result<Vec<String>> {
+        Ok(Self::fetch_tickers(http)
+            .await
+            .map_err(|e| eyre::eyre!("{}", e))?
+            .into_iter()
+            .filter(|t| t.symbol.ends_with("USDT"))
+            .map(|t| t.symbol)
+            .collect())
+    }
+
+    /// tickers 채널 구독/해지 메시지 생성 (op: "subscribe" | "unsubscribe")
+    fn ticker_channel_messages(op: &str, symbols: &[String]) -> eyre::Result<Vec<String>> {
+        symbols
+            .chunks(SUBSCRIBE_BATCH)
+            .map(|chunk| {
+                let args: Vec<String> = chunk.iter().map(|s| format!("tickers.{}", s)).collect();
+                Ok(serde_json::to_string(&json!({ "op": op, "args": args }))?)
+            })
+            .collect()
+    }
+
+    /// tickers 메시지 반영. snapshot은 교체, delta는 값이 있는 필드만 덮어쓴다
+    async fn handle_ws_message(text: &str, cache: &TickerCache) -> eyre::Result<()> {
+        #[derive(Debug, Deserialize)]
+        struct WsTickerMessage {
+            #[serde(default)]
+            topic: String,
+            #[serde(default, rename = "type")]
+            kind: String,
+            data: Option<BybitTicker>,
+        }
+
+        // 구독 확인/pong 등은 무시
+        let Ok(message) = serde_json::from_str::<WsTickerMessage>(text) else {
+            return Ok(());
+        };
+        let (true, Some(ticker)) = (message.topic.starts_with("tickers."), message.data) else {
+            return Ok(());
+        };
+
+        let mut guard = cache.write().await;
+        match guard.get_mut(&ticker.symbol) {
+            Some(current) if message.kind == "delta" => current.merge(ticker),
+            _ => {
+                guard.insert(ticker.symbol.clone(), ticker);
+            }
         }
+        Ok(())
+    }
+}
+
+/// ReconnectingClient 용 tickers 채널 핸들러
+struct BybitTickerHandler {
+    http: reqwest::Client,
+    cache: TickerCache,
+    connected: Arc<AtomicBool>,
+    /// 현재 연결에서 구독 중인 심볼
+    subscribed: HashSet<String>,
+}
+
+#[async_trait]
+impl WsHandler for BybitTickerHandler {
+    /// 연결(재연결 포함)마다 USDT 무기한 심볼 목록을 새로 가져와 전체 구독
+    async fn subscriptions(&mut self) -> eyre::Result<Vec<String>> {
+        let symbols = BybitClient::fetch_usdt_symbols(&self.http).await?;
+        tracing::info!("Bybit tickers 채널 구독 시작: {}개 심볼", symbols.len());
+        let messages = BybitClient::ticker_channel_messages("subscribe", &symbols)?;
+        self.subscribed = symbols.into_iter().collect();
+        Ok(messages)
+    }
+
+    /// 심볼 목록 변경분만 기존 연결에 추가 구독/해지
+    async fn resubscribe(&mut self) -> eyre::Result<Vec<String>> {
+        let symbols = BybitClient::fetch_usdt_symbols(&self.http).await?;
+        let (added, removed) = diff_symbols(&self.subscribed, &symbols);
+        if added.is_empty() && removed.is_empty() {
+            return Ok(Vec::new());
+        }
+        tracing::info!(
+            "Bybit tickers 구독 갱신: 추가 {:?}, 해지 {:?}",
+            added,
+            removed
+        );
+
+        let mut messages = BybitClient::ticker_channel_messages("subscribe", &added)?;
+        messages.extend(BybitClient::ticker_channel_messages(
+            "unsubscribe",
+            &removed,
+        )?);
+
+        // 상장 폐지된 심볼은 더 이상 갱신되지 않으므로 제거
+        if !removed.is_empty() {
+            let mut guard = self.cache.write().await;
+            for symbol in &removed {
+                guard.remove(symbol);
+                self.subscribed.remove(symbol);
+            }
+        }
+        self.subscribed.extend(added);
+        Ok(messages)
+    }
+
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+        if let Err(e) = BybitClient::handle_ws_message(text, &self.cache).await {
+            tracing::warn!("Bybit WebSocket 메시지 처리 오류: {:?}", e);
+        }
+        Ok(())
+    }
+
+    fn on_state_change(&mut self, state: ConnectionState) {
+        let connected = state == ConnectionState::Connected;
+        if connected {
+            tracing::info!("Bybit tickers 채널 구독 완료");
+        }
+        self.connected.store(connected, Ordering::Relaxed);
     }
 }
 
@@ -35,13 +237,15 @@ struct BybitTickerResult {
     list: Vec<BybitTicker>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BybitTicker {
     symbol: String,
     #[serde(default)]
     mark_price: String,
     #[serde(default)]
+    index_price: String,
+    #[serde(default)]
     funding_rate: String,
     #[serde(default)]
     open_interest: String,
@@ -51,6 +255,50 @@ struct BybitTicker {
     next_funding_time: String,
 }
 
+impl BybitTicker {
+    /// WebSocket delta 반영 (바뀐 필드만 들어오므로 빈 값은 유지)
+    fn merge(&mut self, delta: BybitTicker) {
+        let fields = [
+            (&mut self.mark_price, delta.mark_price),
+            (&mut self.index_price, delta.index_price),
+            (&mut self.funding_rate, delta.funding_rate),
+            (&mut self.open_interest, delta.open_interest),
+            (&mut self.turnover24h, delta.turnover24h),
+            (&mut self.next_funding_time, delta.next_funding_time),
+        ];
+        for (current, value) in fields {
+            if !value.is_empty() {
+                *current = value;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitInstrumentsResponse {
+    ret_code: i32,
+    ret_msg: String,
+    result: BybitInstrumentsResult,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitInstrumentsResult {
+    list: Vec<BybitInstrument>,
+    #[serde(default)]
+    next_page_cursor: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitInstrument {
+    symbol: String,
+    /// 펀딩 주기 (분, 일부 계약은 60/240)
+    #[serde(default)]
+    funding_interval: Option<u32>,
+}
+
 #[async_trait]
 impl PerpExchange for BybitClient {
     fn id(&self) -> ExchangeId {
@@ -58,59 +306,205 @@ impl PerpExchange for BybitClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
-        let url = format!("{BASE_URL}/v5/market/tickers?category=linear");
-        let response: BybitTickerResponse = self.http.get(&url).send().await?.json().await?;
+        status_registry().track(ExchangeId::Bybit, "perp", self.fetch_perp_snapshots().await)
+    }
+}
 
-        if response.ret_code != 0 {
-            return Err(ExchangeError::Other(format!(
-                "Bybit API error: {} - {}",
-                response.ret_code, response.ret_msg
-            )));
+impl BybitClient {
+    /// WebSocket 캐시의 티커. 연결이 끊겼거나 캐시가 비어 있으면 REST로 조회해 캐시를 채운다
+    async fn tickers(&self) -> Result<Vec<BybitTicker>, ExchangeError> {
+        if self.ws_connected.load(Ordering::Relaxed) {
+            let cache = self.ticker_cache.read().await;
+            if !cache.is_empty() {
+                return Ok(cache.values().cloned().collect());
+            }
         }
 
+        let tickers = Self::fetch_tickers(&self.http).await?;
+        let mut cache = self.ticker_cache.write().await;
+        for ticker in &tickers {
+            if ticker.symbol.ends_with("USDT") {
+                cache.insert(ticker.symbol.clone(), ticker.clone());
+            }
+        }
+        Ok(tickers)
+    }
+
+    async fn fetch_perp_snapshots(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
+        let tickers = self.tickers().await?;
+
+        let funding_intervals = self.funding_intervals().await;
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Bybit);
         let mut out = Vec::new();
 
-        for ticker in response.result.list {
+        for ticker in tickers {
             if !ticker.symbol.ends_with("USDT") {
                 continue; // 선형 USDT perp만
             }
 
-            let mark_price: f64 = match ticker.mark_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(mark_price) = parser.required("mark_price", &ticker.mark_price) else {
+                continue;
             };
 
-            let funding_rate: f64 = ticker.funding_rate.parse().unwrap_or(0.0);
+            let Ok(index_price) = parser.optional("index_price", &ticker.index_price) else {
+                continue;
+            };
 
-            let oi_contracts: f64 = ticker.open_interest.parse().unwrap_or(0.0);
-            let oi_usd = oi_contracts * mark_price;
+            let Ok(funding_rate) = parser.optional("funding_rate", &ticker.funding_rate) else {
+                continue;
+            };
 
-            let vol_24h_usd: f64 = ticker.turnover24h.parse().unwrap_or(0.0);
+            let Ok(oi_contracts) = parser.optional("oi_contracts", &ticker.open_interest) else {
+                continue;
+            };
+            let oi_usd = oi_contracts * mark_price;
 
-            let next_funding_time: Option<DateTime<Utc>> = if !ticker.next_funding_time.is_empty() {
-                ticker
-                    .next_funding_time
-                    .parse::<i64>()
-                    .ok()
-                    .and_then(|ts| DateTime::from_timestamp_millis(ts))
-            } else {
-                None
+            let Ok(vol_24h_usd) = parser.optional("vol_24h_usd", &ticker.turnover24h) else {
+                continue;
             };
 
+            let next_funding_time = utc_from_epoch_str(&ticker.next_funding_time);
+
+            let funding_interval_hours = funding_intervals.get(&ticker.symbol).copied();
+
             out.push(PerpSnapshot {
                 exchange: ExchangeId::Bybit,
                 symbol: ticker.symbol,
                 currency: Currency::USDT,
-                mark_price,
+                mark_price: Price::new(mark_price),
+                index_price: (index_price > 0.0).then_some(Price::new(index_price)),
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
+                funding_interval_hours,
                 updated_at: now,
             });
         }
 
         Ok(out)
     }
+    /// 심볼별 펀딩 주기 (캐시가 만료됐으면 다시 조회, 실패하면 직전 값 또는 빈 맵)
+    async fn funding_intervals(&self) -> HashMap<String, f64> {
+        let cache = FUNDING_INTERVALS.get_or_init(|| Mutex::new(None));
+        if let Some((fetched_at, intervals)) = cache.lock().unwrap().as_ref() {
+            if fetched_at.elapsed() < FUNDING_INTERVAL_TTL {
+                return intervals.clone();
+            }
+        }
+
+        match self.fetch_funding_intervals().await {
+            Ok(intervals) => {
+                *cache.lock().unwrap() = Some((Instant::now(), intervals.clone()));
+                intervals
+            }
+            Err(e) => {
+                tracing::warn!("Bybit 펀딩 주기 조회 실패: {}", e);
+                cache
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .map(|(_, intervals)| intervals.clone())
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    /// `/v5/market/instruments-info`의 fundingInterval(분)을 시간 단위로 (페이지 전체)
+    async fn fetch_funding_intervals(&self) -> Result<HashMap<String, f64>, ExchangeError> {
+        let mut intervals = HashMap::new();
+        let mut cursor = String::new();
+        loop {
+            let url = format!(
+                "{BASE_URL}/v5/market/instruments-info?category=linear&limit=1000&cursor={cursor}"
+            );
+            let response: BybitInstrumentsResponse =
+                self.http.get(&url).send().await?.json().await?;
+            if response.ret_code != 0 {
+                return Err(ExchangeError::Other(format!(
+                    "Bybit API error: {} - {}",
+                    response.ret_code, response.ret_msg
+                )));
+            }
+
+            intervals.extend(funding_interval_hours(&response.result.list));
+            if response.result.next_page_cursor.is_empty() {
+                return Ok(intervals);
+            }
+            cursor = response.result.next_page_cursor;
+        }
+    }
+}
+
+/// fundingInterval(분)이 있는 계약만 시간 단위로 변환
+fn funding_interval_hours(instruments: &[BybitInstrument]) -> HashMap<String, f64> {
+    instruments
+        .iter()
+        .filter_map(|i| {
+            let minutes = i.funding_interval.filter(|m| *m > 0)?;
+            Some((i.symbol.clone(), minutes as f64 / 60.0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_funding_interval_hours() {
+        let response: BybitInstrumentsResponse = serde_json::from_str(
+            r#"{"retCode": 0, "retMsg": "OK", "result": {"category": "linear", "list": [
+                {"symbol": "BTCUSDT", "fundingInterval": 480},
+                {"symbol": "MEMEUSDT", "fundingInterval": 60},
+                {"symbol": "BTC-27DEC24", "fundingInterval": 0},
+                {"symbol": "NEWUSDT"}
+            ], "nextPageCursor": ""}}"#,
+        )
+        .unwrap();
+
+        let intervals = funding_interval_hours(&response.result.list);
+        assert_eq!(intervals.len(), 2);
+        assert_eq!(intervals["BTCUSDT"], 8.0);
+        assert_eq!(intervals["MEMEUSDT"], 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_ticker_ws_snapshot_and_delta() {
+        let cache: TickerCache = Arc::new(RwLock::new(HashMap::new()));
+        BybitClient::handle_ws_message(
+            r#"{"topic":"tickers.BTCUSDT","type":"snapshot","data":{"symbol":"BTCUSDT",
+                "markPrice":"60000","indexPrice":"59990","fundingRate":"0.0001",
+                "openInterest":"100","turnover24h":"5000000","nextFundingTime":"1714579200000"}}"#,
+            &cache,
+        )
+        .await
+        .unwrap();
+        BybitClient::handle_ws_message(
+            r#"{"topic":"tickers.BTCUSDT","type":"delta","data":{"symbol":"BTCUSDT",
+                "fundingRate":"0.00015"}}"#,
+            &cache,
+        )
+        .await
+        .unwrap();
+        BybitClient::handle_ws_message(r#"{"op":"pong","success":true}"#, &cache)
+            .await
+            .unwrap();
+
+        let guard = cache.read().await;
+        assert_eq!(guard.len(), 1);
+        let btc = &guard["BTCUSDT"];
+        assert_eq!(btc.funding_rate, "0.00015");
+        assert_eq!(btc.mark_price, "60000");
+        assert_eq!(btc.next_funding_time, "1714579200000");
+
+        let symbols: Vec<String> = (0..25).map(|i| format!("S{}USDT", i)).collect();
+        let messages = BybitClient::ticker_channel_messages("subscribe", &symbols).unwrap();
+        assert_eq!(messages.len(), 3);
+        let first: serde_json::Value = serde_json::from_str(&messages[0]).unwrap();
+        assert_eq!(first["op"], "subscribe");
+        assert_eq!(first["args"][0], "tickers.S0USDT");
+        assert_eq!(first["args"].as_array().unwrap().len(), 10);
+    }
 }
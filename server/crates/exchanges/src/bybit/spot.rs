@@ -2,8 +2,9 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
+use crate::status::status_registry;
 use crate::{BybitClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
+use interface::{Currency, ExchangeId, PayloadParser, Price, SpotSnapshot};
 
 const BASE_URL: &str = "https://api.bybit.com";
 
@@ -39,6 +40,12 @@ impl SpotExchange for BybitClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
+        status_registry().track(ExchangeId::Bybit, "spot", self.fetch_spot_snapshots().await)
+    }
+}
+
+impl BybitClient {
+    async fn fetch_spot_snapshots(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
         let url = format!("{BASE_URL}/v5/market/tickers?category=spot");
         let response: BybitSpotTickerResponse = self.http.get(&url).send().await?.json().await?;
 
@@ -50,6 +57,7 @@ impl SpotExchange for BybitClient {
         }
 
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Bybit);
         let mut out = Vec::new();
 
         for ticker in response.result.list {
@@ -57,9 +65,8 @@ impl SpotExchange for BybitClient {
                 continue; // USDT 페어만
             }
 
-            let price: f64 = match ticker.last_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(price) = parser.required("price", &ticker.last_price) else {
+                continue;
             };
 
             // price가 0보다 큰 경우만 추가
@@ -67,13 +74,15 @@ impl SpotExchange for BybitClient {
                 continue;
             }
 
-            let vol_24h_usd: f64 = ticker.turnover24h.parse().unwrap_or(0.0);
+            let Ok(vol_24h_usd) = parser.optional("vol_24h_usd", &ticker.turnover24h) else {
+                continue;
+            };
 
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Bybit,
                 symbol: ticker.symbol,
                 currency: Currency::USDT,
-                price,
+                price: Price::new(price),
                 vol_24h_usd,
                 updated_at: now,
             });
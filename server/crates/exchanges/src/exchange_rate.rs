@@ -25,7 +25,7 @@ struct ExchangeRateRates {
 /// USD/KRW 환율을 가져옵니다.
 /// 1 USD = ? KRW 형식으로 반환합니다.
 pub async fn fetch_usd_krw_rate() -> Result<f64> {
-    let client = reqwest::Client::new();
+    let client = crate::http::http_client();
     let response = client.get(EXCHANGE_RATE_API_URL).send().await?;
     let data: ExchangeRateApiResponse = response.json().await?;
 
@@ -46,7 +46,7 @@ struct BinancePriceResponse {
 /// USDT/USD 환율을 가져옵니다.
 /// Binance Spot에서 USDC/USDT 가격을 가져와서 역으로 계산합니다.
 pub async fn fetch_usdt_usd_rate() -> Result<f64> {
-    let client = reqwest::Client::new();
+    let client = crate::http::http_client();
     let url = "https://api.binance.com/api/v3/ticker/price?symbol=USDCUSDT";
 
     let response = client.get(url).send().await?;
@@ -78,7 +78,7 @@ struct BithumbTickerData {
 /// Bithumb에서 USDT/KRW 가격을 가져옵니다.
 /// 1 USDT = ? KRW 형식으로 반환합니다.
 pub async fn fetch_usdt_krw_rate() -> Result<f64> {
-    let client = reqwest::Client::new();
+    let client = crate::http::http_client();
     let response = client.get(BITHUMB_API_URL).send().await?;
     let data: BithumbTickerResponse = response.json().await?;
 
@@ -0,0 +1,172 @@
+//! 거래소/마켓별 수수료 덮어쓰기
+//!
+//! VIP 등급 리베이트처럼 API로 조회되지 않는 계정별 수수료를 설정으로 지정한다.
+//! 수수료가 필요한 계산(손익분기 베이시스, PnL, 헤지 수량 계산)은 이 설정을 먼저 확인하고,
+//! 없을 때만 거래소 API/기본값(`FeeExchange::get_fee`)을 사용한다.
+//!
+//! 설정: `FEE_OVERRIDES="binance:spot=0.00018/0.0003,binance:futures=0.00016/0.0004"`
+//! (`거래소:마켓=maker/taker`, 쉼표 구분)
+//! - 마켓: `spot`(현물 전체), `futures`(선물), 또는 현물 마켓 `krw`/`usdt`/`btc`
+//! - 현물은 `krw`/`usdt`/`btc` 같은 세부 마켓 설정이 `spot`보다 우선한다
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use tracing::warn;
+
+use interface::{ExchangeId, FeeInfo, MarketType};
+
+/// 현물 전체에 적용되는 마켓 키
+pub const SPOT_MARKET: &str = "spot";
+/// 선물 마켓 키
+pub const FUTURES_MARKET: &str = "futures";
+
+/// 거래소/마켓별 수수료 설정
+#[derive(Debug, Clone, Default)]
+pub struct FeeOverrides {
+    fees: HashMap<(ExchangeId, String), FeeInfo>,
+}
+
+impl FeeOverrides {
+    /// `FEE_OVERRIDES` 환경변수에서 로드. 잘못된 항목은 경고 후 무시
+    pub fn from_env() -> Self {
+        let raw = std::env::var("FEE_OVERRIDES").unwrap_or_default();
+        let (overrides, errors) = Self::parse(&raw);
+        for error in errors {
+            warn!("FEE_OVERRIDES 항목 무시: {}", error);
+        }
+        overrides
+    }
+
+    /// 설정 문자열 파싱. 파싱에 실패한 항목은 따로 반환
+    pub fn parse(raw: &str) -> (Self, Vec<String>) {
+        let mut overrides = Self::default();
+        let mut errors = Vec::new();
+        for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match parse_entry(entry) {
+                Some((exchange, market, fee)) => {
+                    overrides.set(exchange, &market, fee);
+                }
+                None => errors.push(entry.to_string()),
+            }
+        }
+        (overrides, errors)
+    }
+
+    pub fn set(&mut self, exchange: ExchangeId, market: &str, fee: FeeInfo) {
+        self.fees.insert((exchange, market.to_lowercase()), fee);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fees.is_empty()
+    }
+
+    /// 마켓 키에 정확히 일치하는 설정
+    pub fn get(&self, exchange: ExchangeId, market: &str) -> Option<FeeInfo> {
+        self.fees.get(&(exchange, market.to_lowercase())).cloned()
+    }
+
+    /// 현물 마켓 수수료 설정 (세부 마켓 → `spot` 순서로 확인)
+    pub fn spot(&self, exchange: ExchangeId, market_type: &MarketType) -> Option<FeeInfo> {
+        self.get(exchange, &market_key(market_type))
+            .or_else(|| self.get(exchange, SPOT_MARKET))
+    }
+
+    /// 현물 심볼(예: BTCUSDT, BTC_KRW) 수수료 설정
+    pub fn spot_symbol(&self, exchange: ExchangeId, symbol: &str) -> Option<FeeInfo> {
+        self.spot(exchange, &quote_market(symbol))
+    }
+
+    /// 선물 수수료 설정
+    pub fn futures(&self, exchange: ExchangeId) -> Option<FeeInfo> {
+        self.get(exchange, FUTURES_MARKET)
+    }
+}
+
+/// MarketType → 설정 마켓 키
+pub fn market_key(market_type: &MarketType) -> String {
+    match market_type {
+        MarketType::KRW => "krw".to_string(),
+        MarketType::USDT => "usdt".to_string(),
+        MarketType::BTC => "btc".to_string(),
+        MarketType::Other(name) => name.to_lowercase(),
+    }
+}
+
+/// 심볼의 호가 통화로 현물 마켓 판단
+pub fn quote_market(symbol: &str) -> MarketType {
+    let symbol = symbol.to_uppercase();
+    if symbol.ends_with("KRW") {
+        MarketType::KRW
+    } else if symbol.ends_with("USDT") {
+        MarketType::USDT
+    } else if symbol.ends_with("BTC") {
+        MarketType::BTC
+    } else {
+        MarketType::Other(symbol)
+    }
+}
+
+/// "binance:spot=0.0002/0.0004" → (Binance, "spot", FeeInfo)
+fn parse_entry(entry: &str) -> Option<(ExchangeId, String, FeeInfo)> {
+    let (key, rates) = entry.split_once('=')?;
+    let (exchange, market) = key.split_once(':')?;
+    let (maker, taker) = rates.split_once('/')?;
+
+    let exchange = match exchange.trim().to_lowercase().as_str() {
+        "binance" => ExchangeId::Binance,
+        "bybit" => ExchangeId::Bybit,
+        "okx" => ExchangeId::Okx,
+        "bitget" => ExchangeId::Bitget,
+        "bithumb" => ExchangeId::Bithumb,
+        _ => return None,
+    };
+    let market = market.trim().to_lowercase();
+    let maker: f64 = maker.trim().parse().ok()?;
+    let taker: f64 = taker.trim().parse().ok()?;
+    // 리베이트(음수 maker)는 허용하되 1% 이상은 단위 실수로 본다
+    if market.is_empty() || !(-0.01..0.01).contains(&maker) || !(0.0..0.01).contains(&taker) {
+        return None;
+    }
+    Some((exchange, market, FeeInfo::new(maker, taker)))
+}
+
+static GLOBAL_FEE_OVERRIDES: OnceLock<FeeOverrides> = OnceLock::new();
+
+/// 전역 수수료 설정 (처음 조회할 때 `FEE_OVERRIDES`에서 로드)
+pub fn fee_overrides() -> &'static FeeOverrides {
+    GLOBAL_FEE_OVERRIDES.get_or_init(FeeOverrides::from_env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_lookup_order() {
+        let (overrides, errors) = FeeOverrides::parse(
+            "binance:spot=0.00018/0.0003, Binance:USDT=-0.00005/0.00025, \
+             binance:futures=0.00016/0.0004, bithumb:krw=0.1/0.1, upbit:krw=0/0",
+        );
+        assert_eq!(errors, vec!["bithumb:krw=0.1/0.1", "upbit:krw=0/0"]);
+
+        // 세부 마켓이 spot보다 우선
+        let usdt = overrides
+            .spot_symbol(ExchangeId::Binance, "BTCUSDT")
+            .unwrap();
+        assert_eq!((usdt.maker, usdt.taker), (-0.00005, 0.00025));
+        let btc = overrides
+            .spot_symbol(ExchangeId::Binance, "ETHBTC")
+            .unwrap();
+        assert_eq!((btc.maker, btc.taker), (0.00018, 0.0003));
+
+        assert_eq!(
+            overrides.futures(ExchangeId::Binance).unwrap().taker,
+            0.0004
+        );
+        assert!(overrides.futures(ExchangeId::Bybit).is_none());
+        assert!(overrides
+            .spot(ExchangeId::Bithumb, &MarketType::KRW)
+            .is_none());
+    }
+}
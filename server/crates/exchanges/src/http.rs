@@ -0,0 +1,141 @@
+//! 공유 HTTP 클라이언트 팩토리
+//!
+//! `reqwest::Client::new()`는 요청 타임아웃이 없어 거래소가 응답하지 않으면 태스크가 무한정 멈춘다.
+//! 모든 거래소 클라이언트와 트레이더는 여기서 만든 클라이언트를 써서 연결/요청 타임아웃,
+//! 커넥션 풀 제한, 프록시 설정을 한 곳에서 적용한다. 커넥션 풀은 클라이언트(와 그 클론)마다 따로 가진다.
+//! (전역 클라이언트 하나를 쓰면 다른 tokio 런타임에서 만든 풀 연결을 재사용하다 멈출 수 있다)
+//!
+//! 설정 (환경변수)
+//! - `HTTP_CONNECT_TIMEOUT_SECS`: 연결 타임아웃 (기본 5초)
+//! - `HTTP_REQUEST_TIMEOUT_SECS`: 요청 전체 타임아웃 (기본 30초)
+//! - `HTTP_POOL_MAX_IDLE_PER_HOST`: 호스트별 유휴 연결 최대 개수 (기본 32)
+//! - `HTTP_POOL_IDLE_TIMEOUT_SECS`: 유휴 연결 유지 시간 (기본 90초)
+//! - `HTTP_PROXY_URL`: 모든 요청에 쓸 프록시 (http/https, 예: `http://127.0.0.1:8080`)
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use tracing::warn;
+
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 5;
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 32;
+const DEFAULT_POOL_IDLE_TIMEOUT_SECS: u64 = 90;
+
+/// HTTP 클라이언트 설정
+#[derive(Debug, Clone)]
+pub struct HttpConfig {
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+    pub pool_max_idle_per_host: usize,
+    pub pool_idle_timeout: Duration,
+    pub proxy: Option<String>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS),
+            pool_max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            pool_idle_timeout: Duration::from_secs(DEFAULT_POOL_IDLE_TIMEOUT_SECS),
+            proxy: None,
+        }
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
+
+impl HttpConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            connect_timeout: env_parse("HTTP_CONNECT_TIMEOUT_SECS")
+                .filter(|v| *v > 0)
+                .map(Duration::from_secs)
+                .unwrap_or(default.connect_timeout),
+            request_timeout: env_parse("HTTP_REQUEST_TIMEOUT_SECS")
+                .filter(|v| *v > 0)
+                .map(Duration::from_secs)
+                .unwrap_or(default.request_timeout),
+            pool_max_idle_per_host: env_parse("HTTP_POOL_MAX_IDLE_PER_HOST")
+                .unwrap_or(default.pool_max_idle_per_host),
+            pool_idle_timeout: env_parse("HTTP_POOL_IDLE_TIMEOUT_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(default.pool_idle_timeout),
+            proxy: std::env::var("HTTP_PROXY_URL")
+                .ok()
+                .filter(|v| !v.trim().is_empty()),
+        }
+    }
+
+    /// 설정대로 클라이언트 생성 (프록시 URL이 잘못되면 에러)
+    pub fn build(&self) -> Result<reqwest::Client, reqwest::Error> {
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.request_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.as_str())?);
+        }
+        builder.build()
+    }
+
+    /// 설정대로 클라이언트 생성. 실패하면 경고 후 프록시 없이 생성
+    fn build_or_fallback(&self) -> reqwest::Client {
+        self.build().unwrap_or_else(|e| {
+            warn!("HTTP 클라이언트 설정 오류, 프록시 없이 생성: {}", e);
+            Self {
+                proxy: None,
+                ..self.clone()
+            }
+            .build()
+            .unwrap_or_default()
+        })
+    }
+}
+
+/// 전역 HTTP 설정 (`HttpConfig::from_env`, 최초 1회 로드)
+pub fn http_config() -> &'static HttpConfig {
+    static CONFIG: OnceLock<HttpConfig> = OnceLock::new();
+    CONFIG.get_or_init(HttpConfig::from_env)
+}
+
+/// 전역 설정을 적용한 HTTP 클라이언트 (`reqwest::Client::new()` 대신 사용)
+pub fn http_client() -> reqwest::Client {
+    http_config().build_or_fallback()
+}
+
+/// 요청 타임아웃만 다른 클라이언트 (웹훅처럼 짧게 끊어야 하는 호출용)
+pub fn http_client_with_timeout(timeout: Duration) -> reqwest::Client {
+    HttpConfig {
+        request_timeout: timeout,
+        ..http_config().clone()
+    }
+    .build_or_fallback()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_with_proxy() {
+        assert!(HttpConfig::default().build().is_ok());
+
+        let config = HttpConfig {
+            proxy: Some("http://127.0.0.1:8080".to_string()),
+            ..Default::default()
+        };
+        assert!(config.build().is_ok());
+
+        let invalid = HttpConfig {
+            proxy: Some("not a proxy url".to_string()),
+            ..Default::default()
+        };
+        assert!(invalid.build().is_err());
+    }
+}
@@ -10,13 +10,27 @@ pub mod bitget;
 pub mod bithumb;
 pub mod bybit;
 pub mod exchange_rate;
+pub mod fee_override;
+pub mod http;
 pub mod okx;
+pub mod status;
+pub mod supervisor;
+pub mod volume;
+pub mod weight;
+pub mod ws;
+
+use status::{status_registry, ExchangeStatus};
 
 #[async_trait]
 pub trait PerpExchange: Send + Sync {
     fn id(&self) -> ExchangeId;
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError>;
+
+    /// REST/WebSocket 연결 상태 (전역 상태 레지스트리 기준)
+    fn status(&self) -> ExchangeStatus {
+        status_registry().status_of(self.id())
+    }
 }
 
 #[async_trait]
@@ -24,6 +38,11 @@ pub trait SpotExchange: Send + Sync {
     fn id(&self) -> ExchangeId;
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError>;
+
+    /// REST/WebSocket 연결 상태 (전역 상태 레지스트리 기준)
+    fn status(&self) -> ExchangeStatus {
+        status_registry().status_of(self.id())
+    }
 }
 
 #[async_trait]
@@ -33,6 +52,11 @@ pub trait AssetExchange: Send + Sync {
     async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError>;
 
     async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError>;
+
+    /// REST/WebSocket 연결 상태 (전역 상태 레지스트리 기준)
+    fn status(&self) -> ExchangeStatus {
+        status_registry().status_of(self.id())
+    }
 }
 
 #[async_trait]
@@ -52,6 +76,13 @@ pub trait FeeExchange: Send + Sync {
     /// market_type: 마켓 타입 (KRW, USDT, BTC 등)
     fn get_fee(&self, market_type: MarketType) -> FeeInfo;
 
+    /// 수수료 설정(`FEE_OVERRIDES`)을 먼저 확인하고, 없으면 `get_fee` 결과 사용
+    fn effective_fee(&self, market_type: MarketType) -> FeeInfo {
+        fee_override::fee_overrides()
+            .spot(self.id(), &market_type)
+            .unwrap_or_else(|| self.get_fee(market_type))
+    }
+
     /// 특정 통화의 입출금 수수료 조회
     /// currency: 통화 코드 (예: "BTC", "ETH")
     async fn get_deposit_withdrawal_fee(
@@ -1,74 +1,224 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use futures_util::{SinkExt, StreamExt};
+use futures::stream::{self, StreamExt};
 use serde::Deserialize;
 use serde_json::json;
-use tokio::sync::RwLock;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
-
+use tokio::sync::{OnceCell, RwLock};
+
+use crate::status::status_registry;
+use crate::supervisor::{task_supervisor, WS_STALE_AFTER};
+use crate::volume::{volume_24h_usd, VolumeUnit};
+use crate::ws::{
+    diff_symbols, ConnectionState, Heartbeat, PingMessage, ReconnectConfig, ReconnectingClient,
+    WsHandler,
+};
 use crate::{ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
+use interface::{
+    funding_interval_between, utc_from_epoch_str, Currency, ExchangeId, PayloadParser,
+    PerpSnapshot, Price,
+};
 
 const BASE_URL: &str = "https://www.okx.com";
 const WS_URL: &str = "wss://ws.okx.com:8443/ws/v5/public";
+/// 연결을 유지한 채 USDT-SWAP 심볼 목록을 다시 확인하는 주기 (신규 상장/상장 폐지 반영)
+const SYMBOL_REFRESH_INTERVAL: Duration = Duration::from_secs(600);
 
 #[derive(Debug, Clone)]
 pub(crate) struct FundingInfo {
     funding_rate: f64,
     next_funding_time: Option<DateTime<Utc>>,
+    funding_interval_hours: Option<f64>,
+}
+
+/// 이번 정산 시각(fundingTime)과 다음 정산 시각(nextFundingTime) 차이로 정산 주기 계산 (시간)
+/// OKX는 심볼마다 주기가 다르고(1h/2h/4h/8h) 주기 값을 따로 주지 않는다
+fn funding_interval_hours(funding_time: &str, next_funding_time: &str) -> Option<f64> {
+    funding_interval_between(
+        utc_from_epoch_str(funding_time)?,
+        utc_from_epoch_str(next_funding_time)?,
+    )
 }
 
 #[derive(Clone)]
 pub struct OkxClient {
     pub(crate) http: reqwest::Client,
     pub(crate) funding_cache: Arc<RwLock<HashMap<String, FundingInfo>>>,
+    /// REST 펀딩 레이트 백필 성공 여부 (실패하면 비워 두고 다음 호출에서 재시도)
+    pub(crate) funding_warmup: Arc<OnceCell<()>>,
 }
 
 impl OkxClient {
     pub fn new() -> Self {
-        let funding_cache = Arc::new(RwLock::new(HashMap::new()));
-        let cache_clone = funding_cache.clone();
+        let client = Self {
+            http: crate::http::http_client(),
+            funding_cache: Arc::new(RwLock::new(HashMap::new())),
+            funding_warmup: Arc::new(OnceCell::new()),
+        };
 
-        // WebSocket 연결을 백그라운드 태스크로 시작
+        // WebSocket 데이터가 들어오기 전까지 비어 있지 않도록 REST로 먼저 채움
+        let warmup_client = client.clone();
         tokio::spawn(async move {
-            Self::start_websocket(cache_clone).await;
+            warmup_client.warm_up_funding_cache().await;
         });
 
-        Self {
-            http: reqwest::Client::new(),
-            funding_cache,
+        // WebSocket 연결을 감시 태스크로 시작 (종료되거나 멈추면 재시작)
+        let cache = client.funding_cache.clone();
+        task_supervisor().spawn("okx_funding_ws", Some(WS_STALE_AFTER), move || {
+            Self::start_websocket(cache.clone())
+        });
+
+        client
+    }
+
+    /// REST 펀딩 레이트 백필 (성공할 때까지 호출마다 시도, 동시에 호출되면 완료까지 대기)
+    pub(crate) async fn warm_up_funding_cache(&self) {
+        let result = self
+            .funding_warmup
+            .get_or_try_init(|| async {
+                let count = Self::backfill_funding_rates(&self.http, &self.funding_cache).await?;
+                tracing::info!("OKX 펀딩 레이트 REST 백필 완료: {}개 심볼", count);
+                Ok::<_, eyre::Report>(())
+            })
+            .await;
+        if let Err(e) = result {
+            tracing::warn!(
+                "OKX 펀딩 레이트 REST 백필 실패 (다음 조회 때 재시도): {:?}",
+                e
+            );
         }
     }
 
-    async fn start_websocket(cache: Arc<RwLock<HashMap<String, FundingInfo>>>) {
-        loop {
-            match Self::connect_and_subscribe(cache.clone()).await {
-                Ok(_) => {
-                    tracing::warn!("OKX WebSocket 연결이 종료되었습니다. 재연결 시도...");
-                }
-                Err(e) => {
-                    tracing::error!("OKX WebSocket 오류: {:?}. 재연결 시도...", e);
+    /// `/api/v5/public/funding-rate`로 USDT-SWAP 펀딩 레이트를 캐시에 채운다.
+    /// instId=ANY로 한 번에 가져오고, 지원되지 않으면 심볼별로 조회한다.
+    /// WebSocket에서 이미 들어온 값은 덮어쓰지 않는다.
+    async fn backfill_funding_rates(
+        http: &reqwest::Client,
+        cache: &Arc<RwLock<HashMap<String, FundingInfo>>>,
+    ) -> eyre::Result<usize> {
+        let all_url = format!("{BASE_URL}/api/v5/public/funding-rate?instId=ANY");
+        let mut rates: Vec<OkxFundingRate> = match http
+            .get(&all_url)
+            .send()
+            .await?
+            .json::<OkxResponse<Vec<OkxFundingRate>>>()
+            .await
+        {
+            Ok(response) if response.code == "0" => response.data,
+            Ok(response) => {
+                tracing::debug!(
+                    "OKX funding-rate instId=ANY 미지원: {} - {}",
+                    response.code,
+                    response.msg
+                );
+                Vec::new()
+            }
+            Err(e) => {
+                tracing::debug!("OKX funding-rate instId=ANY 파싱 실패: {:?}", e);
+                Vec::new()
+            }
+        };
+
+        if rates.is_empty() {
+            let tickers_url = format!("{BASE_URL}/api/v5/market/tickers?instType=SWAP");
+            let tickers: OkxResponse<Vec<OkxTicker>> =
+                http.get(&tickers_url).send().await?.json().await?;
+
+            if tickers.code != "0" {
+                return Err(eyre::eyre!(
+                    "OKX API error (tickers): {} - {}",
+                    tickers.code,
+                    tickers.msg
+                ));
+            }
+
+            rates = stream::iter(
+                tickers
+                    .data
+                    .into_iter()
+                    .filter(|t| t.inst_id.ends_with("-USDT-SWAP")),
+            )
+            .map(|ticker| {
+                let http = http.clone();
+                async move {
+                    let url = format!(
+                        "{BASE_URL}/api/v5/public/funding-rate?instId={}",
+                        ticker.inst_id
+                    );
+                    let response: OkxResponse<Vec<OkxFundingRate>> =
+                        http.get(&url).send().await.ok()?.json().await.ok()?;
+                    if response.code != "0" {
+                        return None;
+                    }
+                    Some(response.data)
                 }
+            })
+            .buffer_unordered(10) // 동시에 최대 10개 요청만 처리 (rate limit 방지)
+            .filter_map(|r| async move { r })
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+        }
+
+        let parser = PayloadParser::new(ExchangeId::Okx);
+        let mut guard = cache.write().await;
+        let mut count = 0;
+        for rate in rates {
+            if !rate.inst_id.ends_with("-USDT-SWAP") {
+                continue;
             }
+            let Ok(funding_rate) = parser.required("funding_rate", &rate.funding_rate) else {
+                continue;
+            };
+            let next_funding_time = utc_from_epoch_str(&rate.next_funding_time);
 
-            // 재연결 전 대기
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+            let funding_interval_hours =
+                funding_interval_hours(&rate.funding_time, &rate.next_funding_time);
+
+            guard.entry(rate.inst_id).or_insert(FundingInfo {
+                funding_rate,
+                next_funding_time,
+                funding_interval_hours,
+            });
+            count += 1;
         }
+
+        Ok(count)
     }
 
-    async fn connect_and_subscribe(
-        cache: Arc<RwLock<HashMap<String, FundingInfo>>>,
-    ) -> eyre::Result<()> {
-        // WebSocket 연결
-        let (ws_stream, _) = connect_async(WS_URL).await?;
-        let (mut write, mut read) = ws_stream.split();
-        tracing::info!("OKX WebSocket 연결 성공");
+    async fn start_websocket(cache: Arc<RwLock<HashMap<String, FundingInfo>>>) {
+        // OKX는 30초 동안 데이터가 없으면 연결을 끊으므로 텍스트 "ping"으로 keep-alive
+        let client = ReconnectingClient::new("OKX funding-rate", WS_URL)
+            .with_config(ReconnectConfig {
+                initial_backoff: Duration::from_secs(1),
+                max_backoff: Duration::from_secs(60),
+                multiplier: 2.0,
+                // 구독 메시지 간 약간의 지연
+                subscribe_interval: Duration::from_millis(100),
+                heartbeat: Some(Heartbeat {
+                    interval: Duration::from_secs(20),
+                    timeout: Duration::from_secs(60),
+                    message: PingMessage::Text("ping".to_string()),
+                }),
+                resubscribe_interval: Some(SYMBOL_REFRESH_INTERVAL),
+            })
+            .with_status(ExchangeId::Okx, "funding_ws");
+
+        let mut handler = OkxFundingHandler {
+            http: crate::http::http_client(),
+            cache,
+            subscribed: HashSet::new(),
+        };
+        client.run(&mut handler).await;
+    }
 
-        // 먼저 모든 USDT-SWAP 심볼 목록 가져오기
-        let http = reqwest::Client::new();
+    /// USDT-SWAP 심볼 목록
+    async fn fetch_usdt_swap_symbols(http: &reqwest::Client) -> eyre::Result<Vec<String>> {
         let tickers_url = format!("{BASE_URL}/api/v5/market/tickers?instType=SWAP");
         let response: OkxResponse<Vec<OkxTicker>> =
             http.get(&tickers_url).send().await?.json().await?;
@@ -82,21 +232,19 @@ impl OkxClient {
         }
 
         // USDT-SWAP 심볼만 필터링
-        let usdt_swap_symbols: Vec<String> = response
+        Ok(response
             .data
             .into_iter()
             .filter(|t| t.inst_id.ends_with("-USDT-SWAP"))
             .map(|t| t.inst_id)
-            .collect();
-
-        tracing::info!(
-            "OKX funding-rate 채널 구독 시작: {}개 심볼",
-            usdt_swap_symbols.len()
-        );
+            .collect())
+    }
 
-        // 각 심볼에 대해 funding-rate 채널 구독
-        // OKX는 한 번에 최대 20개 심볼까지 구독 가능
-        for chunk in usdt_swap_symbols.chunks(20) {
+    /// funding-rate 채널 구독/해지 메시지 생성 (op: "subscribe" | "unsubscribe")
+    /// OKX는 한 번에 최대 20개 심볼까지 구독 가능
+    fn funding_channel_messages(op: &str, inst_ids: &[String]) -> eyre::Result<Vec<String>> {
+        let mut messages = Vec::new();
+        for chunk in inst_ids.chunks(20) {
             let args: Vec<serde_json::Value> = chunk
                 .iter()
                 .map(|inst_id| {
@@ -107,37 +255,15 @@ impl OkxClient {
                 })
                 .collect();
 
-            let subscribe_msg = json!({
-                "op": "subscribe",
+            let msg = json!({
+                "op": op,
                 "args": args
             });
 
-            let msg = Message::Text(serde_json::to_string(&subscribe_msg)?);
-            write.send(msg).await?;
-
-            // 구독 메시지 간 약간의 지연
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            messages.push(serde_json::to_string(&msg)?);
         }
 
-        tracing::info!("OKX funding-rate 채널 구독 완료");
-
-        // 메시지 수신 루프
-        while let Some(msg) = read.next().await {
-            match msg? {
-                Message::Text(text) => {
-                    if let Err(e) = Self::handle_ws_message(&text, cache.clone()).await {
-                        tracing::warn!("WebSocket 메시지 처리 오류: {:?}", e);
-                    }
-                }
-                Message::Close(_) => {
-                    tracing::warn!("OKX WebSocket 연결이 닫혔습니다");
-                    break;
-                }
-                _ => {}
-            }
-        }
-
-        Ok(())
+        Ok(messages)
     }
 
     async fn handle_ws_message(
@@ -167,6 +293,8 @@ impl OkxClient {
             #[serde(rename = "instId")]
             inst_id: String,
             funding_rate: String,
+            #[serde(default)]
+            funding_time: String,
             next_funding_time: String,
         }
 
@@ -179,19 +307,22 @@ impl OkxClient {
         };
 
         // funding-rate 데이터 처리
+        let parser = PayloadParser::new(ExchangeId::Okx);
         for data in response.data {
             let inst_id = data.inst_id;
 
-            let funding_rate: f64 = data.funding_rate.parse().unwrap_or(0.0);
-            let next_funding_time: Option<DateTime<Utc>> = data
-                .next_funding_time
-                .parse::<i64>()
-                .ok()
-                .and_then(|ts| DateTime::from_timestamp_millis(ts));
+            let Ok(funding_rate) = parser.optional("funding_rate", &data.funding_rate) else {
+                continue;
+            };
+            let next_funding_time = utc_from_epoch_str(&data.next_funding_time);
 
             let funding_info = FundingInfo {
                 funding_rate,
                 next_funding_time,
+                funding_interval_hours: funding_interval_hours(
+                    &data.funding_time,
+                    &data.next_funding_time,
+                ),
             };
 
             let mut guard = cache.write().await;
@@ -202,6 +333,74 @@ impl OkxClient {
     }
 }
 
+/// ReconnectingClient 용 funding-rate 채널 핸들러
+struct OkxFundingHandler {
+    http: reqwest::Client,
+    cache: Arc<RwLock<HashMap<String, FundingInfo>>>,
+    /// 현재 연결에서 구독 중인 심볼
+    subscribed: HashSet<String>,
+}
+
+#[async_trait]
+impl WsHandler for OkxFundingHandler {
+    /// 연결(재연결 포함)마다 USDT-SWAP 심볼 목록을 새로 가져와 전체 구독
+    async fn subscriptions(&mut self) -> eyre::Result<Vec<String>> {
+        let symbols = OkxClient::fetch_usdt_swap_symbols(&self.http).await?;
+        tracing::info!("OKX funding-rate 채널 구독 시작: {}개 심볼", symbols.len());
+        let messages = OkxClient::funding_channel_messages("subscribe", &symbols)?;
+        self.subscribed = symbols.into_iter().collect();
+        Ok(messages)
+    }
+
+    /// 심볼 목록 변경분만 기존 연결에 추가 구독/해지
+    async fn resubscribe(&mut self) -> eyre::Result<Vec<String>> {
+        let symbols = OkxClient::fetch_usdt_swap_symbols(&self.http).await?;
+        let (added, removed) = diff_symbols(&self.subscribed, &symbols);
+        if added.is_empty() && removed.is_empty() {
+            return Ok(Vec::new());
+        }
+        tracing::info!(
+            "OKX funding-rate 구독 갱신: 추가 {:?}, 해지 {:?}",
+            added,
+            removed
+        );
+
+        let mut messages = OkxClient::funding_channel_messages("subscribe", &added)?;
+        messages.extend(OkxClient::funding_channel_messages(
+            "unsubscribe",
+            &removed,
+        )?);
+
+        // 상장 폐지된 심볼의 펀딩 정보는 더 이상 갱신되지 않으므로 제거
+        if !removed.is_empty() {
+            let mut guard = self.cache.write().await;
+            for inst_id in &removed {
+                guard.remove(inst_id);
+                self.subscribed.remove(inst_id);
+            }
+        }
+        self.subscribed.extend(added);
+        Ok(messages)
+    }
+
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+        // 하트비트 "ping"에 대한 응답은 JSON이 아닌 평문 "pong"이므로 파싱하지 않음
+        if text == "pong" {
+            return Ok(());
+        }
+        if let Err(e) = OkxClient::handle_ws_message(text, self.cache.clone()).await {
+            tracing::warn!("WebSocket 메시지 처리 오류: {:?}", e);
+        }
+        Ok(())
+    }
+
+    fn on_state_change(&mut self, state: ConnectionState) {
+        if state == ConnectionState::Connected {
+            tracing::info!("OKX funding-rate 채널 구독 완료");
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct OkxResponse<T> {
     code: String,
@@ -214,16 +413,25 @@ struct OkxResponse<T> {
 struct OkxTicker {
     inst_id: String,
     #[serde(default)]
-    #[allow(dead_code)]
     last: String,
     #[serde(default)]
-    vol_24h: String,
-    #[serde(default)]
-    vol_ccy_24h: String, // 24h volume in quote currency (USDT)
+    vol_ccy_24h: String, // SWAP은 24h volume in base currency (vol24h는 계약 수)
     #[serde(default)]
     funding_rate: String, // funding rate (tickers 응답에 포함됨)
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxFundingRate {
+    inst_id: String,
+    #[serde(default)]
+    funding_rate: String,
+    #[serde(default)]
+    funding_time: String,
+    #[serde(default)]
+    next_funding_time: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct OkxMarkPrice {
@@ -251,6 +459,15 @@ impl PerpExchange for OkxClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
+        status_registry().track(ExchangeId::Okx, "perp", self.fetch_perp_snapshots().await)
+    }
+}
+
+impl OkxClient {
+    async fn fetch_perp_snapshots(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
+        // 첫 호출이 WebSocket 데이터보다 먼저 와도 펀딩 레이트가 채워져 있도록 백필 완료 대기
+        self.warm_up_funding_cache().await;
+
         // 1) 티커 정보 (24h 거래량)
         let tickers_url = format!("{BASE_URL}/api/v5/market/tickers?instType=SWAP");
         let tickers_response: OkxResponse<Vec<OkxTicker>> =
@@ -310,6 +527,7 @@ impl PerpExchange for OkxClient {
         }
 
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Okx);
         let mut out = Vec::new();
 
         // 모든 USDT-SWAP 심볼에 대해 데이터 조합
@@ -324,39 +542,53 @@ impl PerpExchange for OkxClient {
                 None => continue,
             };
 
-            let mark_price: f64 = match mark_price_data.mark_px.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(mark_price) = parser.required("mark_price", &mark_price_data.mark_px) else {
+                continue;
             };
 
             // 펀딩 레이트와 next_funding_time은 WebSocket에서 가져옴
             let funding_cache = self.funding_cache.read().await;
             let funding_info = funding_cache.get(inst_id);
 
-            let funding_rate = funding_info
-                .map(|info| info.funding_rate)
-                .unwrap_or_else(|| ticker.funding_rate.parse().unwrap_or(0.0));
+            let funding_rate = match funding_info {
+                Some(info) => info.funding_rate,
+                None => {
+                    let Ok(rate) = parser.optional("funding_rate", &ticker.funding_rate) else {
+                        continue;
+                    };
+                    rate
+                }
+            };
 
             let next_funding_time = funding_info.and_then(|info| info.next_funding_time);
+            let funding_interval_hours = funding_info.and_then(|info| info.funding_interval_hours);
 
             // 오픈 이너스트는 oi_ccy (USDT 기준)를 우선 사용, 없으면 oi * mark_price
             let oi_usd = match oi_map.get(inst_id) {
+                Some(oi_data) if !oi_data.oi_ccy.is_empty() => {
+                    let Ok(oi_usd) = parser.optional("oi_usd", &oi_data.oi_ccy) else {
+                        continue;
+                    };
+                    oi_usd
+                }
                 Some(oi_data) => {
-                    if !oi_data.oi_ccy.is_empty() {
-                        oi_data.oi_ccy.parse().unwrap_or(0.0)
-                    } else {
-                        let oi_contracts: f64 = oi_data.oi.parse().unwrap_or(0.0);
-                        oi_contracts * mark_price
-                    }
+                    let Ok(oi_contracts) = parser.optional("oi_contracts", &oi_data.oi) else {
+                        continue;
+                    };
+                    oi_contracts * mark_price
                 }
                 None => 0.0,
             };
 
-            // 24h 거래량은 volCcy24h (USDT 기준)를 우선 사용, 없으면 vol24h
-            let vol_24h_usd: f64 = if !ticker.vol_ccy_24h.is_empty() {
-                ticker.vol_ccy_24h.parse().unwrap_or(0.0)
-            } else {
-                ticker.vol_24h.parse().unwrap_or(0.0)
+            // 24h 거래량: SWAP의 volCcy24h는 기초 자산 수량이므로 최근 체결가(없으면 마크 가격)를 곱한다
+            let Ok(last) = parser.optional("last_price", &ticker.last) else {
+                continue;
+            };
+            let price = if last > 0.0 { last } else { mark_price };
+            let Ok(vol_24h_usd) =
+                volume_24h_usd(&parser, &[(&ticker.vol_ccy_24h, VolumeUnit::Base)], price)
+            else {
+                continue;
             };
 
             // OKX는 "BTC-USDT-SWAP" 형식이므로 "BTCUSDT"로 변환
@@ -366,12 +598,14 @@ impl PerpExchange for OkxClient {
                 exchange: ExchangeId::Okx,
                 symbol,
                 currency: Currency::USDT,
-                mark_price,
+                mark_price: Price::new(mark_price),
+                index_price: None,
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
                 updated_at: now,
+                funding_interval_hours,
             });
         }
 
@@ -383,6 +617,48 @@ impl PerpExchange for OkxClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_funding_interval_hours() {
+        // 08:00 → 16:00
+        assert_eq!(
+            funding_interval_hours("1714550400000", "1714579200000"),
+            Some(8.0)
+        );
+        assert_eq!(
+            funding_interval_hours("1714550400000", "1714554000000"),
+            Some(1.0)
+        );
+        assert_eq!(funding_interval_hours("", "1714554000000"), None);
+        assert_eq!(
+            funding_interval_hours("1714554000000", "1714554000000"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_diff_symbols_and_channel_messages() {
+        let subscribed: HashSet<String> = ["BTC-USDT-SWAP", "OLD-USDT-SWAP"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let latest = vec!["BTC-USDT-SWAP".to_string(), "NEW-USDT-SWAP".to_string()];
+
+        let (added, removed) = diff_symbols(&subscribed, &latest);
+        assert_eq!(added, vec!["NEW-USDT-SWAP".to_string()]);
+        assert_eq!(removed, vec!["OLD-USDT-SWAP".to_string()]);
+
+        let symbols: Vec<String> = (0..25).map(|i| format!("S{}-USDT-SWAP", i)).collect();
+        let messages = OkxClient::funding_channel_messages("unsubscribe", &symbols).unwrap();
+        assert_eq!(messages.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(&messages[0]).unwrap();
+        assert_eq!(first["op"], "unsubscribe");
+        assert_eq!(first["args"].as_array().unwrap().len(), 20);
+        assert_eq!(first["args"][0]["channel"], "funding-rate");
+        assert!(OkxClient::funding_channel_messages("subscribe", &[])
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_okx_client_id() {
         let client = OkxClient::new();
@@ -431,7 +707,7 @@ mod tests {
                     for snapshot in &snapshots {
                         assert_eq!(snapshot.exchange, ExchangeId::Okx);
                         assert!(snapshot.symbol.ends_with("USDT"));
-                        assert!(snapshot.mark_price > 0.0);
+                        assert!(snapshot.mark_price.value() > 0.0);
                         assert!(snapshot.oi_usd >= 0.0);
                         assert!(snapshot.vol_24h_usd >= 0.0);
                     }
@@ -2,8 +2,10 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
+use crate::status::status_registry;
+use crate::volume::{volume_24h_usd, VolumeUnit};
 use crate::{ExchangeError, OkxClient, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
+use interface::{Currency, ExchangeId, PayloadParser, Price, SpotSnapshot};
 
 const BASE_URL: &str = "https://www.okx.com";
 
@@ -21,6 +23,8 @@ struct OkxSpotTicker {
     #[serde(default)]
     last: String,
     #[serde(default)]
+    vol_24h: String, // 24h volume in base currency
+    #[serde(default)]
     vol_ccy_24h: String, // 24h volume in quote currency (USDT)
 }
 
@@ -31,6 +35,12 @@ impl SpotExchange for OkxClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
+        status_registry().track(ExchangeId::Okx, "spot", self.fetch_spot_snapshots().await)
+    }
+}
+
+impl OkxClient {
+    async fn fetch_spot_snapshots(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
         let tickers_url = format!("{BASE_URL}/api/v5/market/tickers?instType=SPOT");
         let tickers_response: OkxResponse<Vec<OkxSpotTicker>> =
             self.http.get(&tickers_url).send().await?.json().await?;
@@ -43,6 +53,7 @@ impl SpotExchange for OkxClient {
         }
 
         let now = Utc::now();
+        let parser = PayloadParser::new(ExchangeId::Okx);
         let mut out = Vec::new();
 
         for ticker in tickers_response.data {
@@ -50,9 +61,8 @@ impl SpotExchange for OkxClient {
                 continue; // USDT 페어만
             }
 
-            let price: f64 = match ticker.last.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let Ok(price) = parser.required("price", &ticker.last) else {
+                continue;
             };
 
             // price가 0보다 큰 경우만 추가
@@ -60,7 +70,17 @@ impl SpotExchange for OkxClient {
                 continue;
             }
 
-            let vol_24h_usd: f64 = ticker.vol_ccy_24h.parse().unwrap_or(0.0);
+            // 현물 volCcy24h는 USDT 금액, vol24h는 기초 자산 수량
+            let Ok(vol_24h_usd) = volume_24h_usd(
+                &parser,
+                &[
+                    (&ticker.vol_ccy_24h, VolumeUnit::Quote),
+                    (&ticker.vol_24h, VolumeUnit::Base),
+                ],
+                price,
+            ) else {
+                continue;
+            };
 
             // OKX는 "BTC-USDT" 형식이므로 "BTCUSDT"로 변환
             let symbol = ticker.inst_id.replace("-USDT", "USDT").replace("-", "");
@@ -69,7 +89,7 @@ impl SpotExchange for OkxClient {
                 exchange: ExchangeId::Okx,
                 symbol,
                 currency: Currency::USDT,
-                price,
+                price: Price::new(price),
                 vol_24h_usd,
                 updated_at: now,
             });
@@ -0,0 +1,245 @@
+//! 거래소 클라이언트 연결 상태 레지스트리
+//!
+//! 각 클라이언트(REST, WebSocket)가 마지막 성공/실패 시각과 연결 상태를 보고하고,
+//! oracle `/healthz`, trade preflight 등에서 조회한다.
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use interface::{ExchangeError, ExchangeId};
+
+/// REST 채널이 비정상으로 판단되는 연속 실패 횟수
+const UNHEALTHY_ERROR_STREAK: u32 = 3;
+
+/// 채널 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelKind {
+    Rest,
+    WebSocket,
+}
+
+/// 채널 연결 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkState {
+    /// 아직 한 번도 보고되지 않음
+    Unknown,
+    Connecting,
+    Connected,
+    Disconnected,
+}
+
+/// 채널 하나(예: Binance perp REST, OKX funding WS)의 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelStatus {
+    /// 채널 이름 (예: "perp", "spot", "funding_ws")
+    pub channel: String,
+    pub kind: ChannelKind,
+    pub state: LinkState,
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+    /// 마지막 성공 이후 연속 실패 횟수
+    pub consecutive_errors: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ChannelStatus {
+    fn new(channel: &str, kind: ChannelKind) -> Self {
+        Self {
+            channel: channel.to_string(),
+            kind,
+            state: LinkState::Unknown,
+            last_success: None,
+            last_error: None,
+            last_error_at: None,
+            consecutive_errors: 0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// WebSocket은 연결 상태, REST는 최근 성공 여부와 연속 실패 횟수로 판단
+    pub fn is_healthy(&self) -> bool {
+        match self.kind {
+            ChannelKind::WebSocket => self.state == LinkState::Connected,
+            ChannelKind::Rest => {
+                self.last_success.is_some() && self.consecutive_errors < UNHEALTHY_ERROR_STREAK
+            }
+        }
+    }
+}
+
+/// 거래소별 상태 요약
+#[derive(Debug, Clone, Serialize)]
+pub struct ExchangeStatus {
+    pub exchange: ExchangeId,
+    /// 모든 채널이 정상이면 true (보고된 채널이 없으면 false)
+    pub healthy: bool,
+    pub channels: Vec<ChannelStatus>,
+}
+
+/// 연결 상태 레지스트리
+#[derive(Debug, Default)]
+pub struct StatusRegistry {
+    channels: RwLock<HashMap<(ExchangeId, String), ChannelStatus>>,
+}
+
+impl StatusRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn update<F>(&self, exchange: ExchangeId, channel: &str, kind: ChannelKind, f: F)
+    where
+        F: FnOnce(&mut ChannelStatus),
+    {
+        let mut channels = self.channels.write().unwrap();
+        let status = channels
+            .entry((exchange, channel.to_string()))
+            .or_insert_with(|| ChannelStatus::new(channel, kind));
+        f(status);
+        status.updated_at = Utc::now();
+    }
+
+    /// 요청/메시지 처리 성공 보고
+    pub fn record_success(&self, exchange: ExchangeId, channel: &str, kind: ChannelKind) {
+        self.update(exchange, channel, kind, |s| {
+            s.last_success = Some(Utc::now());
+            s.consecutive_errors = 0;
+            if kind == ChannelKind::Rest {
+                s.state = LinkState::Connected;
+            }
+        });
+    }
+
+    /// 요청/연결 실패 보고
+    pub fn record_error(
+        &self,
+        exchange: ExchangeId,
+        channel: &str,
+        kind: ChannelKind,
+        error: &str,
+    ) {
+        self.update(exchange, channel, kind, |s| {
+            s.last_error = Some(error.chars().take(200).collect());
+            s.last_error_at = Some(Utc::now());
+            s.consecutive_errors += 1;
+            if kind == ChannelKind::Rest {
+                s.state = LinkState::Disconnected;
+            }
+        });
+    }
+
+    /// 연결 상태 변경 보고 (WebSocket)
+    pub fn set_state(
+        &self,
+        exchange: ExchangeId,
+        channel: &str,
+        kind: ChannelKind,
+        state: LinkState,
+    ) {
+        self.update(exchange, channel, kind, |s| s.state = state);
+    }
+
+    /// REST 호출 결과를 보고하고 그대로 돌려준다
+    pub fn track<T>(
+        &self,
+        exchange: ExchangeId,
+        channel: &str,
+        result: Result<T, ExchangeError>,
+    ) -> Result<T, ExchangeError> {
+        match &result {
+            Ok(_) => self.record_success(exchange, channel, ChannelKind::Rest),
+            Err(e) => self.record_error(exchange, channel, ChannelKind::Rest, &e.to_string()),
+        }
+        result
+    }
+
+    /// 특정 거래소의 상태 (채널 이름 순 정렬)
+    pub fn status_of(&self, exchange: ExchangeId) -> ExchangeStatus {
+        let channels = self.channels.read().unwrap();
+        let mut list: Vec<ChannelStatus> = channels
+            .iter()
+            .filter(|((id, _), _)| *id == exchange)
+            .map(|(_, status)| status.clone())
+            .collect();
+        list.sort_by(|a, b| a.channel.cmp(&b.channel));
+
+        ExchangeStatus {
+            exchange,
+            healthy: !list.is_empty() && list.iter().all(|c| c.is_healthy()),
+            channels: list,
+        }
+    }
+
+    /// 보고된 모든 거래소의 상태
+    pub fn snapshot(&self) -> Vec<ExchangeStatus> {
+        let mut exchanges: Vec<ExchangeId> = {
+            let channels = self.channels.read().unwrap();
+            channels.keys().map(|(id, _)| *id).collect()
+        };
+        exchanges.sort_by_key(|id| format!("{:?}", id));
+        exchanges.dedup();
+        exchanges.into_iter().map(|id| self.status_of(id)).collect()
+    }
+}
+
+/// 전역 연결 상태 레지스트리
+static GLOBAL_STATUS: OnceLock<StatusRegistry> = OnceLock::new();
+
+/// 전역 연결 상태 레지스트리 가져오기 (최초 호출 시 생성)
+pub fn status_registry() -> &'static StatusRegistry {
+    GLOBAL_STATUS.get_or_init(StatusRegistry::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rest_channel_health() {
+        let registry = StatusRegistry::new();
+        assert!(!registry.status_of(ExchangeId::Binance).healthy);
+
+        let ok: Result<(), ExchangeError> = Ok(());
+        registry.track(ExchangeId::Binance, "perp", ok).unwrap();
+        assert!(registry.status_of(ExchangeId::Binance).healthy);
+
+        for _ in 0..UNHEALTHY_ERROR_STREAK {
+            let err: Result<(), ExchangeError> = Err(ExchangeError::Other("boom".into()));
+            let _ = registry.track(ExchangeId::Binance, "perp", err);
+        }
+        let status = registry.status_of(ExchangeId::Binance);
+        assert!(!status.healthy);
+        assert_eq!(
+            status.channels[0].last_error.as_deref(),
+            Some("other error: boom")
+        );
+        assert_eq!(status.channels[0].state, LinkState::Disconnected);
+    }
+
+    #[test]
+    fn test_websocket_channel_follows_state() {
+        let registry = StatusRegistry::new();
+        registry.set_state(
+            ExchangeId::Okx,
+            "funding_ws",
+            ChannelKind::WebSocket,
+            LinkState::Connected,
+        );
+        assert!(registry.status_of(ExchangeId::Okx).healthy);
+
+        registry.set_state(
+            ExchangeId::Okx,
+            "funding_ws",
+            ChannelKind::WebSocket,
+            LinkState::Disconnected,
+        );
+        assert!(!registry.status_of(ExchangeId::Okx).healthy);
+        assert_eq!(registry.snapshot().len(), 1);
+    }
+}
@@ -0,0 +1,445 @@
+//! 백그라운드 태스크 감시기 (watchdog)
+//!
+//! 가격 피드, 펀딩 캐시, User Data Stream처럼 `tokio::spawn`으로 띄워 두고 잊는 태스크를 이름으로
+//! 등록해 두고, 종료(패닉 포함)되었거나 하트비트가 끊긴 태스크를 중단한 뒤 다시 띄운다.
+//! 감시 태스크 안에서 실행되는 `ReconnectingClient`는 재연결 시도, 메시지 수신, ping 때마다
+//! 하트비트를 보낸다. oracle/trade `/healthz`, trade `/metrics/tasks`에서 조회한다.
+//!
+//! 연달아 실패하는 태스크는 재시작 간격을 지수적으로 늘리고(최대 10분), 태스크가
+//! `stop_restarting`으로 재시도해도 소용없는 종료(API 키 없음 등)를 알리면 다시 띄우지 않는다.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures_util::FutureExt;
+use serde::Serialize;
+use tokio::task::JoinHandle;
+
+/// 감시 루프 점검 간격
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 연속 실패 시 재시작 대기 상한
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(600);
+
+/// 이 시간 이상 돌다가 실패하면 연속 실패 횟수를 초기화
+const STABLE_RUN: Duration = Duration::from_secs(300);
+
+/// 재연결 WebSocket 태스크의 하트비트 제한 시간
+/// 최대 재연결 백오프(60초)와 ping 간격(20~30초)보다 충분히 길게 잡는다
+pub const WS_STALE_AFTER: Duration = Duration::from_secs(180);
+
+type TaskFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type TaskFactory = Arc<dyn Fn() -> TaskFuture + Send + Sync>;
+
+tokio::task_local! {
+    /// 현재 태스크의 마지막 하트비트 시각 (ms)
+    static CURRENT_BEAT: Arc<AtomicI64>;
+    /// 현재 태스크가 요청한 재시작 중단 사유
+    static CURRENT_STOP: Arc<Mutex<Option<String>>>;
+}
+
+/// 현재 감시 중인 태스크의 하트비트 갱신 (감시 태스크 밖에서 호출하면 아무 일도 하지 않음)
+pub fn heartbeat() {
+    let _ =
+        CURRENT_BEAT.try_with(|beat| beat.store(Utc::now().timestamp_millis(), Ordering::Relaxed));
+}
+
+/// 현재 감시 중인 태스크가 끝나도 다시 띄우지 않도록 요청 (설정 누락처럼 재시도해도 실패하는 경우)
+/// 감시 태스크 밖에서 호출하면 아무 일도 하지 않음
+pub fn stop_restarting(reason: impl Into<String>) {
+    let reason = reason.into();
+    let _ = CURRENT_STOP.try_with(|stop| *stop.lock().unwrap() = Some(reason));
+}
+
+/// n번 연속 실패한 뒤의 재시작 대기 (첫 실패는 바로, 이후 감시 간격의 2배씩, 상한 10분)
+fn restart_backoff(consecutive_failures: u32) -> Duration {
+    match consecutive_failures {
+        0 => Duration::ZERO,
+        n => WATCHDOG_INTERVAL
+            .saturating_mul(2u32.saturating_pow(n - 1))
+            .min(MAX_RESTART_BACKOFF),
+    }
+}
+
+/// 감시 중인 태스크 하나의 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskHealth {
+    /// 태스크 이름 (예: "okx_funding_ws", "binance_mark_price_ws:BTCUSDT")
+    pub name: String,
+    /// 실행 중이고 하트비트가 제한 시간 안에 들어왔으면 true
+    pub healthy: bool,
+    pub running: bool,
+    /// 마지막으로 (재)시작한 시각
+    pub started_at: DateTime<Utc>,
+    pub last_heartbeat: DateTime<Utc>,
+    /// 하트비트 제한 시간 (None이면 종료될 때만 재시작)
+    pub stale_after_secs: Option<u64>,
+    pub restarts: u32,
+    pub last_restart_at: Option<DateTime<Utc>>,
+    pub last_restart_reason: Option<String>,
+    /// 백오프 중이면 다음 재시작 예정 시각
+    pub next_restart_at: Option<DateTime<Utc>>,
+    /// 태스크가 재시작 중단을 요청해 더 이상 띄우지 않으면 그 사유
+    pub stopped_reason: Option<String>,
+}
+
+/// 전체 태스크 상태 요약
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskSupervisorReport {
+    /// 재시작을 중단한 태스크를 뺀 모든 태스크가 정상이면 true (등록된 태스크가 없어도 true)
+    pub healthy: bool,
+    pub tasks: Vec<TaskHealth>,
+}
+
+struct SupervisedTask {
+    factory: TaskFactory,
+    stale_after: Option<Duration>,
+    handle: JoinHandle<()>,
+    last_beat: Arc<AtomicI64>,
+    started_at: DateTime<Utc>,
+    restarts: u32,
+    last_restart_at: Option<DateTime<Utc>>,
+    last_restart_reason: Option<String>,
+    /// 태스크가 `stop_restarting`으로 남긴 사유
+    stop_request: Arc<Mutex<Option<String>>>,
+    /// 마지막 정상 구간 이후 연속 실패 횟수 (백오프 계산용)
+    consecutive_failures: u32,
+    /// 감지했지만 백오프 때문에 아직 재시작하지 않은 실패 (사유, 재시작 예정 시각)
+    pending_restart: Option<(String, DateTime<Utc>)>,
+    /// 재시작을 중단했으면 그 사유
+    stopped: Option<String>,
+}
+
+impl SupervisedTask {
+    /// 하트비트를 현재 시각으로 초기화하고 태스크 실행
+    fn launch(
+        factory: &TaskFactory,
+        last_beat: &Arc<AtomicI64>,
+        stop_request: &Arc<Mutex<Option<String>>>,
+    ) -> JoinHandle<()> {
+        last_beat.store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+        tokio::spawn(CURRENT_BEAT.scope(
+            Arc::clone(last_beat),
+            CURRENT_STOP.scope(Arc::clone(stop_request), factory()),
+        ))
+    }
+
+    fn last_heartbeat(&self) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(self.last_beat.load(Ordering::Relaxed))
+            .single()
+            .unwrap_or(self.started_at)
+    }
+
+    fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        self.stale_after.is_some_and(|stale_after| {
+            (now - self.last_heartbeat())
+                .to_std()
+                .is_ok_and(|silent| silent > stale_after)
+        })
+    }
+
+    /// 재시작이 필요한 이유 (정상이면 None)
+    fn failure(&mut self, now: DateTime<Utc>) -> Option<String> {
+        if self.handle.is_finished() {
+            // 끝난 JoinHandle은 바로 Ready (이후 handle은 교체되므로 한 번만 poll)
+            return Some(match (&mut self.handle).now_or_never() {
+                Some(Err(e)) if e.is_panic() => "panicked".to_string(),
+                _ => "exited".to_string(),
+            });
+        }
+        self.is_stale(now).then(|| {
+            format!(
+                "no heartbeat for {}s",
+                (now - self.last_heartbeat()).num_seconds()
+            )
+        })
+    }
+
+    fn restart(&mut self, reason: String, now: DateTime<Utc>) {
+        self.handle.abort();
+        self.handle = Self::launch(&self.factory, &self.last_beat, &self.stop_request);
+        self.started_at = now;
+        self.restarts += 1;
+        self.consecutive_failures += 1;
+        self.last_restart_at = Some(now);
+        self.last_restart_reason = Some(reason);
+    }
+
+    /// 실패를 처리해 지금 재시작해야 하면 사유 반환 (백오프 대기 중이거나 중단했으면 None)
+    fn due_restart(&mut self, name: &str, now: DateTime<Utc>) -> Option<String> {
+        if self.stopped.is_some() {
+            return None;
+        }
+        if self.pending_restart.is_none() {
+            let reason = self.failure(now)?;
+            if let Some(stop) = self.stop_request.lock().unwrap().clone() {
+                tracing::warn!("백그라운드 태스크 재시작 중단: {} ({})", name, stop);
+                self.handle.abort();
+                self.stopped = Some(stop);
+                return None;
+            }
+            // 한동안 정상으로 돌다가 실패했으면 처음 실패처럼 바로 재시작
+            if (now - self.started_at)
+                .to_std()
+                .is_ok_and(|ran| ran >= STABLE_RUN)
+            {
+                self.consecutive_failures = 0;
+            }
+            let delay = restart_backoff(self.consecutive_failures);
+            let due = now + chrono::Duration::from_std(delay).unwrap_or_default();
+            self.pending_restart = Some((reason, due));
+        }
+        match &self.pending_restart {
+            Some((_, due)) if now < *due => None,
+            _ => self.pending_restart.take().map(|(reason, _)| reason),
+        }
+    }
+
+    fn health(&self, name: &str, now: DateTime<Utc>) -> TaskHealth {
+        let running = !self.handle.is_finished();
+        TaskHealth {
+            name: name.to_string(),
+            healthy: running && !self.is_stale(now),
+            running,
+            started_at: self.started_at,
+            last_heartbeat: self.last_heartbeat(),
+            stale_after_secs: self.stale_after.map(|d| d.as_secs()),
+            restarts: self.restarts,
+            last_restart_at: self.last_restart_at,
+            last_restart_reason: self.last_restart_reason.clone(),
+            next_restart_at: self.pending_restart.as_ref().map(|(_, due)| *due),
+            stopped_reason: self.stopped.clone(),
+        }
+    }
+}
+
+/// 이름 붙은 백그라운드 태스크 감시기
+#[derive(Default)]
+pub struct TaskSupervisor {
+    tasks: Mutex<HashMap<String, SupervisedTask>>,
+    watchdog_started: AtomicBool,
+}
+
+impl TaskSupervisor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 태스크를 등록하고 실행, 실제 등록된 이름 반환 (첫 등록 시 감시 루프도 시작)
+    /// `factory`는 (재)시작할 때마다 새 future를 만든다. 이미 있는 이름이면 `#2`, `#3`…을 붙인다.
+    /// `stale_after`가 Some이면 그 시간 동안 하트비트가 없을 때도 재시작
+    pub fn spawn<F, Fut>(
+        &'static self,
+        name: &str,
+        stale_after: Option<Duration>,
+        factory: F,
+    ) -> String
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let factory: TaskFactory = Arc::new(move || Box::pin(factory()));
+        let last_beat = Arc::new(AtomicI64::new(0));
+        let stop_request = Arc::new(Mutex::new(None));
+        let handle = SupervisedTask::launch(&factory, &last_beat, &stop_request);
+        let task = SupervisedTask {
+            factory,
+            stale_after,
+            handle,
+            last_beat,
+            started_at: Utc::now(),
+            restarts: 0,
+            last_restart_at: None,
+            last_restart_reason: None,
+            stop_request,
+            consecutive_failures: 0,
+            pending_restart: None,
+            stopped: None,
+        };
+
+        let registered = {
+            let mut tasks = self.tasks.lock().unwrap();
+            let mut registered = name.to_string();
+            let mut n = 1;
+            while tasks.contains_key(&registered) {
+                n += 1;
+                registered = format!("{}#{}", name, n);
+            }
+            tasks.insert(registered.clone(), task);
+            registered
+        };
+
+        self.start_watchdog();
+        registered
+    }
+
+    /// 종료되었거나 하트비트가 끊긴 태스크를 (백오프가 지났으면) 재시작하고 재시작한 이름 반환
+    pub fn check(&self, now: DateTime<Utc>) -> Vec<String> {
+        let mut restarted = Vec::new();
+        let mut tasks = self.tasks.lock().unwrap();
+        for (name, task) in tasks.iter_mut() {
+            if let Some(reason) = task.due_restart(name, now) {
+                tracing::warn!(
+                    "백그라운드 태스크 재시작: {} ({}, 누적 {}회)",
+                    name,
+                    reason,
+                    task.restarts + 1
+                );
+                task.restart(reason, now);
+                restarted.push(name.clone());
+            }
+        }
+        restarted.sort();
+        restarted
+    }
+
+    /// 태스크 상태 (이름 순 정렬)
+    pub fn report(&self) -> TaskSupervisorReport {
+        let now = Utc::now();
+        let mut tasks: Vec<TaskHealth> = self
+            .tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, task)| task.health(name, now))
+            .collect();
+        tasks.sort_by(|a, b| a.name.cmp(&b.name));
+        TaskSupervisorReport {
+            healthy: tasks
+                .iter()
+                .all(|t| t.healthy || t.stopped_reason.is_some()),
+            tasks,
+        }
+    }
+
+    /// 주기적으로 `check` 실행 (중복 호출은 무시)
+    fn start_watchdog(&'static self) {
+        if self.watchdog_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(WATCHDOG_INTERVAL).await;
+                self.check(Utc::now());
+            }
+        });
+    }
+}
+
+/// 전역 태스크 감시기
+static GLOBAL_SUPERVISOR: OnceLock<TaskSupervisor> = OnceLock::new();
+
+/// 전역 태스크 감시기 가져오기 (최초 호출 시 생성)
+pub fn task_supervisor() -> &'static TaskSupervisor {
+    GLOBAL_SUPERVISOR.get_or_init(TaskSupervisor::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    #[tokio::test]
+    async fn test_restarts_exited_and_silent_tasks() {
+        let supervisor: &'static TaskSupervisor = Box::leak(Box::new(TaskSupervisor::new()));
+        let runs = Arc::new(AtomicU32::new(0));
+
+        // 바로 끝나는 태스크
+        let counter = Arc::clone(&runs);
+        supervisor.spawn("exiting", None, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async {}
+        });
+        // 하트비트를 계속 보내는 태스크 (같은 이름으로 두 번)와 보내지 않는 태스크
+        for expected in ["beating", "beating#2"] {
+            let name = supervisor.spawn("beating", Some(Duration::from_secs(120)), || async {
+                loop {
+                    heartbeat();
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+            });
+            assert_eq!(name, expected);
+        }
+        supervisor.spawn("silent", Some(Duration::from_secs(60)), || {
+            std::future::pending::<()>()
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!supervisor.report().healthy);
+
+        // 90초 뒤라고 가정: 끝난 태스크와 하트비트가 60초 넘게 끊긴 태스크만 재시작
+        let later = Utc::now() + chrono::Duration::seconds(90);
+        assert_eq!(supervisor.check(later), vec!["exiting", "silent"]);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        let report = supervisor.report();
+        let task = |name: &str| report.tasks.iter().find(|t| t.name == name).unwrap();
+        assert_eq!(
+            task("exiting").last_restart_reason.as_deref(),
+            Some("exited")
+        );
+        assert_eq!(task("beating").restarts, 0);
+        assert!(task("beating#2").healthy);
+        assert_eq!(task("silent").restarts, 1);
+        assert!(task("silent")
+            .last_restart_reason
+            .as_deref()
+            .unwrap()
+            .starts_with("no heartbeat"));
+    }
+
+    #[test]
+    fn test_restart_backoff_grows_and_caps() {
+        assert_eq!(restart_backoff(0), Duration::ZERO);
+        assert_eq!(restart_backoff(1), Duration::from_secs(10));
+        assert_eq!(restart_backoff(3), Duration::from_secs(40));
+        assert_eq!(restart_backoff(20), MAX_RESTART_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn test_failing_task_backs_off_and_stop_request_is_honored() {
+        let supervisor: &'static TaskSupervisor = Box::leak(Box::new(TaskSupervisor::new()));
+        let runs = Arc::new(AtomicU32::new(0));
+
+        // 시작하자마자 끝나는 태스크와, 재시도해도 소용없다고 알리고 끝나는 태스크
+        let counter = Arc::clone(&runs);
+        supervisor.spawn("failing", None, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async {}
+        });
+        supervisor.spawn("no_api_key", None, || async {
+            stop_restarting("API key not set");
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // 첫 실패는 바로 재시작, 중단 요청한 태스크는 다시 띄우지 않음
+        let t0 = Utc::now();
+        let at = |secs: i64| t0 + chrono::Duration::seconds(secs);
+        assert_eq!(supervisor.check(at(0)), vec!["failing"]);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // 두 번째 연속 실패는 10초 뒤에야 재시작
+        assert!(supervisor.check(at(1)).is_empty());
+        assert!(supervisor.check(at(5)).is_empty());
+        assert_eq!(supervisor.check(at(11)), vec!["failing"]);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+
+        // 세 번째 연속 실패는 20초 대기
+        assert!(supervisor.check(at(12)).is_empty());
+        let report = supervisor.report();
+        let task = |name: &str| report.tasks.iter().find(|t| t.name == name).unwrap();
+        assert_eq!(task("failing").next_restart_at, Some(at(32)));
+        assert_eq!(task("no_api_key").restarts, 0);
+        assert_eq!(
+            task("no_api_key").stopped_reason.as_deref(),
+            Some("API key not set")
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! 24시간 거래량 USD 환산
+//!
+//! 거래소마다 24h 거래량 필드의 단위가 다르다.
+//! 예: OKX 현물 `volCcy24h`는 USDT 금액이지만 SWAP `volCcy24h`는 기초 자산 수량,
+//! Bitget 현물은 `usdtVol`/`quoteVol`/`baseVol`을 함께 준다.
+//! USDT 페어만 다루므로 USDT 금액은 그대로 USD로 보고, 기초 자산 수량은 가격을 곱한다.
+
+use interface::{ParseError, PayloadParser};
+
+/// 거래량 필드 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeUnit {
+    /// 호가 통화(USDT) 금액
+    Quote,
+    /// 기초 자산 수량
+    Base,
+}
+
+/// 후보 필드(우선순위 순) 중 값이 있는 첫 필드를 USD로 환산 (모두 비었으면 0)
+pub fn volume_24h_usd(
+    parser: &PayloadParser,
+    candidates: &[(&str, VolumeUnit)],
+    price: f64,
+) -> Result<f64, ParseError> {
+    let Some((value, unit)) = candidates.iter().find(|(v, _)| !v.trim().is_empty()) else {
+        return Ok(0.0);
+    };
+    let volume = parser.optional("vol_24h_usd", value)?;
+    Ok(match unit {
+        VolumeUnit::Quote => volume,
+        VolumeUnit::Base => volume * price,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::ExchangeId;
+
+    #[test]
+    fn test_volume_24h_usd() {
+        let parser = PayloadParser::new(ExchangeId::Okx);
+
+        // 금액 필드가 있으면 그대로
+        let quote = [("1500000", VolumeUnit::Quote), ("25", VolumeUnit::Base)];
+        assert_eq!(
+            volume_24h_usd(&parser, &quote, 60_000.0).unwrap(),
+            1_500_000.0
+        );
+
+        // 금액 필드가 비었으면 수량 × 가격
+        let base = [("", VolumeUnit::Quote), ("25", VolumeUnit::Base)];
+        assert_eq!(
+            volume_24h_usd(&parser, &base, 60_000.0).unwrap(),
+            1_500_000.0
+        );
+
+        assert_eq!(
+            volume_24h_usd(&parser, &[("", VolumeUnit::Quote)], 1.0).unwrap(),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//! 거래소 요청 가중치(IP weight) 사용량 추적과 사전 감속
+//!
+//! Binance는 응답마다 `X-MBX-USED-WEIGHT-1M` 헤더로 현재 분 구간에 사용한 가중치를 알려준다.
+//! 요청을 [`TrackedSend::send_tracked`]로 보내면 URL로 베뉴를 판별해 헤더 값을 기록하고,
+//! 다음 요청 전에 사용률이 높으면 미리 대기해 429/418(IP 차단)을 받기 전에 속도를 줄인다.
+//!
+//! - 사용률이 `RATE_LIMIT_SOFT_RATIO`(기본 0.7) 이상이면 `RATE_LIMIT_MAX_DELAY_MS`(기본 1000ms)까지
+//!   사용률에 비례해 대기
+//! - `RATE_LIMIT_HARD_RATIO`(기본 0.95) 이상이면 현재 분 구간이 끝날 때까지 대기
+//! - 429/418 응답의 `Retry-After` 동안은 요청을 보내지 않음
+//! - 한도: `BINANCE_SPOT_WEIGHT_LIMIT`(기본 6000), `BINANCE_FUTURES_WEIGHT_LIMIT`(기본 2400, 코인 선물 공용)
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::{StatusCode, Url};
+use serde::Serialize;
+use tracing::warn;
+
+const DEFAULT_SOFT_RATIO: f64 = 0.7;
+const DEFAULT_HARD_RATIO: f64 = 0.95;
+const DEFAULT_MAX_DELAY_MS: u64 = 1000;
+const DEFAULT_SPOT_WEIGHT_LIMIT: u32 = 6000;
+const DEFAULT_FUTURES_WEIGHT_LIMIT: u32 = 2400;
+/// Retry-After 헤더가 없을 때 429/418 이후 쉬는 시간
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// 가중치를 따로 집계하는 베뉴
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightVenue {
+    BinanceSpot,
+    BinanceFutures,
+    BinanceCoinFutures,
+}
+
+impl fmt::Display for WeightVenue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightVenue::BinanceSpot => write!(f, "binance_spot"),
+            WeightVenue::BinanceFutures => write!(f, "binance_futures"),
+            WeightVenue::BinanceCoinFutures => write!(f, "binance_coin_futures"),
+        }
+    }
+}
+
+impl WeightVenue {
+    /// 요청 URL로 베뉴 판별 (가중치를 추적하지 않는 거래소는 None)
+    pub fn from_url(url: &Url) -> Option<Self> {
+        let host = url.host_str()?;
+        match host {
+            "fapi.binance.com" => Some(WeightVenue::BinanceFutures),
+            "dapi.binance.com" => Some(WeightVenue::BinanceCoinFutures),
+            "testnet.binancefuture.com" if url.path().starts_with("/dapi") => {
+                Some(WeightVenue::BinanceCoinFutures)
+            }
+            "testnet.binancefuture.com" => Some(WeightVenue::BinanceFutures),
+            "testnet.binance.vision" => Some(WeightVenue::BinanceSpot),
+            _ if host.starts_with("api") && host.ends_with(".binance.com") => {
+                Some(WeightVenue::BinanceSpot)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 감속 설정
+#[derive(Debug, Clone)]
+pub struct ThrottleConfig {
+    pub soft_ratio: f64,
+    pub hard_ratio: f64,
+    pub max_delay: Duration,
+    pub spot_limit: u32,
+    pub futures_limit: u32,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            soft_ratio: DEFAULT_SOFT_RATIO,
+            hard_ratio: DEFAULT_HARD_RATIO,
+            max_delay: Duration::from_millis(DEFAULT_MAX_DELAY_MS),
+            spot_limit: DEFAULT_SPOT_WEIGHT_LIMIT,
+            futures_limit: DEFAULT_FUTURES_WEIGHT_LIMIT,
+        }
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
+
+impl ThrottleConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let soft_ratio = env_parse("RATE_LIMIT_SOFT_RATIO")
+            .filter(|v: &f64| *v > 0.0 && *v <= 1.0)
+            .unwrap_or(default.soft_ratio);
+        Self {
+            soft_ratio,
+            hard_ratio: env_parse("RATE_LIMIT_HARD_RATIO")
+                .filter(|v: &f64| *v > soft_ratio && *v <= 1.0)
+                .unwrap_or(default.hard_ratio.max(soft_ratio)),
+            max_delay: env_parse("RATE_LIMIT_MAX_DELAY_MS")
+                .map(Duration::from_millis)
+                .unwrap_or(default.max_delay),
+            spot_limit: env_parse("BINANCE_SPOT_WEIGHT_LIMIT")
+                .filter(|v| *v > 0)
+                .unwrap_or(default.spot_limit),
+            futures_limit: env_parse("BINANCE_FUTURES_WEIGHT_LIMIT")
+                .filter(|v| *v > 0)
+                .unwrap_or(default.futures_limit),
+        }
+    }
+
+    fn limit(&self, venue: WeightVenue) -> u32 {
+        match venue {
+            WeightVenue::BinanceSpot => self.spot_limit,
+            WeightVenue::BinanceFutures | WeightVenue::BinanceCoinFutures => self.futures_limit,
+        }
+    }
+}
+
+/// 베뉴의 마지막 관측 사용량
+#[derive(Debug, Clone, Default)]
+struct WeightUsage {
+    used: u32,
+    observed_at: Option<DateTime<Utc>>,
+    /// 429/418 이후 요청을 멈출 시각
+    blocked_until: Option<DateTime<Utc>>,
+}
+
+/// 분 구간 끝 시각 (Binance 가중치는 UTC 분 단위로 초기화)
+fn window_end(at: DateTime<Utc>) -> DateTime<Utc> {
+    let secs = at.timestamp();
+    DateTime::from_timestamp(secs - secs.rem_euclid(60) + 60, 0).unwrap_or(at)
+}
+
+impl WeightUsage {
+    /// 현재 분 구간의 사용량 (관측 이후 구간이 바뀌었으면 0)
+    fn current_used(&self, now: DateTime<Utc>) -> u32 {
+        match self.observed_at {
+            Some(at) if now < window_end(at) => self.used,
+            _ => 0,
+        }
+    }
+}
+
+/// 사용량과 설정으로 다음 요청 전 대기 시간 계산
+fn throttle_delay(
+    usage: &WeightUsage,
+    limit: u32,
+    config: &ThrottleConfig,
+    now: DateTime<Utc>,
+) -> Duration {
+    let until = |end: DateTime<Utc>| (end - now).to_std().unwrap_or_default();
+    if let Some(blocked_until) = usage.blocked_until.filter(|t| *t > now) {
+        return until(blocked_until);
+    }
+    let ratio = usage.current_used(now) as f64 / limit.max(1) as f64;
+    if ratio >= config.hard_ratio {
+        return usage
+            .observed_at
+            .map(|at| until(window_end(at)))
+            .unwrap_or_default();
+    }
+    if ratio >= config.soft_ratio {
+        let span = (config.hard_ratio - config.soft_ratio).max(f64::EPSILON);
+        return config
+            .max_delay
+            .mul_f64(((ratio - config.soft_ratio) / span).clamp(0.0, 1.0));
+    }
+    Duration::ZERO
+}
+
+/// 응답 헤더의 분당 사용 가중치 (`X-MBX-USED-WEIGHT-1M`, 없으면 `X-MBX-USED-WEIGHT`)
+fn used_weight(headers: &HeaderMap) -> Option<u32> {
+    ["x-mbx-used-weight-1m", "x-mbx-used-weight"]
+        .iter()
+        .find_map(|name| headers.get(*name)?.to_str().ok()?.trim().parse().ok())
+}
+
+/// 베뉴별 사용량 조회 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct WeightReport {
+    pub venue: WeightVenue,
+    pub used: u32,
+    pub limit: u32,
+    pub usage_ratio: f64,
+    /// 지금 요청하면 대기할 시간 (ms)
+    pub throttle_delay_ms: u64,
+    pub observed_at: Option<DateTime<Utc>>,
+    pub blocked_until: Option<DateTime<Utc>>,
+}
+
+/// 베뉴별 가중치 사용량 저장소
+pub struct WeightTracker {
+    config: ThrottleConfig,
+    usage: RwLock<HashMap<WeightVenue, WeightUsage>>,
+}
+
+impl WeightTracker {
+    pub fn new(config: ThrottleConfig) -> Self {
+        Self {
+            config,
+            usage: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 응답 상태/헤더 반영
+    pub fn record_response(
+        &self,
+        venue: WeightVenue,
+        status: StatusCode,
+        headers: &HeaderMap,
+        now: DateTime<Utc>,
+    ) {
+        let mut usage = self.usage.write().unwrap();
+        let entry = usage.entry(venue).or_default();
+        if let Some(used) = used_weight(headers) {
+            entry.used = used;
+            entry.observed_at = Some(now);
+        }
+        if status == StatusCode::TOO_MANY_REQUESTS || status.as_u16() == 418 {
+            let retry_after = headers
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok()?.trim().parse::<u64>().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_RETRY_AFTER);
+            warn!(
+                "{} 요청 한도 초과 (status {}), {:?} 동안 요청 중단",
+                venue, status, retry_after
+            );
+            entry.blocked_until = chrono::Duration::from_std(retry_after)
+                .ok()
+                .map(|d| now + d);
+        }
+    }
+
+    /// 지금 요청하면 대기할 시간
+    pub fn delay(&self, venue: WeightVenue, now: DateTime<Utc>) -> Duration {
+        let usage = self.usage.read().unwrap();
+        usage
+            .get(&venue)
+            .map(|u| throttle_delay(u, self.config.limit(venue), &self.config, now))
+            .unwrap_or_default()
+    }
+
+    /// 사용률에 따라 대기
+    pub async fn throttle(&self, venue: WeightVenue) {
+        let delay = self.delay(venue, Utc::now());
+        if !delay.is_zero() {
+            tracing::debug!("{} 요청 가중치 감속: {:?} 대기", venue, delay);
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    pub fn report(&self) -> Vec<WeightReport> {
+        let now = Utc::now();
+        let usage = self.usage.read().unwrap();
+        let mut reports: Vec<WeightReport> = usage
+            .iter()
+            .map(|(venue, u)| {
+                let limit = self.config.limit(*venue);
+                let used = u.current_used(now);
+                WeightReport {
+                    venue: *venue,
+                    used,
+                    limit,
+                    usage_ratio: used as f64 / limit.max(1) as f64,
+                    throttle_delay_ms: throttle_delay(u, limit, &self.config, now).as_millis()
+                        as u64,
+                    observed_at: u.observed_at,
+                    blocked_until: u.blocked_until.filter(|t| *t > now),
+                }
+            })
+            .collect();
+        reports.sort_by_key(|r| r.venue);
+        reports
+    }
+}
+
+/// 전역 가중치 추적기 (`ThrottleConfig::from_env`)
+pub fn weight_tracker() -> &'static WeightTracker {
+    static TRACKER: OnceLock<WeightTracker> = OnceLock::new();
+    TRACKER.get_or_init(|| WeightTracker::new(ThrottleConfig::from_env()))
+}
+
+/// 가중치를 추적하며 요청을 보내는 확장 트레이트
+#[async_trait]
+pub trait TrackedSend {
+    /// 사용률에 따라 먼저 대기한 뒤 요청을 보내고 응답 헤더의 사용량을 기록한다.
+    /// 가중치를 추적하지 않는 거래소 URL이면 `send()`와 같다
+    async fn send_tracked(self) -> reqwest::Result<reqwest::Response>;
+}
+
+#[async_trait]
+impl TrackedSend for reqwest::RequestBuilder {
+    async fn send_tracked(self) -> reqwest::Result<reqwest::Response> {
+        let (client, request) = self.build_split();
+        let request = request?;
+        let Some(venue) = WeightVenue::from_url(request.url()) else {
+            return client.execute(request).await;
+        };
+
+        let tracker = weight_tracker();
+        tracker.throttle(venue).await;
+        let response = client.execute(request).await?;
+        tracker.record_response(venue, response.status(), response.headers(), Utc::now());
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_weight_tracking_and_throttle() {
+        let url = |s: &str| Url::parse(s).unwrap();
+        assert_eq!(
+            WeightVenue::from_url(&url("https://api.binance.com/api/v3/order")),
+            Some(WeightVenue::BinanceSpot)
+        );
+        assert_eq!(
+            WeightVenue::from_url(&url("https://testnet.binancefuture.com/fapi/v1/order")),
+            Some(WeightVenue::BinanceFutures)
+        );
+        assert_eq!(
+            WeightVenue::from_url(&url("https://api.bybit.com/v5/market/tickers")),
+            None
+        );
+
+        let tracker = WeightTracker::new(ThrottleConfig {
+            futures_limit: 1000,
+            ..Default::default()
+        });
+        let venue = WeightVenue::BinanceFutures;
+        let now = DateTime::from_timestamp(1_714_550_410, 0).unwrap(); // 분 시작 후 10초
+        let mut headers = HeaderMap::new();
+
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("500"));
+        tracker.record_response(venue, StatusCode::OK, &headers, now);
+        assert_eq!(tracker.delay(venue, now), Duration::ZERO);
+
+        // soft(0.7)와 hard(0.95) 사이: 최대 1초에 비례
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("825"));
+        tracker.record_response(venue, StatusCode::OK, &headers, now);
+        assert_eq!(tracker.delay(venue, now), Duration::from_millis(500));
+
+        // hard 이상: 분 구간 끝까지, 다음 구간에는 초기화
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("960"));
+        tracker.record_response(venue, StatusCode::OK, &headers, now);
+        assert_eq!(tracker.delay(venue, now), Duration::from_secs(50));
+        let next_minute = now + chrono::Duration::seconds(50);
+        assert_eq!(tracker.delay(venue, next_minute), Duration::ZERO);
+
+        // 429: Retry-After 동안 중단
+        headers.insert("retry-after", HeaderValue::from_static("5"));
+        tracker.record_response(venue, StatusCode::TOO_MANY_REQUESTS, &headers, next_minute);
+        assert_eq!(tracker.delay(venue, next_minute), Duration::from_secs(5));
+        assert_eq!(tracker.report()[0].limit, 1000);
+    }
+}
@@ -0,0 +1,468 @@
+//! 재연결 WebSocket 클라이언트
+//!
+//! OKX funding 채널, Binance 가격 피드, User Data Stream 등이 공통으로 사용하는
+//! 재연결 루프를 제공한다.
+//! - 지수 백오프 재연결 (연결에 성공하면 백오프 초기화)
+//! - ping/pong keep-alive 및 무응답 연결 감지
+//! - 재연결 시 구독 메시지 재전송
+//! - 연결을 유지한 채 주기적으로 구독 목록 갱신 (추가 구독/해지)
+//! - 연결 상태 변경 콜백 및 상태 레지스트리 보고
+//! - 태스크 감시기(supervisor) 안에서 실행되면 재연결 시도/수신/ping마다 하트비트 보고
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+use tokio::time::Instant;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use interface::ExchangeId;
+
+use crate::status::{status_registry, ChannelKind, LinkState};
+use crate::supervisor;
+
+/// 연결 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// 연결 시도 중
+    Connecting,
+    /// 연결 및 구독 완료
+    Connected,
+    /// 연결 종료 (정상 종료 또는 오류)
+    Disconnected,
+    /// 재연결 대기 중
+    Reconnecting { attempt: u32, delay: Duration },
+}
+
+impl ConnectionState {
+    fn link_state(&self) -> LinkState {
+        match self {
+            ConnectionState::Connecting => LinkState::Connecting,
+            ConnectionState::Connected => LinkState::Connected,
+            ConnectionState::Disconnected | ConnectionState::Reconnecting { .. } => {
+                LinkState::Disconnected
+            }
+        }
+    }
+}
+
+/// keep-alive 용으로 보낼 메시지 형식
+#[derive(Debug, Clone)]
+pub enum PingMessage {
+    /// WebSocket ping 프레임
+    Frame,
+    /// 텍스트 메시지 (예: OKX의 "ping")
+    Text(String),
+}
+
+/// keep-alive 설정
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    /// ping 전송 간격
+    pub interval: Duration,
+    /// 이 시간 동안 아무 메시지도 받지 못하면 연결이 죽은 것으로 보고 재연결
+    pub timeout: Duration,
+    pub message: PingMessage,
+}
+
+/// 재연결 정책
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// 첫 재연결 대기 시간
+    pub initial_backoff: Duration,
+    /// 최대 재연결 대기 시간
+    pub max_backoff: Duration,
+    /// 재연결 실패 시 대기 시간 증가 배수
+    pub multiplier: f64,
+    /// 구독 메시지 사이 대기 시간 (거래소 rate limit 대응)
+    pub subscribe_interval: Duration,
+    /// keep-alive 설정 (None이면 ping을 보내지 않음)
+    pub heartbeat: Option<Heartbeat>,
+    /// 이 간격마다 `WsHandler::resubscribe`를 호출해 기존 연결에 구독 변경 메시지 전송
+    /// (None이면 재연결할 때만 구독 목록이 바뀜)
+    pub resubscribe_interval: Option<Duration>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            multiplier: 2.0,
+            subscribe_interval: Duration::ZERO,
+            heartbeat: None,
+            resubscribe_interval: None,
+        }
+    }
+}
+
+/// 지수 백오프 계산기
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    multiplier: f64,
+    current: Duration,
+    attempt: u32,
+}
+
+impl Backoff {
+    pub fn new(config: &ReconnectConfig) -> Self {
+        Self {
+            initial: config.initial_backoff,
+            max: config.max_backoff.max(config.initial_backoff),
+            multiplier: config.multiplier.max(1.0),
+            current: config.initial_backoff,
+            attempt: 0,
+        }
+    }
+
+    /// 다음 대기 시간과 시도 횟수를 반환하고 대기 시간을 늘린다
+    pub fn next_delay(&mut self) -> (u32, Duration) {
+        let delay = self.current;
+        self.attempt += 1;
+        self.current = self.current.mul_f64(self.multiplier).min(self.max);
+        (self.attempt, delay)
+    }
+
+    /// 연결 성공 시 초기화
+    pub fn reset(&mut self) {
+        self.current = self.initial;
+        self.attempt = 0;
+    }
+}
+
+/// 현재 구독 목록과 최신 심볼 목록 비교 (추가할 심볼, 해지할 심볼)
+pub(crate) fn diff_symbols(
+    subscribed: &HashSet<String>,
+    latest: &[String],
+) -> (Vec<String>, Vec<String>) {
+    let latest_set: HashSet<&String> = latest.iter().collect();
+    let added: Vec<String> = latest
+        .iter()
+        .filter(|s| !subscribed.contains(*s))
+        .cloned()
+        .collect();
+    let mut removed: Vec<String> = subscribed
+        .iter()
+        .filter(|s| !latest_set.contains(s))
+        .cloned()
+        .collect();
+    removed.sort();
+    (added, removed)
+}
+
+/// ReconnectingClient가 호출하는 메시지/상태 핸들러
+#[async_trait]
+pub trait WsHandler: Send {
+    /// 연결(재연결 포함) 직후 전송할 구독 메시지 목록
+    async fn subscriptions(&mut self) -> eyre::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// 연결 중 `resubscribe_interval`마다 호출. 기존 연결에 보낼 추가 구독/해지 메시지 목록
+    /// Err를 반환해도 연결은 유지하고 다음 주기에 다시 시도한다
+    async fn resubscribe(&mut self) -> eyre::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// 텍스트 메시지 처리. Err를 반환하면 연결을 끊고 재연결한다
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()>;
+
+    /// 연결 상태 변경 알림
+    fn on_state_change(&mut self, _state: ConnectionState) {}
+}
+
+/// 지수 백오프, keep-alive, 구독 재전송을 지원하는 WebSocket 클라이언트
+#[derive(Debug, Clone)]
+pub struct ReconnectingClient {
+    name: String,
+    url: String,
+    config: ReconnectConfig,
+    /// 상태 레지스트리에 보고할 (거래소, 채널 이름)
+    status: Option<(ExchangeId, String)>,
+}
+
+impl ReconnectingClient {
+    /// name은 로그에 사용할 연결 이름 (예: "OKX funding-rate")
+    pub fn new(name: &str, url: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            config: ReconnectConfig::default(),
+            status: None,
+        }
+    }
+
+    pub fn with_config(mut self, config: ReconnectConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 연결 상태와 마지막 성공/실패를 전역 상태 레지스트리에 보고
+    pub fn with_status(mut self, exchange: ExchangeId, channel: &str) -> Self {
+        self.status = Some((exchange, channel.to_string()));
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// 연결이 끊길 때마다 백오프 후 재연결하며 영원히 실행
+    pub async fn run<H: WsHandler>(&self, handler: &mut H) {
+        let mut backoff = Backoff::new(&self.config);
+
+        loop {
+            supervisor::heartbeat();
+            self.change_state(handler, ConnectionState::Connecting);
+
+            match self.connect_once(handler, &mut backoff).await {
+                Ok(_) => {
+                    tracing::warn!("{} WebSocket 연결이 종료되었습니다", self.name);
+                }
+                Err(e) => {
+                    tracing::warn!("{} WebSocket 오류: {:?}", self.name, e);
+                    if let Some((exchange, channel)) = &self.status {
+                        status_registry().record_error(
+                            *exchange,
+                            channel,
+                            ChannelKind::WebSocket,
+                            &e.to_string(),
+                        );
+                    }
+                }
+            }
+            self.change_state(handler, ConnectionState::Disconnected);
+
+            let (attempt, delay) = backoff.next_delay();
+            tracing::info!(
+                "{} WebSocket 재연결 대기: {:?} (시도 {})",
+                self.name,
+                delay,
+                attempt
+            );
+            self.change_state(handler, ConnectionState::Reconnecting { attempt, delay });
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    fn change_state<H: WsHandler>(&self, handler: &mut H, state: ConnectionState) {
+        if let Some((exchange, channel)) = &self.status {
+            status_registry().set_state(
+                *exchange,
+                channel,
+                ChannelKind::WebSocket,
+                state.link_state(),
+            );
+        }
+        handler.on_state_change(state);
+    }
+
+    async fn connect_once<H: WsHandler>(
+        &self,
+        handler: &mut H,
+        backoff: &mut Backoff,
+    ) -> eyre::Result<()> {
+        let (ws_stream, _) = connect_async(self.url.as_str()).await?;
+        let (mut write, mut read) = ws_stream.split();
+        tracing::info!("{} WebSocket 연결 성공: {}", self.name, self.url);
+
+        // 구독 메시지 (재연결 시에도 매번 재전송)
+        let subscriptions = handler.subscriptions().await?;
+        for (i, msg) in subscriptions.into_iter().enumerate() {
+            if i > 0 && !self.config.subscribe_interval.is_zero() {
+                tokio::time::sleep(self.config.subscribe_interval).await;
+            }
+            write.send(Message::Text(msg)).await?;
+        }
+
+        backoff.reset();
+        self.change_state(handler, ConnectionState::Connected);
+
+        let heartbeat = self.config.heartbeat.clone();
+        let ping_every = heartbeat
+            .as_ref()
+            .map(|h| h.interval)
+            .unwrap_or(Duration::from_secs(3600));
+        let mut ping_timer = tokio::time::interval_at(Instant::now() + ping_every, ping_every);
+        let mut last_received = Instant::now();
+
+        let resubscribe_every = self
+            .config
+            .resubscribe_interval
+            .unwrap_or(Duration::from_secs(3600));
+        let mut resubscribe_timer =
+            tokio::time::interval_at(Instant::now() + resubscribe_every, resubscribe_every);
+
+        loop {
+            tokio::select! {
+                msg = read.next() => {
+                    let msg = match msg {
+                        Some(msg) => msg?,
+                        None => return Ok(()),
+                    };
+                    last_received = Instant::now();
+                    supervisor::heartbeat();
+
+                    match msg {
+                        Message::Text(text) => {
+                            handler.on_message(&text).await?;
+                            if let Some((exchange, channel)) = &self.status {
+                                status_registry().record_success(
+                                    *exchange,
+                                    channel,
+                                    ChannelKind::WebSocket,
+                                );
+                            }
+                        }
+                        Message::Ping(data) => write.send(Message::Pong(data)).await?,
+                        Message::Close(_) => return Ok(()),
+                        _ => {}
+                    }
+                }
+                _ = ping_timer.tick(), if heartbeat.is_some() => {
+                    supervisor::heartbeat();
+                    let heartbeat = heartbeat.as_ref().unwrap();
+                    if last_received.elapsed() > heartbeat.timeout {
+                        return Err(eyre::eyre!(
+                            "{}초 동안 메시지를 받지 못했습니다",
+                            heartbeat.timeout.as_secs()
+                        ));
+                    }
+
+                    let ping = match &heartbeat.message {
+                        PingMessage::Frame => Message::Ping(Vec::new()),
+                        PingMessage::Text(text) => Message::Text(text.clone()),
+                    };
+                    write.send(ping).await?;
+                }
+                _ = resubscribe_timer.tick(), if self.config.resubscribe_interval.is_some() => {
+                    let messages = match handler.resubscribe().await {
+                        Ok(messages) => messages,
+                        Err(e) => {
+                            tracing::warn!("{} 구독 목록 갱신 실패: {:?}", self.name, e);
+                            continue;
+                        }
+                    };
+                    for (i, msg) in messages.into_iter().enumerate() {
+                        if i > 0 && !self.config.subscribe_interval.is_zero() {
+                            tokio::time::sleep(self.config.subscribe_interval).await;
+                        }
+                        write.send(Message::Text(msg)).await?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_backoff_grows_and_resets() {
+        let config = ReconnectConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(350),
+            multiplier: 2.0,
+            ..Default::default()
+        };
+        let mut backoff = Backoff::new(&config);
+
+        assert_eq!(backoff.next_delay(), (1, Duration::from_millis(100)));
+        assert_eq!(backoff.next_delay(), (2, Duration::from_millis(200)));
+        assert_eq!(backoff.next_delay(), (3, Duration::from_millis(350)));
+        assert_eq!(backoff.next_delay(), (4, Duration::from_millis(350)));
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), (1, Duration::from_millis(100)));
+    }
+
+    struct RecordingHandler {
+        received: Arc<Mutex<Vec<String>>>,
+        states: Arc<Mutex<Vec<ConnectionState>>>,
+    }
+
+    #[async_trait]
+    impl WsHandler for RecordingHandler {
+        async fn subscriptions(&mut self) -> eyre::Result<Vec<String>> {
+            Ok(vec!["sub".to_string()])
+        }
+
+        async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+            self.received.lock().unwrap().push(text.to_string());
+            Ok(())
+        }
+
+        fn on_state_change(&mut self, state: ConnectionState) {
+            self.states.lock().unwrap().push(state);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_replayed_after_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server_received = Arc::new(Mutex::new(Vec::new()));
+
+        // 연결마다 구독 메시지를 받고 응답한 뒤 연결을 끊는 서버
+        let server_log = server_received.clone();
+        let server = tokio::spawn(async move {
+            let mut n = 0;
+            while let Ok((stream, _)) = listener.accept().await {
+                n += 1;
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                if let Some(Ok(Message::Text(text))) = ws.next().await {
+                    server_log.lock().unwrap().push(text);
+                }
+                ws.send(Message::Text(format!("hello-{}", n)))
+                    .await
+                    .unwrap();
+                let _ = ws.close(None).await;
+            }
+        });
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let states = Arc::new(Mutex::new(Vec::new()));
+        let mut handler = RecordingHandler {
+            received: received.clone(),
+            states: states.clone(),
+        };
+        let client = ReconnectingClient::new("test", &format!("ws://{}", addr)).with_config(
+            ReconnectConfig {
+                initial_backoff: Duration::from_millis(10),
+                ..Default::default()
+            },
+        );
+        let runner = tokio::spawn(async move { client.run(&mut handler).await });
+
+        for _ in 0..100 {
+            if received.lock().unwrap().len() >= 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        runner.abort();
+        server.abort();
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(
+            &received[..2],
+            &["hello-1".to_string(), "hello-2".to_string()]
+        );
+        assert!(server_received.lock().unwrap().len() >= 2);
+        assert!(server_received.lock().unwrap().iter().all(|m| m == "sub"));
+
+        let states = states.lock().unwrap().clone();
+        assert_eq!(states[0], ConnectionState::Connecting);
+        assert_eq!(states[1], ConnectionState::Connected);
+        assert!(states
+            .iter()
+            .any(|s| matches!(s, ConnectionState::Reconnecting { attempt: 1, .. })));
+    }
+}
@@ -0,0 +1,274 @@
+//! 펀딩 정산 주기와 연율 환산
+//!
+//! 거래소/심볼마다 정산 주기가 달라(1h/4h/8h) 같은 펀딩비라도 연율이 몇 배씩 차이 난다.
+//! 연율은 복리 없이 1년 정산 횟수만큼 단순 합산한다.
+//!
+//! 정산 시각도 여기서 계산한다. 거래소마다 epoch 단위(초/밀리초)가 달라 UTC로 정규화하고,
+//! 정산 주기는 UTC 00:00 기준으로 맞춰져 있다고 본다 (1h/2h/4h/8h 모두 24시간을 나눔).
+//! 새 거래소는 `default_funding_interval_hours`에 기본 주기만 추가하면 된다.
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+
+use crate::{ExchangeId, PerpSnapshot, UnifiedSnapshot};
+
+pub const HOURS_PER_YEAR: f64 = 24.0 * 365.0;
+
+/// 거래소 기본 펀딩 정산 주기 (시간, 심볼별 주기를 모를 때 사용)
+pub fn default_funding_interval_hours(exchange: ExchangeId) -> Option<f64> {
+    match exchange {
+        ExchangeId::Binance | ExchangeId::Bybit | ExchangeId::Okx => Some(8.0),
+        ExchangeId::Bitget => Some(4.0),
+        // 선물 마켓 없음
+        ExchangeId::Bithumb => None,
+    }
+}
+
+/// 거래소 epoch 타임스탬프를 UTC 시각으로 (0 이하면 None)
+/// 자릿수로 단위를 판별한다: 초(~10자리), 밀리초(~13자리), 마이크로초(~16자리)
+pub fn utc_from_epoch(value: i64) -> Option<DateTime<Utc>> {
+    if value <= 0 {
+        None
+    } else if value < 100_000_000_000 {
+        DateTime::from_timestamp(value, 0)
+    } else if value < 100_000_000_000_000 {
+        DateTime::from_timestamp_millis(value)
+    } else {
+        DateTime::from_timestamp_micros(value)
+    }
+}
+
+/// 문자열 epoch 타임스탬프를 UTC 시각으로 (빈 문자열이나 숫자가 아니면 None)
+pub fn utc_from_epoch_str(value: &str) -> Option<DateTime<Utc>> {
+    utc_from_epoch(value.trim().parse::<i64>().ok()?)
+}
+
+fn interval_duration(interval_hours: f64) -> Option<Duration> {
+    if !interval_hours.is_finite() {
+        return None;
+    }
+    let minutes = (interval_hours * 60.0).round() as i64;
+    (minutes > 0).then(|| Duration::minutes(minutes))
+}
+
+/// 이번 정산 시각과 다음 정산 시각 차이로 정산 주기 계산 (시간, 0 이하면 None)
+pub fn funding_interval_between(current: DateTime<Utc>, next: DateTime<Utc>) -> Option<f64> {
+    let hours = (next - current).num_seconds() as f64 / 3_600.0;
+    (hours > 0.0).then_some(hours)
+}
+
+/// 연속된 정산 시각들에서 정산 주기 추정 (시간)
+/// 가장 자주 나온 간격(분 단위)을 쓰고, 동률이면 짧은 쪽. 누락된 정산이 섞여도 주기를 잡는다
+pub fn detect_funding_interval_hours(times: &[DateTime<Utc>]) -> Option<f64> {
+    let mut sorted = times.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    let mut counts: Vec<(i64, usize)> = Vec::new();
+    for pair in sorted.windows(2) {
+        let minutes = (pair[1] - pair[0]).num_minutes();
+        if minutes <= 0 {
+            continue;
+        }
+        match counts.iter_mut().find(|(m, _)| *m == minutes) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((minutes, 1)),
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+        .map(|(minutes, _)| minutes as f64 / 60.0)
+}
+
+/// `now` 이후 첫 정산 시각 (주기를 UTC 00:00에 맞춤, `now`가 정산 시각이면 다음 주기)
+pub fn next_funding_after(now: DateTime<Utc>, interval_hours: f64) -> Option<DateTime<Utc>> {
+    let interval = interval_duration(interval_hours)?;
+    Some(now.duration_trunc(interval).ok()? + interval)
+}
+
+/// 지난 정산 시각을 주기만큼 밀어 `now` 이후로 맞춤 (이미 이후면 그대로)
+pub fn roll_forward_funding_time(
+    next: DateTime<Utc>,
+    now: DateTime<Utc>,
+    interval_hours: f64,
+) -> Option<DateTime<Utc>> {
+    if next > now {
+        return Some(next);
+    }
+    let interval = interval_duration(interval_hours)?;
+    let behind = (now - next).num_milliseconds() / interval.num_milliseconds();
+    Some(next + interval * (behind as i32 + 1))
+}
+
+/// 거래소 기본 주기로 계산한 다음 정산 시각
+pub fn default_next_funding_time(
+    exchange: ExchangeId,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    next_funding_after(now, default_funding_interval_hours(exchange)?)
+}
+
+/// 정산 1회 펀딩비를 연율로 환산 (0.1 == 10%/년)
+pub fn annualize_funding(funding_rate: f64, interval_hours: f64) -> f64 {
+    funding_rate * HOURS_PER_YEAR / interval_hours
+}
+
+impl PerpSnapshot {
+    /// 심볼별 정산 주기 (없으면 거래소 기본 주기)
+    pub fn effective_funding_interval_hours(&self) -> Option<f64> {
+        self.funding_interval_hours
+            .filter(|h| *h > 0.0)
+            .or_else(|| default_funding_interval_hours(self.exchange))
+    }
+
+    /// 현재 펀딩비의 연율
+    pub fn annualized_funding(&self) -> Option<f64> {
+        Some(annualize_funding(
+            self.funding_rate,
+            self.effective_funding_interval_hours()?,
+        ))
+    }
+
+    /// `now` 이후 다음 정산 시각과 추정 여부
+    /// next_funding_time이 있으면 `now` 이후로 맞춰 쓰고, 없으면 정산 주기로 계산한다 (추정)
+    pub fn resolved_next_funding_time(&self, now: DateTime<Utc>) -> Option<(DateTime<Utc>, bool)> {
+        let interval_hours = self.effective_funding_interval_hours()?;
+        match self.next_funding_time {
+            Some(next) => Some((roll_forward_funding_time(next, now, interval_hours)?, false)),
+            None => Some((next_funding_after(now, interval_hours)?, true)),
+        }
+    }
+}
+
+impl UnifiedSnapshot {
+    /// 선물 데이터의 정산 주기 (없으면 거래소 기본 주기)
+    pub fn effective_funding_interval_hours(&self) -> Option<f64> {
+        self.perp
+            .as_ref()?
+            .funding_interval_hours
+            .filter(|h| *h > 0.0)
+            .or_else(|| default_funding_interval_hours(self.exchange))
+    }
+
+    /// 현재 펀딩비의 연율 (선물 데이터가 없으면 None)
+    pub fn annualized_funding(&self) -> Option<f64> {
+        let rate = self.perp.as_ref()?.funding_rate;
+        Some(annualize_funding(
+            rate,
+            self.effective_funding_interval_hours()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Currency, Price};
+    use chrono::TimeZone;
+
+    fn perp(exchange: ExchangeId, funding_interval_hours: Option<f64>) -> PerpSnapshot {
+        PerpSnapshot {
+            exchange,
+            symbol: "BTCUSDT".to_string(),
+            currency: Currency::USDT,
+            mark_price: Price::new(100.0),
+            index_price: None,
+            oi_usd: 0.0,
+            vol_24h_usd: 0.0,
+            funding_rate: 0.0001,
+            next_funding_time: None,
+            funding_interval_hours,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_annualized_funding_uses_symbol_interval() {
+        // 0.01% × 3회/일 × 365 = 10.95%
+        let eight = perp(ExchangeId::Binance, None);
+        assert!((eight.annualized_funding().unwrap() - 0.1095).abs() < 1e-12);
+
+        // 같은 펀딩비라도 1시간 주기면 8배
+        let hourly = perp(ExchangeId::Bybit, Some(1.0));
+        assert!((hourly.annualized_funding().unwrap() - 0.876).abs() < 1e-12);
+
+        assert_eq!(
+            perp(ExchangeId::Bitget, None).effective_funding_interval_hours(),
+            Some(4.0)
+        );
+        assert!(perp(ExchangeId::Bithumb, None)
+            .annualized_funding()
+            .is_none());
+    }
+
+    #[test]
+    fn test_epoch_normalization() {
+        let at = Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap();
+        assert_eq!(utc_from_epoch(at.timestamp()), Some(at));
+        assert_eq!(utc_from_epoch(at.timestamp_millis()), Some(at));
+        assert_eq!(utc_from_epoch(at.timestamp_micros()), Some(at));
+        assert_eq!(
+            utc_from_epoch_str(&at.timestamp_millis().to_string()),
+            Some(at)
+        );
+        assert_eq!(utc_from_epoch(0), None);
+        assert_eq!(utc_from_epoch_str(""), None);
+    }
+
+    #[test]
+    fn test_next_funding_schedule() {
+        let at = |h: u32, m: u32| Utc.with_ymd_and_hms(2024, 5, 1, h, m, 0).unwrap();
+        assert_eq!(next_funding_after(at(3, 59), 4.0), Some(at(4, 0)));
+        // 정산 시각 정각이면 다음 주기
+        assert_eq!(next_funding_after(at(4, 0), 4.0), Some(at(8, 0)));
+        assert_eq!(
+            default_next_funding_time(ExchangeId::Binance, at(17, 0)),
+            Some(Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            default_next_funding_time(ExchangeId::Bithumb, at(1, 0)),
+            None
+        );
+
+        // 지난 시각은 주기만큼 밀고, 미래 시각은 그대로
+        assert_eq!(
+            roll_forward_funding_time(at(0, 0), at(9, 0), 4.0),
+            Some(at(12, 0))
+        );
+        assert_eq!(
+            roll_forward_funding_time(at(8, 0), at(8, 0), 4.0),
+            Some(at(12, 0))
+        );
+        assert_eq!(
+            roll_forward_funding_time(at(10, 0), at(9, 0), 4.0),
+            Some(at(10, 0))
+        );
+
+        let mut snapshot = perp(ExchangeId::Bybit, Some(1.0));
+        assert_eq!(
+            snapshot.resolved_next_funding_time(at(9, 30)),
+            Some((at(10, 0), true))
+        );
+        snapshot.next_funding_time = Some(at(9, 0));
+        assert_eq!(
+            snapshot.resolved_next_funding_time(at(9, 30)),
+            Some((at(10, 0), false))
+        );
+    }
+
+    #[test]
+    fn test_detect_funding_interval() {
+        let at = |h: u32| Utc.with_ymd_and_hms(2024, 5, 1, h, 0, 0).unwrap();
+        assert_eq!(funding_interval_between(at(0), at(8)), Some(8.0));
+        assert_eq!(funding_interval_between(at(8), at(8)), None);
+
+        // 정산 하나가 빠져도(8 → 16 사이 12 누락) 가장 흔한 간격
+        let times = [at(16), at(0), at(4), at(8), at(20), at(4)];
+        assert_eq!(detect_funding_interval_hours(&times), Some(4.0));
+        // 동률이면 짧은 쪽
+        assert_eq!(
+            detect_funding_interval_hours(&[at(0), at(1), at(3)]),
+            Some(1.0)
+        );
+        assert_eq!(detect_funding_interval_hours(&[at(0)]), None);
+    }
+}
@@ -2,6 +2,22 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+pub mod funding;
+pub mod orderbook;
+pub mod parse;
+pub mod quality;
+pub mod units;
+
+pub use funding::{
+    annualize_funding, default_funding_interval_hours, default_next_funding_time,
+    detect_funding_interval_hours, funding_interval_between, next_funding_after,
+    roll_forward_funding_time, utc_from_epoch, utc_from_epoch_str,
+};
+pub use orderbook::BookSide;
+pub use parse::{parse_f64, ParseError, PayloadParser};
+pub use quality::{DataSource, QualityFlag, SnapshotQuality};
+pub use units::{Bps, Notional, Price, Qty, UnitError};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ExchangeId {
     Binance,
@@ -23,11 +39,17 @@ pub struct PerpSnapshot {
     pub exchange: ExchangeId,
     pub symbol: String,
     pub currency: Currency,
-    pub mark_price: f64,
+    pub mark_price: Price,
+    /// 인덱스 가격 (제공하는 거래소만, 펀딩비 예측에 사용)
+    #[serde(default)]
+    pub index_price: Option<Price>,
     pub oi_usd: f64,
     pub vol_24h_usd: f64,
     pub funding_rate: f64, // 0.01 == 1%
     pub next_funding_time: Option<DateTime<Utc>>,
+    /// 펀딩 정산 주기 (시간, 거래소가 알려주지 않으면 None)
+    #[serde(default)]
+    pub funding_interval_hours: Option<f64>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -36,25 +58,81 @@ pub struct SpotSnapshot {
     pub exchange: ExchangeId,
     pub symbol: String,
     pub currency: Currency,
-    pub price: f64,
+    pub price: Price,
     pub vol_24h_usd: f64,
     pub updated_at: DateTime<Utc>,
 }
 
+/// 현재 `UnifiedSnapshot` 스키마 버전
+/// 필드를 추가/변경할 때마다 올리고, 새 필드에는 `#[serde(default)]`를 붙여
+/// 이전 버전 페이로드도 역직렬화되도록 유지합니다.
+/// - 1: schema_version 도입 이전 형식
+/// - 2: schema_version 필드 추가
+/// - 3: perp.index_price, perp.predicted_funding_rate 추가
+/// - 4: basis_bps, cross_basis_bps 추가
+/// - 5: quality 추가
+pub const UNIFIED_SNAPSHOT_SCHEMA_VERSION: u32 = 5;
+
+fn legacy_schema_version() -> u32 {
+    1
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnifiedSnapshot {
+    /// 스키마 버전 (필드가 없는 페이로드는 버전 1)
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
     pub exchange: ExchangeId,
     pub symbol: String,
     pub currency: Currency,
     // 선물 데이터
+    #[serde(default)]
     pub perp: Option<PerpData>,
     // 현물 데이터
+    #[serde(default)]
     pub spot: Option<SpotData>,
+    /// 같은 거래소 현물 대비 선물 마크 가격 베이시스 (한쪽이 없으면 None)
+    #[serde(default)]
+    pub basis_bps: Option<Bps>,
+    /// 인덱스 가격 대비 선물 마크 가격 베이시스 (인덱스가 없으면 None)
+    #[serde(default)]
+    pub cross_basis_bps: Option<Bps>,
     // 환율 정보 (USD 기준)
     pub exchange_rates: ExchangeRates,
+    /// 데이터 품질 (구성 요소 나이, 출처, 이상 플래그)
+    #[serde(default)]
+    pub quality: Option<SnapshotQuality>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl UnifiedSnapshot {
+    /// 같은 거래소 현물 대비 선물 마크 가격 베이시스
+    /// 선물/현물 중 하나가 없거나, 표시 통화가 다르거나, 가격이 0이면 None
+    pub fn spot_perp_basis(&self) -> Option<Bps> {
+        let (perp, spot) = (self.perp.as_ref()?, self.spot.as_ref()?);
+        if perp.currency != spot.currency || perp.mark_price.is_zero() || spot.price.is_zero() {
+            return None;
+        }
+        Some(Bps::basis(spot.price, perp.mark_price))
+    }
+
+    /// 인덱스 가격 대비 선물 마크 가격 베이시스 (인덱스가 없거나 가격이 0이면 None)
+    pub fn index_basis(&self) -> Option<Bps> {
+        let perp = self.perp.as_ref()?;
+        let index = perp.index_price.filter(|p| !p.is_zero())?;
+        if perp.mark_price.is_zero() {
+            return None;
+        }
+        Some(Bps::basis(index, perp.mark_price))
+    }
+
+    /// basis_bps, cross_basis_bps 채우기 (통합 스냅샷을 만든 뒤 호출)
+    pub fn fill_basis(&mut self) {
+        self.basis_bps = self.spot_perp_basis();
+        self.cross_basis_bps = self.index_basis();
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeRates {
     pub usd_krw: f64,  // 1 USD = ? KRW (예: 1300.0)
@@ -66,17 +144,25 @@ pub struct ExchangeRates {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerpData {
     pub currency: Currency,
-    pub mark_price: f64,
+    pub mark_price: Price,
+    #[serde(default)]
+    pub index_price: Option<Price>,
     pub oi_usd: f64,
     pub vol_24h_usd: f64,
-    pub funding_rate: f64, // 0.01 == 1%
+    pub funding_rate: f64, // 0.01 == 1% (마지막으로 확정된 펀딩비)
+    /// 프리미엄 인덱스 TWAP으로 예측한 다음 펀딩비 (예측 불가면 None)
+    #[serde(default)]
+    pub predicted_funding_rate: Option<f64>,
     pub next_funding_time: Option<DateTime<Utc>>,
+    /// 펀딩 정산 주기 (시간, 거래소가 알려주지 않으면 None)
+    #[serde(default)]
+    pub funding_interval_hours: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotData {
     pub currency: Currency,
-    pub price: f64,
+    pub price: Price,
     pub vol_24h_usd: f64,
 }
 
@@ -137,6 +223,21 @@ pub struct DepositWithdrawalFee {
     pub updated_at: DateTime<Utc>,
 }
 
+/// 자산 입출금 가능 여부 (네트워크가 여럿이면 하나라도 열려 있으면 가능)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferStatus {
+    pub currency: String,
+    pub deposit_enabled: bool,
+    pub withdraw_enabled: bool,
+}
+
+impl TransferStatus {
+    /// 입금 또는 출금이 막혀 있는지
+    pub fn is_suspended(&self) -> bool {
+        !self.deposit_enabled || !self.withdraw_enabled
+    }
+}
+
 impl FeeInfo {
     pub fn new(maker: f64, taker: f64) -> Self {
         Self { maker, taker }
@@ -158,3 +259,99 @@ pub enum ExchangeError {
     #[error("other error: {0}")]
     Other(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_v1_snapshot() {
+        let payload = include_str!("../fixtures/unified_snapshot_v1.json");
+        let snapshots: Vec<UnifiedSnapshot> = serde_json::from_str(payload).unwrap();
+
+        assert_eq!(snapshots.len(), 2);
+        assert!(snapshots.iter().all(|s| s.schema_version == 1));
+        assert_eq!(snapshots[0].symbol, "BTCUSDT");
+        assert!(snapshots[0].perp.is_some());
+        // 현물 전용 항목은 perp 필드 자체가 없어도 된다
+        assert!(snapshots[1].perp.is_none());
+        assert_eq!(snapshots[1].currency, Currency::KRW);
+    }
+
+    #[test]
+    fn test_deserialize_v2_snapshot() {
+        let payload = include_str!("../fixtures/unified_snapshot_v2.json");
+        let snapshots: Vec<UnifiedSnapshot> = serde_json::from_str(payload).unwrap();
+
+        assert!(snapshots.iter().all(|s| s.schema_version == 2));
+        // 버전 3에서 추가된 필드는 없으면 None
+        let perp = snapshots[0].perp.as_ref().unwrap();
+        assert!(perp.index_price.is_none());
+        assert!(perp.predicted_funding_rate.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_v3_snapshot() {
+        let payload = include_str!("../fixtures/unified_snapshot_v3.json");
+        let snapshots: Vec<UnifiedSnapshot> = serde_json::from_str(payload).unwrap();
+
+        assert!(snapshots.iter().all(|s| s.schema_version == 3));
+        // 버전 4에서 추가된 필드는 없으면 None
+        assert!(snapshots[0].basis_bps.is_none());
+        assert!(snapshots[0].cross_basis_bps.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_v4_snapshot() {
+        let payload = include_str!("../fixtures/unified_snapshot_v4.json");
+        let snapshots: Vec<UnifiedSnapshot> = serde_json::from_str(payload).unwrap();
+
+        assert!(snapshots.iter().all(|s| s.schema_version == 4));
+        // 버전 5에서 추가된 필드는 없으면 None이고, 최소 품질 조건을 통과하지 못한다
+        assert!(snapshots[0].quality.is_none());
+        assert!(!snapshots[0].meets_quality(0.0));
+    }
+
+    #[test]
+    fn test_current_snapshot_round_trip() {
+        let payload = include_str!("../fixtures/unified_snapshot_v5.json");
+        let snapshots: Vec<UnifiedSnapshot> = serde_json::from_str(payload).unwrap();
+        assert!(snapshots
+            .iter()
+            .all(|s| s.schema_version == UNIFIED_SNAPSHOT_SCHEMA_VERSION));
+
+        let json = serde_json::to_value(&snapshots).unwrap();
+        assert_eq!(json[0]["schema_version"], UNIFIED_SNAPSHOT_SCHEMA_VERSION);
+        let again: Vec<UnifiedSnapshot> = serde_json::from_value(json).unwrap();
+        assert_eq!(again[0].symbol, snapshots[0].symbol);
+        let perp = again[0].perp.as_ref().unwrap();
+        assert_eq!(perp.index_price, Some(Price::new(63998.1)));
+        assert_eq!(perp.predicted_funding_rate, Some(0.000182));
+        assert_eq!(again[0].basis_bps, snapshots[0].basis_bps);
+        assert!(again[0].cross_basis_bps.is_some());
+        let quality = again[0].quality.as_ref().unwrap();
+        assert_eq!(quality.perp_source, Some(DataSource::WebSocket));
+        assert!(quality.has_flag(QualityFlag::StaleSpot));
+        assert!(again[0].meets_quality(0.6));
+        assert!(!again[0].meets_quality(0.8));
+    }
+
+    #[test]
+    fn test_fill_basis() {
+        let payload = include_str!("../fixtures/unified_snapshot_v5.json");
+        let mut snapshot = serde_json::from_str::<Vec<UnifiedSnapshot>>(payload)
+            .unwrap()
+            .remove(0);
+        let stored = snapshot.basis_bps.unwrap().value();
+        snapshot.fill_basis();
+        assert!((snapshot.basis_bps.unwrap().value() - stored).abs() < 1e-6);
+        assert!((snapshot.cross_basis_bps.unwrap().value() - 2.2500667989).abs() < 1e-6);
+
+        // 현물이 없거나 인덱스가 없으면 None
+        snapshot.spot = None;
+        snapshot.perp.as_mut().unwrap().index_price = None;
+        snapshot.fill_basis();
+        assert!(snapshot.basis_bps.is_none());
+        assert!(snapshot.cross_basis_bps.is_none());
+    }
+}
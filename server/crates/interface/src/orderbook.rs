@@ -0,0 +1,136 @@
+//! 호가창 계산 (중간가, microprice, 누적 잔량, 수량 기준 VWAP)
+//!
+//! `OrderBook`의 bids는 가격 높은 순, asks는 가격 낮은 순으로 정렬돼 있다고 가정한다.
+
+use crate::{Bps, OrderBook, OrderBookEntry};
+
+/// 호가창 한쪽 (시장가 매수는 Asks를, 시장가 매도는 Bids를 소진)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSide {
+    Bids,
+    Asks,
+}
+
+impl OrderBook {
+    /// 한쪽 호가 (최우선 호가부터)
+    pub fn levels(&self, side: BookSide) -> &[OrderBookEntry] {
+        match side {
+            BookSide::Bids => &self.bids,
+            BookSide::Asks => &self.asks,
+        }
+    }
+
+    pub fn best_bid(&self) -> Option<&OrderBookEntry> {
+        self.bids.first()
+    }
+
+    pub fn best_ask(&self) -> Option<&OrderBookEntry> {
+        self.asks.first()
+    }
+
+    /// 최우선 매수/매도 호가의 중간값
+    pub fn mid_price(&self) -> Option<f64> {
+        Some((self.best_bid()?.price + self.best_ask()?.price) / 2.0)
+    }
+
+    /// 중간가 대비 최우선 호가 스프레드
+    pub fn spread(&self) -> Option<Bps> {
+        let bid = self.best_bid()?.price;
+        let ask = self.best_ask()?.price;
+        let mid = (bid + ask) / 2.0;
+        (mid > 0.0).then(|| Bps::from_fraction((ask - bid) / mid))
+    }
+
+    /// 최우선 호가 잔량으로 가중한 가격 (잔량이 두꺼운 쪽에서 먼 가격 쪽으로 치우침)
+    /// 양쪽 잔량이 모두 0이면 중간가
+    pub fn microprice(&self) -> Option<f64> {
+        let bid = self.best_bid()?;
+        let ask = self.best_ask()?;
+        let total = bid.quantity + ask.quantity;
+        if total <= 0.0 {
+            return self.mid_price();
+        }
+        Some((bid.price * ask.quantity + ask.price * bid.quantity) / total)
+    }
+
+    /// `limit_price`까지 한쪽 호가의 누적 잔량 (Bids는 limit 이상, Asks는 limit 이하)
+    pub fn depth_to_price(&self, side: BookSide, limit_price: f64) -> f64 {
+        self.levels(side)
+            .iter()
+            .take_while(|level| match side {
+                BookSide::Bids => level.price >= limit_price,
+                BookSide::Asks => level.price <= limit_price,
+            })
+            .map(|level| level.quantity)
+            .sum()
+    }
+
+    /// 한쪽 호가를 `qty`만큼 소진할 때의 평균 체결가 (잔량이 부족하면 None)
+    pub fn vwap(&self, side: BookSide, qty: f64) -> Option<f64> {
+        if qty <= 0.0 {
+            return None;
+        }
+        let mut remaining = qty;
+        let mut cost = 0.0;
+        for level in self.levels(side) {
+            let take = remaining.min(level.quantity);
+            cost += take * level.price;
+            remaining -= take;
+            if remaining <= 0.0 {
+                return Some(cost / qty);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExchangeId;
+    use chrono::Utc;
+
+    fn entry(price: f64, quantity: f64) -> OrderBookEntry {
+        OrderBookEntry { price, quantity }
+    }
+
+    fn book() -> OrderBook {
+        OrderBook {
+            exchange: ExchangeId::Binance,
+            symbol: "BTCUSDT".to_string(),
+            bids: vec![entry(99.0, 1.0), entry(98.0, 2.0), entry(97.0, 5.0)],
+            asks: vec![entry(101.0, 3.0), entry(102.0, 1.0), entry(103.0, 4.0)],
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_mid_spread_and_microprice() {
+        let book = book();
+        assert_eq!(book.mid_price(), Some(100.0));
+        assert!((book.spread().unwrap().value() - 200.0).abs() < 1e-9);
+        // 매도 잔량(3)이 매수 잔량(1)보다 두꺼우므로 매수 호가 쪽으로 치우침
+        assert_eq!(book.microprice(), Some(99.5));
+
+        let mut empty_side = book.clone();
+        empty_side.asks.clear();
+        assert!(empty_side.mid_price().is_none());
+        assert!(empty_side.microprice().is_none());
+    }
+
+    #[test]
+    fn test_depth_and_vwap() {
+        let book = book();
+        assert_eq!(book.depth_to_price(BookSide::Asks, 102.0), 4.0);
+        assert_eq!(book.depth_to_price(BookSide::Bids, 98.0), 3.0);
+        assert_eq!(book.depth_to_price(BookSide::Bids, 100.0), 0.0);
+
+        assert_eq!(book.vwap(BookSide::Asks, 2.0), Some(101.0));
+        // 101 × 3 + 102 × 1
+        assert_eq!(book.vwap(BookSide::Asks, 4.0), Some(101.25));
+        // 99 × 1 + 98 × 1
+        assert_eq!(book.vwap(BookSide::Bids, 2.0), Some(98.5));
+        assert!(book.vwap(BookSide::Bids, 9.0).is_none());
+        assert!(book.vwap(BookSide::Asks, 0.0).is_none());
+    }
+}
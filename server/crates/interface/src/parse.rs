@@ -0,0 +1,185 @@
+//! 거래소 응답 숫자 필드 파싱 헬퍼
+//!
+//! `.parse().unwrap_or(0.0)`은 잘못된 페이로드를 0 가격/0 펀딩비로 바꿔 버린다.
+//! 여기의 헬퍼는 필드 이름과 원본 값을 담은 에러를 돌려주고, 거래소별 실패 횟수를 센다.
+//! strict 모드(`STRICT_PARSE=1` 또는 `set_strict_parse(true)`)에서는 선택 필드가
+//! 잘못되어도 0으로 채우지 않고 해당 항목을 버리도록 에러를 반환한다.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{ExchangeError, ExchangeId};
+
+/// 숫자 필드 파싱 실패
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("invalid {field}: {value:?} ({reason})")]
+pub struct ParseError {
+    pub field: &'static str,
+    pub value: String,
+    pub reason: String,
+}
+
+impl From<ParseError> for ExchangeError {
+    fn from(e: ParseError) -> Self {
+        ExchangeError::Other(e.to_string())
+    }
+}
+
+/// 유한한 f64로 파싱 (빈 문자열, NaN, inf는 실패)
+pub fn parse_f64(field: &'static str, value: &str) -> Result<f64, ParseError> {
+    let error = |reason: String| ParseError {
+        field,
+        value: value.to_string(),
+        reason,
+    };
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(error("empty".to_string()));
+    }
+    let parsed = trimmed.parse::<f64>().map_err(|e| error(e.to_string()))?;
+    if !parsed.is_finite() {
+        return Err(error("not finite".to_string()));
+    }
+    Ok(parsed)
+}
+
+/// 거래소별 파싱 실패 통계
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParseFailureStats {
+    /// 잘못된 값을 만난 횟수
+    pub failures: u64,
+    /// 잘못된 값 때문에 버린 항목 수
+    pub dropped: u64,
+    /// 필드별 실패 횟수
+    pub by_field: BTreeMap<&'static str, u64>,
+}
+
+#[derive(Default)]
+struct ParseMetrics {
+    by_exchange: Mutex<BTreeMap<String, ParseFailureStats>>,
+}
+
+static GLOBAL_PARSE_METRICS: OnceLock<ParseMetrics> = OnceLock::new();
+static STRICT_PARSE: OnceLock<AtomicBool> = OnceLock::new();
+
+fn parse_metrics() -> &'static ParseMetrics {
+    GLOBAL_PARSE_METRICS.get_or_init(ParseMetrics::default)
+}
+
+fn strict_flag() -> &'static AtomicBool {
+    STRICT_PARSE.get_or_init(|| {
+        let enabled = std::env::var("STRICT_PARSE")
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        AtomicBool::new(enabled)
+    })
+}
+
+/// strict 모드 여부 (최초 조회 시 `STRICT_PARSE` 환경 변수로 초기화)
+pub fn strict_parse() -> bool {
+    strict_flag().load(Ordering::Relaxed)
+}
+
+pub fn set_strict_parse(enabled: bool) {
+    strict_flag().store(enabled, Ordering::Relaxed);
+}
+
+/// 거래소별 파싱 실패 통계 스냅샷 (거래소 이름 순)
+pub fn parse_failure_stats() -> BTreeMap<String, ParseFailureStats> {
+    parse_metrics().by_exchange.lock().unwrap().clone()
+}
+
+fn record(exchange: ExchangeId, field: &'static str, dropped: bool) {
+    let mut map = parse_metrics().by_exchange.lock().unwrap();
+    let stats = map.entry(format!("{:?}", exchange)).or_default();
+    stats.failures += 1;
+    *stats.by_field.entry(field).or_default() += 1;
+    if dropped {
+        stats.dropped += 1;
+    }
+}
+
+/// 한 거래소 응답을 파싱할 때 쓰는 헬퍼 (실패를 거래소 단위로 집계)
+#[derive(Debug, Clone, Copy)]
+pub struct PayloadParser {
+    exchange: ExchangeId,
+    strict: bool,
+}
+
+impl PayloadParser {
+    /// 현재 strict 모드 설정을 사용
+    pub fn new(exchange: ExchangeId) -> Self {
+        Self {
+            exchange,
+            strict: strict_parse(),
+        }
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// 필수 필드 (가격 등). 잘못된 값이면 항상 에러 → 호출 측에서 항목을 버린다
+    pub fn required(&self, field: &'static str, value: &str) -> Result<f64, ParseError> {
+        parse_f64(field, value).inspect_err(|_| record(self.exchange, field, true))
+    }
+
+    /// 선택 필드 (거래량, OI, 펀딩비 등). 빈 값은 0.0
+    /// 잘못된 값은 strict 모드면 에러(항목 버림), 아니면 실패만 세고 0.0
+    pub fn optional(&self, field: &'static str, value: &str) -> Result<f64, ParseError> {
+        if value.trim().is_empty() {
+            return Ok(0.0);
+        }
+        match parse_f64(field, value) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                record(self.exchange, field, self.strict);
+                if self.strict {
+                    Err(e)
+                } else {
+                    Ok(0.0)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_f64_context() {
+        assert_eq!(parse_f64("mark_price", " 101.5 ").unwrap(), 101.5);
+
+        let err = parse_f64("mark_price", "abc").unwrap_err();
+        assert_eq!(err.field, "mark_price");
+        assert_eq!(err.value, "abc");
+        assert!(parse_f64("mark_price", "").is_err());
+        assert!(parse_f64("mark_price", "NaN").is_err());
+    }
+
+    #[test]
+    fn test_payload_parser_strict_and_lenient() {
+        // 다른 테스트와 통계가 섞이지 않도록 이 테스트에서만 쓰는 거래소 사용
+        let lenient = PayloadParser::new(ExchangeId::Bitget).with_strict(false);
+        assert_eq!(lenient.optional("funding_rate", "").unwrap(), 0.0);
+        assert_eq!(lenient.optional("funding_rate", "x").unwrap(), 0.0);
+        assert!(lenient.required("mark_price", "x").is_err());
+
+        let strict = lenient.with_strict(true);
+        assert!(strict.optional("funding_rate", "x").is_err());
+        assert_eq!(strict.optional("funding_rate", "0.0001").unwrap(), 0.0001);
+
+        let stats = parse_failure_stats().remove("Bitget").unwrap();
+        assert_eq!(stats.failures, 3);
+        // required 실패 1 + strict optional 실패 1
+        assert_eq!(stats.dropped, 2);
+        assert_eq!(stats.by_field["funding_rate"], 2);
+    }
+}
@@ -0,0 +1,68 @@
+//! 통합 스냅샷 데이터 품질
+//!
+//! oracle이 스냅샷마다 구성 요소(선물/현물/환율)의 수집 시점 나이, 데이터 출처(WS/REST),
+//! 파싱 이상 여부를 붙이고 0~1 점수로 요약한다. 트레이더는 점수가 낮은 스냅샷을 시그널에서 뺀다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::UnifiedSnapshot;
+
+/// 구성 요소 데이터 출처
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataSource {
+    Rest,
+    WebSocket,
+}
+
+/// 점수를 깎은 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityFlag {
+    /// 선물 데이터가 기준 시간보다 오래됨
+    StalePerp,
+    /// 현물 데이터가 기준 시간보다 오래됨
+    StaleSpot,
+    /// 환율이 기준 시간보다 오래됨
+    StaleExchangeRate,
+    /// 이번 수집 주기에 해당 거래소 응답 파싱 실패가 있었음
+    ParseAnomaly,
+    /// WebSocket이 끊겨 REST 조회 값으로 대체됨
+    RestFallback,
+}
+
+/// 스냅샷 품질 (나이는 oracle이 스냅샷을 만든 시점 기준, 초)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotQuality {
+    /// 0.0(신뢰 불가) ~ 1.0(정상)
+    pub score: f64,
+    #[serde(default)]
+    pub perp_age_secs: Option<f64>,
+    #[serde(default)]
+    pub spot_age_secs: Option<f64>,
+    pub exchange_rate_age_secs: f64,
+    #[serde(default)]
+    pub perp_source: Option<DataSource>,
+    #[serde(default)]
+    pub spot_source: Option<DataSource>,
+    #[serde(default)]
+    pub flags: Vec<QualityFlag>,
+}
+
+impl SnapshotQuality {
+    pub fn has_flag(&self, flag: QualityFlag) -> bool {
+        self.flags.contains(&flag)
+    }
+}
+
+impl UnifiedSnapshot {
+    /// 품질 점수 (품질 정보가 없는 이전 버전 스냅샷은 None)
+    pub fn quality_score(&self) -> Option<f64> {
+        self.quality.as_ref().map(|q| q.score)
+    }
+
+    /// 최소 품질 점수 충족 여부 (품질 정보가 없으면 충족하지 않은 것으로 본다)
+    pub fn meets_quality(&self, min_score: f64) -> bool {
+        self.quality_score().is_some_and(|score| score >= min_score)
+    }
+}
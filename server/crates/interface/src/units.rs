@@ -0,0 +1,251 @@
+//! 단위가 있는 수치 타입
+//!
+//! 베이시스(bps)와 비율(0.0006), 가격과 수량, 수량과 명목가를 모두 f64로 넘기면
+//! 인자 순서나 단위를 바꿔 써도 컴파일러가 잡아주지 못한다. 여기의 newtype은
+//! JSON에서는 숫자 하나로 그대로 직렬화되고, 역직렬화할 때 값의 범위를 검사한다.
+//! - `Bps`: 유한한 값 (음수 가능)
+//! - `Price`, `Qty`, `Notional`: 유한하고 음수가 아닌 값
+//!
+//! 단위가 바뀌는 계산은 연산자/변환 메서드로만 허용한다
+//! (`Price * Qty = Notional`, `Notional / Price = Qty`, `Bps::basis(현물, 선물)`).
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 단위 값 검증 실패
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum UnitError {
+    #[error("{unit} must be finite: {value}")]
+    NotFinite { unit: &'static str, value: f64 },
+    #[error("{unit} must not be negative: {value}")]
+    Negative { unit: &'static str, value: f64 },
+}
+
+macro_rules! unit_newtype {
+    ($(#[$meta:meta])* $name:ident, $unit:literal, non_negative = $non_negative:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+        #[serde(try_from = "f64", into = "f64")]
+        pub struct $name(f64);
+
+        impl $name {
+            pub const ZERO: Self = Self(0.0);
+
+            /// 검증 없이 생성 (상수, 이미 검증된 계산 결과용)
+            pub const fn new(value: f64) -> Self {
+                Self(value)
+            }
+
+            /// 외부 입력 검증 후 생성
+            pub fn try_new(value: f64) -> Result<Self, UnitError> {
+                if !value.is_finite() {
+                    return Err(UnitError::NotFinite { unit: $unit, value });
+                }
+                if $non_negative && value < 0.0 {
+                    return Err(UnitError::Negative { unit: $unit, value });
+                }
+                Ok(Self(value))
+            }
+
+            pub const fn value(self) -> f64 {
+                self.0
+            }
+
+            pub fn is_zero(self) -> bool {
+                self.0 == 0.0
+            }
+
+            pub fn min(self, other: Self) -> Self {
+                Self(self.0.min(other.0))
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                Self(self.0.max(other.0))
+            }
+        }
+
+        impl TryFrom<f64> for $name {
+            type Error = UnitError;
+
+            fn try_from(value: f64) -> Result<Self, Self::Error> {
+                Self::try_new(value)
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+    };
+}
+
+unit_newtype!(
+    /// 베이시스 포인트 (1bps = 0.01%)
+    Bps,
+    "bps",
+    non_negative = false
+);
+
+unit_newtype!(
+    /// 호가 통화 기준 가격
+    Price,
+    "price",
+    non_negative = true
+);
+
+unit_newtype!(
+    /// 기초자산 수량
+    Qty,
+    "qty",
+    non_negative = true
+);
+
+unit_newtype!(
+    /// 호가 통화 기준 명목가 (가격 × 수량)
+    Notional,
+    "notional",
+    non_negative = true
+);
+
+impl Bps {
+    /// 비율(0.0006 == 6bps)에서 변환
+    pub fn from_fraction(fraction: f64) -> Self {
+        Self(fraction * 10_000.0)
+    }
+
+    /// 비율로 변환 (6bps → 0.0006)
+    pub fn to_fraction(self) -> f64 {
+        self.0 / 10_000.0
+    }
+
+    /// 기준 가격 대비 다른 가격의 괴리 ((other - reference) / reference)
+    pub fn basis(reference: Price, other: Price) -> Self {
+        Self::from_fraction((other.0 - reference.0) / reference.0)
+    }
+
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl Sub for Bps {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Bps {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Mul<Qty> for Price {
+    type Output = Notional;
+
+    fn mul(self, rhs: Qty) -> Notional {
+        Notional(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Price> for Qty {
+    type Output = Notional;
+
+    fn mul(self, rhs: Price) -> Notional {
+        Notional(self.0 * rhs.0)
+    }
+}
+
+impl Div<Price> for Notional {
+    type Output = Qty;
+
+    fn div(self, rhs: Price) -> Qty {
+        Qty(self.0 / rhs.0)
+    }
+}
+
+impl Qty {
+    /// 줄어든 수량 (음수가 되지 않도록 0에서 멈춤)
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self((self.0 - rhs.0).max(0.0))
+    }
+}
+
+impl Notional {
+    /// 다른 호가 통화로 환산 (rate: 1 원래 통화 = rate 새 통화)
+    pub fn convert(self, rate: f64) -> Self {
+        Self(self.0 * rate)
+    }
+}
+
+impl Price {
+    /// 다른 호가 통화로 환산 (rate: 1 원래 통화 = rate 새 통화)
+    pub fn convert(self, rate: f64) -> Self {
+        Self(self.0 * rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions_and_validation() {
+        assert!((Bps::from_fraction(0.0006).value() - 6.0).abs() < 1e-9);
+        assert!((Bps::new(25.0).to_fraction() - 0.0025).abs() < 1e-12);
+        let basis = Bps::basis(Price::new(100.0), Price::new(100.5));
+        assert!((basis.value() - 50.0).abs() < 1e-9);
+
+        let notional = Price::new(50_000.0) * Qty::new(0.02);
+        assert_eq!(notional, Notional::new(1_000.0));
+        assert_eq!(notional / Price::new(50_000.0), Qty::new(0.02));
+        assert_eq!(Qty::new(1.0).saturating_sub(Qty::new(2.0)), Qty::ZERO);
+
+        // JSON에서는 숫자 그대로
+        assert_eq!(serde_json::to_string(&Price::new(1.5)).unwrap(), "1.5");
+        let bps: Bps = serde_json::from_str("-12.5").unwrap();
+        assert_eq!(bps, Bps::new(-12.5));
+        assert!(serde_json::from_str::<Price>("-1.0").is_err());
+        assert!(serde_json::from_str::<Qty>("-0.1").is_err());
+        assert_eq!(
+            Notional::try_new(f64::NAN).unwrap_err().to_string(),
+            "notional must be finite: NaN"
+        );
+    }
+}
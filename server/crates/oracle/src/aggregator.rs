@@ -0,0 +1,312 @@
+//! 외부 펀딩비 집계 서비스 대조 (Coinglass 형식)
+//!
+//! 수집 주기마다(최소 간격 이상 지났을 때) 외부 집계 API의 거래소별 펀딩비를 받아
+//! 우리가 수집한 선물 스냅샷의 펀딩비와 비교한다. 차이가 허용치(bps)를 넘으면 경고를 남기고
+//! `/funding-aggregator-check`로 노출해, 수집기 파싱 버그가 매매에 쓰이기 전에 드러나게 한다.
+//!
+//! 응답은 Coinglass `funding-rate/exchange-list` 형식을 따른다 (펀딩비는 % 단위).
+//! ```json
+//! { "code": "0", "data": [
+//!   { "symbol": "BTC", "stablecoin_margin_list": [ { "exchange": "Binance", "funding_rate": 0.01 } ] }
+//! ] }
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+
+use crate::filter::base_asset;
+use crate::server::AppState;
+use interface::{ExchangeId, PerpSnapshot};
+
+const DEFAULT_API_KEY_HEADER: &str = "CG-API-KEY";
+const DEFAULT_TOLERANCE_BPS: f64 = 5.0;
+const DEFAULT_MIN_INTERVAL_SECS: u64 = 60;
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 외부 집계 서비스 설정
+#[derive(Debug, Clone)]
+pub struct AggregatorConfig {
+    pub url: String,
+    pub api_key: Option<String>,
+    /// API 키를 보낼 헤더 이름
+    pub api_key_header: String,
+    /// 이 이상 차이 나면 불일치로 표시 (bps)
+    pub tolerance_bps: f64,
+    /// 대조 최소 간격 (외부 API 호출 제한)
+    pub min_interval: Duration,
+}
+
+impl AggregatorConfig {
+    /// `ORACLE_AGGREGATOR_URL`(없으면 비활성), `ORACLE_AGGREGATOR_API_KEY`,
+    /// `ORACLE_AGGREGATOR_API_KEY_HEADER`(기본 CG-API-KEY), `ORACLE_AGGREGATOR_TOLERANCE_BPS`(기본 5),
+    /// `ORACLE_AGGREGATOR_MIN_INTERVAL_SECS`(기본 60)
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("ORACLE_AGGREGATOR_URL")
+            .ok()
+            .filter(|v| !v.trim().is_empty())?;
+        Some(Self {
+            url,
+            api_key: std::env::var("ORACLE_AGGREGATOR_API_KEY")
+                .ok()
+                .filter(|v| !v.is_empty()),
+            api_key_header: std::env::var("ORACLE_AGGREGATOR_API_KEY_HEADER")
+                .unwrap_or_else(|_| DEFAULT_API_KEY_HEADER.to_string()),
+            tolerance_bps: std::env::var("ORACLE_AGGREGATOR_TOLERANCE_BPS")
+                .ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| *v > 0.0)
+                .unwrap_or(DEFAULT_TOLERANCE_BPS),
+            min_interval: Duration::from_secs(
+                std::env::var("ORACLE_AGGREGATOR_MIN_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_MIN_INTERVAL_SECS),
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AggregateResponse {
+    #[serde(default)]
+    data: Vec<AggregateSymbol>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AggregateSymbol {
+    symbol: String,
+    #[serde(default)]
+    stablecoin_margin_list: Vec<AggregateVenue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AggregateVenue {
+    exchange: String,
+    funding_rate: Option<f64>,
+}
+
+/// 외부 집계 서비스의 거래소/자산별 펀딩비 (0.01 == 1%)
+pub type AggregateRates = HashMap<(ExchangeId, String), f64>;
+
+/// 집계 서비스 응답 파싱 (모르는 거래소와 펀딩비가 없는 항목은 건너뜀)
+pub fn parse_aggregate(body: &str) -> Result<AggregateRates, serde_json::Error> {
+    let response: AggregateResponse = serde_json::from_str(body)?;
+    let mut rates = HashMap::new();
+    for symbol in response.data {
+        let asset = symbol.symbol.trim().to_uppercase();
+        for venue in symbol.stablecoin_margin_list {
+            let (Some(exchange), Some(rate)) = (parse_venue(&venue.exchange), venue.funding_rate)
+            else {
+                continue;
+            };
+            // 집계 서비스는 % 단위
+            rates.insert((exchange, asset.clone()), rate / 100.0);
+        }
+    }
+    Ok(rates)
+}
+
+fn parse_venue(name: &str) -> Option<ExchangeId> {
+    match name.trim().to_lowercase().as_str() {
+        "binance" => Some(ExchangeId::Binance),
+        "bybit" => Some(ExchangeId::Bybit),
+        "okx" | "okex" => Some(ExchangeId::Okx),
+        "bitget" => Some(ExchangeId::Bitget),
+        _ => None,
+    }
+}
+
+/// 우리 심볼에서 기초 자산 추출 (`BTCUSDT`, `BTC-USDT-SWAP` 모두 `BTC`)
+fn asset_of(symbol: &str) -> String {
+    let symbol = symbol.to_uppercase().replace("-SWAP", "").replace('-', "");
+    base_asset(&symbol).to_string()
+}
+
+/// 허용치를 넘은 펀딩비 차이
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingDiscrepancy {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    pub ours: f64,
+    pub aggregator: f64,
+    pub diff_bps: f64,
+}
+
+/// 대조 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct AggregatorReport {
+    pub checked_at: DateTime<Utc>,
+    pub tolerance_bps: f64,
+    /// 양쪽 모두 값이 있어 비교한 항목 수
+    pub compared: usize,
+    /// 집계 서비스에 없는 우리 항목 수
+    pub unmatched: usize,
+    pub discrepancies: Vec<FundingDiscrepancy>,
+    /// 조회/파싱 실패 사유
+    pub error: Option<String>,
+}
+
+/// 수집한 선물 펀딩비를 집계 서비스 값과 비교
+pub fn compare(
+    perp: &[PerpSnapshot],
+    rates: &AggregateRates,
+    tolerance_bps: f64,
+    checked_at: DateTime<Utc>,
+) -> AggregatorReport {
+    let mut compared = 0;
+    let mut unmatched = 0;
+    let mut discrepancies = Vec::new();
+    for snapshot in perp {
+        let Some(aggregator) = rates.get(&(snapshot.exchange, asset_of(&snapshot.symbol))) else {
+            unmatched += 1;
+            continue;
+        };
+        compared += 1;
+        let diff_bps = (snapshot.funding_rate - aggregator) * 10_000.0;
+        if diff_bps.abs() > tolerance_bps {
+            discrepancies.push(FundingDiscrepancy {
+                exchange: snapshot.exchange,
+                symbol: snapshot.symbol.clone(),
+                ours: snapshot.funding_rate,
+                aggregator: *aggregator,
+                diff_bps,
+            });
+        }
+    }
+    discrepancies.sort_by(|a, b| b.diff_bps.abs().total_cmp(&a.diff_bps.abs()));
+    AggregatorReport {
+        checked_at,
+        tolerance_bps,
+        compared,
+        unmatched,
+        discrepancies,
+        error: None,
+    }
+}
+
+async fn fetch_rates(
+    http: &reqwest::Client,
+    config: &AggregatorConfig,
+) -> Result<AggregateRates, String> {
+    let mut request = http.get(&config.url);
+    if let Some(key) = &config.api_key {
+        request = request.header(config.api_key_header.as_str(), key.as_str());
+    }
+    let body = request
+        .send()
+        .await
+        .and_then(|res| res.error_for_status())
+        .map_err(|e| e.to_string())?
+        .text()
+        .await
+        .map_err(|e| e.to_string())?;
+    parse_aggregate(&body).map_err(|e| e.to_string())
+}
+
+/// 수집 주기(베이시스 프레임)마다 외부 집계 서비스와 펀딩비를 대조하는 태스크 시작
+pub fn start_aggregator_check(config: AggregatorConfig, state: Arc<AppState>) {
+    info!(
+        "펀딩비 외부 집계 대조: {} (허용 {} bps, 최소 {}초 간격)",
+        config.url,
+        config.tolerance_bps,
+        config.min_interval.as_secs()
+    );
+    let http = exchanges::http::http_client_with_timeout(FETCH_TIMEOUT);
+    let mut rx = state.basis_tx.subscribe();
+    tokio::spawn(async move {
+        let mut last_check: Option<Instant> = None;
+        loop {
+            match rx.recv().await {
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            }
+            if last_check.is_some_and(|last| last.elapsed() < config.min_interval) {
+                continue;
+            }
+            last_check = Some(Instant::now());
+
+            let report = match fetch_rates(&http, &config).await {
+                Ok(rates) => {
+                    let perp = state.perp_snapshots.read().await;
+                    compare(&perp, &rates, config.tolerance_bps, Utc::now())
+                }
+                Err(e) => {
+                    warn!("펀딩비 외부 집계 조회 실패: {}", e);
+                    AggregatorReport {
+                        checked_at: Utc::now(),
+                        tolerance_bps: config.tolerance_bps,
+                        compared: 0,
+                        unmatched: 0,
+                        discrepancies: Vec::new(),
+                        error: Some(e),
+                    }
+                }
+            };
+            for d in &report.discrepancies {
+                warn!(
+                    "펀딩비 불일치: {:?} {} 수집 {:.6} / 집계 {:.6} ({:+.2} bps)",
+                    d.exchange, d.symbol, d.ours, d.aggregator, d.diff_bps
+                );
+            }
+            *state.aggregator_report.write().await = Some(report);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::{Currency, Price};
+
+    fn perp(exchange: ExchangeId, symbol: &str, funding_rate: f64) -> PerpSnapshot {
+        PerpSnapshot {
+            exchange,
+            symbol: symbol.to_string(),
+            currency: Currency::USDT,
+            mark_price: Price::new(100.0),
+            index_price: None,
+            oi_usd: 0.0,
+            vol_24h_usd: 0.0,
+            funding_rate,
+            next_funding_time: None,
+            funding_interval_hours: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_compare_with_aggregate() {
+        let body = r#"{"code":"0","data":[
+            {"symbol":"BTC","stablecoin_margin_list":[
+                {"exchange":"Binance","funding_rate":0.01},
+                {"exchange":"OKX","funding_rate":0.01},
+                {"exchange":"Hyperliquid","funding_rate":0.5},
+                {"exchange":"Bybit"}
+            ]}
+        ]}"#;
+        let rates = parse_aggregate(body).unwrap();
+        assert_eq!(rates.len(), 2);
+        assert!((rates[&(ExchangeId::Binance, "BTC".to_string())] - 0.0001).abs() < 1e-12);
+
+        let snapshots = [
+            perp(ExchangeId::Binance, "BTCUSDT", 0.00012),
+            // 파싱 버그로 % 값을 그대로 넣은 경우
+            perp(ExchangeId::Okx, "BTC-USDT-SWAP", 0.01),
+            perp(ExchangeId::Bybit, "BTCUSDT", 0.0001),
+        ];
+        let report = compare(&snapshots, &rates, 5.0, Utc::now());
+        assert_eq!(report.compared, 2);
+        assert_eq!(report.unmatched, 1);
+        assert_eq!(report.discrepancies.len(), 1);
+        assert_eq!(report.discrepancies[0].exchange, ExchangeId::Okx);
+        assert!((report.discrepancies[0].diff_bps - 99.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use interface::{Bps, ExchangeId, Price, UnifiedSnapshot};
+use timeseries::{asof_join, Point};
+
+/// 거래소 하나의 선물-현물 베이시스
+#[derive(Debug, Clone, Serialize)]
+pub struct VenueBasis {
+    pub exchange: ExchangeId,
+    pub perp_price: Price,
+    pub spot_price: Price,
+    /// (선물 - 현물) / 현물
+    pub basis_bps: Bps,
+    pub funding_rate: f64,
+}
+
+/// 심볼 하나의 거래소별 베이시스와 거래소 간 극값
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolBasis {
+    pub symbol: String,
+    pub venues: Vec<VenueBasis>,
+    pub max_exchange: ExchangeId,
+    pub max_basis_bps: Bps,
+    pub min_exchange: ExchangeId,
+    pub min_basis_bps: Bps,
+    /// 최대 - 최소 베이시스
+    pub spread_bps: Bps,
+}
+
+/// 수집 주기마다 WebSocket으로 내보내는 베이시스 프레임
+#[derive(Debug, Clone, Serialize)]
+pub struct BasisFrame {
+    pub generated_at: DateTime<Utc>,
+    pub symbols: Vec<SymbolBasis>,
+}
+
+/// 통합 스냅샷에서 베이시스 프레임 계산
+/// 같은 거래소에 선물과 현물이 모두 있고 표시 통화가 같은 항목만 사용합니다.
+pub fn compute_basis_frame(snapshots: &[UnifiedSnapshot]) -> BasisFrame {
+    let mut by_symbol: BTreeMap<&str, Vec<VenueBasis>> = BTreeMap::new();
+    for snapshot in snapshots {
+        let (Some(perp), Some(spot), Some(basis_bps)) =
+            (&snapshot.perp, &snapshot.spot, snapshot.spot_perp_basis())
+        else {
+            continue;
+        };
+        by_symbol
+            .entry(snapshot.symbol.as_str())
+            .or_default()
+            .push(VenueBasis {
+                exchange: snapshot.exchange,
+                perp_price: perp.mark_price,
+                spot_price: spot.price,
+                basis_bps,
+                funding_rate: perp.funding_rate,
+            });
+    }
+
+    let symbols = by_symbol
+        .into_iter()
+        .filter_map(|(symbol, mut venues)| {
+            venues.sort_by(|a, b| b.basis_bps.value().total_cmp(&a.basis_bps.value()));
+            let max = venues.first()?;
+            let min = venues.last()?;
+            Some(SymbolBasis {
+                symbol: symbol.to_string(),
+                max_exchange: max.exchange,
+                max_basis_bps: max.basis_bps,
+                min_exchange: min.exchange,
+                min_basis_bps: min.basis_bps,
+                spread_bps: max.basis_bps - min.basis_bps,
+                venues,
+            })
+        })
+        .collect();
+
+    BasisFrame {
+        generated_at: Utc::now(),
+        symbols,
+    }
+}
+
+/// 현물/선물 가격을 맞출 때 허용하는 최대 시각 차이 (수집 주기보다 넉넉하게)
+pub const BASIS_HISTORY_MAX_GAP: chrono::Duration = chrono::Duration::seconds(60);
+
+/// 저장된 현물 가격과 선물 마크 가격으로 계산한 베이시스(bps) 시계열
+/// 선물 기록 시각마다 그 이전 가장 가까운 현물 가격을 사용합니다.
+pub fn basis_series(spot: &[Point], perp: &[Point]) -> Vec<Point> {
+    asof_join(perp, spot, BASIS_HISTORY_MAX_GAP)
+        .into_iter()
+        .filter(|(_, perp, spot)| *spot > 0.0 && *perp > 0.0)
+        .map(|(time, perp, spot)| Point::new(time, (perp - spot) / spot * 10_000.0))
+        .collect()
+}
+
+/// "binance"(같은 거래소) 또는 "okx:binance"(현물:선물) 형식의 베뉴 쌍 파싱
+pub fn parse_venue_pair(s: &str) -> Option<(ExchangeId, ExchangeId)> {
+    let parse = |name: &str| {
+        [
+            ExchangeId::Binance,
+            ExchangeId::Bybit,
+            ExchangeId::Okx,
+            ExchangeId::Bitget,
+            ExchangeId::Bithumb,
+        ]
+        .into_iter()
+        .find(|ex| format!("{:?}", ex).eq_ignore_ascii_case(name.trim()))
+    };
+    match s.split_once(':') {
+        Some((spot, perp)) => Some((parse(spot)?, parse(perp)?)),
+        None => parse(s).map(|ex| (ex, ex)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::{Currency, ExchangeRates, PerpData, SpotData, UNIFIED_SNAPSHOT_SCHEMA_VERSION};
+
+    fn unified(exchange: ExchangeId, perp: f64, spot: Option<f64>) -> UnifiedSnapshot {
+        UnifiedSnapshot {
+            schema_version: UNIFIED_SNAPSHOT_SCHEMA_VERSION,
+            exchange,
+            symbol: "BTCUSDT".to_string(),
+            currency: Currency::USDT,
+            perp: Some(PerpData {
+                currency: Currency::USDT,
+                mark_price: Price::new(perp),
+                index_price: None,
+                oi_usd: 0.0,
+                vol_24h_usd: 0.0,
+                funding_rate: 0.0001,
+                next_funding_time: None,
+                predicted_funding_rate: None,
+                funding_interval_hours: None,
+            }),
+            spot: spot.map(|price| SpotData {
+                currency: Currency::USDT,
+                price: Price::new(price),
+                vol_24h_usd: 0.0,
+            }),
+            basis_bps: None,
+            cross_basis_bps: None,
+            exchange_rates: ExchangeRates {
+                usd_krw: 1300.0,
+                usdt_usd: 1.0,
+                usdt_krw: 1300.0,
+                updated_at: Utc::now(),
+            },
+            quality: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_compute_basis_frame() {
+        let frame = compute_basis_frame(&[
+            unified(ExchangeId::Binance, 100.1, Some(100.0)),
+            unified(ExchangeId::Bybit, 99.95, Some(100.0)),
+            unified(ExchangeId::Okx, 100.0, None),
+        ]);
+
+        assert_eq!(frame.symbols.len(), 1);
+        let btc = &frame.symbols[0];
+        assert_eq!(btc.venues.len(), 2);
+        assert_eq!(btc.max_exchange, ExchangeId::Binance);
+        assert_eq!(btc.min_exchange, ExchangeId::Bybit);
+        assert!((btc.max_basis_bps.value() - 10.0).abs() < 1e-6);
+        assert!((btc.spread_bps.value() - 15.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_basis_series_and_venue_pair() {
+        let t0 = Utc::now();
+        let spot = [
+            Point::new(t0, 100.0),
+            Point::new(t0 + chrono::Duration::seconds(10), 200.0),
+        ];
+        let perp = [
+            Point::new(t0 - chrono::Duration::seconds(1), 100.0),
+            Point::new(t0 + chrono::Duration::seconds(1), 100.1),
+            Point::new(t0 + chrono::Duration::seconds(11), 199.8),
+            Point::new(t0 + chrono::Duration::seconds(300), 201.0),
+        ];
+        let series = basis_series(&spot, &perp);
+        // 앞선 현물 가격이 없거나 60초보다 오래된 선물 기록은 제외
+        assert_eq!(series.len(), 2);
+        assert!((series[0].value - 10.0).abs() < 1e-6);
+        assert!((series[1].value + 10.0).abs() < 1e-6);
+
+        assert_eq!(
+            parse_venue_pair("binance"),
+            Some((ExchangeId::Binance, ExchangeId::Binance))
+        );
+        assert_eq!(
+            parse_venue_pair("OKX:bybit"),
+            Some((ExchangeId::Okx, ExchangeId::Bybit))
+        );
+        assert_eq!(parse_venue_pair("binance:kraken"), None);
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use interface::{ExchangeId, PerpSnapshot};
+
+/// 예정된 펀딩 정산 한 건
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingEvent {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    pub funding_time: DateTime<Utc>,
+    pub interval_hours: f64,
+    /// 현재 펀딩비 (0.01 == 1%). 이후 정산분은 같은 값으로 가정
+    pub funding_rate: f64,
+    /// next_funding_time 없이 거래소 기본 주기로 추정한 시각이면 true
+    pub estimated: bool,
+}
+
+/// `now`부터 `horizon` 동안의 펀딩 정산 일정 (시각 오름차순)
+///
+/// 주기는 스냅샷의 심볼별 주기, 없으면 거래소 기본 주기를 쓴다.
+/// 첫 정산 시각은 스냅샷의 next_funding_time을 우선 사용하고,
+/// 없으면 주기를 UTC 00:00에 맞춰 계산한다. 이후는 주기만큼 더한다.
+pub fn build_calendar(
+    snapshots: &[PerpSnapshot],
+    now: DateTime<Utc>,
+    horizon: Duration,
+) -> Vec<FundingEvent> {
+    let end = now + horizon;
+    let mut events = Vec::new();
+
+    for snapshot in snapshots {
+        let Some(interval_hours) = snapshot.effective_funding_interval_hours() else {
+            continue;
+        };
+        let interval = Duration::minutes((interval_hours * 60.0).round() as i64);
+        if interval <= Duration::zero() {
+            continue;
+        }
+
+        // 오래된 next_funding_time은 주기만큼 밀어서 현재 이후로 맞춤
+        let Some((mut time, estimated)) = snapshot.resolved_next_funding_time(now) else {
+            continue;
+        };
+
+        while time <= end {
+            events.push(FundingEvent {
+                exchange: snapshot.exchange,
+                symbol: snapshot.symbol.clone(),
+                funding_time: time,
+                interval_hours,
+                funding_rate: snapshot.funding_rate,
+                estimated,
+            });
+            time += interval;
+        }
+    }
+
+    events.sort_by(|a, b| {
+        a.funding_time
+            .cmp(&b.funding_time)
+            .then_with(|| a.symbol.cmp(&b.symbol))
+    });
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use interface::{Currency, Price};
+
+    fn perp(exchange: ExchangeId, next: Option<DateTime<Utc>>) -> PerpSnapshot {
+        PerpSnapshot {
+            exchange,
+            symbol: "BTCUSDT".to_string(),
+            currency: Currency::USDT,
+            mark_price: Price::new(1.0),
+            index_price: None,
+            oi_usd: 1.0,
+            vol_24h_usd: 0.0,
+            funding_rate: 0.0001,
+            next_funding_time: next,
+            funding_interval_hours: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_build_calendar() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 1, 30, 0).unwrap();
+        let snapshots = [
+            perp(
+                ExchangeId::Binance,
+                Some(Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap()),
+            ),
+            perp(ExchangeId::Bitget, None),
+            perp(ExchangeId::Bithumb, None),
+            PerpSnapshot {
+                symbol: "HOURUSDT".to_string(),
+                funding_interval_hours: Some(1.0),
+                ..perp(ExchangeId::Bybit, None)
+            },
+        ];
+
+        let events = build_calendar(&snapshots, now, Duration::hours(24));
+        let binance: Vec<_> = events
+            .iter()
+            .filter(|e| e.exchange == ExchangeId::Binance)
+            .collect();
+        let bitget: Vec<_> = events
+            .iter()
+            .filter(|e| e.exchange == ExchangeId::Bitget)
+            .collect();
+
+        // 08, 16, 24시
+        assert_eq!(binance.len(), 3);
+        assert!(binance.iter().all(|e| !e.estimated));
+        // 04, 08, ..., 24시
+        assert_eq!(bitget.len(), 6);
+        assert_eq!(
+            bitget[0].funding_time,
+            Utc.with_ymd_and_hms(2024, 5, 1, 4, 0, 0).unwrap()
+        );
+        assert!(bitget.iter().all(|e| e.estimated));
+        // 심볼별 1시간 주기: 02, 03, ..., 25시
+        let hourly = events.iter().filter(|e| e.symbol == "HOURUSDT").count();
+        assert_eq!(hourly, 24);
+        assert!(events
+            .windows(2)
+            .all(|w| w[0].funding_time <= w[1].funding_time));
+    }
+}
@@ -1,18 +1,44 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use tokio::time::sleep;
+use chrono::Utc;
+use tokio::{task::spawn_blocking, time::sleep};
 use tracing::{info, warn};
 
+use crate::basis::compute_basis_frame;
+use crate::filter::SymbolFilter;
+use crate::merge::SnapshotMerge;
+use crate::quality::{age_secs, exchange_source, ParseAnomalyTracker, QualityInput, QualityPolicy};
+use crate::registry::ExchangeRegistry;
 use crate::server::AppState;
-use exchanges::{exchange_rate::fetch_all_exchange_rates, PerpExchange, SpotExchange};
-use interface::{ExchangeId, PerpData, PerpSnapshot, SpotData, SpotSnapshot, UnifiedSnapshot};
+use crate::store::RETENTION_SWEEP_INTERVAL;
+use exchanges::{
+    bithumb::stream::{apply_live_prices, BithumbSpotStream, LIVE_PRICE_MAX_AGE},
+    exchange_rate::fetch_all_exchange_rates,
+    status::{status_registry, ExchangeStatus},
+};
+use interface::{
+    parse::parse_failure_stats, DataSource, ExchangeId, PerpData, PerpSnapshot, SpotData,
+    SpotSnapshot, UnifiedSnapshot, UNIFIED_SNAPSHOT_SCHEMA_VERSION,
+};
 
+/// 수집 루프 시작 (거래소마다 자기 간격이 된 주기에만 조회, 건너뛴 거래소는 이전 값 유지)
 pub fn start_collect_loop(
-    perp_exchanges: Vec<Arc<dyn PerpExchange>>,
-    spot_exchanges: Vec<Arc<dyn SpotExchange>>,
+    registry: ExchangeRegistry,
     state: Arc<AppState>,
-    interval: Duration,
+    filter: SymbolFilter,
+    merge: SnapshotMerge,
+    quality: QualityPolicy,
 ) {
+    let ExchangeRegistry {
+        perp: mut perp_exchanges,
+        spot: mut spot_exchanges,
+        tick: interval,
+        ..
+    } = registry;
     tokio::spawn(async move {
         info!(
             "데이터 수집 루프 시작: {}개 선물 거래소, {}개 현물 거래소, {}초 간격",
@@ -20,82 +46,177 @@ pub fn start_collect_loop(
             spot_exchanges.len(),
             interval.as_secs()
         );
+        let slowest = perp_exchanges
+            .iter()
+            .map(|ex| ex.every)
+            .chain(spot_exchanges.iter().map(|ex| ex.every))
+            .max()
+            .unwrap_or(interval);
+        if merge
+            .max_age
+            .to_std()
+            .is_ok_and(|max_age| max_age <= slowest)
+        {
+            warn!(
+                "스냅샷 유지 시간({}초)이 가장 긴 수집 간격({}초) 이하라 수집 사이에 스냅샷이 만료될 수 있음",
+                merge.max_age.num_seconds(),
+                slowest.as_secs()
+            );
+        }
+        if filter.is_active() {
+            info!(
+                "심볼 필터: include {}개, exclude {}개, 최소 24h 거래량 ${:.0}",
+                filter.include.len(),
+                filter.exclude.len(),
+                filter.min_vol_24h_usd
+            );
+        }
+        info!(
+            "갱신되지 않은 스냅샷 유지 시간: {}초",
+            merge.max_age.num_seconds()
+        );
+        let mut last_retention_sweep: Option<Instant> = None;
+        let mut parse_anomalies = ParseAnomalyTracker::default();
         loop {
-            // 선물 데이터 수집
+            // 선물 데이터 수집 (수집에 실패한 거래소/심볼은 이전 값을 유지)
             let mut all_perp: Vec<PerpSnapshot> = Vec::new();
-            for ex in &perp_exchanges {
-                match ex.fetch_all().await {
+            for ex in perp_exchanges.iter_mut() {
+                if !ex.due(Instant::now()) {
+                    continue;
+                }
+                match ex.client.fetch_all().await {
                     Ok(mut v) => all_perp.append(&mut v),
                     Err(e) => {
-                        warn!("perp fetch error from {:?}: {:?}", ex.id(), e);
+                        warn!("perp fetch error from {:?}: {:?}", ex.client.id(), e);
                     }
                 }
             }
 
-            // 정렬: OI 기준 내림차순
-            all_perp.sort_by(|a, b| {
-                b.oi_usd
-                    .partial_cmp(&a.oi_usd)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+            filter.retain_perp(&mut all_perp);
 
-            let perp_count = all_perp.len();
-            let perp_clone = all_perp.clone();
-            {
-                let mut guard = state.perp_snapshots.write().await;
-                *guard = all_perp;
+            // 기록에는 이번 주기에 실제로 받은 값만 남김
+            let collected_at = Utc::now();
+            state
+                .oi_history
+                .write()
+                .await
+                .record(&all_perp, collected_at);
+            state
+                .funding_history
+                .write()
+                .await
+                .record(&all_perp, collected_at);
+            state
+                .funding_predictor
+                .write()
+                .await
+                .record(&all_perp, collected_at);
+            if let Some(store) = state.market_store.clone() {
+                let perp = all_perp.clone();
+                spawn_blocking(move || store.record_perp(&perp, collected_at));
             }
+            let (perp_clone, perp_stats) = {
+                let mut guard = state.perp_snapshots.write().await;
+                let stats = merge.merge(&mut guard, all_perp, collected_at);
+                // 정렬: OI 기준 내림차순
+                guard.sort_by(|a, b| {
+                    b.oi_usd
+                        .partial_cmp(&a.oi_usd)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                (guard.clone(), stats)
+            };
+            let perp_count = perp_clone.len();
 
             // 현물 데이터 수집
             let mut all_spot: Vec<SpotSnapshot> = Vec::new();
-            for ex in &spot_exchanges {
-                match ex.fetch_all().await {
+            for ex in spot_exchanges.iter_mut() {
+                if !ex.due(Instant::now()) {
+                    continue;
+                }
+                match ex.client.fetch_all().await {
                     Ok(mut v) => all_spot.append(&mut v),
                     Err(e) => {
-                        warn!("spot fetch error from {:?}: {:?}", ex.id(), e);
+                        warn!("spot fetch error from {:?}: {:?}", ex.client.id(), e);
                     }
                 }
             }
 
-            // 정렬: 거래량 기준 내림차순
-            all_spot.sort_by(|a, b| {
-                b.vol_24h_usd
-                    .partial_cmp(&a.vol_24h_usd)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+            filter.retain_spot(&mut all_spot);
 
-            let spot_count = all_spot.len();
-            let spot_clone = all_spot.clone();
-            {
-                let mut guard = state.spot_snapshots.write().await;
-                *guard = all_spot;
+            if let Some(store) = state.market_store.clone() {
+                let spot = all_spot.clone();
+                let sweep = last_retention_sweep
+                    .is_none_or(|last| last.elapsed() >= RETENTION_SWEEP_INTERVAL);
+                if sweep {
+                    last_retention_sweep = Some(Instant::now());
+                }
+                spawn_blocking(move || {
+                    let now = Utc::now();
+                    store.record_spot(&spot, now);
+                    if sweep {
+                        store.enforce_retention(now);
+                    }
+                });
             }
 
+            let (spot_clone, spot_stats) = {
+                let mut guard = state.spot_snapshots.write().await;
+                let stats = merge.merge(&mut guard, all_spot, Utc::now());
+                // 정렬: 거래량 기준 내림차순
+                guard.sort_by(|a, b| {
+                    b.vol_24h_usd
+                        .partial_cmp(&a.vol_24h_usd)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                (guard.clone(), stats)
+            };
+            let spot_count = spot_clone.len();
+
             // 환율 정보 가져오기
             let exchange_rates = fetch_all_exchange_rates().await;
 
+            // 이번 주기에 파싱 실패가 늘어난 거래소
+            let anomalous = parse_anomalies.poll(&parse_failure_stats());
+
             // 통합 스냅샷 생성
             let mut unified_map: HashMap<(ExchangeId, String), UnifiedSnapshot> = HashMap::new();
+            let mut quality_inputs: HashMap<(ExchangeId, String), QualityInput> = HashMap::new();
+            let mut sources: HashMap<ExchangeId, (DataSource, bool)> = HashMap::new();
 
             // 선물 데이터 추가
+            let predictor = state.funding_predictor.read().await;
             for perp in perp_clone {
                 let key = (perp.exchange, perp.symbol.clone());
+                let (source, rest_fallback) = *sources.entry(perp.exchange).or_insert_with(|| {
+                    exchange_source(&status_registry().status_of(perp.exchange))
+                });
+                let input = quality_inputs.entry(key.clone()).or_default();
+                input.perp = Some((perp.updated_at, source));
+                input.rest_fallback |= rest_fallback;
                 let unified = unified_map.entry(key).or_insert_with(|| UnifiedSnapshot {
+                    schema_version: UNIFIED_SNAPSHOT_SCHEMA_VERSION,
                     exchange: perp.exchange,
                     symbol: perp.symbol.clone(),
                     currency: perp.currency,
                     perp: None,
                     spot: None,
+                    basis_bps: None,
+                    cross_basis_bps: None,
                     exchange_rates: exchange_rates.clone(),
+                    quality: None,
                     updated_at: perp.updated_at,
                 });
                 unified.perp = Some(PerpData {
                     currency: perp.currency,
                     mark_price: perp.mark_price,
+                    index_price: perp.index_price,
                     oi_usd: perp.oi_usd,
                     vol_24h_usd: perp.vol_24h_usd,
                     funding_rate: perp.funding_rate,
                     next_funding_time: perp.next_funding_time,
+                    predicted_funding_rate: predictor.predict(perp.exchange, &perp.symbol),
+                    funding_interval_hours: perp.funding_interval_hours,
                 });
                 // currency와 updated_at은 더 최신 것으로 업데이트
                 unified.currency = perp.currency;
@@ -105,16 +226,24 @@ pub fn start_collect_loop(
                 }
             }
 
+            drop(predictor);
+
             // 현물 데이터 추가
             for spot in spot_clone {
                 let key = (spot.exchange, spot.symbol.clone());
+                quality_inputs.entry(key.clone()).or_default().spot =
+                    Some((spot.updated_at, DataSource::Rest));
                 let unified = unified_map.entry(key).or_insert_with(|| UnifiedSnapshot {
+                    schema_version: UNIFIED_SNAPSHOT_SCHEMA_VERSION,
                     exchange: spot.exchange,
                     symbol: spot.symbol.clone(),
                     currency: spot.currency,
                     perp: None,
                     spot: None,
+                    basis_bps: None,
+                    cross_basis_bps: None,
                     exchange_rates: exchange_rates.clone(),
+                    quality: None,
                     updated_at: spot.updated_at,
                 });
                 unified.spot = Some(SpotData {
@@ -132,19 +261,103 @@ pub fn start_collect_loop(
                 }
             }
 
-            let unified_snapshots: Vec<UnifiedSnapshot> = unified_map.into_values().collect();
+            // 베이시스와 품질은 여기서 한 번만 계산해 모든 소비자가 같은 값을 쓰게 한다
+            let now = Utc::now();
+            let unified_snapshots: Vec<UnifiedSnapshot> = unified_map
+                .into_iter()
+                .map(|(key, mut unified)| {
+                    unified.fill_basis();
+                    let mut input = quality_inputs.remove(&key).unwrap_or_default();
+                    input.exchange_rates_at = unified.exchange_rates.updated_at;
+                    input.parse_anomaly = anomalous.contains(&format!("{:?}", unified.exchange));
+                    unified.quality = Some(quality.assess(&input, now));
+                    unified
+                })
+                .collect();
             let unified_count = unified_snapshots.len();
+            // 구독자가 없으면 send가 실패하지만 무시
+            let _ = state
+                .basis_tx
+                .send(Arc::new(compute_basis_frame(&unified_snapshots)));
             {
                 let mut guard = state.unified_snapshots.write().await;
                 *guard = unified_snapshots;
             }
 
+            // 연결 상태 갱신 (같은 거래소는 선물/현물 클라이언트가 같은 상태를 공유)
+            let mut statuses: Vec<ExchangeStatus> = Vec::new();
+            let perp_status = perp_exchanges.iter().map(|ex| ex.client.status());
+            let spot_status = spot_exchanges.iter().map(|ex| ex.client.status());
+            for status in perp_status.chain(spot_status) {
+                if !statuses.iter().any(|s| s.exchange == status.exchange) {
+                    statuses.push(status);
+                }
+            }
+            {
+                let mut guard = state.exchange_status.write().await;
+                *guard = statuses;
+            }
+
             info!(
                 "데이터 수집 완료: {}개 선물 스냅샷, {}개 현물 스냅샷, {}개 통합 스냅샷",
                 perp_count, spot_count, unified_count
             );
+            if perp_stats.kept + perp_stats.expired + spot_stats.kept + spot_stats.expired > 0 {
+                warn!(
+                    "갱신되지 않은 스냅샷: 선물 {}개 유지/{}개 만료, 현물 {}개 유지/{}개 만료",
+                    perp_stats.kept, perp_stats.expired, spot_stats.kept, spot_stats.expired
+                );
+            }
 
             sleep(interval).await;
         }
     });
 }
+
+/// 수집 주기 사이에 빗썸 WebSocket 체결가를 현물/통합 스냅샷에 반영
+/// (김프 계산에 쓰는 원화 가격이 REST 수집 주기만큼 늦어지지 않도록)
+/// 반영한 스냅샷은 현물 출처를 WebSocket으로 바꾸고 품질 점수를 다시 계산한다
+pub fn start_live_spot_loop(
+    stream: BithumbSpotStream,
+    state: Arc<AppState>,
+    interval: Duration,
+    quality: QualityPolicy,
+) {
+    tokio::spawn(async move {
+        info!(
+            "빗썸 실시간 현물 가격 반영 시작: {}ms 간격",
+            interval.as_millis()
+        );
+        loop {
+            sleep(interval).await;
+
+            let live = stream.fresh_prices(LIVE_PRICE_MAX_AGE).await;
+            if live.is_empty() {
+                continue;
+            }
+
+            apply_live_prices(&mut state.spot_snapshots.write().await, &live);
+
+            let mut unified = state.unified_snapshots.write().await;
+            for snapshot in unified
+                .iter_mut()
+                .filter(|s| s.exchange == ExchangeId::Bithumb)
+            {
+                let (Some(spot), Some(price)) =
+                    (snapshot.spot.as_mut(), live.get(&snapshot.symbol))
+                else {
+                    continue;
+                };
+                spot.price = price.price;
+                if price.updated_at > snapshot.updated_at {
+                    snapshot.updated_at = price.updated_at;
+                }
+                if let Some(q) = snapshot.quality.as_mut() {
+                    q.spot_age_secs = Some(age_secs(price.updated_at, Utc::now()));
+                    q.spot_source = Some(DataSource::WebSocket);
+                    quality.rescore(q);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use interface::{PerpSnapshot, SpotSnapshot};
+
+/// 심볼에서 떼어 내는 견적 통화 접미사 (긴 것부터 검사)
+const QUOTE_SUFFIXES: [&str; 4] = ["USDT", "USDC", "KRW", "USD"];
+
+/// 수집기에서 적용하는 심볼 필터
+///
+/// include/exclude 항목은 `BTCUSDT` 같은 전체 심볼이나 `BTC` 같은 기초 자산 모두 허용.
+/// include가 비어 있으면 exclude에 없는 모든 심볼을 통과시킨다.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolFilter {
+    pub include: HashSet<String>,
+    pub exclude: HashSet<String>,
+    /// 24시간 거래량(USD)이 이 값 미만인 스냅샷은 버림 (0이면 비활성)
+    pub min_vol_24h_usd: f64,
+}
+
+impl SymbolFilter {
+    /// 환경 변수에서 로드
+    /// - `ORACLE_SYMBOL_INCLUDE`: 쉼표 구분 (예: `BTC,ETH,SOLUSDT`)
+    /// - `ORACLE_SYMBOL_EXCLUDE`: 쉼표 구분
+    /// - `ORACLE_MIN_VOL_24H_USD`: 최소 24시간 거래량
+    pub fn from_env() -> Self {
+        let list = |key: &str| {
+            std::env::var(key)
+                .map(|v| parse_symbol_list(&v))
+                .unwrap_or_default()
+        };
+        Self {
+            include: list("ORACLE_SYMBOL_INCLUDE"),
+            exclude: list("ORACLE_SYMBOL_EXCLUDE"),
+            min_vol_24h_usd: std::env::var("ORACLE_MIN_VOL_24H_USD")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(0.0),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.include.is_empty() || !self.exclude.is_empty() || self.min_vol_24h_usd > 0.0
+    }
+
+    pub fn allows_symbol(&self, symbol: &str) -> bool {
+        let symbol = symbol.to_uppercase();
+        let base = base_asset(&symbol);
+        let listed = |set: &HashSet<String>| set.contains(&symbol) || set.contains(base);
+
+        if listed(&self.exclude) {
+            return false;
+        }
+        self.include.is_empty() || listed(&self.include)
+    }
+
+    fn allows(&self, symbol: &str, vol_24h_usd: f64) -> bool {
+        vol_24h_usd >= self.min_vol_24h_usd && self.allows_symbol(symbol)
+    }
+
+    pub fn retain_perp(&self, snapshots: &mut Vec<PerpSnapshot>) {
+        if self.is_active() {
+            snapshots.retain(|s| self.allows(&s.symbol, s.vol_24h_usd));
+        }
+    }
+
+    pub fn retain_spot(&self, snapshots: &mut Vec<SpotSnapshot>) {
+        if self.is_active() {
+            snapshots.retain(|s| self.allows(&s.symbol, s.vol_24h_usd));
+        }
+    }
+}
+
+fn parse_symbol_list(value: &str) -> HashSet<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_uppercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+pub(crate) fn base_asset(symbol: &str) -> &str {
+    QUOTE_SUFFIXES
+        .iter()
+        .find_map(|quote| symbol.strip_suffix(quote).filter(|base| !base.is_empty()))
+        .unwrap_or(symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_filter() {
+        let filter = SymbolFilter {
+            include: parse_symbol_list("btc, ETHUSDT"),
+            exclude: parse_symbol_list("ETHUSDT"),
+            min_vol_24h_usd: 1_000_000.0,
+        };
+        assert!(filter.allows_symbol("BTCUSDT"));
+        assert!(filter.allows_symbol("BTCKRW"));
+        assert!(!filter.allows_symbol("ETHUSDT"));
+        assert!(!filter.allows_symbol("SOLUSDT"));
+        assert!(!filter.allows("BTCUSDT", 999_999.0));
+        assert!(filter.allows("BTCUSDT", 1_000_000.0));
+
+        let open = SymbolFilter::default();
+        assert!(!open.is_active());
+        assert!(open.allows("SOLUSDT", 0.0));
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::{HashMap, VecDeque};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use interface::{ExchangeId, PerpSnapshot};
+
+/// 수집 시점의 OI 한 건
+#[derive(Debug, Clone, Copy)]
+struct OiPoint {
+    time: DateTime<Utc>,
+    oi_usd: f64,
+}
+
+/// 거래소/심볼별 OI 시계열 (수집 주기마다 기록, 보관 기간이 지난 값은 버림)
+#[derive(Debug)]
+pub struct OiHistory {
+    retention: Duration,
+    series: HashMap<(ExchangeId, String), VecDeque<OiPoint>>,
+}
+
+/// 거래소 하나의 OI 변화
+#[derive(Debug, Clone, Serialize)]
+pub struct OiChange {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    /// 기준 시점 (window 이전 가장 가까운 기록)
+    pub since: DateTime<Utc>,
+    pub oi_usd_before: f64,
+    pub oi_usd_now: f64,
+    pub change_usd: f64,
+    /// 0.01 == 1%
+    pub change_pct: f64,
+}
+
+/// 심볼 하나의 거래소 합산 OI 변화
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolOiChange {
+    pub symbol: String,
+    pub exchanges: Vec<ExchangeId>,
+    pub oi_usd_before: f64,
+    pub oi_usd_now: f64,
+    pub change_usd: f64,
+    /// 0.01 == 1%
+    pub change_pct: f64,
+}
+
+impl OiHistory {
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            retention,
+            series: HashMap::new(),
+        }
+    }
+
+    /// 수집한 선물 스냅샷의 OI 기록 (OI가 0 이하인 항목은 무시)
+    pub fn record(&mut self, snapshots: &[PerpSnapshot], now: DateTime<Utc>) {
+        for snapshot in snapshots {
+            if !snapshot.oi_usd.is_finite() || snapshot.oi_usd <= 0.0 {
+                continue;
+            }
+            self.series
+                .entry((snapshot.exchange, snapshot.symbol.clone()))
+                .or_default()
+                .push_back(OiPoint {
+                    time: now,
+                    oi_usd: snapshot.oi_usd,
+                });
+        }
+
+        let cutoff = now - self.retention;
+        self.series.retain(|_, points| {
+            while points.front().is_some_and(|p| p.time < cutoff) {
+                points.pop_front();
+            }
+            !points.is_empty()
+        });
+    }
+
+    /// 최근 `window` 동안의 거래소별 OI 변화
+    /// window 이전 기록이 없으면 가장 오래된 기록을 기준으로 쓰고, 기록이 하나뿐이면 제외합니다.
+    pub fn changes(&self, window: Duration, now: DateTime<Utc>) -> Vec<OiChange> {
+        let start = now - window;
+        self.series
+            .iter()
+            .filter_map(|((exchange, symbol), points)| {
+                let latest = points.back()?;
+                let idx = points.partition_point(|p| p.time <= start);
+                let before = points.get(idx.saturating_sub(1))?;
+                if before.time >= latest.time {
+                    return None;
+                }
+                let change_usd = latest.oi_usd - before.oi_usd;
+                Some(OiChange {
+                    exchange: *exchange,
+                    symbol: symbol.clone(),
+                    since: before.time,
+                    oi_usd_before: before.oi_usd,
+                    oi_usd_now: latest.oi_usd,
+                    change_usd,
+                    change_pct: change_usd / before.oi_usd,
+                })
+            })
+            .collect()
+    }
+}
+
+/// 펀딩비 변화 지점 (다음 변화 지점 전까지 같은 값이 유지된 것으로 봅니다)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct FundingPoint {
+    pub time: DateTime<Utc>,
+    /// 0.01 == 1%
+    pub funding_rate: f64,
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
+
+/// 일정 간격으로 다시 샘플링한 펀딩비 (기록이 끊긴 구간은 None)
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct FundingSample {
+    pub time: DateTime<Utc>,
+    pub funding_rate: Option<f64>,
+}
+
+/// 저장 현황 (관측 횟수 대비 실제 저장한 지점 수)
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct FundingHistoryStats {
+    pub series: usize,
+    pub observations: u64,
+    pub stored_points: usize,
+}
+
+#[derive(Debug, Default)]
+struct FundingSeries {
+    points: VecDeque<FundingPoint>,
+    /// 마지막으로 관측한 시각 (값이 같아 저장하지 않은 관측 포함)
+    last_seen: Option<DateTime<Utc>>,
+}
+
+/// 거래소/심볼별 펀딩비 기록
+/// 펀딩비는 수집 주기(10초)에 비해 드물게 바뀌므로 값이 바뀌었거나 `max_interval`이 지났을 때만
+/// 저장하고, 조회 시에는 직전 지점 값을 이어 붙여(sample-and-hold) 복원합니다.
+#[derive(Debug)]
+pub struct FundingHistory {
+    retention: Duration,
+    /// 값이 같아도 이 간격마다 한 번은 저장 (기록 공백과 값 유지 구간을 구분하는 기준)
+    max_interval: Duration,
+    series: HashMap<(ExchangeId, String), FundingSeries>,
+    observations: u64,
+}
+
+/// 이보다 작은 펀딩비 차이는 같은 값으로 취급
+const FUNDING_RATE_EPSILON: f64 = 1e-10;
+
+impl FundingHistory {
+    pub fn new(retention: Duration, max_interval: Duration) -> Self {
+        Self {
+            retention,
+            max_interval,
+            series: HashMap::new(),
+            observations: 0,
+        }
+    }
+
+    /// 수집한 선물 스냅샷의 펀딩비 기록 (직전 지점과 같고 max_interval 이내면 저장하지 않음)
+    pub fn record(&mut self, snapshots: &[PerpSnapshot], now: DateTime<Utc>) {
+        for snapshot in snapshots {
+            if !snapshot.funding_rate.is_finite() {
+                continue;
+            }
+            self.observations += 1;
+            let series = self
+                .series
+                .entry((snapshot.exchange, snapshot.symbol.clone()))
+                .or_default();
+            series.last_seen = Some(now);
+
+            let changed = series.points.back().is_none_or(|last| {
+                (last.funding_rate - snapshot.funding_rate).abs() > FUNDING_RATE_EPSILON
+                    || last.next_funding_time != snapshot.next_funding_time
+                    || now - last.time >= self.max_interval
+            });
+            if changed {
+                series.points.push_back(FundingPoint {
+                    time: now,
+                    funding_rate: snapshot.funding_rate,
+                    next_funding_time: snapshot.next_funding_time,
+                });
+            }
+        }
+
+        let cutoff = now - self.retention;
+        self.series.retain(|_, series| {
+            while series.points.front().is_some_and(|p| p.time < cutoff) {
+                series.points.pop_front();
+            }
+            !series.points.is_empty()
+        });
+    }
+
+    /// `time` 시점의 펀딩비. 직전 지점이 max_interval보다 오래됐으면(기록 공백) None
+    pub fn value_at(
+        &self,
+        exchange: ExchangeId,
+        symbol: &str,
+        time: DateTime<Utc>,
+    ) -> Option<FundingPoint> {
+        let series = self.series.get(&(exchange, symbol.to_string()))?;
+        series.value_at(time, self.max_interval)
+    }
+
+    /// `from`~`to` 구간의 변화 지점
+    /// 구간 시작 시점의 값을 알 수 있도록 `from` 이전 마지막 지점도 포함합니다.
+    pub fn points(
+        &self,
+        exchange: ExchangeId,
+        symbol: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Vec<FundingPoint> {
+        let Some(series) = self.series.get(&(exchange, symbol.to_string())) else {
+            return Vec::new();
+        };
+        let start = series.points.partition_point(|p| p.time <= from);
+        let end = series.points.partition_point(|p| p.time <= to);
+        series
+            .points
+            .range(start.saturating_sub(1)..end.max(start))
+            .copied()
+            .collect()
+    }
+
+    /// `from`부터 `step` 간격으로 다시 샘플링한 펀딩비
+    pub fn resample(
+        &self,
+        exchange: ExchangeId,
+        symbol: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        step: Duration,
+    ) -> Vec<FundingSample> {
+        let series = self.series.get(&(exchange, symbol.to_string()));
+        let mut samples = Vec::new();
+        let mut time = from;
+        while time <= to && step > Duration::zero() {
+            samples.push(FundingSample {
+                time,
+                funding_rate: series
+                    .and_then(|s| s.value_at(time, self.max_interval))
+                    .map(|p| p.funding_rate),
+            });
+            time += step;
+        }
+        samples
+    }
+
+    pub fn stats(&self) -> FundingHistoryStats {
+        FundingHistoryStats {
+            series: self.series.len(),
+            observations: self.observations,
+            stored_points: self.series.values().map(|s| s.points.len()).sum(),
+        }
+    }
+}
+
+impl FundingSeries {
+    fn value_at(&self, time: DateTime<Utc>, max_interval: Duration) -> Option<FundingPoint> {
+        let idx = self.points.partition_point(|p| p.time <= time);
+        let point = *self.points.get(idx.checked_sub(1)?)?;
+        // 마지막 지점 이후는 마지막 관측 시각까지만 값을 안다
+        let known_until = match self.points.get(idx) {
+            Some(next) => next.time,
+            None => self.last_seen.unwrap_or(point.time),
+        };
+        // max_interval마다 한 번은 저장하므로 그보다 긴 간격은 수집이 끊긴 구간
+        let gap_limit = point.time + max_interval + max_interval;
+        (time <= known_until && time <= gap_limit).then_some(point)
+    }
+}
+
+/// 거래소별 변화를 심볼 기준으로 합산
+pub fn aggregate_by_symbol(changes: &[OiChange]) -> Vec<SymbolOiChange> {
+    let mut map: HashMap<&str, SymbolOiChange> = HashMap::new();
+    for change in changes {
+        let entry = map
+            .entry(change.symbol.as_str())
+            .or_insert_with(|| SymbolOiChange {
+                symbol: change.symbol.clone(),
+                exchanges: Vec::new(),
+                oi_usd_before: 0.0,
+                oi_usd_now: 0.0,
+                change_usd: 0.0,
+                change_pct: 0.0,
+            });
+        entry.exchanges.push(change.exchange);
+        entry.oi_usd_before += change.oi_usd_before;
+        entry.oi_usd_now += change.oi_usd_now;
+    }
+
+    map.into_values()
+        .map(|mut s| {
+            s.change_usd = s.oi_usd_now - s.oi_usd_before;
+            s.change_pct = s.change_usd / s.oi_usd_before;
+            s
+        })
+        .collect()
+}
+
+/// "30m", "1h", "4h", "1d" 형식의 기간 파싱 (단위 없으면 초)
+pub fn parse_window(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    let (num, unit) = match s.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((i, _)) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let value: i64 = num.parse().ok()?;
+    if value <= 0 {
+        return None;
+    }
+    match unit {
+        "s" => Some(Duration::seconds(value)),
+        "m" => Some(Duration::minutes(value)),
+        "h" => Some(Duration::hours(value)),
+        "d" => Some(Duration::days(value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::{Currency, Price};
+
+    fn perp(exchange: ExchangeId, symbol: &str, oi_usd: f64) -> PerpSnapshot {
+        PerpSnapshot {
+            exchange,
+            symbol: symbol.to_string(),
+            currency: Currency::USDT,
+            mark_price: Price::new(1.0),
+            index_price: None,
+            oi_usd,
+            vol_24h_usd: 0.0,
+            funding_rate: 0.0,
+            next_funding_time: None,
+            funding_interval_hours: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_changes_over_window() {
+        let t0 = Utc::now();
+        let mut history = OiHistory::new(Duration::hours(2));
+        history.record(
+            &[
+                perp(ExchangeId::Binance, "BTCUSDT", 100.0),
+                perp(ExchangeId::Bybit, "BTCUSDT", 50.0),
+            ],
+            t0,
+        );
+        history.record(
+            &[
+                perp(ExchangeId::Binance, "BTCUSDT", 110.0),
+                perp(ExchangeId::Bybit, "BTCUSDT", 40.0),
+            ],
+            t0 + Duration::minutes(30),
+        );
+        history.record(
+            &[
+                perp(ExchangeId::Binance, "BTCUSDT", 120.0),
+                perp(ExchangeId::Bybit, "BTCUSDT", 45.0),
+            ],
+            t0 + Duration::minutes(60),
+        );
+
+        let now = t0 + Duration::minutes(60);
+        let mut changes = history.changes(Duration::minutes(30), now);
+        changes.sort_by_key(|c| format!("{:?}", c.exchange));
+        assert_eq!(changes.len(), 2);
+        assert!((changes[0].change_usd - 10.0).abs() < 1e-9);
+        assert!((changes[1].change_pct - 0.125).abs() < 1e-9);
+
+        let by_symbol = aggregate_by_symbol(&history.changes(Duration::hours(1), now));
+        assert_eq!(by_symbol.len(), 1);
+        assert!((by_symbol[0].change_usd - 15.0).abs() < 1e-9);
+
+        // 보관 기간이 지난 기록은 삭제
+        history.record(&[], t0 + Duration::hours(4));
+        assert!(history.changes(Duration::hours(1), now).is_empty());
+    }
+
+    #[test]
+    fn test_funding_history_compresses_and_holds() {
+        let with_rate = |rate: f64| PerpSnapshot {
+            funding_rate: rate,
+            ..perp(ExchangeId::Binance, "BTCUSDT", 1.0)
+        };
+        let t0 = Utc::now();
+        let mut history = FundingHistory::new(Duration::days(90), Duration::hours(1));
+
+        // 10초 주기로 2시간 관측, 30분 시점에만 값이 바뀜
+        for i in 0..=720 {
+            let rate = if i < 180 { 0.0001 } else { 0.0002 };
+            history.record(&[with_rate(rate)], t0 + Duration::seconds(i * 10));
+        }
+        let stats = history.stats();
+        assert_eq!(stats.observations, 721);
+        // 시작, 변화 지점, max_interval마다 저장한 지점
+        assert_eq!(stats.stored_points, 3);
+
+        let rate_at = |history: &FundingHistory, t| {
+            history
+                .value_at(ExchangeId::Binance, "BTCUSDT", t)
+                .map(|p| p.funding_rate)
+        };
+        assert_eq!(rate_at(&history, t0 + Duration::minutes(29)), Some(0.0001));
+        assert_eq!(rate_at(&history, t0 + Duration::minutes(31)), Some(0.0002));
+        assert_eq!(rate_at(&history, t0 + Duration::minutes(120)), Some(0.0002));
+        // 관측 이전/이후는 모름
+        assert_eq!(rate_at(&history, t0 - Duration::minutes(1)), None);
+        assert_eq!(rate_at(&history, t0 + Duration::minutes(121)), None);
+
+        let points = history.points(
+            ExchangeId::Binance,
+            "BTCUSDT",
+            t0 + Duration::minutes(10),
+            t0 + Duration::minutes(40),
+        );
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].time, t0);
+
+        let samples = history.resample(
+            ExchangeId::Binance,
+            "BTCUSDT",
+            t0,
+            t0 + Duration::hours(2),
+            Duration::minutes(15),
+        );
+        assert_eq!(samples.len(), 9);
+        assert_eq!(samples[1].funding_rate, Some(0.0001));
+        assert_eq!(samples[2].funding_rate, Some(0.0002));
+
+        // 수집이 끊긴 뒤 다시 관측되면 공백 구간은 None
+        history.record(&[with_rate(0.0002)], t0 + Duration::hours(6));
+        assert_eq!(rate_at(&history, t0 + Duration::hours(4)), None);
+        assert_eq!(rate_at(&history, t0 + Duration::hours(6)), Some(0.0002));
+    }
+
+    #[test]
+    fn test_parse_window() {
+        assert_eq!(parse_window("1h"), Some(Duration::hours(1)));
+        assert_eq!(parse_window("15m"), Some(Duration::minutes(15)));
+        assert_eq!(parse_window("90"), Some(Duration::seconds(90)));
+        assert_eq!(parse_window("0h"), None);
+        assert_eq!(parse_window("1w"), None);
+    }
+}
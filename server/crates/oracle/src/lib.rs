@@ -1,2 +1,14 @@
+pub mod aggregator;
+pub mod basis;
+pub mod calendar;
 pub mod collector;
+pub mod filter;
+pub mod history;
+pub mod merge;
+pub mod predict;
+pub mod quality;
+pub mod rank;
+pub mod registry;
 pub mod server;
+pub mod store;
+pub mod webhook;
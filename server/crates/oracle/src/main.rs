@@ -4,10 +4,6 @@ use color_eyre::eyre;
 use tracing::info;
 use tracing_subscriber::{fmt, EnvFilter};
 
-use exchanges::{
-    bithumb::BithumbClient, BinanceClient, BitgetClient, BybitClient, OkxClient, PerpExchange,
-    SpotExchange,
-};
 use oracle::server::AppState;
 
 #[tokio::main]
@@ -20,33 +16,40 @@ async fn main() -> eyre::Result<()> {
 
     info!("서버 시작 중...");
 
-    let state = Arc::new(AppState::new());
-
-    // set up perp exchanges
-    let perp_exchanges: Vec<Arc<dyn PerpExchange>> = vec![
-        Arc::new(BinanceClient::new()),
-        Arc::new(BybitClient::new()),
-        Arc::new(OkxClient::new()),
-        Arc::new(BitgetClient::new()),
-    ];
-
-    // set up spot exchanges
-    let spot_exchanges: Vec<Arc<dyn SpotExchange>> = vec![
-        Arc::new(BinanceClient::new()),
-        Arc::new(BybitClient::new()),
-        Arc::new(OkxClient::new()),
-        Arc::new(BitgetClient::new()),
-        Arc::new(BithumbClient::new()),
-    ];
+    let state = Arc::new(AppState::new().with_market_store(oracle::store::MarketStore::from_env()));
+
+    // 수집 거래소 구성 (ORACLE_CONFIG, 없으면 전체 거래소)
+    let config = oracle::registry::OracleConfig::load()?;
+    let registry = config.build();
+    let bithumb = registry.bithumb.clone();
+    let quality = oracle::quality::QualityPolicy::from_env();
 
     // start background collector
     oracle::collector::start_collect_loop(
-        perp_exchanges,
-        spot_exchanges,
+        registry,
         state.clone(),
-        Duration::from_secs(10),
+        oracle::filter::SymbolFilter::from_env(),
+        oracle::merge::SnapshotMerge::from_env(),
+        quality,
     );
 
+    if let Some(stream) = bithumb.as_ref().and_then(|b| b.spot_stream()) {
+        oracle::collector::start_live_spot_loop(
+            stream.clone(),
+            state.clone(),
+            Duration::from_secs(1),
+            quality,
+        );
+    }
+
+    // 외부 집계 서비스 펀딩비 대조 (ORACLE_AGGREGATOR_URL)
+    if let Some(aggregator) = oracle::aggregator::AggregatorConfig::from_env() {
+        oracle::aggregator::start_aggregator_check(aggregator, state.clone());
+    }
+
+    // 스냅샷 변화 웹훅 (설정 파일의 webhooks)
+    oracle::webhook::start_webhooks(config.webhooks, &state.basis_tx);
+
     // start HTTP server on 8080
     oracle::server::serve(state, 12090).await?;
 
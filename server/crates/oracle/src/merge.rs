@@ -0,0 +1,228 @@
+//! 수집 주기별 스냅샷 병합
+//!
+//! 거래소 하나의 `fetch_all`이 실패하거나 일부 심볼 파싱에 실패해도 이전 값을 버리지 않도록,
+//! 수집 결과를 (거래소, 심볼) 단위로 기존 스냅샷에 덮어쓴다. 이번 주기에 값이 오지 않은 항목은
+//! 이전 값과 이전 `updated_at`을 그대로 유지하다가 `max_age`보다 오래되면 (상장 폐지 등으로 보고) 제거한다.
+//! 항목별 `updated_at`이 그대로 남으므로 소비자는 `/snapshot-ages`로 심볼별 신선도를 확인할 수 있다.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use interface::{ExchangeId, PerpSnapshot, SpotSnapshot};
+
+/// 병합 가능한 스냅샷 (거래소/심볼 키와 항목별 갱신 시각)
+pub trait SnapshotEntry {
+    fn exchange(&self) -> ExchangeId;
+    fn symbol(&self) -> &str;
+    fn updated_at(&self) -> DateTime<Utc>;
+}
+
+impl SnapshotEntry for PerpSnapshot {
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+impl SnapshotEntry for SpotSnapshot {
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// 병합 설정
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotMerge {
+    /// 갱신되지 않은 항목을 유지하는 최대 시간
+    pub max_age: Duration,
+}
+
+impl Default for SnapshotMerge {
+    fn default() -> Self {
+        Self {
+            max_age: Duration::minutes(5),
+        }
+    }
+}
+
+impl SnapshotMerge {
+    /// `ORACLE_SNAPSHOT_MAX_AGE_SECS` (기본 300초)
+    pub fn from_env() -> Self {
+        std::env::var("ORACLE_SNAPSHOT_MAX_AGE_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse::<i64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(|secs| Self {
+                max_age: Duration::seconds(secs),
+            })
+            .unwrap_or_default()
+    }
+
+    /// 이번 주기 수집 결과를 기존 스냅샷에 병합
+    /// 순서는 기존 항목 순서를 유지하고 새 심볼은 뒤에 붙는다 (정렬은 호출자가 함)
+    pub fn merge<T: SnapshotEntry>(
+        &self,
+        current: &mut Vec<T>,
+        fresh: Vec<T>,
+        now: DateTime<Utc>,
+    ) -> MergeStats {
+        let mut stats = MergeStats::default();
+        let mut fresh: HashMap<(ExchangeId, String), T> = fresh
+            .into_iter()
+            .map(|s| ((s.exchange(), s.symbol().to_string()), s))
+            .collect();
+
+        let previous = std::mem::take(current);
+        for entry in previous {
+            let key = (entry.exchange(), entry.symbol().to_string());
+            if let Some(updated) = fresh.remove(&key) {
+                current.push(updated);
+                stats.updated += 1;
+            } else if now - entry.updated_at() <= self.max_age {
+                current.push(entry);
+                stats.kept += 1;
+            } else {
+                stats.expired += 1;
+            }
+        }
+        stats.added = fresh.len();
+        current.extend(fresh.into_values());
+        stats
+    }
+}
+
+/// 병합 결과 개수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MergeStats {
+    /// 이번 주기 값으로 덮어쓴 항목
+    pub updated: usize,
+    /// 새로 생긴 항목
+    pub added: usize,
+    /// 이번 주기에 값이 없어 이전 값을 유지한 항목
+    pub kept: usize,
+    /// max_age를 넘겨 제거한 항목
+    pub expired: usize,
+}
+
+/// 심볼별 스냅샷 나이
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotAge {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    /// "perp" 또는 "spot"
+    pub market: &'static str,
+    pub updated_at: DateTime<Utc>,
+    pub age_secs: f64,
+}
+
+/// 선물/현물 스냅샷의 심볼별 나이 (오래된 순)
+pub fn snapshot_ages(
+    perp: &[PerpSnapshot],
+    spot: &[SpotSnapshot],
+    now: DateTime<Utc>,
+) -> Vec<SnapshotAge> {
+    fn age<T: SnapshotEntry>(entry: &T, market: &'static str, now: DateTime<Utc>) -> SnapshotAge {
+        SnapshotAge {
+            exchange: entry.exchange(),
+            symbol: entry.symbol().to_string(),
+            market,
+            updated_at: entry.updated_at(),
+            age_secs: (now - entry.updated_at()).num_milliseconds() as f64 / 1000.0,
+        }
+    }
+
+    let mut ages: Vec<SnapshotAge> = perp
+        .iter()
+        .map(|s| age(s, "perp", now))
+        .chain(spot.iter().map(|s| age(s, "spot", now)))
+        .collect();
+    ages.sort_by(|a, b| b.age_secs.total_cmp(&a.age_secs));
+    ages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::{Currency, Price};
+
+    fn spot(
+        exchange: ExchangeId,
+        symbol: &str,
+        price: f64,
+        updated_at: DateTime<Utc>,
+    ) -> SpotSnapshot {
+        SpotSnapshot {
+            exchange,
+            symbol: symbol.to_string(),
+            currency: Currency::USDT,
+            price: Price::new(price),
+            vol_24h_usd: 0.0,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_missing_entries_until_max_age() {
+        let merge = SnapshotMerge {
+            max_age: Duration::seconds(60),
+        };
+        let t0 = Utc::now();
+        let mut current = vec![
+            spot(ExchangeId::Binance, "BTCUSDT", 100.0, t0),
+            spot(ExchangeId::Binance, "ETHUSDT", 10.0, t0),
+            spot(
+                ExchangeId::Bybit,
+                "BTCUSDT",
+                100.0,
+                t0 - Duration::seconds(120),
+            ),
+        ];
+
+        // ETHUSDT 파싱 실패, Bybit 전체 수집 실패
+        let t1 = t0 + Duration::seconds(10);
+        let stats = merge.merge(
+            &mut current,
+            vec![
+                spot(ExchangeId::Binance, "BTCUSDT", 101.0, t1),
+                spot(ExchangeId::Binance, "SOLUSDT", 1.0, t1),
+            ],
+            t1,
+        );
+        assert_eq!(
+            stats,
+            MergeStats {
+                updated: 1,
+                added: 1,
+                kept: 1,
+                expired: 1,
+            }
+        );
+        assert_eq!(current.len(), 3);
+        assert_eq!(current[0].price, Price::new(101.0));
+        // 유지된 항목은 이전 갱신 시각을 그대로 가진다
+        assert_eq!(current[1].symbol, "ETHUSDT");
+        assert_eq!(current[1].updated_at, t0);
+
+        let ages = snapshot_ages(&[], &current, t1);
+        assert_eq!(ages[0].symbol, "ETHUSDT");
+        assert_eq!(ages[0].age_secs, 10.0);
+        assert_eq!(ages[2].age_secs, 0.0);
+    }
+}
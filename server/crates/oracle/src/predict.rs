@@ -0,0 +1,173 @@
+//! 프리미엄 인덱스 TWAP 기반 다음 펀딩비 예측
+//!
+//! Binance/Bybit 무기한 선물의 펀딩비는 정산 주기 동안의 프리미엄 인덱스 평균 P와 이자율 I로 정해진다.
+//!   F = P + clamp(I - P, -0.05%, 0.05%)
+//! P는 주기 안의 샘플에 1, 2, ..., n 가중치를 준 평균(뒤쪽 샘플일수록 큼)이다.
+//! 여기서는 수집 주기마다 (마크 - 인덱스) / 인덱스를 프리미엄 인덱스 근사값으로 기록하고,
+//! next_funding_time이 바뀌면(정산) 샘플을 비운다. 인덱스 가격을 주지 않는 거래소는 예측하지 않는다.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use interface::{ExchangeId, PerpSnapshot};
+
+/// 8시간 주기 기준 이자율 (0.03%/일)
+pub const INTEREST_RATE_8H: f64 = 0.0001;
+/// 이자율 - 프리미엄 차이의 clamp 범위
+pub const INTEREST_CLAMP: f64 = 0.0005;
+/// 예측값 상한 기본값 (실제 상한은 심볼별로 다름)
+pub const DEFAULT_MAX_ABS_RATE: f64 = 0.0075;
+
+/// 한 정산 주기의 프리미엄 샘플
+#[derive(Debug, Clone)]
+struct PremiumWindow {
+    funding_time: DateTime<Utc>,
+    /// 정산 주기 (시간, 이자율 환산용)
+    interval_hours: f64,
+    premiums: Vec<f64>,
+}
+
+/// 거래소/심볼별 다음 펀딩비 예측기
+#[derive(Debug, Clone)]
+pub struct FundingPredictor {
+    windows: HashMap<(ExchangeId, String), PremiumWindow>,
+    /// 예측 펀딩비 절대값 상한
+    pub max_abs_rate: f64,
+}
+
+impl Default for FundingPredictor {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ABS_RATE)
+    }
+}
+
+impl FundingPredictor {
+    pub fn new(max_abs_rate: f64) -> Self {
+        Self {
+            windows: HashMap::new(),
+            max_abs_rate,
+        }
+    }
+
+    /// `ORACLE_FUNDING_PREDICT_CAP` (기본 0.0075 == 0.75%)
+    pub fn from_env() -> Self {
+        std::env::var("ORACLE_FUNDING_PREDICT_CAP")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|cap| *cap > 0.0 && cap.is_finite())
+            .map(Self::new)
+            .unwrap_or_default()
+    }
+
+    /// 이번 주기 스냅샷의 프리미엄 기록
+    /// 인덱스 가격, next_funding_time, 정산 주기 중 하나라도 없는 항목은 건너뛰고, 정산이 지난 주기는 제거한다
+    pub fn record(&mut self, snapshots: &[PerpSnapshot], now: DateTime<Utc>) {
+        for snapshot in snapshots {
+            let (Some(index), Some(funding_time), Some(interval_hours)) = (
+                snapshot.index_price,
+                snapshot.next_funding_time,
+                snapshot.effective_funding_interval_hours(),
+            ) else {
+                continue;
+            };
+            if index.is_zero() || snapshot.mark_price.is_zero() {
+                continue;
+            }
+            let premium = (snapshot.mark_price.value() - index.value()) / index.value();
+
+            let window = self
+                .windows
+                .entry((snapshot.exchange, snapshot.symbol.clone()))
+                .or_insert_with(|| PremiumWindow {
+                    funding_time,
+                    interval_hours,
+                    premiums: Vec::new(),
+                });
+            window.interval_hours = interval_hours;
+            if window.funding_time != funding_time {
+                window.funding_time = funding_time;
+                window.premiums.clear();
+            }
+            window.premiums.push(premium);
+        }
+        self.windows.retain(|_, w| w.funding_time > now);
+    }
+
+    /// 다음 정산 펀딩비 예측 (0.01 == 1%)
+    pub fn predict(&self, exchange: ExchangeId, symbol: &str) -> Option<f64> {
+        let window = self.windows.get(&(exchange, symbol.to_string()))?;
+        let premium = weighted_average(&window.premiums)?;
+        let interest = INTEREST_RATE_8H * window.interval_hours / 8.0;
+        let rate = premium + (interest - premium).clamp(-INTEREST_CLAMP, INTEREST_CLAMP);
+        Some(rate.clamp(-self.max_abs_rate, self.max_abs_rate))
+    }
+}
+
+/// 1..n 가중 평균
+fn weighted_average(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let (sum, weights) = values
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(sum, weights), (i, v)| {
+            let w = (i + 1) as f64;
+            (sum + v * w, weights + w)
+        });
+    Some(sum / weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use interface::{Currency, Price};
+
+    fn perp(mark: f64, index: f64, next_funding_time: DateTime<Utc>) -> PerpSnapshot {
+        PerpSnapshot {
+            exchange: ExchangeId::Binance,
+            symbol: "BTCUSDT".to_string(),
+            currency: Currency::USDT,
+            mark_price: Price::new(mark),
+            index_price: Some(Price::new(index)),
+            oi_usd: 0.0,
+            vol_24h_usd: 0.0,
+            funding_rate: 0.0001,
+            next_funding_time: Some(next_funding_time),
+            funding_interval_hours: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_predict_twap_with_clamp_and_reset() {
+        let now = Utc::now();
+        let first = now + Duration::hours(1);
+        let mut predictor = FundingPredictor::default();
+
+        // 프리미엄이 이자율 근처면 이자율로 수렴
+        predictor.record(&[perp(100.0, 100.0, first)], now);
+        predictor.record(&[perp(100.02, 100.0, first)], now);
+        // P = (0 * 1 + 0.0002 * 2) / 3, |I - P| < 0.05% → F = I
+        let rate = predictor.predict(ExchangeId::Binance, "BTCUSDT").unwrap();
+        assert!((rate - INTEREST_RATE_8H).abs() < 1e-12);
+
+        // 정산 후 샘플 초기화, 큰 프리미엄은 P + 0.05% 쪽으로 clamp, 상한 적용
+        let second = first + Duration::hours(8);
+        predictor.record(&[perp(100.3, 100.0, second)], now);
+        let rate = predictor.predict(ExchangeId::Binance, "BTCUSDT").unwrap();
+        assert!((rate - (0.003 - INTEREST_CLAMP)).abs() < 1e-9);
+
+        predictor.max_abs_rate = 0.002;
+        assert_eq!(
+            predictor.predict(ExchangeId::Binance, "BTCUSDT"),
+            Some(0.002)
+        );
+
+        // 정산 시각이 지난 주기는 제거
+        predictor.record(&[], second + Duration::seconds(1));
+        assert!(predictor.predict(ExchangeId::Binance, "BTCUSDT").is_none());
+    }
+}
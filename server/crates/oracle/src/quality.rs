@@ -0,0 +1,220 @@
+//! 통합 스냅샷 품질 점수
+//!
+//! 1.0에서 시작해 플래그마다 감점한다: 오래된 선물/현물 -0.4, 오래된 환율 -0.1,
+//! 이번 주기 파싱 실패 -0.2, WebSocket 대신 REST 값 사용 -0.1 (0 미만은 0).
+//! 선물/현물이 모두 오래되면 0.2 이하가 되어 대부분의 최소 품질 조건에 걸린다.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, Utc};
+
+use exchanges::status::{ChannelKind, ExchangeStatus};
+use interface::{parse::ParseFailureStats, DataSource, QualityFlag, SnapshotQuality};
+
+/// 품질 판정 기준
+#[derive(Debug, Clone, Copy)]
+pub struct QualityPolicy {
+    /// 선물/현물 값이 이보다 오래되면 stale
+    pub stale_after: Duration,
+    /// 환율이 이보다 오래되면 stale
+    pub rate_stale_after: Duration,
+}
+
+impl Default for QualityPolicy {
+    fn default() -> Self {
+        Self {
+            stale_after: Duration::seconds(60),
+            rate_stale_after: Duration::minutes(10),
+        }
+    }
+}
+
+fn env_secs(key: &str) -> Option<Duration> {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::seconds)
+}
+
+/// 스냅샷 하나의 품질 판정 입력
+#[derive(Debug, Clone, Default)]
+pub struct QualityInput {
+    /// 선물 값 갱신 시각과 출처
+    pub perp: Option<(DateTime<Utc>, DataSource)>,
+    /// 현물 값 갱신 시각과 출처
+    pub spot: Option<(DateTime<Utc>, DataSource)>,
+    pub exchange_rates_at: DateTime<Utc>,
+    /// WebSocket이 끊겨 REST 값을 쓰고 있음
+    pub rest_fallback: bool,
+    /// 이번 주기에 거래소 응답 파싱 실패가 있었음
+    pub parse_anomaly: bool,
+}
+
+/// `now` 기준 나이 (초, 미래 시각은 0)
+pub fn age_secs(at: DateTime<Utc>, now: DateTime<Utc>) -> f64 {
+    (now - at).num_milliseconds().max(0) as f64 / 1000.0
+}
+
+fn penalty(flag: QualityFlag) -> f64 {
+    match flag {
+        QualityFlag::StalePerp | QualityFlag::StaleSpot => 0.4,
+        QualityFlag::StaleExchangeRate => 0.1,
+        QualityFlag::ParseAnomaly => 0.2,
+        QualityFlag::RestFallback => 0.1,
+    }
+}
+
+impl QualityPolicy {
+    /// `ORACLE_QUALITY_STALE_SECS` (기본 60초), `ORACLE_QUALITY_RATE_STALE_SECS` (기본 600초)
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            stale_after: env_secs("ORACLE_QUALITY_STALE_SECS").unwrap_or(default.stale_after),
+            rate_stale_after: env_secs("ORACLE_QUALITY_RATE_STALE_SECS")
+                .unwrap_or(default.rate_stale_after),
+        }
+    }
+
+    pub fn assess(&self, input: &QualityInput, now: DateTime<Utc>) -> SnapshotQuality {
+        let mut flags = Vec::new();
+        if input.parse_anomaly {
+            flags.push(QualityFlag::ParseAnomaly);
+        }
+        if input.rest_fallback {
+            flags.push(QualityFlag::RestFallback);
+        }
+        let mut quality = SnapshotQuality {
+            score: 1.0,
+            perp_age_secs: input.perp.map(|(at, _)| age_secs(at, now)),
+            spot_age_secs: input.spot.map(|(at, _)| age_secs(at, now)),
+            exchange_rate_age_secs: age_secs(input.exchange_rates_at, now),
+            perp_source: input.perp.map(|(_, source)| source),
+            spot_source: input.spot.map(|(_, source)| source),
+            flags,
+        };
+        self.rescore(&mut quality);
+        quality
+    }
+
+    /// 나이/출처가 바뀐 뒤 stale 플래그와 점수 재계산 (파싱 이상, REST 대체 플래그는 유지)
+    pub fn rescore(&self, quality: &mut SnapshotQuality) {
+        let stale = |age: Option<f64>, limit: Duration| {
+            age.is_some_and(|age| age > limit.num_milliseconds() as f64 / 1000.0)
+        };
+        quality
+            .flags
+            .retain(|f| matches!(f, QualityFlag::ParseAnomaly | QualityFlag::RestFallback));
+        if stale(quality.perp_age_secs, self.stale_after) {
+            quality.flags.push(QualityFlag::StalePerp);
+        }
+        if stale(quality.spot_age_secs, self.stale_after) {
+            quality.flags.push(QualityFlag::StaleSpot);
+        }
+        if stale(Some(quality.exchange_rate_age_secs), self.rate_stale_after) {
+            quality.flags.push(QualityFlag::StaleExchangeRate);
+        }
+        let penalty: f64 = quality.flags.iter().map(|f| penalty(*f)).sum();
+        quality.score = (1.0 - penalty).clamp(0.0, 1.0);
+    }
+}
+
+/// 거래소 데이터 출처와 REST 대체 여부
+/// WebSocket 채널이 있는 거래소는 그 채널이 정상이면 WebSocket, 끊겼으면 REST로 대체된 것으로 본다
+pub fn exchange_source(status: &ExchangeStatus) -> (DataSource, bool) {
+    let mut ws = status
+        .channels
+        .iter()
+        .filter(|c| c.kind == ChannelKind::WebSocket)
+        .peekable();
+    if ws.peek().is_none() {
+        return (DataSource::Rest, false);
+    }
+    if ws.all(|c| c.is_healthy()) {
+        (DataSource::WebSocket, false)
+    } else {
+        (DataSource::Rest, true)
+    }
+}
+
+/// 수집 주기 사이에 파싱 실패가 늘어난 거래소 추적 (누적 통계의 증가분으로 판단)
+#[derive(Debug, Default)]
+pub struct ParseAnomalyTracker {
+    last_failures: BTreeMap<String, u64>,
+}
+
+impl ParseAnomalyTracker {
+    /// 지난 호출 이후 실패가 늘어난 거래소 이름 (`format!("{:?}", ExchangeId)`)
+    pub fn poll(&mut self, stats: &BTreeMap<String, ParseFailureStats>) -> BTreeSet<String> {
+        let mut anomalies = BTreeSet::new();
+        for (exchange, stat) in stats {
+            let last = self.last_failures.insert(exchange.clone(), stat.failures);
+            if stat.failures > last.unwrap_or(0) {
+                anomalies.insert(exchange.clone());
+            }
+        }
+        anomalies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assess_and_rescore() {
+        let policy = QualityPolicy::default();
+        let now = Utc::now();
+
+        let fresh = QualityInput {
+            perp: Some((now - Duration::seconds(5), DataSource::WebSocket)),
+            spot: Some((now - Duration::seconds(10), DataSource::Rest)),
+            exchange_rates_at: now,
+            ..Default::default()
+        };
+        let quality = policy.assess(&fresh, now);
+        assert_eq!(quality.score, 1.0);
+        assert!(quality.flags.is_empty());
+        assert_eq!(quality.perp_age_secs, Some(5.0));
+
+        let degraded = QualityInput {
+            spot: Some((now - Duration::seconds(120), DataSource::Rest)),
+            exchange_rates_at: now - Duration::minutes(30),
+            rest_fallback: true,
+            parse_anomaly: true,
+            ..fresh
+        };
+        let mut quality = policy.assess(&degraded, now);
+        assert_eq!(
+            quality.flags,
+            vec![
+                QualityFlag::ParseAnomaly,
+                QualityFlag::RestFallback,
+                QualityFlag::StaleSpot,
+                QualityFlag::StaleExchangeRate,
+            ]
+        );
+        assert!((quality.score - 0.2).abs() < 1e-9);
+
+        // 실시간 가격이 반영되면 stale 플래그만 다시 계산
+        quality.spot_age_secs = Some(0.5);
+        quality.spot_source = Some(DataSource::WebSocket);
+        policy.rescore(&mut quality);
+        assert!(!quality.has_flag(QualityFlag::StaleSpot));
+        assert!(quality.has_flag(QualityFlag::ParseAnomaly));
+        assert!((quality.score - 0.6).abs() < 1e-9);
+
+        // 파싱 실패 증가분만 이상으로 본다
+        let mut tracker = ParseAnomalyTracker::default();
+        let mut stats = BTreeMap::new();
+        stats.insert(
+            "Okx".to_string(),
+            ParseFailureStats {
+                failures: 3,
+                ..Default::default()
+            },
+        );
+        assert!(tracker.poll(&stats).contains("Okx"));
+        assert!(tracker.poll(&stats).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use serde::Serialize;
+
+use interface::ExchangeId;
+use timeseries::Point;
+
+/// 백분위를 계산할 최소 기록 수 (이보다 적으면 분포가 의미 없으므로 제외)
+pub const MIN_RANK_SAMPLES: usize = 30;
+
+/// 현재 펀딩비의 심볼별 과거 분포 내 위치
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingRank {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    /// 현재 펀딩비 (0.01 == 1%)
+    pub funding_rate: f64,
+    /// 과거 분포 내 백분위 (0~100, 같은 값은 절반만 아래로 셈)
+    pub percentile: f64,
+    pub samples: usize,
+    pub min: f64,
+    pub p05: f64,
+    pub median: f64,
+    pub p95: f64,
+    pub max: f64,
+}
+
+/// 과거 기록 대비 현재 펀딩비의 백분위와 분포 요약
+///
+/// 수집 주기마다 기록된 값이므로 시간 가중 분포로 본다. 유효한 기록이
+/// `MIN_RANK_SAMPLES`보다 적으면 None.
+pub fn rank_funding(
+    exchange: ExchangeId,
+    symbol: &str,
+    funding_rate: f64,
+    history: &[Point],
+) -> Option<FundingRank> {
+    let mut values: Vec<f64> = history
+        .iter()
+        .map(|p| p.value)
+        .filter(|v| v.is_finite())
+        .collect();
+    if values.len() < MIN_RANK_SAMPLES || !funding_rate.is_finite() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+
+    let below = values.partition_point(|v| *v < funding_rate);
+    let not_above = values.partition_point(|v| *v <= funding_rate);
+    let equal = not_above - below;
+    let n = values.len();
+
+    Some(FundingRank {
+        exchange,
+        symbol: symbol.to_string(),
+        funding_rate,
+        percentile: (below as f64 + equal as f64 / 2.0) / n as f64 * 100.0,
+        samples: n,
+        min: values[0],
+        p05: quantile(&values, 0.05),
+        median: quantile(&values, 0.5),
+        p95: quantile(&values, 0.95),
+        max: values[n - 1],
+    })
+}
+
+/// 정렬된 값의 분위수 (최근접 순위)
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let index = ((sorted.len() - 1) as f64 * q).round() as usize;
+    sorted[index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn history(values: impl IntoIterator<Item = f64>) -> Vec<Point> {
+        let start = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| Point::new(start + Duration::minutes(i as i64), v))
+            .collect()
+    }
+
+    #[test]
+    fn test_rank_funding() {
+        // 0.0001 ~ 0.0100 균등 분포
+        let points = history((1..=100).map(|i| i as f64 * 0.0001));
+
+        let normal = rank_funding(ExchangeId::Binance, "BTCUSDT", 0.00505, &points).unwrap();
+        assert!((normal.percentile - 50.0).abs() < 1e-9);
+        assert_eq!(normal.samples, 100);
+
+        let extreme = rank_funding(ExchangeId::Binance, "BTCUSDT", 0.05, &points).unwrap();
+        assert_eq!(extreme.percentile, 100.0);
+        assert!((extreme.p95 - 0.0095).abs() < 1e-12);
+        assert!((extreme.max - 0.01).abs() < 1e-12);
+
+        // 같은 값은 절반만 아래로 셈
+        let flat = history(std::iter::repeat_n(0.0001, 40));
+        let rank = rank_funding(ExchangeId::Bybit, "ETHUSDT", 0.0001, &flat).unwrap();
+        assert_eq!(rank.percentile, 50.0);
+
+        // 기록 부족
+        assert!(rank_funding(ExchangeId::Okx, "BTCUSDT", 0.0001, &points[..10]).is_none());
+    }
+}
@@ -0,0 +1,320 @@
+//! 설정 파일 기반 수집 거래소 구성
+//!
+//! `ORACLE_CONFIG`(기본 `oracle.json`) JSON 파일로 거래소별 사용 여부, 선물/현물 수집 여부,
+//! 수집 간격을 정한다. 파일이 없으면 기존과 같이 전체 거래소를 10초 간격으로 수집한다.
+//!
+//! ```json
+//! {
+//!   "interval_secs": 10,
+//!   "exchanges": [
+//!     { "exchange": "bithumb", "interval_secs": 5 },
+//!     { "exchange": "binance", "perp": false },
+//!     { "exchange": "okx", "enabled": false },
+//!     { "exchange": "bybit", "require_credentials": true }
+//!   ]
+//! }
+//! ```
+//! 목록에 없는 거래소는 수집하지 않는다. `require_credentials`가 true인데 API 키 환경 변수가
+//! 없으면 경고 후 제외한다 (수집 자체는 공개 API만 사용).
+
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use exchanges::{
+    bithumb::BithumbClient, BinanceClient, BitgetClient, BybitClient, OkxClient, PerpExchange,
+    SpotExchange,
+};
+use interface::ExchangeId;
+
+use crate::webhook::WebhookConfig;
+
+const DEFAULT_CONFIG_PATH: &str = "oracle.json";
+const DEFAULT_INTERVAL_SECS: u64 = 10;
+
+fn default_true() -> bool {
+    true
+}
+
+/// 거래소 하나의 수집 설정
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExchangeConfig {
+    /// "binance" | "bybit" | "okx" | "bitget" | "bithumb"
+    pub exchange: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 무기한 선물 수집 (빗썸은 무시)
+    #[serde(default = "default_true")]
+    pub perp: bool,
+    #[serde(default = "default_true")]
+    pub spot: bool,
+    /// 거래소별 수집 간격 (없으면 전체 간격)
+    #[serde(default)]
+    pub interval_secs: Option<u64>,
+    /// API 키 환경 변수가 없으면 제외
+    #[serde(default)]
+    pub require_credentials: bool,
+}
+
+impl ExchangeConfig {
+    fn all(exchange: &str) -> Self {
+        Self {
+            exchange: exchange.to_string(),
+            enabled: true,
+            perp: true,
+            spot: true,
+            interval_secs: None,
+            require_credentials: false,
+        }
+    }
+}
+
+/// 오라클 수집 설정
+#[derive(Debug, Clone, Deserialize)]
+pub struct OracleConfig {
+    /// 기본 수집 간격 (초)
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64,
+    pub exchanges: Vec<ExchangeConfig>,
+    /// 스냅샷 변화 웹훅 (`webhook` 모듈 참고)
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+fn default_interval_secs() -> u64 {
+    DEFAULT_INTERVAL_SECS
+}
+
+impl Default for OracleConfig {
+    /// 전체 거래소, 10초 간격 (설정 파일이 없을 때)
+    fn default() -> Self {
+        Self {
+            interval_secs: DEFAULT_INTERVAL_SECS,
+            exchanges: ["binance", "bybit", "okx", "bitget", "bithumb"]
+                .into_iter()
+                .map(ExchangeConfig::all)
+                .collect(),
+            webhooks: Vec::new(),
+        }
+    }
+}
+
+impl OracleConfig {
+    pub fn from_json(json: &str) -> eyre::Result<Self> {
+        let config: Self = serde_json::from_str(json)?;
+        for entry in &config.exchanges {
+            parse_exchange(&entry.exchange)?;
+        }
+        Ok(config)
+    }
+
+    /// `ORACLE_CONFIG` 경로에서 로드 (지정하지 않았고 기본 파일도 없으면 기본값)
+    pub fn load() -> eyre::Result<Self> {
+        let explicit = std::env::var("ORACLE_CONFIG").ok();
+        let path = explicit.as_deref().unwrap_or(DEFAULT_CONFIG_PATH);
+        if explicit.is_none() && !Path::new(path).exists() {
+            return Ok(Self::default());
+        }
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| eyre::eyre!("Failed to read oracle config {}: {}", path, e))?;
+        info!("오라클 설정 파일 로드: {}", path);
+        Self::from_json(&json)
+    }
+
+    /// 기본 간격 적용한 거래소 수집 간격
+    fn interval_of(&self, entry: &ExchangeConfig) -> Duration {
+        Duration::from_secs(entry.interval_secs.unwrap_or(self.interval_secs).max(1))
+    }
+
+    /// 사용할 거래소 (비활성, 중복, 인증 정보 누락 제외)
+    pub fn active_exchanges(&self) -> Vec<(ExchangeId, &ExchangeConfig)> {
+        let mut active: Vec<(ExchangeId, &ExchangeConfig)> = Vec::new();
+        for entry in &self.exchanges {
+            let Ok(id) = parse_exchange(&entry.exchange) else {
+                continue;
+            };
+            if !entry.enabled || active.iter().any(|(seen, _)| *seen == id) {
+                continue;
+            }
+            if entry.require_credentials && !has_credentials(id) {
+                warn!("{:?}: API 키 환경 변수가 없어 수집에서 제외", id);
+                continue;
+            }
+            active.push((id, entry));
+        }
+        active
+    }
+
+    /// 설정대로 거래소 클라이언트 생성
+    pub fn build(&self) -> ExchangeRegistry {
+        let mut registry = ExchangeRegistry {
+            perp: Vec::new(),
+            spot: Vec::new(),
+            bithumb: None,
+            tick: Duration::from_secs(self.interval_secs.max(1)),
+        };
+        for (id, entry) in self.active_exchanges() {
+            let every = self.interval_of(entry);
+            registry.tick = registry.tick.min(every);
+            info!(
+                "{:?}: 선물 {}, 현물 {}, {}초 간격, API 키 {}",
+                id,
+                entry.perp && id != ExchangeId::Bithumb,
+                entry.spot,
+                every.as_secs(),
+                if has_credentials(id) {
+                    "있음"
+                } else {
+                    "없음"
+                }
+            );
+            match id {
+                ExchangeId::Binance => registry.add(BinanceClient::new(), entry, every),
+                ExchangeId::Bybit => registry.add(BybitClient::new(), entry, every),
+                ExchangeId::Okx => registry.add(OkxClient::new(), entry, every),
+                ExchangeId::Bitget => registry.add(BitgetClient::new(), entry, every),
+                ExchangeId::Bithumb => {
+                    if entry.spot {
+                        // 빗썸은 WebSocket 체결가로 REST 현물 가격을 보강
+                        let bithumb = BithumbClient::with_spot_stream();
+                        registry
+                            .spot
+                            .push(Scheduled::new(Arc::new(bithumb.clone()), every));
+                        registry.bithumb = Some(bithumb);
+                    }
+                }
+            }
+        }
+        registry
+    }
+}
+
+/// 설정 파일의 거래소 이름 파싱
+pub fn parse_exchange(name: &str) -> eyre::Result<ExchangeId> {
+    match name.trim().to_lowercase().as_str() {
+        "binance" => Ok(ExchangeId::Binance),
+        "bybit" => Ok(ExchangeId::Bybit),
+        "okx" => Ok(ExchangeId::Okx),
+        "bitget" => Ok(ExchangeId::Bitget),
+        "bithumb" => Ok(ExchangeId::Bithumb),
+        other => Err(eyre::eyre!("Unknown exchange in oracle config: {}", other)),
+    }
+}
+
+/// 거래소 API 키 환경 변수 존재 여부
+pub fn has_credentials(exchange: ExchangeId) -> bool {
+    let vars: &[&str] = match exchange {
+        ExchangeId::Binance => &["BINANCE_API_KEY", "BINANCE_API_SECRET"],
+        ExchangeId::Bybit => &["BYBIT_API_KEY", "BYBIT_API_SECRET"],
+        ExchangeId::Okx => &["OKX_API_KEY", "OKX_API_SECRET", "OKX_API_PASSPHRASE"],
+        ExchangeId::Bitget => &[
+            "BITGET_API_KEY",
+            "BITGET_API_SECRET",
+            "BITGET_API_PASSPHRASE",
+        ],
+        ExchangeId::Bithumb => &["BITHUMB_API_KEY", "BITHUMB_API_SECRET"],
+    };
+    vars.iter().all(|var| std::env::var(var).is_ok())
+}
+
+/// 자기 간격마다 수집하는 거래소 클라이언트
+pub struct Scheduled<T: ?Sized> {
+    pub client: Arc<T>,
+    pub every: Duration,
+    last: Option<Instant>,
+}
+
+impl<T: ?Sized> Scheduled<T> {
+    pub fn new(client: Arc<T>, every: Duration) -> Self {
+        Self {
+            client,
+            every,
+            last: None,
+        }
+    }
+
+    /// 이번 주기에 수집할 차례인지 (처음엔 항상 수집, 수집하면 시각 기록)
+    pub fn due(&mut self, now: Instant) -> bool {
+        if self
+            .last
+            .is_some_and(|last| now.duration_since(last) < self.every)
+        {
+            return false;
+        }
+        self.last = Some(now);
+        true
+    }
+}
+
+/// 수집 루프에 넘기는 거래소 목록
+pub struct ExchangeRegistry {
+    pub perp: Vec<Scheduled<dyn PerpExchange>>,
+    pub spot: Vec<Scheduled<dyn SpotExchange>>,
+    /// 빗썸 현물을 수집하면 실시간 체결가 스트림용 클라이언트
+    pub bithumb: Option<BithumbClient>,
+    /// 수집 루프 주기 (거래소별 간격 중 가장 짧은 값)
+    pub tick: Duration,
+}
+
+impl ExchangeRegistry {
+    fn add<C>(&mut self, client: C, entry: &ExchangeConfig, every: Duration)
+    where
+        C: PerpExchange + SpotExchange + 'static,
+    {
+        let client = Arc::new(client);
+        if entry.perp {
+            self.perp.push(Scheduled::new(client.clone(), every));
+        }
+        if entry.spot {
+            self.spot.push(Scheduled::new(client, every));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_filters_and_schedules() {
+        let config = OracleConfig::from_json(
+            r#"{
+                "interval_secs": 30,
+                "exchanges": [
+                    {"exchange": "Bithumb", "interval_secs": 5},
+                    {"exchange": "binance", "perp": false},
+                    {"exchange": "okx", "enabled": false},
+                    {"exchange": "binance"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let active: Vec<ExchangeId> = config
+            .active_exchanges()
+            .iter()
+            .map(|(id, _)| *id)
+            .collect();
+        assert_eq!(active, vec![ExchangeId::Bithumb, ExchangeId::Binance]);
+        assert_eq!(
+            config.interval_of(&config.exchanges[0]),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            config.interval_of(&config.exchanges[1]),
+            Duration::from_secs(30)
+        );
+        assert!(OracleConfig::from_json(r#"{"exchanges": [{"exchange": "kraken"}]}"#).is_err());
+        assert_eq!(OracleConfig::default().active_exchanges().len(), 5);
+
+        let mut scheduled = Scheduled::new(Arc::new(()), Duration::from_secs(10));
+        let start = Instant::now();
+        assert!(scheduled.due(start));
+        assert!(!scheduled.due(start + Duration::from_secs(5)));
+        assert!(scheduled.due(start + Duration::from_secs(10)));
+    }
+}
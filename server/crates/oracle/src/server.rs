@@ -1,17 +1,83 @@
 use std::{net::SocketAddr, sync::Arc};
 
-use axum::{extract::State, response::IntoResponse, routing::get, Json, Router};
-use tokio::sync::RwLock;
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use tokio::sync::{broadcast, RwLock};
 use tower_http::cors::CorsLayer;
-use tracing::info;
+use tracing::{info, warn};
+use utoipa::{IntoParams, OpenApi};
+use utoipa_swagger_ui::SwaggerUi;
 
-use interface::{PerpSnapshot, SpotSnapshot, UnifiedSnapshot};
+use exchanges::status::ExchangeStatus;
+use interface::{
+    ExchangeId, PerpSnapshot, SpotSnapshot, UnifiedSnapshot, UNIFIED_SNAPSHOT_SCHEMA_VERSION,
+};
+use timeseries::{downsample, keys};
+
+use crate::aggregator::AggregatorReport;
+use crate::basis::{basis_series, compute_basis_frame, parse_venue_pair, BasisFrame};
+use crate::calendar::build_calendar;
+use crate::history::{aggregate_by_symbol, parse_window, FundingHistory, OiHistory};
+use crate::merge::snapshot_ages;
+use crate::predict::FundingPredictor;
+use crate::rank::{rank_funding, MIN_RANK_SAMPLES};
+use crate::store::{exchange_name, MarketStore};
+
+/// OpenAPI 문서 (`/openapi.json`, Swagger UI는 `/swagger-ui`)
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Oracle API", description = "거래소별 선물/현물 시세, OI, 연결 상태 조회 API"),
+    paths(
+        health_handler,
+        healthz_handler,
+        snapshots_handler,
+        spot_snapshots_handler,
+        unified_snapshots_handler,
+        schema_handler,
+        oi_changes_handler,
+        funding_calendar_handler,
+        funding_history_handler,
+        basis_history_handler,
+        funding_rank_handler,
+        snapshot_ages_handler,
+        funding_aggregator_check_handler
+    ),
+    tags(
+        (name = "status", description = "서버/거래소 연결 상태"),
+        (name = "snapshots", description = "시세 스냅샷")
+    )
+)]
+pub struct ApiDoc;
 
 #[derive(Clone)]
 pub struct AppState {
     pub perp_snapshots: Arc<RwLock<Vec<PerpSnapshot>>>,
     pub spot_snapshots: Arc<RwLock<Vec<SpotSnapshot>>>,
     pub unified_snapshots: Arc<RwLock<Vec<UnifiedSnapshot>>>,
+    /// 거래소 클라이언트별 연결 상태 (수집 주기마다 갱신)
+    pub exchange_status: Arc<RwLock<Vec<ExchangeStatus>>>,
+    /// 거래소/심볼별 OI 기록 (최근 24시간)
+    pub oi_history: Arc<RwLock<OiHistory>>,
+    /// 거래소/심볼별 펀딩비 변화 기록 (최근 90일)
+    pub funding_history: Arc<RwLock<FundingHistory>>,
+    /// 프리미엄 인덱스 TWAP 기반 다음 펀딩비 예측기
+    pub funding_predictor: Arc<RwLock<FundingPredictor>>,
+    /// 수집 주기마다 계산한 베이시스 프레임 (`/ws/basis` 구독자에게 전달)
+    pub basis_tx: broadcast::Sender<Arc<BasisFrame>>,
+    /// 수집 데이터 디스크 기록 (`ORACLE_TIMESERIES_DIR` 지정 시)
+    pub market_store: Option<MarketStore>,
+    /// 마지막 외부 집계 서비스 펀딩비 대조 결과 (`ORACLE_AGGREGATOR_URL` 지정 시)
+    pub aggregator_report: Arc<RwLock<Option<AggregatorReport>>>,
 }
 
 impl AppState {
@@ -20,35 +86,710 @@ impl AppState {
             perp_snapshots: Arc::new(RwLock::new(Vec::new())),
             spot_snapshots: Arc::new(RwLock::new(Vec::new())),
             unified_snapshots: Arc::new(RwLock::new(Vec::new())),
+            exchange_status: Arc::new(RwLock::new(Vec::new())),
+            oi_history: Arc::new(RwLock::new(OiHistory::new(chrono::Duration::hours(24)))),
+            funding_history: Arc::new(RwLock::new(FundingHistory::new(
+                chrono::Duration::days(90),
+                chrono::Duration::hours(1),
+            ))),
+            funding_predictor: Arc::new(RwLock::new(FundingPredictor::from_env())),
+            basis_tx: broadcast::channel(16).0,
+            market_store: None,
+            aggregator_report: Arc::new(RwLock::new(None)),
         }
     }
+
+    pub fn with_market_store(mut self, market_store: Option<MarketStore>) -> Self {
+        self.market_store = market_store;
+        self
+    }
 }
 
+#[utoipa::path(
+    get,
+    path = "/snapshots",
+    tag = "snapshots",
+    responses(
+        (status = 200, description = "선물 스냅샷 목록 (OI 내림차순)")
+    )
+)]
 async fn snapshots_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let data = state.perp_snapshots.read().await.clone();
     Json(data)
 }
 
+#[utoipa::path(
+    get,
+    path = "/spot-snapshots",
+    tag = "snapshots",
+    responses(
+        (status = 200, description = "현물 스냅샷 목록 (거래량 내림차순)")
+    )
+)]
 async fn spot_snapshots_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let data = state.spot_snapshots.read().await.clone();
     Json(data)
 }
 
-async fn unified_snapshots_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let data = state.unified_snapshots.read().await.clone();
+#[derive(Debug, Deserialize, IntoParams)]
+struct UnifiedSnapshotsQuery {
+    /// 최소 품질 점수 (0.0~1.0). 지정하면 점수가 낮은 스냅샷은 빠진다
+    min_quality: Option<f64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/unified-snapshots",
+    tag = "snapshots",
+    params(UnifiedSnapshotsQuery),
+    responses(
+        (status = 200, description = "선물·현물·환율을 합친 스냅샷 (quality에 품질 점수 포함)")
+    )
+)]
+async fn unified_snapshots_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<UnifiedSnapshotsQuery>,
+) -> impl IntoResponse {
+    let mut data = state.unified_snapshots.read().await.clone();
+    if let Some(min_quality) = query.min_quality {
+        data.retain(|s| s.meets_quality(min_quality));
+    }
     Json(data)
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+struct OiChangesQuery {
+    /// 비교 기간 (예: 15m, 1h, 4h). 기본 1h
+    window: Option<String>,
+    /// 증가/감소 목록 각각의 최대 개수. 기본 20
+    limit: Option<usize>,
+}
+
+/// 기간 내 OI 증가/감소 상위 목록 (거래소별, 심볼별 합산)
+#[utoipa::path(
+    get,
+    path = "/oi-changes",
+    tag = "snapshots",
+    params(OiChangesQuery),
+    responses(
+        (status = 200, description = "거래소별 OI 증가/감소 상위 목록과 심볼별 합산 변화"),
+        (status = 400, description = "잘못된 window 형식")
+    )
+)]
+async fn oi_changes_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<OiChangesQuery>,
+) -> impl IntoResponse {
+    let window_str = query.window.unwrap_or_else(|| "1h".to_string());
+    let Some(window) = parse_window(&window_str) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": format!("invalid window: {}", window_str) })),
+        );
+    };
+    let limit = query.limit.unwrap_or(20);
+
+    let mut changes = state.oi_history.read().await.changes(window, Utc::now());
+    let mut symbols = aggregate_by_symbol(&changes);
+    changes.sort_by(|a, b| b.change_pct.total_cmp(&a.change_pct));
+    symbols.sort_by(|a, b| b.change_pct.total_cmp(&a.change_pct));
+
+    let increases: Vec<_> = changes
+        .iter()
+        .filter(|c| c.change_usd > 0.0)
+        .take(limit)
+        .collect();
+    let decreases: Vec<_> = changes
+        .iter()
+        .rev()
+        .filter(|c| c.change_usd < 0.0)
+        .take(limit)
+        .collect();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "window": window_str,
+            "window_secs": window.num_seconds(),
+            "increases": increases,
+            "decreases": decreases,
+            "symbols": symbols,
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct FundingCalendarQuery {
+    /// 조회 기간 (시간, 기본 24, 최대 168)
+    hours: Option<i64>,
+    /// 거래소 필터 (예: Binance)
+    exchange: Option<String>,
+    /// 심볼 필터 (예: BTCUSDT)
+    symbol: Option<String>,
+}
+
+/// 앞으로 예정된 거래소/심볼별 펀딩 정산 시각 (시각 오름차순)
+#[utoipa::path(
+    get,
+    path = "/funding-calendar",
+    tag = "snapshots",
+    params(FundingCalendarQuery),
+    responses(
+        (status = 200, description = "예정된 펀딩 정산 목록")
+    )
+)]
+async fn funding_calendar_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<FundingCalendarQuery>,
+) -> impl IntoResponse {
+    let hours = query.hours.unwrap_or(24).clamp(1, 168);
+    let snapshots: Vec<PerpSnapshot> = state
+        .perp_snapshots
+        .read()
+        .await
+        .iter()
+        .filter(|s| {
+            query
+                .exchange
+                .as_deref()
+                .is_none_or(|ex| format!("{:?}", s.exchange).eq_ignore_ascii_case(ex))
+        })
+        .filter(|s| {
+            query
+                .symbol
+                .as_deref()
+                .is_none_or(|sym| s.symbol.eq_ignore_ascii_case(sym))
+        })
+        .cloned()
+        .collect();
+
+    let events = build_calendar(&snapshots, Utc::now(), chrono::Duration::hours(hours));
+    Json(serde_json::json!({
+        "hours": hours,
+        "events": events,
+    }))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct FundingHistoryQuery {
+    /// 거래소 (예: Binance)
+    exchange: String,
+    /// 심볼 (예: BTCUSDT)
+    symbol: String,
+    /// 조회 기간 (예: 1h, 7d). 기본 24h
+    window: Option<String>,
+    /// 샘플링 간격 (예: 10m, 1h). 없으면 변화 지점만 반환
+    step: Option<String>,
+}
+
+/// 펀딩비 기록 조회. step이 있으면 일정 간격으로 다시 샘플링하고, 없으면 변화 지점만 반환
+#[utoipa::path(
+    get,
+    path = "/funding-history",
+    tag = "snapshots",
+    params(FundingHistoryQuery),
+    responses(
+        (status = 200, description = "펀딩비 변화 지점 또는 샘플링한 시계열"),
+        (status = 400, description = "잘못된 exchange/window/step")
+    )
+)]
+async fn funding_history_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<FundingHistoryQuery>,
+) -> impl IntoResponse {
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": message })),
+        )
+    };
+
+    let Some(exchange) = [
+        ExchangeId::Binance,
+        ExchangeId::Bybit,
+        ExchangeId::Okx,
+        ExchangeId::Bitget,
+        ExchangeId::Bithumb,
+    ]
+    .into_iter()
+    .find(|ex| format!("{:?}", ex).eq_ignore_ascii_case(&query.exchange)) else {
+        return bad_request(format!("invalid exchange: {}", query.exchange));
+    };
+    let window_str = query.window.unwrap_or_else(|| "24h".to_string());
+    let Some(window) = parse_window(&window_str) else {
+        return bad_request(format!("invalid window: {}", window_str));
+    };
+    let step = match query.step.as_deref() {
+        Some(s) => match parse_window(s) {
+            Some(step) => Some(step),
+            None => return bad_request(format!("invalid step: {}", s)),
+        },
+        None => None,
+    };
+
+    let to = Utc::now();
+    let from = to - window;
+    let symbol = query.symbol.to_uppercase();
+    let history = state.funding_history.read().await;
+    let body = match step {
+        Some(step) => serde_json::json!({
+            "exchange": exchange,
+            "symbol": symbol,
+            "window": window_str,
+            "step_secs": step.num_seconds(),
+            "samples": history.resample(exchange, &symbol, from, to, step),
+        }),
+        None => serde_json::json!({
+            "exchange": exchange,
+            "symbol": symbol,
+            "window": window_str,
+            "points": history.points(exchange, &symbol, from, to),
+            "stats": history.stats(),
+        }),
+    };
+    (StatusCode::OK, Json(body))
+}
+
+/// 차트 한 번에 돌려주는 최대 버킷 수
+const MAX_BASIS_BUCKETS: i64 = 10_000;
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct BasisHistoryQuery {
+    /// 심볼 (예: BTCUSDT)
+    symbol: String,
+    /// "binance"(같은 거래소 현물/선물) 또는 "okx:binance"(현물 거래소:선물 거래소). 기본 binance
+    venue_pair: Option<String>,
+    /// 버킷 크기 (예: 1m, 15m, 1h). 기본 1m
+    resolution: Option<String>,
+    /// 조회 기간 (예: 6h, 7d). 기본 24h
+    window: Option<String>,
+}
+
+/// 저장된 현물/선물 가격으로 계산한 베이시스 차트 데이터
+/// 버킷마다 처음/마지막/평균과 함께 최소/최대를 내려 평균에 가려지는 급변도 보이게 합니다.
+#[utoipa::path(
+    get,
+    path = "/basis-history",
+    tag = "snapshots",
+    params(BasisHistoryQuery),
+    responses(
+        (status = 200, description = "버킷별 베이시스(bps) first/last/min/max/mean/count"),
+        (status = 400, description = "잘못된 venue_pair/resolution/window 또는 버킷 수 초과"),
+        (status = 503, description = "시계열 기록이 꺼져 있음 (ORACLE_TIMESERIES_DIR)")
+    )
+)]
+async fn basis_history_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<BasisHistoryQuery>,
+) -> impl IntoResponse {
+    let error = |status: StatusCode, message: String| {
+        (status, Json(serde_json::json!({ "error": message })))
+    };
+
+    let Some(market_store) = state.market_store.clone() else {
+        return error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "time series recording is disabled (set ORACLE_TIMESERIES_DIR)".to_string(),
+        );
+    };
+    let venue_pair = query.venue_pair.unwrap_or_else(|| "binance".to_string());
+    let Some((spot_exchange, perp_exchange)) = parse_venue_pair(&venue_pair) else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            format!("invalid venue_pair: {}", venue_pair),
+        );
+    };
+    let resolution_str = query.resolution.unwrap_or_else(|| "1m".to_string());
+    let Some(resolution) = parse_window(&resolution_str) else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            format!("invalid resolution: {}", resolution_str),
+        );
+    };
+    let window_str = query.window.unwrap_or_else(|| "24h".to_string());
+    let Some(window) = parse_window(&window_str) else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            format!("invalid window: {}", window_str),
+        );
+    };
+    if window.num_seconds() / resolution.num_seconds() > MAX_BASIS_BUCKETS {
+        return error(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "too many buckets: window {} / resolution {} (max {})",
+                window_str, resolution_str, MAX_BASIS_BUCKETS
+            ),
+        );
+    }
+
+    let symbol = query.symbol.to_uppercase();
+    let to = Utc::now();
+    let from = to - window;
+    let spot_key = keys::spot_price(&exchange_name(spot_exchange), &symbol);
+    let perp_key = keys::perp_mark(&exchange_name(perp_exchange), &symbol);
+    let loaded = tokio::task::spawn_blocking(move || {
+        let store = market_store.store();
+        Ok::<_, timeseries::TimeSeriesError>((
+            store.range(&spot_key, from, to)?,
+            store.range(&perp_key, from, to)?,
+        ))
+    })
+    .await;
+    let (spot, perp) = match loaded {
+        Ok(Ok(series)) => series,
+        Ok(Err(e)) => return error(StatusCode::BAD_REQUEST, e.to_string()),
+        Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    };
+
+    let buckets = downsample(&basis_series(&spot, &perp), resolution);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "symbol": symbol,
+            "spot_exchange": spot_exchange,
+            "perp_exchange": perp_exchange,
+            "resolution": resolution_str,
+            "window": window_str,
+            "buckets": buckets,
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct FundingRankQuery {
+    /// 거래소 필터 (예: Binance)
+    exchange: Option<String>,
+    /// 심볼 필터 (예: BTCUSDT)
+    symbol: Option<String>,
+    /// 비교할 과거 기간 (예: 7d, 30d). 기본 30d
+    window: Option<String>,
+}
+
+/// 현재 펀딩비를 심볼별 과거 분포의 백분위로 환산 (50에서 먼 순)
+/// 같은 0.05%라도 평소 펀딩비가 높은 심볼에서는 평범하고, 낮은 심볼에서는 극단값으로 나타납니다.
+#[utoipa::path(
+    get,
+    path = "/funding-rank",
+    tag = "snapshots",
+    params(FundingRankQuery),
+    responses(
+        (status = 200, description = "거래소/심볼별 현재 펀딩비 백분위와 과거 분포 요약"),
+        (status = 400, description = "잘못된 window"),
+        (status = 503, description = "시계열 기록이 꺼져 있음 (ORACLE_TIMESERIES_DIR)")
+    )
+)]
+async fn funding_rank_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<FundingRankQuery>,
+) -> impl IntoResponse {
+    let error = |status: StatusCode, message: String| {
+        (status, Json(serde_json::json!({ "error": message })))
+    };
+
+    let Some(market_store) = state.market_store.clone() else {
+        return error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "time series recording is disabled (set ORACLE_TIMESERIES_DIR)".to_string(),
+        );
+    };
+    let window_str = query.window.unwrap_or_else(|| "30d".to_string());
+    let Some(window) = parse_window(&window_str) else {
+        return error(
+            StatusCode::BAD_REQUEST,
+            format!("invalid window: {}", window_str),
+        );
+    };
+
+    let current: Vec<(ExchangeId, String, f64)> = state
+        .perp_snapshots
+        .read()
+        .await
+        .iter()
+        .filter(|s| {
+            query
+                .exchange
+                .as_deref()
+                .is_none_or(|ex| format!("{:?}", s.exchange).eq_ignore_ascii_case(ex))
+        })
+        .filter(|s| {
+            query
+                .symbol
+                .as_deref()
+                .is_none_or(|sym| s.symbol.eq_ignore_ascii_case(sym))
+        })
+        .map(|s| (s.exchange, s.symbol.clone(), s.funding_rate))
+        .collect();
+
+    let to = Utc::now();
+    let from = to - window;
+    let ranked = tokio::task::spawn_blocking(move || {
+        let store = market_store.store();
+        let mut ranks = Vec::new();
+        let mut insufficient = Vec::new();
+        for (exchange, symbol, funding_rate) in current {
+            let key = keys::perp_funding(&exchange_name(exchange), &symbol);
+            let history = store.range(&key, from, to)?;
+            match rank_funding(exchange, &symbol, funding_rate, &history) {
+                Some(rank) => ranks.push(rank),
+                None => insufficient.push(format!("{:?}:{}", exchange, symbol)),
+            }
+        }
+        Ok::<_, timeseries::TimeSeriesError>((ranks, insufficient))
+    })
+    .await;
+    let (mut ranks, insufficient) = match ranked {
+        Ok(Ok(ranked)) => ranked,
+        Ok(Err(e)) => return error(StatusCode::BAD_REQUEST, e.to_string()),
+        Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    };
+    ranks.sort_by(|a, b| {
+        (b.percentile - 50.0)
+            .abs()
+            .total_cmp(&(a.percentile - 50.0).abs())
+    });
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "window": window_str,
+            "min_samples": MIN_RANK_SAMPLES,
+            "ranks": ranks,
+            "insufficient_history": insufficient,
+        })),
+    )
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct SnapshotAgesQuery {
+    /// 이 나이(초) 이상인 항목만 반환. 기본 0 (전체)
+    min_age_secs: Option<f64>,
+}
+
+/// 거래소/심볼별 스냅샷 나이 (오래된 순)
+/// 수집에 실패한 심볼은 이전 값이 유지되므로, 소비자는 이 값으로 신선도를 판단한다
+#[utoipa::path(
+    get,
+    path = "/snapshot-ages",
+    tag = "status",
+    params(SnapshotAgesQuery),
+    responses(
+        (status = 200, description = "선물/현물 스냅샷별 마지막 갱신 시각과 나이")
+    )
+)]
+async fn snapshot_ages_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SnapshotAgesQuery>,
+) -> impl IntoResponse {
+    let min_age_secs = query.min_age_secs.unwrap_or(0.0);
+    let perp = state.perp_snapshots.read().await;
+    let spot = state.spot_snapshots.read().await;
+    let ages: Vec<_> = snapshot_ages(&perp, &spot, Utc::now())
+        .into_iter()
+        .filter(|a| a.age_secs >= min_age_secs)
+        .collect();
+    Json(serde_json::json!({
+        "count": ages.len(),
+        "ages": ages,
+    }))
+}
+
+/// 외부 집계 서비스와의 펀딩비 대조 결과 (허용치를 넘은 항목은 차이가 큰 순)
+#[utoipa::path(
+    get,
+    path = "/funding-aggregator-check",
+    tag = "status",
+    responses(
+        (status = 200, description = "마지막 대조 결과"),
+        (status = 404, description = "대조 비활성 또는 아직 대조 전")
+    )
+)]
+async fn funding_aggregator_check_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    match state.aggregator_report.read().await.clone() {
+        Some(report) => (StatusCode::OK, Json(serde_json::json!(report))),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "aggregator check not available" })),
+        ),
+    }
+}
+
+/// 거래소별 선물-현물 베이시스 스트림
+/// 연결 직후 현재 스냅샷 기준 프레임을 한 번 보내고, 이후 수집 주기마다 새 프레임을 보냅니다.
+async fn basis_ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| basis_ws_session(socket, state))
+}
+
+async fn basis_ws_session(mut socket: WebSocket, state: Arc<AppState>) {
+    let mut rx = state.basis_tx.subscribe();
+
+    let initial = compute_basis_frame(&state.unified_snapshots.read().await);
+    if send_frame(&mut socket, &initial).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            frame = rx.recv() => match frame {
+                Ok(frame) => {
+                    if send_frame(&mut socket, &frame).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("basis ws 구독자가 {}개 프레임을 놓침", n);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                _ => {}
+            },
+        }
+    }
+}
+
+async fn send_frame(socket: &mut WebSocket, frame: &BasisFrame) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(frame).unwrap_or_default();
+    socket.send(Message::Text(text)).await
+}
+
+/// `/unified-snapshots` 응답 형식 설명 (소비자가 버전 호환성을 확인하는 용도)
+#[utoipa::path(
+    get,
+    path = "/schema",
+    tag = "status",
+    responses(
+        (status = 200, description = "UnifiedSnapshot 응답 형식과 schema_version")
+    )
+)]
+async fn schema_handler() -> impl IntoResponse {
+    let exchange_rates = serde_json::json!({
+        "usd_krw": "f64 (1 USD = ? KRW)",
+        "usdt_usd": "f64 (1 USDT = ? USD)",
+        "usdt_krw": "f64 (1 USDT = ? KRW)",
+        "updated_at": "RFC3339 datetime",
+    });
+
+    Json(serde_json::json!({
+        "name": "UnifiedSnapshot",
+        "schema_version": UNIFIED_SNAPSHOT_SCHEMA_VERSION,
+        "compatibility": "새 필드는 항상 기본값과 함께 추가되며, schema_version이 없는 페이로드는 버전 1로 취급합니다.",
+        "fields": {
+            "schema_version": "u32",
+            "exchange": ["Binance", "Bybit", "Okx", "Bitget", "Bithumb"],
+            "symbol": "string",
+            "currency": ["USD", "KRW", "USDT"],
+            "perp": {
+                "optional": true,
+                "fields": {
+                    "currency": "Currency",
+                    "mark_price": "f64",
+                    "index_price": "f64 | null (v3)",
+                    "oi_usd": "f64",
+                    "vol_24h_usd": "f64",
+                    "funding_rate": "f64 (0.01 == 1%)",
+                    "next_funding_time": "RFC3339 datetime | null",
+                    "predicted_funding_rate": "f64 | null (v3, 프리미엄 인덱스 TWAP 기반 예측)",
+                },
+            },
+            "spot": {
+                "optional": true,
+                "fields": {
+                    "currency": "Currency",
+                    "price": "f64",
+                    "vol_24h_usd": "f64",
+                },
+            },
+            "basis_bps": "f64 | null (v4, 같은 거래소 현물 대비 선물 마크 가격, bps)",
+            "cross_basis_bps": "f64 | null (v4, 인덱스 가격 대비 선물 마크 가격, bps)",
+            "exchange_rates": exchange_rates,
+            "quality": {
+                "optional": true,
+                "fields": {
+                    "score": "f64 (v5, 0.0 ~ 1.0, 나이는 수집 시점 기준 초)",
+                    "perp_age_secs": "f64 | null",
+                    "spot_age_secs": "f64 | null",
+                    "exchange_rate_age_secs": "f64",
+                    "perp_source": "\"rest\" | \"web_socket\" | null",
+                    "spot_source": "\"rest\" | \"web_socket\" | null",
+                    "flags": ["stale_perp", "stale_spot", "stale_exchange_rate", "parse_anomaly", "rest_fallback"],
+                },
+            },
+            "updated_at": "RFC3339 datetime",
+        },
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "status",
+    responses(
+        (status = 200, description = "서버 동작 여부")
+    )
+)]
 async fn health_handler() -> impl IntoResponse {
     Json(serde_json::json!({ "status": "ok" }))
 }
 
+/// 거래소별 REST/WebSocket 연결 상태, 백그라운드 태스크 상태, 페이로드 파싱 실패 통계, 요청 가중치 사용량.
+/// 하나라도 비정상이면 503
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    tag = "status",
+    responses(
+        (status = 200, description = "모든 거래소 연결 및 백그라운드 태스크 정상"),
+        (status = 503, description = "하나 이상의 거래소 연결 또는 백그라운드 태스크 비정상")
+    )
+)]
+async fn healthz_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let exchanges = state.exchange_status.read().await.clone();
+    let tasks = exchanges::supervisor::task_supervisor().report();
+    let healthy = !exchanges.is_empty() && exchanges.iter().all(|e| e.healthy) && tasks.healthy;
+    let code = if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        code,
+        Json(serde_json::json!({
+            "status": if healthy { "ok" } else { "degraded" },
+            "exchanges": exchanges,
+            "tasks": tasks.tasks,
+            "strict_parse": interface::parse::strict_parse(),
+            "parse_failures": interface::parse::parse_failure_stats(),
+            "request_weight": exchanges::weight::weight_tracker().report(),
+        })),
+    )
+}
+
 pub async fn serve(state: Arc<AppState>, port: u16) -> eyre::Result<()> {
     let app = Router::new()
         .route("/health", get(health_handler))
+        .route("/healthz", get(healthz_handler))
         .route("/snapshots", get(snapshots_handler))
         .route("/spot-snapshots", get(spot_snapshots_handler))
         .route("/unified-snapshots", get(unified_snapshots_handler))
+        .route("/oi-changes", get(oi_changes_handler))
+        .route("/schema", get(schema_handler))
+        .route("/funding-calendar", get(funding_calendar_handler))
+        .route("/funding-history", get(funding_history_handler))
+        .route("/basis-history", get(basis_history_handler))
+        .route("/funding-rank", get(funding_rank_handler))
+        .route("/snapshot-ages", get(snapshot_ages_handler))
+        .route(
+            "/funding-aggregator-check",
+            get(funding_aggregator_check_handler),
+        )
+        .route("/ws/basis", get(basis_ws_handler))
+        .merge(SwaggerUi::new("/swagger-ui").url("/openapi.json", ApiDoc::openapi()))
         .layer(CorsLayer::permissive())
         .with_state(state);
 
@@ -0,0 +1,112 @@
+//! 수집 데이터 디스크 기록 (`timeseries` 크레이트)
+//!
+//! `ORACLE_TIMESERIES_DIR`를 지정하면 수집 주기마다 실제로 받은 선물 마크 가격/펀딩비와
+//! 현물 가격을 시리즈별 세그먼트 파일에 추가한다. 키 형식은 `timeseries::keys`를 따르므로
+//! 트레이드 백테스트가 같은 디렉터리를 그대로 읽을 수 있다.
+
+use chrono::{DateTime, Duration, Utc};
+use tracing::{info, warn};
+
+use interface::{ExchangeId, PerpSnapshot, SpotSnapshot};
+use timeseries::{keys, Point, StoreConfig, TimeSeriesStore};
+
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+/// 보관 기간 정리 주기
+pub const RETENTION_SWEEP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3600);
+
+/// 시장 데이터 시계열 기록기
+#[derive(Debug, Clone)]
+pub struct MarketStore {
+    store: TimeSeriesStore,
+}
+
+/// 시리즈 키에 쓰는 거래소 이름
+pub fn exchange_name(exchange: ExchangeId) -> String {
+    format!("{:?}", exchange).to_lowercase()
+}
+
+impl MarketStore {
+    pub fn new(store: TimeSeriesStore) -> Self {
+        Self { store }
+    }
+
+    /// `ORACLE_TIMESERIES_DIR`(없으면 기록 안 함), `ORACLE_TIMESERIES_RETENTION_DAYS`(기본 30일)
+    pub fn from_env() -> Option<Self> {
+        let dir = std::env::var("ORACLE_TIMESERIES_DIR").ok()?;
+        let retention_days = std::env::var("ORACLE_TIMESERIES_RETENTION_DAYS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|days| *days > 0)
+            .unwrap_or(DEFAULT_RETENTION_DAYS);
+        let config = StoreConfig {
+            retention: Some(Duration::days(retention_days)),
+            ..StoreConfig::default()
+        };
+        match TimeSeriesStore::open(&dir, config) {
+            Ok(store) => {
+                info!(
+                    "시계열 기록: {} (보관 {}일)",
+                    store.root().display(),
+                    retention_days
+                );
+                Some(Self::new(store))
+            }
+            Err(e) => {
+                warn!("시계열 저장소 열기 실패, 기록하지 않음: {} ({})", dir, e);
+                None
+            }
+        }
+    }
+
+    pub fn store(&self) -> &TimeSeriesStore {
+        &self.store
+    }
+
+    /// 선물 마크 가격과 펀딩비 기록 (블로킹 I/O)
+    pub fn record_perp(&self, snapshots: &[PerpSnapshot], now: DateTime<Utc>) {
+        let mut failed = 0;
+        for snapshot in snapshots {
+            let exchange = exchange_name(snapshot.exchange);
+            let mark = self.store.append(
+                &keys::perp_mark(&exchange, &snapshot.symbol),
+                Point::new(now, snapshot.mark_price.value()),
+            );
+            let funding = self.store.append(
+                &keys::perp_funding(&exchange, &snapshot.symbol),
+                Point::new(now, snapshot.funding_rate),
+            );
+            failed += usize::from(mark.is_err()) + usize::from(funding.is_err());
+        }
+        if failed > 0 {
+            warn!("선물 시계열 기록 실패 {}건", failed);
+        }
+    }
+
+    /// 현물 가격 기록 (블로킹 I/O)
+    pub fn record_spot(&self, snapshots: &[SpotSnapshot], now: DateTime<Utc>) {
+        let failed = snapshots
+            .iter()
+            .filter(|snapshot| {
+                self.store
+                    .append(
+                        &keys::spot_price(&exchange_name(snapshot.exchange), &snapshot.symbol),
+                        Point::new(now, snapshot.price.value()),
+                    )
+                    .is_err()
+            })
+            .count();
+        if failed > 0 {
+            warn!("현물 시계열 기록 실패 {}건", failed);
+        }
+    }
+
+    /// 보관 기간이 지난 세그먼트 삭제 (블로킹 I/O)
+    pub fn enforce_retention(&self, now: DateTime<Utc>) {
+        match self.store.enforce_retention(now) {
+            Ok(0) => {}
+            Ok(removed) => info!("보관 기간이 지난 시계열 세그먼트 {}개 삭제", removed),
+            Err(e) => warn!("시계열 보관 기간 정리 실패: {}", e),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! 스냅샷 변화 웹훅
+//!
+//! WebSocket 연결을 유지할 수 없는 하위 시스템을 위해, 수집 주기마다 계산한 베이시스 프레임
+//! (`/ws/basis`와 같은 데이터)에서 필터에 걸린 심볼의 펀딩비 또는 베이시스가 마지막으로 보낸
+//! 값보다 `min_change_bps` 이상 변하면 바뀐 항목만 모아 POST한다. 전송에 실패하면 기준값을
+//! 갱신하지 않아 다음 주기에 다시 보낸다.
+//!
+//! `oracle.json`의 `webhooks` 항목으로 설정한다.
+//! ```json
+//! {
+//!   "exchanges": [ ... ],
+//!   "webhooks": [
+//!     { "url": "http://localhost:9000/hook", "symbols": ["BTC", "ETHUSDT"], "min_change_bps": 2.0 }
+//!   ]
+//! }
+//! ```
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{info, warn};
+
+use crate::basis::BasisFrame;
+use crate::filter::SymbolFilter;
+use interface::ExchangeId;
+
+const POST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 웹훅 하나의 설정
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// 대상 심볼 (`BTCUSDT` 또는 `BTC`, 비어 있으면 전체)
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    /// 펀딩비/베이시스 최소 변화 (bps)
+    #[serde(default)]
+    pub min_change_bps: f64,
+}
+
+impl WebhookConfig {
+    fn filter(&self) -> SymbolFilter {
+        SymbolFilter {
+            include: self
+                .symbols
+                .iter()
+                .map(|s| s.trim().to_uppercase())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            ..SymbolFilter::default()
+        }
+    }
+}
+
+/// 거래소/심볼 하나의 변화
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SnapshotChange {
+    pub symbol: String,
+    pub exchange: ExchangeId,
+    pub funding_rate: f64,
+    /// 마지막으로 보낸 값 대비 펀딩비 변화 (bps, 처음 보내는 항목은 None)
+    pub funding_change_bps: Option<f64>,
+    pub basis_bps: f64,
+    /// 마지막으로 보낸 값 대비 베이시스 변화 (bps, 처음 보내는 항목은 None)
+    pub basis_change_bps: Option<f64>,
+}
+
+/// 웹훅으로 보내는 본문
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotDiff {
+    pub generated_at: DateTime<Utc>,
+    pub changes: Vec<SnapshotChange>,
+}
+
+/// 마지막으로 보낸 (펀딩비, 베이시스 bps)
+type Sent = HashMap<(String, ExchangeId), (f64, f64)>;
+
+/// 웹훅 하나의 변화 감지 상태
+#[derive(Debug)]
+pub struct DiffTracker {
+    filter: SymbolFilter,
+    min_change_bps: f64,
+    sent: Sent,
+}
+
+impl DiffTracker {
+    pub fn new(config: &WebhookConfig) -> Self {
+        Self {
+            filter: config.filter(),
+            min_change_bps: config.min_change_bps.max(0.0),
+            sent: HashMap::new(),
+        }
+    }
+
+    /// 마지막으로 보낸 값보다 기준 이상 바뀐 항목 (처음 보는 항목 포함)
+    pub fn diff(&self, frame: &BasisFrame) -> Vec<SnapshotChange> {
+        let mut changes = Vec::new();
+        for symbol in frame
+            .symbols
+            .iter()
+            .filter(|s| self.filter.allows_symbol(&s.symbol))
+        {
+            for venue in &symbol.venues {
+                let key = (symbol.symbol.clone(), venue.exchange);
+                let basis = venue.basis_bps.value();
+                let (funding_change, basis_change) = match self.sent.get(&key) {
+                    Some((funding, prev_basis)) => {
+                        let funding_change = (venue.funding_rate - funding) * 10_000.0;
+                        let basis_change = basis - prev_basis;
+                        if funding_change.abs() < self.min_change_bps
+                            && basis_change.abs() < self.min_change_bps
+                        {
+                            continue;
+                        }
+                        (Some(funding_change), Some(basis_change))
+                    }
+                    None => (None, None),
+                };
+                changes.push(SnapshotChange {
+                    symbol: symbol.symbol.clone(),
+                    exchange: venue.exchange,
+                    funding_rate: venue.funding_rate,
+                    funding_change_bps: funding_change,
+                    basis_bps: basis,
+                    basis_change_bps: basis_change,
+                });
+            }
+        }
+        changes
+    }
+
+    /// 전송에 성공한 항목을 다음 비교 기준으로 기록
+    pub fn commit(&mut self, changes: &[SnapshotChange]) {
+        for change in changes {
+            self.sent.insert(
+                (change.symbol.clone(), change.exchange),
+                (change.funding_rate, change.basis_bps),
+            );
+        }
+    }
+}
+
+/// 설정된 웹훅마다 베이시스 프레임을 구독해 변화를 POST하는 태스크 시작
+pub fn start_webhooks(webhooks: Vec<WebhookConfig>, basis_tx: &broadcast::Sender<Arc<BasisFrame>>) {
+    if webhooks.is_empty() {
+        return;
+    }
+    let http = exchanges::http::http_client_with_timeout(POST_TIMEOUT);
+    for config in webhooks {
+        info!(
+            "스냅샷 웹훅: {} (심볼 {}개, 최소 변화 {} bps)",
+            config.url,
+            config.symbols.len(),
+            config.min_change_bps
+        );
+        let mut rx = basis_tx.subscribe();
+        let http = http.clone();
+        tokio::spawn(async move {
+            let mut tracker = DiffTracker::new(&config);
+            loop {
+                let frame = match rx.recv().await {
+                    Ok(frame) => frame,
+                    // 밀린 프레임은 건너뛰고 최신 프레임부터 비교
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                let changes = tracker.diff(&frame);
+                if changes.is_empty() {
+                    continue;
+                }
+                let body = SnapshotDiff {
+                    generated_at: frame.generated_at,
+                    changes,
+                };
+                let result = http
+                    .post(&config.url)
+                    .json(&body)
+                    .send()
+                    .await
+                    .and_then(|res| res.error_for_status());
+                match result {
+                    Ok(_) => tracker.commit(&body.changes),
+                    Err(e) => warn!("스냅샷 웹훅 전송 실패 ({}): {}", config.url, e),
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basis::{SymbolBasis, VenueBasis};
+    use interface::{Bps, Price};
+
+    fn frame(symbol: &str, funding_rate: f64, basis_bps: f64) -> BasisFrame {
+        let venue = VenueBasis {
+            exchange: ExchangeId::Binance,
+            perp_price: Price::new(100.0),
+            spot_price: Price::new(100.0),
+            basis_bps: Bps::new(basis_bps),
+            funding_rate,
+        };
+        BasisFrame {
+            generated_at: Utc::now(),
+            symbols: vec![SymbolBasis {
+                symbol: symbol.to_string(),
+                max_exchange: venue.exchange,
+                max_basis_bps: venue.basis_bps,
+                min_exchange: venue.exchange,
+                min_basis_bps: venue.basis_bps,
+                spread_bps: Bps::new(0.0),
+                venues: vec![venue],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_diff_tracker_threshold_and_filter() {
+        let mut tracker = DiffTracker::new(&WebhookConfig {
+            url: String::new(),
+            symbols: vec!["btc".to_string()],
+            min_change_bps: 2.0,
+        });
+        assert!(tracker.diff(&frame("ETHUSDT", 0.0001, 5.0)).is_empty());
+
+        // 처음 보는 항목은 바로 전송
+        let first = tracker.diff(&frame("BTCUSDT", 0.0001, 5.0));
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].basis_change_bps, None);
+        tracker.commit(&first);
+
+        // 기준 미만 변화는 무시, 보내지 않은 변화는 누적되어 비교
+        assert!(tracker.diff(&frame("BTCUSDT", 0.00011, 6.5)).is_empty());
+        let changes = tracker.diff(&frame("BTCUSDT", 0.0001, 7.0));
+        assert_eq!(changes.len(), 1);
+        assert!((changes[0].basis_change_bps.unwrap() - 2.0).abs() < 1e-9);
+
+        // 전송 실패(commit 안 함)면 다음 주기에도 같은 기준으로 비교
+        let changes = tracker.diff(&frame("BTCUSDT", 0.0004, 5.0));
+        assert!((changes[0].funding_change_bps.unwrap() - 3.0).abs() < 1e-9);
+        tracker.commit(&changes);
+        assert!(tracker.diff(&frame("BTCUSDT", 0.0004, 5.0)).is_empty());
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::Point;
+
+/// `base`의 각 점에 그 시각 이전(같은 시각 포함) 가장 가까운 `other` 값을 붙임
+///
+/// 서로 다른 주기로 기록된 두 시리즈(예: 선물 마크와 현물 가격)를 맞출 때 쓴다.
+/// 앞선 `other` 값이 없거나 `max_gap`보다 오래됐으면 그 점은 버린다. 두 입력 모두 시각순이어야 한다.
+pub fn asof_join(
+    base: &[Point],
+    other: &[Point],
+    max_gap: Duration,
+) -> Vec<(DateTime<Utc>, f64, f64)> {
+    base.iter()
+        .filter_map(|point| {
+            let idx = other.partition_point(|o| o.time <= point.time);
+            let matched = other.get(idx.checked_sub(1)?)?;
+            (point.time - matched.time <= max_gap).then_some((
+                point.time,
+                point.value,
+                matched.value,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asof_join_drops_stale_and_leading_points() {
+        let at =
+            |ms: i64, value: f64| Point::new(DateTime::from_timestamp_millis(ms).unwrap(), value);
+        let other = [at(1_000, 1.0), at(5_000, 2.0)];
+        let base = [
+            at(500, 10.0),
+            at(1_000, 11.0),
+            at(6_000, 12.0),
+            at(70_000, 13.0),
+        ];
+        let joined = asof_join(&base, &other, Duration::seconds(60));
+        let values: Vec<(f64, f64)> = joined.iter().map(|(_, b, o)| (*b, *o)).collect();
+        assert_eq!(values, vec![(11.0, 1.0), (12.0, 2.0)]);
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::Point;
+
+/// 다운샘플링 버킷 하나 (평균만 보면 가려지는 급변을 min/max로 남김)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Bucket {
+    /// 버킷 시작 시각 (epoch 기준 step 배수로 정렬)
+    pub start: DateTime<Utc>,
+    pub first: f64,
+    pub last: f64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub count: usize,
+}
+
+/// 시각순 점들을 `step` 간격 버킷으로 묶음 (점이 없는 버킷은 생략, 유한하지 않은 값은 무시)
+pub fn downsample(points: &[Point], step: Duration) -> Vec<Bucket> {
+    let step_ms = step.num_milliseconds();
+    if step_ms <= 0 {
+        return Vec::new();
+    }
+    let mut buckets: Vec<Bucket> = Vec::new();
+    let mut sum = 0.0;
+    for point in points.iter().filter(|p| p.value.is_finite()) {
+        let ms = point.time.timestamp_millis();
+        let start_ms = ms - ms.rem_euclid(step_ms);
+        match buckets.last_mut() {
+            Some(bucket) if bucket.start.timestamp_millis() == start_ms => {
+                bucket.last = point.value;
+                bucket.min = bucket.min.min(point.value);
+                bucket.max = bucket.max.max(point.value);
+                bucket.count += 1;
+                sum += point.value;
+                bucket.mean = sum / bucket.count as f64;
+            }
+            _ => {
+                let Some(start) = DateTime::from_timestamp_millis(start_ms) else {
+                    continue;
+                };
+                sum = point.value;
+                buckets.push(Bucket {
+                    start,
+                    first: point.value,
+                    last: point.value,
+                    min: point.value,
+                    max: point.value,
+                    mean: point.value,
+                    count: 1,
+                });
+            }
+        }
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downsample_keeps_envelope() {
+        let t0 = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let values = [1.0, 9.0, 2.0, 3.0, f64::NAN, 5.0];
+        let points: Vec<Point> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| Point::new(t0 + Duration::seconds(i as i64 * 15), *v))
+            .collect();
+
+        let buckets = downsample(&points, Duration::minutes(1));
+        assert_eq!(buckets.len(), 2);
+        // 1_700_000_000은 분 경계에서 20초 지난 시각
+        assert_eq!(buckets[0].start, t0 - Duration::seconds(20));
+        assert_eq!(buckets[0].count, 3);
+        assert_eq!((buckets[0].min, buckets[0].max), (1.0, 9.0));
+        assert_eq!((buckets[0].first, buckets[0].last), (1.0, 2.0));
+        // NaN은 건너뜀
+        assert_eq!(buckets[1].count, 2);
+        assert_eq!((buckets[1].first, buckets[1].last), (3.0, 5.0));
+        assert!((buckets[1].mean - 4.0).abs() < 1e-12);
+
+        assert!(downsample(&points, Duration::zero()).is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+//! 시장 데이터 시계열 저장소
+//!
+//! 펀딩비, 현물/선물 가격, 베이시스처럼 시각-값 쌍으로 쌓이는 데이터를 오라클과 트레이드가
+//! 같은 형식으로 디스크에 보관하기 위한 크레이트.
+//!
+//! - 시리즈 키는 `/`로 구분한 경로 (예: `perp/binance/BTCUSDT/mark`)
+//! - 시리즈마다 일정 기간(기본 1일) 단위의 세그먼트 파일에 추가만 한다 (`{시작 epoch ms}.seg`)
+//! - 보관 기간이 지난 세그먼트는 파일 단위로 삭제
+//! - 구간 조회 후 `downsample`로 차트용 버킷(처음/마지막/최소/최대/평균) 생성
+//! - 주기가 다른 두 시리즈는 `asof_join`으로 시각을 맞춤
+
+pub mod align;
+pub mod downsample;
+pub mod store;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+pub use align::asof_join;
+pub use downsample::{downsample, Bucket};
+pub use store::{StoreConfig, TimeSeriesStore};
+
+/// 시계열 한 점
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub time: DateTime<Utc>,
+    pub value: f64,
+}
+
+impl Point {
+    pub fn new(time: DateTime<Utc>, value: f64) -> Self {
+        Self { time, value }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TimeSeriesError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid series key: {0}")]
+    InvalidKey(String),
+}
+
+pub type Result<T> = std::result::Result<T, TimeSeriesError>;
+
+/// 오라클이 기록하고 트레이드가 읽는 시장 데이터 시리즈 키
+pub mod keys {
+    /// 선물 마크 가격 (예: `perp/binance/BTCUSDT/mark`)
+    pub fn perp_mark(exchange: &str, symbol: &str) -> String {
+        format!("perp/{}/{}/mark", exchange.to_lowercase(), symbol)
+    }
+
+    /// 선물 펀딩비 (0.01 == 1%)
+    pub fn perp_funding(exchange: &str, symbol: &str) -> String {
+        format!("perp/{}/{}/funding", exchange.to_lowercase(), symbol)
+    }
+
+    /// 현물 가격 (거래소 표시 통화 기준)
+    pub fn spot_price(exchange: &str, symbol: &str) -> String {
+        format!("spot/{}/{}/price", exchange.to_lowercase(), symbol)
+    }
+}
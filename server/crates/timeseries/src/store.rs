@@ -0,0 +1,316 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{Point, Result, TimeSeriesError};
+
+const SEGMENT_EXT: &str = "seg";
+
+/// 저장소 설정
+#[derive(Debug, Clone, Copy)]
+pub struct StoreConfig {
+    /// 세그먼트 파일 하나가 담는 기간
+    pub segment: Duration,
+    /// 보관 기간 (None이면 삭제하지 않음)
+    pub retention: Option<Duration>,
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            segment: Duration::days(1),
+            retention: None,
+        }
+    }
+}
+
+/// 디스크 기반 추가 전용 시계열 저장소
+///
+/// 세그먼트 파일은 `{epoch ms},{value}` 줄의 나열이다. 추가는 파일 끝에 한 줄씩 쓰므로
+/// 여러 작업이 같은 시리즈에 써도 줄 단위로 섞이지 않으며, 비정상 종료로 잘린 줄은 조회 시 건너뛴다.
+#[derive(Debug, Clone)]
+pub struct TimeSeriesStore {
+    root: PathBuf,
+    config: StoreConfig,
+}
+
+impl TimeSeriesStore {
+    pub fn open(root: impl Into<PathBuf>, config: StoreConfig) -> Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        let config = StoreConfig {
+            segment: config.segment.max(Duration::minutes(1)),
+            ..config
+        };
+        Ok(Self { root, config })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn config(&self) -> StoreConfig {
+        self.config
+    }
+
+    /// 점 하나 추가
+    pub fn append(&self, key: &str, point: Point) -> Result<()> {
+        self.append_batch(key, &[point])
+    }
+
+    /// 여러 점 추가 (세그먼트별로 묶어 한 번씩 씀, 유한하지 않은 값은 버림)
+    pub fn append_batch(&self, key: &str, points: &[Point]) -> Result<()> {
+        let dir = self.series_dir(key)?;
+        let mut by_segment: BTreeMap<i64, String> = BTreeMap::new();
+        for point in points.iter().filter(|p| p.value.is_finite()) {
+            let ms = point.time.timestamp_millis();
+            by_segment
+                .entry(self.segment_start(ms))
+                .or_default()
+                .push_str(&format!("{},{}\n", ms, point.value));
+        }
+        if by_segment.is_empty() {
+            return Ok(());
+        }
+        fs::create_dir_all(&dir)?;
+        for (start, lines) in by_segment {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(segment_path(&dir, start))?;
+            file.write_all(lines.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// `from` 이상 `to` 이하 구간의 점 (시각순)
+    pub fn range(&self, key: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<Point>> {
+        let dir = self.series_dir(key)?;
+        let (from_ms, to_ms) = (from.timestamp_millis(), to.timestamp_millis());
+        let segment_ms = self.config.segment.num_milliseconds();
+        let mut points = Vec::new();
+        for start in segment_starts(&dir)? {
+            if start > to_ms || start + segment_ms <= from_ms {
+                continue;
+            }
+            let content = fs::read_to_string(segment_path(&dir, start))?;
+            points.extend(
+                content
+                    .lines()
+                    .filter_map(parse_line)
+                    .filter(|(ms, _)| (from_ms..=to_ms).contains(ms))
+                    .filter_map(|(ms, value)| {
+                        Some(Point::new(DateTime::from_timestamp_millis(ms)?, value))
+                    }),
+            );
+        }
+        points.sort_by_key(|p| p.time);
+        Ok(points)
+    }
+
+    /// 가장 최근 점
+    pub fn latest(&self, key: &str) -> Result<Option<Point>> {
+        let dir = self.series_dir(key)?;
+        for start in segment_starts(&dir)?.into_iter().rev() {
+            let content = fs::read_to_string(segment_path(&dir, start))?;
+            let latest = content
+                .lines()
+                .filter_map(parse_line)
+                .max_by_key(|(ms, _)| *ms)
+                .and_then(|(ms, value)| {
+                    Some(Point::new(DateTime::from_timestamp_millis(ms)?, value))
+                });
+            if latest.is_some() {
+                return Ok(latest);
+            }
+        }
+        Ok(None)
+    }
+
+    /// 저장된 시리즈 키 목록 (정렬)
+    pub fn series(&self) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        collect_series(&self.root, &self.root, &mut keys)?;
+        keys.sort();
+        Ok(keys)
+    }
+
+    /// 보관 기간이 지난 세그먼트 삭제, 삭제한 파일 수 반환
+    /// 세그먼트의 마지막 시각까지 보관 기간이 지나야 지우므로 최대 세그먼트 길이만큼 더 남을 수 있다.
+    pub fn enforce_retention(&self, now: DateTime<Utc>) -> Result<usize> {
+        let Some(retention) = self.config.retention else {
+            return Ok(0);
+        };
+        let cutoff_ms = (now - retention).timestamp_millis();
+        let segment_ms = self.config.segment.num_milliseconds();
+        let mut removed = 0;
+        for key in self.series()? {
+            let dir = self.series_dir(&key)?;
+            for start in segment_starts(&dir)? {
+                if start + segment_ms <= cutoff_ms {
+                    fs::remove_file(segment_path(&dir, start))?;
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    fn segment_start(&self, ms: i64) -> i64 {
+        let segment_ms = self.config.segment.num_milliseconds();
+        ms - ms.rem_euclid(segment_ms)
+    }
+
+    fn series_dir(&self, key: &str) -> Result<PathBuf> {
+        let mut dir = self.root.clone();
+        for part in key.split('/') {
+            let valid = !part.is_empty()
+                && part != "."
+                && part != ".."
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+            if !valid {
+                return Err(TimeSeriesError::InvalidKey(key.to_string()));
+            }
+            dir.push(part);
+        }
+        Ok(dir)
+    }
+}
+
+fn segment_path(dir: &Path, start: i64) -> PathBuf {
+    dir.join(format!("{}.{}", start, SEGMENT_EXT))
+}
+
+/// 디렉터리의 세그먼트 시작 시각 (오름차순, 시리즈가 없으면 빈 목록)
+fn segment_starts(dir: &Path) -> Result<Vec<i64>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut starts = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(SEGMENT_EXT) {
+            continue;
+        }
+        if let Some(start) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.parse::<i64>().ok())
+        {
+            starts.push(start);
+        }
+    }
+    starts.sort_unstable();
+    Ok(starts)
+}
+
+fn collect_series(root: &Path, dir: &Path, keys: &mut Vec<String>) -> Result<()> {
+    let mut has_segments = false;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_series(root, &path, keys)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some(SEGMENT_EXT) {
+            has_segments = true;
+        }
+    }
+    if has_segments {
+        if let Ok(relative) = dir.strip_prefix(root) {
+            let key: Vec<String> = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            keys.push(key.join("/"));
+        }
+    }
+    Ok(())
+}
+
+fn parse_line(line: &str) -> Option<(i64, f64)> {
+    let (ms, value) = line.split_once(',')?;
+    let value: f64 = value.trim().parse().ok()?;
+    value
+        .is_finite()
+        .then_some((ms.trim().parse().ok()?, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "timeseries-{}-{}-{}",
+            name,
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_append_range_and_retention() {
+        let root = temp_root("store");
+        let store = TimeSeriesStore::open(
+            &root,
+            StoreConfig {
+                segment: Duration::hours(1),
+                retention: Some(Duration::hours(3)),
+            },
+        )
+        .unwrap();
+        let t0 = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let key = "perp/binance/BTCUSDT/mark";
+
+        let points: Vec<Point> = (0..6)
+            .map(|i| Point::new(t0 + Duration::minutes(i * 30), 100.0 + i as f64))
+            .collect();
+        store.append_batch(key, &points[1..]).unwrap();
+        // 늦게 도착한 점도 시각순으로 조회
+        store.append(key, points[0]).unwrap();
+        store
+            .append("spot/bithumb/BTCKRW/price", Point::new(t0, f64::NAN))
+            .unwrap();
+
+        let all = store.range(key, t0, t0 + Duration::hours(3)).unwrap();
+        assert_eq!(all, points);
+        let middle = store
+            .range(key, t0 + Duration::minutes(30), t0 + Duration::minutes(90))
+            .unwrap();
+        assert_eq!(middle.len(), 3);
+        assert_eq!(store.latest(key).unwrap(), Some(points[5]));
+        // 값이 전부 버려진 시리즈는 만들어지지 않음
+        assert_eq!(store.series().unwrap(), vec![key.to_string()]);
+
+        // 잘린 줄은 건너뜀
+        let dir = store.series_dir(key).unwrap();
+        let last_segment = *segment_starts(&dir).unwrap().last().unwrap();
+        OpenOptions::new()
+            .append(true)
+            .open(segment_path(&dir, last_segment))
+            .unwrap()
+            .write_all(b"17000")
+            .unwrap();
+        assert_eq!(store.latest(key).unwrap(), Some(points[5]));
+
+        assert!(store.append("../escape", points[0]).is_err());
+        assert!(store.range("a//b", t0, t0).is_err());
+
+        let removed = store.enforce_retention(t0 + Duration::hours(5)).unwrap();
+        assert!(removed >= 1);
+        let kept = store.range(key, t0, t0 + Duration::hours(3)).unwrap();
+        assert!(kept.first().unwrap().time >= t0 + Duration::hours(1));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
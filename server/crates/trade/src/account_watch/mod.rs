@@ -0,0 +1,483 @@
+//! 계정 잔고 이상 변동 감지
+//!
+//! 우리가 낸 주문의 체결(주문 응답의 fills)로 예상한 자산별 잔고 변화와 User Data Stream의
+//! 실제 잔고(outboundAccountPosition)를 비교한다. 설명되지 않는 차이(수동 매매, API 키 도용,
+//! 입금/출금/이체)가 유예 시간이 지나도 허용 오차보다 크면 알림을 보내고, 설정 시 새 진입을
+//! 일시 정지한다. 예상 변화와 실제 잔고 이벤트는 도착 순서가 바뀔 수 있어 차이가 유예 시간 동안
+//! 이어질 때만 이상으로 본다. 지금은 Binance 현물 계정만 감시한다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::{error, info, warn};
+
+use exchanges::supervisor::{WS_STALE_AFTER, stop_restarting, task_supervisor};
+
+use crate::arbitrage::control::operator_control;
+use crate::notification::{AlertLevel, notification_center};
+use crate::trader::OrderResponse;
+use crate::trader::binance::{BinanceTrader, UserDataEvent};
+use crate::trader::quote::split_symbol;
+
+/// 잔고 이상 변동 알림 종류
+pub const ACCOUNT_ANOMALY_ALERT: &str = "account_anomaly";
+
+/// 메모리에 보관하는 최근 이상 변동 개수
+const DEFAULT_CAPACITY: usize = 200;
+
+/// 유예 시간이 지난 차이를 확인하는 간격
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 감시 설정
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct AccountWatchParams {
+    /// 잔고 대비 이 비율 이하의 차이는 무시 (0.001 = 0.1%)
+    pub tolerance_ratio: f64,
+    /// 이 수량 이하의 차이는 무시 (수수료 반올림 등)
+    pub min_amount: f64,
+    /// 차이가 이 시간 이상 설명되지 않으면 이상으로 판단
+    pub grace: Duration,
+    /// 이상 감지 시 새 진입 일시 정지
+    pub pause_on_anomaly: bool,
+}
+
+impl Default for AccountWatchParams {
+    fn default() -> Self {
+        Self {
+            tolerance_ratio: 0.001,
+            min_amount: 1e-8,
+            grace: Duration::from_secs(10),
+            pause_on_anomaly: false,
+        }
+    }
+}
+
+impl AccountWatchParams {
+    /// ACCOUNT_WATCH(필수, 1/true) / ACCOUNT_WATCH_TOLERANCE / ACCOUNT_WATCH_MIN_AMOUNT /
+    /// ACCOUNT_WATCH_GRACE_SECS / ACCOUNT_WATCH_PAUSE
+    /// 감시가 켜져 있지 않으면 None
+    pub fn from_env() -> Option<Self> {
+        fn var<T: std::str::FromStr>(key: &str) -> Option<T> {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<T>().ok())
+        }
+        let flag = |key: &str| {
+            std::env::var(key)
+                .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+                .unwrap_or(false)
+        };
+        if !flag("ACCOUNT_WATCH") {
+            return None;
+        }
+        let defaults = Self::default();
+        Some(Self {
+            tolerance_ratio: var::<f64>("ACCOUNT_WATCH_TOLERANCE")
+                .filter(|v| *v >= 0.0)
+                .unwrap_or(defaults.tolerance_ratio),
+            min_amount: var::<f64>("ACCOUNT_WATCH_MIN_AMOUNT")
+                .filter(|v| *v >= 0.0)
+                .unwrap_or(defaults.min_amount),
+            grace: var::<u64>("ACCOUNT_WATCH_GRACE_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.grace),
+            pause_on_anomaly: flag("ACCOUNT_WATCH_PAUSE"),
+        })
+    }
+
+    fn tolerance(&self, balance: f64) -> f64 {
+        self.min_amount.max(balance.abs() * self.tolerance_ratio)
+    }
+}
+
+/// 계정/자산별 잔고 장부
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetLedger {
+    /// 마지막으로 관찰한 총 잔고 (free + locked, 관찰 전이면 None)
+    pub balance: Option<f64>,
+    /// 차이가 생긴 뒤 우리 체결로 예상한 변화 합계
+    pub expected: f64,
+    /// 차이가 생긴 뒤 실제로 관찰한 변화 합계
+    pub observed: f64,
+    /// 차이가 처음 생긴 시각
+    pub residual_since: Option<DateTime<Utc>>,
+    /// 마지막 balanceUpdate 이벤트 (입금/출금/이체) 변화량
+    pub last_balance_update: Option<f64>,
+}
+
+impl AssetLedger {
+    /// 실제 변화 - 예상 변화 (0이면 모든 변화가 설명됨)
+    pub fn residual(&self) -> f64 {
+        self.observed - self.expected
+    }
+
+    fn clear_residual(&mut self) {
+        self.expected = 0.0;
+        self.observed = 0.0;
+        self.residual_since = None;
+        self.last_balance_update = None;
+    }
+}
+
+/// 감지된 이상 변동
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountAnomaly {
+    pub account: String,
+    pub asset: String,
+    /// 설명되지 않은 잔고 변화 (양수면 늘어남)
+    pub unexplained: f64,
+    /// 감지 시점 잔고
+    pub balance: Option<f64>,
+    /// 추정 원인
+    pub cause: String,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// 감시 상태 조회 결과 (`GET /account/anomalies`)
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountWatchReport {
+    /// 감시 중이 아니면 None
+    pub params: Option<AccountWatchParams>,
+    /// 아직 설명되지 않은 차이가 있는 자산 ("계정:자산")
+    pub pending: HashMap<String, AssetLedger>,
+    /// 최근 이상 변동 (최신순)
+    pub anomalies: Vec<AccountAnomaly>,
+}
+
+/// 주문 응답의 체결 내역으로 자산별 예상 잔고 변화 계산
+/// 매수는 베이스 +수량 / 견적 -금액, 매도는 반대이고, 수수료는 수수료 자산에서 뺀다
+pub fn fill_deltas(symbol: &str, side: &str, order: &OrderResponse) -> Vec<(String, f64)> {
+    let Some((base, quote)) = split_symbol(symbol) else {
+        return Vec::new();
+    };
+    let sign = if side.eq_ignore_ascii_case("BUY") {
+        1.0
+    } else {
+        -1.0
+    };
+    let num = |v: &serde_json::Value, key: &str| {
+        v.get(key)
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<f64>().ok())
+            .unwrap_or(0.0)
+    };
+
+    let mut deltas: Vec<(String, f64)> = Vec::new();
+    let mut add = |asset: &str, delta: f64| match deltas.iter_mut().find(|(a, _)| a == asset) {
+        Some((_, total)) => *total += delta,
+        None => deltas.push((asset.to_string(), delta)),
+    };
+    let fills = order.extra.get("fills").and_then(|v| v.as_array());
+    for fill in fills.into_iter().flatten() {
+        let qty = num(fill, "qty");
+        add(base, sign * qty);
+        add(quote, -sign * qty * num(fill, "price"));
+        if let Some(asset) = fill.get("commissionAsset").and_then(|v| v.as_str()) {
+            add(asset, -num(fill, "commission"));
+        }
+    }
+    deltas.retain(|(_, delta)| *delta != 0.0);
+    deltas
+}
+
+/// 전역 계정 감시기
+#[derive(Debug, Default)]
+pub struct AccountWatcher {
+    params: RwLock<Option<AccountWatchParams>>,
+    ledgers: RwLock<HashMap<(String, String), AssetLedger>>,
+    anomalies: RwLock<VecDeque<AccountAnomaly>>,
+    /// 시작 시 전체 잔고로 기준을 잡았으면 true (목록에 없던 자산은 0에서 시작한 것으로 본다)
+    seeded: AtomicBool,
+    started: AtomicBool,
+}
+
+impl AccountWatcher {
+    fn enabled(&self) -> bool {
+        self.params.read().unwrap().is_some()
+    }
+
+    /// 우리 체결로 예상되는 잔고 변화 반영 (감시 중이 아니면 무시)
+    pub fn expect(&self, account: &str, asset: &str, delta: f64) {
+        self.expect_at(account, asset, delta, Utc::now());
+    }
+
+    fn expect_at(&self, account: &str, asset: &str, delta: f64, now: DateTime<Utc>) {
+        if !self.enabled() {
+            return;
+        }
+        let mut ledgers = self.ledgers.write().unwrap();
+        let ledger = ledgers
+            .entry((account.to_string(), asset.to_string()))
+            .or_default();
+        ledger.expected += delta;
+        ledger.residual_since.get_or_insert(now);
+    }
+
+    /// 현물 주문 체결을 예상 변화로 반영
+    pub fn expect_spot_fill(&self, account: &str, symbol: &str, side: &str, order: &OrderResponse) {
+        for (asset, delta) in fill_deltas(symbol, side, order) {
+            self.expect(account, &asset, delta);
+        }
+    }
+
+    /// 실제 잔고 관찰. 처음 보는 자산은 기준 잔고로만 기록한다
+    pub fn observe_balance(&self, account: &str, asset: &str, balance: f64, now: DateTime<Utc>) {
+        let mut ledgers = self.ledgers.write().unwrap();
+        let ledger = ledgers
+            .entry((account.to_string(), asset.to_string()))
+            .or_default();
+        let previous = ledger
+            .balance
+            .or_else(|| self.seeded.load(Ordering::SeqCst).then_some(0.0));
+        if let Some(previous) = previous {
+            let change = balance - previous;
+            if change != 0.0 {
+                ledger.observed += change;
+                ledger.residual_since.get_or_insert(now);
+            }
+        }
+        ledger.balance = Some(balance);
+    }
+
+    /// User Data Stream 이벤트 처리
+    pub fn on_event(&self, account: &str, event: &UserDataEvent) {
+        let now = Utc::now();
+        match event {
+            UserDataEvent::OutboundAccountPosition(position) => {
+                for balance in &position.balances {
+                    let parse = |v: &str| v.parse::<f64>().unwrap_or(0.0);
+                    let total = parse(&balance.free) + parse(&balance.locked);
+                    self.observe_balance(account, &balance.asset, total, now);
+                }
+            }
+            UserDataEvent::BalanceUpdate(update) => {
+                let delta = update.balance_delta.parse::<f64>().ok();
+                let mut ledgers = self.ledgers.write().unwrap();
+                ledgers
+                    .entry((account.to_string(), update.asset.clone()))
+                    .or_default()
+                    .last_balance_update = delta;
+            }
+            UserDataEvent::ExecutionReport(_) | UserDataEvent::Unknown(_) => {}
+        }
+    }
+
+    /// 차이가 허용 오차 안으로 들어온 자산은 정리하고, 유예 시간이 지난 차이는 이상으로 보고
+    pub fn check(&self, now: DateTime<Utc>) -> Vec<AccountAnomaly> {
+        let Some(params) = *self.params.read().unwrap() else {
+            return Vec::new();
+        };
+        let grace = chrono::Duration::from_std(params.grace).unwrap_or_default();
+
+        let mut found = Vec::new();
+        for ((account, asset), ledger) in self.ledgers.write().unwrap().iter_mut() {
+            let Some(since) = ledger.residual_since else {
+                continue;
+            };
+            let residual = ledger.residual();
+            if residual.abs() <= params.tolerance(ledger.balance.unwrap_or(0.0)) {
+                ledger.clear_residual();
+                continue;
+            }
+            if now - since < grace {
+                continue;
+            }
+            let cause = match ledger.last_balance_update {
+                Some(delta) => format!("balanceUpdate {:+} (입금/출금/이체)", delta),
+                None if ledger.observed == 0.0 => "예상 체결이 잔고에 반영되지 않음".to_string(),
+                None => "기록되지 않은 체결 (수동 매매 또는 외부 주문)".to_string(),
+            };
+            found.push(AccountAnomaly {
+                account: account.clone(),
+                asset: asset.clone(),
+                unexplained: residual,
+                balance: ledger.balance,
+                cause,
+                detected_at: now,
+            });
+            // 같은 차이로 다시 알리지 않도록 현재 잔고를 새 기준으로 삼는다
+            ledger.clear_residual();
+        }
+
+        let mut anomalies = self.anomalies.write().unwrap();
+        for anomaly in &found {
+            anomalies.push_front(anomaly.clone());
+        }
+        anomalies.truncate(DEFAULT_CAPACITY);
+        found
+    }
+
+    /// 이상 변동 알림 (설정 시 새 진입 일시 정지)
+    async fn alert(&self, anomaly: &AccountAnomaly, params: &AccountWatchParams) {
+        let message = format!(
+            "[{}] {} 잔고가 설명되지 않게 {:+} 변동 (잔고 {:?}, {})",
+            anomaly.account, anomaly.asset, anomaly.unexplained, anomaly.balance, anomaly.cause
+        );
+        error!("계정 잔고 이상 변동: {}", message);
+        if params.pause_on_anomaly {
+            let state = operator_control().pause(&format!("account anomaly: {}", message));
+            warn!("계정 이상 변동으로 새 진입 일시 정지 ({})", state.reason);
+        }
+        notification_center()
+            .notify(
+                ACCOUNT_ANOMALY_ALERT,
+                AlertLevel::Critical,
+                format!("계정 잔고 이상 변동: {}", anomaly.asset),
+                message,
+                serde_json::to_value(anomaly).unwrap_or_default(),
+            )
+            .await;
+    }
+
+    pub fn report(&self) -> AccountWatchReport {
+        let pending = self
+            .ledgers
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, ledger)| ledger.residual_since.is_some())
+            .map(|((account, asset), ledger)| (format!("{}:{}", account, asset), ledger.clone()))
+            .collect();
+        AccountWatchReport {
+            params: *self.params.read().unwrap(),
+            pending,
+            anomalies: self.anomalies.read().unwrap().iter().cloned().collect(),
+        }
+    }
+
+    /// 감시 시작 (현물 잔고로 기준을 잡은 뒤 User Data Stream 구독)
+    pub fn start(&'static self, params: AccountWatchParams) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        *self.params.write().unwrap() = Some(params);
+        info!(
+            "계정 잔고 감시 시작 (허용 오차 {:.4}%, 유예 {}초, 이상 시 일시 정지: {})",
+            params.tolerance_ratio * 100.0,
+            params.grace.as_secs(),
+            params.pause_on_anomaly
+        );
+        tokio::spawn(async move {
+            let trader = match BinanceTrader::new() {
+                Ok(trader) => trader,
+                Err(e) => {
+                    error!("계정 잔고 감시: BinanceTrader 생성 실패: {}", e);
+                    return;
+                }
+            };
+            let account = trader.accounts.spot.label.clone();
+            match trader.spot.get_balances().await {
+                Ok(assets) => {
+                    let now = Utc::now();
+                    for asset in assets {
+                        self.observe_balance(&account, &asset.currency, asset.total, now);
+                    }
+                    self.seeded.store(true, Ordering::SeqCst);
+                }
+                Err(e) => warn!("계정 잔고 감시: 기준 잔고 조회 실패: {}", e),
+            }
+
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(CHECK_INTERVAL).await;
+                    for anomaly in self.check(Utc::now()) {
+                        self.alert(&anomaly, &params).await;
+                    }
+                }
+            });
+
+            // User Data Stream은 종료되거나 멈추면 감시기가 다시 구독
+            let trader = Arc::new(trader);
+            task_supervisor().spawn(
+                &format!("binance_user_stream_ws:{}", account),
+                Some(WS_STALE_AFTER),
+                move || {
+                    let trader = Arc::clone(&trader);
+                    let label = account.clone();
+                    async move {
+                        // API 키가 없으면 재시작해도 같은 실패라 감시기에 중단을 요청
+                        if !trader
+                            .user_stream
+                            .as_ref()
+                            .is_some_and(|stream| stream.has_credentials())
+                        {
+                            error!(
+                                "계정 잔고 감시: API 키가 없어 User Data Stream을 시작하지 않음"
+                            );
+                            stop_restarting("API key not set");
+                            return;
+                        }
+                        if let Err(e) = trader
+                            .start_user_data_stream(move |event| self.on_event(&label, &event))
+                            .await
+                        {
+                            error!("계정 잔고 감시: User Data Stream 시작 실패: {}", e);
+                        }
+                    }
+                },
+            );
+        });
+    }
+}
+
+static GLOBAL_ACCOUNT_WATCHER: OnceLock<AccountWatcher> = OnceLock::new();
+
+/// 전역 계정 감시기
+pub fn account_watcher() -> &'static AccountWatcher {
+    GLOBAL_ACCOUNT_WATCHER.get_or_init(AccountWatcher::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_fill_explains_balance_change() {
+        let watcher = AccountWatcher::default();
+        *watcher.params.write().unwrap() = Some(AccountWatchParams::default());
+        let t0 = Utc::now();
+        let later = t0 + chrono::Duration::seconds(30);
+
+        watcher.observe_balance("main", "BTC", 1.0, t0);
+        watcher.observe_balance("main", "USDT", 10_000.0, t0);
+
+        // 우리 매수 체결: 0.1 BTC @ 50,000, 수수료 0.0001 BTC
+        let order: OrderResponse = serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "fills": [{"price": "50000", "qty": "0.1", "commission": "0.0001", "commissionAsset": "BTC"}]
+        }))
+        .unwrap();
+        let deltas = fill_deltas("BTCUSDT", "BUY", &order);
+        assert_eq!(deltas.len(), 2);
+        assert!((deltas[0].1 - 0.0999).abs() < 1e-12);
+        assert!((deltas[1].1 + 5_000.0).abs() < 1e-9);
+
+        // 잔고 이벤트가 체결 기록보다 먼저 와도 유예 시간 안에 설명되면 정상
+        watcher.observe_balance("main", "BTC", 1.0999, t0);
+        watcher.expect_at("main", "BTC", 0.0999, t0);
+        watcher.expect_at("main", "USDT", -5_000.0, t0);
+        watcher.observe_balance("main", "USDT", 5_000.0, t0);
+        assert!(watcher.check(t0).is_empty());
+        assert!(watcher.check(later).is_empty());
+        assert!(watcher.report().pending.is_empty());
+
+        // 기록되지 않은 출금은 유예 시간이 지나면 이상으로 보고
+        watcher.observe_balance("main", "USDT", 4_000.0, later);
+        assert!(watcher.check(later).is_empty());
+        let anomalies = watcher.check(later + chrono::Duration::seconds(11));
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].asset, "USDT");
+        assert!((anomalies[0].unexplained + 1_000.0).abs() < 1e-9);
+        // 한 번 보고한 차이는 다시 알리지 않음
+        assert!(
+            watcher
+                .check(later + chrono::Duration::seconds(60))
+                .is_empty()
+        );
+        assert_eq!(watcher.report().anomalies.len(), 1);
+    }
+}
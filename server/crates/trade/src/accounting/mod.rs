@@ -0,0 +1,465 @@
+//! 거래 기록 기반 세무/회계 보고서 (자산별 실현 손익)
+//!
+//! 저장된 스팟 거래 기록을 시간순으로 훑으며 자산별 매수 lot과 매도를 매칭해
+//! 과세 연도(KST 기준)별 실현 손익을 계산한다. 취득가액 산정 방식은 선입선출(FIFO)과
+//! 이동평균(average cost) 중 고른다.
+//!
+//! - 선물 거래, 체결 가격이 없는 기록은 계산에서 제외하고 건수만 보고한다.
+//! - 수수료는 거래 기록에 없으므로 반영하지 않는다.
+//! - 견적 통화가 다른 거래(USDT/KRW)는 보고서에 지정한 단일 USDT/KRW 환율로 환산한다.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+use serde::Serialize;
+
+use crate::record::csv::escape_csv_field;
+use crate::record::{MarketType, StoredTradeRecord, TradeSide};
+use crate::trader::quote::split_symbol;
+
+/// 과세 연도 판정 기준 시간대 (KST, UTC+9)
+const KST_OFFSET_SECS: i32 = 9 * 3600;
+
+/// 매도 수량이 보유 lot보다 많을 때 남는 수량 허용 오차
+const QTY_EPSILON: f64 = 1e-12;
+
+/// 보고서 CSV 헤더 (줄바꿈 포함)
+pub const DISPOSAL_CSV_HEADER: &str = "record_id,executed_at,exchange,symbol,asset,quote,quantity,proceeds,cost_basis,gain,gain_krw,gain_usdt,unmatched_qty\r\n";
+
+/// 취득가액 산정 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CostBasisMethod {
+    /// 선입선출
+    Fifo,
+    /// 이동평균
+    AverageCost,
+}
+
+impl FromStr for CostBasisMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "fifo" => Ok(CostBasisMethod::Fifo),
+            "average" | "avg" | "average_cost" => Ok(CostBasisMethod::AverageCost),
+            other => Err(format!("Unknown cost basis method: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for CostBasisMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CostBasisMethod::Fifo => write!(f, "fifo"),
+            CostBasisMethod::AverageCost => write!(f, "average"),
+        }
+    }
+}
+
+/// 매도 한 건의 실현 손익
+#[derive(Debug, Clone, Serialize)]
+pub struct Disposal {
+    pub record_id: i64,
+    pub executed_at: DateTime<Utc>,
+    pub exchange: String,
+    pub symbol: String,
+    pub asset: String,
+    pub quote: String,
+    pub quantity: f64,
+    /// 매도 금액 (견적 통화)
+    pub proceeds: f64,
+    /// 매칭된 취득가액 (견적 통화)
+    pub cost_basis: f64,
+    /// 실현 손익 (견적 통화)
+    pub gain: f64,
+    pub gain_krw: f64,
+    pub gain_usdt: f64,
+    /// 매칭할 보유 lot이 없던 수량 (취득가액 0으로 계산)
+    pub unmatched_qty: f64,
+}
+
+/// 자산별 연간 합계
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetGain {
+    pub asset: String,
+    pub disposed_qty: f64,
+    pub gain_krw: f64,
+    pub gain_usdt: f64,
+    /// 연말 기준 남은 보유 수량 (기록상)
+    pub remaining_qty: f64,
+}
+
+/// 과세 연도 보고서
+#[derive(Debug, Clone, Serialize)]
+pub struct TaxReport {
+    pub year: i32,
+    pub method: CostBasisMethod,
+    /// 환산에 사용한 USDT/KRW 환율
+    pub usdt_krw: f64,
+    pub disposals: Vec<Disposal>,
+    pub by_asset: Vec<AssetGain>,
+    pub total_gain_krw: f64,
+    pub total_gain_usdt: f64,
+    /// 선물 기록 등 계산에서 제외한 건수
+    pub skipped_non_spot: usize,
+    /// 체결 가격이 없어 제외한 건수
+    pub skipped_unpriced: usize,
+}
+
+/// 보유 lot (수량, 단위당 취득가, 견적 통화)
+#[derive(Debug, Clone)]
+struct Lot {
+    qty: f64,
+    unit_cost: f64,
+    quote: String,
+}
+
+/// 자산 하나의 보유 lot 장부
+#[derive(Debug, Default)]
+struct Inventory {
+    lots: VecDeque<Lot>,
+}
+
+impl Inventory {
+    fn remaining(&self) -> f64 {
+        self.lots.iter().map(|l| l.qty).sum()
+    }
+
+    fn buy(&mut self, lot: Lot, method: CostBasisMethod) {
+        match method {
+            CostBasisMethod::Fifo => self.lots.push_back(lot),
+            CostBasisMethod::AverageCost => {
+                // 이동평균은 하나의 lot으로 합친다 (견적 통화는 첫 lot 기준)
+                match self.lots.front_mut() {
+                    Some(avg) => {
+                        let total = avg.qty + lot.qty;
+                        if total > 0.0 {
+                            avg.unit_cost =
+                                (avg.qty * avg.unit_cost + lot.qty * lot.unit_cost) / total;
+                        }
+                        avg.qty = total;
+                    }
+                    None => self.lots.push_back(lot),
+                }
+            }
+        }
+    }
+
+    /// qty만큼 lot을 소진하고 (취득가액 합계(견적 통화별), 매칭 안 된 수량) 반환
+    fn sell(&mut self, mut qty: f64) -> (Vec<(String, f64)>, f64) {
+        let mut costs = Vec::new();
+        while qty > QTY_EPSILON {
+            let Some(lot) = self.lots.front_mut() else {
+                break;
+            };
+            let take = qty.min(lot.qty);
+            costs.push((lot.quote.clone(), take * lot.unit_cost));
+            lot.qty -= take;
+            qty -= take;
+            if lot.qty <= QTY_EPSILON {
+                self.lots.pop_front();
+            }
+        }
+        (costs, qty.max(0.0))
+    }
+}
+
+/// 심볼을 (자산, 견적 통화)로 분리 ("BTCUSDT", "BTC-KRW", "KRW-BTC", "BTC_KRW" 지원)
+pub fn parse_pair(symbol: &str) -> Option<(String, String)> {
+    let upper = symbol.to_uppercase();
+    if let Some((left, right)) = upper.split_once(['-', '_']) {
+        let is_quote = |s: &str| split_symbol(&format!("X{}", s)).is_some_and(|(_, q)| q == s);
+        return if is_quote(right) {
+            Some((left.to_string(), right.to_string()))
+        } else if is_quote(left) {
+            Some((right.to_string(), left.to_string()))
+        } else {
+            None
+        };
+    }
+    split_symbol(&upper).map(|(base, quote)| (base.to_string(), quote.to_string()))
+}
+
+/// 과세 연도 (KST 기준)
+pub fn tax_year(at: DateTime<Utc>) -> i32 {
+    let kst = FixedOffset::east_opt(KST_OFFSET_SECS).expect("valid KST offset");
+    at.with_timezone(&kst).year()
+}
+
+/// 견적 통화 금액 → (KRW, USDT)
+fn convert(amount: f64, quote: &str, usdt_krw: f64) -> (f64, f64) {
+    if quote == "KRW" {
+        (amount, amount / usdt_krw)
+    } else {
+        // USDT/USDC/FDUSD 등 달러 스테이블은 1:1로 본다
+        (amount * usdt_krw, amount)
+    }
+}
+
+/// 거래 기록으로 과세 연도 보고서 계산
+/// 연도 이전 기록도 취득 lot을 쌓기 위해 모두 넘겨야 한다
+pub fn build_tax_report(
+    records: &[StoredTradeRecord],
+    year: i32,
+    method: CostBasisMethod,
+    usdt_krw: f64,
+) -> TaxReport {
+    let mut sorted: Vec<&StoredTradeRecord> = records.iter().collect();
+    sorted.sort_by_key(|r| (r.record.executed_at, r.id));
+
+    let mut inventories: HashMap<String, Inventory> = HashMap::new();
+    let mut disposals = Vec::new();
+    let mut skipped_non_spot = 0;
+    let mut skipped_unpriced = 0;
+
+    for stored in sorted {
+        let record = &stored.record;
+        if tax_year(record.executed_at) > year {
+            break;
+        }
+        if record.market_type != MarketType::Spot {
+            skipped_non_spot += 1;
+            continue;
+        }
+        let (Some(price), Some((asset, quote))) =
+            (record.executed_price, parse_pair(&record.symbol))
+        else {
+            skipped_unpriced += 1;
+            continue;
+        };
+        if price <= 0.0 || record.quantity <= 0.0 {
+            skipped_unpriced += 1;
+            continue;
+        }
+
+        let inventory = inventories.entry(asset.clone()).or_default();
+        match record.side {
+            TradeSide::Buy => {
+                let mut unit_cost = price;
+                let mut lot_quote = quote.clone();
+                // 이동평균은 lot 하나로 합치므로 첫 lot의 견적 통화로 맞춘다
+                if method == CostBasisMethod::AverageCost
+                    && let Some(existing) = inventory.lots.front()
+                    && existing.quote != quote
+                {
+                    let (krw, usdt) = convert(price, &quote, usdt_krw);
+                    unit_cost = if existing.quote == "KRW" { krw } else { usdt };
+                    lot_quote = existing.quote.clone();
+                }
+                inventory.buy(
+                    Lot {
+                        qty: record.quantity,
+                        unit_cost,
+                        quote: lot_quote,
+                    },
+                    method,
+                );
+            }
+            TradeSide::Sell => {
+                let (costs, unmatched_qty) = inventory.sell(record.quantity);
+                if tax_year(record.executed_at) != year {
+                    continue;
+                }
+                // 취득가액은 매도 견적 통화로 환산
+                let cost_basis: f64 = costs
+                    .iter()
+                    .map(|(lot_quote, cost)| {
+                        if *lot_quote == quote {
+                            *cost
+                        } else {
+                            let (krw, usdt) = convert(*cost, lot_quote, usdt_krw);
+                            if quote == "KRW" { krw } else { usdt }
+                        }
+                    })
+                    .sum();
+                let proceeds = price * record.quantity;
+                let gain = proceeds - cost_basis;
+                let (gain_krw, gain_usdt) = convert(gain, &quote, usdt_krw);
+                disposals.push(Disposal {
+                    record_id: stored.id,
+                    executed_at: record.executed_at,
+                    exchange: record.exchange.clone(),
+                    symbol: record.symbol.clone(),
+                    asset,
+                    quote,
+                    quantity: record.quantity,
+                    proceeds,
+                    cost_basis,
+                    gain,
+                    gain_krw,
+                    gain_usdt,
+                    unmatched_qty,
+                });
+            }
+        }
+    }
+
+    let mut by_asset: BTreeMap<String, AssetGain> = BTreeMap::new();
+    for disposal in &disposals {
+        let entry = by_asset
+            .entry(disposal.asset.clone())
+            .or_insert_with(|| AssetGain {
+                asset: disposal.asset.clone(),
+                ..Default::default()
+            });
+        entry.disposed_qty += disposal.quantity;
+        entry.gain_krw += disposal.gain_krw;
+        entry.gain_usdt += disposal.gain_usdt;
+    }
+    for (asset, entry) in by_asset.iter_mut() {
+        entry.remaining_qty = inventories.get(asset).map(|i| i.remaining()).unwrap_or(0.0);
+    }
+
+    TaxReport {
+        year,
+        method,
+        usdt_krw,
+        total_gain_krw: disposals.iter().map(|d| d.gain_krw).sum(),
+        total_gain_usdt: disposals.iter().map(|d| d.gain_usdt).sum(),
+        disposals,
+        by_asset: by_asset.into_values().collect(),
+        skipped_non_spot,
+        skipped_unpriced,
+    }
+}
+
+/// 매도 내역 CSV 한 줄 (줄바꿈 포함)
+pub fn disposal_csv_row(d: &Disposal) -> String {
+    let mut row = [
+        d.record_id.to_string(),
+        d.executed_at.to_rfc3339(),
+        d.exchange.clone(),
+        d.symbol.clone(),
+        d.asset.clone(),
+        d.quote.clone(),
+        d.quantity.to_string(),
+        d.proceeds.to_string(),
+        d.cost_basis.to_string(),
+        d.gain.to_string(),
+        d.gain_krw.to_string(),
+        d.gain_usdt.to_string(),
+        d.unmatched_qty.to_string(),
+    ]
+    .iter()
+    .map(|f| escape_csv_field(f))
+    .collect::<Vec<_>>()
+    .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// 보고서의 매도 내역을 CSV 파일로 저장
+pub fn write_disposals_csv(report: &TaxReport, path: &str) -> std::io::Result<()> {
+    if let Some(parent) = std::path::Path::new(path).parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut body = String::from(DISPOSAL_CSV_HEADER);
+    for disposal in &report.disposals {
+        body.push_str(&disposal_csv_row(disposal));
+    }
+    std::fs::write(path, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{TradeRecord, TradeType};
+    use chrono::TimeZone;
+
+    fn trade(
+        id: i64,
+        at: &str,
+        symbol: &str,
+        side: TradeSide,
+        price: f64,
+        qty: f64,
+    ) -> StoredTradeRecord {
+        StoredTradeRecord {
+            id,
+            record: TradeRecord {
+                executed_at: DateTime::parse_from_rfc3339(at)
+                    .unwrap()
+                    .with_timezone(&Utc),
+                exchange: "binance".to_string(),
+                symbol: symbol.to_string(),
+                market_type: MarketType::Spot,
+                side,
+                trade_type: TradeType::Market,
+                executed_price: Some(price),
+                quantity: qty,
+                request_query_string: None,
+                api_response: None,
+                metadata: None,
+                is_liquidation: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_fifo_and_average_cost_gains() {
+        let records = vec![
+            trade(
+                1,
+                "2024-12-01T00:00:00Z",
+                "BTCUSDT",
+                TradeSide::Buy,
+                100.0,
+                1.0,
+            ),
+            trade(
+                2,
+                "2025-01-10T00:00:00Z",
+                "BTCUSDT",
+                TradeSide::Buy,
+                200.0,
+                1.0,
+            ),
+            trade(
+                3,
+                "2025-02-01T00:00:00Z",
+                "BTCUSDT",
+                TradeSide::Sell,
+                300.0,
+                1.5,
+            ),
+            // 2025-12-31T16:00Z = 2026-01-01 KST → 2026년 귀속
+            trade(
+                4,
+                "2025-12-31T16:00:00Z",
+                "BTCUSDT",
+                TradeSide::Sell,
+                300.0,
+                0.5,
+            ),
+        ];
+
+        let fifo = build_tax_report(&records, 2025, CostBasisMethod::Fifo, 1_000.0);
+        assert_eq!(fifo.disposals.len(), 1);
+        // 매도 450 - 취득 (100 + 0.5*200) = 250
+        assert!((fifo.total_gain_usdt - 250.0).abs() < 1e-9);
+        assert!((fifo.total_gain_krw - 250_000.0).abs() < 1e-6);
+        assert!((fifo.by_asset[0].remaining_qty - 0.5).abs() < 1e-12);
+
+        let avg = build_tax_report(&records, 2025, CostBasisMethod::AverageCost, 1_000.0);
+        // 평균 취득가 150 → 450 - 225 = 225
+        assert!((avg.total_gain_usdt - 225.0).abs() < 1e-9);
+
+        let next = build_tax_report(&records, 2026, CostBasisMethod::Fifo, 1_000.0);
+        assert_eq!(next.disposals[0].record_id, 4);
+        assert!((next.total_gain_usdt - 50.0).abs() < 1e-9);
+
+        assert_eq!(
+            tax_year(Utc.with_ymd_and_hms(2025, 12, 31, 15, 0, 0).unwrap()),
+            2026
+        );
+        assert_eq!(parse_pair("KRW-BTC"), Some(("BTC".into(), "KRW".into())));
+        assert_eq!(parse_pair("BTC_KRW"), Some(("BTC".into(), "KRW".into())));
+        assert_eq!(parse_pair("ETHFDUSD"), Some(("ETH".into(), "FDUSD".into())));
+        assert!(disposal_csv_row(&fifo.disposals[0]).starts_with("3,"));
+    }
+}
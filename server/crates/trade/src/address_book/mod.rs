@@ -0,0 +1,793 @@
+//! 출금 주소 화이트리스트 (주소록)
+//!
+//! 자동 리밸런싱 출금은 주소록에 등록된 (거래소, 자산, 네트워크, 주소)로만 보낸다.
+//! 주소록은 `ADDRESS_BOOK_KEY`에서 PBKDF2(솔트는 파일에 저장)로 유도한 AES-256-GCM 키로 암호화해
+//! `ADDRESS_BOOK_FILE`에 저장한다.
+//!
+//! 거래소 쪽 출금 주소 화이트리스트와 대조(verify)해 상태를 기록한다.
+//! - Binance: `/sapi/v1/capital/withdraw/address/list` (서명 필요)
+//! - 그 외 거래소: 조회 API 미지원 → `Unsupported` (로컬 주소록만으로 허용)
+//!
+//! 대조를 지원하는 거래소는 `Verified` 상태인 주소로만 출금을 허용한다.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use chrono::{DateTime, Utc};
+use interface::{ExchangeError, ExchangeId};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tracing::{info, warn};
+
+use crate::arbitrage::state::write_atomic;
+use crate::trader::binance::{BinanceAccount, WithdrawAddress, transfer};
+
+const DEFAULT_FILE: &str = "address_book.enc";
+/// PBKDF2-HMAC-SHA256 + 솔트
+const ENVELOPE_VERSION: u32 = 2;
+/// PBKDF2 반복 횟수 (파일에 함께 저장, 테스트는 디버그 빌드 속도 때문에 줄인다)
+#[cfg(not(test))]
+const KDF_ITERATIONS: u32 = 600_000;
+#[cfg(test)]
+const KDF_ITERATIONS: u32 = 1_000;
+const SALT_LEN: usize = 16;
+
+/// 주소록 에러
+#[derive(Debug, thiserror::Error)]
+pub enum AddressBookError {
+    #[error("Address book key not set (ADDRESS_BOOK_KEY)")]
+    KeyNotSet,
+
+    #[error("Address not whitelisted: {exchange:?} {asset} {network} {address}")]
+    NotWhitelisted {
+        exchange: ExchangeId,
+        asset: String,
+        network: String,
+        address: String,
+    },
+
+    #[error("Exchange whitelist mismatch for {0}: {1:?}")]
+    ExchangeMismatch(String, VerificationStatus),
+
+    #[error("Address not verified against exchange whitelist yet: {0}")]
+    NotVerified(String),
+
+    #[error("Duplicate address book entry: {0}")]
+    Duplicate(String),
+
+    #[error("Crypto error: {0}")]
+    Crypto(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Exchange error: {0}")]
+    Exchange(#[from] ExchangeError),
+}
+
+/// 주소록 항목
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AddressEntry {
+    pub exchange: ExchangeId,
+    /// 자산 (예: USDT)
+    pub asset: String,
+    /// 출금 네트워크 (예: TRX, ETH)
+    pub network: String,
+    pub address: String,
+    /// 메모/태그 (XRP 등)
+    pub memo: Option<String>,
+    pub label: String,
+    pub added_at: DateTime<Utc>,
+}
+
+impl AddressEntry {
+    /// 로그/표시용 키 (거래소:자산:네트워크:마스킹 주소)
+    pub fn key(&self) -> String {
+        format!(
+            "{:?}:{}:{}:{}",
+            self.exchange,
+            self.asset,
+            self.network,
+            mask_address(&self.address)
+        )
+    }
+
+    /// 대조 상태 조회용 식별 키 (거래소, 자산, 네트워크, 전체 주소, 메모)
+    fn id(&self) -> String {
+        let address = self.address.trim();
+        let address = if address.starts_with("0x") {
+            address.to_ascii_lowercase()
+        } else {
+            address.to_string()
+        };
+        format!(
+            "{:?}:{}:{}:{}:{}",
+            self.exchange,
+            self.asset.to_ascii_uppercase(),
+            self.network.to_ascii_uppercase(),
+            address,
+            self.memo.as_deref().unwrap_or("")
+        )
+    }
+
+    fn matches(
+        &self,
+        exchange: ExchangeId,
+        asset: &str,
+        network: &str,
+        address: &str,
+        memo: Option<&str>,
+    ) -> bool {
+        self.exchange == exchange
+            && self.asset.eq_ignore_ascii_case(asset)
+            && self.network.eq_ignore_ascii_case(network)
+            && same_address(&self.address, address)
+            && self.memo.as_deref().unwrap_or("") == memo.unwrap_or("")
+    }
+}
+
+/// 거래소 화이트리스트 대조 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "detail", rename_all = "snake_case")]
+pub enum VerificationStatus {
+    /// 아직 대조 전
+    Unverified,
+    /// 거래소 화이트리스트에 등록됨
+    Verified,
+    /// 거래소 주소록에 있으나 화이트리스트(출금 제한)로 표시되지 않음
+    NotWhitelistedOnExchange,
+    /// 거래소 주소록에 없음
+    MissingOnExchange,
+    /// 거래소 주소록 조회 API 미지원
+    Unsupported,
+    /// 조회 실패
+    Error(String),
+}
+
+/// 주소록 (암호화 전 평문 내용)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddressBook {
+    pub entries: Vec<AddressEntry>,
+}
+
+impl AddressBook {
+    /// 항목 추가 (같은 주소가 이미 있으면 에러)
+    pub fn add(&mut self, entry: AddressEntry) -> Result<(), AddressBookError> {
+        if self
+            .find(
+                entry.exchange,
+                &entry.asset,
+                &entry.network,
+                &entry.address,
+                entry.memo.as_deref(),
+            )
+            .is_some()
+        {
+            return Err(AddressBookError::Duplicate(entry.key()));
+        }
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// 주소 삭제 (`find`와 같은 기준), 삭제된 항목 수 반환
+    pub fn remove(
+        &mut self,
+        exchange: ExchangeId,
+        asset: &str,
+        network: &str,
+        address: &str,
+        memo: Option<&str>,
+    ) -> usize {
+        let before = self.entries.len();
+        self.entries
+            .retain(|e| !e.matches(exchange, asset, network, address, memo));
+        before - self.entries.len()
+    }
+
+    pub fn find(
+        &self,
+        exchange: ExchangeId,
+        asset: &str,
+        network: &str,
+        address: &str,
+        memo: Option<&str>,
+    ) -> Option<&AddressEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.matches(exchange, asset, network, address, memo))
+    }
+}
+
+/// 암호화된 주소록 파일 형식
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    /// PBKDF2 솔트
+    salt: String,
+    /// PBKDF2 반복 횟수
+    iterations: u32,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// 비밀 문자열과 솔트에서 AES-256 키 유도 (PBKDF2-HMAC-SHA256)
+fn derive_key(secret: &str, salt: &[u8], iterations: u32) -> Key<Aes256Gcm> {
+    let mut key = Key::<Aes256Gcm>::default();
+    pbkdf2::pbkdf2_hmac::<Sha256>(secret.as_bytes(), salt, iterations, &mut key);
+    key
+}
+
+/// 주소록 암호화 (솔트와 nonce는 저장할 때마다 새로 생성)
+pub fn seal(book: &AddressBook, secret: &str) -> Result<String, AddressBookError> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = Aes256Gcm::new(&derive_key(secret, &salt, KDF_ITERATIONS));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let plaintext = serde_json::to_vec(book)?;
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_ref())
+        .map_err(|e| AddressBookError::Crypto(e.to_string()))?;
+    let envelope = Envelope {
+        version: ENVELOPE_VERSION,
+        salt: BASE64.encode(salt),
+        iterations: KDF_ITERATIONS,
+        nonce: BASE64.encode(nonce),
+        ciphertext: BASE64.encode(ciphertext),
+    };
+    Ok(serde_json::to_string_pretty(&envelope)?)
+}
+
+/// 주소록 복호화 (키가 다르거나 파일이 변조되면 Crypto 에러)
+pub fn open(raw: &str, secret: &str) -> Result<AddressBook, AddressBookError> {
+    let envelope: Envelope = serde_json::from_str(raw)?;
+    if envelope.version != ENVELOPE_VERSION {
+        return Err(AddressBookError::Crypto(format!(
+            "unsupported envelope version {}",
+            envelope.version
+        )));
+    }
+    let decode = |v: &str| {
+        BASE64
+            .decode(v)
+            .map_err(|e| AddressBookError::Crypto(e.to_string()))
+    };
+    let key = derive_key(secret, &decode(&envelope.salt)?, envelope.iterations);
+    let nonce = decode(&envelope.nonce)?;
+    if nonce.len() != 12 {
+        return Err(AddressBookError::Crypto("invalid nonce length".to_string()));
+    }
+    let ciphertext = decode(&envelope.ciphertext)?;
+    let cipher = Aes256Gcm::new(&key);
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| AddressBookError::Crypto("decryption failed (wrong key?)".to_string()))?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+/// 주소 비교 (0x 주소는 체크섬 대소문자 무시)
+fn same_address(a: &str, b: &str) -> bool {
+    let (a, b) = (a.trim(), b.trim());
+    if a.starts_with("0x") && b.starts_with("0x") {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+/// 표시용 주소 마스킹 (앞 6자 + 뒤 4자)
+pub fn mask_address(address: &str) -> String {
+    let chars: Vec<char> = address.chars().collect();
+    if chars.len() <= 10 {
+        return address.to_string();
+    }
+    let head: String = chars[..6].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}…{}", head, tail)
+}
+
+/// 거래소 주소록과 대조해 항목 하나의 상태 판정
+pub fn verify_entry(entry: &AddressEntry, remote: &[WithdrawAddress]) -> VerificationStatus {
+    let found = remote.iter().find(|r| {
+        r.coin.eq_ignore_ascii_case(&entry.asset)
+            && r.network.eq_ignore_ascii_case(&entry.network)
+            && same_address(&r.address, &entry.address)
+            && r.address_tag == entry.memo.clone().unwrap_or_default()
+    });
+    match found {
+        Some(r) if r.white_status => VerificationStatus::Verified,
+        Some(_) => VerificationStatus::NotWhitelistedOnExchange,
+        None => VerificationStatus::MissingOnExchange,
+    }
+}
+
+/// 항목별 대조 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct EntryView {
+    pub exchange: ExchangeId,
+    pub asset: String,
+    pub network: String,
+    /// 마스킹된 주소
+    pub address: String,
+    pub label: String,
+    pub status: VerificationStatus,
+}
+
+/// 거래소 화이트리스트 대조 보고서
+#[derive(Debug, Clone, Serialize)]
+pub struct VerificationReport {
+    pub checked_at: DateTime<Utc>,
+    pub entries: Vec<EntryView>,
+    /// 거래소 화이트리스트에는 있으나 주소록에 없는 주소 (마스킹, "거래소:자산:네트워크:주소")
+    pub unknown_on_exchange: Vec<String>,
+}
+
+/// 암호화 주소록 저장소
+pub struct AddressBookStore {
+    path: PathBuf,
+    secret: Option<String>,
+    book: RwLock<AddressBook>,
+    /// 대조 상태 (`AddressEntry::id` 기준, 마스킹된 `key`는 표시용)
+    statuses: RwLock<HashMap<String, VerificationStatus>>,
+    last_report: RwLock<Option<VerificationReport>>,
+}
+
+impl AddressBookStore {
+    /// `ADDRESS_BOOK_FILE`(기본 address_book.enc), `ADDRESS_BOOK_KEY`
+    /// 키가 없으면 빈 주소록으로 시작하고 모든 출금을 거부한다
+    pub fn from_env() -> Self {
+        let path = std::env::var("ADDRESS_BOOK_FILE").unwrap_or_else(|_| DEFAULT_FILE.to_string());
+        let secret = std::env::var("ADDRESS_BOOK_KEY")
+            .ok()
+            .filter(|v| !v.is_empty());
+        let store = Self::new(path, secret);
+        if let Err(e) = store.load() {
+            warn!("주소록 로드 실패: {}", e);
+        }
+        store
+    }
+
+    pub fn new(path: impl Into<PathBuf>, secret: Option<String>) -> Self {
+        Self {
+            path: path.into(),
+            secret,
+            book: RwLock::new(AddressBook::default()),
+            statuses: RwLock::new(HashMap::new()),
+            last_report: RwLock::new(None),
+        }
+    }
+
+    fn secret(&self) -> Result<&str, AddressBookError> {
+        self.secret.as_deref().ok_or(AddressBookError::KeyNotSet)
+    }
+
+    /// 파일에서 주소록 로드 (파일이 없으면 빈 주소록)
+    pub fn load(&self) -> Result<(), AddressBookError> {
+        let secret = self.secret()?;
+        let book = match std::fs::read_to_string(&self.path) {
+            Ok(raw) => open(&raw, secret)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => AddressBook::default(),
+            Err(e) => return Err(e.into()),
+        };
+        info!("주소록 로드: {}개 주소", book.entries.len());
+        *self.book.write().unwrap() = book;
+        Ok(())
+    }
+
+    /// 원자적 쓰기 (저장 중 죽어도 기존 파일은 온전함)
+    fn save(&self, book: &AddressBook) -> Result<(), AddressBookError> {
+        let sealed = seal(book, self.secret()?)?;
+        write_atomic(&self.path, sealed.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn entries(&self) -> Vec<AddressEntry> {
+        self.book.read().unwrap().entries.clone()
+    }
+
+    /// 항목 추가. 저장이 끝날 때까지 쓰기 잠금을 잡아 동시 수정이 서로를 덮어쓰지 않게 한다
+    pub fn add(&self, entry: AddressEntry) -> Result<(), AddressBookError> {
+        let mut book = self.book.write().unwrap();
+        let mut updated = book.clone();
+        updated.add(entry)?;
+        self.save(&updated)?;
+        *book = updated;
+        Ok(())
+    }
+
+    pub fn remove(
+        &self,
+        exchange: ExchangeId,
+        asset: &str,
+        network: &str,
+        address: &str,
+        memo: Option<&str>,
+    ) -> Result<usize, AddressBookError> {
+        let mut book = self.book.write().unwrap();
+        let mut updated = book.clone();
+        let removed = updated.remove(exchange, asset, network, address, memo);
+        if removed > 0 {
+            self.save(&updated)?;
+            *book = updated;
+        }
+        Ok(removed)
+    }
+
+    fn status(&self, entry: &AddressEntry) -> VerificationStatus {
+        self.statuses
+            .read()
+            .unwrap()
+            .get(&entry.id())
+            .cloned()
+            .unwrap_or(VerificationStatus::Unverified)
+    }
+
+    /// 출금 허용 여부 확인
+    /// 주소록에 없거나, 거래소 화이트리스트와 어긋나거나, 대조 가능한 거래소인데 아직 대조 전이면 거부
+    pub fn authorize(
+        &self,
+        exchange: ExchangeId,
+        asset: &str,
+        network: &str,
+        address: &str,
+        memo: Option<&str>,
+    ) -> Result<AddressEntry, AddressBookError> {
+        let entry = self
+            .book
+            .read()
+            .unwrap()
+            .find(exchange, asset, network, address, memo)
+            .cloned()
+            .ok_or_else(|| AddressBookError::NotWhitelisted {
+                exchange,
+                asset: asset.to_string(),
+                network: network.to_string(),
+                address: mask_address(address),
+            })?;
+        match self.status(&entry) {
+            VerificationStatus::Verified | VerificationStatus::Unsupported => Ok(entry),
+            VerificationStatus::Unverified | VerificationStatus::Error(_) => {
+                Err(AddressBookError::NotVerified(entry.key()))
+            }
+            status => Err(AddressBookError::ExchangeMismatch(entry.key(), status)),
+        }
+    }
+
+    /// 대조 결과 반영 (거래소 조회 결과는 거래소 단위로 넘김)
+    pub fn apply_verification(
+        &self,
+        remote: &HashMap<ExchangeId, Result<Vec<WithdrawAddress>, String>>,
+    ) -> VerificationReport {
+        let entries = self.entries();
+        let mut statuses = HashMap::new();
+        let mut views = Vec::with_capacity(entries.len());
+        for entry in &entries {
+            let status = match remote.get(&entry.exchange) {
+                None => VerificationStatus::Unsupported,
+                Some(Err(e)) => VerificationStatus::Error(e.clone()),
+                Some(Ok(list)) => verify_entry(entry, list),
+            };
+            if status != VerificationStatus::Verified {
+                warn!("주소록 대조 불일치: {} → {:?}", entry.key(), status);
+            }
+            statuses.insert(entry.id(), status.clone());
+            views.push(EntryView {
+                exchange: entry.exchange,
+                asset: entry.asset.clone(),
+                network: entry.network.clone(),
+                address: mask_address(&entry.address),
+                label: entry.label.clone(),
+                status,
+            });
+        }
+        let unknown_on_exchange = remote
+            .iter()
+            .filter_map(|(exchange, list)| Some((exchange, list.as_ref().ok()?)))
+            .flat_map(|(exchange, list)| {
+                list.iter()
+                    .filter(|r| r.white_status)
+                    .filter(|r| {
+                        !entries.iter().any(|e| {
+                            e.exchange == *exchange
+                                && e.asset.eq_ignore_ascii_case(&r.coin)
+                                && same_address(&e.address, &r.address)
+                        })
+                    })
+                    .map(move |r| {
+                        format!(
+                            "{:?}:{}:{}:{}",
+                            exchange,
+                            r.coin,
+                            r.network,
+                            mask_address(&r.address)
+                        )
+                    })
+            })
+            .collect();
+        *self.statuses.write().unwrap() = statuses;
+        let report = VerificationReport {
+            checked_at: Utc::now(),
+            entries: views,
+            unknown_on_exchange,
+        };
+        *self.last_report.write().unwrap() = Some(report.clone());
+        report
+    }
+
+    /// 거래소 화이트리스트 조회 후 대조
+    pub async fn verify(&self) -> VerificationReport {
+        let mut remote = HashMap::new();
+        if self
+            .entries()
+            .iter()
+            .any(|e| e.exchange == ExchangeId::Binance)
+        {
+            let list = match BinanceAccount::main() {
+                Ok(account) => transfer::withdraw_address_list(&account.client)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            remote.insert(ExchangeId::Binance, list);
+        }
+        self.apply_verification(&remote)
+    }
+
+    /// 항목별 현재 상태 (마지막 대조 기준, 주소 마스킹)
+    pub fn view(&self) -> Vec<EntryView> {
+        self.entries()
+            .iter()
+            .map(|entry| EntryView {
+                exchange: entry.exchange,
+                asset: entry.asset.clone(),
+                network: entry.network.clone(),
+                address: mask_address(&entry.address),
+                label: entry.label.clone(),
+                status: self.status(entry),
+            })
+            .collect()
+    }
+
+    pub fn last_report(&self) -> Option<VerificationReport> {
+        self.last_report.read().unwrap().clone()
+    }
+}
+
+static ADDRESS_BOOK: OnceLock<AddressBookStore> = OnceLock::new();
+
+/// 전역 주소록
+pub fn address_book() -> &'static AddressBookStore {
+    ADDRESS_BOOK.get_or_init(AddressBookStore::from_env)
+}
+
+/// 주소록 확인 후 출금 (현재 Binance만 지원)
+pub async fn withdraw_whitelisted(
+    exchange: ExchangeId,
+    asset: &str,
+    network: &str,
+    address: &str,
+    memo: Option<&str>,
+    amount: f64,
+) -> Result<String, AddressBookError> {
+    let entry = address_book().authorize(exchange, asset, network, address, memo)?;
+    match exchange {
+        ExchangeId::Binance => {
+            let account = BinanceAccount::main()?;
+            let id = transfer::withdraw_apply(
+                &account.client,
+                &entry.asset,
+                &entry.network,
+                &entry.address,
+                entry.memo.as_deref(),
+                amount,
+            )
+            .await?;
+            info!(
+                "출금 신청: {} {} {} (id {})",
+                entry.key(),
+                amount,
+                entry.asset,
+                id
+            );
+            Ok(id)
+        }
+        _ => Err(ExchangeError::Other(format!("withdraw not supported for {:?}", exchange)).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(address: &str) -> AddressEntry {
+        AddressEntry {
+            exchange: ExchangeId::Binance,
+            asset: "USDT".to_string(),
+            network: "TRX".to_string(),
+            address: address.to_string(),
+            memo: None,
+            label: "bithumb".to_string(),
+            added_at: Utc::now(),
+        }
+    }
+
+    fn remote(address: &str, white_status: bool) -> WithdrawAddress {
+        WithdrawAddress {
+            address: address.to_string(),
+            address_tag: String::new(),
+            coin: "USDT".to_string(),
+            name: "bithumb".to_string(),
+            network: "TRX".to_string(),
+            white_status,
+        }
+    }
+
+    #[test]
+    fn test_address_book_seal_and_authorize() {
+        let mut book = AddressBook::default();
+        book.add(entry("TXYZabcdef1234567890")).unwrap();
+        assert!(book.add(entry("TXYZabcdef1234567890")).is_err());
+
+        // 암호화 왕복, 다른 키로는 복호화 실패
+        let sealed = seal(&book, "secret").unwrap();
+        assert!(!sealed.contains("TXYZabcdef"));
+        assert_eq!(open(&sealed, "secret").unwrap().entries, book.entries);
+        assert!(matches!(
+            open(&sealed, "other"),
+            Err(AddressBookError::Crypto(_))
+        ));
+
+        let path =
+            std::env::temp_dir().join(format!("address_book_test_{}.enc", std::process::id()));
+        let store = AddressBookStore::new(&path, Some("secret".to_string()));
+        store.add(entry("TXYZabcdef1234567890")).unwrap();
+        store.add(entry("TQQQabcdef0000000000")).unwrap();
+
+        // 주소록에 없는 주소, 대조 전 주소는 거부
+        let authorize =
+            |address: &str| store.authorize(ExchangeId::Binance, "USDT", "TRX", address, None);
+        assert!(matches!(
+            authorize("Tunknown"),
+            Err(AddressBookError::NotWhitelisted { .. })
+        ));
+        assert!(matches!(
+            authorize("TXYZabcdef1234567890"),
+            Err(AddressBookError::NotVerified(_))
+        ));
+
+        // 거래소 화이트리스트와 대조: 하나는 등록, 하나는 누락, 하나는 주소록 밖
+        let report = store.apply_verification(&HashMap::from([(
+            ExchangeId::Binance,
+            Ok(vec![
+                remote("TXYZabcdef1234567890", true),
+                remote("TZZZextra00000000000", true),
+            ]),
+        )]));
+        assert_eq!(report.unknown_on_exchange.len(), 1);
+        assert!(authorize("TXYZabcdef1234567890").is_ok());
+        assert!(matches!(
+            authorize("TQQQabcdef0000000000"),
+            Err(AddressBookError::ExchangeMismatch(
+                _,
+                VerificationStatus::MissingOnExchange
+            ))
+        ));
+
+        // 파일에서 다시 로드
+        let reloaded = AddressBookStore::new(&path, Some("secret".to_string()));
+        reloaded.load().unwrap();
+        assert_eq!(reloaded.entries().len(), 2);
+        assert_eq!(
+            reloaded
+                .remove(
+                    ExchangeId::Binance,
+                    "USDT",
+                    "TRX",
+                    "TQQQabcdef0000000000",
+                    None
+                )
+                .unwrap(),
+            1
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_address_book_status_uses_full_address_and_memo() {
+        let path = std::env::temp_dir().join(format!(
+            "address_book_lookalike_test_{}.enc",
+            std::process::id()
+        ));
+        let store = AddressBookStore::new(&path, Some("secret".to_string()));
+        // 앞 6자와 뒤 4자가 같아 마스킹 키가 같은 두 주소, 메모만 다른 두 항목
+        let genuine = entry("TXYZab1111111111wxyz");
+        let lookalike = entry("TXYZab2222222222wxyz");
+        assert_eq!(genuine.key(), lookalike.key());
+        let tagged = |memo: &str| AddressEntry {
+            memo: Some(memo.to_string()),
+            ..entry("rXRPaddress000000000")
+        };
+        for e in [genuine, lookalike, tagged("100"), tagged("200")] {
+            store.add(e).unwrap();
+        }
+
+        let mut tagged_remote = remote("rXRPaddress000000000", true);
+        tagged_remote.address_tag = "100".to_string();
+        store.apply_verification(&HashMap::from([(
+            ExchangeId::Binance,
+            Ok(vec![remote("TXYZab1111111111wxyz", true), tagged_remote]),
+        )]));
+
+        let authorize = |address: &str, memo: Option<&str>| {
+            store.authorize(ExchangeId::Binance, "USDT", "TRX", address, memo)
+        };
+        assert!(authorize("TXYZab1111111111wxyz", None).is_ok());
+        assert!(authorize("TXYZab2222222222wxyz", None).is_err());
+        assert!(authorize("rXRPaddress000000000", Some("100")).is_ok());
+        assert!(authorize("rXRPaddress000000000", Some("200")).is_err());
+
+        // 삭제도 네트워크와 메모까지 맞는 항목만
+        assert_eq!(
+            store
+                .remove(
+                    ExchangeId::Binance,
+                    "USDT",
+                    "ETH",
+                    "rXRPaddress000000000",
+                    Some("200")
+                )
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            store
+                .remove(
+                    ExchangeId::Binance,
+                    "USDT",
+                    "TRX",
+                    "rXRPaddress000000000",
+                    Some("200")
+                )
+                .unwrap(),
+            1
+        );
+        assert!(authorize("rXRPaddress000000000", Some("100")).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_address_book_concurrent_adds_are_not_lost() {
+        let path = std::env::temp_dir().join(format!(
+            "address_book_concurrent_test_{}.enc",
+            std::process::id()
+        ));
+        let store = AddressBookStore::new(&path, Some("secret".to_string()));
+        std::thread::scope(|scope| {
+            for i in 0..4 {
+                let store = &store;
+                scope.spawn(move || store.add(entry(&format!("TCONCURRENT{:09}", i))).unwrap());
+            }
+        });
+        assert_eq!(store.entries().len(), 4);
+        let reloaded = AddressBookStore::new(&path, Some("secret".to_string()));
+        reloaded.load().unwrap();
+        assert_eq!(reloaded.entries().len(), 4);
+        let _ = std::fs::remove_file(&path);
+
+        // 솔트 없는 SHA-256 키(버전 1) 파일은 읽지 않는다
+        let legacy = r#"{"version":1,"nonce":"AAAAAAAAAAAAAAAA","ciphertext":"AAAA"}"#;
+        assert!(open(legacy, "secret").is_err());
+        let legacy = r#"{"version":1,"salt":"AAAA","iterations":1,"nonce":"AAAAAAAAAAAAAAAA","ciphertext":"AAAA"}"#;
+        assert!(matches!(
+            open(legacy, "secret"),
+            Err(AddressBookError::Crypto(_))
+        ));
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 전략별 운용 자금 배분 에러
+#[derive(Debug, thiserror::Error)]
+pub enum AllocationError {
+    #[error("Unknown strategy: {0}")]
+    UnknownStrategy(String),
+
+    #[error(
+        "Capital budget exceeded for {strategy_id}: requested {requested:.4}, used {used:.4}, budget {budget:.4}"
+    )]
+    BudgetExceeded {
+        strategy_id: String,
+        requested: f64,
+        used: f64,
+        budget: f64,
+    },
+}
+
+/// 전략 인스턴스 하나에 배정된 자금과 현재 사용량
+///
+/// 사용량은 스팟 레그에 묶인 quote 자산(USDT 등)과
+/// 선물 레그에 잡힌 증거금(명목가 / 레버리지)의 합으로 계산한다.
+#[derive(Debug, Clone, Serialize)]
+pub struct Allocation {
+    /// 전략 인스턴스 ID (예: "intra_basis:BTCUSDT")
+    pub strategy_id: String,
+    /// 배정된 자금 한도 (quote 통화 기준)
+    pub budget: f64,
+    /// 스팟 레그에 사용 중인 quote 금액
+    pub spot_quote_used: f64,
+    /// 선물 레그에 사용 중인 증거금
+    pub futures_margin_used: f64,
+    /// 마지막 갱신 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Allocation {
+    /// 현재 총 사용량
+    pub fn used(&self) -> f64 {
+        self.spot_quote_used + self.futures_margin_used
+    }
+
+    /// 남은 자금
+    pub fn available(&self) -> f64 {
+        (self.budget - self.used()).max(0.0)
+    }
+
+    /// 사용률 (0.0 ~ 1.0, 한도가 0이면 0.0)
+    pub fn utilization(&self) -> f64 {
+        if self.budget <= 0.0 {
+            return 0.0;
+        }
+        self.used() / self.budget
+    }
+}
+
+/// `/allocations` 응답용 리포트
+#[derive(Debug, Clone, Serialize)]
+pub struct AllocationReport {
+    #[serde(flatten)]
+    pub allocation: Allocation,
+    pub used: f64,
+    pub available: f64,
+    pub utilization: f64,
+}
+
+impl From<&Allocation> for AllocationReport {
+    fn from(allocation: &Allocation) -> Self {
+        Self {
+            allocation: allocation.clone(),
+            used: allocation.used(),
+            available: allocation.available(),
+            utilization: allocation.utilization(),
+        }
+    }
+}
+
+/// 전략별 운용 자금 배분기
+///
+/// 전략은 진입 전에 `try_reserve`로 필요한 자금을 예약하고,
+/// 청산 후에는 `release_all`로 반납한다. 한도를 넘는 진입은 거부된다.
+#[derive(Debug, Default)]
+pub struct CapitalAllocator {
+    allocations: RwLock<HashMap<String, Allocation>>,
+}
+
+impl CapitalAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 전략 인스턴스 등록 (이미 있으면 한도만 갱신하고 사용량은 유지)
+    pub fn register(&self, strategy_id: &str, budget: f64) {
+        let mut allocations = self.allocations.write().unwrap();
+        let now = Utc::now();
+        allocations
+            .entry(strategy_id.to_string())
+            .and_modify(|a| {
+                a.budget = budget;
+                a.updated_at = now;
+            })
+            .or_insert_with(|| Allocation {
+                strategy_id: strategy_id.to_string(),
+                budget,
+                spot_quote_used: 0.0,
+                futures_margin_used: 0.0,
+                updated_at: now,
+            });
+    }
+
+    /// 전략 인스턴스 등록 해제
+    pub fn unregister(&self, strategy_id: &str) {
+        self.allocations.write().unwrap().remove(strategy_id);
+    }
+
+    /// 진입에 필요한 자금 예약. 한도를 넘으면 사용량을 건드리지 않고 에러를 반환
+    pub fn try_reserve(
+        &self,
+        strategy_id: &str,
+        spot_quote: f64,
+        futures_margin: f64,
+    ) -> Result<(), AllocationError> {
+        let mut allocations = self.allocations.write().unwrap();
+        let allocation = allocations
+            .get_mut(strategy_id)
+            .ok_or_else(|| AllocationError::UnknownStrategy(strategy_id.to_string()))?;
+
+        let requested = spot_quote.max(0.0) + futures_margin.max(0.0);
+        if allocation.used() + requested > allocation.budget {
+            return Err(AllocationError::BudgetExceeded {
+                strategy_id: strategy_id.to_string(),
+                requested,
+                used: allocation.used(),
+                budget: allocation.budget,
+            });
+        }
+
+        allocation.spot_quote_used += spot_quote.max(0.0);
+        allocation.futures_margin_used += futures_margin.max(0.0);
+        allocation.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 실제 잔고 사용량으로 덮어쓰기 (체결 후 또는 재시작 시 상태 복원용)
+    pub fn set_usage(
+        &self,
+        strategy_id: &str,
+        spot_quote: f64,
+        futures_margin: f64,
+    ) -> Result<(), AllocationError> {
+        let mut allocations = self.allocations.write().unwrap();
+        let allocation = allocations
+            .get_mut(strategy_id)
+            .ok_or_else(|| AllocationError::UnknownStrategy(strategy_id.to_string()))?;
+
+        allocation.spot_quote_used = spot_quote.max(0.0);
+        allocation.futures_margin_used = futures_margin.max(0.0);
+        allocation.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 예약된 자금 전부 반납
+    pub fn release_all(&self, strategy_id: &str) {
+        if let Some(allocation) = self.allocations.write().unwrap().get_mut(strategy_id) {
+            allocation.spot_quote_used = 0.0;
+            allocation.futures_margin_used = 0.0;
+            allocation.updated_at = Utc::now();
+        }
+    }
+
+    /// 특정 전략의 배분 정보 조회
+    pub fn get(&self, strategy_id: &str) -> Option<Allocation> {
+        self.allocations.read().unwrap().get(strategy_id).cloned()
+    }
+
+    /// 모든 전략의 사용률 리포트 (strategy_id 순으로 정렬)
+    pub fn report(&self) -> Vec<AllocationReport> {
+        let allocations = self.allocations.read().unwrap();
+        let mut reports: Vec<AllocationReport> =
+            allocations.values().map(AllocationReport::from).collect();
+        reports.sort_by(|a, b| a.allocation.strategy_id.cmp(&b.allocation.strategy_id));
+        reports
+    }
+}
+
+/// 전역 자금 배분기
+static GLOBAL_ALLOCATOR: OnceLock<CapitalAllocator> = OnceLock::new();
+
+/// 전역 자금 배분기 가져오기 (최초 호출 시 생성)
+pub fn global_allocator() -> &'static CapitalAllocator {
+    GLOBAL_ALLOCATOR.get_or_init(CapitalAllocator::new)
+}
+
+/// 스팟 quote 사용량과 선물 증거금 계산
+/// - spot_quote = spot_qty * spot_price
+/// - futures_margin = fut_qty * futures_mark / leverage
+pub fn required_capital(
+    spot_qty: f64,
+    spot_price: f64,
+    fut_qty: f64,
+    futures_mark: f64,
+    leverage: u32,
+) -> (f64, f64) {
+    let spot_quote = spot_qty * spot_price;
+    let futures_margin = fut_qty * futures_mark / leverage.max(1) as f64;
+    (spot_quote, futures_margin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_within_budget() {
+        let allocator = CapitalAllocator::new();
+        allocator.register("intra_basis:BTCUSDT", 100.0);
+
+        allocator
+            .try_reserve("intra_basis:BTCUSDT", 50.0, 25.0)
+            .unwrap();
+
+        let allocation = allocator.get("intra_basis:BTCUSDT").unwrap();
+        assert_eq!(allocation.used(), 75.0);
+        assert_eq!(allocation.available(), 25.0);
+        assert!((allocation.utilization() - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reserve_exceeding_budget_is_rejected() {
+        let allocator = CapitalAllocator::new();
+        allocator.register("intra_basis:BTCUSDT", 100.0);
+        allocator
+            .try_reserve("intra_basis:BTCUSDT", 60.0, 30.0)
+            .unwrap();
+
+        let err = allocator
+            .try_reserve("intra_basis:BTCUSDT", 10.0, 10.0)
+            .unwrap_err();
+        assert!(matches!(err, AllocationError::BudgetExceeded { .. }));
+
+        // 거부된 예약은 사용량에 반영되지 않음
+        assert_eq!(allocator.get("intra_basis:BTCUSDT").unwrap().used(), 90.0);
+    }
+
+    #[test]
+    fn test_release_and_unknown_strategy() {
+        let allocator = CapitalAllocator::new();
+        allocator.register("a", 10.0);
+        allocator.try_reserve("a", 5.0, 5.0).unwrap();
+        allocator.release_all("a");
+        assert_eq!(allocator.get("a").unwrap().used(), 0.0);
+
+        assert!(matches!(
+            allocator.try_reserve("b", 1.0, 1.0),
+            Err(AllocationError::UnknownStrategy(_))
+        ));
+    }
+
+    #[test]
+    fn test_required_capital_uses_leverage() {
+        let (spot_quote, futures_margin) = required_capital(2.0, 10.0, 2.0, 11.0, 2);
+        assert_eq!(spot_quote, 20.0);
+        assert_eq!(futures_margin, 11.0);
+    }
+}
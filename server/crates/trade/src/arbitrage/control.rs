@@ -0,0 +1,128 @@
+//! 운영자 제어 (일시 정지 / 전량 청산)
+//!
+//! 제어 API(`POST /control/pause`, `/control/resume`, `/control/flatten`)나 `trade console`에서
+//! 보내는 명령을 전략 루프에 전달한다. 일시 정지 중에는 `inflight_orders().try_begin`이
+//! 새 진입을 막고, 보유 포지션 청산은 평소 조건대로 진행된다. 전량 청산은 일시 정지를 건 뒤
+//! 청산 요청 번호를 올리고, 각 전략 루프가 처음 본 번호보다 큰 요청을 보면 베이시스와
+//! 무관하게 보유 포지션을 닫는다.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 일시 정지 상태
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PauseState {
+    pub reason: String,
+    pub since: DateTime<Utc>,
+}
+
+/// 제어 상태 조회 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct ControlReport {
+    /// 일시 정지 중이면 사유와 시각
+    pub paused: Option<PauseState>,
+    /// 지금까지의 전량 청산 요청 수
+    pub flatten_requests: u64,
+    pub last_flatten_at: Option<DateTime<Utc>>,
+}
+
+/// 전역 운영자 제어 상태
+#[derive(Debug, Default)]
+pub struct OperatorControl {
+    paused: RwLock<Option<PauseState>>,
+    flatten_seq: AtomicU64,
+    last_flatten_at: RwLock<Option<DateTime<Utc>>>,
+}
+
+impl OperatorControl {
+    /// 새 진입 중단. 이미 정지 중이면 기존 상태 유지
+    pub fn pause(&self, reason: &str) -> PauseState {
+        let mut paused = self.paused.write().unwrap();
+        paused
+            .get_or_insert_with(|| PauseState {
+                reason: reason.to_string(),
+                since: Utc::now(),
+            })
+            .clone()
+    }
+
+    /// 진입 재개. 정지 중이었으면 해제된 상태 반환
+    pub fn resume(&self) -> Option<PauseState> {
+        self.paused.write().unwrap().take()
+    }
+
+    /// 정지 중이면 그 상태
+    pub fn paused(&self) -> Option<PauseState> {
+        self.paused.read().unwrap().clone()
+    }
+
+    /// 전량 청산 요청 (진입도 함께 정지). 새 요청 번호 반환
+    pub fn request_flatten(&self, reason: &str) -> u64 {
+        self.pause(reason);
+        *self.last_flatten_at.write().unwrap() = Some(Utc::now());
+        self.flatten_seq.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// 현재 청산 요청 번호 (전략 루프 시작 시 기준값)
+    pub fn flatten_seq(&self) -> u64 {
+        self.flatten_seq.load(Ordering::SeqCst)
+    }
+
+    /// `seen` 이후 새 청산 요청이 있으면 true, `seen`을 최신 번호로 갱신
+    pub fn take_flatten(&self, seen: &mut u64) -> bool {
+        let current = self.flatten_seq();
+        if current > *seen {
+            *seen = current;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn report(&self) -> ControlReport {
+        ControlReport {
+            paused: self.paused(),
+            flatten_requests: self.flatten_seq(),
+            last_flatten_at: *self.last_flatten_at.read().unwrap(),
+        }
+    }
+}
+
+static GLOBAL_OPERATOR_CONTROL: OnceLock<OperatorControl> = OnceLock::new();
+
+/// 전역 운영자 제어 상태
+pub fn operator_control() -> &'static OperatorControl {
+    GLOBAL_OPERATOR_CONTROL.get_or_init(OperatorControl::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_resume_and_flatten_requests() {
+        let control = OperatorControl::default();
+        assert!(control.paused().is_none());
+
+        let first = control.pause("maintenance");
+        // 이미 정지 중이면 처음 사유 유지
+        assert_eq!(control.pause("other"), first);
+        assert_eq!(control.resume(), Some(first));
+        assert!(control.resume().is_none());
+
+        let mut seen = control.flatten_seq();
+        assert!(!control.take_flatten(&mut seen));
+        assert_eq!(control.request_flatten("console"), 1);
+        // 청산 요청은 진입도 정지
+        assert_eq!(control.paused().unwrap().reason, "console");
+        assert!(control.take_flatten(&mut seen));
+        assert!(!control.take_flatten(&mut seen));
+
+        let report = control.report();
+        assert_eq!(report.flatten_requests, 1);
+        assert!(report.last_flatten_at.is_some());
+    }
+}
@@ -0,0 +1,53 @@
+//! 전략 비용 계산용 수수료
+//!
+//! 수수료율은 `BinanceTrader::get_trade_fee_for_symbol` / `futures_fee`에서 가져오며,
+//! 둘 다 `FEE_OVERRIDES` 설정을 API 조회보다 먼저 확인한다.
+//! 현물 수수료를 BNB로 내는 동안에는 `bnb_fee_manager`의 할인율을 현물 레그에 반영한다.
+
+use interface::Bps;
+
+/// 현물/선물 레그에 실제로 적용되는 수수료율 (0.001 == 0.1%)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegFees {
+    pub spot_rate: f64,
+    pub futures_rate: f64,
+}
+
+impl LegFees {
+    /// 진입+청산 양쪽 레그 수수료를 베이시스로 환산한 손익분기점
+    pub fn break_even_bps(&self) -> Bps {
+        Bps::from_fraction(2.0 * (self.spot_rate + self.futures_rate))
+    }
+
+    /// 현물 레그 수수료 할인 반영 (0.25 = 25% 할인)
+    pub fn with_spot_discount(self, discount: f64) -> Self {
+        Self {
+            spot_rate: self.spot_rate * (1.0 - discount.clamp(0.0, 1.0)),
+            ..self
+        }
+    }
+
+    /// 진입+청산 수수료 합계 (호가 통화 기준)
+    pub fn round_trip_cost(&self, spot_notional: f64, futures_notional: f64) -> f64 {
+        2.0 * (spot_notional * self.spot_rate + futures_notional * self.futures_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_break_even_and_cost() {
+        let fees = LegFees {
+            spot_rate: 0.001,
+            futures_rate: 0.0005,
+        };
+        assert!((fees.break_even_bps().value() - 30.0).abs() < 1e-9);
+        assert!((fees.round_trip_cost(1_000.0, 1_000.0) - 3.0).abs() < 1e-9);
+
+        let discounted = fees.with_spot_discount(0.25);
+        assert!((discounted.spot_rate - 0.00075).abs() < 1e-12);
+        assert_eq!(discounted.futures_rate, 0.0005);
+    }
+}
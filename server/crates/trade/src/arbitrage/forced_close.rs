@@ -0,0 +1,102 @@
+//! 선물 레그 강제 청산/ADL 감지
+//!
+//! 거래소가 선물 포지션을 강제 청산하거나 자동 디레버리징(ADL)하면 스팟 레그만 남아 헤지가 풀린다.
+//! 전략 루프는 REST 포지션 조회 주기 사이에 이를 알 수 없으므로, 선물 계정 User Data Stream의
+//! ORDER_TRADE_UPDATE에서 거래소가 낸 청산 체결을 받는 즉시 Critical 알림을 보낸다.
+
+use std::sync::Arc;
+
+use tracing::error;
+
+use exchanges::supervisor::{WS_STALE_AFTER, stop_restarting, task_supervisor};
+
+use crate::notification::{AlertLevel, notification_center};
+use crate::trader::binance::{BinanceTrader, FuturesOrderUpdate, FuturesUserDataEvent};
+
+/// 선물 강제 청산/ADL 알림 종류
+pub const FORCED_CLOSE_ALERT: &str = "futures_forced_close";
+
+/// 심볼의 선물 강제 청산/ADL 체결 감시 시작 (User Data Stream은 감시기가 재시작)
+pub fn watch_forced_close(trader: &BinanceTrader, strategy_id: &str, symbol: &str) {
+    let Some(user_stream) = trader.futures_user_stream.clone() else {
+        return;
+    };
+    let strategy_id = strategy_id.to_string();
+    let symbol = symbol.to_string();
+    task_supervisor().spawn(
+        &format!("binance_futures_user_stream_ws:{}", strategy_id),
+        Some(WS_STALE_AFTER),
+        move || {
+            let user_stream = Arc::clone(&user_stream);
+            let (strategy_id, symbol) = (strategy_id.clone(), symbol.clone());
+            async move {
+                if !user_stream.has_credentials() {
+                    error!(
+                        "{} 선물 API 키가 없어 강제 청산 감지를 시작하지 않음",
+                        strategy_id
+                    );
+                    stop_restarting("API key not set");
+                    return;
+                }
+                let handler_id = strategy_id.clone();
+                let result = user_stream
+                    .start(move |event| {
+                        if let FuturesUserDataEvent::OrderTradeUpdate(update) = event
+                            && update.order.symbol == symbol
+                            && is_forced_fill(&update.order)
+                        {
+                            tokio::spawn(report_forced_close(handler_id.clone(), update.order));
+                        }
+                    })
+                    .await;
+                if let Err(e) = result {
+                    error!(
+                        "{} 선물 User Data Stream 시작 실패 (강제 청산 감지 불가): {}",
+                        strategy_id, e
+                    );
+                }
+            }
+        },
+    );
+}
+
+/// 강제 청산/ADL 주문의 실제 체결인지 (접수만 된 NEW 이벤트는 제외)
+fn is_forced_fill(order: &FuturesOrderUpdate) -> bool {
+    order.is_forced_close()
+        && order
+            .last_filled_quantity
+            .parse::<f64>()
+            .is_ok_and(|qty| qty > 0.0)
+}
+
+async fn report_forced_close(strategy_id: String, order: FuturesOrderUpdate) {
+    let message = format!(
+        "{} {} {} @ {} (누적 {}, 주문 {}, {}) — 스팟 레그만 남아 헤지가 풀렸을 수 있음",
+        order.symbol,
+        order.side,
+        order.last_filled_quantity,
+        order.last_filled_price,
+        order.cumulative_filled_quantity,
+        order.client_order_id,
+        order.order_status
+    );
+    error!("{} 선물 강제 청산/ADL 체결: {}", strategy_id, message);
+    notification_center()
+        .notify(
+            FORCED_CLOSE_ALERT,
+            AlertLevel::Critical,
+            format!("{} {} 선물 강제 청산", strategy_id, order.symbol),
+            message,
+            serde_json::json!({
+                "strategy_id": strategy_id,
+                "symbol": order.symbol,
+                "side": order.side,
+                "client_order_id": order.client_order_id,
+                "order_id": order.order_id,
+                "last_filled_quantity": order.last_filled_quantity,
+                "last_filled_price": order.last_filled_price,
+                "realized_profit": order.realized_profit,
+            }),
+        )
+        .await;
+}
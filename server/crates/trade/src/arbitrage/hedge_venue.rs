@@ -0,0 +1,534 @@
+//! 진입 시점 헤지 거래소 선택
+//!
+//! 같은 심볼을 여러 무기한 선물 거래소가 상장하고 있으면, 진입 직전에 Oracle 통합 스냅샷을 읽어
+//! 보유 기간 예상 펀딩 - 왕복 수수료 - 호가 깊이 패널티가 가장 좋은 거래소로 헤지한다.
+//! 고른 거래소와 점수 내역은 `hedge_venue_choices()`와 `hedge_venue_selected` 이벤트로 남는다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use interface::{Currency, ExchangeError, ExchangeId, Price, Qty, UnifiedSnapshot};
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::events::{PositionDirection, StrategyEvent, event_bus};
+use crate::oracle_client::OracleClient;
+use crate::trader::{
+    ContractKind, FuturesExchangeTrader, OrderResponse, futures_trader_for, parse_exchange_id,
+};
+
+/// 펀딩 정산 주기를 모를 때 가정하는 값 (시간)
+const DEFAULT_FUNDING_INTERVAL_HOURS: f64 = 8.0;
+
+/// 거래소별 기본 선물 taker 수수료 (bps, VIP0 기준)
+pub fn default_taker_fee_bps(exchange: ExchangeId) -> f64 {
+    match exchange {
+        ExchangeId::Binance => 5.0,
+        ExchangeId::Bybit => 5.5,
+        ExchangeId::Okx => 5.0,
+        ExchangeId::Bitget => 6.0,
+        ExchangeId::Bithumb => 25.0,
+    }
+}
+
+/// 헤지 거래소 선택 기준
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HedgeVenueParams {
+    /// 후보 거래소 (선물 주문 API가 있는 거래소만 실제로 고를 수 있음)
+    pub venues: Vec<ExchangeId>,
+    /// 예상 보유 기간 (시간). 이 기간 동안 받을/낼 펀딩을 점수에 반영
+    pub holding_hours: f64,
+    /// 거래소별 taker 수수료 덮어쓰기 (bps, 없으면 기본값)
+    pub taker_fee_bps: HashMap<ExchangeId, f64>,
+    /// 24h 거래대금 하한 (USD). 못 미치면 후보에서 제외
+    pub min_vol_24h_usd: f64,
+    /// 미결제약정 하한 (USD). 못 미치면 후보에서 제외
+    pub min_oi_usd: f64,
+    /// 같은 진입 신호가 이어질 때 다시 고르기까지의 간격 (초)
+    pub reselect_secs: u64,
+}
+
+impl Default for HedgeVenueParams {
+    fn default() -> Self {
+        Self {
+            venues: vec![ExchangeId::Binance, ExchangeId::Bybit, ExchangeId::Okx],
+            holding_hours: 24.0,
+            taker_fee_bps: HashMap::new(),
+            min_vol_24h_usd: 1_000_000.0,
+            min_oi_usd: 0.0,
+            reselect_secs: 60,
+        }
+    }
+}
+
+impl HedgeVenueParams {
+    /// ARB_HEDGE_VENUES ("binance,bybit,okx")가 있으면 선택 사용, 없으면 None
+    /// ARB_HEDGE_HOLDING_HOURS / ARB_HEDGE_TAKER_FEES ("bybit:4.0,okx:4.5") /
+    /// ARB_HEDGE_MIN_VOL_USD / ARB_HEDGE_MIN_OI_USD / ARB_HEDGE_RESELECT_SECS
+    pub fn from_env() -> Option<Self> {
+        let raw = std::env::var("ARB_HEDGE_VENUES").ok()?;
+        let venues: Vec<ExchangeId> = raw
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .filter_map(|v| match parse_exchange_id(v) {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    warn!("ARB_HEDGE_VENUES 항목 무시: {}", e);
+                    None
+                }
+            })
+            .collect();
+        if venues.is_empty() {
+            return None;
+        }
+
+        let env_f64 = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+        };
+        let defaults = Self::default();
+        let taker_fee_bps = std::env::var("ARB_HEDGE_TAKER_FEES")
+            .map(|raw| {
+                raw.split(',')
+                    .filter_map(|entry| {
+                        let (venue, bps) = entry.split_once(':')?;
+                        Some((
+                            parse_exchange_id(venue.trim()).ok()?,
+                            bps.trim().parse::<f64>().ok()?,
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self {
+            venues,
+            holding_hours: env_f64("ARB_HEDGE_HOLDING_HOURS").unwrap_or(defaults.holding_hours),
+            taker_fee_bps,
+            min_vol_24h_usd: env_f64("ARB_HEDGE_MIN_VOL_USD").unwrap_or(defaults.min_vol_24h_usd),
+            min_oi_usd: env_f64("ARB_HEDGE_MIN_OI_USD").unwrap_or(defaults.min_oi_usd),
+            reselect_secs: std::env::var("ARB_HEDGE_RESELECT_SECS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(defaults.reselect_secs),
+        })
+    }
+
+    fn taker_fee(&self, exchange: ExchangeId) -> f64 {
+        self.taker_fee_bps
+            .get(&exchange)
+            .copied()
+            .unwrap_or_else(|| default_taker_fee_bps(exchange))
+    }
+}
+
+/// 후보 거래소 점수 (bps, 클수록 유리)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VenueScore {
+    pub exchange: ExchangeId,
+    /// 보유 기간 동안 헤지 레그가 받을(+) / 낼(-) 예상 펀딩
+    pub funding_bps: f64,
+    /// 왕복 taker 수수료
+    pub fee_bps: f64,
+    /// 명목가 / 24h 거래대금 기반 충격 비용
+    pub depth_bps: f64,
+    /// funding - fee - depth
+    pub score_bps: f64,
+}
+
+/// 후보에서 빠진 거래소와 사유
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RejectedVenue {
+    pub exchange: ExchangeId,
+    pub reason: String,
+}
+
+/// 헤지 거래소 선택 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HedgeVenueChoice {
+    pub symbol: String,
+    pub direction: PositionDirection,
+    pub chosen: VenueScore,
+    /// 점수 내림차순 (chosen 포함)
+    pub candidates: Vec<VenueScore>,
+    pub rejected: Vec<RejectedVenue>,
+    pub reason: String,
+    pub selected_at: DateTime<Utc>,
+}
+
+/// 스냅샷으로 후보 거래소 점수를 매겨 가장 좋은 거래소 선택
+/// carry는 헤지 선물 숏이라 양(+)의 펀딩을 받고, reverse는 선물 롱이라 반대
+pub fn rank_hedge_venues(
+    snapshots: &[UnifiedSnapshot],
+    symbol: &str,
+    direction: PositionDirection,
+    notional_usd: f64,
+    params: &HedgeVenueParams,
+) -> Option<HedgeVenueChoice> {
+    let sign = match direction {
+        PositionDirection::Carry => 1.0,
+        PositionDirection::Reverse => -1.0,
+    };
+    let mut candidates = Vec::new();
+    let mut rejected = Vec::new();
+    for &exchange in &params.venues {
+        let reject = |reason: String| RejectedVenue { exchange, reason };
+        let Some(perp) = snapshots
+            .iter()
+            .filter(|s| s.exchange == exchange && s.symbol == symbol)
+            .find_map(|s| s.perp.as_ref())
+        else {
+            rejected.push(reject("no perp snapshot".to_string()));
+            continue;
+        };
+        if !matches!(perp.currency, Currency::USDT) {
+            rejected.push(reject(format!("{:?}-margined perp", perp.currency)));
+            continue;
+        }
+        if perp.vol_24h_usd < params.min_vol_24h_usd {
+            rejected.push(reject(format!(
+                "24h volume {:.0} USD < {:.0} USD",
+                perp.vol_24h_usd, params.min_vol_24h_usd
+            )));
+            continue;
+        }
+        if perp.oi_usd < params.min_oi_usd {
+            rejected.push(reject(format!(
+                "open interest {:.0} USD < {:.0} USD",
+                perp.oi_usd, params.min_oi_usd
+            )));
+            continue;
+        }
+
+        let rate = perp.predicted_funding_rate.unwrap_or(perp.funding_rate);
+        let interval = perp
+            .funding_interval_hours
+            .filter(|h| *h > 0.0)
+            .unwrap_or(DEFAULT_FUNDING_INTERVAL_HOURS);
+        let funding_bps = sign * rate * 10_000.0 * (params.holding_hours / interval);
+        let fee_bps = 2.0 * params.taker_fee(exchange);
+        let depth_bps = if perp.vol_24h_usd > 0.0 {
+            notional_usd / perp.vol_24h_usd * 10_000.0
+        } else {
+            0.0
+        };
+        candidates.push(VenueScore {
+            exchange,
+            funding_bps,
+            fee_bps,
+            depth_bps,
+            score_bps: funding_bps - fee_bps - depth_bps,
+        });
+    }
+
+    candidates.sort_by(|a, b| b.score_bps.total_cmp(&a.score_bps));
+    let chosen = candidates.first()?.clone();
+    let mut reason = format!(
+        "{:?}: funding {:+.2} bps, fees -{:.2} bps, depth -{:.2} bps = {:+.2} bps",
+        chosen.exchange, chosen.funding_bps, chosen.fee_bps, chosen.depth_bps, chosen.score_bps
+    );
+    if let Some(runner_up) = candidates.get(1) {
+        reason.push_str(&format!(
+            " (next {:?} {:+.2} bps)",
+            runner_up.exchange, runner_up.score_bps
+        ));
+    }
+    Some(HedgeVenueChoice {
+        symbol: symbol.to_string(),
+        direction,
+        chosen,
+        candidates,
+        rejected,
+        reason,
+        selected_at: Utc::now(),
+    })
+}
+
+/// 전략별 마지막 헤지 거래소 선택 기록
+#[derive(Debug, Default)]
+pub struct HedgeVenueChoices {
+    by_strategy: RwLock<BTreeMap<String, HedgeVenueChoice>>,
+}
+
+impl HedgeVenueChoices {
+    pub fn record(&self, strategy_id: &str, choice: HedgeVenueChoice) {
+        self.by_strategy
+            .write()
+            .unwrap()
+            .insert(strategy_id.to_string(), choice);
+    }
+
+    pub fn get(&self, strategy_id: &str) -> Option<HedgeVenueChoice> {
+        self.by_strategy.read().unwrap().get(strategy_id).cloned()
+    }
+
+    pub fn report(&self) -> BTreeMap<String, HedgeVenueChoice> {
+        self.by_strategy.read().unwrap().clone()
+    }
+}
+
+static GLOBAL_HEDGE_VENUE_CHOICES: OnceLock<HedgeVenueChoices> = OnceLock::new();
+
+/// 전역 헤지 거래소 선택 기록
+pub fn hedge_venue_choices() -> &'static HedgeVenueChoices {
+    GLOBAL_HEDGE_VENUE_CHOICES.get_or_init(HedgeVenueChoices::default)
+}
+
+/// 진입 시점에 헤지 거래소를 바꿔 끼우는 선물 트레이더
+///
+/// 평소에는 현재 거래소 트레이더로 위임하고, 포지션이 없을 때 `prepare_entry`가 불리면
+/// Oracle 스냅샷으로 거래소를 다시 고른다. 보유 중에는 바꾸지 않으므로 청산은 진입한 거래소에서 한다.
+pub struct HedgeVenueRouter {
+    strategy_id: String,
+    params: HedgeVenueParams,
+    oracle: OracleClient,
+    current: RwLock<(ExchangeId, Arc<dyn FuturesExchangeTrader>)>,
+    /// 마지막 ensure_account_setup 인자 (거래소를 바꾸면 새 거래소에도 적용)
+    account_setup: Mutex<Option<(String, u32, bool)>>,
+    last_selected: Mutex<Option<Instant>>,
+}
+
+impl HedgeVenueRouter {
+    /// `initial` 거래소로 시작 (재시작 시 보유 포지션이 있는 거래소를 넘겨야 함)
+    pub fn new(
+        strategy_id: impl Into<String>,
+        initial: ExchangeId,
+        params: HedgeVenueParams,
+        oracle: OracleClient,
+    ) -> Result<Self, ExchangeError> {
+        let trader: Arc<dyn FuturesExchangeTrader> = Arc::from(futures_trader_for(initial)?);
+        Ok(Self {
+            strategy_id: strategy_id.into(),
+            params,
+            oracle,
+            current: RwLock::new((initial, trader)),
+            account_setup: Mutex::new(None),
+            last_selected: Mutex::new(None),
+        })
+    }
+
+    pub fn current_venue(&self) -> ExchangeId {
+        self.current.read().unwrap().0
+    }
+
+    fn trader(&self) -> Arc<dyn FuturesExchangeTrader> {
+        self.current.read().unwrap().1.clone()
+    }
+
+    /// reselect_secs 안에 이미 골랐으면 건너뜀
+    fn reselect_due(&self) -> bool {
+        let mut last = self.last_selected.lock().unwrap();
+        let interval = Duration::from_secs(self.params.reselect_secs);
+        if last.is_some_and(|at| at.elapsed() < interval) {
+            return false;
+        }
+        *last = Some(Instant::now());
+        true
+    }
+}
+
+#[async_trait]
+impl FuturesExchangeTrader for HedgeVenueRouter {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        self.trader().ensure_exchange_info().await
+    }
+
+    async fn ensure_account_setup(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        *self.account_setup.lock().unwrap() = Some((symbol.to_string(), leverage, isolated));
+        self.trader()
+            .ensure_account_setup(symbol, leverage, isolated)
+            .await
+    }
+
+    async fn get_mark_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        self.trader().get_mark_price(symbol).await
+    }
+
+    async fn get_funding_rate(&self, symbol: &str) -> Result<Option<f64>, ExchangeError> {
+        self.trader().get_funding_rate(symbol).await
+    }
+
+    fn contract_kind(&self) -> ContractKind {
+        ContractKind::Linear
+    }
+
+    fn venue(&self) -> Option<ExchangeId> {
+        Some(self.current_venue())
+    }
+
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        self.trader().clamp_futures_quantity(symbol, qty)
+    }
+
+    async fn buy_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.trader().buy_futures(symbol, qty, reduce_only).await
+    }
+
+    async fn sell_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.trader().sell_futures(symbol, qty, reduce_only).await
+    }
+
+    async fn prepare_entry(
+        &self,
+        symbol: &str,
+        direction: PositionDirection,
+        notional_usd: f64,
+    ) -> Result<bool, ExchangeError> {
+        if !self.reselect_due() {
+            return Ok(false);
+        }
+        let snapshots =
+            self.oracle.fetch_signal_snapshots().await.map_err(|e| {
+                ExchangeError::Other(format!("Oracle snapshot fetch failed: {}", e))
+            })?;
+        let Some(choice) =
+            rank_hedge_venues(&snapshots, symbol, direction, notional_usd, &self.params)
+        else {
+            warn!(
+                "No hedge venue candidate for {} (keeping {:?})",
+                symbol,
+                self.current_venue()
+            );
+            return Ok(false);
+        };
+
+        let previous = self.current_venue();
+        let next = choice.chosen.exchange;
+        info!("헤지 거래소 선택 {}: {}", symbol, choice.reason);
+        event_bus().publish(
+            &self.strategy_id,
+            "cross_basis",
+            symbol,
+            StrategyEvent::HedgeVenueSelected {
+                direction,
+                previous,
+                selected: next,
+                score_bps: choice.chosen.score_bps,
+                reason: choice.reason.clone(),
+            },
+        );
+        hedge_venue_choices().record(&self.strategy_id, choice);
+        if next == previous {
+            return Ok(false);
+        }
+
+        let trader: Arc<dyn FuturesExchangeTrader> = Arc::from(futures_trader_for(next)?);
+        trader.ensure_exchange_info().await?;
+        let setup = self.account_setup.lock().unwrap().clone();
+        if let Some((symbol, leverage, isolated)) = setup {
+            trader
+                .ensure_account_setup(&symbol, leverage, isolated)
+                .await?;
+        }
+        *self.current.write().unwrap() = (next, trader);
+        info!("헤지 거래소 변경: {:?} → {:?}", previous, next);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::{ExchangeRates, PerpData};
+
+    fn snapshot(exchange: ExchangeId, funding_rate: f64, vol_24h_usd: f64) -> UnifiedSnapshot {
+        UnifiedSnapshot {
+            schema_version: interface::UNIFIED_SNAPSHOT_SCHEMA_VERSION,
+            exchange,
+            symbol: "BTCUSDT".to_string(),
+            currency: Currency::USDT,
+            perp: Some(PerpData {
+                currency: Currency::USDT,
+                mark_price: Price::new(100_000.0),
+                index_price: None,
+                oi_usd: 1e9,
+                vol_24h_usd,
+                funding_rate,
+                predicted_funding_rate: None,
+                next_funding_time: None,
+                funding_interval_hours: Some(8.0),
+            }),
+            spot: None,
+            basis_bps: None,
+            cross_basis_bps: None,
+            exchange_rates: ExchangeRates {
+                usd_krw: 1300.0,
+                usdt_usd: 1.0,
+                usdt_krw: 1300.0,
+                updated_at: Utc::now(),
+            },
+            quality: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_rank_hedge_venues() {
+        let snapshots = vec![
+            snapshot(ExchangeId::Binance, 0.0001, 5e9),
+            snapshot(ExchangeId::Bybit, 0.0003, 2e9),
+            // 거래대금 하한 미달
+            snapshot(ExchangeId::Okx, 0.001, 1e5),
+        ];
+        let params = HedgeVenueParams::default();
+
+        // carry(선물 숏)는 펀딩을 받으므로 펀딩비가 높은 Bybit
+        let choice = rank_hedge_venues(
+            &snapshots,
+            "BTCUSDT",
+            PositionDirection::Carry,
+            5_000.0,
+            &params,
+        )
+        .unwrap();
+        assert_eq!(choice.chosen.exchange, ExchangeId::Bybit);
+        // 24h / 8h = 3회 × 3bps
+        assert!((choice.chosen.funding_bps - 9.0).abs() < 1e-9);
+        assert!((choice.chosen.fee_bps - 11.0).abs() < 1e-9);
+        assert_eq!(choice.candidates.len(), 2);
+        assert_eq!(choice.rejected.len(), 1);
+        assert_eq!(choice.rejected[0].exchange, ExchangeId::Okx);
+        assert!(choice.reason.contains("Bybit"));
+
+        // reverse(선물 롱)는 펀딩을 내므로 펀딩비가 낮은 Binance
+        let choice = rank_hedge_venues(
+            &snapshots,
+            "BTCUSDT",
+            PositionDirection::Reverse,
+            5_000.0,
+            &params,
+        )
+        .unwrap();
+        assert_eq!(choice.chosen.exchange, ExchangeId::Binance);
+
+        assert!(
+            rank_hedge_venues(
+                &snapshots,
+                "ETHUSDT",
+                PositionDirection::Carry,
+                5_000.0,
+                &params
+            )
+            .is_none()
+        );
+    }
+}
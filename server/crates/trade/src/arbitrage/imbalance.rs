@@ -0,0 +1,93 @@
+use serde::Serialize;
+
+use crate::trader::OrderSide;
+use crate::trader::binance::BookTop;
+
+/// 최우선 호가 수량 불균형: (bid_qty - ask_qty) / (bid_qty + ask_qty), -1.0 ~ 1.0
+/// 양수면 매수 호가가 두껍고 매도 호가가 얇다. 수량이 없으면 0.0
+pub fn book_imbalance(book: &BookTop) -> f64 {
+    let total = book.bid_qty + book.ask_qty;
+    if total <= 0.0 {
+        return 0.0;
+    }
+    (book.bid_qty - book.ask_qty) / total
+}
+
+/// 시장가로 `side` 방향 주문을 낼 때 호가가 불리하게 기울었는지
+/// 매수는 얇은 매도 호가를, 매도는 얇은 매수 호가를 먹게 되므로
+/// 매수는 imbalance > threshold, 매도는 imbalance < -threshold 이면 불리하다.
+pub fn is_tilted_against(imbalance: f64, side: OrderSide, threshold: f64) -> bool {
+    match side {
+        OrderSide::Buy => imbalance > threshold,
+        OrderSide::Sell => imbalance < -threshold,
+    }
+}
+
+/// 진입 시점의 현물/선물 호가 불균형
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ImbalanceSignal {
+    pub spot: f64,
+    pub futures: f64,
+}
+
+impl ImbalanceSignal {
+    pub fn from_books(spot: &BookTop, futures: &BookTop) -> Self {
+        Self {
+            spot: book_imbalance(spot),
+            futures: book_imbalance(futures),
+        }
+    }
+
+    /// 진입을 막아야 하면 사유 반환
+    /// carry: 현물 매수 + 선물 매도, reverse: 현물 매도 + 선물 매수
+    pub fn veto(&self, carry: bool, threshold: f64) -> Option<String> {
+        let (spot_side, futures_side) = if carry {
+            (OrderSide::Buy, OrderSide::Sell)
+        } else {
+            (OrderSide::Sell, OrderSide::Buy)
+        };
+
+        let mut reasons = Vec::new();
+        if is_tilted_against(self.spot, spot_side, threshold) {
+            reasons.push(format!("spot {:?} imbalance {:.3}", spot_side, self.spot));
+        }
+        if is_tilted_against(self.futures, futures_side, threshold) {
+            reasons.push(format!(
+                "futures {:?} imbalance {:.3}",
+                futures_side, self.futures
+            ));
+        }
+        (!reasons.is_empty()).then(|| reasons.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(bid_qty: f64, ask_qty: f64) -> BookTop {
+        BookTop {
+            bid_price: 99.9,
+            bid_qty,
+            ask_price: 100.0,
+            ask_qty,
+        }
+    }
+
+    #[test]
+    fn test_imbalance_veto() {
+        assert_eq!(book_imbalance(&book(3.0, 1.0)), 0.5);
+        assert_eq!(book_imbalance(&book(0.0, 0.0)), 0.0);
+
+        // 현물 매도 호가가 얇음 → carry(현물 매수) 거부, reverse(현물 매도)는 허용
+        let signal = ImbalanceSignal::from_books(&book(9.0, 1.0), &book(1.0, 1.0));
+        assert!(signal.veto(true, 0.6).is_some());
+        assert!(signal.veto(false, 0.6).is_none());
+
+        // 선물 매수 호가가 얇음 → carry(선물 매도) 거부
+        let signal = ImbalanceSignal::from_books(&book(1.0, 1.0), &book(1.0, 9.0));
+        assert!(signal.veto(true, 0.6).unwrap().contains("futures"));
+        assert!(signal.veto(false, 0.6).is_none());
+        assert!(signal.veto(true, 0.9).is_none());
+    }
+}
@@ -0,0 +1,231 @@
+//! 진입 주문 중복 방지
+//!
+//! 운영자가 일시 정지(`operator_control`)를 걸었으면 모든 새 진입을 막는다.
+//! 같은 심볼에 대해 이전 진입 주문이 끝나지 않았거나(진행 중/미해결),
+//! 같은 전략의 직전 진입 시도 후 최소 간격이 지나지 않았으면 새 진입을 막는다.
+//! 주문은 체결됐는데 상태 파일 저장에 실패하면 티켓이 완료 처리되지 않은 채 버려지고,
+//! 이 경우 해당 심볼은 프로세스가 다시 시작되거나 `resolve`로 직접 풀 때까지 미해결로 남는다.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::error;
+
+use super::control::operator_control;
+
+/// 진행 중이거나 미해결인 진입 주문
+#[derive(Debug, Clone, Serialize)]
+pub struct InflightEntry {
+    pub strategy_id: String,
+    pub symbol: String,
+    pub started_at: DateTime<Utc>,
+    /// 주문 후 결과(상태 저장)가 확정되지 않은 채 티켓이 버려졌는지
+    pub unresolved: bool,
+}
+
+/// 진입이 막힌 이유
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryBlocked {
+    /// 같은 심볼의 진입 주문이 진행 중
+    InFlight { strategy_id: String },
+    /// 같은 심볼의 이전 진입 결과가 확정되지 않음
+    Unresolved { strategy_id: String },
+    /// 직전 진입 시도 후 최소 간격이 지나지 않음
+    TooSoon { remaining: Duration },
+    /// 운영자가 진입을 일시 정지함
+    Paused { reason: String },
+}
+
+impl fmt::Display for EntryBlocked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntryBlocked::InFlight { strategy_id } => {
+                write!(f, "entry order in flight ({})", strategy_id)
+            }
+            EntryBlocked::Unresolved { strategy_id } => {
+                write!(f, "previous entry unresolved ({})", strategy_id)
+            }
+            EntryBlocked::TooSoon { remaining } => {
+                write!(
+                    f,
+                    "min entry interval not elapsed ({:.1}s left)",
+                    remaining.as_secs_f64()
+                )
+            }
+            EntryBlocked::Paused { reason } => write!(f, "entries paused ({})", reason),
+        }
+    }
+}
+
+/// 심볼별 진행 중 진입 주문 / 전략별 마지막 진입 시각 저장소
+#[derive(Debug, Default)]
+pub struct InflightOrders {
+    by_symbol: Mutex<HashMap<String, InflightEntry>>,
+    last_entry: Mutex<HashMap<String, Instant>>,
+}
+
+impl InflightOrders {
+    /// 진입 시작. 성공하면 주문 결과가 확정될 때 `complete`/`release`로 닫아야 하는 티켓 반환
+    pub fn try_begin(
+        &self,
+        strategy_id: &str,
+        symbol: &str,
+        min_interval: Duration,
+    ) -> Result<EntryTicket<'_>, EntryBlocked> {
+        if let Some(paused) = operator_control().paused() {
+            return Err(EntryBlocked::Paused {
+                reason: paused.reason,
+            });
+        }
+        let mut by_symbol = self.by_symbol.lock().unwrap();
+        if let Some(entry) = by_symbol.get(symbol) {
+            let strategy_id = entry.strategy_id.clone();
+            return Err(if entry.unresolved {
+                EntryBlocked::Unresolved { strategy_id }
+            } else {
+                EntryBlocked::InFlight { strategy_id }
+            });
+        }
+
+        let now = Instant::now();
+        let mut last_entry = self.last_entry.lock().unwrap();
+        if let Some(last) = last_entry.get(strategy_id) {
+            let elapsed = now.duration_since(*last);
+            if elapsed < min_interval {
+                return Err(EntryBlocked::TooSoon {
+                    remaining: min_interval - elapsed,
+                });
+            }
+        }
+
+        last_entry.insert(strategy_id.to_string(), now);
+        by_symbol.insert(
+            symbol.to_string(),
+            InflightEntry {
+                strategy_id: strategy_id.to_string(),
+                symbol: symbol.to_string(),
+                started_at: Utc::now(),
+                unresolved: false,
+            },
+        );
+        Ok(EntryTicket {
+            registry: self,
+            symbol: symbol.to_string(),
+            closed: false,
+        })
+    }
+
+    /// 미해결 심볼을 수동으로 해제 (포지션 상태를 직접 확인한 뒤)
+    pub fn resolve(&self, symbol: &str) -> Option<InflightEntry> {
+        self.by_symbol.lock().unwrap().remove(symbol)
+    }
+
+    /// 진행 중/미해결 진입 목록 (심볼 순)
+    pub fn report(&self) -> Vec<InflightEntry> {
+        let mut entries: Vec<InflightEntry> =
+            self.by_symbol.lock().unwrap().values().cloned().collect();
+        entries.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        entries
+    }
+
+    fn mark_unresolved(&self, symbol: &str) {
+        if let Some(entry) = self.by_symbol.lock().unwrap().get_mut(symbol) {
+            entry.unresolved = true;
+            error!(
+                "진입 주문 결과 미확정: {} ({}), 수동 확인 전까지 같은 심볼 진입 차단",
+                entry.symbol, entry.strategy_id
+            );
+        }
+    }
+}
+
+/// 진행 중인 진입 주문 티켓
+///
+/// `complete`(포지션 상태 저장 완료) 또는 `release`(주문 실패/취소, 열린 포지션 없음)로
+/// 닫지 않고 버려지면 해당 심볼을 미해결 상태로 남긴다.
+#[must_use = "진입 결과가 확정되면 complete() 또는 release()를 호출해야 한다"]
+pub struct EntryTicket<'a> {
+    registry: &'a InflightOrders,
+    symbol: String,
+    closed: bool,
+}
+
+impl EntryTicket<'_> {
+    /// 포지션이 열리고 상태 저장까지 끝남
+    pub fn complete(mut self) {
+        self.close();
+    }
+
+    /// 주문이 실패했거나 진입을 포기함 (열린 포지션 없음)
+    pub fn release(mut self) {
+        self.close();
+    }
+
+    fn close(&mut self) {
+        self.registry.by_symbol.lock().unwrap().remove(&self.symbol);
+        self.closed = true;
+    }
+}
+
+impl Drop for EntryTicket<'_> {
+    fn drop(&mut self) {
+        if !self.closed {
+            self.registry.mark_unresolved(&self.symbol);
+        }
+    }
+}
+
+static GLOBAL_INFLIGHT_ORDERS: OnceLock<InflightOrders> = OnceLock::new();
+
+/// 전역 진입 주문 저장소
+pub fn inflight_orders() -> &'static InflightOrders {
+    GLOBAL_INFLIGHT_ORDERS.get_or_init(InflightOrders::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflight_blocks_duplicate_and_throttles() {
+        let orders = InflightOrders::default();
+
+        let ticket = orders.try_begin("a", "BTCUSDT", Duration::ZERO).unwrap();
+        assert_eq!(
+            orders.try_begin("b", "BTCUSDT", Duration::ZERO).err(),
+            Some(EntryBlocked::InFlight {
+                strategy_id: "a".to_string()
+            })
+        );
+        // 다른 심볼은 영향 없음
+        orders
+            .try_begin("b", "ETHUSDT", Duration::ZERO)
+            .unwrap()
+            .release();
+        ticket.complete();
+
+        // 최소 간격
+        assert!(matches!(
+            orders.try_begin("a", "BTCUSDT", Duration::from_secs(60)),
+            Err(EntryBlocked::TooSoon { .. })
+        ));
+
+        // 티켓을 닫지 않고 버리면 미해결로 남음
+        drop(orders.try_begin("a", "BTCUSDT", Duration::ZERO).unwrap());
+        assert!(matches!(
+            orders.try_begin("a", "BTCUSDT", Duration::ZERO),
+            Err(EntryBlocked::Unresolved { .. })
+        ));
+        assert!(orders.report()[0].unresolved);
+        assert!(orders.resolve("BTCUSDT").is_some());
+        orders
+            .try_begin("a", "BTCUSDT", Duration::ZERO)
+            .unwrap()
+            .release();
+        assert!(orders.report().is_empty());
+    }
+}
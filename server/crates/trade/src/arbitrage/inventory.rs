@@ -0,0 +1,304 @@
+use chrono::{DateTime, Utc};
+use interface::ExchangeError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const INVENTORY_FILE: &str = "inventory_state.json";
+
+/// 프리미엄 거래소 spot 재고 버퍼 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryParams {
+    /// 유지할 base 자산 수량 (REVERSE 진입에 사용)
+    pub target_qty: f64,
+    /// 한 번에 매수할 최대 수량
+    pub step_qty: f64,
+    /// 매수 사이 최소 간격 (초)
+    pub buy_interval_secs: i64,
+    /// 중립으로 보는 펀딩비 절대값 상한 (0.0001 == 0.01%)
+    pub max_abs_funding_rate: f64,
+    /// 중립으로 보는 베이시스 절대값 상한 (bps)
+    pub max_abs_basis_bps: f64,
+}
+
+impl Default for InventoryParams {
+    fn default() -> Self {
+        Self {
+            target_qty: 0.0,
+            step_qty: 0.001,
+            buy_interval_secs: 60,
+            max_abs_funding_rate: 0.0001,
+            max_abs_basis_bps: 10.0,
+        }
+    }
+}
+
+/// 재고 원가와 손익 기록 (spot 통화 기준)
+///
+/// 버퍼로 산 수량은 평균 원가로 관리하고, REVERSE 진입 시 매도한 수량은
+/// "빌려준" 수량으로 옮겨 원가를 유지한다. 청산 시 되사온 가격과의 차이는
+/// 베이시스 손익으로, 평균 원가 대비 가격 변동은 재고 손익으로 분리한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryLedger {
+    pub asset: String,
+    /// 현재 spot 지갑에 있는 버퍼 수량
+    pub qty: f64,
+    /// REVERSE 포지션에 사용 중인 수량
+    pub lent_qty: f64,
+    /// 버퍼 평균 원가
+    pub avg_cost: f64,
+    /// 빌려준 수량의 평균 매도가
+    pub lent_sell_price: f64,
+    /// 빌려준 수량에 대응하는 선물 롱 평균 진입가
+    pub lent_hedge_price: f64,
+    pub realized_inventory_pnl: f64,
+    pub realized_basis_pnl: f64,
+    pub last_buy_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 재고 손익 보고 (베이시스 손익과 분리)
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryReport {
+    pub asset: String,
+    pub qty: f64,
+    pub lent_qty: f64,
+    pub avg_cost: f64,
+    pub mark_price: f64,
+    /// 평균 원가 대비 평가 손익 (빌려준 수량 포함, 선물 롱으로 노출이 유지되므로)
+    pub unrealized_inventory_pnl: f64,
+    pub realized_inventory_pnl: f64,
+    pub realized_basis_pnl: f64,
+}
+
+impl InventoryLedger {
+    pub fn new(asset: String) -> Self {
+        Self {
+            asset,
+            qty: 0.0,
+            lent_qty: 0.0,
+            avg_cost: 0.0,
+            lent_sell_price: 0.0,
+            lent_hedge_price: 0.0,
+            realized_inventory_pnl: 0.0,
+            realized_basis_pnl: 0.0,
+            last_buy_at: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// 저장된 원장 읽기 (파일이 없거나 다른 자산이면 새 원장)
+    pub fn read(asset: &str) -> Result<Self, ExchangeError> {
+        if !Path::new(INVENTORY_FILE).exists() {
+            return Ok(Self::new(asset.to_string()));
+        }
+
+        let content = fs::read_to_string(INVENTORY_FILE)
+            .map_err(|e| ExchangeError::Other(format!("Failed to read inventory file: {}", e)))?;
+        let ledger: InventoryLedger = serde_json::from_str(&content)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse inventory file: {}", e)))?;
+
+        if ledger.asset != asset {
+            return Ok(Self::new(asset.to_string()));
+        }
+        Ok(ledger)
+    }
+
+    pub fn write(&self) -> Result<(), ExchangeError> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| ExchangeError::Other(format!("Failed to serialize inventory: {}", e)))?;
+        fs::write(INVENTORY_FILE, content)
+            .map_err(|e| ExchangeError::Other(format!("Failed to write inventory file: {}", e)))?;
+        Ok(())
+    }
+
+    pub fn total_qty(&self) -> f64 {
+        self.qty + self.lent_qty
+    }
+
+    /// 버퍼 매수 (평균 원가 갱신)
+    pub fn record_buy(&mut self, qty: f64, price: f64, now: DateTime<Utc>) {
+        if qty <= 0.0 {
+            return;
+        }
+        let total = self.total_qty();
+        self.avg_cost = (self.avg_cost * total + price * qty) / (total + qty);
+        self.qty += qty;
+        self.last_buy_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// 버퍼 직접 매도 (재고 손익 실현)
+    pub fn record_sell(&mut self, qty: f64, price: f64) {
+        let qty = qty.min(self.qty);
+        if qty <= 0.0 {
+            return;
+        }
+        self.realized_inventory_pnl += (price - self.avg_cost) * qty;
+        self.qty -= qty;
+        if self.total_qty() <= 0.0 {
+            self.avg_cost = 0.0;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// REVERSE 진입으로 버퍼를 매도. 실제로 빌려준 수량 반환
+    pub fn lend_for_reverse(&mut self, qty: f64, sell_price: f64, hedge_price: f64) -> f64 {
+        let qty = qty.min(self.qty);
+        if qty <= 0.0 {
+            return 0.0;
+        }
+        let lent = self.lent_qty + qty;
+        self.lent_sell_price = (self.lent_sell_price * self.lent_qty + sell_price * qty) / lent;
+        self.lent_hedge_price = (self.lent_hedge_price * self.lent_qty + hedge_price * qty) / lent;
+        self.lent_qty = lent;
+        self.qty -= qty;
+        self.updated_at = Utc::now();
+        qty
+    }
+
+    /// REVERSE 청산으로 되사온 수량 반환
+    /// fx_adjustment: spot 가격을 선물 통화로 바꾸는 계수 (선물 손익은 spot 통화로 환산해 기록)
+    pub fn return_from_reverse(
+        &mut self,
+        qty: f64,
+        buy_price: f64,
+        hedge_close_price: f64,
+        fx_adjustment: f64,
+    ) {
+        let qty = qty.min(self.lent_qty);
+        if qty <= 0.0 || fx_adjustment <= 0.0 {
+            return;
+        }
+        let hedge_pnl = (hedge_close_price - self.lent_hedge_price) * qty / fx_adjustment;
+        self.realized_basis_pnl += (self.lent_sell_price - buy_price) * qty + hedge_pnl;
+        self.lent_qty -= qty;
+        self.qty += qty;
+        if self.lent_qty <= 0.0 {
+            self.lent_sell_price = 0.0;
+            self.lent_hedge_price = 0.0;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    pub fn report(&self, mark_price: f64) -> InventoryReport {
+        InventoryReport {
+            asset: self.asset.clone(),
+            qty: self.qty,
+            lent_qty: self.lent_qty,
+            avg_cost: self.avg_cost,
+            mark_price,
+            unrealized_inventory_pnl: (mark_price - self.avg_cost) * self.total_qty(),
+            realized_inventory_pnl: self.realized_inventory_pnl,
+            realized_basis_pnl: self.realized_basis_pnl,
+        }
+    }
+}
+
+/// spot 재고 버퍼 관리자
+///
+/// 포지션이 없고 펀딩/베이시스가 중립일 때만 목표 수량까지 조금씩 매수해
+/// REVERSE 진입에 필요한 재고를 미리 쌓아둔다.
+#[derive(Debug, Clone)]
+pub struct InventoryManager {
+    pub params: InventoryParams,
+    pub ledger: InventoryLedger,
+}
+
+impl InventoryManager {
+    pub fn new(params: InventoryParams, ledger: InventoryLedger) -> Self {
+        Self { params, ledger }
+    }
+
+    /// 이번 주기에 매수할 수량 (조건이 맞지 않으면 None)
+    /// balance: 거래소에서 조회한 현재 spot 보유량
+    pub fn plan_buy(
+        &self,
+        balance: f64,
+        position_open: bool,
+        basis_bps: f64,
+        funding_rate: Option<f64>,
+        now: DateTime<Utc>,
+    ) -> Option<f64> {
+        if position_open || balance >= self.params.target_qty {
+            return None;
+        }
+        if basis_bps.abs() > self.params.max_abs_basis_bps {
+            return None;
+        }
+        if funding_rate.is_some_and(|rate| rate.abs() > self.params.max_abs_funding_rate) {
+            return None;
+        }
+        if let Some(last) = self.ledger.last_buy_at
+            && (now - last).num_seconds() < self.params.buy_interval_secs
+        {
+            return None;
+        }
+
+        let qty = (self.params.target_qty - balance).min(self.params.step_qty);
+        (qty > 0.0).then_some(qty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_plan_buy_only_when_flat_and_neutral() {
+        let params = InventoryParams {
+            target_qty: 0.01,
+            step_qty: 0.004,
+            buy_interval_secs: 60,
+            max_abs_funding_rate: 0.0001,
+            max_abs_basis_bps: 10.0,
+        };
+        let mut manager = InventoryManager::new(params, InventoryLedger::new("BTC".to_string()));
+        let now = Utc::now();
+
+        assert_eq!(
+            manager.plan_buy(0.0, false, 2.0, Some(0.00005), now),
+            Some(0.004)
+        );
+        assert_eq!(manager.plan_buy(0.008, false, 2.0, None, now), Some(0.002));
+        assert!(manager.plan_buy(0.0, true, 2.0, None, now).is_none());
+        assert!(manager.plan_buy(0.0, false, 25.0, None, now).is_none());
+        assert!(
+            manager
+                .plan_buy(0.0, false, 2.0, Some(0.0003), now)
+                .is_none()
+        );
+        assert!(manager.plan_buy(0.01, false, 2.0, None, now).is_none());
+
+        manager.ledger.record_buy(0.004, 100.0, now);
+        assert!(manager.plan_buy(0.004, false, 2.0, None, now).is_none());
+        assert!(
+            manager
+                .plan_buy(0.004, false, 2.0, None, now + Duration::seconds(61))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_ledger_separates_inventory_and_basis_pnl() {
+        let mut ledger = InventoryLedger::new("BTC".to_string());
+        ledger.record_buy(1.0, 100.0, Utc::now());
+        ledger.record_buy(1.0, 110.0, Utc::now());
+        assert!((ledger.avg_cost - 105.0).abs() < 1e-9);
+
+        // REVERSE: 120에 매도 + 선물 120 롱, 115에 되사옴 + 선물 117 청산
+        assert_eq!(ledger.lend_for_reverse(1.0, 120.0, 120.0), 1.0);
+        assert_eq!(ledger.qty, 1.0);
+        ledger.return_from_reverse(1.0, 115.0, 117.0, 1.0);
+        assert!((ledger.realized_basis_pnl - 2.0).abs() < 1e-9);
+        assert_eq!(ledger.qty, 2.0);
+        assert!((ledger.avg_cost - 105.0).abs() < 1e-9);
+
+        let report = ledger.report(110.0);
+        assert!((report.unrealized_inventory_pnl - 10.0).abs() < 1e-9);
+
+        ledger.record_sell(1.0, 125.0);
+        assert!((ledger.realized_inventory_pnl - 20.0).abs() < 1e-9);
+    }
+}
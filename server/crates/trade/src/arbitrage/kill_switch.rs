@@ -0,0 +1,291 @@
+//! 가격 이상 감지 및 전략별 킬 스위치
+//!
+//! 매 반복마다 현물/선물 가격을 직전 정상 가격과 비교해 한 tick에 기준 이상 튀었거나
+//! 현·선물 스프레드가 비정상적으로 벌어졌으면 잘못된 데이터로 보고 그 반복을 건너뛴다.
+//! 이상 상태가 일정 시간 이상 이어지면 전략의 킬 스위치를 내리고, 제어 API
+//! (`POST /strategy/:id/kill-switch/rearm`)로 직접 재가동할 때까지 주문을 멈춘다.
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::{error, trace, warn};
+
+use crate::notification::{AlertLevel, notification_center};
+
+/// 킬 스위치 알림 종류
+pub const KILL_SWITCH_ALERT: &str = "kill_switch";
+
+/// 킬 스위치 작동 중 재가동 여부를 확인하는 간격
+const TRIPPED_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 가격 이상 감지 기준
+#[derive(Debug, Clone, Copy)]
+pub struct PriceGuardParams {
+    /// 직전 정상 가격 대비 이 비율(%) 이상 움직이면 이상 tick
+    pub max_jump_pct: f64,
+    /// 현·선물 스프레드 절댓값이 이 값(bps) 이상이면 이상 tick
+    pub max_spread_bps: f64,
+    /// 이상 상태가 이 시간 이상 이어지면 킬 스위치 작동
+    pub trip_after: Duration,
+}
+
+impl Default for PriceGuardParams {
+    fn default() -> Self {
+        Self {
+            max_jump_pct: 3.0,
+            max_spread_bps: 1_000.0,
+            trip_after: Duration::from_secs(10),
+        }
+    }
+}
+
+/// 가격 검사 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum PriceCheck {
+    Ok,
+    /// 이번 반복만 건너뜀
+    Skip(String),
+    /// 이상 상태가 지속되어 킬 스위치 작동
+    Trip(String),
+}
+
+/// 전략 루프 안에서 쓰는 가격 이상 감지기
+#[derive(Debug, Clone)]
+pub struct PriceGuard {
+    params: PriceGuardParams,
+    last_spot: Option<f64>,
+    last_futures: Option<f64>,
+    /// 연속 이상 tick 수와 첫 이상 시각
+    consecutive: u32,
+    anomaly_since: Option<Instant>,
+}
+
+impl PriceGuard {
+    pub fn new(params: PriceGuardParams) -> Self {
+        Self {
+            params,
+            last_spot: None,
+            last_futures: None,
+            consecutive: 0,
+            anomaly_since: None,
+        }
+    }
+
+    /// 가격 검사. 정상 가격만 다음 비교 기준이 된다
+    pub fn check(&mut self, spot: f64, futures: f64) -> PriceCheck {
+        self.check_at(spot, futures, Instant::now())
+    }
+
+    fn check_at(&mut self, spot: f64, futures: f64, now: Instant) -> PriceCheck {
+        let Some(reason) = self.anomaly(spot, futures) else {
+            self.last_spot = Some(spot);
+            self.last_futures = Some(futures);
+            self.consecutive = 0;
+            self.anomaly_since = None;
+            return PriceCheck::Ok;
+        };
+
+        self.consecutive += 1;
+        let since = *self.anomaly_since.get_or_insert(now);
+        let elapsed = now.duration_since(since);
+        let reason = format!(
+            "{} ({}회 연속, {:.1}초)",
+            reason,
+            self.consecutive,
+            elapsed.as_secs_f64()
+        );
+        if elapsed >= self.params.trip_after {
+            PriceCheck::Trip(reason)
+        } else {
+            PriceCheck::Skip(reason)
+        }
+    }
+
+    /// 기준 가격 초기화 (재가동 후 첫 가격을 새 기준으로 사용)
+    pub fn reset(&mut self) {
+        self.last_spot = None;
+        self.last_futures = None;
+        self.consecutive = 0;
+        self.anomaly_since = None;
+    }
+
+    fn anomaly(&self, spot: f64, futures: f64) -> Option<String> {
+        if spot <= 0.0 || futures <= 0.0 || !spot.is_finite() || !futures.is_finite() {
+            return Some(format!("invalid price spot={} futures={}", spot, futures));
+        }
+
+        let jump_pct = |last: Option<f64>, now: f64| {
+            last.map(|last| (now - last).abs() / last * 100.0)
+                .unwrap_or(0.0)
+        };
+        let spot_jump = jump_pct(self.last_spot, spot);
+        if spot_jump > self.params.max_jump_pct {
+            return Some(format!("spot jump {:.2}%", spot_jump));
+        }
+        let futures_jump = jump_pct(self.last_futures, futures);
+        if futures_jump > self.params.max_jump_pct {
+            return Some(format!("futures jump {:.2}%", futures_jump));
+        }
+
+        let spread_bps = (futures - spot) / spot * 10_000.0;
+        if spread_bps.abs() > self.params.max_spread_bps {
+            return Some(format!("spread {:.1} bps", spread_bps));
+        }
+        None
+    }
+}
+
+/// 작동한 킬 스위치
+#[derive(Debug, Clone, Serialize)]
+pub struct KillSwitchTrip {
+    pub strategy_id: String,
+    pub reason: String,
+    pub tripped_at: DateTime<Utc>,
+}
+
+/// 전략 ID별 킬 스위치 상태
+#[derive(Debug, Default)]
+pub struct KillSwitchRegistry {
+    tripped: RwLock<HashMap<String, KillSwitchTrip>>,
+}
+
+impl KillSwitchRegistry {
+    pub fn trip(&self, strategy_id: &str, reason: &str) {
+        self.tripped.write().unwrap().insert(
+            strategy_id.to_string(),
+            KillSwitchTrip {
+                strategy_id: strategy_id.to_string(),
+                reason: reason.to_string(),
+                tripped_at: Utc::now(),
+            },
+        );
+    }
+
+    pub fn is_tripped(&self, strategy_id: &str) -> bool {
+        self.tripped.read().unwrap().contains_key(strategy_id)
+    }
+
+    /// 킬 스위치 해제. 작동 중이었으면 해제된 기록 반환
+    pub fn rearm(&self, strategy_id: &str) -> Option<KillSwitchTrip> {
+        self.tripped.write().unwrap().remove(strategy_id)
+    }
+
+    /// 작동 중인 킬 스위치 목록 (전략 ID 순)
+    pub fn report(&self) -> Vec<KillSwitchTrip> {
+        let mut trips: Vec<KillSwitchTrip> =
+            self.tripped.read().unwrap().values().cloned().collect();
+        trips.sort_by(|a, b| a.strategy_id.cmp(&b.strategy_id));
+        trips
+    }
+}
+
+/// 전략 루프 반복마다 호출. false면 이번 반복의 주문 판단을 건너뛴다
+/// 킬 스위치가 작동 중이면 재가동될 때까지 잠시 대기하고, 재가동 후 첫 가격을 새 기준으로 쓴다
+pub async fn guard_iteration(
+    strategy_id: &str,
+    guard: &mut PriceGuard,
+    spot: f64,
+    futures: f64,
+) -> bool {
+    if kill_switches().is_tripped(strategy_id) {
+        guard.reset();
+        tokio::time::sleep(TRIPPED_POLL_INTERVAL).await;
+        return false;
+    }
+
+    match guard.check(spot, futures) {
+        PriceCheck::Ok => true,
+        PriceCheck::Skip(reason) => {
+            if guard.consecutive == 1 {
+                warn!("{} 가격 이상, 반복 건너뜀: {}", strategy_id, reason);
+            } else {
+                trace!("{} 가격 이상 지속: {}", strategy_id, reason);
+            }
+            false
+        }
+        PriceCheck::Trip(reason) => {
+            error!("{} 킬 스위치 작동: {}", strategy_id, reason);
+            kill_switches().trip(strategy_id, &reason);
+            guard.reset();
+            notification_center()
+                .notify(
+                    KILL_SWITCH_ALERT,
+                    AlertLevel::Critical,
+                    format!("{} 킬 스위치 작동", strategy_id),
+                    format!(
+                        "{} (재가동: POST /strategy/{}/kill-switch/rearm)",
+                        reason, strategy_id
+                    ),
+                    serde_json::json!({
+                        "strategy_id": strategy_id,
+                        "reason": reason,
+                        "spot": spot,
+                        "futures": futures,
+                    }),
+                )
+                .await;
+            false
+        }
+    }
+}
+
+static GLOBAL_KILL_SWITCHES: OnceLock<KillSwitchRegistry> = OnceLock::new();
+
+/// 전역 킬 스위치 저장소
+pub fn kill_switches() -> &'static KillSwitchRegistry {
+    GLOBAL_KILL_SWITCHES.get_or_init(KillSwitchRegistry::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_guard_skips_then_trips() {
+        let mut guard = PriceGuard::new(PriceGuardParams {
+            max_jump_pct: 2.0,
+            max_spread_bps: 100.0,
+            trip_after: Duration::from_secs(5),
+        });
+        let t0 = Instant::now();
+        assert_eq!(guard.check_at(100.0, 100.1, t0), PriceCheck::Ok);
+
+        // 한 번 튄 가격은 건너뛰고 기준 가격은 유지
+        assert!(matches!(
+            guard.check_at(150.0, 100.1, t0),
+            PriceCheck::Skip(_)
+        ));
+        assert_eq!(guard.check_at(100.5, 100.6, t0), PriceCheck::Ok);
+
+        // 스프레드 이상이 trip_after 이상 이어지면 작동
+        let t1 = t0 + Duration::from_secs(1);
+        assert!(matches!(
+            guard.check_at(100.5, 102.0, t1),
+            PriceCheck::Skip(_)
+        ));
+        assert!(matches!(
+            guard.check_at(100.5, 102.0, t1 + Duration::from_secs(4)),
+            PriceCheck::Skip(_)
+        ));
+        let PriceCheck::Trip(reason) = guard.check_at(100.5, 102.0, t1 + Duration::from_secs(5))
+        else {
+            panic!("expected trip");
+        };
+        assert!(reason.contains("spread"));
+
+        let registry = KillSwitchRegistry::default();
+        registry.trip("intra_basis:BTCUSDT", &reason);
+        assert!(registry.is_tripped("intra_basis:BTCUSDT"));
+        assert_eq!(registry.report().len(), 1);
+        assert!(registry.rearm("intra_basis:BTCUSDT").is_some());
+        assert!(!registry.is_tripped("intra_basis:BTCUSDT"));
+        assert!(registry.rearm("intra_basis:BTCUSDT").is_none());
+
+        // 재가동 후에는 새 가격이 기준
+        guard.reset();
+        assert_eq!(guard.check_at(120.0, 120.1, t1), PriceCheck::Ok);
+    }
+}
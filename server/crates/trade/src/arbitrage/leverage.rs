@@ -0,0 +1,315 @@
+//! 변동성 기반 동적 레버리지
+//!
+//! 고정 레버리지는 변동성이 낮을 때는 증거금을 놀리고, 높을 때는 청산 위험을 키운다.
+//! 최근 가격 변동성(1분봉, `volatility_registry`)을 보유 기간으로 늘린 값과 관측한 베이시스
+//! 표준편차로 버텨야 할 가격 이동 폭을 잡고, 그 폭 + 유지 증거금률만큼 청산 거리를 남기는
+//! 가장 큰 레버리지를 `[min_leverage, max_leverage]` 안에서 고른다.
+//!
+//! 포지션 보유 중에는 실제 청산 거리(positionRisk)가 요구 버퍼보다 가까우면 한 단계 더 낮추고,
+//! 레버리지를 올리는 것은 포지션이 없을 때만 한다.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::volatility::{VolatilityEstimate, VolatilityMeasure};
+
+/// 동적 레버리지 설정
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicLeverage {
+    pub min_leverage: u32,
+    pub max_leverage: u32,
+    /// 가격 변동성 지표 (1분 기준)
+    pub measure: VolatilityMeasure,
+    /// 버텨야 할 보유 기간 (분). 1분 변동성 × √horizon_minutes 만큼의 이동을 가정
+    pub horizon_minutes: f64,
+    /// 변동성 배수 (예: 3.0 = 보유 기간 변동성의 3배 이동까지 청산되지 않게)
+    pub buffer_multiple: f64,
+    /// 유지 증거금률 (bps, 예: 50 = 0.5%)
+    pub maintenance_margin_bps: f64,
+    /// 조정에 필요한 최소 완성 봉 개수 (부족하면 조정 안 함)
+    pub min_bars: usize,
+    /// 재평가 간격
+    pub check_interval: Duration,
+    /// 베이시스 표준편차 계산 구간
+    pub basis_window: Duration,
+}
+
+impl Default for DynamicLeverage {
+    fn default() -> Self {
+        Self {
+            min_leverage: 1,
+            max_leverage: 5,
+            measure: VolatilityMeasure::Atr,
+            horizon_minutes: 1_440.0,
+            buffer_multiple: 3.0,
+            maintenance_margin_bps: 50.0,
+            min_bars: 15,
+            check_interval: Duration::from_secs(60),
+            basis_window: Duration::from_secs(3_600),
+        }
+    }
+}
+
+impl DynamicLeverage {
+    /// ARB_DYN_LEVERAGE_MAX(필수) / ARB_DYN_LEVERAGE_MIN / ARB_DYN_LEVERAGE_HORIZON_MIN /
+    /// ARB_DYN_LEVERAGE_BUFFER / ARB_DYN_LEVERAGE_MMR_BPS / ARB_DYN_LEVERAGE_INTERVAL_SECS
+    /// 최대 레버리지가 설정되지 않으면 None
+    pub fn from_env() -> Option<Self> {
+        fn var<T: std::str::FromStr>(key: &str) -> Option<T> {
+            std::env::var(key).ok().and_then(|v| v.parse::<T>().ok())
+        }
+        let defaults = Self::default();
+        let max_leverage = var::<u32>("ARB_DYN_LEVERAGE_MAX")?.max(1);
+        Some(Self {
+            min_leverage: var::<u32>("ARB_DYN_LEVERAGE_MIN")
+                .unwrap_or(defaults.min_leverage)
+                .clamp(1, max_leverage),
+            max_leverage,
+            horizon_minutes: var("ARB_DYN_LEVERAGE_HORIZON_MIN")
+                .unwrap_or(defaults.horizon_minutes),
+            buffer_multiple: var("ARB_DYN_LEVERAGE_BUFFER").unwrap_or(defaults.buffer_multiple),
+            maintenance_margin_bps: var("ARB_DYN_LEVERAGE_MMR_BPS")
+                .unwrap_or(defaults.maintenance_margin_bps),
+            check_interval: var::<u64>("ARB_DYN_LEVERAGE_INTERVAL_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.check_interval),
+            ..defaults
+        })
+    }
+
+    /// 청산까지 남겨야 하는 거리 (bps)
+    /// buffer_multiple × (1분 가격 변동성 × √horizon + 베이시스 σ) + 유지 증거금률
+    pub fn required_buffer_bps(&self, price_vol_bps: f64, basis_vol_bps: f64) -> f64 {
+        self.buffer_multiple
+            * (price_vol_bps * self.horizon_minutes.max(1.0).sqrt() + basis_vol_bps)
+            + self.maintenance_margin_bps
+    }
+
+    /// 요구 버퍼를 남기는 최대 레버리지 (레버리지 L의 청산 거리 ≈ 1/L)
+    pub fn target_leverage(&self, required_buffer_bps: f64) -> u32 {
+        if !required_buffer_bps.is_finite() || required_buffer_bps <= 0.0 {
+            return self.max_leverage;
+        }
+        let max_safe = (10_000.0 / required_buffer_bps).floor();
+        (max_safe.min(self.max_leverage as f64) as u32).clamp(self.min_leverage, self.max_leverage)
+    }
+}
+
+/// 레버리지 변경 결정
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LeverageDecision {
+    pub from: u32,
+    pub to: u32,
+    /// 1분 가격 변동성 (bps)
+    pub price_vol_bps: f64,
+    /// 베이시스 표준편차 (bps)
+    pub basis_vol_bps: f64,
+    /// 요구 청산 거리 (bps)
+    pub required_buffer_bps: f64,
+    /// 현재 포지션의 청산 거리 (bps, 포지션이 없거나 조회 실패 시 None)
+    pub liquidation_distance_bps: Option<f64>,
+    pub reason: String,
+}
+
+/// 전략 하나의 레버리지 조정 상태
+#[derive(Debug)]
+pub struct LeverageAdjuster {
+    params: DynamicLeverage,
+    current: u32,
+    basis: VecDeque<(Instant, f64)>,
+    last_check: Option<Instant>,
+}
+
+impl LeverageAdjuster {
+    pub fn new(params: DynamicLeverage, initial: u32) -> Self {
+        Self {
+            current: initial.clamp(params.min_leverage, params.max_leverage),
+            params,
+            basis: VecDeque::new(),
+            last_check: None,
+        }
+    }
+
+    /// 현재 레버리지
+    pub fn current(&self) -> u32 {
+        self.current
+    }
+
+    /// 베이시스 표본 기록 (구간 밖 표본은 버림)
+    pub fn record_basis(&mut self, now: Instant, basis_bps: f64) {
+        if !basis_bps.is_finite() {
+            return;
+        }
+        self.basis.push_back((now, basis_bps));
+        while let Some((at, _)) = self.basis.front() {
+            if now.duration_since(*at) <= self.params.basis_window {
+                break;
+            }
+            self.basis.pop_front();
+        }
+    }
+
+    /// 구간 내 베이시스 표본 표준편차 (bps, 표본이 2개 미만이면 0)
+    pub fn basis_std_bps(&self) -> f64 {
+        let n = self.basis.len();
+        if n < 2 {
+            return 0.0;
+        }
+        let mean = self.basis.iter().map(|(_, b)| b).sum::<f64>() / n as f64;
+        let var = self
+            .basis
+            .iter()
+            .map(|(_, b)| (b - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1) as f64;
+        var.sqrt()
+    }
+
+    /// 재평가 시점이면 true (첫 호출은 항상 true)
+    pub fn check_due(&mut self, now: Instant) -> bool {
+        if let Some(last) = self.last_check
+            && now.duration_since(last) < self.params.check_interval
+        {
+            return false;
+        }
+        self.last_check = Some(now);
+        true
+    }
+
+    /// 바꿔야 할 레버리지 (그대로면 None)
+    pub fn decide(
+        &self,
+        estimate: Option<&VolatilityEstimate>,
+        position_open: bool,
+        liquidation_distance_bps: Option<f64>,
+    ) -> Option<LeverageDecision> {
+        let estimate = estimate.filter(|e| e.bars >= self.params.min_bars)?;
+        let price_vol_bps = estimate.value(self.params.measure);
+        if !price_vol_bps.is_finite() || price_vol_bps <= 0.0 {
+            return None;
+        }
+        let basis_vol_bps = self.basis_std_bps();
+        let required = self
+            .params
+            .required_buffer_bps(price_vol_bps, basis_vol_bps);
+        let mut target = self.params.target_leverage(required);
+        let mut reason = format!(
+            "required buffer {:.0} bps (vol {:.2} bps, basis σ {:.2} bps)",
+            required, price_vol_bps, basis_vol_bps
+        );
+
+        if position_open {
+            // 실제 청산 거리가 버퍼보다 가까우면 한 단계 더 낮춤
+            if let Some(distance) = liquidation_distance_bps
+                && distance < required
+            {
+                target = target
+                    .min(self.current.saturating_sub(1))
+                    .max(self.params.min_leverage);
+                reason = format!("liquidation distance {:.0} bps < {}", distance, reason);
+            }
+            // 보유 중에는 낮추기만 한다
+            if target > self.current {
+                return None;
+            }
+        }
+
+        if target == self.current {
+            return None;
+        }
+        Some(LeverageDecision {
+            from: self.current,
+            to: target,
+            price_vol_bps,
+            basis_vol_bps,
+            required_buffer_bps: required,
+            liquidation_distance_bps,
+            reason,
+        })
+    }
+
+    /// 거래소에 반영된 레버리지 기록
+    pub fn apply(&mut self, leverage: u32) {
+        self.current = leverage;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(atr_bps: f64) -> VolatilityEstimate {
+        VolatilityEstimate {
+            atr_bps,
+            return_std_bps: 0.0,
+            bars: 30,
+        }
+    }
+
+    #[test]
+    fn test_leverage_follows_volatility_within_band() {
+        let params = DynamicLeverage {
+            min_leverage: 1,
+            max_leverage: 10,
+            horizon_minutes: 100.0,
+            buffer_multiple: 2.0,
+            maintenance_margin_bps: 50.0,
+            ..Default::default()
+        };
+        // 2 × (10 × 10) + 50 = 250 bps → 40배지만 최대 10배
+        assert_eq!(params.required_buffer_bps(10.0, 0.0), 250.0);
+        assert_eq!(params.target_leverage(250.0), 10);
+        // 2 × (100 × 10 + 25) + 50 = 2100 bps → 4배
+        assert_eq!(
+            params.target_leverage(params.required_buffer_bps(100.0, 25.0)),
+            4
+        );
+        // 극단적 변동성이어도 최소 1배
+        assert_eq!(params.target_leverage(50_000.0), 1);
+
+        let mut adjuster = LeverageAdjuster::new(params, 3);
+        // 봉이 부족하면 조정 안 함
+        let few = VolatilityEstimate {
+            bars: 3,
+            ..estimate(100.0)
+        };
+        assert!(adjuster.decide(Some(&few), false, None).is_none());
+
+        let up = adjuster
+            .decide(Some(&estimate(100.0)), false, None)
+            .unwrap();
+        assert_eq!((up.from, up.to), (3, 4));
+        adjuster.apply(up.to);
+
+        // 보유 중에는 올리지 않는다
+        assert!(adjuster.decide(Some(&estimate(10.0)), true, None).is_none());
+        // 청산 거리가 버퍼보다 가까우면 목표보다 한 단계 더 낮춤
+        let down = adjuster
+            .decide(Some(&estimate(100.0)), true, Some(1_500.0))
+            .unwrap();
+        assert_eq!((down.from, down.to), (4, 3));
+        assert!(down.reason.starts_with("liquidation distance 1500 bps"));
+    }
+
+    #[test]
+    fn test_basis_window_and_check_interval() {
+        let params = DynamicLeverage {
+            check_interval: Duration::from_secs(60),
+            basis_window: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let mut adjuster = LeverageAdjuster::new(params, 2);
+        let t0 = Instant::now();
+        adjuster.record_basis(t0, 100.0);
+        adjuster.record_basis(t0 + Duration::from_secs(20), 2.0);
+        adjuster.record_basis(t0 + Duration::from_secs(21), 4.0);
+        // 오래된 100bps 표본은 빠짐
+        assert!((adjuster.basis_std_bps() - 2f64.sqrt()).abs() < 1e-9);
+
+        assert!(adjuster.check_due(t0));
+        assert!(!adjuster.check_due(t0 + Duration::from_secs(30)));
+        assert!(adjuster.check_due(t0 + Duration::from_secs(60)));
+    }
+}
@@ -0,0 +1,199 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use interface::{BookSide, ExchangeId, OrderBook, PerpData, UnifiedSnapshot};
+
+use crate::oracle_client::OracleClient;
+
+/// Oracle 스냅샷을 다시 받는 주기 (24h 거래대금/OI는 천천히 변하므로 매 틱 조회하지 않음)
+const SNAPSHOT_TTL: Duration = Duration::from_secs(60);
+
+/// 진입을 허용하는 최소 유동성 (USD 기준, 0이면 해당 항목은 확인 안 함)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct LiquidityFloors {
+    /// 무기한 선물 24시간 거래대금
+    pub min_perp_vol_24h_usd: f64,
+    /// 무기한 선물 미결제약정
+    pub min_perp_oi_usd: f64,
+    /// 스팟 호가 깊이 (중간가 ±`depth_band_bps` 안의 매수/매도 잔량 중 작은 쪽)
+    pub min_spot_depth_usd: f64,
+    pub depth_band_bps: f64,
+}
+
+impl Default for LiquidityFloors {
+    fn default() -> Self {
+        Self {
+            min_perp_vol_24h_usd: 0.0,
+            min_perp_oi_usd: 0.0,
+            min_spot_depth_usd: 0.0,
+            depth_band_bps: 20.0,
+        }
+    }
+}
+
+impl LiquidityFloors {
+    /// ARB_MIN_PERP_VOL_USD / ARB_MIN_PERP_OI_USD / ARB_MIN_SPOT_DEPTH_USD / ARB_DEPTH_BAND_BPS
+    /// 최소값이 하나도 설정되지 않으면 None
+    pub fn from_env() -> Option<Self> {
+        let var = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<f64>().ok());
+        let vol = var("ARB_MIN_PERP_VOL_USD");
+        let oi = var("ARB_MIN_PERP_OI_USD");
+        let depth = var("ARB_MIN_SPOT_DEPTH_USD");
+        if vol.is_none() && oi.is_none() && depth.is_none() {
+            return None;
+        }
+        let defaults = Self::default();
+        Some(Self {
+            min_perp_vol_24h_usd: vol.unwrap_or(0.0),
+            min_perp_oi_usd: oi.unwrap_or(0.0),
+            min_spot_depth_usd: depth.unwrap_or(0.0),
+            depth_band_bps: var("ARB_DEPTH_BAND_BPS").unwrap_or(defaults.depth_band_bps),
+        })
+    }
+
+    /// 최소값에 못 미치는 항목이 있으면 사유 반환 (조회하지 못한 항목은 통과)
+    pub fn veto(&self, perp: Option<&PerpData>, spot_depth_usd: Option<f64>) -> Option<String> {
+        let mut reasons = Vec::new();
+        if let Some(perp) = perp {
+            if perp.vol_24h_usd < self.min_perp_vol_24h_usd {
+                reasons.push(format!(
+                    "perp 24h volume ${:.0} < ${:.0}",
+                    perp.vol_24h_usd, self.min_perp_vol_24h_usd
+                ));
+            }
+            if perp.oi_usd < self.min_perp_oi_usd {
+                reasons.push(format!(
+                    "perp OI ${:.0} < ${:.0}",
+                    perp.oi_usd, self.min_perp_oi_usd
+                ));
+            }
+        }
+        if let Some(depth) = spot_depth_usd
+            && depth < self.min_spot_depth_usd
+        {
+            reasons.push(format!(
+                "spot depth ±{} bps ${:.0} < ${:.0}",
+                self.depth_band_bps, depth, self.min_spot_depth_usd
+            ));
+        }
+        (!reasons.is_empty()).then(|| reasons.join(", "))
+    }
+}
+
+/// 중간가 ±`band_bps` 안의 호가 명목가 (매수/매도 중 작은 쪽, 진입과 청산 모두 한쪽을 먹으므로)
+pub fn spot_depth_usd(book: &OrderBook, band_bps: f64) -> Option<f64> {
+    let mid = book.mid_price()?;
+    let band = mid * band_bps / 10_000.0;
+    let notional = |side: BookSide, limit: f64| -> f64 {
+        book.levels(side)
+            .iter()
+            .take_while(|level| match side {
+                BookSide::Bids => level.price >= limit,
+                BookSide::Asks => level.price <= limit,
+            })
+            .map(|level| level.price * level.quantity)
+            .sum()
+    };
+    Some(notional(BookSide::Bids, mid - band).min(notional(BookSide::Asks, mid + band)))
+}
+
+/// Oracle 스냅샷으로 무기한 선물 유동성을 조회하는 진입 게이트
+pub struct LiquidityGate {
+    pub floors: LiquidityFloors,
+    oracle: OracleClient,
+    snapshots: Mutex<Option<(Instant, Vec<UnifiedSnapshot>)>>,
+}
+
+impl LiquidityGate {
+    pub fn new(floors: LiquidityFloors) -> Self {
+        Self {
+            floors,
+            oracle: OracleClient::from_env(),
+            snapshots: Mutex::new(None),
+        }
+    }
+
+    /// 거래소/심볼의 무기한 선물 데이터 (Oracle 조회 실패 시 직전 스냅샷, 없으면 None)
+    pub async fn perp_data(&self, exchange: ExchangeId, symbol: &str) -> Option<PerpData> {
+        let mut cached = self.snapshots.lock().await;
+        let stale = cached
+            .as_ref()
+            .is_none_or(|(fetched_at, _)| fetched_at.elapsed() >= SNAPSHOT_TTL);
+        if stale {
+            match self.oracle.fetch_signal_snapshots().await {
+                Ok(snapshots) => *cached = Some((Instant::now(), snapshots)),
+                Err(e) => warn!("Failed to fetch oracle snapshots for liquidity gate: {}", e),
+            }
+        }
+        cached
+            .as_ref()?
+            .1
+            .iter()
+            .find(|s| s.exchange == exchange && s.symbol == symbol)
+            .and_then(|s| s.perp.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use interface::{Currency, OrderBookEntry, Price};
+
+    fn perp(vol_24h_usd: f64, oi_usd: f64) -> PerpData {
+        PerpData {
+            currency: Currency::USDT,
+            mark_price: Price::new(100.0),
+            index_price: None,
+            oi_usd,
+            vol_24h_usd,
+            funding_rate: 0.0001,
+            predicted_funding_rate: None,
+            next_funding_time: None,
+            funding_interval_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_liquidity_veto() {
+        let floors = LiquidityFloors {
+            min_perp_vol_24h_usd: 10_000_000.0,
+            min_perp_oi_usd: 5_000_000.0,
+            min_spot_depth_usd: 50_000.0,
+            depth_band_bps: 20.0,
+        };
+
+        let entry = |price: f64, quantity: f64| OrderBookEntry { price, quantity };
+        // 중간가 100, ±0.2% → 99.8 ~ 100.2
+        let book = OrderBook {
+            exchange: ExchangeId::Binance,
+            symbol: "THINUSDT".to_string(),
+            bids: vec![entry(99.9, 600.0), entry(99.5, 10_000.0)],
+            asks: vec![
+                entry(100.1, 300.0),
+                entry(100.2, 200.0),
+                entry(101.0, 10_000.0),
+            ],
+            updated_at: Utc::now(),
+        };
+        let depth = spot_depth_usd(&book, floors.depth_band_bps).unwrap();
+        assert!((depth - (100.1 * 300.0 + 100.2 * 200.0)).abs() < 1e-6);
+
+        assert!(
+            floors
+                .veto(Some(&perp(20_000_000.0, 8_000_000.0)), Some(depth))
+                .is_none()
+        );
+        let reason = floors
+            .veto(Some(&perp(2_000_000.0, 8_000_000.0)), Some(10_000.0))
+            .unwrap();
+        assert!(reason.contains("24h volume") && reason.contains("spot depth"));
+        assert!(!reason.contains("OI"));
+
+        // 조회하지 못한 항목은 통과
+        assert!(floors.veto(None, None).is_none());
+    }
+}
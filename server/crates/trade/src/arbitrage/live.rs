@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use chrono::{DateTime, Utc};
+use interface::Bps;
+use serde::Serialize;
+
+use super::state::ArbitrageState;
+use super::strategy::StrategyMode;
+use crate::trader::binance::HedgedPair;
+
+/// 마지막 tick 이후 이 시간(초)이 지나면 루프가 멈춘 것으로 본다
+const STALE_AFTER_SECS: i64 = 10;
+
+/// 실행 중인 전략의 현재 상태 (run_loop가 매 반복마다 갱신)
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyLiveState {
+    pub strategy_id: String,
+    pub symbol: String,
+    pub mode: StrategyMode,
+    pub entry_bps: Bps,
+    pub exit_bps: Bps,
+    pub spot_price: f64,
+    pub futures_mark: f64,
+    pub basis_bps: f64,
+    pub open: bool,
+    pub dir: Option<String>,
+    pub pair: HedgedPair,
+    pub last_open_basis_bps: Option<f64>,
+    pub last_close_basis_bps: Option<f64>,
+    /// 마지막 주문 응답 ({spot, futures})
+    pub last_actions: Option<serde_json::Value>,
+    pub started_at: DateTime<Utc>,
+    pub last_tick: DateTime<Utc>,
+    pub ticks: u64,
+}
+
+impl StrategyLiveState {
+    pub fn new(
+        strategy_id: String,
+        symbol: String,
+        mode: StrategyMode,
+        entry_bps: Bps,
+        exit_bps: Bps,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            strategy_id,
+            symbol,
+            mode,
+            entry_bps,
+            exit_bps,
+            spot_price: 0.0,
+            futures_mark: 0.0,
+            basis_bps: 0.0,
+            open: false,
+            dir: None,
+            pair: HedgedPair::default(),
+            last_open_basis_bps: None,
+            last_close_basis_bps: None,
+            last_actions: None,
+            started_at: now,
+            last_tick: now,
+            ticks: 0,
+        }
+    }
+
+    /// 한 번의 루프 반복 결과 반영
+    pub fn tick(&mut self, spot_price: f64, futures_mark: f64, basis_bps: f64) {
+        self.spot_price = spot_price;
+        self.futures_mark = futures_mark;
+        self.basis_bps = basis_bps;
+        self.last_tick = Utc::now();
+        self.ticks += 1;
+    }
+
+    /// 파일로 관리되는 포지션 상태 반영
+    pub fn sync_position(&mut self, state: &ArbitrageState) {
+        self.open = state.open;
+        self.dir = state.dir.clone();
+        self.pair = state.pair;
+        self.last_open_basis_bps = state.last_open_basis_bps;
+        self.last_close_basis_bps = state.last_close_basis_bps;
+        self.last_actions = state.actions.clone();
+    }
+
+    /// 진입 이후 베이시스 변화로 본 미실현 손익 (bps, 수수료 제외)
+    /// carry는 베이시스가 줄어들수록, reverse는 늘어날수록 이득
+    pub fn unrealized_bps(&self) -> Option<f64> {
+        if !self.open {
+            return None;
+        }
+        let open_basis = self.last_open_basis_bps?;
+        match self.dir.as_deref() {
+            Some("carry") => Some(open_basis - self.basis_bps),
+            Some("reverse") => Some(self.basis_bps - open_basis),
+            _ => None,
+        }
+    }
+
+    /// 미실현 손익 USDT 환산 (현물 수량 기준)
+    pub fn unrealized_pnl_usdt(&self) -> Option<f64> {
+        self.unrealized_bps()
+            .map(|bps| bps / 10000.0 * self.spot_price * self.pair.spot_order_qty)
+    }
+}
+
+/// 조회 응답 (루프 상태 판정 포함)
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyStateReport {
+    #[serde(flatten)]
+    pub state: StrategyLiveState,
+    /// 마지막 tick 이후 경과 시간 (ms)
+    pub since_last_tick_ms: i64,
+    /// 마지막 tick이 오래되어 루프가 멈춘 것으로 보이면 false
+    pub healthy: bool,
+}
+
+/// 전략 ID별 실시간 상태 저장소
+#[derive(Debug, Default)]
+pub struct StrategyStateRegistry {
+    states: RwLock<HashMap<String, StrategyLiveState>>,
+}
+
+impl StrategyStateRegistry {
+    pub fn update(&self, state: &StrategyLiveState) {
+        self.states
+            .write()
+            .unwrap()
+            .insert(state.strategy_id.clone(), state.clone());
+    }
+
+    pub fn get(&self, strategy_id: &str) -> Option<StrategyStateReport> {
+        let state = self.states.read().unwrap().get(strategy_id)?.clone();
+        let since_last_tick_ms = (Utc::now() - state.last_tick).num_milliseconds();
+        Some(StrategyStateReport {
+            state,
+            since_last_tick_ms,
+            healthy: since_last_tick_ms < STALE_AFTER_SECS * 1000,
+        })
+    }
+
+    /// 전체 전략 상태 (전략 ID 순)
+    pub fn reports(&self) -> Vec<StrategyStateReport> {
+        self.ids().iter().filter_map(|id| self.get(id)).collect()
+    }
+
+    /// 등록된 전략 ID 목록
+    pub fn ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.states.read().unwrap().keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+}
+
+static GLOBAL_STRATEGY_STATES: OnceLock<StrategyStateRegistry> = OnceLock::new();
+
+/// 전역 전략 상태 저장소 가져오기 (최초 호출 시 생성)
+pub fn strategy_states() -> &'static StrategyStateRegistry {
+    GLOBAL_STRATEGY_STATES.get_or_init(StrategyStateRegistry::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_reports_tick_and_position() {
+        let registry = StrategyStateRegistry::default();
+        let mut live = StrategyLiveState::new(
+            "intra_basis:BTCUSDT".to_string(),
+            "BTCUSDT".to_string(),
+            StrategyMode::Carry,
+            Bps::new(6.0),
+            Bps::new(-6.0),
+        );
+        live.tick(100.0, 100.1, 10.0);
+
+        let mut state = ArbitrageState::new("BTCUSDT".to_string());
+        state.update_position(
+            true,
+            Some("carry".to_string()),
+            HedgedPair::default(),
+            Some(10.0),
+            None,
+        );
+        live.sync_position(&state);
+        registry.update(&live);
+
+        let report = registry.get("intra_basis:BTCUSDT").unwrap();
+        assert!(report.healthy);
+        assert_eq!(report.state.ticks, 1);
+        assert!(report.state.open);
+        assert_eq!(report.state.last_open_basis_bps, Some(10.0));
+        assert_eq!(report.state.unrealized_bps(), Some(0.0));
+        assert!(registry.get("intra_basis:ETHUSDT").is_none());
+        assert_eq!(registry.ids(), vec!["intra_basis:BTCUSDT".to_string()]);
+    }
+}
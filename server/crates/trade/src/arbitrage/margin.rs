@@ -0,0 +1,101 @@
+//! 보유 중 선물 증거금 자동 보충
+//!
+//! 포지션이 열려 있는 동안 주기적으로 선물 USDT 잔고를 확인해 `min_balance` 아래로 내려가면
+//! 현물 지갑(스팟/선물 계정이 분리되어 있으면 스팟 서브 계정)에서 `target_balance`까지 이체한다.
+//! 선물 레그가 손실을 보는 동안 청산 가격까지의 거리를 벌려 강제 청산을 막는다.
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+/// 기본 잔고 확인 간격 (초)
+const DEFAULT_CHECK_INTERVAL_SECS: u64 = 60;
+
+/// 선물 증거금 보충 기준
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MarginTopUp {
+    /// 선물 USDT 잔고가 이 값 미만이면 보충
+    pub min_balance: f64,
+    /// 보충 후 목표 잔고 (USDT)
+    pub target_balance: f64,
+    /// 잔고 확인 간격 (초)
+    pub check_interval_secs: u64,
+}
+
+impl MarginTopUp {
+    /// ARB_MARGIN_MIN_BALANCE / ARB_MARGIN_TARGET_BALANCE (기본 min의 2배) /
+    /// ARB_MARGIN_CHECK_SECS (기본 60). ARB_MARGIN_MIN_BALANCE가 없으면 None
+    pub fn from_env() -> Option<Self> {
+        let min_balance = std::env::var("ARB_MARGIN_MIN_BALANCE")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())?;
+        let target_balance = std::env::var("ARB_MARGIN_TARGET_BALANCE")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or(min_balance * 2.0);
+        let check_interval_secs = std::env::var("ARB_MARGIN_CHECK_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_CHECK_INTERVAL_SECS);
+        Some(Self {
+            min_balance,
+            target_balance,
+            check_interval_secs,
+        })
+    }
+}
+
+/// 증거금 확인 주기 관리 (포지션이 열려 있을 때만 확인)
+#[derive(Debug)]
+pub struct MarginWatch {
+    config: MarginTopUp,
+    last_check: Option<Instant>,
+}
+
+impl MarginWatch {
+    pub fn new(config: MarginTopUp) -> Self {
+        Self {
+            config,
+            last_check: None,
+        }
+    }
+
+    pub fn config(&self) -> MarginTopUp {
+        self.config
+    }
+
+    /// 지금 잔고를 확인해야 하는지 (true면 확인 시각 기록)
+    pub fn check_due(&mut self, now: Instant, position_open: bool) -> bool {
+        if !position_open {
+            return false;
+        }
+        let interval = Duration::from_secs(self.config.check_interval_secs);
+        if self
+            .last_check
+            .is_some_and(|last| now.duration_since(last) < interval)
+        {
+            return false;
+        }
+        self.last_check = Some(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_due_only_while_open() {
+        let mut watch = MarginWatch::new(MarginTopUp {
+            min_balance: 50.0,
+            target_balance: 100.0,
+            check_interval_secs: 60,
+        });
+        let start = Instant::now();
+        assert!(!watch.check_due(start, false));
+        assert!(watch.check_due(start, true));
+        assert!(!watch.check_due(start + Duration::from_secs(30), true));
+        assert!(watch.check_due(start + Duration::from_secs(60), true));
+    }
+}
@@ -1,9 +1,31 @@
+pub mod control;
+pub mod fees;
+pub mod forced_close;
+pub mod hedge_venue;
+pub mod imbalance;
+pub mod inflight;
+pub mod inventory;
+pub mod kill_switch;
+pub mod leverage;
+pub mod liquidity;
+pub mod live;
+pub mod margin;
+pub mod recheck;
+pub mod scale_out;
+pub mod shadow;
 pub mod state;
 pub mod strategy;
+pub mod trading_status;
+pub mod two_phase;
 
 pub use crate::trader::{binance::BinanceTrader, bithumb::BithumbTrader};
+pub use inventory::{InventoryLedger, InventoryManager, InventoryParams, InventoryReport};
 pub use state::ArbitrageState;
 pub use strategy::{
-    cross_basis::CrossBasisArbitrageStrategy, intra_basis::IntraBasisArbitrageStrategy,
-    StrategyParams,
+    cash_and_carry::CashAndCarryStrategy,
+    cross_basis::{CrossBasisArbitrageStrategy, VenueCrossBasisArbitrageStrategy},
+    intra_basis::IntraBasisArbitrageStrategy,
+    spot_spread::SpotSpreadStrategy,
+    CashAndCarryParams, SpotSpreadParams, StrategyParams, StrategyParamsBuilder,
+    StrategyParamsError,
 };
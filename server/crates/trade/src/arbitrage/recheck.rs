@@ -0,0 +1,139 @@
+use serde::Serialize;
+
+use interface::Bps;
+
+use crate::events::PositionDirection;
+
+/// 주문 제출 직전 엣지 재확인 기준
+///
+/// 신호를 본 뒤 필터(호가 불균형/유동성 조회)와 자금 예약을 거치는 동안 가격이 움직일 수 있으므로,
+/// 주문 직전에 가격을 다시 읽어 베이시스를 재계산하고 엣지가 줄었으면 진입을 취소한다.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct EntryRecheck {
+    /// 재확인 시 남아 있어야 하는 엣지 (entry_bps 대비 비율)
+    /// 예: 0.8 = entry_bps가 6bps면 진입 방향 베이시스가 4.8bps 이상이어야 주문
+    pub min_edge_ratio: f64,
+    /// 신호 → 주문 제출 허용 지연 (ms, 0이면 확인 안 함)
+    pub latency_budget_ms: u64,
+}
+
+impl Default for EntryRecheck {
+    fn default() -> Self {
+        Self {
+            min_edge_ratio: 0.8,
+            latency_budget_ms: 0,
+        }
+    }
+}
+
+impl EntryRecheck {
+    /// ARB_RECHECK_MIN_EDGE_RATIO / ARB_RECHECK_LATENCY_BUDGET_MS
+    /// 둘 다 설정되지 않으면 None
+    pub fn from_env() -> Option<Self> {
+        let ratio = std::env::var("ARB_RECHECK_MIN_EDGE_RATIO")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok());
+        let budget = std::env::var("ARB_RECHECK_LATENCY_BUDGET_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok());
+        if ratio.is_none() && budget.is_none() {
+            return None;
+        }
+        let defaults = Self::default();
+        Some(Self {
+            min_edge_ratio: ratio.unwrap_or(defaults.min_edge_ratio),
+            latency_budget_ms: budget.unwrap_or(defaults.latency_budget_ms),
+        })
+    }
+
+    /// 주문 직전 진입 방향 베이시스의 최소값 (bps)
+    pub fn min_edge_bps(&self, entry_bps: Bps) -> f64 {
+        entry_bps.value() * self.min_edge_ratio
+    }
+
+    /// 진입을 취소해야 하면 사유 반환
+    /// carry는 basis, reverse는 -basis가 진입 방향 엣지
+    pub fn veto(
+        &self,
+        direction: PositionDirection,
+        basis_bps: f64,
+        entry_bps: Bps,
+        elapsed_ms: u64,
+    ) -> Option<String> {
+        let mut reasons = Vec::new();
+        let edge = edge_bps(direction, basis_bps);
+        let min_edge = self.min_edge_bps(entry_bps);
+        if edge < min_edge {
+            reasons.push(format!("edge {:.2} bps < {:.2} bps", edge, min_edge));
+        }
+        if self.latency_budget_ms > 0 && elapsed_ms > self.latency_budget_ms {
+            reasons.push(format!(
+                "signal age {} ms > {} ms budget",
+                elapsed_ms, self.latency_budget_ms
+            ));
+        }
+        (!reasons.is_empty()).then(|| reasons.join(", "))
+    }
+}
+
+/// 진입 방향 기준 베이시스 (양수일수록 유리)
+pub fn edge_bps(direction: PositionDirection, basis_bps: f64) -> f64 {
+    match direction {
+        PositionDirection::Carry => basis_bps,
+        PositionDirection::Reverse => -basis_bps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_recheck_veto() {
+        let recheck = EntryRecheck {
+            min_edge_ratio: 0.5,
+            latency_budget_ms: 200,
+        };
+        let entry = Bps::new(6.0);
+        assert_eq!(recheck.min_edge_bps(entry), 3.0);
+
+        // 엣지가 절반 이상 남아 있으면 통과
+        assert!(
+            recheck
+                .veto(PositionDirection::Carry, 4.0, entry, 50)
+                .is_none()
+        );
+        assert!(
+            recheck
+                .veto(PositionDirection::Reverse, -3.5, entry, 50)
+                .is_none()
+        );
+
+        let reason = recheck
+            .veto(PositionDirection::Carry, 2.0, entry, 50)
+            .unwrap();
+        assert!(reason.contains("edge 2.00 bps < 3.00 bps"));
+        // reverse인데 베이시스가 양수로 뒤집힘
+        assert!(
+            recheck
+                .veto(PositionDirection::Reverse, 1.0, entry, 50)
+                .is_some()
+        );
+
+        let reason = recheck
+            .veto(PositionDirection::Carry, 7.0, entry, 350)
+            .unwrap();
+        assert!(reason.contains("signal age 350 ms") && !reason.contains("edge"));
+
+        // 지연 예산 0이면 지연은 확인 안 함
+        let no_budget = EntryRecheck {
+            latency_budget_ms: 0,
+            ..recheck
+        };
+        assert!(
+            no_budget
+                .veto(PositionDirection::Carry, 7.0, entry, 10_000)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! 부분 청산(scale-out) 사다리
+//!
+//! 베이시스가 exit_bps까지 완전히 수렴하기 전에, 중간 단계에 도달할 때마다 진입 수량의
+//! 일정 비율씩 먼저 청산한다. 마지막 잔량은 기존과 같이 exit_bps에서 전량 청산된다.
+
+use std::str::FromStr;
+
+use interface::Bps;
+use serde::Serialize;
+
+use super::strategy::exit_reached;
+use crate::trader::binance::HedgedPair;
+
+/// 사다리 한 단계
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ExitLevel {
+    /// 이 단계 청산 기준 (exit_bps와 같은 부호 규칙: carry는 basis <= bps, reverse는 basis >= -bps)
+    pub bps: Bps,
+    /// 진입 수량 대비 이 단계에서 청산할 비율 (0.0 ~ 1.0)
+    pub fraction: f64,
+}
+
+/// 부분 청산 단계 목록 (진입에 가까운 단계부터)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExitLadder {
+    pub levels: Vec<ExitLevel>,
+}
+
+impl FromStr for ExitLadder {
+    type Err = String;
+
+    /// "bps:fraction" 목록 파싱 (쉼표 구분, 예: "3:0.3,0:0.3")
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let levels = raw
+            .split(',')
+            .map(str::trim)
+            .filter(|level| !level.is_empty())
+            .map(|level| {
+                let Some((bps, fraction)) = level.split_once(':') else {
+                    return Err(format!("expected bps:fraction, got {:?}", level));
+                };
+                let bps = bps
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|e| format!("invalid bps {:?}: {}", bps, e))
+                    .and_then(|v| Bps::try_new(v).map_err(|e| e.to_string()))?;
+                let fraction = fraction
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|e| format!("invalid fraction {:?}: {}", fraction, e))?;
+                Ok(ExitLevel { bps, fraction })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let ladder = Self { levels };
+        ladder.check()?;
+        Ok(ladder)
+    }
+}
+
+impl ExitLadder {
+    /// 단계 순서/비율 검사
+    /// bps는 단계마다 줄어들어야 하고(먼저 닿는 단계가 앞), 비율 합은 1 미만이어야 한다
+    pub fn check(&self) -> Result<(), String> {
+        if self.levels.is_empty() {
+            return Err("ladder has no levels".to_string());
+        }
+        for level in &self.levels {
+            if !(level.fraction > 0.0 && level.fraction < 1.0) {
+                return Err(format!(
+                    "fraction must be in (0, 1), got {}",
+                    level.fraction
+                ));
+            }
+        }
+        for pair in self.levels.windows(2) {
+            if pair[1].bps >= pair[0].bps {
+                return Err(format!(
+                    "levels must be in decreasing bps order, got {} then {}",
+                    pair[0].bps, pair[1].bps
+                ));
+            }
+        }
+        let total: f64 = self.levels.iter().map(|l| l.fraction).sum();
+        if total >= 1.0 {
+            return Err(format!(
+                "fractions must sum below 1 (the rest closes at exit_bps), got {}",
+                total
+            ));
+        }
+        Ok(())
+    }
+
+    /// 지금까지 `taken` 단계를 청산했을 때 현재 베이시스로 새로 도달한 마지막 단계
+    /// 한 번에 여러 단계를 건너뛰면 가장 깊은 단계를 반환한다
+    pub fn reached(&self, dir: Option<&str>, basis_bps: Bps, taken: usize) -> Option<usize> {
+        self.levels
+            .iter()
+            .enumerate()
+            .skip(taken)
+            .take_while(|(_, level)| exit_reached(dir, basis_bps, level.bps))
+            .last()
+            .map(|(i, _)| i)
+    }
+
+    /// 앞의 `steps` 단계까지 청산한 누적 비율 (진입 수량 대비)
+    pub fn cumulative_fraction(&self, steps: usize) -> f64 {
+        self.levels.iter().take(steps).map(|l| l.fraction).sum()
+    }
+
+    /// `taken` 단계를 청산한 상태에서 `upto` 단계까지 청산할 때, 남은 수량 대비 청산 비율
+    pub fn close_ratio(&self, taken: usize, upto: usize) -> f64 {
+        let before = self.cumulative_fraction(taken);
+        let after = self.cumulative_fraction(upto + 1);
+        let remaining = 1.0 - before;
+        if remaining <= 0.0 {
+            return 1.0;
+        }
+        ((after - before) / remaining).clamp(0.0, 1.0)
+    }
+}
+
+/// 헤지 쌍을 비율로 나눔 (청산분, 잔량)
+/// `clamp`로 각 레그 수량을 거래소 LOT_SIZE에 맞춘다
+pub fn split_pair(
+    pair: &HedgedPair,
+    ratio: f64,
+    clamp_spot: impl Fn(f64) -> f64,
+    clamp_futures: impl Fn(f64) -> f64,
+) -> (HedgedPair, HedgedPair) {
+    let spot_order_qty = clamp_spot(pair.spot_order_qty * ratio);
+    let fut_order_qty = clamp_futures(pair.fut_order_qty * ratio);
+    let spot_net_qty_est = clamp_spot(pair.spot_net_qty_est * ratio);
+    let closed = HedgedPair {
+        spot_order_qty,
+        fut_order_qty,
+        spot_net_qty_est,
+        delta_est: spot_net_qty_est - fut_order_qty,
+    };
+    let rest_spot_net = (pair.spot_net_qty_est - spot_net_qty_est).max(0.0);
+    let rest_fut = (pair.fut_order_qty - fut_order_qty).max(0.0);
+    let rest = HedgedPair {
+        spot_order_qty: (pair.spot_order_qty - spot_order_qty).max(0.0),
+        fut_order_qty: rest_fut,
+        spot_net_qty_est: rest_spot_net,
+        delta_est: rest_spot_net - rest_fut,
+    };
+    (closed, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_ladder_steps_and_split() {
+        let ladder: ExitLadder = "3:0.25, 0:0.25".parse().unwrap();
+        assert_eq!(ladder.levels.len(), 2);
+        assert!("0:0.3,3:0.3".parse::<ExitLadder>().is_err());
+        assert!("3:0.6,0:0.5".parse::<ExitLadder>().is_err());
+        assert!("3".parse::<ExitLadder>().is_err());
+
+        // carry: 베이시스가 줄어들며 단계 도달
+        assert_eq!(ladder.reached(Some("carry"), Bps::new(4.0), 0), None);
+        assert_eq!(ladder.reached(Some("carry"), Bps::new(2.5), 0), Some(0));
+        assert_eq!(ladder.reached(Some("carry"), Bps::new(2.5), 1), None);
+        // 한 번에 두 단계를 지나면 깊은 단계까지
+        assert_eq!(ladder.reached(Some("carry"), Bps::new(-1.0), 0), Some(1));
+        // reverse: 베이시스가 -3 이상으로 올라오면 첫 단계
+        assert_eq!(ladder.reached(Some("reverse"), Bps::new(-2.0), 0), Some(0));
+        assert_eq!(ladder.reached(None, Bps::new(-2.0), 0), None);
+
+        // 진입 수량 기준 25%씩: 처음엔 전체의 25%, 다음엔 남은 75% 중 1/3
+        assert!((ladder.close_ratio(0, 0) - 0.25).abs() < 1e-12);
+        assert!((ladder.close_ratio(1, 1) - 1.0 / 3.0).abs() < 1e-12);
+        assert!((ladder.close_ratio(0, 1) - 0.5).abs() < 1e-12);
+
+        let step = |q: f64| (q / 0.001).floor() * 0.001;
+        let (closed, rest) = split_pair(&HedgedPair::filled(1.0), 0.25, step, step);
+        assert!((closed.fut_order_qty - 0.25).abs() < 1e-9);
+        assert!((rest.fut_order_qty - 0.75).abs() < 1e-9);
+        assert!((rest.spot_net_qty_est - 0.75).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,282 @@
+//! 실전 전략 옆에서 후보 파라미터를 페이퍼로 돌리는 섀도 모드
+//!
+//! 실전 루프가 매 반복 받은 시세(스팟/선물 마크/베이시스)를 그대로 `ShadowTwin`에 넘기면,
+//! 후보 entry/exit 기준으로 진입·청산했을 가상 거래를 `shadow_trade_records` 테이블에 남긴다.
+//! 주문은 넣지 않으며 자금 배분·중복 진입 방지·이벤트 버스와도 분리되어 실전 상태에 영향이 없다.
+
+use chrono::{DateTime, Utc};
+use interface::Bps;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use super::strategy::{StrategyMode, entry_direction, exit_reached};
+use crate::events::PositionDirection;
+use crate::record::ShadowTradeRecord;
+
+/// 후보 파라미터 한 벌
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShadowParams {
+    /// 기록에 남길 이름 (예: "tight")
+    pub name: String,
+    pub entry_bps: Bps,
+    pub exit_bps: Bps,
+    /// None이면 실전 전략과 같은 모드
+    pub mode: Option<StrategyMode>,
+}
+
+impl ShadowParams {
+    /// "name:entry:exit[:mode]" 목록 파싱 (쉼표 구분, 예: "tight:4:-6,wide:8:-4:auto")
+    pub fn parse_list(raw: &str) -> Result<Vec<Self>, String> {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let parts: Vec<&str> = entry.split(':').map(str::trim).collect();
+                let (name, entry_bps, exit_bps, mode) = match parts.as_slice() {
+                    [name, entry_bps, exit_bps] => (*name, *entry_bps, *exit_bps, None),
+                    [name, entry_bps, exit_bps, mode] => (
+                        *name,
+                        *entry_bps,
+                        *exit_bps,
+                        Some(mode.parse::<StrategyMode>()?),
+                    ),
+                    _ => return Err(format!("Invalid shadow params: {}", entry)),
+                };
+                let bps = |v: &str| {
+                    v.parse::<f64>()
+                        .map_err(|e| format!("Invalid shadow bps '{}': {}", v, e))
+                        .and_then(|v| Bps::try_new(v).map_err(|e| e.to_string()))
+                };
+                Ok(Self {
+                    name: name.to_string(),
+                    entry_bps: bps(entry_bps)?,
+                    exit_bps: bps(exit_bps)?,
+                    mode,
+                })
+            })
+            .collect()
+    }
+
+    /// `ARB_SHADOW` 환경 변수 (없거나 잘못되면 섀도 없음)
+    pub fn from_env() -> Vec<Self> {
+        let Ok(raw) = std::env::var("ARB_SHADOW") else {
+            return Vec::new();
+        };
+        Self::parse_list(&raw).unwrap_or_else(|e| {
+            warn!("ARB_SHADOW 무시: {}", e);
+            Vec::new()
+        })
+    }
+}
+
+/// 가상 포지션
+#[derive(Debug, Clone, Copy)]
+struct PaperPosition {
+    direction: PositionDirection,
+    qty: f64,
+    open_basis_bps: f64,
+    open_spot_price: f64,
+}
+
+/// 후보 파라미터로 돌리는 페이퍼 전략 인스턴스
+#[derive(Debug, Clone)]
+pub struct ShadowTwin {
+    strategy_id: String,
+    symbol: String,
+    mode: StrategyMode,
+    params: ShadowParams,
+    notional: f64,
+    /// 왕복 수수료 (bps, 청산 손익에서 차감)
+    round_trip_fee_bps: f64,
+    position: Option<PaperPosition>,
+}
+
+impl ShadowTwin {
+    pub fn new(
+        strategy_id: &str,
+        symbol: &str,
+        live_mode: StrategyMode,
+        params: ShadowParams,
+        notional: f64,
+        round_trip_fee_bps: f64,
+    ) -> Self {
+        Self {
+            strategy_id: strategy_id.to_string(),
+            symbol: symbol.to_string(),
+            mode: params.mode.unwrap_or(live_mode),
+            params,
+            notional,
+            round_trip_fee_bps,
+            position: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.params.name
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// 한 번의 시세 반영. 가상 진입/청산이 일어나면 기록을 반환
+    pub fn on_tick(
+        &mut self,
+        spot_price: f64,
+        futures_mark: f64,
+        basis_bps: f64,
+        now: DateTime<Utc>,
+    ) -> Option<ShadowTradeRecord> {
+        if spot_price <= 0.0 {
+            return None;
+        }
+        let basis = Bps::new(basis_bps);
+        match self.position {
+            None => {
+                let direction = entry_direction(self.mode, basis, self.params.entry_bps)?;
+                let qty = self.notional / spot_price;
+                self.position = Some(PaperPosition {
+                    direction,
+                    qty,
+                    open_basis_bps: basis_bps,
+                    open_spot_price: spot_price,
+                });
+                Some(self.record(
+                    direction,
+                    "OPEN",
+                    (basis_bps, spot_price, futures_mark),
+                    qty,
+                    None,
+                    now,
+                ))
+            }
+            Some(position) => {
+                let dir = match position.direction {
+                    PositionDirection::Carry => "carry",
+                    PositionDirection::Reverse => "reverse",
+                };
+                if !exit_reached(Some(dir), basis, self.params.exit_bps) {
+                    return None;
+                }
+                self.position = None;
+                let gross_bps = match position.direction {
+                    PositionDirection::Carry => position.open_basis_bps - basis_bps,
+                    PositionDirection::Reverse => basis_bps - position.open_basis_bps,
+                };
+                let pnl_bps = gross_bps - self.round_trip_fee_bps;
+                let pnl_usdt = pnl_bps / 10000.0 * position.open_spot_price * position.qty;
+                Some(self.record(
+                    position.direction,
+                    "CLOSE",
+                    (basis_bps, spot_price, futures_mark),
+                    position.qty,
+                    Some((pnl_bps, pnl_usdt)),
+                    now,
+                ))
+            }
+        }
+    }
+
+    fn record(
+        &self,
+        direction: PositionDirection,
+        action: &str,
+        (basis_bps, spot_price, futures_mark): (f64, f64, f64),
+        quantity: f64,
+        pnl: Option<(f64, f64)>,
+        now: DateTime<Utc>,
+    ) -> ShadowTradeRecord {
+        ShadowTradeRecord {
+            executed_at: now,
+            strategy_id: self.strategy_id.clone(),
+            variant: self.params.name.clone(),
+            symbol: self.symbol.clone(),
+            carry: direction.record_label().to_string(),
+            action: action.to_string(),
+            entry_bps: self.params.entry_bps.value(),
+            exit_bps: self.params.exit_bps.value(),
+            basis_bps,
+            spot_price,
+            futures_mark,
+            quantity,
+            pnl_bps: pnl.map(|(bps, _)| bps),
+            pnl_usdt: pnl.map(|(_, usdt)| usdt),
+        }
+    }
+}
+
+/// 모든 섀도 인스턴스에 시세를 넘기고 가상 체결을 저장
+pub async fn step_shadows(
+    twins: &mut [ShadowTwin],
+    spot_price: f64,
+    futures_mark: f64,
+    basis_bps: f64,
+) {
+    let now = Utc::now();
+    for twin in twins.iter_mut() {
+        if let Some(record) = twin.on_tick(spot_price, futures_mark, basis_bps, now) {
+            info!(
+                "[shadow:{}] {} {} @ {:.4} bps (pnl {:?} bps)",
+                twin.name(),
+                record.action,
+                record.carry,
+                basis_bps,
+                record.pnl_bps
+            );
+            crate::record::save_shadow_trade_record_safe(&record).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{ShadowTradeRecordRepository, SqliteShadowTradeRecordRepository};
+
+    #[tokio::test]
+    async fn test_shadow_twin_round_trip_and_storage() {
+        let params = ShadowParams::parse_list("tight:4:-2, wide:8:-4:reverse").unwrap();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[1].mode, Some(StrategyMode::Reverse));
+        assert!(ShadowParams::parse_list("bad:4").is_err());
+
+        let now = Utc::now();
+        let mut twin = ShadowTwin::new(
+            "intra_basis:BTCUSDT",
+            "BTCUSDT",
+            StrategyMode::Carry,
+            params[0].clone(),
+            100.0,
+            1.0,
+        );
+        // 실전 기준(6bps)에는 못 미치는 5bps에서 후보(4bps)는 진입
+        assert!(twin.on_tick(100.0, 100.05, 3.0, now).is_none());
+        let open = twin.on_tick(100.0, 100.05, 5.0, now).unwrap();
+        assert_eq!(
+            (open.carry.as_str(), open.action.as_str()),
+            ("CARRY", "OPEN")
+        );
+        assert!((open.quantity - 1.0).abs() < 1e-12);
+        assert!(twin.on_tick(100.0, 100.0, 0.0, now).is_none());
+
+        // 5 → -3 bps: 8bps 이득 - 왕복 수수료 1bps
+        let close = twin.on_tick(100.0, 99.97, -3.0, now).unwrap();
+        assert_eq!(close.action, "CLOSE");
+        assert!((close.pnl_bps.unwrap() - 7.0).abs() < 1e-9);
+        assert!((close.pnl_usdt.unwrap() - 0.07).abs() < 1e-9);
+        assert!(!twin.is_open());
+
+        let repo = SqliteShadowTradeRecordRepository::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        repo.save(&open).await.unwrap();
+        repo.save(&close).await.unwrap();
+        let stored = repo
+            .find_all(Some("intra_basis:BTCUSDT"), None)
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0].record.variant, "tight");
+        assert!(repo.find_all(Some("other"), None).await.unwrap().is_empty());
+    }
+}
@@ -1,12 +1,109 @@
 use chrono::{DateTime, Utc};
 use interface::ExchangeError;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use tracing::info;
 
 use crate::trader::binance::HedgedPair;
 
-const STATE_FILE: &str = "arb_state.json";
+/// 기본 포지션 상태 파일 경로
+pub const DEFAULT_STATE_FILE: &str = "arb_state.json";
+
+/// 이전 버전 공용 상태 파일을 전략별 파일로 옮긴 뒤 붙이는 접미사
+const MIGRATED_SUFFIX: &str = ".migrated";
+
+/// 상태 파일 형식 버전
+pub const STATE_FILE_VERSION: u32 = 1;
+
+/// 버전/체크섬 헤더를 붙여 저장하는 상태 파일 형식
+#[derive(Debug, Serialize, Deserialize)]
+struct StateEnvelope {
+    version: u32,
+    /// `state` JSON의 SHA-256 (hex)
+    checksum: String,
+    state: serde_json::Value,
+}
+
+fn checksum_of(state: &serde_json::Value) -> String {
+    hex::encode(Sha256::digest(state.to_string().as_bytes()))
+}
+
+/// 전략 인스턴스별 상태 파일 경로.
+/// 기본 경로를 쓰면 `arb_state.<전략 ID>.json`으로 나눠 여러 전략이 한 파일을 덮어쓰지 않게 하고,
+/// 직접 지정한 경로는 그대로 쓴다.
+pub fn strategy_state_path(state_file: &str, strategy_id: &str) -> PathBuf {
+    if state_file != DEFAULT_STATE_FILE {
+        return PathBuf::from(state_file);
+    }
+    let id: String = strategy_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    PathBuf::from(format!("arb_state.{}.json", id))
+}
+
+/// 상태 파일 경로별 소유 전략 (같은 프로세스에서 두 전략이 한 파일을 쓰지 않도록)
+fn state_file_owners() -> &'static Mutex<HashMap<PathBuf, String>> {
+    static OWNERS: OnceLock<Mutex<HashMap<PathBuf, String>>> = OnceLock::new();
+    OWNERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 공용 상태 파일 이전 잠금 (두 전략이 동시에 같은 파일을 가져가지 않도록)
+fn migrate_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+/// 상태 파일 쓰기 직렬화 잠금
+fn write_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+/// 상태 파일을 전략에 할당. 다른 전략이 이미 쓰고 있으면 에러
+fn claim_state_file(path: &Path, strategy_id: &str) -> Result<(), ExchangeError> {
+    let mut owners = state_file_owners().lock().unwrap();
+    match owners.get(path) {
+        Some(owner) if owner != strategy_id => Err(ExchangeError::Other(format!(
+            "State file {} is already used by {}",
+            path.display(),
+            owner
+        ))),
+        _ => {
+            owners.insert(path.to_path_buf(), strategy_id.to_string());
+            Ok(())
+        }
+    }
+}
+
+/// 임시 파일에 쓰고 fsync 후 rename (중간에 죽어도 기존 파일은 온전함)
+pub(crate) fn write_atomic(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(
+        ".tmp.{}.{}",
+        std::process::id(),
+        SEQ.fetch_add(1, Ordering::Relaxed)
+    ));
+    let tmp = PathBuf::from(tmp);
+
+    let result = (|| {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(content)?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArbitrageState {
@@ -16,6 +113,9 @@ pub struct ArbitrageState {
     pub symbol: String,
     pub last_open_basis_bps: Option<f64>,
     pub last_close_basis_bps: Option<f64>,
+    /// 현재 포지션에서 이미 실행한 부분 청산 단계 수 (ExitLadder 기준)
+    #[serde(default)]
+    pub scale_out_steps: usize,
     pub actions: Option<serde_json::Value>,
     pub updated_at: DateTime<Utc>,
 }
@@ -29,6 +129,7 @@ impl Default for ArbitrageState {
             symbol: "BTCUSDT".to_string(),
             last_open_basis_bps: None,
             last_close_basis_bps: None,
+            scale_out_steps: 0,
             actions: None,
             updated_at: Utc::now(),
         }
@@ -44,24 +145,87 @@ impl ArbitrageState {
     }
 
     pub fn read() -> Result<Self, ExchangeError> {
-        if !Path::new(STATE_FILE).exists() {
+        Self::read_from(DEFAULT_STATE_FILE)
+    }
+
+    pub fn write(&self) -> Result<(), ExchangeError> {
+        self.write_to(DEFAULT_STATE_FILE)
+    }
+
+    /// 전략 인스턴스 상태 읽기. 상태 파일을 전략에 할당하고,
+    /// 전략별 파일이 아직 없으면 이전 버전의 공용 파일을 `owns`가 인정하는 첫 전략에게만 넘긴다
+    /// (전략별 파일의 심볼 확인은 호출자 몫)
+    pub fn read_for_strategy(
+        state_file: &str,
+        strategy_id: &str,
+        owns: impl Fn(&ArbitrageState) -> bool,
+    ) -> Result<Self, ExchangeError> {
+        let path = strategy_state_path(state_file, strategy_id);
+        claim_state_file(&path, strategy_id)?;
+        if !path.exists()
+            && state_file == DEFAULT_STATE_FILE
+            && let Some(state) =
+                migrate_legacy_state(Path::new(DEFAULT_STATE_FILE), &path, strategy_id, owns)?
+        {
+            return Ok(state);
+        }
+        Self::read_from(path)
+    }
+
+    /// 상태 파일 읽기 (파일이 없으면 기본 상태).
+    /// 버전/체크섬이 맞지 않으면 에러, 헤더 없는 이전 형식도 읽는다
+    pub fn read_from(path: impl AsRef<Path>) -> Result<Self, ExchangeError> {
+        let path = path.as_ref();
+        if !path.exists() {
             return Ok(Self::default());
         }
 
-        let content = fs::read_to_string(STATE_FILE)
+        let content = fs::read_to_string(path)
             .map_err(|e| ExchangeError::Other(format!("Failed to read state file: {}", e)))?;
 
-        let state: ArbitrageState = serde_json::from_str(&content)
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse state file: {}", e)))?;
+        let value = if value.get("checksum").is_some() {
+            let envelope: StateEnvelope = serde_json::from_value(value)
+                .map_err(|e| ExchangeError::Other(format!("Failed to parse state file: {}", e)))?;
+            if envelope.version != STATE_FILE_VERSION {
+                return Err(ExchangeError::Other(format!(
+                    "Unsupported state file version {} in {}",
+                    envelope.version,
+                    path.display()
+                )));
+            }
+            if checksum_of(&envelope.state) != envelope.checksum {
+                return Err(ExchangeError::Other(format!(
+                    "State file checksum mismatch: {}",
+                    path.display()
+                )));
+            }
+            envelope.state
+        } else {
+            value
+        };
+
+        let state: ArbitrageState = serde_json::from_value(value)
             .map_err(|e| ExchangeError::Other(format!("Failed to parse state file: {}", e)))?;
 
         Ok(state)
     }
 
-    pub fn write(&self) -> Result<(), ExchangeError> {
-        let content = serde_json::to_string_pretty(self)
+    /// 버전/체크섬 헤더를 붙여 원자적으로 저장
+    pub fn write_to(&self, path: impl AsRef<Path>) -> Result<(), ExchangeError> {
+        let state = serde_json::to_value(self)
+            .map_err(|e| ExchangeError::Other(format!("Failed to serialize state: {}", e)))?;
+        let envelope = StateEnvelope {
+            version: STATE_FILE_VERSION,
+            checksum: checksum_of(&state),
+            state,
+        };
+        let content = serde_json::to_string_pretty(&envelope)
             .map_err(|e| ExchangeError::Other(format!("Failed to serialize state: {}", e)))?;
 
-        fs::write(STATE_FILE, content)
+        let _guard = write_lock().lock().unwrap();
+        write_atomic(path.as_ref(), content.as_bytes())
             .map_err(|e| ExchangeError::Other(format!("Failed to write state file: {}", e)))?;
 
         Ok(())
@@ -78,6 +242,7 @@ impl ArbitrageState {
         self.open = open;
         self.dir = dir.clone();
         self.pair = pair;
+        self.scale_out_steps = 0;
         self.updated_at = Utc::now();
 
         if open {
@@ -88,4 +253,136 @@ impl ArbitrageState {
 
         self.actions = actions;
     }
+
+    /// 부분 청산 반영: 포지션은 열린 채로 잔량과 실행한 단계 수만 갱신
+    pub fn record_partial_close(
+        &mut self,
+        remaining: HedgedPair,
+        scale_out_steps: usize,
+        basis_bps: f64,
+        actions: Option<serde_json::Value>,
+    ) {
+        self.pair = remaining;
+        self.scale_out_steps = scale_out_steps;
+        self.last_close_basis_bps = Some(basis_bps);
+        self.actions = actions;
+        self.updated_at = Utc::now();
+    }
+}
+
+/// 공용 상태 파일이 이 전략 것이면 전략별 파일로 옮기고 공용 파일은 `.migrated`로 이름을 바꾼다.
+/// 이름을 바꾼 뒤에는 다른 전략이 같은 포지션을 다시 가져가지 못한다.
+fn migrate_legacy_state(
+    legacy: &Path,
+    path: &Path,
+    strategy_id: &str,
+    owns: impl Fn(&ArbitrageState) -> bool,
+) -> Result<Option<ArbitrageState>, ExchangeError> {
+    let _guard = migrate_lock().lock().unwrap();
+    if !legacy.exists() {
+        return Ok(None);
+    }
+    let state = ArbitrageState::read_from(legacy)?;
+    if !owns(&state) {
+        return Ok(None);
+    }
+    state.write_to(path)?;
+    let mut migrated = legacy.as_os_str().to_owned();
+    migrated.push(MIGRATED_SUFFIX);
+    fs::rename(legacy, &migrated).map_err(|e| {
+        ExchangeError::Other(format!(
+            "Failed to rename migrated state file {}: {}",
+            legacy.display(),
+            e
+        ))
+    })?;
+    info!(
+        "공용 상태 파일 {}을 {}로 이전 ({})",
+        legacy.display(),
+        path.display(),
+        strategy_id
+    );
+    Ok(Some(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_file_roundtrip_and_validation() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("arb_state_envelope_{}.json", std::process::id()));
+
+        let mut state = ArbitrageState::new("ETHUSDT".to_string());
+        state.open = true;
+        state.last_open_basis_bps = Some(12.5);
+        state.write_to(&path).unwrap();
+        let read = ArbitrageState::read_from(&path).unwrap();
+        assert!(read.open);
+        assert_eq!(read.symbol, "ETHUSDT");
+        assert_eq!(read.last_open_basis_bps, Some(12.5));
+
+        // 체크섬이 맞지 않으면 거부
+        let tampered = fs::read_to_string(&path)
+            .unwrap()
+            .replace("ETHUSDT", "BTCUSDT");
+        fs::write(&path, tampered).unwrap();
+        assert!(ArbitrageState::read_from(&path).is_err());
+
+        // 헤더 없는 이전 형식
+        fs::write(&path, serde_json::to_string(&state).unwrap()).unwrap();
+        assert_eq!(ArbitrageState::read_from(&path).unwrap().symbol, "ETHUSDT");
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            strategy_state_path(DEFAULT_STATE_FILE, "intra_basis:BTCUSDT"),
+            PathBuf::from("arb_state.intra_basis_BTCUSDT.json")
+        );
+        assert_eq!(
+            strategy_state_path("custom.json", "intra_basis:BTCUSDT"),
+            PathBuf::from("custom.json")
+        );
+        let shared = dir.join(format!("arb_state_shared_{}.json", std::process::id()));
+        let shared = shared.to_string_lossy();
+        ArbitrageState::read_for_strategy(&shared, "a", |_| true).unwrap();
+        assert!(ArbitrageState::read_for_strategy(&shared, "b", |_| true).is_err());
+    }
+
+    #[test]
+    fn test_legacy_state_migrates_to_one_strategy() {
+        let dir = std::env::temp_dir();
+        let file = |name: &str| dir.join(format!("{}_{}.json", name, std::process::id()));
+        let legacy = file("arb_state_legacy");
+        let (first, second) = (file("arb_state_first"), file("arb_state_second"));
+
+        let mut state = ArbitrageState::new("BTCUSDT".to_string());
+        state.open = true;
+        state.write_to(&legacy).unwrap();
+        let owns = |state: &ArbitrageState| state.symbol == "BTCUSDT";
+
+        // 다른 심볼 전략은 가져가지 않음
+        let other = |state: &ArbitrageState| state.symbol == "ETHUSDT";
+        assert!(
+            migrate_legacy_state(&legacy, &first, "eth", other)
+                .unwrap()
+                .is_none()
+        );
+        // 같은 심볼이어도 첫 전략만 이전받고, 공용 파일은 이름이 바뀜
+        let migrated = migrate_legacy_state(&legacy, &first, "first", owns).unwrap();
+        assert!(migrated.is_some_and(|s| s.open));
+        assert!(ArbitrageState::read_from(&first).unwrap().open);
+        assert!(!legacy.exists());
+        assert!(
+            migrate_legacy_state(&legacy, &second, "second", owns)
+                .unwrap()
+                .is_none()
+        );
+
+        let mut renamed = legacy.into_os_string();
+        renamed.push(MIGRATED_SUFFIX);
+        for path in [PathBuf::from(renamed), first, second] {
+            let _ = fs::remove_file(path);
+        }
+    }
 }
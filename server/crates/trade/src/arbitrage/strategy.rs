@@ -42,10 +42,27 @@ pub enum LegExecutionPolicy {
     PostOnlyMaker,
 }
 
-use interface::ExchangeId;
+use interface::{Bps, ExchangeId, Notional};
+use serde::Serialize;
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::arbitrage::inventory::InventoryParams;
+use crate::arbitrage::kill_switch::PriceGuardParams;
+use crate::arbitrage::leverage::DynamicLeverage;
+use crate::arbitrage::liquidity::LiquidityFloors;
+use crate::arbitrage::margin::MarginTopUp;
+use crate::arbitrage::recheck::EntryRecheck;
+use crate::arbitrage::scale_out::ExitLadder;
+use crate::arbitrage::shadow::ShadowParams;
+use crate::arbitrage::state::DEFAULT_STATE_FILE;
+use crate::arbitrage::two_phase::LegOrder;
+use crate::events::PositionDirection;
+use crate::trader::ContractKind;
+use crate::transfer_status::TransferMonitorParams;
+use crate::volatility::VolatilitySizing;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum StrategyMode {
     /// 스팟 롱 + 선물 숏
     Carry,
@@ -55,6 +72,19 @@ pub enum StrategyMode {
     Auto,
 }
 
+impl std::str::FromStr for StrategyMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "carry" => Ok(StrategyMode::Carry),
+            "reverse" => Ok(StrategyMode::Reverse),
+            "auto" => Ok(StrategyMode::Auto),
+            other => Err(format!("Unknown strategy mode: {}", other)),
+        }
+    }
+}
+
 impl fmt::Display for StrategyMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -74,15 +104,22 @@ pub struct StrategyParams {
     pub mode: StrategyMode,
     /// 진입 임계값 (basis points). 베이시스가 이 값 이상 벌어지면 포지션 진입
     /// 예: 2.0 bps = 0.02% = (선물 - 스팟) / 스팟 * 10000 >= 2.0
-    pub entry_bps: f64,
+    pub entry_bps: Bps,
     /// 청산 임계값 (basis points). 베이시스가 이 값 이하로 좁혀지면 포지션 청산
     /// 예: 0.2 bps = 0.002%
-    pub exit_bps: f64,
+    pub exit_bps: Bps,
+    /// 부분 청산 사다리 (None이면 exit_bps에서 전량 청산)
+    /// 베이시스가 중간 단계에 닿을 때마다 진입 수량의 일정 비율씩 먼저 청산한다
+    pub exit_ladder: Option<ExitLadder>,
     /// 거래 명목가 (USDT 단위). 이 금액만큼의 포지션을 잡음
     /// 예: 100.0 USDT = 약 100 USDT 상당의 BTC를 거래
-    pub notional: f64,
+    pub notional: Notional,
     /// 선물 레버리지 배수 (1 = 무레버리지, 2 = 2배 레버리지 등)
     pub leverage: u32,
+    /// 변동성 기반 동적 레버리지 (None이면 leverage 고정)
+    /// 설정 시 leverage는 시작 값으로 쓰고, 가격/베이시스 변동성과 청산 거리에 따라
+    /// 설정한 범위 안에서 레버리지를 바꾼다
+    pub dynamic_leverage: Option<DynamicLeverage>,
     /// 선물 마진 타입: true = 격리 마진(ISOLATED), false = 교차 마진(CROSS)
     pub isolated: bool,
     /// 테스트 모드: true면 실제 주문을 넣지 않고 로그만 출력
@@ -93,28 +130,137 @@ pub struct StrategyParams {
     pub spot_leg: LegExecutionPolicy,
     /// 선물 레그의 개별 실행 정책 (MarketTaker, AggressiveLimitTaker, PassiveMaker, PostOnlyMaker)
     pub futures_leg: LegExecutionPolicy,
+    /// 전략 인스턴스에 배정된 운용 자금 한도 (USDT 단위)
+    /// 스팟 quote 사용량 + 선물 증거금이 이 값을 넘는 진입은 거부된다
+    pub capital_budget: Notional,
+    /// 변동성 기반 명목가 스케일링 (None이면 notional 고정)
+    /// 설정 시 변동성이 높은 구간에서는 명목가를 줄이고 낮은 구간에서는 늘려
+    /// 같은 bps 엣지에 대해 비슷한 리스크를 지도록 한다
+    pub vol_sizing: Option<VolatilitySizing>,
+    /// 최우선 호가 불균형 거부 임계값 (0.0 ~ 1.0, None이면 사용 안 함)
+    /// 진입 방향으로 먹어야 할 호가가 이 값 이상 얇게 기울어 있으면 진입을 보류한다
+    /// 예: 0.6 = 매수 시 (bid_qty - ask_qty) / (bid_qty + ask_qty) > 0.6 이면 거부
+    pub imbalance_threshold: Option<f64>,
+    /// 진입 전 최소 유동성 (None이면 사용 안 함)
+    /// 무기한 선물 24h 거래대금/OI(Oracle 스냅샷)나 스팟 호가 깊이가 모자라면
+    /// 베이시스 신호가 나와도 진입하지 않는다 (청산 시 큰 슬리피지 방지)
+    pub liquidity_floors: Option<LiquidityFloors>,
+    /// 주문 제출 직전 가격 재확인 (None이면 사용 안 함)
+    /// 신호 시점 이후 베이시스가 entry_bps의 일정 비율 아래로 줄었거나 지연 예산을 넘기면
+    /// 진입을 취소하고 EntryAborted 이벤트로 남긴다
+    pub entry_recheck: Option<EntryRecheck>,
+    /// 보유 중 선물 증거금 자동 보충 (None이면 사용 안 함)
+    pub margin_top_up: Option<MarginTopUp>,
+    /// 진입 시 레그 주문 순서 (스팟 먼저 / 선물 헤지 먼저)
+    pub leg_order: LegOrder,
+    /// 진입 두 번째 레그 재시도 횟수. 모두 실패하면 첫 레그를 즉시 되돌린다
+    pub second_leg_retries: u32,
+    /// 스팟 레그 심볼 (None이면 symbol과 같음)
+    /// 예: "BTCUSDC" / "BTCFDUSD" 스팟을 "BTCUSDT" 선물로 헤지. 스팟 가격은 USDT로 환산해 사용
+    pub spot_symbol: Option<String>,
+    /// 같은 전략의 연속 진입 사이 최소 간격 (초)
+    pub min_entry_interval_secs: u64,
+    /// 가격 이상 감지 / 킬 스위치 기준
+    pub price_guard: PriceGuardParams,
+    /// 포지션 상태 파일 경로 (여러 전략을 함께 돌릴 때는 전략마다 다르게 지정)
+    pub state_file: String,
+    /// 같은 시세로 함께 돌릴 페이퍼 후보 파라미터 (가상 거래는 shadow_trade_records에 기록)
+    pub shadows: Vec<ShadowParams>,
 }
 
+impl StrategyParams {
+    /// 스팟 주문/시세에 쓰는 심볼
+    pub fn spot_symbol(&self) -> &str {
+        self.spot_symbol.as_deref().unwrap_or(&self.symbol)
+    }
+}
+
+/// `StrategyParams` 기본 심볼
+pub const DEFAULT_SYMBOL: &str = "XPLUSDT";
+/// 기본 진입 임계값 (bps)
+pub const DEFAULT_ENTRY_BPS: f64 = 6.0;
+/// 기본 청산 임계값 (bps). 진입과 반대 부호까지 되돌아와야 청산해 왕복 수수료를 덮는다
+pub const DEFAULT_EXIT_BPS: f64 = -6.0;
+/// 기본 명목가 (USDT, 거래소 최소 주문 금액보다 약간 크게)
+pub const DEFAULT_NOTIONAL: f64 = 6.0;
+/// 기본 선물 레버리지
+pub const DEFAULT_LEVERAGE: u32 = 1;
+/// 기본 운용 자금 한도 (USDT). 1배 레버리지에서 명목가 1회 진입분 (현물 + 증거금)에
+/// 베이시스(증거금은 선물 마크 가격 기준)와 수량 반올림 여유를 더한 값
+pub const DEFAULT_CAPITAL_BUDGET: f64 = 13.0;
+/// 기본 두 번째 레그 재시도 횟수
+pub const DEFAULT_SECOND_LEG_RETRIES: u32 = 2;
+/// 기본 연속 진입 최소 간격 (초)
+pub const DEFAULT_MIN_ENTRY_INTERVAL_SECS: u64 = 30;
+
 impl Default for StrategyParams {
     fn default() -> Self {
         Self {
-            symbol: "XPLUSDT".to_string(),
+            symbol: DEFAULT_SYMBOL.to_string(),
             mode: StrategyMode::Carry,
-            entry_bps: 6.0,
-            exit_bps: -6.0,
-            notional: 6.0,
-            leverage: 1,
+            entry_bps: Bps::new(DEFAULT_ENTRY_BPS),
+            exit_bps: Bps::new(DEFAULT_EXIT_BPS),
+            exit_ladder: None,
+            notional: Notional::new(DEFAULT_NOTIONAL),
+            leverage: DEFAULT_LEVERAGE,
+            dynamic_leverage: None,
             isolated: false,
             dry_run: false,
             policy: ExecutionPolicy::TakerTaker,
             spot_leg: LegExecutionPolicy::MarketTaker,
             futures_leg: LegExecutionPolicy::MarketTaker,
+            capital_budget: Notional::new(DEFAULT_CAPITAL_BUDGET),
+            vol_sizing: None,
+            imbalance_threshold: None,
+            liquidity_floors: None,
+            entry_recheck: None,
+            margin_top_up: None,
+            leg_order: LegOrder::SpotFirst,
+            second_leg_retries: DEFAULT_SECOND_LEG_RETRIES,
+            spot_symbol: None,
+            min_entry_interval_secs: DEFAULT_MIN_ENTRY_INTERVAL_SECS,
+            price_guard: PriceGuardParams::default(),
+            state_file: DEFAULT_STATE_FILE.to_string(),
+            shadows: Vec::new(),
         }
     }
 }
 
+/// 포지션이 없을 때의 진입 방향
+/// Carry는 basis > entry_bps, Reverse는 basis < -entry_bps (Auto는 둘 다 확인, Carry 우선)
+pub fn entry_direction(
+    mode: StrategyMode,
+    basis_bps: Bps,
+    entry_bps: Bps,
+) -> Option<PositionDirection> {
+    if matches!(mode, StrategyMode::Carry | StrategyMode::Auto) && basis_bps > entry_bps {
+        Some(PositionDirection::Carry)
+    } else if matches!(mode, StrategyMode::Reverse | StrategyMode::Auto) && basis_bps < -entry_bps {
+        Some(PositionDirection::Reverse)
+    } else {
+        None
+    }
+}
+
+/// 열린 포지션(ArbitrageState.dir)의 청산 조건
+/// Carry는 basis <= exit_bps, Reverse는 basis >= -exit_bps
+pub fn exit_reached(dir: Option<&str>, basis_bps: Bps, exit_bps: Bps) -> bool {
+    match dir {
+        Some("carry") => basis_bps <= exit_bps,
+        Some("reverse") => basis_bps >= -exit_bps,
+        _ => false,
+    }
+}
+
+pub mod builder;
+pub mod cash_and_carry;
 pub mod cross_basis;
 pub mod intra_basis;
+#[cfg(test)]
+mod mock_traders;
+pub mod spot_spread;
+
+pub use builder::{StrategyParamsBuilder, StrategyParamsError};
 
 #[derive(Debug, Clone)]
 pub struct CrossStrategyParams {
@@ -129,13 +275,15 @@ pub struct CrossStrategyParams {
     /// 프리미엄 거래소 spot 포지션 기준 모드 (carry/reverse/auto)
     pub mode: StrategyMode,
     /// 진입 임계값 (basis points)
-    pub entry_bps: f64,
+    pub entry_bps: Bps,
     /// 청산 임계값 (basis points)
-    pub exit_bps: f64,
+    pub exit_bps: Bps,
     /// 프리미엄 거래소에서 사용할 명목가 (현지 통화 단위)
-    pub primary_notional: f64,
+    pub primary_notional: Notional,
     /// 헤지 거래소에서 사용할 명목가 (USDT 등)
-    pub hedge_notional: f64,
+    pub hedge_notional: Notional,
+    /// 헤지 선물 계약 종류 (Inverse면 코인 마진 선물로 헤지, hedge_symbol 예: "BTCUSD_PERP")
+    pub hedge_contract: ContractKind,
     /// 헤지 선물 레버리지
     pub leverage: u32,
     /// 헤지 선물 마진 타입 (true = 격리)
@@ -152,6 +300,16 @@ pub struct CrossStrategyParams {
     pub fx_adjustment: f64,
     /// 프리미엄 거래소에서 보유해야 하는 베이스 자산명 (예: "BTC")
     pub primary_base_asset: String,
+    /// REVERSE용 spot 재고 버퍼 설정 (None이면 재고를 직접 관리하지 않음)
+    pub inventory: Option<InventoryParams>,
+    /// 같은 전략의 연속 진입 사이 최소 간격 (초)
+    pub min_entry_interval_secs: u64,
+    /// 가격 이상 감지 / 킬 스위치 기준 (프리미엄 가격은 환산 후 비교)
+    pub price_guard: PriceGuardParams,
+    /// 양쪽 거래소의 베이스 자산/USDT 입출금 중단 감시 (None이면 감시하지 않음)
+    pub transfer_monitor: Option<TransferMonitorParams>,
+    /// 포지션 상태 파일 경로
+    pub state_file: String,
 }
 
 impl Default for CrossStrategyParams {
@@ -162,10 +320,11 @@ impl Default for CrossStrategyParams {
             primary_exchange: ExchangeId::Bithumb,
             hedge_exchange: ExchangeId::Binance,
             mode: StrategyMode::Carry,
-            entry_bps: 50.0,
-            exit_bps: 5.0,
-            primary_notional: 5_000_000.0, // KRW 단위 예시
-            hedge_notional: 5_000.0,       // USDT 단위 예시
+            entry_bps: Bps::new(50.0),
+            exit_bps: Bps::new(5.0),
+            primary_notional: Notional::new(5_000_000.0), // KRW 단위 예시
+            hedge_notional: Notional::new(5_000.0),       // USDT 단위 예시
+            hedge_contract: ContractKind::Linear,
             leverage: 1,
             isolated: false,
             dry_run: true,
@@ -174,6 +333,115 @@ impl Default for CrossStrategyParams {
             futures_leg: LegExecutionPolicy::MarketTaker,
             fx_adjustment: 1.0,
             primary_base_asset: "BTC".to_string(),
+            inventory: None,
+            min_entry_interval_secs: 30,
+            price_guard: PriceGuardParams::default(),
+            transfer_monitor: None,
+            state_file: DEFAULT_STATE_FILE.to_string(),
+        }
+    }
+}
+
+/// 분기물 캐시 앤 캐리 전략 설정 (현물 롱 + 분기물 숏을 만기까지 보유)
+#[derive(Debug, Clone)]
+pub struct CashAndCarryParams {
+    /// 현물 심볼 (예: "BTCUSDT")
+    pub spot_symbol: String,
+    /// 분기물 페어 (USDⓈ-M "BTCUSDT", COIN-M "BTCUSD")
+    pub pair: String,
+    /// 분기물 계약 종류 (Linear = USDⓈ-M, Inverse = COIN-M)
+    pub contract: ContractKind,
+    /// 진입 임계값 (연율 환산 베이시스, bps)
+    /// 만기에 베이시스가 0으로 수렴하므로 진입 베이시스 × 365 / 남은 일수가 고정 수익률이 된다
+    pub entry_annualized_bps: Bps,
+    /// 롤오버 최소 연율 베이시스. 다음 계약이 이보다 낮으면 롤오버 대신 청산
+    pub min_roll_annualized_bps: Bps,
+    /// 만기 며칠 전부터 다음 계약으로 롤오버할지 (진입도 이 구간 밖의 계약만 사용)
+    pub roll_days: f64,
+    /// 거래 명목가 (현물 호가 통화 단위)
+    pub notional: Notional,
+    /// 선물 레버리지
+    pub leverage: u32,
+    /// 선물 마진 타입 (true = 격리)
+    pub isolated: bool,
+    /// 테스트 모드 여부
+    pub dry_run: bool,
+    /// 같은 전략의 연속 진입 사이 최소 간격 (초)
+    pub min_entry_interval_secs: u64,
+    /// 포지션 상태 파일 경로
+    pub state_file: String,
+}
+
+impl Default for CashAndCarryParams {
+    fn default() -> Self {
+        Self {
+            spot_symbol: "BTCUSDT".to_string(),
+            pair: "BTCUSDT".to_string(),
+            contract: ContractKind::Linear,
+            entry_annualized_bps: Bps::new(800.0),
+            min_roll_annualized_bps: Bps::new(400.0),
+            roll_days: 3.0,
+            notional: Notional::new(100.0),
+            leverage: 1,
+            isolated: false,
+            dry_run: true,
+            min_entry_interval_secs: 30,
+            state_file: DEFAULT_STATE_FILE.to_string(),
+        }
+    }
+}
+
+/// 현물-현물 거래소 간 스프레드 전략 설정 (선물 없이 양쪽 거래소 재고로 매매)
+#[derive(Debug, Clone)]
+pub struct SpotSpreadParams {
+    /// 거래소 A (가격/명목가 기준 통화 거래소)
+    pub venue_a: ExchangeId,
+    /// 거래소 A 현물 심볼 (예: "BTCUSDT")
+    pub symbol_a: String,
+    /// 거래소 B
+    pub venue_b: ExchangeId,
+    /// 거래소 B 현물 심볼
+    pub symbol_b: String,
+    /// 양쪽에서 주고받는 베이스 자산 (예: "BTC")
+    pub base_asset: String,
+    /// 호가 자산 (예: "USDT")
+    pub quote_asset: String,
+    /// 거래소 B 가격/호가 잔고를 A 통화로 환산하는 계수 (같은 통화면 1.0)
+    pub fx_b: f64,
+    /// 거래소 A taker 수수료율 (0.001 == 0.1%)
+    pub fee_a: f64,
+    /// 거래소 B taker 수수료율
+    pub fee_b: f64,
+    /// 진입 임계값 (양쪽 수수료를 뺀 스프레드, bps)
+    pub entry_bps: Bps,
+    /// 1회 매매 명목가 (A 통화)
+    pub notional: Notional,
+    /// 거래소별로 남길 최소 베이스 재고 비율 (0.0 ~ 0.5, 전체 보유량 대비)
+    /// 매도 거래소 재고가 이 비율 아래로 내려가는 매매는 수량을 줄이거나 건너뜀
+    pub min_inventory_ratio: f64,
+    /// 테스트 모드 여부
+    pub dry_run: bool,
+    /// 연속 매매 사이 최소 간격 (초)
+    pub min_entry_interval_secs: u64,
+}
+
+impl Default for SpotSpreadParams {
+    fn default() -> Self {
+        Self {
+            venue_a: ExchangeId::Binance,
+            symbol_a: "BTCUSDT".to_string(),
+            venue_b: ExchangeId::Bithumb,
+            symbol_b: "BTCUSDT".to_string(),
+            base_asset: "BTC".to_string(),
+            quote_asset: "USDT".to_string(),
+            fx_b: 1.0,
+            fee_a: 0.001,
+            fee_b: 0.0025,
+            entry_bps: Bps::new(10.0),
+            notional: Notional::new(100.0),
+            min_inventory_ratio: 0.2,
+            dry_run: true,
+            min_entry_interval_secs: 5,
         }
     }
 }
@@ -0,0 +1,519 @@
+//! 검증된 `StrategyParams` 생성
+//!
+//! 환경 변수(ARB_*)와 CLI 인자를 기본값 위에 덮어쓴 뒤 `build`에서 한 번에 검증한다.
+//! 청산 임계값이 진입 임계값 이상이거나 명목가가 0인 조합처럼 실행 중에야 드러나던 설정 오류를
+//! 전략을 만들기 전에 어떤 필드가 왜 잘못됐는지와 함께 돌려준다.
+
+use interface::{Bps, Notional};
+
+use super::{StrategyMode, StrategyParams};
+use crate::arbitrage::scale_out::ExitLadder;
+use crate::arbitrage::two_phase::LegOrder;
+use crate::volatility::VolatilitySizing;
+
+/// 허용하는 최대 선물 레버리지 (Binance USDⓈ-M 상한)
+pub const MAX_LEVERAGE: u32 = 125;
+/// 진입 두 번째 레그 재시도 상한
+pub const MAX_SECOND_LEG_RETRIES: u32 = 10;
+/// 자금 한도 검증에서 증거금에 더하는 베이시스 여유 (선물 마크가 현물보다 최대 2% 비싼 경우까지)
+pub const BUDGET_BASIS_HEADROOM: f64 = 0.02;
+
+/// 전략 설정 오류
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum StrategyParamsError {
+    #[error("{field}: cannot parse {value:?} ({reason})")]
+    Parse {
+        field: &'static str,
+        value: String,
+        reason: String,
+    },
+
+    #[error("{field}: {reason}")]
+    OutOfRange { field: &'static str, reason: String },
+
+    #[error(
+        "exit_bps ({exit_bps}) must be below entry_bps ({entry_bps}), otherwise every entry closes on the next tick"
+    )]
+    ExitNotBelowEntry { entry_bps: f64, exit_bps: f64 },
+
+    #[error(
+        "capital_budget {budget:.4} is below {required:.4} needed for one entry (notional up to {notional:.4}, leverage {leverage}x, incl. basis headroom)"
+    )]
+    BudgetTooSmall {
+        budget: f64,
+        required: f64,
+        notional: f64,
+        leverage: u32,
+    },
+}
+
+fn out_of_range(field: &'static str, reason: impl Into<String>) -> StrategyParamsError {
+    StrategyParamsError::OutOfRange {
+        field,
+        reason: reason.into(),
+    }
+}
+
+/// 환경 변수가 있으면 파싱 (형식이 틀리면 에러, 없으면 None)
+fn env_var<T>(field: &'static str, key: &str) -> Result<Option<T>, StrategyParamsError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let Ok(value) = std::env::var(key) else {
+        return Ok(None);
+    };
+    value
+        .trim()
+        .parse()
+        .map(Some)
+        .map_err(|e: T::Err| StrategyParamsError::Parse {
+            field,
+            value,
+            reason: e.to_string(),
+        })
+}
+
+/// ARB_VOL_* 환경 변수로 변동성 사이징 구성 (하나도 없으면 None)
+fn vol_sizing_from_env() -> Result<Option<VolatilitySizing>, StrategyParamsError> {
+    let target_bps = env_var("vol_sizing.target_bps", "ARB_VOL_TARGET_BPS")?;
+    let measure = env_var("vol_sizing.measure", "ARB_VOL_MEASURE")?;
+    let min_scale = env_var("vol_sizing.min_scale", "ARB_VOL_MIN_SCALE")?;
+    let max_scale = env_var("vol_sizing.max_scale", "ARB_VOL_MAX_SCALE")?;
+    let min_bars = env_var("vol_sizing.min_bars", "ARB_VOL_MIN_BARS")?;
+    if target_bps.is_none()
+        && measure.is_none()
+        && min_scale.is_none()
+        && max_scale.is_none()
+        && min_bars.is_none()
+    {
+        return Ok(None);
+    }
+    let defaults = VolatilitySizing::default();
+    Ok(Some(VolatilitySizing {
+        measure: measure.unwrap_or(defaults.measure),
+        target_bps: target_bps.unwrap_or(defaults.target_bps),
+        min_scale: min_scale.unwrap_or(defaults.min_scale),
+        max_scale: max_scale.unwrap_or(defaults.max_scale),
+        min_bars: min_bars.unwrap_or(defaults.min_bars),
+    }))
+}
+
+/// `StrategyParams` 빌더. 기본값(`StrategyParams::default`)에서 시작한다
+#[derive(Debug, Clone, Default)]
+pub struct StrategyParamsBuilder {
+    params: StrategyParams,
+}
+
+impl StrategyParamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 기본값 위에 ARB_* 환경 변수를 덮어씀
+    /// ARB_SYMBOL, ARB_MODE, ARB_ENTRY_BPS, ARB_EXIT_BPS, ARB_NOTIONAL, ARB_LEVERAGE,
+    /// ARB_CAPITAL_BUDGET, ARB_IMBALANCE_THRESHOLD, ARB_LEG_ORDER, ARB_SECOND_LEG_RETRIES,
+    /// ARB_SPOT_SYMBOL, ARB_MIN_ENTRY_INTERVAL_SECS, ARB_EXIT_LADDER
+    /// 변동성 사이징: ARB_VOL_TARGET_BPS, ARB_VOL_MEASURE, ARB_VOL_MIN_SCALE, ARB_VOL_MAX_SCALE,
+    /// ARB_VOL_MIN_BARS (하나라도 있으면 나머지는 `VolatilitySizing` 기본값으로 켠다)
+    pub fn from_env() -> Result<Self, StrategyParamsError> {
+        let mut builder = Self::new();
+        if let Some(symbol) = env_var::<String>("symbol", "ARB_SYMBOL")? {
+            builder = builder.symbol(symbol);
+        }
+        if let Some(mode) = env_var("mode", "ARB_MODE")? {
+            builder = builder.mode(mode);
+        }
+        if let Some(bps) = env_var("entry_bps", "ARB_ENTRY_BPS")? {
+            builder = builder.entry_bps(bps);
+        }
+        if let Some(bps) = env_var("exit_bps", "ARB_EXIT_BPS")? {
+            builder = builder.exit_bps(bps);
+        }
+        if let Some(notional) = env_var("notional", "ARB_NOTIONAL")? {
+            builder = builder.notional(notional);
+        }
+        if let Some(leverage) = env_var("leverage", "ARB_LEVERAGE")? {
+            builder = builder.leverage(leverage);
+        }
+        if let Some(budget) = env_var("capital_budget", "ARB_CAPITAL_BUDGET")? {
+            builder = builder.capital_budget(budget);
+        }
+        if let Some(threshold) = env_var("imbalance_threshold", "ARB_IMBALANCE_THRESHOLD")? {
+            builder = builder.imbalance_threshold(Some(threshold));
+        }
+        if let Some(order) = env_var("leg_order", "ARB_LEG_ORDER")? {
+            builder = builder.leg_order(order);
+        }
+        if let Some(retries) = env_var("second_leg_retries", "ARB_SECOND_LEG_RETRIES")? {
+            builder = builder.second_leg_retries(retries);
+        }
+        if let Some(symbol) = env_var::<String>("spot_symbol", "ARB_SPOT_SYMBOL")? {
+            builder = builder.spot_symbol(Some(symbol));
+        }
+        if let Some(secs) = env_var("min_entry_interval_secs", "ARB_MIN_ENTRY_INTERVAL_SECS")? {
+            builder = builder.min_entry_interval_secs(secs);
+        }
+        if let Some(ladder) = env_var("exit_ladder", "ARB_EXIT_LADDER")? {
+            builder = builder.exit_ladder(Some(ladder));
+        }
+        if let Some(sizing) = vol_sizing_from_env()? {
+            builder = builder.vol_sizing(Some(sizing));
+        }
+        Ok(builder)
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.params.symbol = symbol.into().trim().to_uppercase();
+        self
+    }
+
+    pub fn mode(mut self, mode: StrategyMode) -> Self {
+        self.params.mode = mode;
+        self
+    }
+
+    pub fn entry_bps(mut self, bps: f64) -> Self {
+        self.params.entry_bps = Bps::new(bps);
+        self
+    }
+
+    pub fn exit_bps(mut self, bps: f64) -> Self {
+        self.params.exit_bps = Bps::new(bps);
+        self
+    }
+
+    pub fn exit_ladder(mut self, ladder: Option<ExitLadder>) -> Self {
+        self.params.exit_ladder = ladder;
+        self
+    }
+
+    pub fn notional(mut self, notional: f64) -> Self {
+        self.params.notional = Notional::new(notional);
+        self
+    }
+
+    pub fn leverage(mut self, leverage: u32) -> Self {
+        self.params.leverage = leverage;
+        self
+    }
+
+    pub fn capital_budget(mut self, budget: f64) -> Self {
+        self.params.capital_budget = Notional::new(budget);
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.params.dry_run = dry_run;
+        self
+    }
+
+    pub fn isolated(mut self, isolated: bool) -> Self {
+        self.params.isolated = isolated;
+        self
+    }
+
+    pub fn imbalance_threshold(mut self, threshold: Option<f64>) -> Self {
+        self.params.imbalance_threshold = threshold;
+        self
+    }
+
+    pub fn leg_order(mut self, order: LegOrder) -> Self {
+        self.params.leg_order = order;
+        self
+    }
+
+    pub fn second_leg_retries(mut self, retries: u32) -> Self {
+        self.params.second_leg_retries = retries;
+        self
+    }
+
+    pub fn spot_symbol(mut self, symbol: Option<String>) -> Self {
+        self.params.spot_symbol = symbol.map(|s| s.trim().to_uppercase());
+        self
+    }
+
+    pub fn vol_sizing(mut self, sizing: Option<VolatilitySizing>) -> Self {
+        self.params.vol_sizing = sizing;
+        self
+    }
+
+    pub fn min_entry_interval_secs(mut self, secs: u64) -> Self {
+        self.params.min_entry_interval_secs = secs;
+        self
+    }
+
+    /// 빌더로 표현하지 않는 나머지 필드 (유동성 하한, 재확인, 섀도 등) 수정
+    pub fn with(mut self, f: impl FnOnce(&mut StrategyParams)) -> Self {
+        f(&mut self.params);
+        self
+    }
+
+    /// 검증 후 설정 반환
+    pub fn build(self) -> Result<StrategyParams, StrategyParamsError> {
+        self.params.validate()?;
+        Ok(self.params)
+    }
+}
+
+impl StrategyParams {
+    pub fn builder() -> StrategyParamsBuilder {
+        StrategyParamsBuilder::new()
+    }
+
+    /// 설정 조합 검증 (첫 번째 오류 반환)
+    pub fn validate(&self) -> Result<(), StrategyParamsError> {
+        validate_symbol("symbol", &self.symbol)?;
+        if let Some(spot_symbol) = &self.spot_symbol {
+            validate_symbol("spot_symbol", spot_symbol)?;
+        }
+
+        let (entry, exit) = (self.entry_bps.value(), self.exit_bps.value());
+        if entry <= 0.0 {
+            return Err(out_of_range(
+                "entry_bps",
+                format!("must be positive, got {}", entry),
+            ));
+        }
+        if exit >= entry {
+            return Err(StrategyParamsError::ExitNotBelowEntry {
+                entry_bps: entry,
+                exit_bps: exit,
+            });
+        }
+        if let Some(ladder) = &self.exit_ladder {
+            ladder.check().map_err(|e| out_of_range("exit_ladder", e))?;
+            // 사다리 단계는 진입과 최종 청산 사이에 있어야 한다
+            for level in &ladder.levels {
+                if level.bps >= self.entry_bps || level.bps <= self.exit_bps {
+                    return Err(out_of_range(
+                        "exit_ladder",
+                        format!(
+                            "level {} must be between exit_bps {} and entry_bps {}",
+                            level.bps, exit, entry
+                        ),
+                    ));
+                }
+            }
+        }
+        for shadow in &self.shadows {
+            if shadow.exit_bps >= shadow.entry_bps {
+                return Err(out_of_range(
+                    "shadows",
+                    format!(
+                        "{}: exit_bps {} must be below entry_bps {}",
+                        shadow.name, shadow.exit_bps, shadow.entry_bps
+                    ),
+                ));
+            }
+        }
+
+        let notional = self.notional.value();
+        if notional <= 0.0 {
+            return Err(out_of_range(
+                "notional",
+                format!("must be positive, got {}", notional),
+            ));
+        }
+        if !(1..=MAX_LEVERAGE).contains(&self.leverage) {
+            return Err(out_of_range(
+                "leverage",
+                format!("must be 1..={}, got {}", MAX_LEVERAGE, self.leverage),
+            ));
+        }
+
+        if let Some(sizing) = &self.vol_sizing {
+            if !sizing.target_bps.is_finite() || sizing.target_bps <= 0.0 {
+                return Err(out_of_range(
+                    "vol_sizing.target_bps",
+                    format!("must be positive, got {}", sizing.target_bps),
+                ));
+            }
+            if !(sizing.min_scale > 0.0 && sizing.min_scale <= sizing.max_scale) {
+                return Err(out_of_range(
+                    "vol_sizing",
+                    format!(
+                        "need 0 < min_scale <= max_scale, got {}..{}",
+                        sizing.min_scale, sizing.max_scale
+                    ),
+                ));
+            }
+        }
+
+        // 동적 레버리지면 가장 낮은 레버리지에서도 한 번은 진입할 수 있어야 한다
+        let leverage = self
+            .dynamic_leverage
+            .as_ref()
+            .map_or(self.leverage, |d| d.min_leverage.min(self.leverage))
+            .max(1);
+        // 변동성 사이징은 명목가를 max_scale까지 키우고, 증거금은 현물보다 비싼 선물 마크 가격 기준이다
+        let max_notional = notional
+            * self
+                .vol_sizing
+                .as_ref()
+                .map_or(1.0, |sizing| sizing.max_scale.max(1.0));
+        let required =
+            max_notional + max_notional * (1.0 + BUDGET_BASIS_HEADROOM) / leverage as f64;
+        let budget = self.capital_budget.value();
+        if budget + 1e-9 < required {
+            return Err(StrategyParamsError::BudgetTooSmall {
+                budget,
+                required,
+                notional: max_notional,
+                leverage,
+            });
+        }
+
+        if let Some(threshold) = self.imbalance_threshold
+            && !(threshold > 0.0 && threshold <= 1.0)
+        {
+            return Err(out_of_range(
+                "imbalance_threshold",
+                format!("must be in (0, 1], got {}", threshold),
+            ));
+        }
+        if let Some(top_up) = &self.margin_top_up
+            && !(top_up.min_balance > 0.0 && top_up.target_balance >= top_up.min_balance)
+        {
+            return Err(out_of_range(
+                "margin_top_up",
+                format!(
+                    "need 0 < min_balance <= target_balance, got {} / {}",
+                    top_up.min_balance, top_up.target_balance
+                ),
+            ));
+        }
+        if self.second_leg_retries > MAX_SECOND_LEG_RETRIES {
+            return Err(out_of_range(
+                "second_leg_retries",
+                format!(
+                    "must be at most {}, got {}",
+                    MAX_SECOND_LEG_RETRIES, self.second_leg_retries
+                ),
+            ));
+        }
+        if self.state_file.trim().is_empty() {
+            return Err(out_of_range("state_file", "must not be empty"));
+        }
+        Ok(())
+    }
+}
+
+fn validate_symbol(field: &'static str, symbol: &str) -> Result<(), StrategyParamsError> {
+    if symbol.is_empty() || !symbol.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(out_of_range(
+            field,
+            format!("expected an exchange symbol like BTCUSDT, got {:?}", symbol),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_rejects_invalid_combinations() {
+        let params = StrategyParams::builder()
+            .symbol("btcusdt")
+            .entry_bps(8.0)
+            .exit_bps(-2.0)
+            .notional(50.0)
+            .leverage(2)
+            .capital_budget(80.0)
+            .build()
+            .unwrap();
+        assert_eq!(params.symbol, "BTCUSDT");
+
+        assert_eq!(
+            StrategyParams::builder()
+                .entry_bps(4.0)
+                .exit_bps(4.0)
+                .build()
+                .unwrap_err(),
+            StrategyParamsError::ExitNotBelowEntry {
+                entry_bps: 4.0,
+                exit_bps: 4.0
+            }
+        );
+        assert!(matches!(
+            StrategyParams::builder().notional(0.0).build(),
+            Err(StrategyParamsError::OutOfRange {
+                field: "notional",
+                ..
+            })
+        ));
+        // 1배 레버리지 100 USDT 진입은 현물 100 + 증거금 100 + 베이시스 여유 2가 필요
+        assert!(matches!(
+            StrategyParams::builder()
+                .notional(100.0)
+                .capital_budget(200.0)
+                .build(),
+            Err(StrategyParamsError::BudgetTooSmall { required, .. }) if (required - 202.0).abs() < 1e-9
+        ));
+        // 변동성 사이징은 max_scale(기본 2배)까지 커진 명목가로 검증
+        assert!(matches!(
+            StrategyParams::builder()
+                .notional(100.0)
+                .capital_budget(300.0)
+                .vol_sizing(Some(VolatilitySizing::default()))
+                .build(),
+            Err(StrategyParamsError::BudgetTooSmall { notional, required, .. })
+                if notional == 200.0 && (required - 404.0).abs() < 1e-9
+        ));
+        // 기본 설정은 베이시스가 있어도 한 번 진입할 수 있어야 한다
+        assert!(StrategyParams::builder().build().is_ok());
+        assert!(matches!(
+            StrategyParams::builder().leverage(0).build(),
+            Err(StrategyParamsError::OutOfRange {
+                field: "leverage",
+                ..
+            })
+        ));
+        assert!(matches!(
+            StrategyParams::builder()
+                .imbalance_threshold(Some(1.5))
+                .build(),
+            Err(StrategyParamsError::OutOfRange {
+                field: "imbalance_threshold",
+                ..
+            })
+        ));
+        assert!(matches!(
+            StrategyParams::builder()
+                .spot_symbol(Some("BTC/USDC".to_string()))
+                .build(),
+            Err(StrategyParamsError::OutOfRange {
+                field: "spot_symbol",
+                ..
+            })
+        ));
+        assert!(matches!(
+            StrategyParams::builder()
+                .entry_bps(6.0)
+                .exit_bps(-2.0)
+                .exit_ladder(Some("8:0.5".parse().unwrap()))
+                .build(),
+            Err(StrategyParamsError::OutOfRange {
+                field: "exit_ladder",
+                ..
+            })
+        ));
+        assert!(matches!(
+            StrategyParams::builder()
+                .vol_sizing(Some(VolatilitySizing {
+                    min_scale: 3.0,
+                    max_scale: 2.0,
+                    ..Default::default()
+                }))
+                .build(),
+            Err(StrategyParamsError::OutOfRange {
+                field: "vol_sizing",
+                ..
+            })
+        ));
+        // 기본값은 그대로 유효
+        assert!(StrategyParams::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,689 @@
+//! 분기물 선물을 이용한 캐시 앤 캐리 전략.
+//!
+//! 현물 롱 + 분기물(delivery) 숏을 잡으면 만기에 선물 가격이 현물로 수렴하므로
+//! 진입 시점의 베이시스가 만기까지 고정 수익이 된다 (펀딩비 변동 없음).
+//! 만기 `roll_days`일 전에는 선물 레그만 다음 분기물로 이월(롤오버)하고,
+//! 다음 계약의 연율 베이시스가 `min_roll_annualized_bps`보다 낮으면 포지션을 청산한다.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde_json;
+use tracing::{info, trace, warn};
+
+use crate::clock::{SharedClock, system_clock};
+use crate::events::{PositionAction, PositionDirection, StrategyEvent, event_bus};
+use crate::trader::binance::delivery::{
+    BinanceDeliveryContracts, DeliveryContract, DeliveryContractSource, annualized_basis,
+    select_contract,
+};
+use crate::trader::binance::{BinanceInverseTrader, HedgedPair};
+use crate::trader::{
+    BinanceTrader, ContractKind, FuturesExchangeTrader, OrderResponse, SpotExchangeTrader,
+};
+use exchanges::BinanceClient;
+use interface::{Bps, ExchangeError, Price, Qty};
+
+use super::super::inflight::inflight_orders;
+use super::super::state::{ArbitrageState, strategy_state_path};
+use super::CashAndCarryParams;
+
+/// 분기물 목록을 다시 조회하는 주기 (롤오버 시점에는 항상 다시 조회)
+const CONTRACT_REFRESH_INTERVAL: chrono::Duration = chrono::Duration::hours(1);
+
+/// 현물 롱 + 분기물 숏을 만기 직전까지 보유하고, 만기 전에 다음 분기물로 롤오버하는 전략.
+///
+/// - 진입: 롤오버 구간 밖에서 만기가 가장 가까운 분기물의 연율 베이시스
+///   ((선물 - 현물) / 현물 × 365 / 남은 일수)가 `entry_annualized_bps` 이상이면
+///   현물 BUY + 분기물 SELL
+/// - 보유: 청산 조건 없음 (베이시스가 만기까지 고정되므로 중간 변동은 무시)
+/// - 롤오버: 보유 계약이 만기 `roll_days`일 이내로 들어오면
+///   보유 계약 BUY(reduce-only) + 다음 분기물 SELL. 현물 레그는 그대로 둔다
+/// - 청산: 롤오버할 다음 계약이 없거나 다음 계약의 연율 베이시스가
+///   `min_roll_annualized_bps` 미만이면 보유 계약 BUY(reduce-only) + 현물 SELL
+///
+/// 상태 파일(ArbitrageState)의 symbol에는 보유 중인 분기물 심볼을 기록한다.
+pub struct CashAndCarryStrategy<
+    S = BinanceTrader,
+    F = Box<dyn FuturesExchangeTrader>,
+    C = BinanceDeliveryContracts,
+> where
+    S: SpotExchangeTrader,
+    F: FuturesExchangeTrader,
+    C: DeliveryContractSource,
+{
+    spot_trader: S,
+    futures_trader: F,
+    contracts: C,
+    params: CashAndCarryParams,
+    clock: SharedClock,
+}
+
+impl CashAndCarryStrategy {
+    /// Binance 현물 + USDⓈ-M 또는 COIN-M 분기물 (params.contract)
+    pub fn new(params: CashAndCarryParams) -> Result<Self, ExchangeError> {
+        let spot_trader = BinanceTrader::new()?;
+        let futures_trader: Box<dyn FuturesExchangeTrader> = match params.contract {
+            ContractKind::Linear => Box::new(BinanceTrader::new()?),
+            ContractKind::Inverse => Box::new(BinanceInverseTrader::new()?),
+        };
+        let contracts = BinanceDeliveryContracts::new(BinanceClient::new(), params.contract);
+        Ok(Self::with_traders(
+            spot_trader,
+            futures_trader,
+            contracts,
+            params,
+        ))
+    }
+}
+
+impl<S, F, C> CashAndCarryStrategy<S, F, C>
+where
+    S: SpotExchangeTrader,
+    F: FuturesExchangeTrader,
+    C: DeliveryContractSource,
+{
+    pub fn with_traders(
+        spot_trader: S,
+        futures_trader: F,
+        contracts: C,
+        params: CashAndCarryParams,
+    ) -> Self {
+        Self {
+            spot_trader,
+            futures_trader,
+            contracts,
+            params,
+            clock: system_clock(),
+        }
+    }
+
+    /// 루프의 현재 시각/대기에 쓸 시계 (기본값은 벽시계)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn params(&self) -> &CashAndCarryParams {
+        &self.params
+    }
+
+    /// 이벤트 버스에 쓰는 전략 인스턴스 ID
+    pub fn strategy_id(&self) -> String {
+        format!("cash_and_carry:{}", self.params.pair)
+    }
+
+    /// 이 전략 인스턴스의 상태 파일 경로
+    fn state_path(&self) -> PathBuf {
+        strategy_state_path(&self.params.state_file, &self.strategy_id())
+    }
+
+    fn publish(&self, event: StrategyEvent) {
+        event_bus().publish(
+            &self.strategy_id(),
+            "cash_and_carry",
+            &self.params.spot_symbol,
+            event,
+        );
+    }
+
+    /// 상태 파일이 이 전략(페어 또는 페어의 분기물)의 것인지
+    fn owns_state(&self, state: &ArbitrageState) -> bool {
+        state.symbol == self.params.pair
+            || state
+                .symbol
+                .strip_prefix(&self.params.pair)
+                .is_some_and(|rest| rest.starts_with('_'))
+    }
+
+    /// 진입(Filled) 또는 청산(Closed) 이벤트. 진입은 현물 매수/선물 매도, 청산은 반대
+    fn position_event(
+        &self,
+        action: PositionAction,
+        qty: Qty,
+        basis: Bps,
+        spot_price: Price,
+        futures_mark: Price,
+    ) -> StrategyEvent {
+        let (spot_venue, futures_venue) =
+            ("binance_spot".to_string(), "binance_futures".to_string());
+        let pair = HedgedPair::filled(qty.value());
+        let direction = PositionDirection::Carry;
+        match action {
+            PositionAction::Open => StrategyEvent::Filled {
+                direction,
+                pair,
+                basis_bps: basis.value(),
+                spot_price: spot_price.value(),
+                futures_mark: futures_mark.value(),
+                buy_exchange: spot_venue,
+                sell_exchange: futures_venue,
+            },
+            PositionAction::Close => StrategyEvent::Closed {
+                direction,
+                pair,
+                basis_bps: basis.value(),
+                spot_price: spot_price.value(),
+                futures_mark: futures_mark.value(),
+                buy_exchange: futures_venue,
+                sell_exchange: spot_venue,
+            },
+        }
+    }
+
+    /// 현물/선물 양쪽 LOT_SIZE를 만족하는 수량
+    fn clamp_quantity(&self, futures_symbol: &str, qty: Qty) -> Qty {
+        let spot_qty = self
+            .spot_trader
+            .clamp_spot_quantity(&self.params.spot_symbol, qty);
+        let fut_qty = self
+            .futures_trader
+            .clamp_futures_quantity(futures_symbol, qty);
+        spot_qty.min(fut_qty)
+    }
+
+    /// 캐시 앤 캐리 메인 루프 (1초 주기)
+    ///
+    /// 가격 조회 실패는 에러로 전파해 루프를 종료하고,
+    /// 주문 실패는 에러 이벤트를 발행한 뒤 다음 주기에 다시 시도한다.
+    pub async fn run_loop(&self) -> Result<(), ExchangeError> {
+        self.spot_trader.ensure_exchange_info().await?;
+        self.futures_trader.ensure_exchange_info().await?;
+
+        let mut state = ArbitrageState::read_for_strategy(
+            &self.params.state_file,
+            &self.strategy_id(),
+            |state| self.owns_state(state),
+        )?;
+        if !self.owns_state(&state) {
+            state = ArbitrageState::new(self.params.pair.clone());
+        }
+
+        let mut contracts = self.contracts.delivery_contracts().await?;
+        let mut contracts_loaded_at = self.clock.now();
+
+        info!("Starting cash-and-carry strategy");
+        info!(
+            "Spot: {}, Pair: {} ({:?})",
+            self.params.spot_symbol, self.params.pair, self.params.contract
+        );
+        info!(
+            "Entry: {} bps/yr, Min roll: {} bps/yr, Roll: {} days before delivery",
+            self.params.entry_annualized_bps,
+            self.params.min_roll_annualized_bps,
+            self.params.roll_days
+        );
+        info!(
+            "Current state: open={}, contract={}, qty={}",
+            state.open, state.symbol, state.pair.fut_order_qty
+        );
+
+        loop {
+            self.clock.sleep(Duration::from_secs(1)).await;
+
+            let spot_price = self
+                .spot_trader
+                .get_spot_price(&self.params.spot_symbol)
+                .await
+                .inspect_err(|e| warn!("Failed to get spot price: {}", e))?;
+
+            // 목록에 없는 보유 계약(상장 폐지/만기 지남)도 롤오버 대상으로 본다
+            let now = self.clock.now();
+            let roll_due = state.open
+                && contracts
+                    .iter()
+                    .find(|c| c.symbol == state.symbol)
+                    .is_none_or(|c| c.roll_due(now, self.params.roll_days));
+
+            // 롤오버 시점에는 새로 상장된 분기물까지 보도록 항상 다시 조회
+            if roll_due || now - contracts_loaded_at >= CONTRACT_REFRESH_INTERVAL {
+                match self.contracts.delivery_contracts().await {
+                    Ok(fresh) => {
+                        contracts = fresh;
+                        contracts_loaded_at = now;
+                    }
+                    Err(e) => warn!("Failed to refresh delivery contracts: {}", e),
+                }
+            }
+
+            if roll_due {
+                self.roll_or_close(&mut state, &contracts, spot_price, now)
+                    .await?;
+            } else if !state.open {
+                self.try_enter(&mut state, &contracts, spot_price, now)
+                    .await?;
+            }
+        }
+    }
+
+    /// 포지션이 없을 때: 롤오버 구간 밖의 가장 가까운 분기물로 진입 조건 확인
+    async fn try_enter(
+        &self,
+        state: &mut ArbitrageState,
+        contracts: &[DeliveryContract],
+        spot_price: Price,
+        now: DateTime<Utc>,
+    ) -> Result<(), ExchangeError> {
+        let Some(contract) =
+            select_contract(contracts, &self.params.pair, now, self.params.roll_days)
+        else {
+            trace!(
+                "No delivery contract for {} outside roll window",
+                self.params.pair
+            );
+            return Ok(());
+        };
+
+        let futures_mark = self.futures_trader.get_mark_price(&contract.symbol).await?;
+        let basis = Bps::basis(spot_price, futures_mark);
+        let days = contract.days_to_expiry(now);
+        let annualized = annualized_basis(basis, days);
+        trace!(
+            "Spot: {}, {}: {}, Basis: {:.2} bps ({:.1} days, {:.2} bps/yr)",
+            spot_price,
+            contract.symbol,
+            futures_mark,
+            basis.value(),
+            days,
+            annualized.value()
+        );
+        if annualized < self.params.entry_annualized_bps {
+            return Ok(());
+        }
+
+        let ticket = match inflight_orders().try_begin(
+            &self.strategy_id(),
+            &contract.symbol,
+            Duration::from_secs(self.params.min_entry_interval_secs),
+        ) {
+            Ok(ticket) => ticket,
+            Err(blocked) => {
+                info!("Cash-and-carry entry blocked: {}", blocked);
+                return Ok(());
+            }
+        };
+        info!(
+            "Entry condition met: {} basis {:.2} bps ({:.2} bps/yr, {:.1} days to delivery)",
+            contract.symbol,
+            basis.value(),
+            annualized.value(),
+            days
+        );
+        self.publish(StrategyEvent::EntrySignal {
+            direction: PositionDirection::Carry,
+            basis_bps: basis.value(),
+            spot_price: spot_price.value(),
+            futures_mark: futures_mark.value(),
+        });
+
+        let qty = self.clamp_quantity(&contract.symbol, self.params.notional / spot_price);
+        if qty.is_zero() {
+            warn!("Quantity too small after clamping. Increase notional.");
+            ticket.release();
+            return Ok(());
+        }
+        self.publish(StrategyEvent::OrderPlaced {
+            direction: PositionDirection::Carry,
+            action: PositionAction::Open,
+            qty: qty.value(),
+        });
+
+        match self.open_position(&contract.symbol, qty).await {
+            Ok((spot_order, futures_order)) => {
+                self.publish(self.position_event(
+                    PositionAction::Open,
+                    qty,
+                    basis,
+                    spot_price,
+                    futures_mark,
+                ));
+                let actions = serde_json::json!({
+                    "spot": spot_order,
+                    "futures": futures_order,
+                });
+                state.symbol = contract.symbol.clone();
+                state.update_position(
+                    true,
+                    Some("carry".to_string()),
+                    HedgedPair::filled(qty.value()),
+                    Some(basis.value()),
+                    Some(actions),
+                );
+                state.write_to(self.state_path())?;
+                ticket.complete();
+                info!("Cash-and-carry position opened in {}", contract.symbol);
+            }
+            Err(e) => {
+                ticket.release();
+                warn!("Failed to open cash-and-carry position: {}", e);
+                self.publish(StrategyEvent::Error {
+                    stage: "open".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// 보유 계약이 롤오버 구간에 들어왔을 때: 다음 분기물로 이월하거나 청산
+    async fn roll_or_close(
+        &self,
+        state: &mut ArbitrageState,
+        contracts: &[DeliveryContract],
+        spot_price: Price,
+        now: DateTime<Utc>,
+    ) -> Result<(), ExchangeError> {
+        let held_symbol = state.symbol.clone();
+        let qty = Qty::new(state.pair.fut_order_qty);
+
+        let next = select_contract(contracts, &self.params.pair, now, self.params.roll_days)
+            .filter(|c| c.symbol != held_symbol);
+        if let Some(next) = next {
+            let next_mark = self.futures_trader.get_mark_price(&next.symbol).await?;
+            let basis = Bps::basis(spot_price, next_mark);
+            let annualized = annualized_basis(basis, next.days_to_expiry(now));
+            if annualized >= self.params.min_roll_annualized_bps {
+                info!(
+                    "Rolling {} → {} ({:.2} bps, {:.2} bps/yr)",
+                    held_symbol,
+                    next.symbol,
+                    basis.value(),
+                    annualized.value()
+                );
+                match self.roll_position(&held_symbol, &next.symbol, qty).await {
+                    Ok((close_order, open_order)) => {
+                        self.publish(StrategyEvent::Rolled {
+                            from_symbol: held_symbol.clone(),
+                            to_symbol: next.symbol.clone(),
+                            qty: qty.value(),
+                            basis_bps: basis.value(),
+                        });
+                        let actions = serde_json::json!({
+                            "close": close_order,
+                            "open": open_order,
+                        });
+                        state.symbol = next.symbol.clone();
+                        state.update_position(
+                            true,
+                            Some("carry".to_string()),
+                            state.pair,
+                            Some(basis.value()),
+                            Some(actions),
+                        );
+                        state.write_to(self.state_path())?;
+                    }
+                    Err(e) => {
+                        warn!("Failed to roll {}: {}", held_symbol, e);
+                        self.publish(StrategyEvent::Error {
+                            stage: "roll".to_string(),
+                            message: e.to_string(),
+                        });
+                    }
+                }
+                return Ok(());
+            }
+            info!(
+                "Next contract {} basis {:.2} bps/yr is below roll minimum. Closing position",
+                next.symbol,
+                annualized.value()
+            );
+        } else {
+            info!(
+                "No delivery contract to roll {} into. Closing position",
+                held_symbol
+            );
+        }
+
+        let futures_mark = self
+            .futures_trader
+            .get_mark_price(&held_symbol)
+            .await
+            .unwrap_or(spot_price);
+        let basis = Bps::basis(spot_price, futures_mark);
+        self.publish(StrategyEvent::OrderPlaced {
+            direction: PositionDirection::Carry,
+            action: PositionAction::Close,
+            qty: qty.value(),
+        });
+        match self.close_position(&held_symbol, qty).await {
+            Ok((futures_order, spot_order)) => {
+                self.publish(self.position_event(
+                    PositionAction::Close,
+                    qty,
+                    basis,
+                    spot_price,
+                    futures_mark,
+                ));
+                let actions = serde_json::json!({
+                    "futures": futures_order,
+                    "spot": spot_order,
+                });
+                state.update_position(
+                    false,
+                    None,
+                    Default::default(),
+                    Some(basis.value()),
+                    Some(actions),
+                );
+                state.write_to(self.state_path())?;
+                info!("Cash-and-carry position closed");
+            }
+            Err(e) => {
+                warn!("Failed to close cash-and-carry position: {}", e);
+                self.publish(StrategyEvent::Error {
+                    stage: "close".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// 진입: 현물 매수 + 분기물 매도
+    async fn open_position(
+        &self,
+        futures_symbol: &str,
+        qty: Qty,
+    ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+        if self.params.dry_run {
+            info!("DRY RUN: spot BUY {} {}", qty, self.params.spot_symbol);
+            info!("DRY RUN: futures SELL {} {}", qty, futures_symbol);
+            return Err(ExchangeError::Other("Dry run mode".to_string()));
+        }
+        self.futures_trader
+            .ensure_account_setup(futures_symbol, self.params.leverage, self.params.isolated)
+            .await?;
+        let spot_order = self
+            .spot_trader
+            .buy_spot(&self.params.spot_symbol, qty)
+            .await?;
+        let futures_order = self
+            .futures_trader
+            .sell_futures(futures_symbol, qty, false)
+            .await?;
+        Ok((spot_order, futures_order))
+    }
+
+    /// 롤오버: 보유 분기물 매수(reduce-only) + 다음 분기물 매도
+    async fn roll_position(
+        &self,
+        from_symbol: &str,
+        to_symbol: &str,
+        qty: Qty,
+    ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+        if self.params.dry_run {
+            info!("DRY RUN: futures BUY {} {} (reduceOnly)", qty, from_symbol);
+            info!("DRY RUN: futures SELL {} {}", qty, to_symbol);
+            return Err(ExchangeError::Other("Dry run mode".to_string()));
+        }
+        self.futures_trader
+            .ensure_account_setup(to_symbol, self.params.leverage, self.params.isolated)
+            .await?;
+        let close_order = self
+            .futures_trader
+            .buy_futures(from_symbol, qty, true)
+            .await?;
+        let open_order = self
+            .futures_trader
+            .sell_futures(to_symbol, qty, false)
+            .await?;
+        Ok((close_order, open_order))
+    }
+
+    /// 청산: 분기물 매수(reduce-only) + 현물 매도
+    async fn close_position(
+        &self,
+        futures_symbol: &str,
+        qty: Qty,
+    ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+        if self.params.dry_run {
+            info!(
+                "DRY RUN: futures BUY {} {} (reduceOnly)",
+                qty, futures_symbol
+            );
+            info!("DRY RUN: spot SELL {} {}", qty, self.params.spot_symbol);
+            return Err(ExchangeError::Other("Dry run mode".to_string()));
+        }
+        let futures_order = self
+            .futures_trader
+            .buy_futures(futures_symbol, qty, true)
+            .await?;
+        let spot_order = self
+            .spot_trader
+            .sell_spot(&self.params.spot_symbol, qty)
+            .await?;
+        Ok((futures_order, spot_order))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock_traders::{
+        EventCapture, Leg, MockFuturesTrader, MockSpotTrader, OrderCall, SCRIPT_EXHAUSTED,
+        ScriptedMarket, temp_state_file,
+    };
+    use super::*;
+    use crate::clock::SimClock;
+    use crate::trader::OrderSide;
+    use crate::trader::binance::DeliveryContractType;
+    use chrono::TimeZone;
+    use interface::Notional;
+    use std::sync::Arc;
+
+    type MockStrategy =
+        CashAndCarryStrategy<MockSpotTrader, MockFuturesTrader, Vec<DeliveryContract>>;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()
+    }
+
+    fn contract(symbol: &str, days: i64) -> DeliveryContract {
+        DeliveryContract {
+            symbol: symbol.to_string(),
+            pair: "CNCUSDT".to_string(),
+            contract_type: DeliveryContractType::CurrentQuarter,
+            kind: ContractKind::Linear,
+            delivery_date: start() + chrono::Duration::days(days),
+        }
+    }
+
+    fn strategy(market: &ScriptedMarket, name: &str, current_days: i64) -> MockStrategy {
+        let params = CashAndCarryParams {
+            spot_symbol: "CNCUSDT".to_string(),
+            pair: "CNCUSDT".to_string(),
+            entry_annualized_bps: Bps::new(150.0),
+            min_roll_annualized_bps: Bps::new(100.0),
+            roll_days: 3.0,
+            notional: Notional::new(1_000.0),
+            dry_run: false,
+            min_entry_interval_secs: 0,
+            state_file: temp_state_file(name),
+            ..Default::default()
+        };
+        let contracts = vec![
+            contract("CNCUSDT_CUR", current_days),
+            contract("CNCUSDT_NEXT", 92),
+        ];
+        CashAndCarryStrategy::with_traders(market.spot(), market.futures(), contracts, params)
+            .with_clock(Arc::new(SimClock::starting_at(start())))
+    }
+
+    fn futures_order(side: OrderSide, symbol: &str, reduce_only: bool) -> OrderCall {
+        OrderCall {
+            leg: Leg::Futures,
+            side,
+            symbol: symbol.to_string(),
+            qty: Qty::new(10.0),
+            reduce_only,
+        }
+    }
+
+    async fn run_script(strategy: &MockStrategy) {
+        let err = strategy.run_loop().await.unwrap_err();
+        assert!(err.to_string().contains(SCRIPT_EXHAUSTED), "{}", err);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_entry_skips_contract_inside_roll_window() {
+        // 현재 분기물은 만기 2일 전(롤오버 구간) → 다음 분기물(92일)로 진입
+        // 20bps → 79bps/yr(대기), 50bps → 198bps/yr(진입)
+        let market = ScriptedMarket::new(&[(100.0, 100.2), (100.0, 100.5)]);
+        let strategy = strategy(&market, "CNC_ENTRY", 2);
+        let state_file = strategy.params().state_file.clone();
+
+        run_script(&strategy).await;
+
+        assert_eq!(
+            market.orders(),
+            vec![
+                OrderCall {
+                    leg: Leg::Spot,
+                    side: OrderSide::Buy,
+                    symbol: "CNCUSDT".to_string(),
+                    qty: Qty::new(10.0),
+                    reduce_only: false,
+                },
+                futures_order(OrderSide::Sell, "CNCUSDT_NEXT", false),
+            ]
+        );
+        let state = ArbitrageState::read_from(&state_file).unwrap();
+        assert!(state.open);
+        assert_eq!(state.symbol, "CNCUSDT_NEXT");
+        let _ = std::fs::remove_file(state_file);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rolls_held_contract_before_delivery() {
+        let market = ScriptedMarket::new(&[(100.0, 100.5)]);
+        let strategy = strategy(&market, "CNC_ROLL", 2);
+        let state_file = strategy.params().state_file.clone();
+        let mut state = ArbitrageState::new("CNCUSDT_CUR".to_string());
+        state.update_position(
+            true,
+            Some("carry".to_string()),
+            HedgedPair::filled(10.0),
+            Some(40.0),
+            None,
+        );
+        state.write_to(&state_file).unwrap();
+        let mut events = EventCapture::new(&strategy.strategy_id());
+
+        run_script(&strategy).await;
+
+        // 현물 레그는 유지하고 선물 레그만 이월
+        assert_eq!(
+            market.orders(),
+            vec![
+                futures_order(OrderSide::Buy, "CNCUSDT_CUR", true),
+                futures_order(OrderSide::Sell, "CNCUSDT_NEXT", false),
+            ]
+        );
+        let state = ArbitrageState::read_from(&state_file).unwrap();
+        assert!(state.open);
+        assert_eq!(state.symbol, "CNCUSDT_NEXT");
+        let kinds: Vec<&str> = events.drain().iter().map(|e| e.event.kind()).collect();
+        assert_eq!(kinds, vec!["rolled"]);
+        let _ = std::fs::remove_file(state_file);
+    }
+}
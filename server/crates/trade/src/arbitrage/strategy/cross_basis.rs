@@ -1,14 +1,30 @@
 //! 두 개의 거래소 간 가격 격차(베이시스)를 동시에 이용하는 크로스 거래 전략.
 //! 프리미엄 거래소(spot)와 헤지 거래소(선물)의 가격을 비교해 carry/reverse 포지션을 관리한다.
 
+use std::path::PathBuf;
+use std::time::Duration;
+
 use serde_json;
 use tracing::{info, warn};
 
-use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse, SpotExchangeTrader};
-use interface::ExchangeError;
+use crate::clock::{SharedClock, system_clock};
+use crate::events::{PositionAction, PositionDirection, StrategyEvent, event_bus};
+use crate::oracle_client::OracleClient;
+use crate::trader::binance::{BinanceInverseTrader, HedgedPair};
+use crate::trader::{
+    BinanceTrader, ContractKind, FuturesExchangeTrader, OrderResponse, SpotExchangeTrader,
+    futures_trader_for, parse_exchange_id, spot_trader_for,
+};
+use crate::transfer_status::transfer_monitor;
+use interface::{Bps, ExchangeError, ExchangeId, Price, Qty};
 
-use super::super::state::ArbitrageState;
-use super::{CrossStrategyParams, StrategyMode};
+use super::super::control::operator_control;
+use super::super::hedge_venue::{HedgeVenueParams, HedgeVenueRouter};
+use super::super::inflight::inflight_orders;
+use super::super::inventory::{InventoryLedger, InventoryManager};
+use super::super::kill_switch::{PriceGuard, guard_iteration};
+use super::super::state::{ArbitrageState, strategy_state_path};
+use super::{CrossStrategyParams, entry_direction, exit_reached};
 
 /// 두 개의 서로 다른 거래소 간 베이시스(가격 격차)를 이용해
 /// **크로스 거래소 델타-뉴트럴 포지션**을 자동으로 관리하는 전략 엔진.
@@ -86,6 +102,7 @@ where
     spot_trader: S,
     hedge_trader: F,
     params: CrossStrategyParams,
+    clock: SharedClock,
 }
 
 // TODO: 각 거래소별 taker/maker 수수료, 리베이트(VIP, MM 프로그램 등)를 반영해
@@ -162,6 +179,79 @@ impl CrossBasisArbitrageStrategy {
     }
 }
 
+/// 상태 파일/전략 ID에 쓰는 심볼 (거래소 조합 포함)
+fn state_symbol(params: &CrossStrategyParams) -> String {
+    format!(
+        "{}@{:?}|{}@{:?}",
+        params.primary_symbol, params.primary_exchange, params.hedge_symbol, params.hedge_exchange
+    )
+}
+
+/// 설정의 거래소 조합으로 만든 전략 (레그별 동적 디스패치)
+pub type VenueCrossBasisArbitrageStrategy =
+    CrossBasisArbitrageStrategy<Box<dyn SpotExchangeTrader>, Box<dyn FuturesExchangeTrader>>;
+
+impl VenueCrossBasisArbitrageStrategy {
+    /// params.primary_exchange(spot) / params.hedge_exchange(선물)에 맞는 트레이더로 생성
+    pub fn from_venues(params: CrossStrategyParams) -> Result<Self, ExchangeError> {
+        let spot_trader = spot_trader_for(params.primary_exchange)?;
+        let hedge_trader: Box<dyn FuturesExchangeTrader> = match params.hedge_contract {
+            ContractKind::Linear => futures_trader_for(params.hedge_exchange)?,
+            ContractKind::Inverse => match params.hedge_exchange {
+                ExchangeId::Binance => Box::new(BinanceInverseTrader::new()?),
+                other => {
+                    return Err(ExchangeError::Other(format!(
+                        "Coin-margined hedge is not supported for {:?}",
+                        other
+                    )));
+                }
+            },
+        };
+        info!(
+            "크로스 전략 거래소 구성: spot={:?}, 선물={:?} ({:?})",
+            params.primary_exchange, params.hedge_exchange, params.hedge_contract
+        );
+        Ok(Self::with_traders(spot_trader, hedge_trader, params))
+    }
+
+    /// 헤지 거래소를 진입 시점마다 Oracle 스냅샷으로 고르는 전략
+    /// params.hedge_exchange는 시작(또는 보유 중인 포지션의) 거래소로 쓴다
+    pub fn with_hedge_selection(
+        params: CrossStrategyParams,
+        selection: HedgeVenueParams,
+    ) -> Result<Self, ExchangeError> {
+        if params.hedge_contract != ContractKind::Linear {
+            return Err(ExchangeError::Other(
+                "Hedge venue selection supports USDT-margined perps only".to_string(),
+            ));
+        }
+        let spot_trader = spot_trader_for(params.primary_exchange)?;
+        let strategy_id = format!("cross_basis:{}", state_symbol(&params));
+        let router = HedgeVenueRouter::new(
+            strategy_id,
+            params.hedge_exchange,
+            selection,
+            OracleClient::from_env(),
+        )?;
+        info!(
+            "크로스 전략 거래소 구성: spot={:?}, 선물=진입 시 선택 (시작 {:?})",
+            params.primary_exchange, params.hedge_exchange
+        );
+        Ok(Self::with_traders(spot_trader, Box::new(router), params))
+    }
+
+    /// 설정 파일의 거래소 이름("binance", "bybit", "okx" 등)으로 생성
+    pub fn from_venue_names(
+        primary_exchange: &str,
+        hedge_exchange: &str,
+        mut params: CrossStrategyParams,
+    ) -> Result<Self, ExchangeError> {
+        params.primary_exchange = parse_exchange_id(primary_exchange)?;
+        params.hedge_exchange = parse_exchange_id(hedge_exchange)?;
+        Self::from_venues(params)
+    }
+}
+
 impl<S, F> CrossBasisArbitrageStrategy<S, F>
 where
     S: SpotExchangeTrader,
@@ -172,24 +262,117 @@ where
             spot_trader,
             hedge_trader,
             params,
+            clock: system_clock(),
         }
     }
 
+    /// 루프의 현재 시각/대기에 쓸 시계 (기본값은 벽시계)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     pub fn params(&self) -> &CrossStrategyParams {
         &self.params
     }
 
+    /// 이벤트 버스에 쓰는 전략 인스턴스 ID
+    pub fn strategy_id(&self) -> String {
+        format!("cross_basis:{}", self.state_symbol())
+    }
+
+    /// 이 전략 인스턴스의 상태 파일 경로
+    fn state_path(&self) -> PathBuf {
+        strategy_state_path(&self.params.state_file, &self.strategy_id())
+    }
+
+    /// 전략 이벤트 발행 (기록 저장/알림/지표/감사 로그는 구독자가 처리)
+    fn publish(&self, event: StrategyEvent) {
+        event_bus().publish(
+            &self.strategy_id(),
+            "cross_basis",
+            &self.params.primary_symbol,
+            event,
+        );
+    }
+
+    /// 포지션 체결(진입) 또는 청산 이벤트
+    /// CARRY 진입은 primary spot 매수 / hedge 선물 매도, 청산과 REVERSE는 반대 방향
+    fn position_event(
+        &self,
+        action: PositionAction,
+        direction: PositionDirection,
+        qty: Qty,
+        basis_bps: Bps,
+        primary_price: Price,
+        hedge_mark: Price,
+    ) -> StrategyEvent {
+        let spot_venue = format!("{:?}_spot", self.params.primary_exchange).to_lowercase();
+        let hedge_exchange = self
+            .hedge_trader
+            .venue()
+            .unwrap_or(self.params.hedge_exchange);
+        let hedge_venue = format!("{:?}_futures", hedge_exchange).to_lowercase();
+        let buys_spot = matches!(
+            (direction, action),
+            (PositionDirection::Carry, PositionAction::Open)
+                | (PositionDirection::Reverse, PositionAction::Close)
+        );
+        let (buy_exchange, sell_exchange) = if buys_spot {
+            (spot_venue, hedge_venue)
+        } else {
+            (hedge_venue, spot_venue)
+        };
+        let pair = HedgedPair::filled(qty.value());
+        let (basis_bps, primary_price, hedge_mark) =
+            (basis_bps.value(), primary_price.value(), hedge_mark.value());
+        match action {
+            PositionAction::Open => StrategyEvent::Filled {
+                direction,
+                pair,
+                basis_bps,
+                spot_price: primary_price,
+                futures_mark: hedge_mark,
+                buy_exchange,
+                sell_exchange,
+            },
+            PositionAction::Close => StrategyEvent::Closed {
+                direction,
+                pair,
+                basis_bps,
+                spot_price: primary_price,
+                futures_mark: hedge_mark,
+                buy_exchange,
+                sell_exchange,
+            },
+        }
+    }
+
     fn state_symbol(&self) -> String {
-        format!(
-            "{}@{:?}|{}@{:?}",
-            self.params.primary_symbol,
-            self.params.primary_exchange,
-            self.params.hedge_symbol,
-            self.params.hedge_exchange
-        )
+        state_symbol(&self.params)
+    }
+
+    /// 입출금을 감시할 (거래소, 자산): 양쪽 거래소의 베이스 자산과 USDT
+    fn transfer_targets(&self) -> Vec<(ExchangeId, &str)> {
+        let base = self.params.primary_base_asset.as_str();
+        vec![
+            (self.params.primary_exchange, base),
+            (self.params.primary_exchange, "USDT"),
+            (self.params.hedge_exchange, base),
+            (self.params.hedge_exchange, "USDT"),
+        ]
+    }
+
+    /// 입출금 중단으로 진입을 막아야 하면 사유
+    fn transfer_block_reason(&self) -> Option<String> {
+        self.params
+            .transfer_monitor
+            .filter(|monitor| monitor.block_entries)?;
+        let suspensions = transfer_monitor().suspensions(&self.transfer_targets());
+        (!suspensions.is_empty()).then(|| suspensions.join(", "))
     }
 
-    fn clamp_cross_quantity(&self, qty: f64) -> f64 {
+    fn clamp_cross_quantity(&self, qty: Qty) -> Qty {
         let spot_qty = self
             .spot_trader
             .clamp_spot_quantity(&self.params.primary_symbol, qty);
@@ -199,17 +382,17 @@ where
         spot_qty.min(fut_qty)
     }
 
-    fn target_quantity(&self, primary_price: f64, hedge_price: f64) -> f64 {
-        let primary_qty = if primary_price > 0.0 {
-            self.params.primary_notional / primary_price
+    fn target_quantity(&self, primary_price: Price, hedge_price: Price) -> Qty {
+        let primary_qty = if primary_price.is_zero() {
+            Qty::ZERO
         } else {
-            0.0
+            self.params.primary_notional / primary_price
         };
 
-        let hedge_qty = if hedge_price > 0.0 {
-            self.params.hedge_notional / hedge_price
+        let hedge_qty = if hedge_price.is_zero() {
+            Qty::ZERO
         } else {
-            0.0
+            self.params.hedge_notional / hedge_price
         };
 
         primary_qty.min(hedge_qty)
@@ -294,12 +477,24 @@ where
             )
             .await?;
 
-        let mut state = ArbitrageState::read()?;
         let state_symbol = self.state_symbol();
+        let mut state = ArbitrageState::read_for_strategy(
+            &self.params.state_file,
+            &self.strategy_id(),
+            |state| state.symbol == state_symbol,
+        )?;
         if state.symbol != state_symbol {
             state = ArbitrageState::new(state_symbol.clone());
         }
 
+        let mut inventory = match &self.params.inventory {
+            Some(params) => Some(InventoryManager::new(
+                params.clone(),
+                InventoryLedger::read(&self.params.primary_base_asset)?,
+            )),
+            None => None,
+        };
+
         info!("Starting cross-exchange basis arbitrage strategy");
         info!(
             "Premium Exchange: {:?} {}, Hedge Exchange: {:?} {}",
@@ -313,8 +508,17 @@ where
             self.params.mode, self.params.entry_bps, self.params.exit_bps
         );
 
+        if let Some(monitor) = &self.params.transfer_monitor {
+            for (exchange, asset) in self.transfer_targets() {
+                transfer_monitor().watch(exchange, asset);
+            }
+            transfer_monitor().start(monitor.interval);
+        }
+
+        let mut price_guard = PriceGuard::new(self.params.price_guard);
+        let mut flatten_seen = operator_control().flatten_seq();
         loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            self.clock.sleep(Duration::from_secs(1)).await;
 
             let primary_price = self
                 .spot_trader
@@ -334,8 +538,8 @@ where
                     e
                 })?;
 
-            let adjusted_primary = primary_price * self.params.fx_adjustment;
-            if adjusted_primary <= 0.0 {
+            let adjusted_primary = primary_price.convert(self.params.fx_adjustment);
+            if adjusted_primary.value() <= 0.0 {
                 warn!(
                     "Adjusted primary price invalid ({}). Skipping iteration.",
                     adjusted_primary
@@ -343,65 +547,134 @@ where
                 continue;
             }
 
-            let basis_bps = (hedge_mark - adjusted_primary) / adjusted_primary * 10_000.0;
+            let basis_bps = Bps::basis(adjusted_primary, hedge_mark);
 
             info!(
                 "Primary: {:.8}, Hedge: {:.8}, Adjusted Basis: {:.8} bps",
                 primary_price, hedge_mark, basis_bps
             );
 
+            // 가격 이상(잘못된 데이터)이면 건너뛰고, 지속되면 킬 스위치
+            if !guard_iteration(
+                &self.strategy_id(),
+                &mut price_guard,
+                adjusted_primary.value(),
+                hedge_mark.value(),
+            )
+            .await
+            {
+                continue;
+            }
+
+            // 운영자 전량 청산 요청: 보유 중이면 베이시스와 무관하게 청산
+            let flatten_requested = operator_control().take_flatten(&mut flatten_seen);
+
             if state.open {
                 // 이미 포지션이 있을 경우 청산 조건만 감시
-                let should_close = match state.dir.as_deref() {
-                    Some("carry") => basis_bps <= self.params.exit_bps,
-                    Some("reverse") => basis_bps >= -self.params.exit_bps,
-                    _ => false,
-                };
+                let should_close = flatten_requested
+                    || exit_reached(state.dir.as_deref(), basis_bps, self.params.exit_bps);
 
                 if should_close {
-                    info!("Exit condition met. Closing position...");
-                    let result = match state.dir.as_deref() {
-                        Some("carry") => self.close_carry(todo!()).await,
-                        Some("reverse") => self.close_reverse(todo!()).await,
-                        _ => {
-                            warn!("Unknown position direction: {:?}", state.dir);
-                            continue;
-                        }
+                    if flatten_requested {
+                        warn!("Operator flatten requested. Closing position...");
+                    } else {
+                        info!("Exit condition met. Closing position...");
+                    }
+                    let Some(direction) = PositionDirection::from_state_dir(state.dir.as_deref())
+                    else {
+                        warn!("Unknown position direction: {:?}", state.dir);
+                        continue;
+                    };
+                    let qty = Qty::new(state.pair.fut_order_qty);
+                    self.publish(StrategyEvent::OrderPlaced {
+                        direction,
+                        action: PositionAction::Close,
+                        qty: qty.value(),
+                    });
+                    let result = match direction {
+                        PositionDirection::Carry => self.close_carry(qty).await,
+                        PositionDirection::Reverse => self.close_reverse(qty).await,
                     };
 
                     match result {
                         Ok((hedge_order, spot_order)) => {
+                            self.publish(self.position_event(
+                                PositionAction::Close,
+                                direction,
+                                qty,
+                                basis_bps,
+                                primary_price,
+                                hedge_mark,
+                            ));
                             let actions = serde_json::json!({
                                 "hedge": hedge_order,
                                 "spot": spot_order,
                             });
+                            if state.dir.as_deref() == Some("reverse")
+                                && let Some(manager) = inventory.as_mut()
+                            {
+                                manager.ledger.return_from_reverse(
+                                    state.pair.fut_order_qty,
+                                    primary_price.value(),
+                                    hedge_mark.value(),
+                                    self.params.fx_adjustment,
+                                );
+                                self.save_inventory(manager, primary_price);
+                            }
                             state.update_position(
                                 false,
                                 None,
                                 Default::default(),
-                                Some(basis_bps),
+                                Some(basis_bps.value()),
                                 Some(actions),
                             );
-                            state.write()?;
+                            state.write_to(self.state_path())?;
                             info!("Position closed successfully");
                         }
                         Err(e) => {
                             warn!("Failed to close position: {}", e);
+                            self.publish(StrategyEvent::Error {
+                                stage: "close".to_string(),
+                                message: e.to_string(),
+                            });
                         }
                     }
                 }
             } else {
                 // 포지션이 없을 때만 carry/reverse 진입 여부 판단
-                let should_open_carry =
-                    matches!(self.params.mode, StrategyMode::Carry | StrategyMode::Auto)
-                        && basis_bps > self.params.entry_bps;
+                let entry = entry_direction(self.params.mode, basis_bps, self.params.entry_bps);
+                let should_open_carry = entry == Some(PositionDirection::Carry);
+                let should_open_reverse = entry == Some(PositionDirection::Reverse);
 
-                let should_open_reverse =
-                    matches!(self.params.mode, StrategyMode::Reverse | StrategyMode::Auto)
-                        && basis_bps < -self.params.entry_bps;
+                if entry.is_some()
+                    && let Some(reason) = self.transfer_block_reason()
+                {
+                    info!(
+                        "Cross-exchange entry blocked by transfer suspension: {}",
+                        reason
+                    );
+                    continue;
+                }
+
+                // 진입 시점 헤지 거래소 선택: 바뀌었으면 새 거래소 가격으로 다음 주기에 재확인
+                if let Some(direction) = entry {
+                    match self
+                        .hedge_trader
+                        .prepare_entry(
+                            &self.params.hedge_symbol,
+                            direction,
+                            self.params.hedge_notional.value(),
+                        )
+                        .await
+                    {
+                        Ok(true) => continue,
+                        Ok(false) => {}
+                        Err(e) => warn!("Hedge venue selection failed, keeping current: {}", e),
+                    }
+                }
 
                 let qty = self.target_quantity(primary_price, hedge_mark);
-                if qty <= 0.0 {
+                if qty.is_zero() {
                     warn!(
                         "Target quantity too small. primary/hedge prices: {}/{}",
                         primary_price, hedge_mark
@@ -410,9 +683,39 @@ where
                 }
 
                 if should_open_carry {
+                    let ticket = match inflight_orders().try_begin(
+                        &self.strategy_id(),
+                        &self.params.hedge_symbol,
+                        Duration::from_secs(self.params.min_entry_interval_secs),
+                    ) {
+                        Ok(ticket) => ticket,
+                        Err(blocked) => {
+                            info!("Cross-exchange CARRY entry blocked: {}", blocked);
+                            continue;
+                        }
+                    };
                     info!("Entry condition met for cross-exchange CARRY. Opening position...");
+                    self.publish(StrategyEvent::EntrySignal {
+                        direction: PositionDirection::Carry,
+                        basis_bps: basis_bps.value(),
+                        spot_price: primary_price.value(),
+                        futures_mark: hedge_mark.value(),
+                    });
+                    self.publish(StrategyEvent::OrderPlaced {
+                        direction: PositionDirection::Carry,
+                        action: PositionAction::Open,
+                        qty: qty.value(),
+                    });
                     match self.open_carry(qty).await {
                         Ok((spot_order, hedge_order, filled_qty)) => {
+                            self.publish(self.position_event(
+                                PositionAction::Open,
+                                PositionDirection::Carry,
+                                filled_qty,
+                                basis_bps,
+                                primary_price,
+                                hedge_mark,
+                            ));
                             let actions = serde_json::json!({
                                 "spot": spot_order,
                                 "hedge": hedge_order,
@@ -420,48 +723,195 @@ where
                             state.update_position(
                                 true,
                                 Some("carry".to_string()),
-                                todo!(),
-                                Some(basis_bps),
+                                HedgedPair::filled(filled_qty.value()),
+                                Some(basis_bps.value()),
                                 Some(actions),
                             );
-                            state.write()?;
+                            state.write_to(self.state_path())?;
+                            ticket.complete();
                             info!("Cross-exchange CARRY position opened successfully");
                         }
                         Err(e) => {
+                            ticket.release();
                             warn!("Failed to open CARRY position: {}", e);
+                            self.publish(StrategyEvent::Error {
+                                stage: "open".to_string(),
+                                message: e.to_string(),
+                            });
                         }
                     }
                 } else if should_open_reverse {
+                    let ticket = match inflight_orders().try_begin(
+                        &self.strategy_id(),
+                        &self.params.hedge_symbol,
+                        Duration::from_secs(self.params.min_entry_interval_secs),
+                    ) {
+                        Ok(ticket) => ticket,
+                        Err(blocked) => {
+                            info!("Cross-exchange REVERSE entry blocked: {}", blocked);
+                            continue;
+                        }
+                    };
                     info!("Entry condition met for cross-exchange REVERSE. Opening position...");
+                    self.publish(StrategyEvent::EntrySignal {
+                        direction: PositionDirection::Reverse,
+                        basis_bps: basis_bps.value(),
+                        spot_price: primary_price.value(),
+                        futures_mark: hedge_mark.value(),
+                    });
+                    self.publish(StrategyEvent::OrderPlaced {
+                        direction: PositionDirection::Reverse,
+                        action: PositionAction::Open,
+                        qty: qty.value(),
+                    });
                     match self.open_reverse(qty).await {
                         Ok((spot_order, hedge_order, filled_qty)) => {
+                            self.publish(self.position_event(
+                                PositionAction::Open,
+                                PositionDirection::Reverse,
+                                filled_qty,
+                                basis_bps,
+                                primary_price,
+                                hedge_mark,
+                            ));
                             let actions = serde_json::json!({
                                 "spot": spot_order,
                                 "hedge": hedge_order,
                             });
+                            if let Some(manager) = inventory.as_mut() {
+                                manager.ledger.lend_for_reverse(
+                                    filled_qty.value(),
+                                    primary_price.value(),
+                                    hedge_mark.value(),
+                                );
+                                self.save_inventory(manager, primary_price);
+                            }
                             state.update_position(
                                 true,
                                 Some("reverse".to_string()),
-                                todo!(),
-                                Some(basis_bps),
+                                HedgedPair::filled(filled_qty.value()),
+                                Some(basis_bps.value()),
                                 Some(actions),
                             );
-                            state.write()?;
+                            state.write_to(self.state_path())?;
+                            ticket.complete();
                             info!("Cross-exchange REVERSE position opened successfully");
                         }
                         Err(e) => {
+                            ticket.release();
                             warn!("Failed to open REVERSE position: {}", e);
+                            self.publish(StrategyEvent::Error {
+                                stage: "open".to_string(),
+                                message: e.to_string(),
+                            });
                         }
                     }
+                } else if let Some(manager) = inventory.as_mut() {
+                    // 진입 신호가 없을 때만 REVERSE용 재고를 쌓음
+                    self.accumulate_inventory(manager, primary_price, basis_bps)
+                        .await;
                 }
             }
         }
     }
 
+    /// 포지션이 없고 펀딩/베이시스가 중립이면 spot 재고 버퍼를 조금씩 매수
+    async fn accumulate_inventory(
+        &self,
+        manager: &mut InventoryManager,
+        primary_price: Price,
+        basis_bps: Bps,
+    ) {
+        let balance = match self
+            .spot_trader
+            .get_spot_balance(&self.params.primary_base_asset)
+            .await
+        {
+            Ok(balance) => balance,
+            Err(e) => {
+                warn!("Failed to get spot inventory balance: {}", e);
+                return;
+            }
+        };
+        let funding_rate = self
+            .hedge_trader
+            .get_funding_rate(&self.params.hedge_symbol)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to get funding rate: {}", e);
+                None
+            });
+
+        let Some(qty) = manager.plan_buy(
+            balance.value(),
+            false,
+            basis_bps.value(),
+            funding_rate,
+            self.clock.now(),
+        ) else {
+            return;
+        };
+
+        if self.params.dry_run {
+            info!(
+                "DRY RUN: Would BUY inventory {} {} (balance {}, target {})",
+                qty, self.params.primary_symbol, balance, manager.params.target_qty
+            );
+            return;
+        }
+
+        let trade_qty = self
+            .spot_trader
+            .clamp_spot_quantity(&self.params.primary_symbol, Qty::new(qty));
+        if trade_qty.is_zero() {
+            return;
+        }
+
+        match self
+            .spot_trader
+            .buy_spot(&self.params.primary_symbol, trade_qty)
+            .await
+        {
+            Ok(_) => {
+                info!(
+                    "재고 버퍼 매수: {} {} @ {:.8} (보유 {} → 목표 {})",
+                    trade_qty,
+                    self.params.primary_base_asset,
+                    primary_price,
+                    balance,
+                    manager.params.target_qty
+                );
+                manager.ledger.record_buy(
+                    trade_qty.value(),
+                    primary_price.value(),
+                    self.clock.now(),
+                );
+                self.save_inventory(manager, primary_price);
+            }
+            Err(e) => warn!("Failed to buy inventory buffer: {}", e),
+        }
+    }
+
+    fn save_inventory(&self, manager: &InventoryManager, primary_price: Price) {
+        if let Err(e) = manager.ledger.write() {
+            warn!("Failed to save inventory ledger: {}", e);
+        }
+        let report = manager.ledger.report(primary_price.value());
+        info!(
+            "재고 손익: 보유 {} (REVERSE 사용 {}), 평균 원가 {:.8}, 재고 평가 {:.8}, 재고 실현 {:.8}, 베이시스 실현 {:.8}",
+            report.qty,
+            report.lent_qty,
+            report.avg_cost,
+            report.unrealized_inventory_pnl,
+            report.realized_inventory_pnl,
+            report.realized_basis_pnl
+        );
+    }
+
     async fn open_carry(
         &self,
-        qty: f64,
-    ) -> Result<(OrderResponse, OrderResponse, f64), ExchangeError> {
+        qty: Qty,
+    ) -> Result<(OrderResponse, OrderResponse, Qty), ExchangeError> {
         info!(
             "Opening cross CARRY: buy {} {} on {:?}, sell futures {} {} on {:?}",
             qty,
@@ -481,7 +931,7 @@ where
         }
 
         let trade_qty = self.clamp_cross_quantity(qty);
-        if trade_qty <= 0.0 {
+        if trade_qty.is_zero() {
             return Err(ExchangeError::Other(format!(
                 "Quantity too small after clamping. Requested={}",
                 qty
@@ -500,7 +950,7 @@ where
         Ok((spot_order, hedge_order, trade_qty))
     }
 
-    async fn close_carry(&self, qty: f64) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+    async fn close_carry(&self, qty: Qty) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
         info!("Closing cross CARRY position (reduce-only) qty {}", qty);
 
         if self.params.dry_run {
@@ -512,7 +962,7 @@ where
         }
 
         let trade_qty = self.clamp_cross_quantity(qty);
-        if trade_qty <= 0.0 {
+        if trade_qty.is_zero() {
             return Err(ExchangeError::Other(
                 "Quantity too small after clamping".to_string(),
             ));
@@ -532,8 +982,8 @@ where
 
     async fn open_reverse(
         &self,
-        qty: f64,
-    ) -> Result<(OrderResponse, OrderResponse, f64), ExchangeError> {
+        qty: Qty,
+    ) -> Result<(OrderResponse, OrderResponse, Qty), ExchangeError> {
         info!(
             "Opening cross REVERSE: sell {} {} on {:?}, buy futures {} {} on {:?}",
             qty,
@@ -556,7 +1006,7 @@ where
             .spot_trader
             .get_spot_balance(&self.params.primary_base_asset)
             .await?;
-        if spot_balance <= 0.0 {
+        if spot_balance.is_zero() {
             return Err(ExchangeError::Other(format!(
                 "Insufficient spot inventory on {:?}. balance={}",
                 self.params.primary_exchange, spot_balance
@@ -565,7 +1015,7 @@ where
 
         let max_qty = spot_balance.min(qty);
         let trade_qty = self.clamp_cross_quantity(max_qty);
-        if trade_qty <= 0.0 {
+        if trade_qty.is_zero() {
             return Err(ExchangeError::Other(
                 "Quantity too small after inventory clamp".to_string(),
             ));
@@ -585,7 +1035,7 @@ where
 
     async fn close_reverse(
         &self,
-        qty: f64,
+        qty: Qty,
     ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
         info!("Closing cross REVERSE position qty {}", qty);
 
@@ -598,7 +1048,7 @@ where
         }
 
         let trade_qty = self.clamp_cross_quantity(qty);
-        if trade_qty <= 0.0 {
+        if trade_qty.is_zero() {
             return Err(ExchangeError::Other(
                 "Quantity too small after clamping".to_string(),
             ));
@@ -616,3 +1066,180 @@ where
         Ok((hedge_order, spot_order))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::StrategyMode;
+    use super::super::mock_traders::{
+        EventCapture, Leg, MockFuturesTrader, MockSpotTrader, OrderCall, SCRIPT_EXHAUSTED,
+        ScriptedMarket, temp_state_file,
+    };
+    use super::*;
+    use crate::trader::OrderSide;
+    use interface::Notional;
+
+    type MockStrategy = CrossBasisArbitrageStrategy<MockSpotTrader, MockFuturesTrader>;
+
+    fn params(name: &str, mode: StrategyMode) -> CrossStrategyParams {
+        CrossStrategyParams {
+            primary_symbol: format!("{}KRW", name),
+            hedge_symbol: format!("{}USDT", name),
+            primary_exchange: ExchangeId::Bithumb,
+            hedge_exchange: ExchangeId::Binance,
+            mode,
+            entry_bps: Bps::new(50.0),
+            exit_bps: Bps::new(5.0),
+            primary_notional: Notional::new(1_000.0),
+            hedge_notional: Notional::new(1_000.0),
+            dry_run: false,
+            primary_base_asset: name.to_string(),
+            min_entry_interval_secs: 0,
+            state_file: temp_state_file(name),
+            ..Default::default()
+        }
+    }
+
+    fn strategy(market: &ScriptedMarket, params: CrossStrategyParams) -> MockStrategy {
+        CrossBasisArbitrageStrategy::with_traders(market.spot(), market.futures(), params)
+    }
+
+    fn order(leg: Leg, side: OrderSide, symbol: &str, qty: f64, reduce_only: bool) -> OrderCall {
+        OrderCall {
+            leg,
+            side,
+            symbol: symbol.to_string(),
+            qty: Qty::new(qty),
+            reduce_only,
+        }
+    }
+
+    /// 스크립트가 끝날 때까지 run loop 실행
+    async fn run_script(strategy: &MockStrategy) {
+        let err = strategy.run_loop().await.unwrap_err();
+        assert!(err.to_string().contains(SCRIPT_EXHAUSTED), "{}", err);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_carry_open_and_close_cycle() {
+        let params = params("HARNESSA", StrategyMode::Carry);
+        let state_file = params.state_file.clone();
+        // 베이시스: 20bps(대기) → 60bps(진입) → 30bps(유지) → 4bps(청산)
+        let market = ScriptedMarket::new(&[
+            (100.0, 100.2),
+            (100.0, 100.6),
+            (100.0, 100.3),
+            (100.0, 100.04),
+        ]);
+        let strategy = strategy(&market, params);
+        let mut events = EventCapture::new(&strategy.strategy_id());
+
+        run_script(&strategy).await;
+
+        let qty = 1_000.0 / 100.6;
+        assert_eq!(
+            market.orders(),
+            vec![
+                order(Leg::Spot, OrderSide::Buy, "HARNESSAKRW", qty, false),
+                order(Leg::Futures, OrderSide::Sell, "HARNESSAUSDT", qty, false),
+                order(Leg::Futures, OrderSide::Buy, "HARNESSAUSDT", qty, true),
+                order(Leg::Spot, OrderSide::Sell, "HARNESSAKRW", qty, false),
+            ]
+        );
+
+        let state = ArbitrageState::read_from(&state_file).unwrap();
+        assert!(!state.open);
+        assert!((state.last_open_basis_bps.unwrap() - 60.0).abs() < 1e-6);
+        assert!((state.last_close_basis_bps.unwrap() - 4.0).abs() < 1e-6);
+
+        // 체결/청산 이벤트는 포지션 기록으로 저장됨 (매수/매도 거래소 방향 확인)
+        let events = events.drain();
+        let kinds: Vec<&str> = events.iter().map(|e| e.event.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "entry_signal",
+                "order_placed",
+                "filled",
+                "order_placed",
+                "closed"
+            ]
+        );
+        let StrategyEvent::Filled {
+            buy_exchange,
+            sell_exchange,
+            ..
+        } = &events[2].event
+        else {
+            panic!("expected filled event");
+        };
+        assert_eq!(
+            (buy_exchange.as_str(), sell_exchange.as_str()),
+            ("bithumb_spot", "binance_futures")
+        );
+        let StrategyEvent::Closed { buy_exchange, .. } = &events[4].event else {
+            panic!("expected closed event");
+        };
+        assert_eq!(buy_exchange, "binance_futures");
+        let _ = std::fs::remove_file(state_file);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reverse_limited_by_inventory_and_resumed_after_restart() {
+        let params = params("HARNESSB", StrategyMode::Reverse);
+        let state_file = params.state_file.clone();
+
+        // 목표 수량(약 10)보다 재고(2)가 적으면 재고만큼만 진입
+        let market = ScriptedMarket::new(&[(100.0, 99.4)]).with_spot_balance(2.0);
+        run_script(&strategy(&market, params.clone())).await;
+        assert_eq!(
+            market.orders(),
+            vec![
+                order(Leg::Spot, OrderSide::Sell, "HARNESSBKRW", 2.0, false),
+                order(Leg::Futures, OrderSide::Buy, "HARNESSBUSDT", 2.0, false),
+            ]
+        );
+        let state = ArbitrageState::read_from(&state_file).unwrap();
+        assert!(state.open);
+        assert_eq!(state.dir.as_deref(), Some("reverse"));
+
+        // 재시작하면 저장된 포지션을 이어받아 청산
+        let market = ScriptedMarket::new(&[(100.0, 99.8), (100.0, 99.97)]);
+        run_script(&strategy(&market, params)).await;
+        assert_eq!(
+            market.orders(),
+            vec![
+                order(Leg::Futures, OrderSide::Sell, "HARNESSBUSDT", 2.0, true),
+                order(Leg::Spot, OrderSide::Buy, "HARNESSBKRW", 2.0, false),
+            ]
+        );
+        assert!(!ArbitrageState::read_from(&state_file).unwrap().open);
+        let _ = std::fs::remove_file(state_file);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_entry_keeps_state_closed() {
+        // 재고가 없으면 REVERSE 진입 실패 → 주문 없음, 에러 이벤트, 상태 유지
+        let params = params("HARNESSC", StrategyMode::Auto);
+        let state_file = params.state_file.clone();
+        let market = ScriptedMarket::new(&[(100.0, 99.4), (100.0, 99.4)]);
+        let strategy = strategy(&market, params);
+        let mut events = EventCapture::new(&strategy.strategy_id());
+
+        run_script(&strategy).await;
+
+        assert!(market.orders().is_empty());
+        assert!(!ArbitrageState::read_from(&state_file).unwrap().open);
+        let errors: Vec<_> = events
+            .drain()
+            .into_iter()
+            .filter_map(|e| match &e.event {
+                StrategyEvent::Error { stage, message } => Some((stage.clone(), message.clone())),
+                _ => None,
+            })
+            .collect();
+        // 진입 간격 제한이 없으므로 틱마다 다시 시도
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, "open");
+        assert!(errors[0].1.contains("Insufficient spot inventory"));
+    }
+}
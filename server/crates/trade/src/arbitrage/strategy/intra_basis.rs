@@ -1,11 +1,44 @@
-use interface::ExchangeError;
-use serde_json;
-use tracing::{info, trace, warn};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
 
-use super::super::state::ArbitrageState;
-use super::{StrategyMode, StrategyParams};
+use chrono::{DateTime, Utc};
+use interface::{Bps, ExchangeError, ExchangeId};
+use serde_json;
+use tracing::{error, info, trace, warn};
+
+use super::super::control::operator_control;
+use super::super::fees::LegFees;
+use super::super::forced_close::watch_forced_close;
+use super::super::imbalance::ImbalanceSignal;
+use super::super::inflight::inflight_orders;
+use super::super::kill_switch::{PriceGuard, guard_iteration};
+use super::super::leverage::LeverageAdjuster;
+use super::super::liquidity::{LiquidityGate, spot_depth_usd};
+use super::super::live::{StrategyLiveState, strategy_states};
+use super::super::margin::{MarginTopUp, MarginWatch};
+use super::super::recheck::EntryRecheck;
+use super::super::scale_out::{ExitLadder, split_pair};
+use super::super::shadow::{ShadowTwin, step_shadows};
+use super::super::state::{ArbitrageState, strategy_state_path};
+use super::super::trading_status::{
+    LegStatuses, STATUS_REFRESH_INTERVAL, StatusAction, TradingStatusWatch, report_status_change,
+};
+use super::super::two_phase::{TwoPhaseError, execute_two_phase};
+use super::{StrategyMode, StrategyParams, entry_direction, exit_reached};
+use crate::allocation::{global_allocator, required_capital};
+use crate::bnb_fee::bnb_fee_manager;
+use crate::clock::{SharedClock, system_clock};
+use crate::equity::{EquityTracker, equity_tracker};
+use crate::events::{PositionAction, PositionDirection, StrategyEvent, event_bus};
+use crate::record::determine_exchanges_for_intra_basis;
 use crate::trader::binance::HedgedPair;
+use crate::trader::quote::QuoteConverter;
 use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse};
+use crate::volatility::volatility_registry;
+
+/// 시작 시 스팟/선물 WebSocket 가격을 기다리는 최대 시간 (이후 HTTP 시드)
+const PRICE_FEED_READY_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// 단일 거래소(Binance) 안에서 스팟/선물 간 베이시스(가격 격차)를 이용해
 /// 델타-뉴트럴 포지션을 자동으로 관리하는 인트라(intra) 베이시스 아비트라지 전략.
@@ -61,7 +94,8 @@ use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse};
 ///   - basis_bps = (futures_mark - spot_price) / spot_price * 10_000
 ///   - 양수(+)면 선물 프리미엄, 음수(-)면 선물 디스카운트 상태를 의미.
 /// - `size_from_notional(spot_price)`
-///   - params.notional(USDT 기준 명목가)을 spot_price 로 나누어 기준 수량을 계산하고,
+///   - params.notional(USDT 기준 명목가, vol_sizing 설정 시 변동성으로 스케일)을
+///     spot_price 로 나누어 기준 수량을 계산하고,
 ///     거래소 LOT_SIZE 규칙에 맞게 clamp 한 최종 주문 수량을 리턴.
 /// - `open_carry` / `close_carry`
 ///   - CARRY 포지션의 진입/청산을 담당.
@@ -91,12 +125,26 @@ use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse};
 pub struct IntraBasisArbitrageStrategy {
     trader: BinanceTrader,
     params: StrategyParams,
+    clock: SharedClock,
+    /// 현재 선물 레버리지 (dynamic_leverage 설정 시 루프에서 바뀜)
+    leverage: AtomicU32,
 }
 
 impl IntraBasisArbitrageStrategy {
     pub fn new(params: StrategyParams) -> Result<Self, ExchangeError> {
         let trader = BinanceTrader::new()?;
-        Ok(Self { trader, params })
+        Ok(Self {
+            trader,
+            leverage: AtomicU32::new(params.leverage),
+            params,
+            clock: system_clock(),
+        })
+    }
+
+    /// 루프 대기에 쓸 시계 (기본값은 벽시계)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
     }
 
     /// 베이시스 계산 (bps 단위)
@@ -108,6 +156,103 @@ impl IntraBasisArbitrageStrategy {
         (futures_mark - spot_price) / spot_price * 10000.0
     }
 
+    /// 자금 배분기에 등록되는 전략 인스턴스 ID
+    pub fn strategy_id(&self) -> String {
+        format!("intra_basis:{}", self.params.symbol)
+    }
+
+    /// 이 전략 인스턴스의 상태 파일 경로
+    fn state_path(&self) -> PathBuf {
+        strategy_state_path(&self.params.state_file, &self.strategy_id())
+    }
+
+    /// 현재 선물 레버리지
+    pub fn leverage(&self) -> u32 {
+        self.leverage.load(Ordering::Relaxed)
+    }
+
+    /// 전략 이벤트 발행 (기록 저장/알림/지표/감사 로그는 구독자가 처리)
+    fn publish(&self, event: StrategyEvent) {
+        event_bus().publish(
+            &self.strategy_id(),
+            "intra_basis",
+            &self.params.symbol,
+            event,
+        );
+    }
+
+    /// 포지션 체결(진입) 또는 청산 이벤트
+    fn position_event(
+        &self,
+        action: PositionAction,
+        direction: PositionDirection,
+        pair: HedgedPair,
+        basis_bps: f64,
+        spot_price: f64,
+        futures_mark: f64,
+    ) -> StrategyEvent {
+        let dir = match direction {
+            PositionDirection::Carry => "carry",
+            PositionDirection::Reverse => "reverse",
+        };
+        let (buy_exchange, sell_exchange) = determine_exchanges_for_intra_basis(
+            self.trader.exchange_name(),
+            dir,
+            action.record_label(),
+        );
+        match action {
+            PositionAction::Open => StrategyEvent::Filled {
+                direction,
+                pair,
+                basis_bps,
+                spot_price,
+                futures_mark,
+                buy_exchange,
+                sell_exchange,
+            },
+            PositionAction::Close => StrategyEvent::Closed {
+                direction,
+                pair,
+                basis_bps,
+                spot_price,
+                futures_mark,
+                buy_exchange,
+                sell_exchange,
+            },
+        }
+    }
+
+    /// 진입 전 운용 자금 예약 (스팟 quote + 선물 증거금)
+    /// 배정된 한도를 넘으면 에러를 반환해 진입을 막는다
+    fn reserve_capital(
+        &self,
+        qty: f64,
+        spot_price: f64,
+        futures_mark: f64,
+    ) -> Result<(), ExchangeError> {
+        let (spot_quote, futures_margin) =
+            required_capital(qty, spot_price, qty, futures_mark, self.leverage());
+        global_allocator()
+            .try_reserve(&self.strategy_id(), spot_quote, futures_margin)
+            .map_err(|e| ExchangeError::Other(e.to_string()))
+    }
+
+    /// 체결된 HedgedPair 기준으로 실제 자금 사용량 반영
+    fn sync_capital_usage(&self, pair: &HedgedPair, spot_price: f64, futures_mark: f64) {
+        let (spot_quote, futures_margin) = required_capital(
+            pair.spot_order_qty,
+            spot_price,
+            pair.fut_order_qty,
+            futures_mark,
+            self.leverage(),
+        );
+        if let Err(e) =
+            global_allocator().set_usage(&self.strategy_id(), spot_quote, futures_margin)
+        {
+            warn!("Failed to update capital usage: {}", e);
+        }
+    }
+
     /// 포지션 청산 시 PnL 계산 및 로깅
     fn log_position_pnl(
         &self,
@@ -115,6 +260,7 @@ impl IntraBasisArbitrageStrategy {
         spot_price: f64,
         futures_mark: f64,
         basis_bps: f64,
+        fees: &LegFees,
     ) {
         let open_basis = state.last_open_basis_bps.unwrap_or(0.0);
         let close_basis = basis_bps;
@@ -194,12 +340,243 @@ impl IntraBasisArbitrageStrategy {
             total_pnl, total_pnl_bps
         );
         info!("Basis-based PnL Estimate: {:.6} USDT", basis_pnl_usdt);
+
+        let fee_cost = fees.round_trip_cost(
+            spot_price * pair.spot_order_qty,
+            futures_mark * pair.fut_order_qty,
+        );
+        info!(
+            "Fees (round trip): {:.6} USDT, Net PnL: {:.6} USDT",
+            fee_cost,
+            total_pnl - fee_cost
+        );
+    }
+
+    /// 사다리 단계 도달 시 부분 청산: 청산분만 주문하고 잔량/단계 수를 상태에 반영
+    /// 청산분이 LOT_SIZE 미만이면 주문 없이 단계만 넘긴다
+    async fn scale_out(
+        &self,
+        state: &mut ArbitrageState,
+        ladder: &ExitLadder,
+        upto: usize,
+        basis_bps: f64,
+        spot_price: f64,
+        futures_mark: f64,
+    ) -> Result<(), ExchangeError> {
+        let Some(direction) = PositionDirection::from_state_dir(state.dir.as_deref()) else {
+            warn!("Unknown position direction: {:?}", state.dir);
+            return Ok(());
+        };
+        let ratio = ladder.close_ratio(state.scale_out_steps, upto);
+        let (closed, remaining) = split_pair(
+            &state.pair,
+            ratio,
+            |qty| {
+                self.trader
+                    .clamp_spot_quantity(self.params.spot_symbol(), qty)
+            },
+            |qty| self.trader.clamp_futures_quantity(&self.params.symbol, qty),
+        );
+        if closed.fut_order_qty <= 0.0 || closed.spot_order_qty <= 0.0 {
+            info!(
+                "Scale-out step {} below lot size (ratio {:.4}), skipping",
+                upto + 1,
+                ratio
+            );
+            state.scale_out_steps = upto + 1;
+            return state.write_to(self.state_path());
+        }
+
+        info!(
+            "Scale-out step {}/{} reached at {:.4} bps: closing {:.8} of {:.8}",
+            upto + 1,
+            ladder.levels.len(),
+            basis_bps,
+            closed.fut_order_qty,
+            state.pair.fut_order_qty
+        );
+        self.publish(StrategyEvent::OrderPlaced {
+            direction,
+            action: PositionAction::Close,
+            qty: closed.fut_order_qty,
+        });
+        let result = match direction {
+            PositionDirection::Carry => self.close_carry(closed).await,
+            PositionDirection::Reverse => self.close_reverse(closed).await,
+        };
+
+        match result {
+            Ok((futures_order, spot_order)) => {
+                let dir = match direction {
+                    PositionDirection::Carry => "carry",
+                    PositionDirection::Reverse => "reverse",
+                };
+                let (buy_exchange, sell_exchange) = determine_exchanges_for_intra_basis(
+                    self.trader.exchange_name(),
+                    dir,
+                    PositionAction::Close.record_label(),
+                );
+                self.publish(StrategyEvent::PartiallyClosed {
+                    direction,
+                    pair: closed,
+                    remaining,
+                    step: upto + 1,
+                    basis_bps,
+                    spot_price,
+                    futures_mark,
+                    buy_exchange,
+                    sell_exchange,
+                });
+
+                let actions = serde_json::json!({
+                    "futures": futures_order,
+                    "spot": spot_order,
+                });
+                state.record_partial_close(remaining, upto + 1, basis_bps, Some(actions));
+                state.write_to(self.state_path())?;
+                self.sync_capital_usage(&state.pair, spot_price, futures_mark);
+                info!(
+                    "Partial close done, remaining {:.8}",
+                    state.pair.fut_order_qty
+                );
+            }
+            Err(e) => {
+                warn!("Failed to scale out position: {}", e);
+                self.publish(StrategyEvent::Error {
+                    stage: "scale_out".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+        Ok(())
     }
 
-    /// 명목가에서 수량 계산 (스팟 기준)
+    /// 손익분기/PnL 계산용 수수료율
+    /// 스팟은 모드 방향 진입 시 쓰는 taker (auto는 maker), 선물은 taker 기준
+    async fn leg_fees(&self) -> Result<LegFees, ExchangeError> {
+        let fee = self
+            .trader
+            .get_trade_fee_for_symbol(self.params.spot_symbol())
+            .await?;
+        let spot_rate = match self.params.mode {
+            StrategyMode::Auto => fee.maker,
+            _ => fee.taker,
+        };
+        // 현물 수수료를 BNB로 내는 중이면 할인 반영
+        Ok(LegFees {
+            spot_rate,
+            futures_rate: self.trader.futures_fee().taker,
+        }
+        .with_spot_discount(bnb_fee_manager().spot_fee_discount()))
+    }
+
+    /// 호가 불균형으로 진입을 막아야 하면 사유 반환 (imbalance_threshold 미설정 시 None)
+    /// 호가 조회에 실패하면 진입을 막지 않는다
+    async fn imbalance_veto(&self, carry: bool) -> Option<String> {
+        let threshold = self.params.imbalance_threshold?;
+        let books = tokio::try_join!(
+            self.trader.get_spot_book_top(self.params.spot_symbol()),
+            self.trader.get_futures_book_top(&self.params.symbol),
+        );
+        match books {
+            Ok((spot, futures)) => {
+                ImbalanceSignal::from_books(&spot, &futures).veto(carry, threshold)
+            }
+            Err(e) => {
+                warn!("Failed to get book tops for imbalance check: {}", e);
+                None
+            }
+        }
+    }
+
+    /// 유동성이 최소값에 못 미치면 사유 반환 (liquidity_floors 미설정 시 None)
+    /// 스팟 호가창 조회에 실패하면 호가 깊이는 확인하지 않는다
+    async fn liquidity_veto(&self, gate: Option<&LiquidityGate>) -> Option<String> {
+        let gate = gate?;
+        let (perp, book) = tokio::join!(
+            gate.perp_data(ExchangeId::Binance, &self.params.symbol),
+            self.trader.get_spot_order_book(self.params.spot_symbol()),
+        );
+        let spot_depth = match book {
+            Ok(book) => spot_depth_usd(&book, gate.floors.depth_band_bps),
+            Err(e) => {
+                warn!("Failed to get spot order book for liquidity check: {}", e);
+                None
+            }
+        };
+        gate.floors.veto(perp.as_ref(), spot_depth)
+    }
+
+    /// 주문 직전 가격을 다시 읽어 베이시스를 재계산하고, 엣지가 줄었으면 취소 이벤트 반환
+    /// (entry_recheck 미설정 시 None). 가격 재조회에 실패해도 진입을 취소한다
+    async fn entry_recheck_abort(
+        &self,
+        recheck: Option<&EntryRecheck>,
+        quote: &QuoteConverter,
+        direction: PositionDirection,
+        signal_basis_bps: f64,
+        signal_at: DateTime<Utc>,
+    ) -> Option<StrategyEvent> {
+        let recheck = recheck?;
+        let (spot_price, futures_mark) = tokio::join!(
+            self.trader.get_spot_price(self.params.spot_symbol()),
+            self.trader.get_futures_mark_price(&self.params.symbol),
+        );
+        let elapsed_ms = (self.clock.now() - signal_at).num_milliseconds().max(0) as u64;
+        let min_edge_bps = recheck.min_edge_bps(self.params.entry_bps);
+        let (recheck_basis_bps, reason) = match (spot_price, futures_mark) {
+            (Ok(spot_price), Ok(futures_mark)) => {
+                let basis_bps =
+                    self.compute_basis_bps(quote.to_settlement(spot_price), futures_mark);
+                let reason =
+                    recheck.veto(direction, basis_bps, self.params.entry_bps, elapsed_ms)?;
+                (Some(basis_bps), reason)
+            }
+            (Err(e), _) | (_, Err(e)) => (None, format!("price re-read failed: {}", e)),
+        };
+        Some(StrategyEvent::EntryAborted {
+            direction,
+            signal_basis_bps,
+            recheck_basis_bps,
+            min_edge_bps,
+            elapsed_ms,
+            reason,
+        })
+    }
+
+    /// 스팟 견적 자산 → USDT 환산율 갱신 (USDT 견적이거나 아직 유효하면 조회 안 함)
+    async fn refresh_quote_rate(&self, quote: &mut QuoteConverter) -> Result<(), ExchangeError> {
+        let now = std::time::Instant::now();
+        let Some(rate_symbol) = quote.symbol_to_refresh(now) else {
+            return Ok(());
+        };
+        let rate = self.trader.get_spot_price(&rate_symbol).await?;
+        quote.update(rate, now);
+        trace!("Quote rate {}: {:.6}", rate_symbol, rate);
+        Ok(())
+    }
+
+    /// 변동성 스케일링을 적용한 명목가 (vol_sizing 미설정 시 notional 그대로)
+    pub fn effective_notional(&self) -> f64 {
+        let Some(sizing) = self.params.vol_sizing else {
+            return self.params.notional.value();
+        };
+        let estimate = volatility_registry().estimate(&self.params.symbol);
+        let scale = sizing.scale(estimate.as_ref());
+        if let Some(estimate) = estimate {
+            info!(
+                "Volatility sizing: ATR {:.2} bps, σ {:.2} bps ({} bars) → scale {:.3}",
+                estimate.atr_bps, estimate.return_std_bps, estimate.bars, scale
+            );
+        }
+        self.params.notional.value() * scale
+    }
+
+    /// 명목가에서 수량 계산 (스팟 기준, spot_price는 USDT 환산 가격)
     pub fn size_from_notional(&self, spot_price: f64) -> f64 {
-        let qty = self.params.notional / spot_price;
-        self.trader.clamp_spot_quantity(&self.params.symbol, qty)
+        let qty = self.effective_notional() / spot_price;
+        self.trader
+            .clamp_spot_quantity(self.params.spot_symbol(), qty)
     }
 
     /// Carry 포지션 오픈: 스팟 롱 + 선물 숏
@@ -209,12 +586,15 @@ impl IntraBasisArbitrageStrategy {
     ) -> Result<(OrderResponse, OrderResponse, HedgedPair), ExchangeError> {
         info!(
             "Opening CARRY position: spot BUY {} {}, futures SELL {} {}",
-            qty, self.params.symbol, qty, self.params.symbol
+            qty,
+            self.params.spot_symbol(),
+            qty,
+            self.params.symbol
         );
 
         let fee = self
             .trader
-            .get_trade_fee_for_symbol(&self.params.symbol)
+            .get_trade_fee_for_symbol(self.params.spot_symbol())
             .await?;
 
         let spot_fee_rate = match self.params.mode {
@@ -225,12 +605,17 @@ impl IntraBasisArbitrageStrategy {
         // 스팟과 선물의 수량을 각각 clamp하고, 더 작은 쪽 사용
         let pair = self
             .trader
-            .find_hedged_pair(&self.params.symbol, qty, spot_fee_rate)
+            .find_hedged_pair_for(
+                self.params.spot_symbol(),
+                &self.params.symbol,
+                qty,
+                spot_fee_rate,
+            )
             .ok_or_else(|| ExchangeError::Other("Failed to find hedged pair".into()))?;
 
         if self.params.dry_run {
             info!("DRY RUN: pair: {:?}", pair);
-            info!("DRY RUN: spot BUY {} {}", qty, self.params.symbol);
+            info!("DRY RUN: spot BUY {} {}", qty, self.params.spot_symbol());
             info!("DRY RUN: futures SELL {} {}", qty, self.params.symbol);
             return Err(ExchangeError::Other("Dry run mode".to_string()));
         }
@@ -244,25 +629,76 @@ impl IntraBasisArbitrageStrategy {
 
         // TODO: spot order qty < fut order qty 라서 항상 손해보고 있음 고쳐야함
 
-        // 스팟 매수
-        let spot_order = self
+        // 스팟 매수 + 선물 숏 (leg_order 순서, 두 번째 레그 실패 시 첫 레그 되돌림)
+        let spot_symbol = self.params.spot_symbol();
+        let symbol = self.params.symbol.as_str();
+        let spot_unwind_qty = self
             .trader
-            .place_spot_order(&self.params.symbol, "BUY", pair.spot_order_qty, false)
-            .await?;
-
-        // 선물 숏
-        let futures_order = self
-            .trader
-            .place_futures_order(&self.params.symbol, "SELL", pair.fut_order_qty, false)
-            .await?;
-
-        // TODO: 선물 실패 처리, 트랜잭션
+            .clamp_spot_quantity(spot_symbol, pair.spot_net_qty_est);
+        let (spot_order, futures_order) = execute_two_phase(
+            self.params.leg_order,
+            self.params.second_leg_retries,
+            self.clock.as_ref(),
+            pair.spot_order_qty,
+            pair.fut_order_qty,
+            |id: String, qty: f64| async move {
+                self.trader
+                    .place_spot_order_with_id(spot_symbol, "BUY", qty, &id)
+                    .await
+            },
+            |id: String, qty: f64| async move {
+                self.trader
+                    .place_futures_order_with_id(symbol, "SELL", qty, false, &id)
+                    .await
+            },
+            |id: String| async move { self.trader.find_spot_order(spot_symbol, &id).await },
+            |id: String| async move { self.trader.find_futures_order(symbol, &id).await },
+            |order_id| self.trader.cancel_spot_order(spot_symbol, order_id),
+            |order_id| self.trader.cancel_futures_order(symbol, order_id),
+            || {
+                self.trader
+                    .place_spot_order(spot_symbol, "SELL", spot_unwind_qty, false)
+            },
+            || {
+                self.trader.place_futures_order(
+                    &self.params.symbol,
+                    "BUY",
+                    pair.fut_order_qty,
+                    true,
+                )
+            },
+        )
+        .await
+        .map_err(|e| self.two_phase_failed(PositionDirection::Carry, e))?;
 
         // TODO: delta_est 어떻게 처리할 지 고민하기
 
         Ok((spot_order, futures_order, pair))
     }
 
+    /// 2단계 진입 실패 처리: 첫 레그를 되돌렸으면 LegUnwound 이벤트로 기록/알림
+    fn two_phase_failed(&self, direction: PositionDirection, e: TwoPhaseError) -> ExchangeError {
+        if let TwoPhaseError::SecondLeg {
+            first_leg,
+            attempts,
+            error,
+            unwind,
+        } = &e
+        {
+            if e.left_naked() {
+                error!("{:?} entry left {} leg open: {}", direction, first_leg, e);
+            }
+            self.publish(StrategyEvent::LegUnwound {
+                direction,
+                first_leg: *first_leg,
+                attempts: *attempts,
+                error: error.to_string(),
+                unwind_error: unwind.as_ref().err().map(|e| e.to_string()),
+            });
+        }
+        e.into()
+    }
+
     /// Carry 포지션 클로즈: 스팟 매도 + 선물 매수 (reduceOnly)
     pub async fn close_carry(
         &self,
@@ -270,7 +706,10 @@ impl IntraBasisArbitrageStrategy {
     ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
         info!(
             "Closing CARRY position: spot SELL {} {}, futures BUY {} {} (reduceOnly)",
-            pair.spot_order_qty, self.params.symbol, pair.fut_order_qty, self.params.symbol
+            pair.spot_order_qty,
+            self.params.spot_symbol(),
+            pair.fut_order_qty,
+            self.params.symbol
         );
 
         if self.params.dry_run {
@@ -280,19 +719,20 @@ impl IntraBasisArbitrageStrategy {
             );
             info!(
                 "DRY RUN: spot SELL {} {}",
-                pair.spot_order_qty, self.params.symbol
+                pair.spot_order_qty,
+                self.params.spot_symbol()
             );
             return Err(ExchangeError::Other("Dry run mode".to_string()));
         }
 
         let spot_sell_qty = self
             .trader
-            .clamp_spot_quantity(&self.params.symbol, pair.spot_net_qty_est);
+            .clamp_spot_quantity(self.params.spot_symbol(), pair.spot_net_qty_est);
 
         // 스팟 매도
         let spot_order = self
             .trader
-            .place_spot_order(&self.params.symbol, "SELL", spot_sell_qty, false)
+            .place_spot_order(self.params.spot_symbol(), "SELL", spot_sell_qty, false)
             .await?;
 
         // 선물 청산 (reduceOnly)
@@ -311,22 +751,25 @@ impl IntraBasisArbitrageStrategy {
     ) -> Result<(OrderResponse, OrderResponse, HedgedPair), ExchangeError> {
         info!(
             "Opening REVERSE position: spot SELL {} {}, futures BUY {} {}",
-            qty, self.params.symbol, qty, self.params.symbol
+            qty,
+            self.params.spot_symbol(),
+            qty,
+            self.params.symbol
         );
 
         if self.params.dry_run {
-            info!("DRY RUN: spot SELL {} {}", qty, self.params.symbol);
+            info!("DRY RUN: spot SELL {} {}", qty, self.params.spot_symbol());
             info!("DRY RUN: futures BUY {} {}", qty, self.params.symbol);
             return Err(ExchangeError::Other("Dry run mode".to_string()));
         }
 
         // 스팟 잔고 확인
-        let base_asset = BinanceTrader::base_asset_from_symbol(&self.params.symbol);
+        let base_asset = BinanceTrader::base_asset_from_symbol(self.params.spot_symbol());
         let free = self.trader.get_spot_balance(&base_asset).await?;
         let available_qty = qty.min(free);
         let use_qty = self
             .trader
-            .clamp_spot_quantity(&self.params.symbol, available_qty);
+            .clamp_spot_quantity(self.params.spot_symbol(), available_qty);
 
         if use_qty <= 0.0 {
             return Err(ExchangeError::Other(format!(
@@ -349,7 +792,7 @@ impl IntraBasisArbitrageStrategy {
         // 수수료 정보 가져오기
         let fee = self
             .trader
-            .get_trade_fee_for_symbol(&self.params.symbol)
+            .get_trade_fee_for_symbol(self.params.spot_symbol())
             .await?;
 
         let spot_fee_rate = match self.params.mode {
@@ -357,17 +800,40 @@ impl IntraBasisArbitrageStrategy {
             _ => fee.maker,
         };
 
-        // 스팟 매도
-        let spot_order = self
-            .trader
-            .place_spot_order(&self.params.symbol, "SELL", final_qty, false)
-            .await?;
-
-        // 선물 롱
-        let futures_order = self
-            .trader
-            .place_futures_order(&self.params.symbol, "BUY", final_qty, false)
-            .await?;
+        // 스팟 매도 + 선물 롱 (leg_order 순서, 두 번째 레그 실패 시 첫 레그 되돌림)
+        let spot_symbol = self.params.spot_symbol();
+        let symbol = self.params.symbol.as_str();
+        let (spot_order, futures_order) = execute_two_phase(
+            self.params.leg_order,
+            self.params.second_leg_retries,
+            self.clock.as_ref(),
+            final_qty,
+            final_qty,
+            |id: String, qty: f64| async move {
+                self.trader
+                    .place_spot_order_with_id(spot_symbol, "SELL", qty, &id)
+                    .await
+            },
+            |id: String, qty: f64| async move {
+                self.trader
+                    .place_futures_order_with_id(symbol, "BUY", qty, false, &id)
+                    .await
+            },
+            |id: String| async move { self.trader.find_spot_order(spot_symbol, &id).await },
+            |id: String| async move { self.trader.find_futures_order(symbol, &id).await },
+            |order_id| self.trader.cancel_spot_order(spot_symbol, order_id),
+            |order_id| self.trader.cancel_futures_order(symbol, order_id),
+            || {
+                self.trader
+                    .place_spot_order(spot_symbol, "BUY", final_qty, false)
+            },
+            || {
+                self.trader
+                    .place_futures_order(&self.params.symbol, "SELL", final_qty, true)
+            },
+        )
+        .await
+        .map_err(|e| self.two_phase_failed(PositionDirection::Reverse, e))?;
 
         // HedgedPair 생성
         // 스팟 매도 시: 매도 수량 * (1 - fee_rate) = 실제 받는 USDT 수량
@@ -394,7 +860,10 @@ impl IntraBasisArbitrageStrategy {
     ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
         info!(
             "Closing REVERSE position: spot BUY {} {}, futures SELL {} {} (reduceOnly)",
-            pair.spot_order_qty, self.params.symbol, pair.fut_order_qty, self.params.symbol
+            pair.spot_order_qty,
+            self.params.spot_symbol(),
+            pair.fut_order_qty,
+            self.params.symbol
         );
 
         if self.params.dry_run {
@@ -404,7 +873,8 @@ impl IntraBasisArbitrageStrategy {
             );
             info!(
                 "DRY RUN: spot BUY {} {}",
-                pair.spot_order_qty, self.params.symbol
+                pair.spot_order_qty,
+                self.params.spot_symbol()
             );
             return Err(ExchangeError::Other("Dry run mode".to_string()));
         }
@@ -418,12 +888,100 @@ impl IntraBasisArbitrageStrategy {
         // 스팟 매수
         let spot_order = self
             .trader
-            .place_spot_order(&self.params.symbol, "BUY", pair.spot_order_qty, false)
+            .place_spot_order(self.params.spot_symbol(), "BUY", pair.spot_order_qty, false)
             .await?;
 
         Ok((futures_order, spot_order))
     }
 
+    /// 스팟/선물 심볼의 현재 거래 상태 (마지막 exchangeInfo 기준)
+    fn leg_statuses(&self) -> LegStatuses {
+        LegStatuses {
+            spot: self.trader.spot_symbol_status(self.params.spot_symbol()),
+            futures: self.trader.futures_symbol_status(&self.params.symbol),
+        }
+    }
+
+    /// 동적 레버리지 재평가. 바꿔야 하면 ensure_account_setup으로 반영하고
+    /// LeverageAdjusted 이벤트로 남긴다 (반영했으면 true)
+    async fn adjust_leverage(&self, adjuster: &mut LeverageAdjuster, position_open: bool) -> bool {
+        let liquidation_distance = if position_open {
+            match self
+                .trader
+                .get_futures_position_risk(&self.params.symbol)
+                .await
+            {
+                Ok(risk) => risk.and_then(|risk| risk.liquidation_distance_bps()),
+                Err(e) => {
+                    warn!("Failed to get futures position risk: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let estimate = volatility_registry().estimate(&self.params.symbol);
+        let Some(decision) =
+            adjuster.decide(estimate.as_ref(), position_open, liquidation_distance)
+        else {
+            return false;
+        };
+
+        if let Err(e) = self
+            .trader
+            .ensure_account_setup(&self.params.symbol, decision.to, self.params.isolated)
+            .await
+        {
+            warn!(
+                "Failed to adjust leverage {}x -> {}x: {}",
+                decision.from, decision.to, e
+            );
+            return false;
+        }
+        adjuster.apply(decision.to);
+        self.leverage.store(decision.to, Ordering::Relaxed);
+        info!(
+            "Leverage adjusted {}x -> {}x: {}",
+            decision.from, decision.to, decision.reason
+        );
+        self.publish(StrategyEvent::LeverageAdjusted {
+            from: decision.from,
+            to: decision.to,
+            price_vol_bps: decision.price_vol_bps,
+            basis_vol_bps: decision.basis_vol_bps,
+            required_buffer_bps: decision.required_buffer_bps,
+            liquidation_distance_bps: decision.liquidation_distance_bps,
+            reason: decision.reason,
+        });
+        true
+    }
+
+    /// 선물 USDT 잔고가 기준 미만이면 목표 잔고까지 이체 (실패는 로그만 남기고 다음 확인 때 재시도)
+    async fn top_up_margin(&self, top_up: MarginTopUp) {
+        match self
+            .trader
+            .ensure_futures_margin(top_up.min_balance, top_up.target_balance)
+            .await
+        {
+            Ok(Some(transfer)) => info!(
+                "Futures margin topped up to {} USDT (tranId {})",
+                top_up.target_balance, transfer.tran_id
+            ),
+            Ok(None) => {}
+            Err(e) => warn!("Failed to top up futures margin: {}", e),
+        }
+    }
+
+    /// exchangeInfo 재조회 (실패하면 직전 상태 유지)
+    async fn refresh_trading_status(&self) {
+        if let Err(e) = self.trader.load_spot_exchange_info().await {
+            warn!("Failed to refresh spot exchangeInfo: {}", e);
+        }
+        if let Err(e) = self.trader.load_futures_exchange_info().await {
+            warn!("Failed to refresh futures exchangeInfo: {}", e);
+        }
+    }
+
     /// 메인 베이시스 아비트라지 루프.
     ///
     /// 이 루프는 다음과 같은 순서로 동작한다:
@@ -490,6 +1048,8 @@ impl IntraBasisArbitrageStrategy {
     ///   베이시스가 장기간 확장되는 경우 선물 측 마진 부족으로 청산 위험이 존재한다.
     /// - 수수료, 슬리피지, 펀딩 비용은 별도로 추적하지 않고, entry_bps/exit_bps 설정에
     ///   간접적으로 녹여서 사용해야 한다.
+    /// - exchangeInfo 심볼 상태가 TRADING이 아니면 진입하지 않고, 보유 중 상태가 바뀌면
+    ///   알림 후 두 레그가 모두 거래 가능해지는 대로 청산한다 (`trading_status`).
     pub async fn run_loop(&self) -> Result<(), ExchangeError> {
         // exchangeInfo 로드 (스팟 및 선물 LOT_SIZE 필터 캐싱)
         info!("Loading spot exchangeInfo...");
@@ -505,47 +1065,160 @@ impl IntraBasisArbitrageStrategy {
                 ExchangeError::Other(format!("Failed to load futures exchangeInfo: {}", e))
             })?;
 
+        // 동적 레버리지면 시작 레버리지를 범위 안으로 맞춤
+        let mut leverage_adjuster = self
+            .params
+            .dynamic_leverage
+            .map(|dynamic| LeverageAdjuster::new(dynamic, self.params.leverage));
+        if let Some(adjuster) = &leverage_adjuster {
+            self.leverage.store(adjuster.current(), Ordering::Relaxed);
+        }
+
         // 선물 설정 확인
         self.trader
-            .ensure_account_setup(
-                &self.params.symbol,
-                self.params.leverage,
-                self.params.isolated,
-            )
+            .ensure_account_setup(&self.params.symbol, self.leverage(), self.params.isolated)
             .await?;
 
+        // 수수료 기준 손익분기 베이시스 확인
+        let fees = self.leg_fees().await?;
+        info!(
+            "Fees: spot {:.4}%, futures {:.4}%, break-even {:.2} bps",
+            fees.spot_rate * 100.0,
+            fees.futures_rate * 100.0,
+            fees.break_even_bps()
+        );
+        if self.params.entry_bps - self.params.exit_bps < fees.break_even_bps() {
+            warn!(
+                "entry_bps - exit_bps ({:.2}) is below fee break-even ({:.2} bps)",
+                self.params.entry_bps - self.params.exit_bps,
+                fees.break_even_bps()
+            );
+        }
+
         // WebSocket 리스너 시작 (백그라운드에서 실시간 가격 수신)
         info!("Starting WebSocket listeners for real-time price updates...");
-        self.trader.start_websocket_listener(&self.params.symbol);
+        self.trader
+            .start_websocket_listeners(self.params.spot_symbol(), &self.params.symbol);
 
-        // WebSocket 연결이 안정화될 때까지 잠시 대기
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        // 선물 강제 청산/ADL 감지 (선물 계정 User Data Stream, dry-run은 API 키가 없을 수 있어 생략)
+        if !self.params.dry_run {
+            watch_forced_close(&self.trader, &self.strategy_id(), &self.params.symbol);
+        }
+
+        // 스팟/선물 가격을 모두 받을 때까지 대기 (제한 시간이 지나면 검증된 HTTP 가격으로 시드)
+        let ready = self
+            .trader
+            .await_prices_ready(
+                self.params.spot_symbol(),
+                &self.params.symbol,
+                PRICE_FEED_READY_TIMEOUT,
+            )
+            .await?;
+        info!(
+            "Price feed ready via {:?} after {:?}: spot {}, futures mark {}",
+            ready.source, ready.waited, ready.spot_price, ready.futures_mark_price
+        );
 
         // 상태 로드
-        let mut state = ArbitrageState::read()?;
+        let mut state = ArbitrageState::read_for_strategy(
+            &self.params.state_file,
+            &self.strategy_id(),
+            |state| state.symbol == self.params.symbol,
+        )?;
         if state.symbol != self.params.symbol {
             state = ArbitrageState::new(self.params.symbol.clone());
         }
 
         info!("Starting basis arbitrage strategy");
         info!("Symbol: {}", self.params.symbol);
+        if self.params.spot_symbol() != self.params.symbol {
+            info!("Spot Symbol: {}", self.params.spot_symbol());
+        }
         info!("Mode: {}", self.params.mode);
         info!("Entry BPS: {}", self.params.entry_bps);
         info!("Exit BPS: {}", self.params.exit_bps);
         info!("Notional: {} USDT", self.params.notional);
+        info!("Capital Budget: {} USDT", self.params.capital_budget);
+        info!("Volatility Sizing: {:?}", self.params.vol_sizing);
+        info!(
+            "Leverage: {}x (dynamic: {:?})",
+            self.leverage(),
+            self.params.dynamic_leverage
+        );
         info!(
             "Current state: open={}, dir={:?}, pair={:?}",
             state.open, state.dir, state.pair
         );
 
+        // 실시간 상태 조회용 (GET /strategy/:id/state)
+        let mut live = StrategyLiveState::new(
+            self.strategy_id(),
+            self.params.symbol.clone(),
+            self.params.mode,
+            self.params.entry_bps,
+            self.params.exit_bps,
+        );
+        live.sync_position(&state);
+        strategy_states().update(&live);
+
+        // 스팟 견적 자산이 USDT가 아니면 스팟 가격을 USDT로 환산해서 사용
+        let mut quote = QuoteConverter::for_symbol(self.params.spot_symbol());
+        self.refresh_quote_rate(&mut quote).await?;
+
+        // 자금 배분기 등록 (재시작 시 열린 포지션의 사용량 복원)
+        global_allocator().register(&self.strategy_id(), self.params.capital_budget.value());
+        if state.open {
+            let spot_price = quote.to_settlement(
+                self.trader
+                    .get_spot_price(self.params.spot_symbol())
+                    .await?,
+            );
+            let futures_mark = self
+                .trader
+                .get_futures_mark_price(&self.params.symbol)
+                .await?;
+            self.sync_capital_usage(&state.pair, spot_price, futures_mark);
+        }
+
+        // 후보 파라미터 페이퍼 트윈 (실전과 같은 시세, 주문 없음)
+        let mut shadows: Vec<ShadowTwin> = self
+            .params
+            .shadows
+            .iter()
+            .map(|shadow| {
+                info!(
+                    "Shadow '{}': entry {} bps, exit {} bps",
+                    shadow.name, shadow.entry_bps, shadow.exit_bps
+                );
+                ShadowTwin::new(
+                    &self.strategy_id(),
+                    &self.params.symbol,
+                    self.params.mode,
+                    shadow.clone(),
+                    self.params.notional.value(),
+                    fees.break_even_bps().value(),
+                )
+            })
+            .collect();
+
+        let mut price_guard = PriceGuard::new(self.params.price_guard);
+        let liquidity_gate = self.params.liquidity_floors.map(LiquidityGate::new);
+        let entry_recheck = self.params.entry_recheck;
+        let mut status_watch = TradingStatusWatch::new(STATUS_REFRESH_INTERVAL);
+        let mut margin_watch = self.params.margin_top_up.map(MarginWatch::new);
+        let mut flatten_seen = operator_control().flatten_seq();
         loop {
-            tokio::time::sleep(tokio::time::Duration::from_micros(100)).await;
+            self.clock.sleep(Duration::from_micros(100)).await;
 
-            // 가격 조회
+            // 가격 조회 (스팟은 USDT 환산 가격)
+            if let Err(e) = self.refresh_quote_rate(&mut quote).await {
+                warn!("Failed to refresh {} quote rate: {}", quote.quote(), e);
+            }
             let spot_price = self
                 .trader
-                .get_spot_price(&self.params.symbol)
+                .get_spot_price(self.params.spot_symbol())
                 .await
+                .map(|price| quote.to_settlement(price))
                 .map_err(|e| {
                     warn!("Failed to get spot price: {}", e);
                     e
@@ -561,55 +1234,126 @@ impl IntraBasisArbitrageStrategy {
                 })?;
 
             let basis_bps = self.compute_basis_bps(spot_price, futures_mark);
+            let signal_at = self.clock.now();
 
             trace!(
                 "Spot: {:.8}, Futures: {:.8}, Basis: {:.8} bps",
                 spot_price, futures_mark, basis_bps
             );
 
+            // 직전 반복의 포지션 변경까지 반영
+            live.tick(spot_price, futures_mark, basis_bps);
+            live.sync_position(&state);
+            strategy_states().update(&live);
+
+            // 가격 이상(잘못된 데이터)이면 건너뛰고, 지속되면 킬 스위치
+            if !guard_iteration(
+                &self.strategy_id(),
+                &mut price_guard,
+                spot_price,
+                futures_mark,
+            )
+            .await
+            {
+                continue;
+            }
+
+            step_shadows(&mut shadows, spot_price, futures_mark, basis_bps).await;
+
+            // 변동성/청산 거리 기준 레버리지 재평가
+            if let Some(adjuster) = leverage_adjuster.as_mut() {
+                let now = Instant::now();
+                adjuster.record_basis(now, basis_bps);
+                if adjuster.check_due(now)
+                    && self.adjust_leverage(adjuster, state.open).await
+                    && state.open
+                {
+                    self.sync_capital_usage(&state.pair, spot_price, futures_mark);
+                }
+            }
+
+            // 보유 중 선물 증거금이 모자라면 현물 쪽에서 보충
+            if let Some(watch) = margin_watch.as_mut()
+                && watch.check_due(Instant::now(), state.open)
+            {
+                self.top_up_margin(watch.config()).await;
+            }
+
+            // 심볼 거래 상태: TRADING이 아니면 진입 보류, 보유 중 바뀌면 거래 재개 즉시 청산
+            if status_watch.refresh_due(Instant::now()) {
+                self.refresh_trading_status().await;
+            }
+            let status = status_watch.observe(self.leg_statuses(), state.open);
+            if let Some(change) = &status.change {
+                report_status_change(&self.strategy_id(), &self.params.symbol, change, state.open)
+                    .await;
+            }
+            let force_close = match status.action {
+                StatusAction::Trade => false,
+                StatusAction::Flatten => true,
+                StatusAction::BlockEntry(reason) | StatusAction::Wait(reason) => {
+                    trace!("Symbol not trading, skipping orders: {}", reason);
+                    continue;
+                }
+            };
+            // 운영자 전량 청산 요청: 보유 중이면 베이시스와 무관하게 청산
+            let flatten_requested = operator_control().take_flatten(&mut flatten_seen);
+
             if state.open {
                 // 포지션이 열려있으면 청산 조건 확인
-                let should_close = match state.dir.as_deref() {
-                    Some("carry") => basis_bps <= self.params.exit_bps,
-                    Some("reverse") => basis_bps >= -self.params.exit_bps,
-                    _ => false,
-                };
+                let should_close = force_close
+                    || flatten_requested
+                    || exit_reached(
+                        state.dir.as_deref(),
+                        Bps::new(basis_bps),
+                        self.params.exit_bps,
+                    );
 
                 if should_close {
-                    info!("Exit condition met. Closing position...");
-                    let result = match state.dir.as_deref() {
-                        Some("carry") => self.close_carry(state.pair).await,
-                        Some("reverse") => self.close_reverse(state.pair).await,
-                        _ => {
-                            warn!("Unknown position direction: {:?}", state.dir);
-                            continue;
-                        }
+                    if force_close {
+                        warn!(
+                            "Symbol trading status changed while holding. Flattening position..."
+                        );
+                    } else if flatten_requested {
+                        warn!("Operator flatten requested. Closing position...");
+                    } else {
+                        info!("Exit condition met. Closing position...");
+                    }
+                    let Some(direction) = PositionDirection::from_state_dir(state.dir.as_deref())
+                    else {
+                        warn!("Unknown position direction: {:?}", state.dir);
+                        continue;
+                    };
+                    self.publish(StrategyEvent::OrderPlaced {
+                        direction,
+                        action: PositionAction::Close,
+                        qty: state.pair.fut_order_qty,
+                    });
+                    let result = match direction {
+                        PositionDirection::Carry => self.close_carry(state.pair).await,
+                        PositionDirection::Reverse => self.close_reverse(state.pair).await,
                     };
 
                     match result {
                         Ok((futures_order, spot_order)) => {
                             // 포지션 이득 계산 및 로깅
-                            self.log_position_pnl(&state, spot_price, futures_mark, basis_bps);
-
-                            // 포지션 닫기 기록 저장 (새로운 position_records 테이블)
-                            if let Some(dir) = state.dir.as_deref() {
-                                if let Some(carry_upper) = match dir {
-                                    "carry" => Some("CARRY"),
-                                    "reverse" => Some("REVERSE"),
-                                    _ => None,
-                                } {
-                                    crate::record::save_position_record(
-                                        "intra_basis",
-                                        carry_upper,
-                                        "CLOSE",
-                                        &self.params.symbol,
-                                        spot_price,
-                                        futures_mark,
-                                        self.trader.exchange_name(),
-                                    )
-                                    .await;
-                                }
-                            }
+                            self.log_position_pnl(
+                                &state,
+                                spot_price,
+                                futures_mark,
+                                basis_bps,
+                                &fees,
+                            );
+
+                            // 포지션 닫기 기록 저장/알림은 이벤트 구독자가 처리
+                            self.publish(self.position_event(
+                                PositionAction::Close,
+                                direction,
+                                state.pair,
+                                basis_bps,
+                                spot_price,
+                                futures_mark,
+                            ));
 
                             let actions = serde_json::json!({
                                 "futures": futures_order,
@@ -623,40 +1367,115 @@ impl IntraBasisArbitrageStrategy {
                                 Some(basis_bps),
                                 Some(actions),
                             );
-                            state.write()?;
+                            state.write_to(self.state_path())?;
+                            global_allocator().release_all(&self.strategy_id());
                             info!("Position closed successfully");
                         }
                         Err(e) => {
                             warn!("Failed to close position: {}", e);
+                            self.publish(StrategyEvent::Error {
+                                stage: "close".to_string(),
+                                message: e.to_string(),
+                            });
                         }
                     }
+                } else if let Some(ladder) = &self.params.exit_ladder
+                    && let Some(upto) = ladder.reached(
+                        state.dir.as_deref(),
+                        Bps::new(basis_bps),
+                        state.scale_out_steps,
+                    )
+                {
+                    // 최종 청산 전 사다리 단계 도달: 일부만 청산
+                    self.scale_out(
+                        &mut state,
+                        ladder,
+                        upto,
+                        basis_bps,
+                        spot_price,
+                        futures_mark,
+                    )
+                    .await?;
                 }
             } else {
                 // 포지션이 없으면 진입 조건 확인
-                let should_open_carry =
-                    matches!(self.params.mode, StrategyMode::Carry | StrategyMode::Auto)
-                        && basis_bps > self.params.entry_bps;
-
-                let should_open_reverse =
-                    matches!(self.params.mode, StrategyMode::Reverse | StrategyMode::Auto)
-                        && basis_bps < -self.params.entry_bps;
+                let entry =
+                    entry_direction(self.params.mode, Bps::new(basis_bps), self.params.entry_bps);
+                let should_open_carry = entry == Some(PositionDirection::Carry);
+                let should_open_reverse = entry == Some(PositionDirection::Reverse);
+
+                if let Some(reason) = breaker_block(entry, equity_tracker()) {
+                    trace!("Entry blocked by drawdown breaker: {}", reason);
+                    continue;
+                }
 
                 if should_open_carry {
+                    if let Some(reason) = self.imbalance_veto(true).await {
+                        info!("CARRY entry vetoed by book imbalance: {}", reason);
+                        continue;
+                    }
+                    if let Some(reason) = self.liquidity_veto(liquidity_gate.as_ref()).await {
+                        info!("CARRY entry vetoed by liquidity: {}", reason);
+                        continue;
+                    }
+                    let ticket = match inflight_orders().try_begin(
+                        &self.strategy_id(),
+                        &self.params.symbol,
+                        Duration::from_secs(self.params.min_entry_interval_secs),
+                    ) {
+                        Ok(ticket) => ticket,
+                        Err(blocked) => {
+                            info!("CARRY entry blocked: {}", blocked);
+                            continue;
+                        }
+                    };
                     info!("Entry condition met for CARRY. Opening position...");
+                    self.publish(StrategyEvent::EntrySignal {
+                        direction: PositionDirection::Carry,
+                        basis_bps,
+                        spot_price,
+                        futures_mark,
+                    });
                     let qty = self.size_from_notional(spot_price);
+                    if let Err(e) = self.reserve_capital(qty, spot_price, futures_mark) {
+                        warn!("CARRY entry rejected by capital allocation: {}", e);
+                        ticket.release();
+                        continue;
+                    }
+                    if let Some(aborted) = self
+                        .entry_recheck_abort(
+                            entry_recheck.as_ref(),
+                            &quote,
+                            PositionDirection::Carry,
+                            basis_bps,
+                            signal_at,
+                        )
+                        .await
+                    {
+                        if let StrategyEvent::EntryAborted { reason, .. } = &aborted {
+                            info!("CARRY entry aborted by pre-submit recheck: {}", reason);
+                        }
+                        ticket.release();
+                        global_allocator().release_all(&self.strategy_id());
+                        self.publish(aborted);
+                        continue;
+                    }
+                    self.publish(StrategyEvent::OrderPlaced {
+                        direction: PositionDirection::Carry,
+                        action: PositionAction::Open,
+                        qty,
+                    });
                     match self.open_carry(qty).await {
                         Ok((spot_order, futures_order, pair)) => {
-                            // 포지션 열기 기록 저장 (새로운 position_records 테이블)
-                            crate::record::save_position_record(
-                                "intra_basis",
-                                "CARRY",
-                                "OPEN",
-                                &self.params.symbol,
+                            // 포지션 열기 기록 저장/알림은 이벤트 구독자가 처리
+                            self.publish(self.position_event(
+                                PositionAction::Open,
+                                PositionDirection::Carry,
+                                pair,
+                                basis_bps,
                                 spot_price,
                                 futures_mark,
-                                self.trader.exchange_name(),
-                            )
-                            .await;
+                            ));
 
                             let actions = serde_json::json!({
                                 "spot": spot_order,
@@ -670,29 +1489,88 @@ impl IntraBasisArbitrageStrategy {
                                 Some(basis_bps),
                                 Some(actions),
                             );
-                            state.write()?;
+                            state.write_to(self.state_path())?;
+                            ticket.complete();
+                            self.sync_capital_usage(&state.pair, spot_price, futures_mark);
                             info!("CARRY position opened successfully");
                         }
                         Err(e) => {
+                            ticket.release();
+                            global_allocator().release_all(&self.strategy_id());
                             warn!("Failed to open CARRY position: {}", e);
+                            self.publish(StrategyEvent::Error {
+                                stage: "open".to_string(),
+                                message: e.to_string(),
+                            });
                         }
                     }
                 } else if should_open_reverse {
+                    if let Some(reason) = self.imbalance_veto(false).await {
+                        info!("REVERSE entry vetoed by book imbalance: {}", reason);
+                        continue;
+                    }
+                    if let Some(reason) = self.liquidity_veto(liquidity_gate.as_ref()).await {
+                        info!("REVERSE entry vetoed by liquidity: {}", reason);
+                        continue;
+                    }
+                    let ticket = match inflight_orders().try_begin(
+                        &self.strategy_id(),
+                        &self.params.symbol,
+                        Duration::from_secs(self.params.min_entry_interval_secs),
+                    ) {
+                        Ok(ticket) => ticket,
+                        Err(blocked) => {
+                            info!("REVERSE entry blocked: {}", blocked);
+                            continue;
+                        }
+                    };
                     info!("Entry condition met for REVERSE. Opening position...");
+                    self.publish(StrategyEvent::EntrySignal {
+                        direction: PositionDirection::Reverse,
+                        basis_bps,
+                        spot_price,
+                        futures_mark,
+                    });
                     let qty = self.size_from_notional(spot_price);
+                    if let Err(e) = self.reserve_capital(qty, spot_price, futures_mark) {
+                        warn!("REVERSE entry rejected by capital allocation: {}", e);
+                        ticket.release();
+                        continue;
+                    }
+                    if let Some(aborted) = self
+                        .entry_recheck_abort(
+                            entry_recheck.as_ref(),
+                            &quote,
+                            PositionDirection::Reverse,
+                            basis_bps,
+                            signal_at,
+                        )
+                        .await
+                    {
+                        if let StrategyEvent::EntryAborted { reason, .. } = &aborted {
+                            info!("REVERSE entry aborted by pre-submit recheck: {}", reason);
+                        }
+                        ticket.release();
+                        global_allocator().release_all(&self.strategy_id());
+                        self.publish(aborted);
+                        continue;
+                    }
+                    self.publish(StrategyEvent::OrderPlaced {
+                        direction: PositionDirection::Reverse,
+                        action: PositionAction::Open,
+                        qty,
+                    });
                     match self.open_reverse(qty).await {
                         Ok((spot_order, futures_order, pair)) => {
-                            // 포지션 열기 기록 저장 (새로운 position_records 테이블)
-                            crate::record::save_position_record(
-                                "intra_basis",
-                                "REVERSE",
-                                "OPEN",
-                                &self.params.symbol,
+                            // 포지션 열기 기록 저장/알림은 이벤트 구독자가 처리
+                            self.publish(self.position_event(
+                                PositionAction::Open,
+                                PositionDirection::Reverse,
+                                pair,
+                                basis_bps,
                                 spot_price,
                                 futures_mark,
-                                self.trader.exchange_name(),
-                            )
-                            .await;
+                            ));
 
                             let actions = serde_json::json!({
                                 "spot": spot_order,
@@ -706,11 +1584,19 @@ impl IntraBasisArbitrageStrategy {
                                 Some(basis_bps),
                                 Some(actions),
                             );
-                            state.write()?;
+                            state.write_to(self.state_path())?;
+                            ticket.complete();
+                            self.sync_capital_usage(&state.pair, spot_price, futures_mark);
                             info!("REVERSE position opened successfully");
                         }
                         Err(e) => {
+                            ticket.release();
+                            global_allocator().release_all(&self.strategy_id());
                             warn!("Failed to open REVERSE position: {}", e);
+                            self.publish(StrategyEvent::Error {
+                                stage: "open".to_string(),
+                                message: e.to_string(),
+                            });
                         }
                     }
                 }
@@ -718,3 +1604,85 @@ impl IntraBasisArbitrageStrategy {
         }
     }
 }
+
+/// 진입 신호가 있는데 드로다운 서킷 브레이커가 작동 중이면 차단 사유
+fn breaker_block(entry: Option<PositionDirection>, tracker: &EquityTracker) -> Option<String> {
+    entry.and_then(|_| tracker.entry_block())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equity::{EquityParams, EquityValuation};
+    use crate::trader::binance::{BinanceAccount, BinanceAccounts};
+    use crate::volatility::VolatilitySizing;
+    use exchanges::BinanceClient;
+
+    #[test]
+    fn test_vol_sizing_scales_entry_notional() {
+        let symbol = "VOLSIZEUSDT";
+        let params = StrategyParams::builder()
+            .symbol(symbol)
+            .notional(100.0)
+            .capital_budget(410.0)
+            .vol_sizing(Some(VolatilitySizing {
+                target_bps: 10.0,
+                min_bars: 5,
+                ..Default::default()
+            }))
+            .build()
+            .unwrap();
+        // API 키 없는 트레이더 (주문/시세 조회 없이 사이징만 확인)
+        let account = BinanceAccount {
+            label: "test".to_string(),
+            client: BinanceClient::new(),
+            email: None,
+        };
+        let strategy = IntraBasisArbitrageStrategy {
+            trader: BinanceTrader::with_accounts(BinanceAccounts::single(account)),
+            leverage: AtomicU32::new(params.leverage),
+            params,
+            clock: system_clock(),
+        };
+
+        // 변동성 추정치가 없으면 notional 그대로
+        assert_eq!(strategy.effective_notional(), 100.0);
+
+        // 1분봉 종가 100 ↔ 100.2 반복: ATR 0.2 ≈ 20 bps → 목표 10 bps의 절반 규모
+        for i in 0..12 {
+            let price = if i % 2 == 0 { 100.0 } else { 100.2 };
+            volatility_registry().record(symbol, i * 60_000, price);
+        }
+        let notional = strategy.effective_notional();
+        assert!((notional - 50.0).abs() < 0.5, "notional {}", notional);
+        // LOT_SIZE 정보가 없으면 수량은 clamp 없이 명목가 / 가격
+        assert!((strategy.size_from_notional(100.0) - notional / 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_drawdown_breaker_blocks_entry() {
+        let tracker = EquityTracker::new(EquityParams {
+            max_drawdown_pct: Some(10.0),
+            ..Default::default()
+        });
+        let valuation = |equity_usdt| EquityValuation {
+            cash_usdt: equity_usdt,
+            equity_usdt,
+            ..Default::default()
+        };
+        let now = Utc::now();
+        tracker.record(&valuation(1_000.0), now).unwrap();
+        assert!(breaker_block(Some(PositionDirection::Carry), &tracker).is_none());
+
+        // 고점 대비 15% 하락: 브레이커 작동, 진입 신호가 있을 때만 차단
+        tracker
+            .record(&valuation(850.0), now + chrono::Duration::minutes(1))
+            .unwrap();
+        assert!(breaker_block(Some(PositionDirection::Carry), &tracker).is_some());
+        assert!(breaker_block(Some(PositionDirection::Reverse), &tracker).is_some());
+        assert!(breaker_block(None, &tracker).is_none());
+
+        tracker.rearm();
+        assert!(breaker_block(Some(PositionDirection::Carry), &tracker).is_none());
+    }
+}
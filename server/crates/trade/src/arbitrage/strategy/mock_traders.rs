@@ -0,0 +1,253 @@
+//! 전략 run loop 테스트용 스크립트 목 트레이더
+//!
+//! 틱마다 미리 정해둔 (현물 가격, 선물 마크 가격)을 순서대로 돌려주고,
+//! 주문 호출을 순서대로 기록한다. 스크립트가 끝나면 현물 가격 조회가
+//! `SCRIPT_EXHAUSTED` 에러를 돌려주므로 run loop는 그 에러로 종료된다.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use interface::{ExchangeError, Price, Qty};
+
+use crate::events::{EventEnvelope, event_bus};
+use crate::trader::{FuturesExchangeTrader, OrderResponse, OrderSide, SpotExchangeTrader};
+
+/// 가격 스크립트 종료 에러 메시지
+pub const SCRIPT_EXHAUSTED: &str = "price script exhausted";
+
+/// 주문 레그
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Leg {
+    Spot,
+    Futures,
+}
+
+/// 기록된 주문 호출
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderCall {
+    pub leg: Leg,
+    pub side: OrderSide,
+    pub symbol: String,
+    pub qty: Qty,
+    pub reduce_only: bool,
+}
+
+#[derive(Debug, Default)]
+struct Script {
+    ticks: Vec<(f64, f64)>,
+    /// 다음에 돌려줄 틱
+    cursor: usize,
+    spot_balance: f64,
+    orders: Vec<OrderCall>,
+}
+
+/// 현물/선물 목 트레이더가 공유하는 시장 스크립트
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedMarket {
+    inner: Arc<Mutex<Script>>,
+}
+
+impl ScriptedMarket {
+    /// ticks: 틱마다 (현물 가격, 선물 마크 가격)
+    pub fn new(ticks: &[(f64, f64)]) -> Self {
+        let market = Self::default();
+        market.inner.lock().unwrap().ticks = ticks.to_vec();
+        market
+    }
+
+    /// 현물 잔고 (REVERSE 진입 재고)
+    pub fn with_spot_balance(self, balance: f64) -> Self {
+        self.inner.lock().unwrap().spot_balance = balance;
+        self
+    }
+
+    pub fn spot(&self) -> MockSpotTrader {
+        MockSpotTrader {
+            market: self.clone(),
+        }
+    }
+
+    pub fn futures(&self) -> MockFuturesTrader {
+        MockFuturesTrader {
+            market: self.clone(),
+        }
+    }
+
+    /// 지금까지 기록된 주문 (호출 순서)
+    pub fn orders(&self) -> Vec<OrderCall> {
+        self.inner.lock().unwrap().orders.clone()
+    }
+
+    /// 현물 가격 조회마다 다음 틱으로 이동
+    fn next_spot(&self) -> Result<f64, ExchangeError> {
+        let mut script = self.inner.lock().unwrap();
+        let price = script
+            .ticks
+            .get(script.cursor)
+            .map(|&(spot, _)| spot)
+            .ok_or_else(|| ExchangeError::Other(SCRIPT_EXHAUSTED.to_string()))?;
+        script.cursor += 1;
+        Ok(price)
+    }
+
+    /// 마지막으로 조회한 틱의 선물 가격
+    fn current_mark(&self) -> Result<f64, ExchangeError> {
+        let script = self.inner.lock().unwrap();
+        script
+            .cursor
+            .checked_sub(1)
+            .and_then(|i| script.ticks.get(i))
+            .map(|&(_, mark)| mark)
+            .ok_or_else(|| ExchangeError::Other(SCRIPT_EXHAUSTED.to_string()))
+    }
+
+    fn fill(
+        &self,
+        leg: Leg,
+        side: OrderSide,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> OrderResponse {
+        let mut script = self.inner.lock().unwrap();
+        script.orders.push(OrderCall {
+            leg,
+            side,
+            symbol: symbol.to_string(),
+            qty,
+            reduce_only,
+        });
+        if leg == Leg::Spot {
+            match side {
+                OrderSide::Buy => script.spot_balance += qty.value(),
+                OrderSide::Sell => script.spot_balance -= qty.value(),
+            }
+        }
+        OrderResponse {
+            symbol: symbol.to_string(),
+            order_id: Some(script.orders.len() as u64),
+            client_order_id: None,
+            executed_qty: Some(qty.to_string()),
+            status: Some("FILLED".to_string()),
+            extra: serde_json::json!({}),
+        }
+    }
+}
+
+pub struct MockSpotTrader {
+    market: ScriptedMarket,
+}
+
+#[async_trait]
+impl SpotExchangeTrader for MockSpotTrader {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        Ok(())
+    }
+
+    async fn get_spot_price(&self, _symbol: &str) -> Result<Price, ExchangeError> {
+        self.market.next_spot().map(Price::new)
+    }
+
+    fn clamp_spot_quantity(&self, _symbol: &str, qty: Qty) -> Qty {
+        qty
+    }
+
+    async fn buy_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        Ok(self
+            .market
+            .fill(Leg::Spot, OrderSide::Buy, symbol, qty, false))
+    }
+
+    async fn sell_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        Ok(self
+            .market
+            .fill(Leg::Spot, OrderSide::Sell, symbol, qty, false))
+    }
+
+    async fn get_spot_balance(&self, _asset: &str) -> Result<Qty, ExchangeError> {
+        Ok(Qty::new(self.market.inner.lock().unwrap().spot_balance))
+    }
+}
+
+pub struct MockFuturesTrader {
+    market: ScriptedMarket,
+}
+
+#[async_trait]
+impl FuturesExchangeTrader for MockFuturesTrader {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        Ok(())
+    }
+
+    async fn ensure_account_setup(
+        &self,
+        _symbol: &str,
+        _leverage: u32,
+        _isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        Ok(())
+    }
+
+    async fn get_mark_price(&self, _symbol: &str) -> Result<Price, ExchangeError> {
+        self.market.current_mark().map(Price::new)
+    }
+
+    fn clamp_futures_quantity(&self, _symbol: &str, qty: Qty) -> Qty {
+        qty
+    }
+
+    async fn buy_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        Ok(self
+            .market
+            .fill(Leg::Futures, OrderSide::Buy, symbol, qty, reduce_only))
+    }
+
+    async fn sell_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        Ok(self
+            .market
+            .fill(Leg::Futures, OrderSide::Sell, symbol, qty, reduce_only))
+    }
+}
+
+/// 전략 하나가 발행한 이벤트 수집기 (run loop 실행 전에 만들어야 함)
+pub struct EventCapture {
+    strategy_id: String,
+    rx: tokio::sync::broadcast::Receiver<Arc<EventEnvelope>>,
+}
+
+impl EventCapture {
+    pub fn new(strategy_id: &str) -> Self {
+        Self {
+            strategy_id: strategy_id.to_string(),
+            rx: event_bus().receiver(),
+        }
+    }
+
+    /// 지금까지 받은 이벤트 (다른 전략의 이벤트는 제외)
+    pub fn drain(&mut self) -> Vec<Arc<EventEnvelope>> {
+        let mut events = Vec::new();
+        while let Ok(envelope) = self.rx.try_recv() {
+            if envelope.strategy_id == self.strategy_id {
+                events.push(envelope);
+            }
+        }
+        events
+    }
+}
+
+/// 테스트별 임시 상태 파일 경로 (이전 실행 파일은 삭제)
+pub fn temp_state_file(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("arb_state_{}_{}.json", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path.to_string_lossy().into_owned()
+}
@@ -0,0 +1,535 @@
+//! 두 현물 거래소 간 가격 차이를 이용하는 스프레드 전략 (선물 헤지 없음).
+//!
+//! 같은 페어를 두 거래소(예: Binance / Bithumb USDT 마켓)에서 감시하다가 수수료를 뺀 스프레드가
+//! 임계값을 넘으면 싼 거래소에서 사고 비싼 거래소에서 보유 재고를 판다. 양쪽 매매 수량이 같으므로
+//! 전체 베이스 자산 보유량은 그대로이고 재고만 비싼 거래소에서 싼 거래소로 옮겨 간다.
+//! 한쪽 재고가 바닥나면 그 방향으로는 더 팔 수 없으므로 거래소별 최소 재고 비율을 지킨다.
+
+use std::fmt;
+use std::time::Duration;
+
+use tracing::{info, trace, warn};
+
+use crate::clock::{SharedClock, system_clock};
+use crate::events::{StrategyEvent, event_bus};
+use crate::trader::{OrderResponse, SpotExchangeTrader, spot_trader_for};
+use interface::{Bps, ExchangeError, ExchangeId, Price, Qty};
+
+use super::super::inflight::inflight_orders;
+use super::SpotSpreadParams;
+
+/// 거래소 한 곳의 재고 (베이스 수량, A 호가 통화로 환산한 호가 잔고)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VenueInventory {
+    pub base: f64,
+    pub quote: f64,
+}
+
+/// 두 거래소 중 어느 쪽인지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadVenue {
+    A,
+    B,
+}
+
+impl SpreadVenue {
+    fn other(self) -> Self {
+        match self {
+            Self::A => Self::B,
+            Self::B => Self::A,
+        }
+    }
+}
+
+/// 이번 주기에 실행할 매매
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpreadTrade {
+    /// 싼 거래소 (매수)
+    pub buy: SpreadVenue,
+    /// 수수료 차감 전 수량 (거래소별 LOT_SIZE 적용 전)
+    pub qty: f64,
+    /// A 호가 통화 기준 매수/매도 가격
+    pub buy_price: f64,
+    pub sell_price: f64,
+    /// 양쪽 수수료를 뺀 스프레드
+    pub net_spread: Bps,
+}
+
+impl SpreadTrade {
+    pub fn sell(&self) -> SpreadVenue {
+        self.buy.other()
+    }
+}
+
+/// 이번 주기 매매를 건너뛰는 이유
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpreadSkip {
+    /// 스프레드가 수수료 차감 후 임계값 미만
+    BelowThreshold,
+    /// 매도 거래소 베이스 재고가 최소 비율에 닿음
+    InventoryFloor { venue: SpreadVenue, ratio: f64 },
+    /// 매수 거래소 호가 잔고 부족
+    QuoteShort { venue: SpreadVenue },
+}
+
+impl fmt::Display for SpreadSkip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BelowThreshold => write!(f, "spread below threshold"),
+            Self::InventoryFloor { venue, ratio } => write!(
+                f,
+                "venue {:?} base inventory at floor ({:.1}% of total)",
+                venue,
+                ratio * 100.0
+            ),
+            Self::QuoteShort { venue } => write!(f, "venue {:?} quote balance too low", venue),
+        }
+    }
+}
+
+/// 수수료를 뺀 스프레드 (`buy_price`에 사서 `sell_price`에 팔 때)
+pub fn net_spread(buy_price: f64, sell_price: f64, buy_fee: f64, sell_fee: f64) -> Bps {
+    Bps::from_fraction((sell_price - buy_price) / buy_price - buy_fee - sell_fee)
+}
+
+/// 수수료 차감 후 스프레드가 더 큰 방향 (매수 거래소, 순 스프레드)
+pub fn best_spread(params: &SpotSpreadParams, price_a: f64, price_b: f64) -> (SpreadVenue, Bps) {
+    let a_to_b = net_spread(price_a, price_b, params.fee_a, params.fee_b);
+    let b_to_a = net_spread(price_b, price_a, params.fee_b, params.fee_a);
+    if a_to_b >= b_to_a {
+        (SpreadVenue::A, a_to_b)
+    } else {
+        (SpreadVenue::B, b_to_a)
+    }
+}
+
+/// 두 거래소 가격(A 호가 통화 기준)과 재고로 이번 주기 매매 결정
+///
+/// 수수료 차감 후 스프레드가 더 큰 방향만 보고, 수량은 명목가 기준 수량에서
+/// 매도 거래소가 전체 베이스 재고의 `min_inventory_ratio` 이상을 남기는 범위와
+/// 매수 거래소 호가 잔고(수수료 포함)로 줄인다.
+pub fn plan_spread_trade(
+    params: &SpotSpreadParams,
+    price_a: f64,
+    price_b: f64,
+    inventory_a: VenueInventory,
+    inventory_b: VenueInventory,
+) -> Result<SpreadTrade, SpreadSkip> {
+    let (buy, spread) = best_spread(params, price_a, price_b);
+    let (buy_price, sell_price) = match buy {
+        SpreadVenue::A => (price_a, price_b),
+        SpreadVenue::B => (price_b, price_a),
+    };
+    if spread < params.entry_bps {
+        return Err(SpreadSkip::BelowThreshold);
+    }
+
+    let (buyer, seller, buy_fee) = match buy {
+        SpreadVenue::A => (inventory_a, inventory_b, params.fee_a),
+        SpreadVenue::B => (inventory_b, inventory_a, params.fee_b),
+    };
+    let total_base = inventory_a.base + inventory_b.base;
+    let sellable = seller.base - params.min_inventory_ratio * total_base;
+    if sellable <= 0.0 {
+        let ratio = if total_base > 0.0 {
+            seller.base / total_base
+        } else {
+            0.0
+        };
+        return Err(SpreadSkip::InventoryFloor {
+            venue: buy.other(),
+            ratio,
+        });
+    }
+    let affordable = buyer.quote / (buy_price * (1.0 + buy_fee));
+    if affordable <= 0.0 {
+        return Err(SpreadSkip::QuoteShort { venue: buy });
+    }
+
+    let qty = (params.notional.value() / buy_price)
+        .min(sellable)
+        .min(affordable);
+    Ok(SpreadTrade {
+        buy,
+        qty,
+        buy_price,
+        sell_price,
+        net_spread: spread,
+    })
+}
+
+/// 두 현물 거래소 간 스프레드 전략.
+///
+/// - 1초 주기로 양쪽 현물 가격을 조회하고 B 가격은 `fx_b`로 A 호가 통화로 환산
+/// - 수수료 차감 후 스프레드가 `entry_bps` 이상이면 양쪽 잔고를 조회해
+///   싼 거래소 BUY + 비싼 거래소 SELL을 동시에 낸다
+/// - 포지션을 열고 닫는 개념이 없으므로 상태 파일을 쓰지 않는다.
+///   재고 쏠림은 `min_inventory_ratio`로 막고, 바닥에 닿으면 한 번 경고를 남긴다
+///   (재고 재배분은 수동 이체)
+pub struct SpotSpreadStrategy<A = Box<dyn SpotExchangeTrader>, B = Box<dyn SpotExchangeTrader>>
+where
+    A: SpotExchangeTrader,
+    B: SpotExchangeTrader,
+{
+    trader_a: A,
+    trader_b: B,
+    params: SpotSpreadParams,
+    clock: SharedClock,
+}
+
+impl SpotSpreadStrategy {
+    /// 설정의 두 거래소로 spot 트레이더 생성
+    pub fn new(params: SpotSpreadParams) -> Result<Self, ExchangeError> {
+        let trader_a = spot_trader_for(params.venue_a)?;
+        let trader_b = spot_trader_for(params.venue_b)?;
+        Ok(Self::with_traders(trader_a, trader_b, params))
+    }
+}
+
+impl<A, B> SpotSpreadStrategy<A, B>
+where
+    A: SpotExchangeTrader,
+    B: SpotExchangeTrader,
+{
+    pub fn with_traders(trader_a: A, trader_b: B, params: SpotSpreadParams) -> Self {
+        Self {
+            trader_a,
+            trader_b,
+            params,
+            clock: system_clock(),
+        }
+    }
+
+    /// 루프 대기에 쓸 시계 (기본값은 벽시계)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn params(&self) -> &SpotSpreadParams {
+        &self.params
+    }
+
+    /// 이벤트 버스에 쓰는 전략 인스턴스 ID
+    pub fn strategy_id(&self) -> String {
+        format!(
+            "spot_spread:{}:{}:{}",
+            self.params.base_asset,
+            venue_name(self.params.venue_a),
+            venue_name(self.params.venue_b)
+        )
+    }
+
+    fn publish(&self, event: StrategyEvent) {
+        event_bus().publish(
+            &self.strategy_id(),
+            "spot_spread",
+            &self.params.symbol_a,
+            event,
+        );
+    }
+
+    fn venue(&self, venue: SpreadVenue) -> (ExchangeId, &str) {
+        match venue {
+            SpreadVenue::A => (self.params.venue_a, &self.params.symbol_a),
+            SpreadVenue::B => (self.params.venue_b, &self.params.symbol_b),
+        }
+    }
+
+    /// 양쪽 LOT_SIZE를 만족하는 수량
+    fn clamp_quantity(&self, qty: Qty) -> Qty {
+        let qty_a = self
+            .trader_a
+            .clamp_spot_quantity(&self.params.symbol_a, qty);
+        let qty_b = self
+            .trader_b
+            .clamp_spot_quantity(&self.params.symbol_b, qty);
+        qty_a.min(qty_b)
+    }
+
+    /// 양쪽 베이스/호가 잔고 (B 호가 잔고는 A 통화로 환산)
+    async fn inventories(&self) -> Result<(VenueInventory, VenueInventory), ExchangeError> {
+        let (base_a, quote_a, base_b, quote_b) = tokio::try_join!(
+            self.trader_a.get_spot_balance(&self.params.base_asset),
+            self.trader_a.get_spot_balance(&self.params.quote_asset),
+            self.trader_b.get_spot_balance(&self.params.base_asset),
+            self.trader_b.get_spot_balance(&self.params.quote_asset),
+        )?;
+        Ok((
+            VenueInventory {
+                base: base_a.value(),
+                quote: quote_a.value(),
+            },
+            VenueInventory {
+                base: base_b.value(),
+                quote: quote_b.value() * self.params.fx_b,
+            },
+        ))
+    }
+
+    /// 스프레드 전략 메인 루프 (1초 주기)
+    ///
+    /// 가격 조회 실패는 에러로 전파해 루프를 종료하고,
+    /// 잔고 조회/주문 실패는 에러 이벤트를 발행한 뒤 다음 주기에 다시 시도한다.
+    pub async fn run_loop(&self) -> Result<(), ExchangeError> {
+        self.trader_a.ensure_exchange_info().await?;
+        self.trader_b.ensure_exchange_info().await?;
+
+        info!("Starting spot spread strategy");
+        info!(
+            "A: {:?} {}, B: {:?} {} (fx {})",
+            self.params.venue_a,
+            self.params.symbol_a,
+            self.params.venue_b,
+            self.params.symbol_b,
+            self.params.fx_b
+        );
+        info!(
+            "Entry: {} bps net of fees ({:.2} / {:.2} bps), Notional: {}, Min inventory ratio: {}",
+            self.params.entry_bps,
+            self.params.fee_a * 10_000.0,
+            self.params.fee_b * 10_000.0,
+            self.params.notional,
+            self.params.min_inventory_ratio
+        );
+
+        let mut floor_warned = false;
+        loop {
+            self.clock.sleep(Duration::from_secs(1)).await;
+
+            let (price_a, price_b) = tokio::try_join!(
+                self.trader_a.get_spot_price(&self.params.symbol_a),
+                self.trader_b.get_spot_price(&self.params.symbol_b),
+            )
+            .inspect_err(|e| warn!("Failed to get spot prices: {}", e))?;
+            let price_a = price_a.value();
+            let price_b = price_b.value() * self.params.fx_b;
+            trace!(
+                "A: {}, B: {} (A currency), spread {:.2} bps",
+                price_a,
+                price_b,
+                Bps::basis(Price::new(price_a), Price::new(price_b)).value()
+            );
+
+            // 임계값 미만이면 잔고 조회 없이 넘어감
+            if best_spread(&self.params, price_a, price_b).1 < self.params.entry_bps {
+                continue;
+            }
+
+            let (inventory_a, inventory_b) = match self.inventories().await {
+                Ok(inventories) => inventories,
+                Err(e) => {
+                    warn!("Failed to get spot balances: {}", e);
+                    self.publish(StrategyEvent::Error {
+                        stage: "balance".to_string(),
+                        message: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let trade = match plan_spread_trade(
+                &self.params,
+                price_a,
+                price_b,
+                inventory_a,
+                inventory_b,
+            ) {
+                Ok(trade) => {
+                    floor_warned = false;
+                    trade
+                }
+                Err(skip) => {
+                    if !floor_warned {
+                        warn!(
+                            "Spread opportunity skipped: {} (A base {:.8}, B base {:.8}). Rebalance inventory",
+                            skip, inventory_a.base, inventory_b.base
+                        );
+                        floor_warned = true;
+                    }
+                    continue;
+                }
+            };
+            self.execute(trade).await;
+        }
+    }
+
+    /// 싼 거래소 매수 + 비싼 거래소 매도를 동시에 제출
+    async fn execute(&self, trade: SpreadTrade) {
+        let (buy_exchange, buy_symbol) = self.venue(trade.buy);
+        let (sell_exchange, sell_symbol) = self.venue(trade.sell());
+
+        let ticket = match inflight_orders().try_begin(
+            &self.strategy_id(),
+            &self.params.symbol_a,
+            Duration::from_secs(self.params.min_entry_interval_secs),
+        ) {
+            Ok(ticket) => ticket,
+            Err(blocked) => {
+                info!("Spot spread trade blocked: {}", blocked);
+                return;
+            }
+        };
+
+        let qty = self.clamp_quantity(Qty::new(trade.qty));
+        if qty.is_zero() {
+            warn!("Quantity too small after clamping. Increase notional or inventory.");
+            ticket.release();
+            return;
+        }
+        info!(
+            "Spread {:.2} bps net: BUY {} {:?} {} @ {}, SELL {:?} {} @ {}",
+            trade.net_spread.value(),
+            qty,
+            buy_exchange,
+            buy_symbol,
+            trade.buy_price,
+            sell_exchange,
+            sell_symbol,
+            trade.sell_price
+        );
+
+        match self.place_orders(trade, qty).await {
+            Ok((buy_order, sell_order)) => {
+                ticket.complete();
+                info!(
+                    "Spot spread filled: buy {:?}, sell {:?}",
+                    buy_order.order_id, sell_order.order_id
+                );
+                self.publish(StrategyEvent::SpreadTraded {
+                    buy_exchange: venue_name(buy_exchange),
+                    sell_exchange: venue_name(sell_exchange),
+                    qty: qty.value(),
+                    buy_price: trade.buy_price,
+                    sell_price: trade.sell_price,
+                    net_spread_bps: trade.net_spread.value(),
+                });
+            }
+            Err(e) => {
+                ticket.release();
+                warn!("Failed to execute spot spread: {}", e);
+                self.publish(StrategyEvent::Error {
+                    stage: "spread".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    /// 양쪽 주문 동시 제출. 한쪽만 체결되면 재고가 한 방향으로 쏠린 채 남으므로 에러에 그 사실을 담는다
+    async fn place_orders(
+        &self,
+        trade: SpreadTrade,
+        qty: Qty,
+    ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+        let (_, buy_symbol) = self.venue(trade.buy);
+        let (_, sell_symbol) = self.venue(trade.sell());
+        if self.params.dry_run {
+            info!("DRY RUN: spot BUY {} {} ({:?})", qty, buy_symbol, trade.buy);
+            info!(
+                "DRY RUN: spot SELL {} {} ({:?})",
+                qty,
+                sell_symbol,
+                trade.sell()
+            );
+            return Err(ExchangeError::Other("Dry run mode".to_string()));
+        }
+
+        let (buy, sell) = match trade.buy {
+            SpreadVenue::A => tokio::join!(
+                self.trader_a.buy_spot(buy_symbol, qty),
+                self.trader_b.sell_spot(sell_symbol, qty)
+            ),
+            SpreadVenue::B => tokio::join!(
+                self.trader_b.buy_spot(buy_symbol, qty),
+                self.trader_a.sell_spot(sell_symbol, qty)
+            ),
+        };
+        match (buy, sell) {
+            (Ok(buy), Ok(sell)) => Ok((buy, sell)),
+            (Ok(_), Err(e)) => Err(ExchangeError::Other(format!(
+                "sell leg failed after buy filled ({} {}): {}",
+                qty, sell_symbol, e
+            ))),
+            (Err(e), Ok(_)) => Err(ExchangeError::Other(format!(
+                "buy leg failed after sell filled ({} {}): {}",
+                qty, buy_symbol, e
+            ))),
+            (Err(buy), Err(sell)) => Err(ExchangeError::Other(format!(
+                "both legs failed: buy {}, sell {}",
+                buy, sell
+            ))),
+        }
+    }
+}
+
+/// 이벤트/기록에 쓰는 거래소 이름 (예: "binance_spot")
+fn venue_name(exchange: ExchangeId) -> String {
+    format!("{:?}_spot", exchange).to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Notional;
+
+    fn params() -> SpotSpreadParams {
+        SpotSpreadParams {
+            fee_a: 0.001,
+            fee_b: 0.001,
+            entry_bps: Bps::new(10.0),
+            notional: Notional::new(1_000.0),
+            min_inventory_ratio: 0.2,
+            ..Default::default()
+        }
+    }
+
+    fn inventory(base: f64, quote: f64) -> VenueInventory {
+        VenueInventory { base, quote }
+    }
+
+    #[test]
+    fn test_plan_spread_trade() {
+        let params = params();
+        let even = inventory(50.0, 10_000.0);
+        let a = inventory(10.0, 10_000.0);
+
+        // B가 0.4% 비쌈: 수수료 0.2% 차감 후 20 bps → A 매수, B 매도
+        let trade = plan_spread_trade(&params, 100.0, 100.4, even, even).unwrap();
+        assert_eq!(trade.buy, SpreadVenue::A);
+        assert_eq!(trade.sell(), SpreadVenue::B);
+        assert!((trade.net_spread.value() - 20.0).abs() < 1e-6);
+        assert!((trade.qty - 10.0).abs() < 1e-9);
+
+        // 반대 방향
+        let trade = plan_spread_trade(&params, 100.5, 100.0, even, even).unwrap();
+        assert_eq!(trade.buy, SpreadVenue::B);
+
+        // 수수료 차감 후 임계값 미만
+        assert_eq!(
+            plan_spread_trade(&params, 100.0, 100.25, even, even),
+            Err(SpreadSkip::BelowThreshold)
+        );
+
+        // 매도 거래소(B)는 전체 15개 중 20%인 3개를 남김
+        let trade = plan_spread_trade(&params, 100.0, 100.5, a, inventory(5.0, 0.0)).unwrap();
+        assert!((trade.qty - 2.0).abs() < 1e-9);
+        assert!(matches!(
+            plan_spread_trade(&params, 100.0, 100.5, a, inventory(2.0, 0.0)),
+            Err(SpreadSkip::InventoryFloor {
+                venue: SpreadVenue::B,
+                ..
+            })
+        ));
+
+        // 매수 거래소(A) 호가 잔고로 살 수 있는 만큼만
+        let trade = plan_spread_trade(&params, 100.0, 100.5, inventory(10.0, 100.1), even).unwrap();
+        assert!((trade.qty - 1.0).abs() < 1e-9);
+        assert_eq!(
+            plan_spread_trade(&params, 100.0, 100.5, inventory(10.0, 0.0), even),
+            Err(SpreadSkip::QuoteShort {
+                venue: SpreadVenue::A
+            })
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! 심볼 거래 상태 감시 (거래 중단/상장 폐지)
+//!
+//! Binance exchangeInfo의 심볼 상태(현물 TRADING/BREAK/HALT, 선물 TRADING/SETTLING/CLOSE 등)를
+//! 주기적으로 다시 읽어 TRADING이 아닌 레그가 있으면 진입하지 않는다.
+//! 포지션 보유 중 어느 레그든 상태가 바뀌면 한쪽 레그만 묶여 헤지가 풀릴 수 있으므로 즉시 알림을
+//! 보내고, 두 레그가 모두 거래 가능해지는 대로 베이시스와 무관하게 청산한다. 멈춘 레그가 있는 동안
+//! 청산 주문을 내면 반대 레그만 체결되어 오히려 노출이 생기므로 그동안은 주문하지 않고 기다린다.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use tracing::{error, warn};
+
+use crate::notification::{AlertLevel, notification_center};
+use crate::trader::binance::SymbolStatus;
+
+/// 심볼 거래 상태 변경 알림 종류
+pub const SYMBOL_STATUS_ALERT: &str = "symbol_status";
+
+/// exchangeInfo 재조회 간격
+pub const STATUS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 스팟/선물 레그의 거래 상태 (exchangeInfo에 없으면 None)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegStatuses {
+    pub spot: Option<SymbolStatus>,
+    pub futures: Option<SymbolStatus>,
+}
+
+impl LegStatuses {
+    /// 두 레그 모두 TRADING
+    pub fn trading() -> Self {
+        Self {
+            spot: Some(SymbolStatus::new("TRADING")),
+            futures: Some(SymbolStatus::new("TRADING")),
+        }
+    }
+
+    /// 주문할 수 없는 레그가 있으면 그 사유 (exchangeInfo에서 사라진 심볼은 상장 폐지로 봄)
+    pub fn halt_reason(&self) -> Option<String> {
+        let halted: Vec<String> = [("spot", &self.spot), ("futures", &self.futures)]
+            .into_iter()
+            .filter(|(_, status)| !status.as_ref().is_some_and(SymbolStatus::is_trading))
+            .map(|(leg, status)| format!("{} {}", leg, describe(status)))
+            .collect();
+        (!halted.is_empty()).then(|| halted.join(", "))
+    }
+}
+
+impl fmt::Display for LegStatuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "spot {}, futures {}",
+            describe(&self.spot),
+            describe(&self.futures)
+        )
+    }
+}
+
+fn describe(status: &Option<SymbolStatus>) -> &str {
+    status.as_ref().map_or("DELISTED", SymbolStatus::as_str)
+}
+
+/// 이번 반복에서 할 일
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusAction {
+    /// 평소대로 진입/청산 판단
+    Trade,
+    /// 포지션 없음, 거래할 수 없는 레그가 있어 진입 보류
+    BlockEntry(String),
+    /// 포지션 보유 중 거래할 수 없는 레그가 있어 청산도 보류
+    Wait(String),
+    /// 보유 중 상태가 바뀐 뒤 두 레그가 거래 가능해짐, 즉시 청산
+    Flatten,
+}
+
+/// 상태 관찰 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusCheck {
+    pub action: StatusAction,
+    /// 직전 관찰 대비 상태 변경 ("이전 → 현재")
+    pub change: Option<String>,
+}
+
+/// 전략 루프별 거래 상태 감시기
+#[derive(Debug)]
+pub struct TradingStatusWatch {
+    refresh_interval: Duration,
+    last_refresh: Instant,
+    last: LegStatuses,
+    flatten_pending: bool,
+}
+
+impl TradingStatusWatch {
+    /// 시작 직전에 exchangeInfo를 읽었다고 보고 다음 재조회는 `refresh_interval` 뒤
+    pub fn new(refresh_interval: Duration) -> Self {
+        Self {
+            refresh_interval,
+            last_refresh: Instant::now(),
+            last: LegStatuses::trading(),
+            flatten_pending: false,
+        }
+    }
+
+    /// exchangeInfo 재조회 시점이면 true를 반환하고 다음 시점으로 넘김
+    pub fn refresh_due(&mut self, now: Instant) -> bool {
+        if now.duration_since(self.last_refresh) < self.refresh_interval {
+            return false;
+        }
+        self.last_refresh = now;
+        true
+    }
+
+    /// 현재 상태를 반영하고 이번 반복의 행동 결정
+    /// 시작 시점 기준은 두 레그 모두 TRADING이므로 이미 멈춘 심볼로 시작해도 변경으로 잡힌다
+    pub fn observe(&mut self, statuses: LegStatuses, holding: bool) -> StatusCheck {
+        let change = (statuses != self.last).then(|| format!("{} → {}", self.last, statuses));
+        if !holding {
+            self.flatten_pending = false;
+        } else if change.is_some() {
+            self.flatten_pending = true;
+        }
+
+        let action = match (statuses.halt_reason(), holding) {
+            (Some(reason), false) => StatusAction::BlockEntry(reason),
+            (Some(reason), true) => StatusAction::Wait(reason),
+            (None, true) if self.flatten_pending => StatusAction::Flatten,
+            (None, _) => StatusAction::Trade,
+        };
+        self.last = statuses;
+        StatusCheck { action, change }
+    }
+}
+
+/// 상태 변경 로그 및 알림 (보유 중이면 Critical 알림)
+pub async fn report_status_change(strategy_id: &str, symbol: &str, change: &str, holding: bool) {
+    if !holding {
+        warn!("{} {} 거래 상태 변경: {}", strategy_id, symbol, change);
+        return;
+    }
+    error!(
+        "{} {} 보유 중 거래 상태 변경, 청산 대기: {}",
+        strategy_id, symbol, change
+    );
+    notification_center()
+        .notify(
+            SYMBOL_STATUS_ALERT,
+            AlertLevel::Critical,
+            format!("{} {} 거래 상태 변경", strategy_id, symbol),
+            format!(
+                "{} (두 레그가 모두 거래 가능해지면 즉시 청산, 그전까지 헤지 노출 확인 필요)",
+                change
+            ),
+            serde_json::json!({
+                "strategy_id": strategy_id,
+                "symbol": symbol,
+                "change": change,
+            }),
+        )
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statuses(spot: Option<&str>, futures: Option<&str>) -> LegStatuses {
+        LegStatuses {
+            spot: spot.map(SymbolStatus::new),
+            futures: futures.map(SymbolStatus::new),
+        }
+    }
+
+    #[test]
+    fn test_halt_blocks_entry_and_flattens_held_position() {
+        let mut watch = TradingStatusWatch::new(STATUS_REFRESH_INTERVAL);
+        let trading = LegStatuses::trading();
+        let halted = statuses(Some("BREAK"), Some("TRADING"));
+
+        // 포지션 없음: 진입만 보류하고 청산 대기는 남기지 않음
+        let check = watch.observe(halted.clone(), false);
+        assert_eq!(
+            check.action,
+            StatusAction::BlockEntry("spot BREAK".to_string())
+        );
+        assert!(check.change.is_some());
+        assert_eq!(
+            watch.observe(trading.clone(), false).action,
+            StatusAction::Trade
+        );
+
+        // 보유 중 현물 중단: 알림 후 멈춘 동안 대기, 재개되면 청산
+        let check = watch.observe(halted.clone(), true);
+        assert_eq!(
+            check.change.as_deref(),
+            Some("spot TRADING, futures TRADING → spot BREAK, futures TRADING")
+        );
+        assert!(matches!(check.action, StatusAction::Wait(_)));
+        assert_eq!(watch.observe(halted, true).change, None);
+        assert_eq!(
+            watch.observe(trading.clone(), true).action,
+            StatusAction::Flatten
+        );
+        assert_eq!(
+            watch.observe(trading.clone(), true).action,
+            StatusAction::Flatten
+        );
+        assert_eq!(
+            watch.observe(trading.clone(), false).action,
+            StatusAction::Trade
+        );
+        assert_eq!(watch.observe(trading, true).action, StatusAction::Trade);
+
+        // exchangeInfo에서 사라진 선물은 상장 폐지
+        assert_eq!(
+            statuses(Some("TRADING"), None).halt_reason().as_deref(),
+            Some("futures DELISTED")
+        );
+    }
+}
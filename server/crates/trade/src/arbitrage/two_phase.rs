@@ -0,0 +1,722 @@
+//! 2단계 진입: 한 레그를 먼저 체결하고 나머지 레그를 붙인다
+//!
+//! 두 번째 레그가 재시도 후에도 실패하면 첫 레그를 즉시 되돌려(반대 주문) 한쪽만 열린
+//! 포지션(헤지 없는 현물 롱 등)이 남지 않게 한다. 되돌림 결과는 호출 쪽에서
+//! `StrategyEvent::LegUnwound`로 발행해 감사 로그/알림에 남긴다.
+//!
+//! 두 번째 레그는 시도마다 client order id를 붙이고, 실패 응답을 받으면 재주문 전에 그 ID로
+//! 조회한다. 타임아웃처럼 응답만 잃은 체결을 다시 주문해 헤지가 두 배가 되는 일을 막는다.
+//! 조회한 주문이 아직 열려 있으면 취소해 체결량을 확정하고, 일부만 체결됐으면 남은 수량만 다시 주문한다.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use serde::Serialize;
+use tracing::warn;
+
+use interface::ExchangeError;
+
+use crate::clock::Clock;
+use crate::trader::OrderResponse;
+
+/// 두 번째 레그 재시도 사이 대기
+const RETRY_DELAY: Duration = Duration::from_millis(200);
+/// 체결량이 요청 수량에 도달했는지 비교할 때의 상대 오차
+const FILL_TOLERANCE: f64 = 1e-9;
+
+/// 진입 주문 레그
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Leg {
+    Spot,
+    Futures,
+}
+
+impl fmt::Display for Leg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Leg::Spot => f.write_str("spot"),
+            Leg::Futures => f.write_str("futures"),
+        }
+    }
+}
+
+/// 진입 시 어느 레그를 먼저 주문할지
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegOrder {
+    /// 스팟 먼저, 선물(헤지) 나중
+    #[default]
+    SpotFirst,
+    /// 선물(헤지) 먼저, 스팟 나중
+    HedgeFirst,
+}
+
+impl std::str::FromStr for LegOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "spot_first" | "spot" => Ok(LegOrder::SpotFirst),
+            "hedge_first" | "hedge" | "futures" => Ok(LegOrder::HedgeFirst),
+            other => Err(format!("Unknown leg order: {}", other)),
+        }
+    }
+}
+
+/// 2단계 진입 실패
+#[derive(Debug)]
+pub enum TwoPhaseError {
+    /// 첫 레그 실패 (열린 포지션 없음)
+    FirstLeg { leg: Leg, error: ExchangeError },
+    /// 두 번째 레그가 재시도 후에도 실패해 첫 레그를 되돌림
+    SecondLeg {
+        first_leg: Leg,
+        /// 두 번째 레그 시도 횟수 (최초 1회 + 재시도)
+        attempts: u32,
+        error: ExchangeError,
+        /// 첫 레그 되돌림 주문 결과 (실패면 한쪽 포지션이 남아 있음)
+        unwind: Result<OrderResponse, ExchangeError>,
+    },
+}
+
+impl TwoPhaseError {
+    /// 첫 레그 되돌림까지 실패했는지 (수동 정리 필요)
+    pub fn left_naked(&self) -> bool {
+        matches!(self, TwoPhaseError::SecondLeg { unwind: Err(_), .. })
+    }
+}
+
+impl fmt::Display for TwoPhaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TwoPhaseError::FirstLeg { leg, error } => write!(f, "{} leg failed: {}", leg, error),
+            TwoPhaseError::SecondLeg {
+                first_leg,
+                attempts,
+                error,
+                unwind,
+            } => {
+                write!(
+                    f,
+                    "second leg failed after {} attempts ({}); {} leg ",
+                    attempts, error, first_leg
+                )?;
+                match unwind {
+                    Ok(_) => f.write_str("unwound"),
+                    Err(e) => write!(f, "unwind FAILED: {}", e),
+                }
+            }
+        }
+    }
+}
+
+impl From<TwoPhaseError> for ExchangeError {
+    fn from(e: TwoPhaseError) -> Self {
+        match e {
+            TwoPhaseError::FirstLeg { error, .. } => error,
+            other => ExchangeError::Other(other.to_string()),
+        }
+    }
+}
+
+/// 레그 순서대로 주문하고 (스팟 주문 결과, 선물 주문 결과) 반환
+///
+/// 주문 클로저는 시도마다 새로 만든 client order id와 주문 수량을 받는다. 두 번째 레그는
+/// 최초 1회 + `retries`번까지 시도하는데, 실패 응답을 받으면 다시 주문하기 전에 같은 ID로
+/// 조회(`find_spot` / `find_futures`)한다. 열린 주문이면 취소(`cancel_spot` / `cancel_futures`,
+/// 거래소 order id)한 뒤 최종 체결량을 보고, 요청 수량을 다 채웠으면 그 주문을 쓰고
+/// 일부만 채웠으면 남은 수량만 다시 주문한다. 모두 실패하면 첫 레그의 되돌림 주문
+/// (`unwind_spot` / `unwind_futures` 중 첫 레그 쪽)을 내는데, 두 번째 레그가 일부라도
+/// 체결돼 있으면 첫 레그 전량 되돌림이 반대쪽 노출을 만들므로 되돌리지 않고 수동 정리로 넘긴다.
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_two_phase<
+    S,
+    F,
+    QS,
+    QF,
+    CS,
+    CF,
+    SFut,
+    FFut,
+    QSFut,
+    QFFut,
+    CSFut,
+    CFFut,
+    US,
+    UF,
+    USFut,
+    UFFut,
+>(
+    order: LegOrder,
+    retries: u32,
+    clock: &dyn Clock,
+    spot_qty: f64,
+    futures_qty: f64,
+    spot: S,
+    futures: F,
+    find_spot: QS,
+    find_futures: QF,
+    cancel_spot: CS,
+    cancel_futures: CF,
+    unwind_spot: US,
+    unwind_futures: UF,
+) -> Result<(OrderResponse, OrderResponse), TwoPhaseError>
+where
+    S: Fn(String, f64) -> SFut,
+    F: Fn(String, f64) -> FFut,
+    QS: Fn(String) -> QSFut,
+    QF: Fn(String) -> QFFut,
+    CS: Fn(u64) -> CSFut,
+    CF: Fn(u64) -> CFFut,
+    US: FnOnce() -> USFut,
+    UF: FnOnce() -> UFFut,
+    SFut: Future<Output = Result<OrderResponse, ExchangeError>>,
+    FFut: Future<Output = Result<OrderResponse, ExchangeError>>,
+    QSFut: Future<Output = Result<Option<OrderResponse>, ExchangeError>>,
+    QFFut: Future<Output = Result<Option<OrderResponse>, ExchangeError>>,
+    CSFut: Future<Output = Result<(), ExchangeError>>,
+    CFFut: Future<Output = Result<(), ExchangeError>>,
+    USFut: Future<Output = Result<OrderResponse, ExchangeError>>,
+    UFFut: Future<Output = Result<OrderResponse, ExchangeError>>,
+{
+    let prefix = client_order_id_prefix(clock);
+    match order {
+        LegOrder::SpotFirst => {
+            first_then_second(
+                Leg::Spot,
+                retries,
+                clock,
+                &prefix,
+                (spot_qty, futures_qty),
+                spot,
+                futures,
+                find_futures,
+                cancel_futures,
+                unwind_spot,
+            )
+            .await
+        }
+        LegOrder::HedgeFirst => first_then_second(
+            Leg::Futures,
+            retries,
+            clock,
+            &prefix,
+            (futures_qty, spot_qty),
+            futures,
+            spot,
+            find_spot,
+            cancel_spot,
+            unwind_futures,
+        )
+        .await
+        .map(|(futures_order, spot_order)| (spot_order, futures_order)),
+    }
+}
+
+/// 같은 밀리초에 시작한 진입끼리도 겹치지 않게 붙이는 순번
+static ENTRY_SEQ: AtomicU32 = AtomicU32::new(0);
+
+/// 진입 한 번의 client order id 접두사 (예: "tp1700000000000-7")
+fn client_order_id_prefix(clock: &dyn Clock) -> String {
+    format!(
+        "tp{}-{}",
+        clock.now().timestamp_millis(),
+        ENTRY_SEQ.fetch_add(1, Ordering::Relaxed) % 10_000
+    )
+}
+
+/// 레그/시도별 client order id (예: "tp1700000000000-7-f2", Binance 제한 36자 이내)
+fn attempt_client_order_id(prefix: &str, leg: Leg, attempt: u32) -> String {
+    let leg = match leg {
+        Leg::Spot => 's',
+        Leg::Futures => 'f',
+    };
+    format!("{}-{}{}", prefix, leg, attempt)
+}
+
+/// 주문의 체결 수량 (없거나 읽을 수 없으면 0)
+fn executed_qty(order: &OrderResponse) -> f64 {
+    order
+        .executed_qty
+        .as_deref()
+        .and_then(|q| q.parse::<f64>().ok())
+        .unwrap_or(0.0)
+}
+
+/// 아직 체결될 수 있는 주문인지
+fn is_open(order: &OrderResponse) -> bool {
+    matches!(
+        order.status.as_deref(),
+        Some("NEW" | "PARTIALLY_FILLED" | "PENDING_NEW")
+    )
+}
+
+/// 앞선 시도에서 응답만 잃은 부분 체결분을 합친 주문 결과
+fn with_prior_fills(mut order: OrderResponse, prior: f64) -> OrderResponse {
+    if prior > 0.0 {
+        order.executed_qty = Some((prior + executed_qty(&order)).to_string());
+    }
+    order
+}
+
+/// 실패 응답을 받은 주문의 최종 상태 (거래소에 없으면 None)
+/// 열린 주문은 재주문과 함께 체결되지 않도록 취소한 뒤 다시 조회해 체결량을 확정한다
+async fn settle_lost_order<Q, QFut, C, CFut>(
+    find: &Q,
+    cancel: &C,
+    client_order_id: &str,
+) -> Result<Option<OrderResponse>, ExchangeError>
+where
+    Q: Fn(String) -> QFut,
+    C: Fn(u64) -> CFut,
+    QFut: Future<Output = Result<Option<OrderResponse>, ExchangeError>>,
+    CFut: Future<Output = Result<(), ExchangeError>>,
+{
+    let Some(order) = find(client_order_id.to_string()).await? else {
+        return Ok(None);
+    };
+    if !is_open(&order) {
+        return Ok(Some(order));
+    }
+    let order_id = order.order_id.ok_or_else(|| {
+        ExchangeError::Other(format!("open order {} has no order id", client_order_id))
+    })?;
+    warn!(
+        "Second leg order {} still open ({:?}), cancelling before retry",
+        client_order_id, order.status
+    );
+    cancel(order_id).await?;
+    find(client_order_id.to_string())
+        .await?
+        .ok_or_else(|| {
+            ExchangeError::Other(format!("order {} not found after cancel", client_order_id))
+        })
+        .map(Some)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn first_then_second<A, B, Q, C, AFut, BFut, QFut, CFut, U, UFut>(
+    first_leg: Leg,
+    retries: u32,
+    clock: &dyn Clock,
+    prefix: &str,
+    (first_qty, second_qty): (f64, f64),
+    first: A,
+    second: B,
+    find_second: Q,
+    cancel_second: C,
+    unwind_first: U,
+) -> Result<(OrderResponse, OrderResponse), TwoPhaseError>
+where
+    A: Fn(String, f64) -> AFut,
+    B: Fn(String, f64) -> BFut,
+    Q: Fn(String) -> QFut,
+    C: Fn(u64) -> CFut,
+    U: FnOnce() -> UFut,
+    AFut: Future<Output = Result<OrderResponse, ExchangeError>>,
+    BFut: Future<Output = Result<OrderResponse, ExchangeError>>,
+    QFut: Future<Output = Result<Option<OrderResponse>, ExchangeError>>,
+    CFut: Future<Output = Result<(), ExchangeError>>,
+    UFut: Future<Output = Result<OrderResponse, ExchangeError>>,
+{
+    let second_leg = match first_leg {
+        Leg::Spot => Leg::Futures,
+        Leg::Futures => Leg::Spot,
+    };
+    let first_order = first(attempt_client_order_id(prefix, first_leg, 1), first_qty)
+        .await
+        .map_err(|error| TwoPhaseError::FirstLeg {
+            leg: first_leg,
+            error,
+        })?;
+
+    // 응답을 잃은 시도에서 확인된 두 번째 레그 체결량 (남은 수량만 다시 주문)
+    let mut filled = 0.0;
+    let mut attempts = 0;
+    let error = loop {
+        attempts += 1;
+        let remaining = second_qty - filled;
+        let client_order_id = attempt_client_order_id(prefix, second_leg, attempts);
+        let error = match second(client_order_id.clone(), remaining).await {
+            Ok(second_order) => return Ok((first_order, with_prior_fills(second_order, filled))),
+            Err(e) => e,
+        };
+
+        // 실패 응답이어도 거래소에는 접수/체결됐을 수 있으니 다시 주문하기 전에 같은 ID로 조회
+        match settle_lost_order(&find_second, &cancel_second, &client_order_id).await {
+            Ok(Some(second_order)) => {
+                let qty = executed_qty(&second_order);
+                if qty >= remaining * (1.0 - FILL_TOLERANCE) {
+                    warn!(
+                        "Second leg attempt {} returned an error but order {} was filled: {}",
+                        attempts, client_order_id, error
+                    );
+                    return Ok((first_order, with_prior_fills(second_order, filled)));
+                }
+                if qty > 0.0 {
+                    warn!(
+                        "Second leg order {} filled {} of {}, ordering the rest",
+                        client_order_id, qty, remaining
+                    );
+                    filled += qty;
+                }
+            }
+            Ok(None) => {}
+            Err(find_error) => {
+                // 체결 여부를 모르면 재주문(이중 헤지)도 되돌림(반대쪽 노출)도 하지 않고 수동 확인으로 넘긴다
+                warn!(
+                    "Second leg order {} unconfirmed, leaving {} leg open: {}",
+                    client_order_id, first_leg, find_error
+                );
+                return Err(TwoPhaseError::SecondLeg {
+                    first_leg,
+                    attempts,
+                    error,
+                    unwind: Err(ExchangeError::Other(format!(
+                        "not unwound, second leg order {} unconfirmed: {}",
+                        client_order_id, find_error
+                    ))),
+                });
+            }
+        }
+
+        if attempts > retries {
+            break error;
+        }
+        warn!(
+            "Second leg attempt {}/{} failed: {}",
+            attempts,
+            retries + 1,
+            error
+        );
+        clock.sleep(RETRY_DELAY).await;
+    };
+
+    if filled > 0.0 {
+        // 두 번째 레그가 일부 체결된 채라 첫 레그를 전량 되돌리면 반대쪽이 노출된다
+        warn!(
+            "Second leg partially filled ({} of {}), leaving {} leg for manual cleanup",
+            filled, second_qty, first_leg
+        );
+        return Err(TwoPhaseError::SecondLeg {
+            first_leg,
+            attempts,
+            error,
+            unwind: Err(ExchangeError::Other(format!(
+                "not unwound, second leg partially filled {} of {}",
+                filled, second_qty
+            ))),
+        });
+    }
+
+    warn!(
+        "Second leg failed after {} attempts, unwinding {} leg",
+        attempts, first_leg
+    );
+    Err(TwoPhaseError::SecondLeg {
+        first_leg,
+        attempts,
+        error,
+        unwind: unwind_first().await,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::SystemClock;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    fn order(symbol: &str, qty: f64, status: &str) -> OrderResponse {
+        OrderResponse {
+            symbol: symbol.to_string(),
+            order_id: Some(1),
+            client_order_id: None,
+            executed_qty: Some(qty.to_string()),
+            status: Some(status.to_string()),
+            extra: serde_json::Value::Null,
+        }
+    }
+
+    /// 호출 순서를 기록하고, 처음 `futures_failures`번의 선물 주문을 실패시키는 스크립트
+    #[derive(Default)]
+    struct Script {
+        calls: Mutex<Vec<&'static str>>,
+        futures_failures: u32,
+        /// 실패 응답을 돌려준 선물 주문 중 실제로 체결된 비율 (응답 유실, 0이면 접수 안 됨)
+        lost_fill_ratio: f64,
+        /// 실패 응답을 돌려준 선물 주문이 체결 없이 열린 채(NEW) 남음
+        lost_open: bool,
+        /// 주문 조회 실패
+        find_fails: bool,
+        /// 거래소에 접수된 주문 (client order id별)
+        orders: Mutex<HashMap<String, OrderResponse>>,
+        /// 선물 주문 수량 (시도 순)
+        futures_qtys: Mutex<Vec<f64>>,
+    }
+
+    impl Script {
+        fn new(futures_failures: u32) -> Self {
+            Self {
+                futures_failures,
+                ..Default::default()
+            }
+        }
+
+        async fn leg(
+            &self,
+            name: &'static str,
+            client_order_id: Option<String>,
+            qty: f64,
+        ) -> Result<OrderResponse, ExchangeError> {
+            let mut calls = self.calls.lock().unwrap();
+            calls.push(name);
+            if name == "futures" {
+                self.futures_qtys.lock().unwrap().push(qty);
+            }
+            let futures_calls = calls.iter().filter(|c| **c == "futures").count() as u32;
+            let failed = name == "futures" && futures_calls <= self.futures_failures;
+            let accepted = if !failed {
+                Some(order(name, qty, "FILLED"))
+            } else if self.lost_open {
+                Some(order(name, 0.0, "NEW"))
+            } else if self.lost_fill_ratio > 0.0 {
+                Some(order(name, qty * self.lost_fill_ratio, "EXPIRED"))
+            } else {
+                None
+            };
+            if let (Some(id), Some(accepted)) = (client_order_id, accepted) {
+                self.orders.lock().unwrap().insert(
+                    id.clone(),
+                    OrderResponse {
+                        client_order_id: Some(id),
+                        ..accepted
+                    },
+                );
+            }
+            if failed {
+                return Err(ExchangeError::Other("timeout".to_string()));
+            }
+            Ok(order(name, qty, "FILLED"))
+        }
+
+        async fn find(
+            &self,
+            name: &'static str,
+            client_order_id: String,
+        ) -> Result<Option<OrderResponse>, ExchangeError> {
+            self.calls.lock().unwrap().push(name);
+            if self.find_fails {
+                return Err(ExchangeError::Other("timeout".to_string()));
+            }
+            Ok(self.orders.lock().unwrap().get(&client_order_id).cloned())
+        }
+
+        async fn cancel(&self, name: &'static str) -> Result<(), ExchangeError> {
+            self.calls.lock().unwrap().push(name);
+            for order in self.orders.lock().unwrap().values_mut() {
+                if is_open(order) {
+                    order.status = Some("CANCELED".to_string());
+                }
+            }
+            Ok(())
+        }
+
+        fn calls(&self) -> Vec<&'static str> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    async fn run(
+        script: &Script,
+        order: LegOrder,
+        retries: u32,
+    ) -> Result<(OrderResponse, OrderResponse), TwoPhaseError> {
+        execute_two_phase(
+            order,
+            retries,
+            &SystemClock,
+            1.0,
+            1.0,
+            |id, qty| script.leg("spot", Some(id), qty),
+            |id, qty| script.leg("futures", Some(id), qty),
+            |id| script.find("find_spot", id),
+            |id| script.find("find_futures", id),
+            |_| script.cancel("cancel_spot"),
+            |_| script.cancel("cancel_futures"),
+            || script.leg("unwind_spot", None, 1.0),
+            || script.leg("unwind_futures", None, 1.0),
+        )
+        .await
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_second_leg_retried_then_succeeds() {
+        let script = Script::new(2);
+        let (spot, futures) = run(&script, LegOrder::SpotFirst, 2).await.unwrap();
+        assert_eq!(
+            (spot.symbol.as_str(), futures.symbol.as_str()),
+            ("spot", "futures")
+        );
+        assert_eq!(
+            script.calls(),
+            vec![
+                "spot",
+                "futures",
+                "find_futures",
+                "futures",
+                "find_futures",
+                "futures"
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_lost_ack_is_not_ordered_twice() {
+        // 선물 주문이 타임아웃으로 실패했지만 실제로는 체결됨 → 재주문 없이 조회한 주문 사용
+        let script = Script {
+            lost_fill_ratio: 1.0,
+            ..Script::new(1)
+        };
+        let (_, futures) = run(&script, LegOrder::SpotFirst, 2).await.unwrap();
+        assert_eq!(script.calls(), vec!["spot", "futures", "find_futures"]);
+        assert!(futures.client_order_id.unwrap().ends_with("-f1"));
+
+        // 조회까지 실패하면 체결 여부를 모르므로 재주문도 되돌림도 하지 않는다
+        let script = Script {
+            find_fails: true,
+            ..Script::new(1)
+        };
+        let err = run(&script, LegOrder::SpotFirst, 2).await.unwrap_err();
+        assert_eq!(script.calls(), vec!["spot", "futures", "find_futures"]);
+        assert!(err.left_naked());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_partial_lost_fill_orders_only_the_rest() {
+        // 응답을 잃은 주문이 40%만 체결 → 완전한 헤지로 보지 않고 남은 60%만 다시 주문
+        let script = Script {
+            lost_fill_ratio: 0.4,
+            ..Script::new(1)
+        };
+        let (_, futures) = run(&script, LegOrder::SpotFirst, 2).await.unwrap();
+        assert_eq!(
+            script.calls(),
+            vec!["spot", "futures", "find_futures", "futures"]
+        );
+        let qtys = script.futures_qtys.lock().unwrap().clone();
+        assert_eq!(qtys.len(), 2);
+        assert!((qtys[1] - 0.6).abs() < 1e-9);
+        assert!((executed_qty(&futures) - 1.0).abs() < 1e-9);
+
+        // 남은 수량도 끝내 못 채우면 첫 레그를 전량 되돌리지 않고 수동 정리로 넘긴다
+        let script = Script {
+            lost_fill_ratio: 0.4,
+            ..Script::new(u32::MAX)
+        };
+        let err = run(&script, LegOrder::SpotFirst, 1).await.unwrap_err();
+        assert_eq!(
+            script.calls(),
+            vec!["spot", "futures", "find_futures", "futures", "find_futures"]
+        );
+        assert!(err.left_naked());
+        assert!(err.to_string().contains("partially filled"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_open_lost_order_is_cancelled_before_retry() {
+        // 응답을 잃은 주문이 열린 채(NEW)면 취소해 체결량(0)을 확정한 뒤 재주문
+        let script = Script {
+            lost_open: true,
+            ..Script::new(1)
+        };
+        let (_, futures) = run(&script, LegOrder::SpotFirst, 2).await.unwrap();
+        assert_eq!(
+            script.calls(),
+            vec![
+                "spot",
+                "futures",
+                "find_futures",
+                "cancel_futures",
+                "find_futures",
+                "futures"
+            ]
+        );
+        assert_eq!(executed_qty(&futures), 1.0);
+        let orders = script.orders.lock().unwrap();
+        let cancelled = orders
+            .values()
+            .filter(|o| o.status.as_deref() == Some("CANCELED"))
+            .count();
+        assert_eq!(cancelled, 1);
+    }
+
+    #[test]
+    fn test_attempt_client_order_ids_are_distinct() {
+        let prefix = client_order_id_prefix(&SystemClock);
+        assert_ne!(prefix, client_order_id_prefix(&SystemClock));
+        let first = attempt_client_order_id(&prefix, Leg::Futures, 1);
+        assert_ne!(first, attempt_client_order_id(&prefix, Leg::Futures, 2));
+        assert_ne!(first, attempt_client_order_id(&prefix, Leg::Spot, 1));
+        // 분할 주문 접미사("-20")를 붙여도 Binance 제한(36자) 이내
+        assert!(first.len() + 3 <= 36);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_second_leg_failure_unwinds_first_leg() {
+        let script = Script::new(u32::MAX);
+        let err = run(&script, LegOrder::SpotFirst, 1).await.unwrap_err();
+        assert_eq!(
+            script.calls(),
+            vec![
+                "spot",
+                "futures",
+                "find_futures",
+                "futures",
+                "find_futures",
+                "unwind_spot"
+            ]
+        );
+        match &err {
+            TwoPhaseError::SecondLeg {
+                first_leg,
+                attempts,
+                unwind,
+                ..
+            } => {
+                assert_eq!(*first_leg, Leg::Spot);
+                assert_eq!(*attempts, 2);
+                assert!(unwind.is_ok());
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(!err.left_naked());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_hedge_first_fails_without_opening_spot() {
+        let script = Script::new(u32::MAX);
+        let err = run(&script, LegOrder::HedgeFirst, 3).await.unwrap_err();
+        // 첫 레그(선물) 실패면 스팟 주문도 되돌림도 없다
+        assert_eq!(script.calls(), vec!["futures"]);
+        assert!(matches!(
+            err,
+            TwoPhaseError::FirstLeg {
+                leg: Leg::Futures,
+                ..
+            }
+        ));
+
+        let script = Script::new(0);
+        let (spot, futures) = run(&script, LegOrder::HedgeFirst, 0).await.unwrap();
+        assert_eq!(script.calls(), vec!["futures", "spot"]);
+        assert_eq!(
+            (spot.symbol.as_str(), futures.symbol.as_str()),
+            ("spot", "futures")
+        );
+    }
+}
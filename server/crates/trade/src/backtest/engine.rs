@@ -0,0 +1,336 @@
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use timeseries::{Point, StoreConfig, TimeSeriesStore, asof_join, keys};
+
+use super::{BacktestResult, BacktestTrade, EquityPoint};
+use crate::arbitrage::strategy::StrategyMode;
+
+/// 리플레이할 가격 샘플 한 건
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PriceSample {
+    /// epoch ms
+    pub time: i64,
+    pub spot: f64,
+    pub futures: f64,
+}
+
+impl PriceSample {
+    pub fn basis_bps(&self) -> f64 {
+        (self.futures - self.spot) / self.spot * 10_000.0
+    }
+}
+
+/// `time,spot,futures` 형식의 CSV 로드 (헤더 행과 빈 줄은 건너뜀)
+pub fn load_samples_csv(path: impl AsRef<Path>) -> eyre::Result<Vec<PriceSample>> {
+    let content = std::fs::read_to_string(path.as_ref())?;
+    parse_samples_csv(&content)
+}
+
+pub fn parse_samples_csv(content: &str) -> eyre::Result<Vec<PriceSample>> {
+    let mut samples = Vec::new();
+    for (line_no, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("time") {
+            continue;
+        }
+        let cols: Vec<&str> = line.split(',').map(|c| c.trim()).collect();
+        if cols.len() < 3 {
+            return Err(eyre::eyre!("{}번째 줄 컬럼 부족: {}", line_no + 1, line));
+        }
+        let parse = |v: &str| -> eyre::Result<f64> {
+            v.parse::<f64>()
+                .map_err(|e| eyre::eyre!("{}번째 줄 파싱 실패: {} ({})", line_no + 1, e, v))
+        };
+        let sample = PriceSample {
+            time: cols[0]
+                .parse::<i64>()
+                .map_err(|e| eyre::eyre!("{}번째 줄 시각 파싱 실패: {}", line_no + 1, e))?,
+            spot: parse(cols[1])?,
+            futures: parse(cols[2])?,
+        };
+        if sample.spot > 0.0 && sample.futures > 0.0 {
+            samples.push(sample);
+        }
+    }
+    samples.sort_by_key(|s| s.time);
+    Ok(samples)
+}
+
+/// 현물/선물 시세를 맞출 때 허용하는 최대 시각 차이 (ms)
+const STORE_PAIR_MAX_GAP_MS: i64 = 60_000;
+
+/// 오라클 시계열 저장소(`ORACLE_TIMESERIES_DIR`)에서 한 거래소의 현물 가격/선물 마크 가격 로드
+pub fn load_samples_store(
+    root: impl AsRef<Path>,
+    exchange: &str,
+    symbol: &str,
+) -> eyre::Result<Vec<PriceSample>> {
+    let store = TimeSeriesStore::open(root.as_ref(), StoreConfig::default())?;
+    let (from, to) = (DateTime::<Utc>::UNIX_EPOCH, Utc::now());
+    let spot = store.range(&keys::spot_price(exchange, symbol), from, to)?;
+    let perp = store.range(&keys::perp_mark(exchange, symbol), from, to)?;
+    Ok(pair_samples(&spot, &perp, STORE_PAIR_MAX_GAP_MS))
+}
+
+/// 디렉터리면 시계열 저장소, 파일이면 CSV로 로드
+pub fn load_samples(
+    data: impl AsRef<Path>,
+    exchange: &str,
+    symbol: &str,
+) -> eyre::Result<Vec<PriceSample>> {
+    if data.as_ref().is_dir() {
+        load_samples_store(data, exchange, symbol)
+    } else {
+        load_samples_csv(data)
+    }
+}
+
+/// 선물 시각마다 그 이전 가장 가까운 현물 가격을 붙임 (`max_gap_ms`보다 오래된 현물 가격은 버림)
+pub fn pair_samples(spot: &[Point], perp: &[Point], max_gap_ms: i64) -> Vec<PriceSample> {
+    asof_join(perp, spot, chrono::Duration::milliseconds(max_gap_ms))
+        .into_iter()
+        .filter(|(_, futures, spot)| *spot > 0.0 && *futures > 0.0)
+        .map(|(time, futures, spot)| PriceSample {
+            time: time.timestamp_millis(),
+            spot,
+            futures,
+        })
+        .collect()
+}
+
+/// 백테스트 설정 (IntraBasis 전략의 진입/청산 규칙을 그대로 재현)
+#[derive(Debug, Clone)]
+pub struct BacktestConfig {
+    pub name: String,
+    pub symbol: String,
+    pub mode: StrategyMode,
+    pub entry_bps: f64,
+    pub exit_bps: f64,
+    /// 진입 명목가 (USDT)
+    pub notional: f64,
+    /// 진입 베이시스 대비 불리한 방향으로 이만큼(bps) 움직이면 손절 (None이면 손절 없음)
+    pub stop_bps: Option<f64>,
+    /// 레그별 체결 수수료 (bps, 진입/청산 각각 양쪽 레그에 부과)
+    pub fee_bps: f64,
+    pub initial_equity: f64,
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            symbol: String::new(),
+            mode: StrategyMode::Carry,
+            entry_bps: 6.0,
+            exit_bps: -6.0,
+            notional: 100.0,
+            stop_bps: None,
+            fee_bps: 4.0,
+            initial_equity: 1_000.0,
+        }
+    }
+}
+
+/// 열린 포지션
+#[derive(Debug, Clone, Copy)]
+struct OpenPosition {
+    /// 1.0 = carry (스팟 롱 + 선물 숏), -1.0 = reverse
+    side: f64,
+    qty: f64,
+    entry: PriceSample,
+    entry_fees: f64,
+}
+
+impl OpenPosition {
+    fn dir(&self) -> &'static str {
+        if self.side > 0.0 { "carry" } else { "reverse" }
+    }
+
+    /// 수수료 제외 평가 손익
+    fn gross_pnl(&self, sample: &PriceSample) -> f64 {
+        self.side
+            * self.qty
+            * ((sample.spot - self.entry.spot) - (sample.futures - self.entry.futures))
+    }
+}
+
+fn leg_fees(qty: f64, sample: &PriceSample, fee_bps: f64) -> f64 {
+    qty * (sample.spot + sample.futures) * fee_bps / 10_000.0
+}
+
+/// 가격 샘플을 순서대로 리플레이하며 진입/청산을 시뮬레이션
+///
+/// 체결은 샘플 가격 그대로 이루어진다고 가정하고(슬리피지 없음),
+/// 마지막 샘플에서 열린 포지션은 강제 청산한다.
+pub fn run_backtest(samples: &[PriceSample], config: &BacktestConfig) -> BacktestResult {
+    let mut trades = Vec::new();
+    let mut equity = Vec::with_capacity(samples.len());
+    let mut basis_samples = Vec::with_capacity(samples.len());
+    let mut realized = 0.0;
+    let mut position: Option<OpenPosition> = None;
+
+    for (i, sample) in samples.iter().enumerate() {
+        let basis = sample.basis_bps();
+        basis_samples.push(basis);
+        let is_last = i + 1 == samples.len();
+
+        if let Some(pos) = position {
+            let entry_basis = pos.entry.basis_bps();
+            let should_exit = if pos.side > 0.0 {
+                basis <= config.exit_bps
+            } else {
+                basis >= -config.exit_bps
+            };
+            // carry는 베이시스가 더 벌어질 때, reverse는 더 좁혀질 때 손실
+            let adverse_bps = pos.side * (basis - entry_basis);
+            let stopped = config.stop_bps.is_some_and(|stop| adverse_bps >= stop);
+
+            if should_exit || stopped || is_last {
+                let exit_fees = leg_fees(pos.qty, sample, config.fee_bps);
+                let fees = pos.entry_fees + exit_fees;
+                let pnl = pos.gross_pnl(sample) - fees;
+                realized += pnl;
+                trades.push(BacktestTrade {
+                    entry_time: pos.entry.time,
+                    exit_time: sample.time,
+                    dir: pos.dir().to_string(),
+                    qty: pos.qty,
+                    entry_basis_bps: entry_basis,
+                    exit_basis_bps: basis,
+                    pnl,
+                    fees,
+                });
+                position = None;
+            }
+        } else if !is_last {
+            let side = if matches!(config.mode, StrategyMode::Carry | StrategyMode::Auto)
+                && basis > config.entry_bps
+            {
+                Some(1.0)
+            } else if matches!(config.mode, StrategyMode::Reverse | StrategyMode::Auto)
+                && basis < -config.entry_bps
+            {
+                Some(-1.0)
+            } else {
+                None
+            };
+
+            if let Some(side) = side {
+                let qty = config.notional / sample.spot;
+                position = Some(OpenPosition {
+                    side,
+                    qty,
+                    entry: *sample,
+                    entry_fees: leg_fees(qty, sample, config.fee_bps),
+                });
+            }
+        }
+
+        let unrealized = position
+            .map(|p| p.gross_pnl(sample) - p.entry_fees)
+            .unwrap_or(0.0);
+        equity.push(EquityPoint {
+            time: sample.time,
+            equity: config.initial_equity + realized + unrealized,
+        });
+    }
+
+    BacktestResult {
+        name: config.name.clone(),
+        symbol: config.symbol.clone(),
+        initial_equity: config.initial_equity,
+        trades,
+        equity,
+        basis_samples,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(time: i64, basis_bps: f64) -> PriceSample {
+        PriceSample {
+            time,
+            spot: 100.0,
+            futures: 100.0 * (1.0 + basis_bps / 10_000.0),
+        }
+    }
+
+    #[test]
+    fn test_parse_samples_csv() {
+        let csv = "time,spot,futures\n2000,100,100.1\n\n1000,99.5,99.6\n";
+        let samples = parse_samples_csv(csv).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].time, 1000);
+        assert!(parse_samples_csv("1000,abc,1").is_err());
+    }
+
+    #[test]
+    fn test_pair_samples_as_of_spot() {
+        let at =
+            |ms: i64, value: f64| Point::new(DateTime::from_timestamp_millis(ms).unwrap(), value);
+        let spot = [at(1_000, 100.0), at(5_000, 101.0)];
+        let perp = [
+            at(500, 100.2),
+            at(2_000, 100.1),
+            at(6_000, 101.3),
+            at(70_000, 101.5),
+        ];
+        let samples = pair_samples(&spot, &perp, 60_000);
+        // 이전 현물 가격이 없거나 너무 오래된 시점은 제외
+        assert_eq!(samples.len(), 2);
+        assert_eq!((samples[0].time, samples[0].spot), (2_000, 100.0));
+        assert_eq!((samples[1].time, samples[1].spot), (6_000, 101.0));
+    }
+
+    #[test]
+    fn test_carry_round_trip() {
+        let config = BacktestConfig {
+            entry_bps: 5.0,
+            exit_bps: 0.0,
+            notional: 100.0,
+            fee_bps: 0.0,
+            ..Default::default()
+        };
+        let samples = [
+            sample(0, 2.0),
+            sample(1, 10.0),
+            sample(2, 4.0),
+            sample(3, -1.0),
+            sample(4, 0.0),
+        ];
+        let result = run_backtest(&samples, &config);
+
+        assert_eq!(result.trades.len(), 1);
+        let trade = &result.trades[0];
+        assert_eq!(trade.dir, "carry");
+        assert_eq!(trade.exit_time, 3);
+        // 베이시스 11bps 축소 → 명목가 100 USDT 기준 약 0.11 USDT
+        assert!((trade.pnl - 0.11).abs() < 1e-9);
+        assert_eq!(result.equity.len(), samples.len());
+    }
+
+    #[test]
+    fn test_stop_loss_and_fees() {
+        let config = BacktestConfig {
+            mode: StrategyMode::Auto,
+            entry_bps: 5.0,
+            exit_bps: 0.0,
+            stop_bps: Some(5.0),
+            fee_bps: 1.0,
+            ..Default::default()
+        };
+        let samples = [sample(0, -8.0), sample(1, -14.0), sample(2, -20.0)];
+        let result = run_backtest(&samples, &config);
+
+        assert_eq!(result.trades.len(), 1);
+        let trade = &result.trades[0];
+        assert_eq!(trade.dir, "reverse");
+        assert_eq!(trade.exit_time, 1);
+        assert!(trade.fees > 0.0);
+        assert!(trade.pnl < 0.0);
+    }
+}
@@ -0,0 +1,59 @@
+//! 백테스트 결과 타입과 리포트 생성
+//!
+//! `engine`이 가격 샘플을 리플레이해 `BacktestResult`를 만들고,
+//! `report` 모듈이 이를 JSON/HTML 리포트로 변환한다.
+//! `optimize`는 파라미터 격자에 대해 엔진을 병렬 실행하고,
+//! `walk_forward`는 학습/검증 구간을 나눠 out-of-sample 성과를 측정한다.
+
+pub mod engine;
+pub mod optimize;
+pub mod report;
+pub mod walk_forward;
+
+use serde::{Deserialize, Serialize};
+
+pub use engine::{
+    BacktestConfig, PriceSample, load_samples, load_samples_csv, load_samples_store, run_backtest,
+};
+pub use report::{BacktestReport, ReportSummary, build_report, write_report};
+
+/// 백테스트에서 체결된 왕복 거래 한 건
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestTrade {
+    /// 진입 시각 (epoch ms)
+    pub entry_time: i64,
+    /// 청산 시각 (epoch ms)
+    pub exit_time: i64,
+    /// 방향 ("carry" / "reverse")
+    pub dir: String,
+    pub qty: f64,
+    pub entry_basis_bps: f64,
+    pub exit_basis_bps: f64,
+    /// 수수료 차감 후 손익 (USDT)
+    pub pnl: f64,
+    /// 지불한 수수료 (USDT)
+    pub fees: f64,
+}
+
+/// 자산 곡선의 한 시점
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct EquityPoint {
+    /// epoch ms
+    pub time: i64,
+    /// 평가 자산 (USDT)
+    pub equity: f64,
+}
+
+/// 백테스트 실행 결과 (리포트 입력)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BacktestResult {
+    /// 결과 이름 (리포트 파일명에 사용)
+    pub name: String,
+    pub symbol: String,
+    /// 시작 자산 (USDT)
+    pub initial_equity: f64,
+    pub trades: Vec<BacktestTrade>,
+    pub equity: Vec<EquityPoint>,
+    /// 리플레이 구간의 베이시스 샘플 (bps)
+    pub basis_samples: Vec<f64>,
+}
@@ -0,0 +1,354 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use super::engine::{BacktestConfig, PriceSample, run_backtest};
+use super::report::{ReportSummary, build_report};
+
+/// 파라미터 조합의 순위를 매길 목적 함수
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Objective {
+    /// 총 손익
+    Pnl,
+    /// 연환산 Sharpe
+    Sharpe,
+    /// 수익률 / 최대 낙폭
+    Calmar,
+    /// 승률
+    HitRate,
+}
+
+impl Objective {
+    /// 요약 통계에서 점수 계산 (클수록 좋음)
+    pub fn score(&self, summary: &ReportSummary) -> f64 {
+        match self {
+            Objective::Pnl => summary.total_pnl,
+            Objective::Sharpe => summary.sharpe,
+            Objective::Calmar => {
+                if summary.max_drawdown_pct > 0.0 {
+                    summary.return_pct / summary.max_drawdown_pct
+                } else {
+                    summary.return_pct
+                }
+            }
+            Objective::HitRate => summary.hit_rate,
+        }
+    }
+}
+
+impl FromStr for Objective {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pnl" => Ok(Objective::Pnl),
+            "sharpe" => Ok(Objective::Sharpe),
+            "calmar" => Ok(Objective::Calmar),
+            "hit_rate" | "hitrate" => Ok(Objective::HitRate),
+            other => Err(format!("Unknown objective: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Objective::Pnl => "pnl",
+            Objective::Sharpe => "sharpe",
+            Objective::Calmar => "calmar",
+            Objective::HitRate => "hit_rate",
+        };
+        f.write_str(s)
+    }
+}
+
+/// 백테스트 한 번에 사용할 파라미터 조합
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SweepParams {
+    pub entry_bps: f64,
+    pub exit_bps: f64,
+    pub notional: f64,
+    pub stop_bps: Option<f64>,
+}
+
+impl SweepParams {
+    /// 기본 설정에 파라미터를 덮어쓴 백테스트 설정
+    pub fn apply(&self, base: &BacktestConfig) -> BacktestConfig {
+        BacktestConfig {
+            entry_bps: self.entry_bps,
+            exit_bps: self.exit_bps,
+            notional: self.notional,
+            stop_bps: self.stop_bps,
+            ..base.clone()
+        }
+    }
+}
+
+/// 파라미터 격자
+#[derive(Debug, Clone)]
+pub struct ParamGrid {
+    pub entry_bps: Vec<f64>,
+    pub exit_bps: Vec<f64>,
+    pub notional: Vec<f64>,
+    pub stop_bps: Vec<Option<f64>>,
+}
+
+impl ParamGrid {
+    /// 모든 조합 (exit_bps >= entry_bps 인 조합은 즉시 청산되므로 제외)
+    pub fn combinations(&self) -> Vec<SweepParams> {
+        let mut result = Vec::new();
+        for &entry_bps in &self.entry_bps {
+            for &exit_bps in &self.exit_bps {
+                if exit_bps >= entry_bps {
+                    continue;
+                }
+                for &notional in &self.notional {
+                    for &stop_bps in &self.stop_bps {
+                        result.push(SweepParams {
+                            entry_bps,
+                            exit_bps,
+                            notional,
+                            stop_bps,
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// 전체 조합 중 `count`개를 중복 없이 무작위 추출 (같은 seed면 같은 결과)
+    pub fn random_sample(&self, count: usize, seed: u64) -> Vec<SweepParams> {
+        let mut all = self.combinations();
+        let mut state = seed.max(1);
+        // 부분 Fisher-Yates 셔플 (xorshift64)
+        let take = count.min(all.len());
+        for i in 0..take {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let j = i + (state % (all.len() - i) as u64) as usize;
+            all.swap(i, j);
+        }
+        all.truncate(take);
+        all
+    }
+}
+
+/// 값 목록 파싱: "2,4,6" 또는 범위 "start:end:step" (끝 포함)
+pub fn parse_values(s: &str) -> Result<Vec<f64>, String> {
+    let s = s.trim();
+    if let Some((range, step)) = s.rsplit_once(':')
+        && let Some((start, end)) = range.split_once(':')
+    {
+        let parse = |v: &str| {
+            v.trim()
+                .parse::<f64>()
+                .map_err(|e| format!("Invalid number '{}': {}", v, e))
+        };
+        let (start, end, step) = (parse(start)?, parse(end)?, parse(step)?);
+        if step <= 0.0 || end < start {
+            return Err(format!("Invalid range: {}", s));
+        }
+        let steps = ((end - start) / step + 1e-9).floor() as usize;
+        return Ok((0..=steps).map(|i| start + step * i as f64).collect());
+    }
+
+    s.split(',')
+        .map(|v| {
+            v.trim()
+                .parse::<f64>()
+                .map_err(|e| format!("Invalid number '{}': {}", v, e))
+        })
+        .collect()
+}
+
+/// 손절 값 목록 파싱 ("none"은 손절 없음, 예: "none,10,20")
+pub fn parse_stop_values(s: &str) -> Result<Vec<Option<f64>>, String> {
+    s.split(',')
+        .map(|v| {
+            let v = v.trim();
+            if v.eq_ignore_ascii_case("none") {
+                Ok(None)
+            } else {
+                v.parse::<f64>()
+                    .map(Some)
+                    .map_err(|e| format!("Invalid stop '{}': {}", v, e))
+            }
+        })
+        .collect()
+}
+
+/// 파라미터 조합 하나의 백테스트 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepRun {
+    pub params: SweepParams,
+    pub summary: ReportSummary,
+    pub score: f64,
+}
+
+/// 백테스트 하나 실행 후 요약
+pub fn evaluate(
+    samples: &[PriceSample],
+    base: &BacktestConfig,
+    params: SweepParams,
+    objective: Objective,
+) -> SweepRun {
+    let result = run_backtest(samples, &params.apply(base));
+    let summary = build_report(&result).summary;
+    SweepRun {
+        params,
+        score: objective.score(&summary),
+        summary,
+    }
+}
+
+/// 파라미터 조합들을 병렬로 백테스트하고 점수 내림차순으로 정렬해 반환
+///
+/// 조합을 CPU 코어 수만큼 묶어 각각 blocking 태스크에서 실행한다.
+pub async fn run_sweep(
+    samples: Arc<Vec<PriceSample>>,
+    base: &BacktestConfig,
+    params: Vec<SweepParams>,
+    objective: Objective,
+) -> eyre::Result<Vec<SweepRun>> {
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4);
+    let chunk_size = params.len().div_ceil(workers).max(1);
+
+    let mut handles = Vec::new();
+    for chunk in params.chunks(chunk_size) {
+        let chunk = chunk.to_vec();
+        let samples = Arc::clone(&samples);
+        let base = base.clone();
+        handles.push(tokio::task::spawn_blocking(move || {
+            chunk
+                .into_iter()
+                .map(|p| evaluate(&samples, &base, p, objective))
+                .collect::<Vec<_>>()
+        }));
+    }
+
+    let mut runs = Vec::with_capacity(params.len());
+    for handle in handles {
+        runs.extend(handle.await?);
+    }
+    runs.sort_by(|a, b| b.score.total_cmp(&a.score));
+    Ok(runs)
+}
+
+/// 전체 실행 결과를 CSV로 저장 (점수 순)
+pub fn write_csv(runs: &[SweepRun], path: impl AsRef<Path>) -> eyre::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut csv = String::from(
+        "rank,entry_bps,exit_bps,notional,stop_bps,score,total_trades,hit_rate,total_pnl,total_fees,return_pct,sharpe,max_drawdown,max_drawdown_pct\n",
+    );
+    for (i, run) in runs.iter().enumerate() {
+        let p = &run.params;
+        let s = &run.summary;
+        csv.push_str(&format!(
+            "{},{},{},{},{},{:.6},{},{:.4},{:.6},{:.6},{:.4},{:.4},{:.6},{:.4}\n",
+            i + 1,
+            p.entry_bps,
+            p.exit_bps,
+            p.notional,
+            p.stop_bps.map(|v| v.to_string()).unwrap_or_default(),
+            run.score,
+            s.total_trades,
+            s.hit_rate,
+            s.total_pnl,
+            s.total_fees,
+            s.return_pct,
+            s.sharpe,
+            s.max_drawdown,
+            s.max_drawdown_pct
+        ));
+    }
+    std::fs::write(path, csv)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> ParamGrid {
+        ParamGrid {
+            entry_bps: vec![4.0, 8.0],
+            exit_bps: vec![0.0, 6.0],
+            notional: vec![100.0],
+            stop_bps: vec![None, Some(10.0)],
+        }
+    }
+
+    #[test]
+    fn test_parse_values() {
+        assert_eq!(parse_values("2, 4,6").unwrap(), vec![2.0, 4.0, 6.0]);
+        assert_eq!(parse_values("1:2:0.5").unwrap(), vec![1.0, 1.5, 2.0]);
+        assert_eq!(parse_values("-2").unwrap(), vec![-2.0]);
+        assert!(parse_values("1:0:1").is_err());
+        assert_eq!(
+            parse_stop_values("none,15").unwrap(),
+            vec![None, Some(15.0)]
+        );
+    }
+
+    #[test]
+    fn test_grid_combinations_and_sampling() {
+        // (4,0), (8,0), (8,6) × stop 2개
+        let all = grid().combinations();
+        assert_eq!(all.len(), 6);
+        assert!(all.iter().all(|p| p.exit_bps < p.entry_bps));
+
+        let sample = grid().random_sample(4, 42);
+        assert_eq!(sample.len(), 4);
+        assert_eq!(sample, grid().random_sample(4, 42));
+        assert_eq!(grid().random_sample(100, 1).len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_run_sweep_ranks_by_objective() {
+        let samples: Vec<PriceSample> = [2.0, 10.0, 7.0, 3.0, -1.0, 9.0, 0.0, 1.0]
+            .iter()
+            .enumerate()
+            .map(|(i, basis)| PriceSample {
+                time: i as i64 * 60_000,
+                spot: 100.0,
+                futures: 100.0 * (1.0 + basis / 10_000.0),
+            })
+            .collect();
+        let base = BacktestConfig {
+            fee_bps: 0.0,
+            ..Default::default()
+        };
+
+        let runs = run_sweep(
+            Arc::new(samples),
+            &base,
+            grid().combinations(),
+            Objective::Pnl,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(runs.len(), 6);
+        assert!(runs.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let path = std::env::temp_dir().join(format!("sweep_{}.csv", uuid::Uuid::new_v4()));
+        write_csv(&runs, &path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 7);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,452 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{BacktestResult, BacktestTrade, EquityPoint};
+
+/// 베이시스 히스토그램 기본 구간 개수
+const DEFAULT_HISTOGRAM_BINS: usize = 20;
+/// 연환산에 사용하는 1년 길이 (ms)
+const YEAR_MS: f64 = 365.0 * 24.0 * 60.0 * 60.0 * 1000.0;
+
+/// 히스토그램 구간
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HistogramBin {
+    pub lower: f64,
+    pub upper: f64,
+    pub count: usize,
+}
+
+/// 요약 통계
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportSummary {
+    pub total_trades: usize,
+    pub winning_trades: usize,
+    /// 수익 거래 비율 (0~1)
+    pub hit_rate: f64,
+    pub total_pnl: f64,
+    pub total_fees: f64,
+    pub avg_trade_pnl: f64,
+    pub final_equity: f64,
+    /// 시작 자산 대비 수익률 (%)
+    pub return_pct: f64,
+    /// 자산 곡선 구간 수익률 기준 연환산 Sharpe (무위험 수익률 0)
+    pub sharpe: f64,
+    /// 최대 낙폭 (USDT)
+    pub max_drawdown: f64,
+    /// 최대 낙폭 (고점 대비 %)
+    pub max_drawdown_pct: f64,
+}
+
+/// 백테스트 리포트 (JSON 문서)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestReport {
+    pub name: String,
+    pub symbol: String,
+    pub generated_at: DateTime<Utc>,
+    pub summary: ReportSummary,
+    pub equity_curve: Vec<EquityPoint>,
+    pub trades: Vec<BacktestTrade>,
+    pub basis_histogram: Vec<HistogramBin>,
+}
+
+/// 최대 낙폭 (절대값, 고점 대비 %)
+pub fn max_drawdown(equity: &[EquityPoint]) -> (f64, f64) {
+    let mut peak = f64::MIN;
+    let mut max_dd = 0.0;
+    let mut max_dd_pct = 0.0;
+    for point in equity {
+        peak = peak.max(point.equity);
+        let dd = peak - point.equity;
+        if dd > max_dd {
+            max_dd = dd;
+        }
+        if peak > 0.0 && dd / peak * 100.0 > max_dd_pct {
+            max_dd_pct = dd / peak * 100.0;
+        }
+    }
+    (max_dd, max_dd_pct)
+}
+
+/// 자산 곡선의 구간 수익률로 계산한 연환산 Sharpe
+/// 구간 길이는 평균 시점 간격을 사용하며, 수익률이 2개 미만이거나 변동이 없으면 0
+pub fn sharpe_ratio(equity: &[EquityPoint]) -> f64 {
+    let returns: Vec<f64> = equity
+        .windows(2)
+        .filter(|w| w[0].equity > 0.0)
+        .map(|w| w[1].equity / w[0].equity - 1.0)
+        .collect();
+    if returns.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+    let var = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+    let std = var.sqrt();
+    if std <= f64::EPSILON {
+        return 0.0;
+    }
+
+    let span_ms = (equity[equity.len() - 1].time - equity[0].time) as f64;
+    let avg_interval_ms = span_ms / (equity.len() - 1) as f64;
+    let periods_per_year = if avg_interval_ms > 0.0 {
+        YEAR_MS / avg_interval_ms
+    } else {
+        1.0
+    };
+    mean / std * periods_per_year.sqrt()
+}
+
+/// 샘플을 같은 폭의 구간으로 나눈 히스토그램
+pub fn histogram(samples: &[f64], bins: usize) -> Vec<HistogramBin> {
+    let finite: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.is_empty() || bins == 0 {
+        return Vec::new();
+    }
+
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if max - min <= f64::EPSILON {
+        return vec![HistogramBin {
+            lower: min,
+            upper: max,
+            count: finite.len(),
+        }];
+    }
+
+    let width = (max - min) / bins as f64;
+    let mut result: Vec<HistogramBin> = (0..bins)
+        .map(|i| HistogramBin {
+            lower: min + width * i as f64,
+            upper: min + width * (i + 1) as f64,
+            count: 0,
+        })
+        .collect();
+    for v in finite {
+        let idx = (((v - min) / width) as usize).min(bins - 1);
+        result[idx].count += 1;
+    }
+    result
+}
+
+/// 백테스트 결과로부터 리포트 생성
+pub fn build_report(result: &BacktestResult) -> BacktestReport {
+    let total_trades = result.trades.len();
+    let winning_trades = result.trades.iter().filter(|t| t.pnl > 0.0).count();
+    let total_pnl: f64 = result.trades.iter().map(|t| t.pnl).sum();
+    let total_fees: f64 = result.trades.iter().map(|t| t.fees).sum();
+    let final_equity = result
+        .equity
+        .last()
+        .map(|p| p.equity)
+        .unwrap_or(result.initial_equity + total_pnl);
+    let (max_drawdown, max_drawdown_pct) = max_drawdown(&result.equity);
+
+    let summary = ReportSummary {
+        total_trades,
+        winning_trades,
+        hit_rate: if total_trades > 0 {
+            winning_trades as f64 / total_trades as f64
+        } else {
+            0.0
+        },
+        total_pnl,
+        total_fees,
+        avg_trade_pnl: if total_trades > 0 {
+            total_pnl / total_trades as f64
+        } else {
+            0.0
+        },
+        final_equity,
+        return_pct: if result.initial_equity > 0.0 {
+            (final_equity / result.initial_equity - 1.0) * 100.0
+        } else {
+            0.0
+        },
+        sharpe: sharpe_ratio(&result.equity),
+        max_drawdown,
+        max_drawdown_pct,
+    };
+
+    BacktestReport {
+        name: result.name.clone(),
+        symbol: result.symbol.clone(),
+        generated_at: Utc::now(),
+        summary,
+        equity_curve: result.equity.clone(),
+        trades: result.trades.clone(),
+        basis_histogram: histogram(&result.basis_samples, DEFAULT_HISTOGRAM_BINS),
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn format_time(ms: i64) -> String {
+    DateTime::<Utc>::from_timestamp_millis(ms)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| ms.to_string())
+}
+
+/// 자산 곡선 SVG (polyline)
+fn equity_svg(equity: &[EquityPoint], width: f64, height: f64) -> String {
+    if equity.len() < 2 {
+        return "<p>자산 곡선 데이터 없음</p>".to_string();
+    }
+    let t0 = equity[0].time as f64;
+    let t1 = equity[equity.len() - 1].time as f64;
+    let min = equity
+        .iter()
+        .map(|p| p.equity)
+        .fold(f64::INFINITY, f64::min);
+    let max = equity
+        .iter()
+        .map(|p| p.equity)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let x_span = (t1 - t0).max(1.0);
+    let y_span = (max - min).max(f64::EPSILON);
+
+    let points: Vec<String> = equity
+        .iter()
+        .map(|p| {
+            let x = (p.time as f64 - t0) / x_span * width;
+            let y = height - (p.equity - min) / y_span * height;
+            format!("{:.1},{:.1}", x, y)
+        })
+        .collect();
+
+    format!(
+        "<svg viewBox=\"0 0 {w} {h}\" width=\"{w}\" height=\"{h}\"><polyline fill=\"none\" stroke=\"#2563eb\" stroke-width=\"1.5\" points=\"{pts}\"/></svg><p class=\"axis\">min {min:.4} / max {max:.4} USDT</p>",
+        w = width,
+        h = height,
+        pts = points.join(" "),
+        min = min,
+        max = max,
+    )
+}
+
+/// 히스토그램 SVG (막대)
+fn histogram_svg(bins: &[HistogramBin], width: f64, height: f64) -> String {
+    let Some(max_count) = bins.iter().map(|b| b.count).max().filter(|c| *c > 0) else {
+        return "<p>베이시스 샘플 없음</p>".to_string();
+    };
+    let bar_width = width / bins.len() as f64;
+    let mut svg = format!(
+        "<svg viewBox=\"0 0 {w} {h}\" width=\"{w}\" height=\"{h}\">",
+        w = width,
+        h = height
+    );
+    for (i, bin) in bins.iter().enumerate() {
+        let bar_height = bin.count as f64 / max_count as f64 * height;
+        let _ = write!(
+            svg,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"#16a34a\"><title>{:.2} ~ {:.2} bps: {}</title></rect>",
+            i as f64 * bar_width + 1.0,
+            height - bar_height,
+            (bar_width - 2.0).max(1.0),
+            bar_height,
+            bin.lower,
+            bin.upper,
+            bin.count
+        );
+    }
+    svg.push_str("</svg>");
+    if let (Some(first), Some(last)) = (bins.first(), bins.last()) {
+        let _ = write!(
+            svg,
+            "<p class=\"axis\">{:.2} bps ~ {:.2} bps</p>",
+            first.lower, last.upper
+        );
+    }
+    svg
+}
+
+/// 외부 리소스 없이 열 수 있는 단일 HTML 페이지
+pub fn render_html(report: &BacktestReport) -> String {
+    let s = &report.summary;
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Backtest {name}</title><style>\
+body{{font-family:sans-serif;margin:24px;color:#111}}table{{border-collapse:collapse;margin-bottom:24px}}\
+td,th{{border:1px solid #ddd;padding:4px 8px;text-align:right}}th{{background:#f3f4f6}}\
+.axis{{color:#666;font-size:12px}}.neg{{color:#dc2626}}</style></head><body>\
+<h1>Backtest: {name} ({symbol})</h1><p class=\"axis\">generated at {generated}</p>",
+        name = escape_html(&report.name),
+        symbol = escape_html(&report.symbol),
+        generated = report.generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+    );
+
+    let _ = write!(
+        html,
+        "<h2>Summary</h2><table>\
+<tr><th>Trades</th><td>{}</td></tr><tr><th>Hit rate</th><td>{:.1}%</td></tr>\
+<tr><th>Total PnL</th><td>{:.6}</td></tr><tr><th>Total fees</th><td>{:.6}</td></tr>\
+<tr><th>Avg trade PnL</th><td>{:.6}</td></tr><tr><th>Final equity</th><td>{:.6}</td></tr>\
+<tr><th>Return</th><td>{:.3}%</td></tr><tr><th>Sharpe</th><td>{:.3}</td></tr>\
+<tr><th>Max drawdown</th><td>{:.6} ({:.3}%)</td></tr></table>",
+        s.total_trades,
+        s.hit_rate * 100.0,
+        s.total_pnl,
+        s.total_fees,
+        s.avg_trade_pnl,
+        s.final_equity,
+        s.return_pct,
+        s.sharpe,
+        s.max_drawdown,
+        s.max_drawdown_pct,
+    );
+
+    html.push_str("<h2>Equity curve</h2>");
+    html.push_str(&equity_svg(&report.equity_curve, 800.0, 240.0));
+    html.push_str("<h2>Basis distribution</h2>");
+    html.push_str(&histogram_svg(&report.basis_histogram, 800.0, 200.0));
+
+    html.push_str(
+        "<h2>Trades</h2><table><tr><th>#</th><th>Entry</th><th>Exit</th><th>Dir</th><th>Qty</th>\
+<th>Entry bps</th><th>Exit bps</th><th>Fees</th><th>PnL</th></tr>",
+    );
+    for (i, t) in report.trades.iter().enumerate() {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.2}</td><td>{:.2}</td><td>{:.6}</td><td{}>{:.6}</td></tr>",
+            i + 1,
+            format_time(t.entry_time),
+            format_time(t.exit_time),
+            escape_html(&t.dir),
+            t.qty,
+            t.entry_basis_bps,
+            t.exit_basis_bps,
+            t.fees,
+            if t.pnl < 0.0 { " class=\"neg\"" } else { "" },
+            t.pnl
+        );
+    }
+    html.push_str("</table></body></html>\n");
+    html
+}
+
+/// 리포트를 `{dir}/{name}.json`, `{dir}/{name}.html`로 저장하고 두 경로를 반환
+pub fn write_report(
+    result: &BacktestResult,
+    dir: impl AsRef<Path>,
+) -> eyre::Result<(PathBuf, PathBuf)> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    let report = build_report(result);
+    let name = if report.name.is_empty() {
+        format!("backtest_{}", report.generated_at.format("%Y%m%d_%H%M%S"))
+    } else {
+        report.name.clone()
+    };
+
+    let json_path = dir.join(format!("{}.json", name));
+    let html_path = dir.join(format!("{}.html", name));
+    std::fs::write(&json_path, serde_json::to_string_pretty(&report)?)?;
+    std::fs::write(&html_path, render_html(&report))?;
+
+    Ok((json_path, html_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(time: i64, equity: f64) -> EquityPoint {
+        EquityPoint { time, equity }
+    }
+
+    fn trade(pnl: f64) -> BacktestTrade {
+        BacktestTrade {
+            entry_time: 0,
+            exit_time: 60_000,
+            dir: "carry".to_string(),
+            qty: 1.0,
+            entry_basis_bps: 10.0,
+            exit_basis_bps: 0.0,
+            pnl,
+            fees: 0.1,
+        }
+    }
+
+    #[test]
+    fn test_max_drawdown() {
+        let equity = [
+            point(0, 100.0),
+            point(1, 120.0),
+            point(2, 90.0),
+            point(3, 130.0),
+            point(4, 125.0),
+        ];
+        let (dd, dd_pct) = max_drawdown(&equity);
+        assert!((dd - 30.0).abs() < 1e-9);
+        assert!((dd_pct - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_histogram_counts_all_samples() {
+        let samples = [-5.0, -1.0, 0.0, 1.0, 5.0, f64::NAN];
+        let bins = histogram(&samples, 5);
+        assert_eq!(bins.len(), 5);
+        assert_eq!(bins.iter().map(|b| b.count).sum::<usize>(), 5);
+        assert_eq!(bins[0].lower, -5.0);
+        assert_eq!(bins[4].upper, 5.0);
+        assert_eq!(bins[4].count, 1);
+
+        assert_eq!(histogram(&[2.0, 2.0], 5).len(), 1);
+        assert!(histogram(&[], 5).is_empty());
+    }
+
+    #[test]
+    fn test_sharpe_ratio() {
+        // 변동 없는 곡선은 0
+        assert_eq!(sharpe_ratio(&[point(0, 100.0), point(1, 100.0)]), 0.0);
+
+        let equity = [
+            point(0, 100.0),
+            point(60_000, 101.0),
+            point(120_000, 100.5),
+            point(180_000, 102.0),
+        ];
+        assert!(sharpe_ratio(&equity) > 0.0);
+    }
+
+    #[test]
+    fn test_build_report_and_write() {
+        let result = BacktestResult {
+            name: "unit".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            initial_equity: 100.0,
+            trades: vec![trade(2.0), trade(-1.0), trade(1.0)],
+            equity: vec![
+                point(0, 100.0),
+                point(60_000, 102.0),
+                point(120_000, 101.0),
+                point(180_000, 102.0),
+            ],
+            basis_samples: vec![1.0, 2.0, 3.0],
+        };
+
+        let report = build_report(&result);
+        assert_eq!(report.summary.total_trades, 3);
+        assert_eq!(report.summary.winning_trades, 2);
+        assert!((report.summary.total_pnl - 2.0).abs() < 1e-9);
+        assert!((report.summary.return_pct - 2.0).abs() < 1e-9);
+
+        let dir = std::env::temp_dir().join(format!("backtest_report_{}", uuid::Uuid::new_v4()));
+        let (json_path, html_path) = write_report(&result, &dir).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(json_path).unwrap()).unwrap();
+        assert_eq!(json["summary"]["total_trades"], 3);
+        let html = std::fs::read_to_string(html_path).unwrap();
+        assert!(html.contains("<polyline"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,270 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use super::engine::{BacktestConfig, PriceSample};
+use super::optimize::{Objective, SweepParams, evaluate, run_sweep};
+use super::report::ReportSummary;
+
+/// 워크포워드 구간 설정 (ms 단위)
+#[derive(Debug, Clone, Copy)]
+pub struct WalkForwardConfig {
+    /// 파라미터 선택에 사용하는 학습 구간 길이
+    pub train_ms: i64,
+    /// 선택된 파라미터를 평가하는 검증 구간 길이 (다음 구간은 이만큼 이동)
+    pub test_ms: i64,
+}
+
+/// 학습/검증 구간 한 쌍 (샘플 인덱스 범위, 끝 제외)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowSplit {
+    pub train: (usize, usize),
+    pub test: (usize, usize),
+}
+
+/// 구간 하나의 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct WalkForwardWindow {
+    pub train_start: i64,
+    pub train_end: i64,
+    pub test_start: i64,
+    pub test_end: i64,
+    /// 학습 구간에서 선택된 파라미터
+    pub params: SweepParams,
+    pub train_score: f64,
+    /// 검증(out-of-sample) 구간 점수
+    pub test_score: f64,
+    pub test_summary: ReportSummary,
+}
+
+/// 워크포워드 전체 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct WalkForwardReport {
+    pub objective: Objective,
+    pub windows: Vec<WalkForwardWindow>,
+    /// 검증 구간 손익 합계
+    pub oos_total_pnl: f64,
+    pub oos_total_trades: usize,
+    /// 검증 구간 중 손익이 양수인 구간 비율 (0~1)
+    pub oos_profitable_ratio: f64,
+    /// 검증 점수 평균 / 학습 점수 평균 (1에 가까울수록 과최적화가 적음)
+    pub efficiency: f64,
+}
+
+/// 시간 기준 롤링 구간 분할
+/// 학습 구간 끝에서 검증 구간이 시작하고, 다음 구간은 검증 길이만큼 이동한다.
+/// 학습/검증 구간에 샘플이 2개 미만이면 건너뛴다.
+pub fn split_windows(samples: &[PriceSample], config: WalkForwardConfig) -> Vec<WindowSplit> {
+    let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+        return Vec::new();
+    };
+    if config.train_ms <= 0 || config.test_ms <= 0 {
+        return Vec::new();
+    }
+
+    let index_of = |time: i64| samples.partition_point(|s| s.time < time);
+    let mut splits = Vec::new();
+    let mut start = first.time;
+    while start + config.train_ms < last.time {
+        let test_start = start + config.train_ms;
+        let test_end = test_start + config.test_ms;
+        let split = WindowSplit {
+            train: (index_of(start), index_of(test_start)),
+            test: (index_of(test_start), index_of(test_end)),
+        };
+        if split.train.1 - split.train.0 >= 2 && split.test.1 - split.test.0 >= 2 {
+            splits.push(split);
+        }
+        start += config.test_ms;
+    }
+    splits
+}
+
+/// 워크포워드 검증
+///
+/// 각 학습 구간에서 목적 함수 기준 최적 파라미터를 고르고,
+/// 바로 다음 검증 구간에서 그 파라미터의 성과를 측정해 합산한다.
+pub async fn run_walk_forward(
+    samples: Arc<Vec<PriceSample>>,
+    base: &BacktestConfig,
+    params: Vec<SweepParams>,
+    objective: Objective,
+    config: WalkForwardConfig,
+) -> eyre::Result<WalkForwardReport> {
+    let mut windows = Vec::new();
+
+    for split in split_windows(&samples, config) {
+        let train: Arc<Vec<PriceSample>> = Arc::new(samples[split.train.0..split.train.1].to_vec());
+        let test = &samples[split.test.0..split.test.1];
+
+        let runs = run_sweep(Arc::clone(&train), base, params.clone(), objective).await?;
+        let Some(best) = runs.first() else {
+            continue;
+        };
+        let oos = evaluate(test, base, best.params, objective);
+
+        windows.push(WalkForwardWindow {
+            train_start: train[0].time,
+            train_end: train[train.len() - 1].time,
+            test_start: test[0].time,
+            test_end: test[test.len() - 1].time,
+            params: best.params,
+            train_score: best.score,
+            test_score: oos.score,
+            test_summary: oos.summary,
+        });
+    }
+
+    let oos_total_pnl = windows.iter().map(|w| w.test_summary.total_pnl).sum();
+    let oos_total_trades = windows.iter().map(|w| w.test_summary.total_trades).sum();
+    let (oos_profitable_ratio, efficiency) = if windows.is_empty() {
+        (0.0, 0.0)
+    } else {
+        let n = windows.len() as f64;
+        let profitable = windows
+            .iter()
+            .filter(|w| w.test_summary.total_pnl > 0.0)
+            .count() as f64;
+        let train_avg = windows.iter().map(|w| w.train_score).sum::<f64>() / n;
+        let test_avg = windows.iter().map(|w| w.test_score).sum::<f64>() / n;
+        let efficiency = if train_avg.abs() > f64::EPSILON {
+            test_avg / train_avg
+        } else {
+            0.0
+        };
+        (profitable / n, efficiency)
+    };
+
+    Ok(WalkForwardReport {
+        objective,
+        windows,
+        oos_total_pnl,
+        oos_total_trades,
+        oos_profitable_ratio,
+        efficiency,
+    })
+}
+
+/// 구간별 결과를 CSV로 저장
+pub fn write_csv(report: &WalkForwardReport, path: impl AsRef<Path>) -> eyre::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut csv = String::from(
+        "window,train_start,train_end,test_start,test_end,entry_bps,exit_bps,notional,stop_bps,train_score,test_score,test_trades,test_pnl,test_max_drawdown_pct\n",
+    );
+    for (i, w) in report.windows.iter().enumerate() {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{:.6},{:.6},{},{:.6},{:.4}\n",
+            i + 1,
+            w.train_start,
+            w.train_end,
+            w.test_start,
+            w.test_end,
+            w.params.entry_bps,
+            w.params.exit_bps,
+            w.params.notional,
+            w.params.stop_bps.map(|v| v.to_string()).unwrap_or_default(),
+            w.train_score,
+            w.test_score,
+            w.test_summary.total_trades,
+            w.test_summary.total_pnl,
+            w.test_summary.max_drawdown_pct
+        ));
+    }
+    std::fs::write(path, csv)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::optimize::ParamGrid;
+
+    fn samples(basis: &[f64]) -> Vec<PriceSample> {
+        basis
+            .iter()
+            .enumerate()
+            .map(|(i, b)| PriceSample {
+                time: i as i64 * 1_000,
+                spot: 100.0,
+                futures: 100.0 * (1.0 + b / 10_000.0),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split_windows() {
+        let data = samples(&[0.0; 10]);
+        let splits = split_windows(
+            &data,
+            WalkForwardConfig {
+                train_ms: 4_000,
+                test_ms: 2_000,
+            },
+        );
+
+        assert_eq!(
+            splits,
+            vec![
+                WindowSplit {
+                    train: (0, 4),
+                    test: (4, 6)
+                },
+                WindowSplit {
+                    train: (2, 6),
+                    test: (6, 8)
+                },
+                WindowSplit {
+                    train: (4, 8),
+                    test: (8, 10)
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_walk_forward_aggregates_out_of_sample() {
+        let data = samples(&[
+            0.0, 10.0, 0.0, 10.0, 0.0, 10.0, 0.0, 10.0, 0.0, 10.0, 0.0, 10.0,
+        ]);
+        let grid = ParamGrid {
+            entry_bps: vec![5.0, 20.0],
+            exit_bps: vec![1.0],
+            notional: vec![100.0],
+            stop_bps: vec![None],
+        };
+        let base = BacktestConfig {
+            fee_bps: 0.0,
+            ..Default::default()
+        };
+
+        let report = run_walk_forward(
+            Arc::new(data),
+            &base,
+            grid.combinations(),
+            Objective::Pnl,
+            WalkForwardConfig {
+                train_ms: 6_000,
+                test_ms: 3_000,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(!report.windows.is_empty());
+        // 학습 구간에서 거래가 발생하는 5bps 진입이 선택된다
+        assert!(report.windows.iter().all(|w| w.params.entry_bps == 5.0));
+        let total: f64 = report
+            .windows
+            .iter()
+            .map(|w| w.test_summary.total_pnl)
+            .sum();
+        assert!((report.oos_total_pnl - total).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,324 @@
+//! BNB 수수료 자산 관리
+//!
+//! Binance 현물 수수료를 BNB로 내면 할인(기본 25%)을 받지만, BNB가 바닥나면 할인이 끊기고
+//! 수수료가 거래 자산에서 빠진다. 시작 시 현물 BNB 차감(`spotBNBBurn`)을 켜고, 주기적으로
+//! 잔고를 확인해 `min_bnb` 아래로 내려가면 `target_bnb`까지 소량 시장가 매수한다.
+//!
+//! 잔고가 줄어든 만큼을 수수료로 쓴 BNB로 보고 당시 가격으로 USDT 환산해 누적한다
+//! (입금/더스트 변환으로 늘어난 잔고는 소비로 보지 않는다). 할인이 켜져 있는 동안
+//! 전략의 현물 수수료율(`spot_fee_discount`)에 할인을 반영해 손익분기점과 손익을 계산한다.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use interface::Qty;
+use serde::Serialize;
+use tracing::{error, info, warn};
+
+use crate::trader::SpotExchangeTrader;
+use crate::trader::binance::BinanceTrader;
+
+/// BNB 가격 조회/매수 심볼
+pub const BNB_SYMBOL: &str = "BNBUSDT";
+
+/// BNB 수수료 관리 설정
+#[derive(Debug, Clone, Copy)]
+pub struct BnbFeeParams {
+    /// 이 잔고(BNB) 아래로 내려가면 보충
+    pub min_bnb: f64,
+    /// 보충 목표 잔고 (BNB)
+    pub target_bnb: f64,
+    /// 한 번에 매수할 최대 금액 (USDT)
+    pub max_buy_usdt: f64,
+    /// 잔고 확인 간격
+    pub check_interval: Duration,
+    /// BNB 차감 시 현물 수수료 할인율 (0.25 = 25%)
+    pub spot_discount: f64,
+}
+
+impl Default for BnbFeeParams {
+    fn default() -> Self {
+        Self {
+            min_bnb: 0.05,
+            target_bnb: 0.1,
+            max_buy_usdt: 20.0,
+            check_interval: Duration::from_secs(300),
+            spot_discount: 0.25,
+        }
+    }
+}
+
+impl BnbFeeParams {
+    /// BNB_FEE_MIN(필수) / BNB_FEE_TARGET (기본 최소의 2배) / BNB_FEE_MAX_BUY_USDT /
+    /// BNB_FEE_CHECK_SECS / BNB_FEE_SPOT_DISCOUNT
+    /// 최소 잔고가 설정되지 않으면 None
+    pub fn from_env() -> Option<Self> {
+        fn var<T: std::str::FromStr>(key: &str) -> Option<T> {
+            std::env::var(key).ok().and_then(|v| v.parse::<T>().ok())
+        }
+        let defaults = Self::default();
+        let min_bnb = var::<f64>("BNB_FEE_MIN").filter(|v| *v > 0.0)?;
+        Some(Self {
+            min_bnb,
+            target_bnb: var::<f64>("BNB_FEE_TARGET")
+                .unwrap_or(min_bnb * 2.0)
+                .max(min_bnb),
+            max_buy_usdt: var("BNB_FEE_MAX_BUY_USDT").unwrap_or(defaults.max_buy_usdt),
+            check_interval: var::<u64>("BNB_FEE_CHECK_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.check_interval),
+            spot_discount: var::<f64>("BNB_FEE_SPOT_DISCOUNT")
+                .unwrap_or(defaults.spot_discount)
+                .clamp(0.0, 1.0),
+        })
+    }
+
+    /// 보충 매수 수량 (보충이 필요 없거나 가격을 모르면 None)
+    pub fn refill_qty(&self, balance_bnb: f64, price_usdt: f64) -> Option<f64> {
+        if balance_bnb >= self.min_bnb || !price_usdt.is_finite() || price_usdt <= 0.0 {
+            return None;
+        }
+        let qty = (self.target_bnb - balance_bnb).min(self.max_buy_usdt / price_usdt);
+        (qty > 0.0).then_some(qty)
+    }
+}
+
+/// BNB 수수료 장부 (`GET /fees/bnb`)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BnbFeeLedger {
+    /// 현물 BNB 차감 설정 (확인 전이면 None)
+    pub burn_enabled: Option<bool>,
+    /// 마지막으로 확인한 잔고 (BNB)
+    pub balance_bnb: Option<f64>,
+    pub price_usdt: f64,
+    /// 수수료로 쓴 것으로 보이는 BNB 누적
+    pub spent_bnb: f64,
+    /// 소비 시점 가격으로 환산한 수수료 누적 (USDT)
+    pub spent_usdt: f64,
+    /// 보충 매수 누적
+    pub bought_bnb: f64,
+    pub bought_usdt: f64,
+    pub refills: u64,
+    pub last_checked: Option<DateTime<Utc>>,
+    pub last_refill: Option<DateTime<Utc>>,
+}
+
+impl BnbFeeLedger {
+    /// 잔고 관찰. 직전 잔고보다 줄어든 만큼을 수수료 소비로 누적하고 그 양을 반환
+    pub fn observe(&mut self, balance_bnb: f64, price_usdt: f64, at: DateTime<Utc>) -> f64 {
+        let spent = self
+            .balance_bnb
+            .map_or(0.0, |prev| (prev - balance_bnb).max(0.0));
+        self.spent_bnb += spent;
+        self.spent_usdt += spent * price_usdt;
+        self.balance_bnb = Some(balance_bnb);
+        self.price_usdt = price_usdt;
+        self.last_checked = Some(at);
+        spent
+    }
+
+    /// 보충 매수 반영 (다음 관찰에서 소비로 잡히지 않도록 잔고에 더함)
+    pub fn record_purchase(&mut self, qty: f64, cost_usdt: f64, at: DateTime<Utc>) {
+        self.bought_bnb += qty;
+        self.bought_usdt += cost_usdt;
+        self.refills += 1;
+        self.last_refill = Some(at);
+        if let Some(balance) = self.balance_bnb.as_mut() {
+            *balance += qty;
+        }
+    }
+}
+
+/// 전역 BNB 수수료 관리자
+#[derive(Debug, Default)]
+pub struct BnbFeeManager {
+    params: RwLock<Option<BnbFeeParams>>,
+    ledger: RwLock<BnbFeeLedger>,
+    started: AtomicBool,
+}
+
+impl BnbFeeManager {
+    /// 현재 적용 중인 현물 수수료 할인율 (BNB 차감이 켜져 있고 잔고가 남아 있을 때만)
+    pub fn spot_fee_discount(&self) -> f64 {
+        let Some(params) = *self.params.read().unwrap() else {
+            return 0.0;
+        };
+        let ledger = self.ledger.read().unwrap();
+        if ledger.burn_enabled == Some(true) && ledger.balance_bnb.is_some_and(|b| b > 0.0) {
+            params.spot_discount
+        } else {
+            0.0
+        }
+    }
+
+    pub fn report(&self) -> BnbFeeLedger {
+        self.ledger.read().unwrap().clone()
+    }
+
+    /// 관리 시작 (BNB 차감 켜기 후 주기적 잔고 확인/보충)
+    /// 시장가 매수를 할 수 있으므로 봇 실행(`run`)에서만 호출한다
+    pub fn start(&'static self, params: BnbFeeParams) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        *self.params.write().unwrap() = Some(params);
+        info!(
+            "BNB 수수료 관리 시작 (최소 {} BNB, 목표 {} BNB, 1회 최대 {} USDT, {}초 간격)",
+            params.min_bnb,
+            params.target_bnb,
+            params.max_buy_usdt,
+            params.check_interval.as_secs()
+        );
+        tokio::spawn(async move {
+            let trader = match BinanceTrader::new() {
+                Ok(trader) => trader,
+                Err(e) => {
+                    error!("BNB 수수료 관리: BinanceTrader 생성 실패: {}", e);
+                    return;
+                }
+            };
+            if let Err(e) = trader.load_spot_exchange_info().await {
+                warn!("BNB 수수료 관리: Spot ExchangeInfo 로드 실패: {}", e);
+            }
+            self.enable_burn(&trader).await;
+            loop {
+                self.check(&trader, &params).await;
+                tokio::time::sleep(params.check_interval).await;
+            }
+        });
+    }
+
+    async fn enable_burn(&self, trader: &BinanceTrader) {
+        match trader.set_spot_bnb_burn(true).await {
+            Ok(status) => {
+                info!("현물 BNB 수수료 차감: {}", status.spot_bnb_burn);
+                self.ledger.write().unwrap().burn_enabled = Some(status.spot_bnb_burn);
+            }
+            Err(e) => warn!("현물 BNB 수수료 차감 설정 실패: {}", e),
+        }
+    }
+
+    async fn check(&self, trader: &BinanceTrader, params: &BnbFeeParams) {
+        let (balance, price) = match tokio::try_join!(
+            trader.get_spot_balance("BNB"),
+            trader.get_spot_price(BNB_SYMBOL)
+        ) {
+            Ok(values) => values,
+            Err(e) => {
+                warn!("BNB 잔고/가격 조회 실패: {}", e);
+                return;
+            }
+        };
+        let spent = self
+            .ledger
+            .write()
+            .unwrap()
+            .observe(balance, price, Utc::now());
+        if spent > 0.0 {
+            info!(
+                "BNB 수수료 소비: {:.8} BNB (≈{:.4} USDT)",
+                spent,
+                spent * price
+            );
+        }
+
+        let Some(qty) = params.refill_qty(balance, price) else {
+            return;
+        };
+        let qty = SpotExchangeTrader::clamp_spot_quantity(trader, BNB_SYMBOL, Qty::new(qty));
+        if qty.is_zero() {
+            warn!(
+                "BNB 보충 수량이 최소 수량보다 작음 (잔고 {:.8} BNB)",
+                balance
+            );
+            return;
+        }
+        info!(
+            "BNB 잔고 부족 ({:.8} < {}), {} BNB 매수",
+            balance, params.min_bnb, qty
+        );
+        match SpotExchangeTrader::buy_spot(trader, BNB_SYMBOL, qty).await {
+            Ok(order) => {
+                let filled = order
+                    .executed_qty
+                    .as_deref()
+                    .and_then(|q| q.parse::<f64>().ok())
+                    .unwrap_or(qty.value());
+                self.ledger
+                    .write()
+                    .unwrap()
+                    .record_purchase(filled, filled * price, Utc::now());
+            }
+            Err(e) => error!("BNB 보충 매수 실패: {}", e),
+        }
+        // 보충 후 BNB 차감이 꺼져 있었으면 다시 켠다
+        if self.ledger.read().unwrap().burn_enabled != Some(true) {
+            self.enable_burn(trader).await;
+        }
+    }
+}
+
+static GLOBAL_BNB_FEE: OnceLock<BnbFeeManager> = OnceLock::new();
+
+/// 전역 BNB 수수료 관리자
+pub fn bnb_fee_manager() -> &'static BnbFeeManager {
+    GLOBAL_BNB_FEE.get_or_init(BnbFeeManager::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refill_qty_capped_by_budget() {
+        let params = BnbFeeParams {
+            min_bnb: 0.05,
+            target_bnb: 0.2,
+            max_buy_usdt: 30.0,
+            ..Default::default()
+        };
+        assert_eq!(params.refill_qty(0.06, 600.0), None);
+        // 0.2 - 0.02 = 0.18 BNB, 예산 30 USDT / 600 = 0.05 BNB
+        assert!((params.refill_qty(0.02, 600.0).unwrap() - 0.05).abs() < 1e-12);
+        assert!((params.refill_qty(0.02, 100.0).unwrap() - 0.18).abs() < 1e-12);
+        assert_eq!(params.refill_qty(0.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_ledger_counts_spend_but_not_refills() {
+        let mut ledger = BnbFeeLedger::default();
+        let now = Utc::now();
+        assert_eq!(ledger.observe(0.1, 600.0, now), 0.0);
+        assert!((ledger.observe(0.09, 600.0, now) - 0.01).abs() < 1e-12);
+        ledger.record_purchase(0.05, 30.0, now);
+        // 매수분은 소비로 잡지 않음
+        assert!((ledger.observe(0.138, 600.0, now) - 0.002).abs() < 1e-12);
+        // 입금으로 늘어난 잔고도 소비 아님
+        assert_eq!(ledger.observe(0.5, 600.0, now), 0.0);
+        assert!((ledger.spent_usdt - 0.012 * 600.0).abs() < 1e-9);
+        assert_eq!(ledger.refills, 1);
+    }
+
+    #[test]
+    fn test_discount_only_when_burn_enabled_with_balance() {
+        let manager = BnbFeeManager::default();
+        assert_eq!(manager.spot_fee_discount(), 0.0);
+        *manager.params.write().unwrap() = Some(BnbFeeParams::default());
+        manager
+            .ledger
+            .write()
+            .unwrap()
+            .observe(0.1, 600.0, Utc::now());
+        assert_eq!(manager.spot_fee_discount(), 0.0);
+        manager.ledger.write().unwrap().burn_enabled = Some(true);
+        assert_eq!(manager.spot_fee_discount(), 0.25);
+        manager
+            .ledger
+            .write()
+            .unwrap()
+            .observe(0.0, 600.0, Utc::now());
+        assert_eq!(manager.spot_fee_discount(), 0.0);
+    }
+}
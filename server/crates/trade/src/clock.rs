@@ -0,0 +1,105 @@
+//! 전략 루프가 쓰는 시계 (현재 시각, 대기)
+//!
+//! 전략은 `Utc::now()`/`tokio::time::sleep`을 직접 부르지 않고 `Clock`을 통해 시간을 얻는다.
+//! 실거래는 `SystemClock`, 테스트/백테스트는 `SimClock`으로 시간을 결정적으로 진행시킨다.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::time::Instant;
+
+#[async_trait]
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+
+    async fn sleep(&self, duration: Duration);
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+/// 벽시계 (`Utc::now`, `tokio::time::sleep`)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// 시뮬레이션 시계: 기준 시각 + tokio 시계 경과 시간
+///
+/// `tokio::time::pause()`(또는 `#[tokio::test(start_paused = true)]`) 상태에서는 sleep이
+/// 즉시 끝나고 그만큼 `now()`도 정확히 진행하므로, 벽시계를 기다리지 않고 며칠치 루프를 돌릴 수 있다.
+/// `set`/`advance`로 sleep 없이 시각을 옮길 수도 있다 (백테스트에서 봉 시각에 맞출 때).
+#[derive(Debug)]
+pub struct SimClock {
+    /// (기준 시각, 그 시각에 해당하는 tokio Instant)
+    origin: Mutex<(DateTime<Utc>, Instant)>,
+}
+
+impl SimClock {
+    pub fn starting_at(start: DateTime<Utc>) -> Self {
+        Self {
+            origin: Mutex::new((start, Instant::now())),
+        }
+    }
+
+    /// 현재 시각을 `at`으로 이동 (이후에는 tokio 시계를 따라 진행)
+    pub fn set(&self, at: DateTime<Utc>) {
+        *self.origin.lock().unwrap() = (at, Instant::now());
+    }
+
+    pub fn advance(&self, by: chrono::Duration) {
+        self.set(self.now() + by);
+    }
+}
+
+#[async_trait]
+impl Clock for SimClock {
+    fn now(&self) -> DateTime<Utc> {
+        let (start, base) = *self.origin.lock().unwrap();
+        let elapsed = chrono::Duration::from_std(base.elapsed()).unwrap_or(chrono::Duration::MAX);
+        start + elapsed
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_sim_clock_follows_paused_tokio_time() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let clock = SimClock::starting_at(start);
+        assert_eq!(clock.now(), start);
+
+        clock.sleep(Duration::from_secs(90)).await;
+        assert_eq!(clock.now(), start + chrono::Duration::seconds(90));
+
+        clock.advance(chrono::Duration::days(2));
+        assert_eq!(
+            clock.now(),
+            start + chrono::Duration::days(2) + chrono::Duration::seconds(90)
+        );
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
@@ -0,0 +1,361 @@
+//! 대화형 콘솔 (`trade console`)
+//!
+//! 실행 중인 봇의 제어 API(TRADE_API_URL, 기본 http://localhost:12091)에 붙어
+//! 한 줄 명령으로 상태 조회, 일시 정지/재개, 전량 청산을 보낸다.
+
+use std::str::FromStr;
+
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+/// 콘솔 도움말
+pub const HELP: &str = "\
+status              제어 상태와 전략별 베이시스/포지션
+basis <SYMBOL|ID>   전략 상세 상태 (예: basis BTCUSDT, basis intra_basis:BTCUSDT)
+balances            거래소별 잔고/노출
+pause [reason]      새 진입 일시 정지
+resume              진입 재개
+flatten [reason]    진입 정지 후 모든 전략의 보유 포지션 청산
+help                도움말
+quit                종료";
+
+/// 콘솔 명령
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsoleCommand {
+    Help,
+    Status,
+    Basis(String),
+    Balances,
+    Pause(Option<String>),
+    Resume,
+    Flatten(Option<String>),
+    Quit,
+}
+
+impl FromStr for ConsoleCommand {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+        let (name, rest) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(name, rest)| (name, rest.trim()));
+        let reason = (!rest.is_empty()).then(|| rest.to_string());
+        match name.to_ascii_lowercase().as_str() {
+            "help" | "?" => Ok(Self::Help),
+            "status" => Ok(Self::Status),
+            "basis" if rest.is_empty() => Err("usage: basis <SYMBOL|ID>".to_string()),
+            "basis" => Ok(Self::Basis(rest.to_string())),
+            "balances" => Ok(Self::Balances),
+            "pause" => Ok(Self::Pause(reason)),
+            "resume" => Ok(Self::Resume),
+            "flatten" => Ok(Self::Flatten(reason)),
+            "quit" | "exit" => Ok(Self::Quit),
+            _ => Err(format!("unknown command: {} (help)", name)),
+        }
+    }
+}
+
+/// 심볼 또는 전략 ID로 실행 중인 전략 찾기 (심볼이면 `<전략>:<SYMBOL>` 모두)
+pub fn resolve_strategies(ids: &[String], target: &str) -> Vec<String> {
+    if target.contains(':') {
+        return ids.iter().filter(|id| *id == target).cloned().collect();
+    }
+    let symbol = target.to_ascii_uppercase();
+    ids.iter()
+        .filter(|id| id.rsplit(':').next() == Some(symbol.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// 전략 상태 한 줄 요약
+pub fn format_strategy_line(state: &Value) -> String {
+    let position = if state["open"].as_bool().unwrap_or(false) {
+        format!(
+            "open {} (unrealized {})",
+            state["dir"].as_str().unwrap_or("?"),
+            unrealized(state)
+        )
+    } else {
+        "flat".to_string()
+    };
+    format!(
+        "{:<28} basis {:>9.2} bps (entry {}, exit {})  {}{}",
+        state["strategy_id"].as_str().unwrap_or("?"),
+        state["basis_bps"].as_f64().unwrap_or(f64::NAN),
+        state["entry_bps"],
+        state["exit_bps"],
+        position,
+        if state["healthy"].as_bool() == Some(false) {
+            "  [STALE]"
+        } else {
+            ""
+        }
+    )
+}
+
+fn unrealized(state: &Value) -> String {
+    let (Some(open), Some(basis)) = (
+        state["last_open_basis_bps"].as_f64(),
+        state["basis_bps"].as_f64(),
+    ) else {
+        return "-".to_string();
+    };
+    match state["dir"].as_str() {
+        Some("carry") => format!("{:+.2} bps", open - basis),
+        Some("reverse") => format!("{:+.2} bps", basis - open),
+        _ => "-".to_string(),
+    }
+}
+
+/// 제어 API 클라이언트
+#[derive(Debug, Clone)]
+pub struct ConsoleClient {
+    base_url: String,
+    http: reqwest::Client,
+    /// 제어 요청(pause/resume/flatten)에 붙이는 토큰
+    control_token: Option<String>,
+}
+
+impl ConsoleClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            http: exchanges::http::http_client(),
+            control_token: None,
+        }
+    }
+
+    pub fn with_control_token(mut self, token: Option<String>) -> Self {
+        self.control_token = token;
+        self
+    }
+
+    /// TRADE_API_URL (기본 http://localhost:12091), TRADE_CONTROL_TOKEN
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("TRADE_API_URL").unwrap_or_else(|_| "http://localhost:12091".to_string()),
+        )
+        .with_control_token(crate::server::control_token_from_env())
+    }
+
+    async fn get(&self, path: &str) -> eyre::Result<Value> {
+        let response = self
+            .http
+            .get(format!("{}{}", self.base_url, path))
+            .send()
+            .await?;
+        Self::json(response).await
+    }
+
+    async fn post(&self, path: &str, reason: Option<&str>) -> eyre::Result<Value> {
+        let mut request = self.http.post(format!("{}{}", self.base_url, path));
+        if let Some(reason) = reason {
+            request = request.query(&[("reason", reason)]);
+        }
+        if let Some(token) = &self.control_token {
+            request = request.bearer_auth(token);
+        }
+        Self::json(request.send().await?).await
+    }
+
+    async fn json(response: reqwest::Response) -> eyre::Result<Value> {
+        let status = response.status();
+        let body: Value = response.json().await?;
+        if !status.is_success() {
+            let error = body["error"].as_str().unwrap_or("unknown error");
+            return Err(eyre::eyre!("서버 응답 오류 ({}): {}", status, error));
+        }
+        Ok(body)
+    }
+
+    async fn strategy_ids(&self) -> eyre::Result<Vec<String>> {
+        let control = self.get("/control").await?;
+        Ok(serde_json::from_value(control["strategies"].clone())?)
+    }
+
+    /// 명령 실행 결과를 출력용 문자열로 반환
+    pub async fn execute(&self, command: &ConsoleCommand) -> eyre::Result<String> {
+        match command {
+            ConsoleCommand::Help => Ok(HELP.to_string()),
+            ConsoleCommand::Quit => Ok(String::new()),
+            ConsoleCommand::Status => {
+                let control = self.get("/control").await?;
+                let mut lines = vec![match control["control"]["paused"].as_object() {
+                    Some(paused) => format!(
+                        "entries PAUSED since {} ({})",
+                        paused["since"].as_str().unwrap_or("?"),
+                        paused["reason"].as_str().unwrap_or("?")
+                    ),
+                    None => "entries active".to_string(),
+                }];
+                let ids: Vec<String> = serde_json::from_value(control["strategies"].clone())?;
+                if ids.is_empty() {
+                    lines.push("no running strategies".to_string());
+                }
+                for id in ids {
+                    let state = self.get(&format!("/strategy/{}/state", id)).await?;
+                    lines.push(format_strategy_line(&state));
+                }
+                Ok(lines.join("\n"))
+            }
+            ConsoleCommand::Basis(target) => {
+                let ids = self.strategy_ids().await?;
+                let matched = resolve_strategies(&ids, target);
+                if matched.is_empty() {
+                    return Err(eyre::eyre!(
+                        "실행 중인 전략 없음: {} (실행 중: {})",
+                        target,
+                        ids.join(", ")
+                    ));
+                }
+                let mut out = Vec::new();
+                for id in matched {
+                    let state = self.get(&format!("/strategy/{}/state", id)).await?;
+                    out.push(serde_json::to_string_pretty(&state)?);
+                }
+                Ok(out.join("\n"))
+            }
+            ConsoleCommand::Balances => {
+                let exposure = self.get("/exposure").await?;
+                let mut lines = vec![format!(
+                    "cash {:.2} USDT, net delta {:.2} USDT, gross {:.2} USDT",
+                    exposure["cash_usdt"].as_f64().unwrap_or(0.0),
+                    exposure["net_delta_usdt"].as_f64().unwrap_or(0.0),
+                    exposure["gross_notional_usdt"].as_f64().unwrap_or(0.0)
+                )];
+                for venue in exposure["venues"].as_array().into_iter().flatten() {
+                    let mut line = format!(
+                        "  {:<10} cash {:>12.2}  spot {:>12.2}  futures net {:>12.2}",
+                        venue["venue"].as_str().unwrap_or("?"),
+                        venue["cash_usdt"].as_f64().unwrap_or(0.0),
+                        venue["spot_notional_usdt"].as_f64().unwrap_or(0.0),
+                        venue["futures_net_usdt"].as_f64().unwrap_or(0.0)
+                    );
+                    if let Some(error) = venue["error"].as_str() {
+                        line.push_str(&format!("  [error: {}]", error));
+                    }
+                    lines.push(line);
+                }
+                Ok(lines.join("\n"))
+            }
+            ConsoleCommand::Pause(reason) => {
+                let body = self.post("/control/pause", reason.as_deref()).await?;
+                Ok(format!(
+                    "entries paused ({})",
+                    body["paused"]["reason"].as_str().unwrap_or("?")
+                ))
+            }
+            ConsoleCommand::Resume => {
+                let body = self.post("/control/resume", None).await?;
+                Ok(if body["resumed"].is_null() {
+                    "entries were not paused".to_string()
+                } else {
+                    "entries resumed".to_string()
+                })
+            }
+            ConsoleCommand::Flatten(reason) => {
+                let body = self.post("/control/flatten", reason.as_deref()).await?;
+                Ok(format!(
+                    "flatten request #{} sent, entries paused",
+                    body["flatten_request"]
+                ))
+            }
+        }
+    }
+}
+
+/// 표준 입력으로 명령을 읽어 실행 (quit 또는 EOF에서 종료)
+pub async fn run_console(client: &ConsoleClient) -> eyre::Result<()> {
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+    println!("trade console ({}), 'help' for commands", client.base_url);
+    loop {
+        stdout.write_all(b"trade> ").await?;
+        stdout.flush().await?;
+        let Some(line) = lines.next_line().await? else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let command = match line.parse::<ConsoleCommand>() {
+            Ok(command) => command,
+            Err(e) => {
+                println!("{}", e);
+                continue;
+            }
+        };
+        match command {
+            ConsoleCommand::Quit => break,
+            ConsoleCommand::Flatten(_) => {
+                // 되돌릴 수 없으므로 한 번 더 확인
+                stdout
+                    .write_all(b"flatten all open positions? [y/N] ")
+                    .await?;
+                stdout.flush().await?;
+                let answer = lines.next_line().await?.unwrap_or_default();
+                if !answer.trim().eq_ignore_ascii_case("y") {
+                    println!("cancelled");
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        match client.execute(&command).await {
+            Ok(output) => println!("{}", output),
+            Err(e) => println!("error: {}", e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands_and_resolve_strategies() {
+        assert_eq!("status".parse(), Ok(ConsoleCommand::Status));
+        assert_eq!(
+            "  basis   btcusdt ".parse(),
+            Ok(ConsoleCommand::Basis("btcusdt".to_string()))
+        );
+        assert!("basis".parse::<ConsoleCommand>().is_err());
+        assert_eq!("PAUSE".parse(), Ok(ConsoleCommand::Pause(None)));
+        assert_eq!(
+            "flatten funding flip".parse(),
+            Ok(ConsoleCommand::Flatten(Some("funding flip".to_string())))
+        );
+        assert!("sell everything".parse::<ConsoleCommand>().is_err());
+
+        let ids = vec![
+            "cross_basis:BTCUSDT".to_string(),
+            "intra_basis:BTCUSDT".to_string(),
+            "intra_basis:ETHUSDT".to_string(),
+        ];
+        assert_eq!(resolve_strategies(&ids, "btcusdt").len(), 2);
+        assert_eq!(
+            resolve_strategies(&ids, "intra_basis:ETHUSDT"),
+            vec!["intra_basis:ETHUSDT".to_string()]
+        );
+        assert!(resolve_strategies(&ids, "SOLUSDT").is_empty());
+
+        let line = format_strategy_line(&serde_json::json!({
+            "strategy_id": "intra_basis:BTCUSDT",
+            "basis_bps": 5.0,
+            "entry_bps": 4.0,
+            "exit_bps": 0.0,
+            "open": true,
+            "dir": "carry",
+            "last_open_basis_bps": 6.5,
+            "since_last_tick_ms": 120,
+            "healthy": true,
+        }));
+        assert!(
+            line.contains("open carry (unrealized +1.50 bps)"),
+            "{}",
+            line
+        );
+    }
+}
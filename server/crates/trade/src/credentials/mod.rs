@@ -0,0 +1,342 @@
+//! 거래소 API 키 상태 점검
+//!
+//! 설정된 거래소마다 가벼운 인증 API(잔고/계정 조회)를 한 번씩 호출해
+//! 키가 유효한지, 만료·오타로 거부되는지, 권한(선물 등)이 빠졌는지 분류한다.
+//! 진입 도중 주문이 인증 오류로 실패하기 전에 만료된 키를 잡기 위한 것으로,
+//! 응답에는 키 끝 4자리만 남기고 시크릿은 절대 포함하지 않는다.
+
+use std::future::Future;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use exchanges::{AssetExchange, BithumbClient};
+use interface::ExchangeError;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::trader::binance::{BinanceAccount, BinanceAccounts, BinanceFuturesApi};
+use crate::trader::{BybitOrderApi, ExchangeOrderApi, MarketKind, OkxOrderApi};
+
+/// 응답에 남기는 오류 메시지 최대 길이
+const MAX_DETAIL_LEN: usize = 200;
+
+/// 키 점검 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialState {
+    /// 인증 호출 성공
+    Valid,
+    /// 키/서명 거부 (만료, 삭제, 오타, IP 제한 포함)
+    Invalid,
+    /// 키는 유효하지만 해당 기능 권한 없음
+    PermissionMissing,
+    /// 환경 변수에 키가 없음
+    NotConfigured,
+    /// 네트워크 오류 등으로 판정 불가
+    Unknown,
+}
+
+/// 거래소(계정, 범위) 하나의 점검 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct CredentialCheck {
+    pub exchange: String,
+    /// 계정 라벨 (Binance 서브 계정 구분, 그 외 "main")
+    pub account: String,
+    /// 점검한 기능 ("spot" | "futures" | "account")
+    pub scope: String,
+    pub state: CredentialState,
+    /// API 키 끝 4자리 (예: "****a1b2")
+    pub key_hint: Option<String>,
+    /// 실패 사유 (거래소 오류 메시지, 잘라서 보관)
+    pub detail: Option<String>,
+    pub latency_ms: Option<u64>,
+}
+
+/// `GET /credentials/status` 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct CredentialReport {
+    pub checks: Vec<CredentialCheck>,
+    /// 설정된 키가 모두 유효한지 (미설정 거래소는 제외)
+    pub all_valid: bool,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl CredentialReport {
+    pub fn new(checks: Vec<CredentialCheck>) -> Self {
+        let all_valid = checks.iter().all(|c| {
+            matches!(
+                c.state,
+                CredentialState::Valid | CredentialState::NotConfigured
+            )
+        });
+        Self {
+            checks,
+            all_valid,
+            checked_at: Utc::now(),
+        }
+    }
+}
+
+/// API 키 표시용 마스킹 (끝 4자리만)
+pub fn mask_key(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("****{}", tail)
+}
+
+fn key_hint(var: &str) -> Option<String> {
+    std::env::var(var).ok().map(|key| mask_key(&key))
+}
+
+/// 거래소 오류 메시지로 키 상태 분류
+///
+/// - Binance: -2014/-2015/-1022/-2008 (-2015는 키·IP·권한 구분 없이 내려와 Invalid로 본다)
+/// - Bybit: 10003/10004/10010/33004 거부, 10005 권한 없음
+/// - OKX: 50110/50111/50113/50119 거부, 50114/50120 권한 없음
+/// - Bithumb: invalid_access_key/jwt_verification/expired_access_key/no_authorization_ip 거부, out_of_scope 권한 없음
+pub fn classify_error(message: &str) -> CredentialState {
+    const PERMISSION: [&str; 5] = [
+        "Bybit API error 10005:",
+        "OKX API error 50114:",
+        "OKX API error 50120:",
+        "out_of_scope",
+        "permission denied",
+    ];
+    const INVALID: [&str; 15] = [
+        "\"code\":-2014",
+        "\"code\":-2015",
+        "\"code\":-1022",
+        "\"code\":-2008",
+        "Bybit API error 10003:",
+        "Bybit API error 10004:",
+        "Bybit API error 10010:",
+        "Bybit API error 33004:",
+        "OKX API error 50110:",
+        "OKX API error 50111:",
+        "OKX API error 50113:",
+        "OKX API error 50119:",
+        "invalid_access_key",
+        "jwt_verification",
+        "expired_access_key",
+    ];
+    let lower = message.to_lowercase();
+    if PERMISSION
+        .iter()
+        .any(|p| message.contains(p) || lower.contains(p))
+    {
+        CredentialState::PermissionMissing
+    } else if INVALID.iter().any(|p| message.contains(p))
+        || lower.contains("no_authorization_ip")
+        || lower.contains("status 401")
+    {
+        CredentialState::Invalid
+    } else {
+        CredentialState::Unknown
+    }
+}
+
+fn not_configured(exchange: &str, scope: &str, error: &ExchangeError) -> CredentialCheck {
+    CredentialCheck {
+        exchange: exchange.to_string(),
+        account: "main".to_string(),
+        scope: scope.to_string(),
+        state: CredentialState::NotConfigured,
+        key_hint: None,
+        detail: Some(error.to_string()),
+        latency_ms: None,
+    }
+}
+
+/// 인증 호출 한 번 실행 후 결과 분류
+async fn probe<T, F>(
+    exchange: &str,
+    account: &str,
+    scope: &str,
+    key_hint: Option<String>,
+    call: F,
+) -> CredentialCheck
+where
+    F: Future<Output = Result<T, ExchangeError>>,
+{
+    let started = Instant::now();
+    let result = call.await;
+    let latency_ms = Some(started.elapsed().as_millis() as u64);
+    let (state, detail) = match result {
+        Ok(_) => (CredentialState::Valid, None),
+        Err(e) => {
+            let message = e.to_string();
+            let state = classify_error(&message);
+            warn!(
+                "{} {} API 키 점검 실패 ({:?}): {}",
+                exchange, scope, state, message
+            );
+            (state, Some(message.chars().take(MAX_DETAIL_LEN).collect()))
+        }
+    };
+    CredentialCheck {
+        exchange: exchange.to_string(),
+        account: account.to_string(),
+        scope: scope.to_string(),
+        state,
+        key_hint,
+        detail,
+        latency_ms,
+    }
+}
+
+fn binance_key_hint(account: &BinanceAccount) -> Option<String> {
+    if account.label == crate::trader::binance::account::DEFAULT_ACCOUNT_LABEL {
+        key_hint("BINANCE_API_KEY")
+    } else {
+        key_hint(&format!("BINANCE_API_KEY_{}", account.label.to_uppercase()))
+    }
+}
+
+/// Binance: 스팟 계정 조회 + 선물 잔고 조회 (레그별 계정)
+async fn check_binance() -> Vec<CredentialCheck> {
+    let accounts = match BinanceAccounts::from_env() {
+        Ok(accounts) => accounts,
+        Err(e) => return vec![not_configured("binance", "account", &e)],
+    };
+    let spot = &accounts.spot;
+    let futures = &accounts.futures;
+    let futures_api = BinanceFuturesApi::new(futures.client.clone());
+    let (spot_check, futures_check) = tokio::join!(
+        probe(
+            "binance",
+            &spot.label,
+            "spot",
+            binance_key_hint(spot),
+            spot.client.fetch_spots(),
+        ),
+        probe(
+            "binance",
+            &futures.label,
+            "futures",
+            binance_key_hint(futures),
+            futures_api.get_balance(),
+        ),
+    );
+    vec![spot_check, futures_check]
+}
+
+/// Bithumb: 전체 계좌 조회
+async fn check_bithumb() -> Vec<CredentialCheck> {
+    match BithumbClient::with_credentials() {
+        Ok(client) => vec![
+            probe(
+                "bithumb",
+                "main",
+                "spot",
+                key_hint("BITHUMB_API_KEY"),
+                client.fetch_spots(),
+            )
+            .await,
+        ],
+        Err(e) => vec![not_configured("bithumb", "spot", &e)],
+    }
+}
+
+/// Bybit: 통합 계정 USDT 잔고 조회
+async fn check_bybit() -> Vec<CredentialCheck> {
+    match BybitOrderApi::from_env() {
+        Ok(api) => vec![
+            probe(
+                "bybit",
+                "main",
+                "account",
+                key_hint("BYBIT_API_KEY"),
+                api.get_balance(MarketKind::Futures, "USDT"),
+            )
+            .await,
+        ],
+        Err(e) => vec![not_configured("bybit", "account", &e)],
+    }
+}
+
+/// OKX: 거래 계정 USDT 잔고 조회
+async fn check_okx() -> Vec<CredentialCheck> {
+    match OkxOrderApi::from_env() {
+        Ok(api) => vec![
+            probe(
+                "okx",
+                "main",
+                "account",
+                key_hint("OKX_API_KEY"),
+                api.get_balance(MarketKind::Futures, "USDT"),
+            )
+            .await,
+        ],
+        Err(e) => vec![not_configured("okx", "account", &e)],
+    }
+}
+
+/// 모든 거래소 API 키 점검 (거래소끼리는 동시에 호출)
+pub async fn check_credentials() -> CredentialReport {
+    let (binance, bithumb, bybit, okx) =
+        tokio::join!(check_binance(), check_bithumb(), check_bybit(), check_okx());
+    CredentialReport::new(
+        binance
+            .into_iter()
+            .chain(bithumb)
+            .chain(bybit)
+            .chain(okx)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_error_and_mask_key() {
+        assert_eq!(
+            classify_error(
+                "Binance API HTTP error: status 401 Unauthorized, response: {\"code\":-2015,\"msg\":\"Invalid API-key, IP, or permissions for action.\"}"
+            ),
+            CredentialState::Invalid
+        );
+        assert_eq!(
+            classify_error(
+                "Bybit API error 10005: Permission denied, please check your API key permissions."
+            ),
+            CredentialState::PermissionMissing
+        );
+        assert_eq!(
+            classify_error("Bybit API error 33004: Your api key has expired."),
+            CredentialState::Invalid
+        );
+        assert_eq!(
+            classify_error("OKX API error 50120: API key doesn't have permission "),
+            CredentialState::PermissionMissing
+        );
+        assert_eq!(
+            classify_error(
+                "Bithumb API HTTP error: status 401 Unauthorized, response: {\"error\":{\"name\":\"invalid_access_key\"}}"
+            ),
+            CredentialState::Invalid
+        );
+        assert_eq!(
+            classify_error("http error: error sending request"),
+            CredentialState::Unknown
+        );
+
+        assert_eq!(mask_key("abcdefgh12345678"), "****5678");
+        assert_eq!(mask_key("short"), "****");
+
+        let report = CredentialReport::new(vec![CredentialCheck {
+            exchange: "okx".to_string(),
+            account: "main".to_string(),
+            scope: "account".to_string(),
+            state: CredentialState::NotConfigured,
+            key_hint: None,
+            detail: None,
+            latency_ms: None,
+        }]);
+        assert!(report.all_valid);
+    }
+}
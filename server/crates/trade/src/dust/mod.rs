@@ -0,0 +1,457 @@
+//! 소액 잔고(더스트) 정리
+//!
+//! 매매를 반복하면 LOT_SIZE 미만 자투리 잔고가 쌓인다.
+//! - Binance: 더스트 변환(`POST /sapi/v1/asset/dust`)으로 BNB 전환
+//! - Bithumb: 평가액이 `bithumb_max_krw` 이하인 잔고를 KRW로 시장가 매도
+//!   (최소 주문 금액 미만은 팔 수 없으므로 건너뛴 것으로 기록)
+//!
+//! 실행 중인 전략의 베이스 자산과 `exclude` 자산은 건드리지 않는다.
+//! 결과는 자산마다 `dust_sweep_records` 테이블에 남긴다.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::Utc;
+use exchanges::{AssetExchange, BithumbClient};
+use interface::Qty;
+use tracing::{error, info, warn};
+
+use crate::arbitrage::live::strategy_states;
+use crate::record::{DustSweepRecord, DustSweepStatus, save_dust_sweep_record_safe};
+use crate::trader::binance::{BinanceTrader, DustCandidate};
+use crate::trader::{SpotExchangeTrader, bithumb::BithumbTrader};
+
+/// 전략 심볼에서 베이스 자산을 떼어낼 때 쓰는 호가 자산
+const QUOTE_ASSETS: [&str; 5] = ["USDT", "USDC", "FDUSD", "BUSD", "KRW"];
+
+/// 더스트 정리 설정
+#[derive(Debug, Clone)]
+pub struct DustSweepParams {
+    /// 정리하지 않을 자산 (현금성 자산은 항상 제외)
+    pub exclude: Vec<String>,
+    /// Bithumb에서 이 평가액(KRW) 이하인 잔고만 소액으로 본다
+    pub bithumb_max_krw: f64,
+    /// Bithumb 최소 주문 금액 (KRW)
+    pub bithumb_min_order_krw: f64,
+    /// 주기 실행 간격 (None이면 `trade sweep-dust`로만 실행)
+    pub interval: Option<Duration>,
+}
+
+impl Default for DustSweepParams {
+    fn default() -> Self {
+        Self {
+            exclude: vec!["BNB".to_string()],
+            bithumb_max_krw: 10_000.0,
+            bithumb_min_order_krw: 5_000.0,
+            interval: None,
+        }
+    }
+}
+
+impl DustSweepParams {
+    /// DUST_EXCLUDE (쉼표 구분, 기본 BNB) / DUST_BITHUMB_MAX_KRW / DUST_BITHUMB_MIN_ORDER_KRW /
+    /// DUST_SWEEP_INTERVAL_HOURS
+    pub fn from_env() -> Self {
+        fn var<T: std::str::FromStr>(key: &str) -> Option<T> {
+            std::env::var(key).ok().and_then(|v| v.parse::<T>().ok())
+        }
+        let defaults = Self::default();
+        Self {
+            exclude: std::env::var("DUST_EXCLUDE")
+                .map(|v| {
+                    v.split(',')
+                        .map(|s| s.trim().to_uppercase())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or(defaults.exclude),
+            bithumb_max_krw: var("DUST_BITHUMB_MAX_KRW").unwrap_or(defaults.bithumb_max_krw),
+            bithumb_min_order_krw: var("DUST_BITHUMB_MIN_ORDER_KRW")
+                .unwrap_or(defaults.bithumb_min_order_krw),
+            interval: var::<u64>("DUST_SWEEP_INTERVAL_HOURS")
+                .filter(|hours| *hours > 0)
+                .map(|hours| Duration::from_secs(hours * 3600)),
+        }
+    }
+
+    /// 건드리지 않을 자산 (설정 + 현금성 자산 + 실행 중인 전략의 베이스 자산)
+    pub fn protected_assets(&self, strategy_symbols: &[String]) -> HashSet<String> {
+        let mut protected: HashSet<String> = self.exclude.iter().cloned().collect();
+        protected.extend(QUOTE_ASSETS.iter().map(|q| q.to_string()));
+        protected.extend(strategy_symbols.iter().filter_map(|s| base_asset(s)));
+        protected
+    }
+}
+
+/// 심볼의 베이스 자산 (BTCUSDT, BTC-KRW → BTC)
+fn base_asset(symbol: &str) -> Option<String> {
+    let symbol = symbol.to_uppercase().replace(['-', '_'], "");
+    QUOTE_ASSETS
+        .iter()
+        .find_map(|quote| {
+            symbol
+                .strip_suffix(quote)
+                .or_else(|| symbol.strip_prefix(quote))
+        })
+        .filter(|base| !base.is_empty())
+        .map(str::to_string)
+}
+
+/// Bithumb 잔고 하나에 대한 처리
+#[derive(Debug, Clone, PartialEq)]
+pub enum BithumbDustAction {
+    /// 소액 아님 (그대로 둠, 기록 안 함)
+    Keep,
+    /// 시장가 매도
+    Sell,
+    /// 소액이지만 팔 수 없음
+    Skip(String),
+}
+
+/// 평가액 기준 Bithumb 잔고 처리 결정
+pub fn plan_bithumb_dust(value_krw: f64, params: &DustSweepParams) -> BithumbDustAction {
+    if !value_krw.is_finite() || value_krw <= 0.0 || value_krw > params.bithumb_max_krw {
+        BithumbDustAction::Keep
+    } else if value_krw < params.bithumb_min_order_krw {
+        BithumbDustAction::Skip(format!(
+            "value {:.0} KRW below min order {:.0} KRW",
+            value_krw, params.bithumb_min_order_krw
+        ))
+    } else {
+        BithumbDustAction::Sell
+    }
+}
+
+/// Binance 더스트 후보 중 변환할 자산과 보호 자산으로 건너뛸 후보 분리
+pub fn split_binance_dust(
+    candidates: Vec<DustCandidate>,
+    protected: &HashSet<String>,
+) -> (Vec<DustCandidate>, Vec<DustCandidate>) {
+    candidates
+        .into_iter()
+        .filter(|c| c.amount_free > 0.0)
+        .partition(|c| !protected.contains(&c.asset))
+}
+
+fn record(
+    exchange: &str,
+    asset: &str,
+    amount: f64,
+    target_asset: &str,
+    status: DustSweepStatus,
+    detail: Option<String>,
+) -> DustSweepRecord {
+    DustSweepRecord {
+        swept_at: Utc::now(),
+        exchange: exchange.to_string(),
+        asset: asset.to_string(),
+        amount,
+        target_asset: target_asset.to_string(),
+        received_amount: 0.0,
+        fee: 0.0,
+        status,
+        detail,
+    }
+}
+
+/// Binance 소액 자산을 BNB로 변환
+pub async fn sweep_binance(
+    protected: &HashSet<String>,
+    dry_run: bool,
+) -> eyre::Result<Vec<DustSweepRecord>> {
+    let trader = BinanceTrader::new().map_err(|e| eyre::eyre!("BinanceTrader 생성 실패: {}", e))?;
+    let candidates = trader
+        .get_dust_candidates()
+        .await
+        .map_err(|e| eyre::eyre!("더스트 후보 조회 실패: {}", e))?;
+    let (convert, protected_candidates) = split_binance_dust(candidates, protected);
+    for candidate in &protected_candidates {
+        info!(
+            "Binance 더스트 보호 자산 건너뜀: {} {}",
+            candidate.amount_free, candidate.asset
+        );
+    }
+    if convert.is_empty() {
+        info!("Binance: BNB로 변환할 소액 자산이 없습니다.");
+        return Ok(Vec::new());
+    }
+
+    if dry_run {
+        return Ok(convert
+            .iter()
+            .map(|c| DustSweepRecord {
+                received_amount: c.to_bnb,
+                ..record(
+                    "binance",
+                    &c.asset,
+                    c.amount_free,
+                    "BNB",
+                    DustSweepStatus::Converted,
+                    Some("dry-run".to_string()),
+                )
+            })
+            .collect());
+    }
+
+    let assets: Vec<String> = convert.iter().map(|c| c.asset.clone()).collect();
+    info!("Binance 더스트 → BNB 변환: {}", assets.join(", "));
+    let response = match trader.convert_dust_to_bnb(&assets).await {
+        Ok(response) => response,
+        Err(e) => {
+            error!("Binance 더스트 변환 실패: {}", e);
+            return Ok(convert
+                .iter()
+                .map(|c| {
+                    record(
+                        "binance",
+                        &c.asset,
+                        c.amount_free,
+                        "BNB",
+                        DustSweepStatus::Failed,
+                        Some(e.to_string()),
+                    )
+                })
+                .collect());
+        }
+    };
+    info!(
+        "Binance 더스트 변환 완료: {:.8} BNB (수수료 {:.8} BNB)",
+        response.total_transfered, response.total_service_charge
+    );
+
+    Ok(convert
+        .iter()
+        .map(|c| {
+            match response.results.iter().find(|r| r.from_asset == c.asset) {
+                Some(result) => DustSweepRecord {
+                    amount: result.amount,
+                    received_amount: result.transfered_amount,
+                    fee: result.service_charge_amount,
+                    ..record(
+                        "binance",
+                        &c.asset,
+                        c.amount_free,
+                        "BNB",
+                        DustSweepStatus::Converted,
+                        Some(format!("tranId {}", result.tran_id)),
+                    )
+                },
+                // 같은 자산은 6시간에 한 번만 변환되므로 결과에서 빠질 수 있다
+                None => record(
+                    "binance",
+                    &c.asset,
+                    c.amount_free,
+                    "BNB",
+                    DustSweepStatus::Skipped,
+                    Some("not in dust transfer result".to_string()),
+                ),
+            }
+        })
+        .collect())
+}
+
+/// Bithumb 소액 잔고를 KRW로 매도
+pub async fn sweep_bithumb(
+    params: &DustSweepParams,
+    protected: &HashSet<String>,
+    dry_run: bool,
+) -> eyre::Result<Vec<DustSweepRecord>> {
+    let trader = BithumbTrader::new().map_err(|e| eyre::eyre!("BithumbTrader 생성 실패: {}", e))?;
+    let client = BithumbClient::with_credentials()?;
+    let assets = client
+        .fetch_spots()
+        .await
+        .map_err(|e| eyre::eyre!("스팟 자산 조회 실패: {}", e))?;
+
+    let mut records = Vec::new();
+    for asset in assets
+        .iter()
+        .filter(|a| a.available > 0.0 && !protected.contains(&a.currency))
+    {
+        let symbol = format!("{}-KRW", asset.currency);
+        let price = match trader.get_spot_price(&symbol).await {
+            Ok(price) => price.value(),
+            Err(e) => {
+                warn!("{} 가격 조회 실패, 건너뜀: {}", symbol, e);
+                continue;
+            }
+        };
+        let value_krw = asset.available * price;
+        let qty = match plan_bithumb_dust(value_krw, params) {
+            BithumbDustAction::Keep => continue,
+            BithumbDustAction::Skip(reason) => {
+                records.push(record(
+                    "bithumb",
+                    &asset.currency,
+                    asset.available,
+                    "KRW",
+                    DustSweepStatus::Skipped,
+                    Some(reason),
+                ));
+                continue;
+            }
+            BithumbDustAction::Sell => {
+                trader.clamp_spot_quantity(&symbol, Qty::new(asset.available))
+            }
+        };
+        if qty.is_zero() {
+            records.push(record(
+                "bithumb",
+                &asset.currency,
+                asset.available,
+                "KRW",
+                DustSweepStatus::Skipped,
+                Some("quantity below step size".to_string()),
+            ));
+            continue;
+        }
+
+        if dry_run {
+            records.push(DustSweepRecord {
+                received_amount: qty.value() * price,
+                ..record(
+                    "bithumb",
+                    &asset.currency,
+                    qty.value(),
+                    "KRW",
+                    DustSweepStatus::Sold,
+                    Some("dry-run".to_string()),
+                )
+            });
+            continue;
+        }
+
+        info!(
+            "Bithumb 소액 잔고 매도: {} {} (≈{:.0} KRW)",
+            qty, asset.currency, value_krw
+        );
+        match trader.sell_spot(&symbol, qty).await {
+            // 받은 KRW는 주문 시점 가격 기준 추정치
+            Ok(order) => records.push(DustSweepRecord {
+                received_amount: qty.value() * price,
+                ..record(
+                    "bithumb",
+                    &asset.currency,
+                    qty.value(),
+                    "KRW",
+                    DustSweepStatus::Sold,
+                    order.order_id.map(|id| format!("order {}", id)),
+                )
+            }),
+            Err(e) => {
+                error!("{} {} 매도 실패: {}", asset.currency, qty, e);
+                records.push(record(
+                    "bithumb",
+                    &asset.currency,
+                    qty.value(),
+                    "KRW",
+                    DustSweepStatus::Failed,
+                    Some(e.to_string()),
+                ));
+            }
+        }
+
+        // API 레이트 리밋 방지를 위한 짧은 대기
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    Ok(records)
+}
+
+/// 모든 거래소의 소액 잔고 정리. dry_run이 아니면 결과를 기록한다
+pub async fn sweep_all(params: &DustSweepParams, dry_run: bool) -> Vec<DustSweepRecord> {
+    let registry = strategy_states();
+    let symbols: Vec<String> = registry
+        .ids()
+        .iter()
+        .filter_map(|id| registry.get(id))
+        .map(|report| report.state.symbol)
+        .collect();
+    let protected = params.protected_assets(&symbols);
+
+    let mut records = Vec::new();
+    match sweep_binance(&protected, dry_run).await {
+        Ok(swept) => records.extend(swept),
+        Err(e) => error!("Binance 더스트 정리 실패: {}", e),
+    }
+    match sweep_bithumb(params, &protected, dry_run).await {
+        Ok(swept) => records.extend(swept),
+        Err(e) => error!("Bithumb 소액 잔고 정리 실패: {}", e),
+    }
+
+    if !dry_run {
+        for record in &records {
+            save_dust_sweep_record_safe(record).await;
+        }
+    }
+    records
+}
+
+/// DUST_SWEEP_INTERVAL_HOURS가 설정되어 있으면 주기적으로 소액 잔고 정리 (첫 정리는 한 주기 뒤)
+/// 실제 전환을 하므로 봇 실행(`run`)에서만 호출한다
+pub fn start_dust_sweep_job(params: DustSweepParams) {
+    let Some(interval) = params.interval else {
+        return;
+    };
+    info!("소액 잔고 정리: {}시간마다", interval.as_secs() / 3600);
+    tokio::spawn(async move {
+        // 첫 정리는 한 주기 뒤 (시작하자마자 실제 전환하지 않도록)
+        let mut ticker =
+            tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        loop {
+            ticker.tick().await;
+            let records = sweep_all(&params, false).await;
+            info!("소액 잔고 정리: {}건", records.len());
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(asset: &str, amount_free: f64) -> DustCandidate {
+        DustCandidate {
+            asset: asset.to_string(),
+            amount_free,
+            to_btc: 0.00001,
+            to_bnb: 0.0005,
+        }
+    }
+
+    #[test]
+    fn test_protected_assets_and_binance_split() {
+        let params = DustSweepParams::default();
+        let protected = params.protected_assets(&["BTCUSDT".to_string(), "ETH-KRW".to_string()]);
+        for asset in ["BNB", "USDT", "KRW", "BTC", "ETH"] {
+            assert!(protected.contains(asset), "{}", asset);
+        }
+        assert!(!protected.contains("XRP"));
+        assert_eq!(base_asset("USDT"), None);
+
+        let (convert, skipped) = split_binance_dust(
+            vec![
+                candidate("XRP", 0.3),
+                candidate("BTC", 0.000001),
+                candidate("ADA", 0.0),
+            ],
+            &protected,
+        );
+        assert_eq!(convert, vec![candidate("XRP", 0.3)]);
+        assert_eq!(skipped, vec![candidate("BTC", 0.000001)]);
+    }
+
+    #[test]
+    fn test_plan_bithumb_dust_thresholds() {
+        let params = DustSweepParams::default();
+        assert_eq!(
+            plan_bithumb_dust(50_000.0, &params),
+            BithumbDustAction::Keep
+        );
+        assert_eq!(plan_bithumb_dust(7_000.0, &params), BithumbDustAction::Sell);
+        assert!(matches!(
+            plan_bithumb_dust(1_200.0, &params),
+            BithumbDustAction::Skip(reason) if reason.contains("below min order")
+        ));
+        assert_eq!(plan_bithumb_dust(0.0, &params), BithumbDustAction::Keep);
+    }
+}
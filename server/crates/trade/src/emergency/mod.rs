@@ -1,5 +1,6 @@
 use color_eyre::eyre;
 use exchanges::{AssetExchange, BinanceClient, BithumbClient};
+use interface::Qty;
 use tracing::{error, info, warn};
 
 use crate::trader::{binance::BinanceTrader, bithumb::BithumbTrader, SpotExchangeTrader};
@@ -66,7 +67,7 @@ pub async fn liquidate_binance() -> eyre::Result<()> {
         }
 
         // 시장가 매도 주문
-        match trader.sell_spot(&symbol, qty).await {
+        match trader.sell_spot(&symbol, Qty::new(qty)).await {
             Ok(order) => {
                 info!(
                     "{} {} 매도 성공: order_id={:?}, executed_qty={:?}",
@@ -181,8 +182,8 @@ pub async fn liquidate_bithumb() -> eyre::Result<()> {
         info!("{} {} -> KRW 변환 시도...", available, currency);
 
         // 수량 클램프
-        let qty = trader.clamp_spot_quantity(&symbol, available);
-        if qty <= 0.0 {
+        let qty = trader.clamp_spot_quantity(&symbol, Qty::new(available));
+        if qty.is_zero() {
             warn!(
                 "{}의 수량이 너무 작아서 거래할 수 없습니다. (available: {})",
                 currency, available
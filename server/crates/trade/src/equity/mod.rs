@@ -0,0 +1,534 @@
+//! 계좌 평가액(에쿼티) 곡선과 드로다운 추적
+//!
+//! 주기적으로 모든 거래소 잔고와 선물 미실현 손익을 USDT(및 KRW)로 평가해 `equity_points`
+//! 테이블에 쌓고, 최근 구간 고점 대비 하락률(롤링 드로다운)을 계산한다. 드로다운이
+//! 설정한 한도를 넘으면 서킷 브레이커가 작동하고, 전략은 `equity_tracker().entry_block()`으로
+//! 확인해 재가동(`POST /equity/breaker/rearm`)될 때까지 새 진입을 멈춘다.
+//!
+//! 평가액 = 현물 잔고(현금성 자산 포함) + 선물 지갑 잔고 + 선물 교차 마진 미실현 손익.
+//! 일부 거래소 조회에 실패한 표본은 평가액이 실제보다 작게 잡혀 가짜 드로다운이 되므로 버린다.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::{error, info, warn};
+
+use crate::exposure::{PriceBook, VenueHoldings, fetch_holdings, fetch_price_book, is_cash};
+use crate::notification::{AlertLevel, notification_center};
+use crate::record::{EquityRecord, get_equity_repository, save_equity_record_safe};
+
+/// 드로다운 서킷 브레이커 알림 종류
+pub const DRAWDOWN_BREAKER_ALERT: &str = "drawdown_breaker";
+
+const DEFAULT_INTERVAL_SECS: u64 = 300;
+const DEFAULT_WINDOW_HOURS: i64 = 24;
+/// 메모리에 유지하는 최대 표본 수 (기본 5분 간격이면 약 35일)
+const MAX_POINTS: usize = 10_000;
+
+/// 에쿼티 표본 수집 설정
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EquityParams {
+    /// 평가 간격
+    pub interval: Duration,
+    /// 롤링 드로다운의 고점 구간
+    pub window: chrono::Duration,
+    /// 서킷 브레이커 드로다운 한도 (%, None이면 사용 안 함)
+    pub max_drawdown_pct: Option<f64>,
+}
+
+impl Default for EquityParams {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(DEFAULT_INTERVAL_SECS),
+            window: chrono::Duration::hours(DEFAULT_WINDOW_HOURS),
+            max_drawdown_pct: None,
+        }
+    }
+}
+
+impl EquityParams {
+    /// `EQUITY_SAMPLE_INTERVAL_SECS`가 설정되어 있을 때만 Some (수집은 선택 기능)
+    /// `EQUITY_DRAWDOWN_WINDOW_HOURS`(기본 24), `EQUITY_MAX_DRAWDOWN_PCT`(기본 없음)
+    pub fn from_env() -> Option<Self> {
+        let interval = std::env::var("EQUITY_SAMPLE_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)?;
+        let window_hours = std::env::var("EQUITY_DRAWDOWN_WINDOW_HOURS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|hours| *hours > 0)
+            .unwrap_or(DEFAULT_WINDOW_HOURS);
+        let max_drawdown_pct = std::env::var("EQUITY_MAX_DRAWDOWN_PCT")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|pct| *pct > 0.0);
+        Some(Self {
+            interval: Duration::from_secs(interval),
+            window: chrono::Duration::hours(window_hours),
+            max_drawdown_pct,
+        })
+    }
+}
+
+/// 잔고/포지션 평가 결과
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EquityValuation {
+    pub cash_usdt: f64,
+    pub spot_usdt: f64,
+    pub futures_wallet_usdt: f64,
+    pub unrealized_pnl_usdt: f64,
+    pub equity_usdt: f64,
+    /// 1 USDT = ? KRW
+    pub usdt_krw: Option<f64>,
+    pub equity_krw: Option<f64>,
+    /// 가격을 알 수 없어 평가에서 빠진 자산
+    pub unpriced_assets: Vec<String>,
+    /// 조회에 실패한 거래소(계정)
+    pub failed_venues: Vec<String>,
+}
+
+/// 조회한 잔고/포지션과 가격표로 총 평가액 계산
+pub fn value_holdings(holdings: &[VenueHoldings], prices: &PriceBook) -> EquityValuation {
+    let mut valuation = EquityValuation {
+        usdt_krw: prices.usdt_krw,
+        ..Default::default()
+    };
+
+    for venue in holdings {
+        if venue.error.is_some() {
+            valuation.failed_venues.push(venue.venue.clone());
+        }
+        for spot in &venue.spots {
+            let Some(price) = prices.price_of(&spot.currency) else {
+                if spot.total != 0.0 && !valuation.unpriced_assets.contains(&spot.currency) {
+                    valuation.unpriced_assets.push(spot.currency.clone());
+                }
+                continue;
+            };
+            if is_cash(&spot.currency) {
+                valuation.cash_usdt += spot.total * price;
+            } else {
+                valuation.spot_usdt += spot.total * price;
+            }
+        }
+        valuation.futures_wallet_usdt += venue.margin_balance_usdt.unwrap_or(0.0);
+        valuation.unrealized_pnl_usdt += venue.unrealized_pnl_usdt.unwrap_or(0.0);
+    }
+
+    valuation.equity_usdt = valuation.cash_usdt
+        + valuation.spot_usdt
+        + valuation.futures_wallet_usdt
+        + valuation.unrealized_pnl_usdt;
+    valuation.equity_krw = valuation.usdt_krw.map(|rate| valuation.equity_usdt * rate);
+    valuation
+}
+
+/// 드로다운 서킷 브레이커 작동 기록
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DrawdownTrip {
+    pub drawdown_pct: f64,
+    pub limit_pct: f64,
+    pub peak_usdt: f64,
+    pub equity_usdt: f64,
+    pub tripped_at: DateTime<Utc>,
+}
+
+/// 에쿼티 곡선 (최근 표본, 롤링 고점/드로다운)
+#[derive(Debug, Clone)]
+pub struct EquityCurve {
+    points: VecDeque<EquityRecord>,
+    window: chrono::Duration,
+    capacity: usize,
+    /// 보유 중인 표본 전체에서 가장 큰 (고점 대비) 하락률
+    max_drawdown_pct: f64,
+}
+
+impl EquityCurve {
+    pub fn new(window: chrono::Duration, capacity: usize) -> Self {
+        Self {
+            points: VecDeque::new(),
+            window,
+            capacity: capacity.max(1),
+            max_drawdown_pct: 0.0,
+        }
+    }
+
+    /// `at` 기준 롤링 구간 안의 최고 평가액 (표본이 없으면 None)
+    pub fn rolling_peak(&self, at: DateTime<Utc>) -> Option<f64> {
+        self.points
+            .iter()
+            .filter(|p| p.sampled_at > at - self.window)
+            .map(|p| p.equity_usdt)
+            .reduce(f64::max)
+    }
+
+    /// 새 평가액의 롤링 고점 대비 하락률 (%, 고점 이상이면 0)
+    pub fn drawdown_pct(&self, equity_usdt: f64, at: DateTime<Utc>) -> f64 {
+        let peak = self.rolling_peak(at).unwrap_or(equity_usdt).max(equity_usdt);
+        if peak <= 0.0 {
+            return 0.0;
+        }
+        ((peak - equity_usdt) / peak * 100.0).max(0.0)
+    }
+
+    /// 평가 결과를 표본으로 추가하고 반환 (drawdown_pct 채움)
+    pub fn push(&mut self, valuation: &EquityValuation, at: DateTime<Utc>) -> EquityRecord {
+        let record = EquityRecord {
+            sampled_at: at,
+            equity_usdt: valuation.equity_usdt,
+            equity_krw: valuation.equity_krw,
+            cash_usdt: valuation.cash_usdt,
+            spot_usdt: valuation.spot_usdt,
+            futures_wallet_usdt: valuation.futures_wallet_usdt,
+            unrealized_pnl_usdt: valuation.unrealized_pnl_usdt,
+            drawdown_pct: self.drawdown_pct(valuation.equity_usdt, at),
+        };
+        self.restore(record.clone());
+        record
+    }
+
+    /// 저장된 표본 복원 (오래된 것부터)
+    pub fn restore(&mut self, record: EquityRecord) {
+        if self.points.len() == self.capacity {
+            self.points.pop_front();
+        }
+        self.max_drawdown_pct = self.max_drawdown_pct.max(record.drawdown_pct);
+        self.points.push_back(record);
+    }
+
+    pub fn latest(&self) -> Option<&EquityRecord> {
+        self.points.back()
+    }
+
+    pub fn max_drawdown_pct(&self) -> f64 {
+        self.max_drawdown_pct
+    }
+
+    /// 최근 표본 (최신순, 최대 `limit`개)
+    pub fn recent(&self, limit: usize) -> Vec<EquityRecord> {
+        self.points.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+/// `GET /equity` 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct EquityReport {
+    pub enabled: bool,
+    pub latest: Option<EquityRecord>,
+    /// 롤링 구간 고점 (USDT)
+    pub rolling_peak_usdt: Option<f64>,
+    pub window_hours: i64,
+    /// 최근 표본의 롤링 드로다운 (%)
+    pub drawdown_pct: f64,
+    /// 보유 중인 표본 전체의 최대 드로다운 (%)
+    pub max_drawdown_pct: f64,
+    pub max_drawdown_limit_pct: Option<f64>,
+    /// 서킷 브레이커 작동 중이면 작동 기록
+    pub breaker: Option<DrawdownTrip>,
+    /// 최근 표본 (최신순)
+    pub points: Vec<EquityRecord>,
+}
+
+/// 전역 에쿼티 추적기 (표본 수집, 드로다운 서킷 브레이커)
+pub struct EquityTracker {
+    params: RwLock<EquityParams>,
+    curve: RwLock<EquityCurve>,
+    breaker: RwLock<Option<DrawdownTrip>>,
+    started: AtomicBool,
+}
+
+impl Default for EquityTracker {
+    fn default() -> Self {
+        let params = EquityParams::default();
+        Self {
+            curve: RwLock::new(EquityCurve::new(params.window, MAX_POINTS)),
+            params: RwLock::new(params),
+            breaker: RwLock::new(None),
+            started: AtomicBool::new(false),
+        }
+    }
+}
+
+impl EquityTracker {
+    pub fn new(params: EquityParams) -> Self {
+        let tracker = Self::default();
+        tracker.configure(params);
+        tracker
+    }
+
+    fn configure(&self, params: EquityParams) {
+        *self.params.write().unwrap() = params;
+        self.curve.write().unwrap().window = params.window;
+    }
+
+    /// 평가 결과 기록. 조회 실패 거래소가 있으면 버리고 None
+    /// 드로다운이 한도를 넘으면 서킷 브레이커를 작동시키고 작동 기록을 함께 반환
+    pub fn record(
+        &self,
+        valuation: &EquityValuation,
+        at: DateTime<Utc>,
+    ) -> Option<(EquityRecord, Option<DrawdownTrip>)> {
+        if !valuation.failed_venues.is_empty() {
+            warn!(
+                "에쿼티 표본 버림 (조회 실패: {})",
+                valuation.failed_venues.join(", ")
+            );
+            return None;
+        }
+        let mut curve = self.curve.write().unwrap();
+        let peak = curve
+            .rolling_peak(at)
+            .unwrap_or(valuation.equity_usdt)
+            .max(valuation.equity_usdt);
+        let record = curve.push(valuation, at);
+        drop(curve);
+
+        let Some(limit) = self.params.read().unwrap().max_drawdown_pct else {
+            return Some((record, None));
+        };
+        let mut breaker = self.breaker.write().unwrap();
+        if record.drawdown_pct < limit || breaker.is_some() {
+            return Some((record, None));
+        }
+        let trip = DrawdownTrip {
+            drawdown_pct: record.drawdown_pct,
+            limit_pct: limit,
+            peak_usdt: peak,
+            equity_usdt: record.equity_usdt,
+            tripped_at: at,
+        };
+        *breaker = Some(trip.clone());
+        Some((record, Some(trip)))
+    }
+
+    /// 서킷 브레이커가 작동 중이면 진입 차단 사유
+    pub fn entry_block(&self) -> Option<String> {
+        self.breaker.read().unwrap().as_ref().map(|trip| {
+            format!(
+                "equity drawdown {:.2}% >= {:.2}% (since {})",
+                trip.drawdown_pct, trip.limit_pct, trip.tripped_at
+            )
+        })
+    }
+
+    /// 서킷 브레이커 재가동 (작동 중이 아니었으면 None)
+    pub fn rearm(&self) -> Option<DrawdownTrip> {
+        self.breaker.write().unwrap().take()
+    }
+
+    pub fn report(&self, limit: usize) -> EquityReport {
+        let params = *self.params.read().unwrap();
+        let curve = self.curve.read().unwrap();
+        let latest = curve.latest().cloned();
+        EquityReport {
+            enabled: self.started.load(Ordering::SeqCst),
+            rolling_peak_usdt: latest.as_ref().and_then(|p| curve.rolling_peak(p.sampled_at)),
+            drawdown_pct: latest.as_ref().map(|p| p.drawdown_pct).unwrap_or(0.0),
+            latest,
+            window_hours: params.window.num_hours(),
+            max_drawdown_pct: curve.max_drawdown_pct(),
+            max_drawdown_limit_pct: params.max_drawdown_pct,
+            breaker: self.breaker.read().unwrap().clone(),
+            points: curve.recent(limit),
+        }
+    }
+
+    /// 거래소 잔고를 조회해 표본 하나 기록
+    pub async fn sample(&self) {
+        let (holdings, prices) = tokio::join!(fetch_holdings(), fetch_price_book());
+        let valuation = value_holdings(&holdings, &prices);
+        if !valuation.unpriced_assets.is_empty() {
+            warn!(
+                "가격을 알 수 없어 에쿼티에서 빠진 자산: {}",
+                valuation.unpriced_assets.join(", ")
+            );
+        }
+        let Some((record, trip)) = self.record(&valuation, Utc::now()) else {
+            return;
+        };
+        info!(
+            "에쿼티 {:.2} USDT (드로다운 {:.2}%)",
+            record.equity_usdt, record.drawdown_pct
+        );
+        save_equity_record_safe(&record).await;
+        if let Some(trip) = trip {
+            notify_trip(&trip).await;
+        }
+    }
+
+    /// 주기 수집 작업 시작 (프로세스당 한 번). 저장된 최근 표본으로 곡선을 먼저 복원한다
+    pub fn start(&'static self, params: EquityParams) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        self.configure(params);
+        info!(
+            "에쿼티 수집 시작 ({}초 간격, 드로다운 구간 {}시간, 한도 {:?}%)",
+            params.interval.as_secs(),
+            params.window.num_hours(),
+            params.max_drawdown_pct
+        );
+        tokio::spawn(async move {
+            self.restore_from_repository().await;
+            loop {
+                self.sample().await;
+                tokio::time::sleep(params.interval).await;
+            }
+        });
+    }
+
+    async fn restore_from_repository(&self) {
+        let Some(repo) = get_equity_repository() else {
+            return;
+        };
+        match repo.find_recent(Some(MAX_POINTS as u64)).await {
+            Ok(stored) => {
+                let mut curve = self.curve.write().unwrap();
+                for point in stored.into_iter().rev() {
+                    curve.restore(point.record);
+                }
+            }
+            Err(e) => warn!("저장된 에쿼티 표본 조회 실패: {}", e),
+        }
+    }
+}
+
+async fn notify_trip(trip: &DrawdownTrip) {
+    error!(
+        "드로다운 서킷 브레이커 작동: {:.2}% >= {:.2}%",
+        trip.drawdown_pct, trip.limit_pct
+    );
+    let data = serde_json::to_value(trip).unwrap_or_default();
+    notification_center()
+        .notify(
+            DRAWDOWN_BREAKER_ALERT,
+            AlertLevel::Critical,
+            "드로다운 서킷 브레이커 작동",
+            format!(
+                "에쿼티 {:.2} USDT, 고점 {:.2} USDT 대비 {:.2}% 하락 (한도 {:.2}%). 재가동 전까지 신규 진입 중단",
+                trip.equity_usdt, trip.peak_usdt, trip.drawdown_pct, trip.limit_pct
+            ),
+            data,
+        )
+        .await;
+}
+
+static GLOBAL_EQUITY: OnceLock<EquityTracker> = OnceLock::new();
+
+/// 전역 에쿼티 추적기 (`/equity`)
+pub fn equity_tracker() -> &'static EquityTracker {
+    GLOBAL_EQUITY.get_or_init(EquityTracker::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use interface::SpotAsset;
+    use std::collections::HashMap;
+
+    fn spot(currency: &str, total: f64) -> SpotAsset {
+        SpotAsset {
+            currency: currency.to_string(),
+            total,
+            available: total,
+            in_use: 0.0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn valuation(equity_usdt: f64) -> EquityValuation {
+        EquityValuation {
+            cash_usdt: equity_usdt,
+            equity_usdt,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_value_holdings() {
+        let holdings = vec![
+            VenueHoldings {
+                venue: "bithumb".to_string(),
+                spots: vec![spot("BTC", 0.5), spot("KRW", 1_300_000.0)],
+                ..Default::default()
+            },
+            VenueHoldings {
+                venue: "binance_futures:main".to_string(),
+                spots: vec![spot("USDT", 500.0), spot("XYZ", 3.0)],
+                margin_balance_usdt: Some(2_000.0),
+                unrealized_pnl_usdt: Some(-150.0),
+                ..Default::default()
+            },
+        ];
+        let prices = PriceBook {
+            usdt_prices: HashMap::from([("BTC".to_string(), 60_000.0)]),
+            usdt_krw: Some(1_300.0),
+        };
+
+        let v = value_holdings(&holdings, &prices);
+        assert!((v.cash_usdt - 1_500.0).abs() < 1e-6);
+        assert!((v.spot_usdt - 30_000.0).abs() < 1e-6);
+        assert!((v.equity_usdt - 33_350.0).abs() < 1e-6);
+        assert!((v.equity_krw.unwrap() - 33_350.0 * 1_300.0).abs() < 1e-3);
+        assert_eq!(v.unpriced_assets, vec!["XYZ".to_string()]);
+        assert!(v.failed_venues.is_empty());
+    }
+
+    #[test]
+    fn test_rolling_drawdown_and_breaker() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let tracker = EquityTracker::new(EquityParams {
+            window: chrono::Duration::hours(24),
+            max_drawdown_pct: Some(10.0),
+            ..Default::default()
+        });
+        let at = |hours: i64| start + chrono::Duration::hours(hours);
+
+        let (first, trip) = tracker.record(&valuation(10_000.0), at(0)).unwrap();
+        assert_eq!(first.drawdown_pct, 0.0);
+        assert!(trip.is_none());
+
+        let (point, trip) = tracker.record(&valuation(9_500.0), at(1)).unwrap();
+        assert!((point.drawdown_pct - 5.0).abs() < 1e-9);
+        assert!(trip.is_none());
+        assert!(tracker.entry_block().is_none());
+
+        // 조회 실패가 섞인 표본은 버린다
+        let mut partial = valuation(1_000.0);
+        partial.failed_venues.push("bithumb".to_string());
+        assert!(tracker.record(&partial, at(2)).is_none());
+
+        let (point, trip) = tracker.record(&valuation(8_800.0), at(3)).unwrap();
+        assert!((point.drawdown_pct - 12.0).abs() < 1e-9);
+        let trip = trip.unwrap();
+        assert_eq!(trip.peak_usdt, 10_000.0);
+        assert!(tracker.entry_block().is_some());
+        // 작동 중에는 다시 알리지 않는다
+        assert!(
+            tracker
+                .record(&valuation(8_700.0), at(4))
+                .unwrap()
+                .1
+                .is_none()
+        );
+
+        assert_eq!(tracker.rearm(), Some(trip));
+        assert!(tracker.entry_block().is_none());
+
+        // 고점이 롤링 구간(24시간) 밖으로 밀려나면 드로다운도 새 고점 기준
+        let (point, _) = tracker.record(&valuation(8_600.0), at(29)).unwrap();
+        assert_eq!(point.drawdown_pct, 0.0);
+
+        let report = tracker.report(3);
+        assert_eq!(report.points.len(), 3);
+        assert_eq!(report.points[0].sampled_at, at(29));
+        assert!((report.max_drawdown_pct - 13.0).abs() < 1e-9);
+        assert_eq!(report.rolling_peak_usdt, Some(8_600.0));
+    }
+}
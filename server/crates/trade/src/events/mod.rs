@@ -0,0 +1,403 @@
+//! 전략 이벤트 버스
+//!
+//! 전략 루프는 진입 신호/주문/체결/청산/에러를 타입이 있는 이벤트로 발행만 하고,
+//! 기록 저장(recorder), 알림(notifier), 지표(metrics), 감사 로그(audit log)는
+//! 버스 구독자로 붙어 각자 처리한다. 구독자 하나가 느리거나 실패해도 전략 루프와
+//! 다른 구독자는 영향을 받지 않는다 (느린 구독자는 밀린 이벤트를 건너뜀).
+
+mod subscribers;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Once, OnceLock};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use interface::ExchangeId;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::arbitrage::two_phase::Leg;
+use crate::trader::binance::HedgedPair;
+
+pub use subscribers::{
+    AuditLogSubscriber, EventMetrics, MetricsSubscriber, NotifierSubscriber, RecorderSubscriber,
+    StrategyEventCounts, event_metrics,
+};
+
+/// 버스에 쌓아 둘 수 있는 이벤트 개수 (넘치면 느린 구독자는 오래된 이벤트를 놓침)
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// 부분 청산 기록의 action 값
+pub const PARTIAL_CLOSE_LABEL: &str = "PARTIAL_CLOSE";
+
+/// 포지션 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionDirection {
+    Carry,
+    Reverse,
+}
+
+impl PositionDirection {
+    /// ArbitrageState.dir 문자열에서 변환 ("carry" / "reverse")
+    pub fn from_state_dir(dir: Option<&str>) -> Option<Self> {
+        match dir {
+            Some("carry") => Some(Self::Carry),
+            Some("reverse") => Some(Self::Reverse),
+            _ => None,
+        }
+    }
+
+    /// 포지션 기록 테이블에 쓰는 값 ("CARRY" / "REVERSE")
+    pub fn record_label(&self) -> &'static str {
+        match self {
+            Self::Carry => "CARRY",
+            Self::Reverse => "REVERSE",
+        }
+    }
+}
+
+/// 주문이 포지션을 여는지 닫는지
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionAction {
+    Open,
+    Close,
+}
+
+impl PositionAction {
+    /// 포지션 기록 테이블에 쓰는 값 ("OPEN" / "CLOSE")
+    pub fn record_label(&self) -> &'static str {
+        match self {
+            Self::Open => "OPEN",
+            Self::Close => "CLOSE",
+        }
+    }
+}
+
+/// 전략이 발행하는 이벤트
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StrategyEvent {
+    /// 진입 조건 충족 (필터/자금 확인 전)
+    EntrySignal {
+        direction: PositionDirection,
+        basis_bps: f64,
+        spot_price: f64,
+        futures_mark: f64,
+    },
+    /// 주문 직전 재확인에서 엣지가 줄어 진입 취소 (필터/자금 확인 후)
+    EntryAborted {
+        direction: PositionDirection,
+        /// 신호 시점 베이시스
+        signal_basis_bps: f64,
+        /// 재확인 시점 베이시스 (가격 재조회 실패 시 None)
+        recheck_basis_bps: Option<f64>,
+        /// 재확인 시 요구한 최소 엣지
+        min_edge_bps: f64,
+        /// 신호 → 재확인까지 걸린 시간
+        elapsed_ms: u64,
+        reason: String,
+    },
+    /// 양 레그 주문 제출
+    OrderPlaced {
+        direction: PositionDirection,
+        action: PositionAction,
+        qty: f64,
+    },
+    /// 진입 주문 체결 → 포지션 열림
+    Filled {
+        direction: PositionDirection,
+        pair: HedgedPair,
+        basis_bps: f64,
+        spot_price: f64,
+        futures_mark: f64,
+        buy_exchange: String,
+        sell_exchange: String,
+    },
+    /// 청산 주문 체결 → 포지션 닫힘
+    Closed {
+        direction: PositionDirection,
+        pair: HedgedPair,
+        basis_bps: f64,
+        spot_price: f64,
+        futures_mark: f64,
+        buy_exchange: String,
+        sell_exchange: String,
+    },
+    /// 부분 청산 체결 (ExitLadder 단계 도달) → 잔량은 계속 보유
+    PartiallyClosed {
+        direction: PositionDirection,
+        /// 이번에 청산한 수량
+        pair: HedgedPair,
+        /// 청산 후 남은 수량
+        remaining: HedgedPair,
+        /// 지금까지 실행한 사다리 단계 수
+        step: usize,
+        basis_bps: f64,
+        spot_price: f64,
+        futures_mark: f64,
+        buy_exchange: String,
+        sell_exchange: String,
+    },
+    /// 진입 두 번째 레그가 재시도 후에도 실패해 첫 레그를 되돌림
+    LegUnwound {
+        direction: PositionDirection,
+        first_leg: Leg,
+        /// 두 번째 레그 시도 횟수
+        attempts: u32,
+        /// 두 번째 레그 마지막 실패 사유
+        error: String,
+        /// 되돌림 주문 실패 사유 (None이면 되돌림 성공, Some이면 한쪽 포지션이 남아 있음)
+        unwind_error: Option<String>,
+    },
+    /// 만기 선물 포지션을 다음 계약으로 이월 (현물 레그는 유지)
+    Rolled {
+        from_symbol: String,
+        to_symbol: String,
+        qty: f64,
+        /// 새 계약의 진입 베이시스
+        basis_bps: f64,
+    },
+    /// 진입 시점 헤지 거래소 선택 (펀딩/수수료/깊이 점수 기준)
+    HedgeVenueSelected {
+        direction: PositionDirection,
+        previous: ExchangeId,
+        selected: ExchangeId,
+        /// 선택된 거래소 점수 (bps)
+        score_bps: f64,
+        reason: String,
+    },
+    /// 동적 레버리지 조정 (변동성/청산 거리 기준)
+    LeverageAdjusted {
+        from: u32,
+        to: u32,
+        /// 1분 가격 변동성 (bps)
+        price_vol_bps: f64,
+        /// 베이시스 표준편차 (bps)
+        basis_vol_bps: f64,
+        /// 요구 청산 거리 (bps)
+        required_buffer_bps: f64,
+        /// 현재 포지션의 청산 거리 (bps)
+        liquidation_distance_bps: Option<f64>,
+        reason: String,
+    },
+    /// 현물-현물 스프레드 매매 체결 (싼 거래소 매수 + 비싼 거래소 재고 매도)
+    SpreadTraded {
+        buy_exchange: String,
+        sell_exchange: String,
+        qty: f64,
+        buy_price: f64,
+        sell_price: f64,
+        /// 수수료 차감 후 스프레드
+        net_spread_bps: f64,
+    },
+    /// 주문/청산 실패 등
+    Error { stage: String, message: String },
+}
+
+impl StrategyEvent {
+    /// 이벤트 종류 이름 (지표 키)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::EntrySignal { .. } => "entry_signal",
+            Self::EntryAborted { .. } => "entry_aborted",
+            Self::OrderPlaced { .. } => "order_placed",
+            Self::Filled { .. } => "filled",
+            Self::Closed { .. } => "closed",
+            Self::PartiallyClosed { .. } => "partially_closed",
+            Self::LegUnwound { .. } => "leg_unwound",
+            Self::Rolled { .. } => "rolled",
+            Self::HedgeVenueSelected { .. } => "hedge_venue_selected",
+            Self::LeverageAdjusted { .. } => "leverage_adjusted",
+            Self::SpreadTraded { .. } => "spread_traded",
+            Self::Error { .. } => "error",
+        }
+    }
+}
+
+/// 버스로 전달되는 이벤트 (발행 전략 정보 포함)
+#[derive(Debug, Clone, Serialize)]
+pub struct EventEnvelope {
+    pub seq: u64,
+    /// 전략 인스턴스 ID (예: "intra_basis:BTCUSDT")
+    pub strategy_id: String,
+    /// 봇 이름 (포지션 기록의 bot_name, 예: "intra_basis")
+    pub bot_name: String,
+    pub symbol: String,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: StrategyEvent,
+}
+
+/// 이벤트 구독자
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn handle(&self, envelope: &EventEnvelope) -> eyre::Result<()>;
+}
+
+/// tokio broadcast 기반 이벤트 버스
+pub struct EventBus {
+    tx: broadcast::Sender<Arc<EventEnvelope>>,
+    next_seq: AtomicU64,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity.max(1));
+        Self {
+            tx,
+            next_seq: AtomicU64::new(1),
+        }
+    }
+
+    /// 이벤트 발행 (구독자가 없으면 버려짐)
+    pub fn publish(
+        &self,
+        strategy_id: &str,
+        bot_name: &str,
+        symbol: &str,
+        event: StrategyEvent,
+    ) -> Arc<EventEnvelope> {
+        let envelope = Arc::new(EventEnvelope {
+            seq: self.next_seq.fetch_add(1, Ordering::Relaxed),
+            strategy_id: strategy_id.to_string(),
+            bot_name: bot_name.to_string(),
+            symbol: symbol.to_string(),
+            at: Utc::now(),
+            event,
+        });
+        let _ = self.tx.send(envelope.clone());
+        envelope
+    }
+
+    /// 원시 수신기 (직접 처리하려는 경우)
+    pub fn receiver(&self) -> broadcast::Receiver<Arc<EventEnvelope>> {
+        self.tx.subscribe()
+    }
+
+    /// 구독자 등록. 이벤트마다 `handle`을 호출하는 태스크를 띄운다
+    pub fn attach(&self, subscriber: Arc<dyn EventSubscriber>) -> JoinHandle<()> {
+        let mut rx = self.tx.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(envelope) => {
+                        if let Err(e) = subscriber.handle(&envelope).await {
+                            warn!(
+                                "이벤트 처리 실패 ({}, {} #{}): {}",
+                                subscriber.name(),
+                                envelope.event.kind(),
+                                envelope.seq,
+                                e
+                            );
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            "이벤트 구독자 {}가 밀려 {}개 이벤트를 건너뜀",
+                            subscriber.name(),
+                            skipped
+                        );
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+/// 전역 이벤트 버스
+static GLOBAL_EVENT_BUS: OnceLock<EventBus> = OnceLock::new();
+static DEFAULT_SUBSCRIBERS: Once = Once::new();
+
+/// 전역 이벤트 버스 가져오기
+pub fn event_bus() -> &'static EventBus {
+    GLOBAL_EVENT_BUS.get_or_init(EventBus::default)
+}
+
+/// 기본 구독자(기록 저장, 알림, 지표, 감사 로그)를 전역 버스에 한 번만 등록
+/// tokio 런타임 안에서 호출해야 한다
+pub fn install_default_subscribers() {
+    DEFAULT_SUBSCRIBERS.call_once(|| {
+        let bus = event_bus();
+        bus.attach(Arc::new(RecorderSubscriber));
+        bus.attach(Arc::new(NotifierSubscriber));
+        bus.attach(Arc::new(MetricsSubscriber));
+        let audit = AuditLogSubscriber::from_env();
+        info!("전략 이벤트 감사 로그: {}", audit.path().display());
+        bus.attach(Arc::new(audit));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct CollectingSubscriber {
+        seen: Mutex<Vec<(u64, &'static str)>>,
+    }
+
+    #[async_trait]
+    impl EventSubscriber for CollectingSubscriber {
+        fn name(&self) -> &str {
+            "collecting"
+        }
+
+        async fn handle(&self, envelope: &EventEnvelope) -> eyre::Result<()> {
+            self.seen
+                .lock()
+                .unwrap()
+                .push((envelope.seq, envelope.event.kind()));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bus_delivers_events_in_order() {
+        let bus = EventBus::new(16);
+        let collector = Arc::new(CollectingSubscriber {
+            seen: Mutex::new(Vec::new()),
+        });
+        let handle = bus.attach(collector.clone());
+
+        bus.publish(
+            "intra_basis:BTCUSDT",
+            "intra_basis",
+            "BTCUSDT",
+            StrategyEvent::EntrySignal {
+                direction: PositionDirection::Carry,
+                basis_bps: 12.0,
+                spot_price: 100.0,
+                futures_mark: 100.12,
+            },
+        );
+        bus.publish(
+            "intra_basis:BTCUSDT",
+            "intra_basis",
+            "BTCUSDT",
+            StrategyEvent::Error {
+                stage: "open".to_string(),
+                message: "rejected".to_string(),
+            },
+        );
+        drop(bus);
+        handle.await.unwrap();
+
+        assert_eq!(
+            *collector.seen.lock().unwrap(),
+            vec![(1, "entry_signal"), (2, "error")]
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! 기본 이벤트 구독자: 기록 저장, 알림, 지표, 감사 로그
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use super::{EventEnvelope, EventSubscriber, PARTIAL_CLOSE_LABEL, PositionAction, StrategyEvent};
+use crate::notification::{AlertLevel, notification_center};
+use crate::record::save_position_record_safe;
+
+/// 감사 로그 기본 경로
+const DEFAULT_AUDIT_LOG_PATH: &str = "strategy_events.jsonl";
+
+/// 체결/청산/부분 청산 이벤트를 position_records 테이블에 저장
+pub struct RecorderSubscriber;
+
+#[async_trait]
+impl EventSubscriber for RecorderSubscriber {
+    fn name(&self) -> &str {
+        "recorder"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> eyre::Result<()> {
+        let (action, direction, spot_price, futures_mark, buy_exchange, sell_exchange) =
+            match &envelope.event {
+                StrategyEvent::Filled {
+                    direction,
+                    spot_price,
+                    futures_mark,
+                    buy_exchange,
+                    sell_exchange,
+                    ..
+                } => (
+                    PositionAction::Open.record_label(),
+                    direction,
+                    spot_price,
+                    futures_mark,
+                    buy_exchange,
+                    sell_exchange,
+                ),
+                StrategyEvent::Closed {
+                    direction,
+                    spot_price,
+                    futures_mark,
+                    buy_exchange,
+                    sell_exchange,
+                    ..
+                } => (
+                    PositionAction::Close.record_label(),
+                    direction,
+                    spot_price,
+                    futures_mark,
+                    buy_exchange,
+                    sell_exchange,
+                ),
+                StrategyEvent::PartiallyClosed {
+                    direction,
+                    spot_price,
+                    futures_mark,
+                    buy_exchange,
+                    sell_exchange,
+                    ..
+                } => (
+                    PARTIAL_CLOSE_LABEL,
+                    direction,
+                    spot_price,
+                    futures_mark,
+                    buy_exchange,
+                    sell_exchange,
+                ),
+                _ => return Ok(()),
+            };
+
+        save_position_record_safe(
+            &envelope.bot_name,
+            direction.record_label(),
+            action,
+            &envelope.symbol,
+            *spot_price,
+            *futures_mark,
+            buy_exchange,
+            sell_exchange,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+/// 체결/청산/에러 이벤트를 알림 센터로 전달
+pub struct NotifierSubscriber;
+
+#[async_trait]
+impl EventSubscriber for NotifierSubscriber {
+    fn name(&self) -> &str {
+        "notifier"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> eyre::Result<()> {
+        let (kind, level, title, message) = match &envelope.event {
+            StrategyEvent::Filled {
+                direction,
+                pair,
+                basis_bps,
+                ..
+            } => (
+                "position_opened",
+                AlertLevel::Info,
+                format!("{} {} 진입", envelope.symbol, direction.record_label()),
+                format!(
+                    "{}: 수량 {:.8}, 베이시스 {:.2} bps",
+                    envelope.strategy_id, pair.fut_order_qty, basis_bps
+                ),
+            ),
+            StrategyEvent::Closed {
+                direction,
+                pair,
+                basis_bps,
+                ..
+            } => (
+                "position_closed",
+                AlertLevel::Info,
+                format!("{} {} 청산", envelope.symbol, direction.record_label()),
+                format!(
+                    "{}: 수량 {:.8}, 베이시스 {:.2} bps",
+                    envelope.strategy_id, pair.fut_order_qty, basis_bps
+                ),
+            ),
+            StrategyEvent::PartiallyClosed {
+                direction,
+                pair,
+                remaining,
+                basis_bps,
+                ..
+            } => (
+                "position_partially_closed",
+                AlertLevel::Info,
+                format!("{} {} 부분 청산", envelope.symbol, direction.record_label()),
+                format!(
+                    "{}: 수량 {:.8} 청산, 잔량 {:.8}, 베이시스 {:.2} bps",
+                    envelope.strategy_id, pair.fut_order_qty, remaining.fut_order_qty, basis_bps
+                ),
+            ),
+            StrategyEvent::LegUnwound {
+                direction,
+                first_leg,
+                attempts,
+                error,
+                unwind_error,
+            } => (
+                "leg_unwound",
+                if unwind_error.is_some() {
+                    AlertLevel::Critical
+                } else {
+                    AlertLevel::Warning
+                },
+                format!(
+                    "{} {} 진입 레그 되돌림",
+                    envelope.symbol,
+                    direction.record_label()
+                ),
+                match unwind_error {
+                    None => format!(
+                        "{}: 두 번째 레그 {}회 실패 ({}), {} 레그 되돌림 완료",
+                        envelope.strategy_id, attempts, error, first_leg
+                    ),
+                    Some(unwind_error) => format!(
+                        "{}: 두 번째 레그 {}회 실패 ({}), {} 레그 되돌림 실패 ({}) - 수동 정리 필요",
+                        envelope.strategy_id, attempts, error, first_leg, unwind_error
+                    ),
+                },
+            ),
+            StrategyEvent::Rolled {
+                from_symbol,
+                to_symbol,
+                qty,
+                basis_bps,
+            } => (
+                "contract_rolled",
+                AlertLevel::Info,
+                format!("{} 선물 롤오버", envelope.symbol),
+                format!(
+                    "{}: {} → {}, 수량 {:.8}, 베이시스 {:.2} bps",
+                    envelope.strategy_id, from_symbol, to_symbol, qty, basis_bps
+                ),
+            ),
+            StrategyEvent::SpreadTraded {
+                buy_exchange,
+                sell_exchange,
+                qty,
+                net_spread_bps,
+                ..
+            } => (
+                "spread_traded",
+                AlertLevel::Info,
+                format!("{} 스프레드 매매", envelope.symbol),
+                format!(
+                    "{}: {} 매수 / {} 매도, 수량 {:.8}, 순 스프레드 {:.2} bps",
+                    envelope.strategy_id, buy_exchange, sell_exchange, qty, net_spread_bps
+                ),
+            ),
+            StrategyEvent::Error { stage, message } => (
+                "strategy_error",
+                AlertLevel::Warning,
+                format!("{} {} 실패", envelope.symbol, stage),
+                format!("{}: {}", envelope.strategy_id, message),
+            ),
+            _ => return Ok(()),
+        };
+
+        notification_center()
+            .notify(kind, level, title, message, serde_json::to_value(envelope)?)
+            .await;
+        Ok(())
+    }
+}
+
+/// 전략별 이벤트 발생 횟수
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StrategyEventCounts {
+    /// 이벤트 종류별 횟수
+    pub counts: BTreeMap<&'static str, u64>,
+    pub last_seq: u64,
+    pub last_event_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// 이벤트 지표 저장소
+#[derive(Default)]
+pub struct EventMetrics {
+    by_strategy: RwLock<BTreeMap<String, StrategyEventCounts>>,
+}
+
+impl EventMetrics {
+    pub fn observe(&self, envelope: &EventEnvelope) {
+        let mut map = self.by_strategy.write().unwrap();
+        let entry = map.entry(envelope.strategy_id.clone()).or_default();
+        *entry.counts.entry(envelope.event.kind()).or_default() += 1;
+        entry.last_seq = envelope.seq;
+        entry.last_event_at = Some(envelope.at);
+    }
+
+    /// 전략 ID 순 스냅샷
+    pub fn report(&self) -> BTreeMap<String, StrategyEventCounts> {
+        self.by_strategy.read().unwrap().clone()
+    }
+}
+
+static GLOBAL_EVENT_METRICS: OnceLock<EventMetrics> = OnceLock::new();
+
+/// 전역 이벤트 지표 (`/metrics/events`)
+pub fn event_metrics() -> &'static EventMetrics {
+    GLOBAL_EVENT_METRICS.get_or_init(EventMetrics::default)
+}
+
+/// 모든 이벤트를 전역 지표에 집계
+pub struct MetricsSubscriber;
+
+#[async_trait]
+impl EventSubscriber for MetricsSubscriber {
+    fn name(&self) -> &str {
+        "metrics"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> eyre::Result<()> {
+        event_metrics().observe(envelope);
+        Ok(())
+    }
+}
+
+/// 모든 이벤트를 JSON Lines 파일에 덧붙여 남기는 감사 로그
+pub struct AuditLogSubscriber {
+    path: PathBuf,
+}
+
+impl AuditLogSubscriber {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// `STRATEGY_AUDIT_LOG` 경로 사용 (기본 strategy_events.jsonl)
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var("STRATEGY_AUDIT_LOG")
+                .unwrap_or_else(|_| DEFAULT_AUDIT_LOG_PATH.to_string()),
+        )
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for AuditLogSubscriber {
+    fn name(&self) -> &str {
+        "audit_log"
+    }
+
+    async fn handle(&self, envelope: &EventEnvelope) -> eyre::Result<()> {
+        let mut line = serde_json::to_string(envelope)?;
+        line.push('\n');
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{EventBus, PositionDirection};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_metrics_and_audit_log_subscribers() {
+        let path =
+            std::env::temp_dir().join(format!("strategy_events_test_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let bus = EventBus::new(16);
+        let metrics = Arc::new(EventMetrics::default());
+        let audit = bus.attach(Arc::new(AuditLogSubscriber::new(&path)));
+        let mut rx = bus.receiver();
+
+        for _ in 0..2 {
+            bus.publish(
+                "cross_basis:test",
+                "cross_basis",
+                "BTCUSDT",
+                StrategyEvent::OrderPlaced {
+                    direction: PositionDirection::Reverse,
+                    action: PositionAction::Open,
+                    qty: 0.1,
+                },
+            );
+        }
+        drop(bus);
+        while let Ok(envelope) = rx.recv().await {
+            metrics.observe(&envelope);
+        }
+        audit.await.unwrap();
+
+        let report = metrics.report();
+        assert_eq!(report["cross_basis:test"].counts["order_placed"], 2);
+        assert_eq!(report["cross_basis:test"].last_seq, 2);
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = log
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["type"], "order_placed");
+        assert_eq!(lines[1]["direction"], "reverse");
+        let _ = std::fs::remove_file(&path);
+    }
+}
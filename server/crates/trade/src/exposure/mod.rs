@@ -0,0 +1,409 @@
+//! 포트폴리오 노출(델타) 집계
+//!
+//! 저장된 포지션 기록이 아니라 거래소에서 직접 조회한 잔고/선물 포지션으로
+//! 베이스 자산별 순 델타, 총 명목가, 선물 증거금 사용률, 거래소(계정)별 내역을 계산한다.
+//! 전략이 여러 개여도 같은 계정을 공유하면 잔고는 합쳐져 있으므로,
+//! 여기서 보는 값이 실제로 시장에 노출된 크기다.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use exchanges::exchange_rate::fetch_usdt_krw_rate;
+use exchanges::{AssetExchange, BithumbClient};
+use interface::{ExchangeError, FutureAsset, SpotAsset};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::arbitrage::live::strategy_states;
+use crate::trader::binance::{BinanceAccounts, BinanceFuturesApi};
+use crate::trader::quote::split_symbol;
+
+pub mod positions;
+
+/// 델타 계산에서 현금으로 취급하는 자산
+const CASH_ASSETS: [&str; 6] = ["USDT", "USDC", "FDUSD", "TUSD", "USD", "KRW"];
+
+const BINANCE_TICKER_URL: &str = "https://api.binance.com/api/v3/ticker/price";
+
+/// 거래소(계정) 하나에서 조회한 잔고와 포지션
+#[derive(Debug, Clone, Default)]
+pub struct VenueHoldings {
+    /// 표시 이름 (예: "binance_spot:main", "bithumb")
+    pub venue: String,
+    pub spots: Vec<SpotAsset>,
+    pub futures: Vec<FutureAsset>,
+    /// 선물 증거금 잔고 (USDT, 선물 계정인 경우)
+    pub margin_balance_usdt: Option<f64>,
+    /// 선물 교차 마진 미실현 손익 (USDT, 선물 계정인 경우)
+    pub unrealized_pnl_usdt: Option<f64>,
+    /// 조회 실패 메시지 (실패한 거래소도 응답에 남긴다)
+    pub error: Option<String>,
+}
+
+/// 베이스 자산별 노출
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetExposure {
+    pub asset: String,
+    pub spot_qty: f64,
+    pub futures_qty: f64,
+    /// spot + 선물 (양수 = 롱)
+    pub net_qty: f64,
+    /// USDT 기준 가격 (알 수 없으면 None)
+    pub price_usdt: Option<f64>,
+    pub net_delta_usdt: f64,
+    /// |spot| + |선물| 명목가
+    pub gross_notional_usdt: f64,
+}
+
+/// 거래소(계정)별 노출
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VenueExposure {
+    pub venue: String,
+    /// 베이스 자산 spot 보유 명목가 (현금 제외)
+    pub spot_notional_usdt: f64,
+    /// 현금성 자산 (USDT 등 스테이블 + KRW 환산)
+    pub cash_usdt: f64,
+    pub futures_gross_usdt: f64,
+    pub futures_net_usdt: f64,
+    pub margin_balance_usdt: Option<f64>,
+    /// 선물 총 명목가 / 증거금 잔고 (실효 레버리지)
+    pub margin_usage: Option<f64>,
+    pub error: Option<String>,
+}
+
+/// `GET /exposure` 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct ExposureReport {
+    pub assets: Vec<AssetExposure>,
+    pub venues: Vec<VenueExposure>,
+    pub net_delta_usdt: f64,
+    pub gross_notional_usdt: f64,
+    pub cash_usdt: f64,
+    /// 전체 선물 총 명목가 / 전체 선물 증거금
+    pub margin_usage: Option<f64>,
+    /// 포지션이 열려 있는 전략 ID
+    pub open_strategies: Vec<String>,
+    /// 가격을 알 수 없어 명목가에서 빠진 자산
+    pub unpriced_assets: Vec<String>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// 베이스 자산 및 KRW 가격표 (USDT 기준)
+#[derive(Debug, Clone, Default)]
+pub struct PriceBook {
+    /// 베이스 자산 -> USDT 가격
+    pub usdt_prices: HashMap<String, f64>,
+    /// 1 USDT = ? KRW
+    pub usdt_krw: Option<f64>,
+}
+
+impl PriceBook {
+    /// 자산 1단위의 USDT 가치
+    pub fn price_of(&self, asset: &str) -> Option<f64> {
+        match asset {
+            "KRW" => self.usdt_krw.filter(|r| *r > 0.0).map(|r| 1.0 / r),
+            a if CASH_ASSETS.contains(&a) => Some(1.0),
+            a => self.usdt_prices.get(a).copied(),
+        }
+    }
+}
+
+pub fn is_cash(asset: &str) -> bool {
+    CASH_ASSETS.contains(&asset)
+}
+
+/// 선물 심볼의 베이스 자산 (예: "BTCUSDT" -> "BTC")
+fn futures_base(symbol: &str) -> &str {
+    split_symbol(symbol).map(|(base, _)| base).unwrap_or(symbol)
+}
+
+/// 조회한 잔고/포지션과 가격표로 노출 보고서 계산
+pub fn aggregate_exposure(
+    holdings: &[VenueHoldings],
+    prices: &PriceBook,
+    open_strategies: Vec<String>,
+) -> ExposureReport {
+    let mut assets: BTreeMap<String, AssetExposure> = BTreeMap::new();
+    let mut venues = Vec::with_capacity(holdings.len());
+    let mut cash_total = 0.0;
+    let mut futures_gross_total = 0.0;
+    let mut margin_total = 0.0;
+
+    for venue in holdings {
+        let mut report = VenueExposure {
+            venue: venue.venue.clone(),
+            margin_balance_usdt: venue.margin_balance_usdt,
+            error: venue.error.clone(),
+            ..Default::default()
+        };
+
+        for spot in &venue.spots {
+            let price = prices.price_of(&spot.currency);
+            if is_cash(&spot.currency) {
+                report.cash_usdt += spot.total * price.unwrap_or(0.0);
+                continue;
+            }
+            let entry = assets
+                .entry(spot.currency.clone())
+                .or_insert_with(|| AssetExposure {
+                    asset: spot.currency.clone(),
+                    price_usdt: price,
+                    ..Default::default()
+                });
+            entry.spot_qty += spot.total;
+            report.spot_notional_usdt += spot.total.abs() * price.unwrap_or(0.0);
+        }
+
+        for position in &venue.futures {
+            let base = futures_base(&position.symbol);
+            let price = prices.price_of(base);
+            let entry = assets
+                .entry(base.to_string())
+                .or_insert_with(|| AssetExposure {
+                    asset: base.to_string(),
+                    price_usdt: price,
+                    ..Default::default()
+                });
+            entry.futures_qty += position.position_amt;
+            let notional = position.position_amt * price.unwrap_or(0.0);
+            report.futures_gross_usdt += notional.abs();
+            report.futures_net_usdt += notional;
+        }
+
+        if let Some(balance) = venue.margin_balance_usdt {
+            margin_total += balance;
+            if balance > 0.0 {
+                report.margin_usage = Some(report.futures_gross_usdt / balance);
+            }
+        }
+        cash_total += report.cash_usdt;
+        futures_gross_total += report.futures_gross_usdt;
+        venues.push(report);
+    }
+
+    let mut unpriced_assets = Vec::new();
+    let mut net_delta_total = 0.0;
+    let mut gross_total = 0.0;
+    for exposure in assets.values_mut() {
+        exposure.net_qty = exposure.spot_qty + exposure.futures_qty;
+        match exposure.price_usdt {
+            Some(price) => {
+                exposure.net_delta_usdt = exposure.net_qty * price;
+                exposure.gross_notional_usdt =
+                    (exposure.spot_qty.abs() + exposure.futures_qty.abs()) * price;
+            }
+            None => unpriced_assets.push(exposure.asset.clone()),
+        }
+        net_delta_total += exposure.net_delta_usdt;
+        gross_total += exposure.gross_notional_usdt;
+    }
+
+    ExposureReport {
+        assets: assets.into_values().collect(),
+        venues,
+        net_delta_usdt: net_delta_total,
+        gross_notional_usdt: gross_total,
+        cash_usdt: cash_total,
+        margin_usage: (margin_total > 0.0).then(|| futures_gross_total / margin_total),
+        open_strategies,
+        unpriced_assets,
+        generated_at: Utc::now(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerPrice {
+    symbol: String,
+    price: String,
+}
+
+/// Binance 전체 스팟 시세에서 `{BASE}USDT` 가격표 구성
+pub async fn fetch_price_book() -> PriceBook {
+    let mut book = PriceBook::default();
+    match fetch_usdt_tickers().await {
+        Ok(prices) => book.usdt_prices = prices,
+        Err(e) => warn!("노출 계산용 시세 조회 실패: {}", e),
+    }
+    match fetch_usdt_krw_rate().await {
+        Ok(rate) => book.usdt_krw = Some(rate),
+        Err(e) => warn!("노출 계산용 USDT/KRW 환율 조회 실패: {}", e),
+    }
+    book
+}
+
+async fn fetch_usdt_tickers() -> Result<HashMap<String, f64>, ExchangeError> {
+    let tickers: Vec<TickerPrice> = exchanges::http::http_client()
+        .get(BINANCE_TICKER_URL)
+        .send()
+        .await?
+        .json()
+        .await
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse ticker prices: {}", e)))?;
+    Ok(tickers
+        .into_iter()
+        .filter_map(|t| {
+            let base = t.symbol.strip_suffix("USDT")?.to_string();
+            let price = t.price.parse::<f64>().ok()?;
+            Some((base, price))
+        })
+        .collect())
+}
+
+async fn holdings_from<C: AssetExchange>(
+    venue: String,
+    client: &C,
+    spots: bool,
+    futures: bool,
+) -> VenueHoldings {
+    let mut holdings = VenueHoldings {
+        venue,
+        ..Default::default()
+    };
+    let mut errors = Vec::new();
+    if spots {
+        match client.fetch_spots().await {
+            Ok(assets) => holdings.spots = assets,
+            Err(e) => errors.push(format!("spot: {}", e)),
+        }
+    }
+    if futures {
+        match client.fetch_futures().await {
+            Ok(positions) => {
+                holdings.futures = positions
+                    .into_iter()
+                    .filter(|p| p.position_amt != 0.0)
+                    .collect()
+            }
+            Err(e) => errors.push(format!("futures: {}", e)),
+        }
+    }
+    if !errors.is_empty() {
+        holdings.error = Some(errors.join("; "));
+    }
+    holdings
+}
+
+/// 설정된 모든 계정의 잔고/포지션 조회 (자격 증명이 없는 거래소는 건너뜀)
+pub async fn fetch_holdings() -> Vec<VenueHoldings> {
+    let mut holdings = Vec::new();
+
+    match BinanceAccounts::from_env() {
+        Ok(accounts) => {
+            if accounts.is_split() {
+                holdings.push(
+                    holdings_from(
+                        format!("binance_spot:{}", accounts.spot.label),
+                        &accounts.spot.client,
+                        true,
+                        false,
+                    )
+                    .await,
+                );
+            }
+            let mut futures = holdings_from(
+                format!("binance_futures:{}", accounts.futures.label),
+                &accounts.futures.client,
+                !accounts.is_split(),
+                true,
+            )
+            .await;
+            let api = BinanceFuturesApi::new(accounts.futures.client.clone());
+            match api.get_usdt_balance().await {
+                Ok(balance) => {
+                    futures.margin_balance_usdt = Some(balance.balance);
+                    futures.unrealized_pnl_usdt = Some(balance.cross_unrealized_pnl);
+                }
+                Err(e) => warn!("바이낸스 선물 증거금 조회 실패: {}", e),
+            }
+            holdings.push(futures);
+        }
+        Err(e) => warn!("바이낸스 계정 없음, 노출 집계에서 제외: {}", e),
+    }
+
+    match BithumbClient::with_credentials() {
+        Ok(client) => {
+            holdings.push(holdings_from("bithumb".to_string(), &client, true, false).await)
+        }
+        Err(e) => warn!("빗썸 계정 없음, 노출 집계에서 제외: {}", e),
+    }
+
+    holdings
+}
+
+/// 실시간 잔고/포지션 기준 포트폴리오 노출 계산
+pub async fn compute_exposure() -> ExposureReport {
+    let (holdings, prices) = tokio::join!(fetch_holdings(), fetch_price_book());
+    let registry = strategy_states();
+    let open_strategies = registry
+        .ids()
+        .into_iter()
+        .filter(|id| registry.get(id).is_some_and(|report| report.state.open))
+        .collect();
+    aggregate_exposure(&holdings, &prices, open_strategies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spot(currency: &str, total: f64) -> SpotAsset {
+        SpotAsset {
+            currency: currency.to_string(),
+            total,
+            available: total,
+            in_use: 0.0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn perp(symbol: &str, position_amt: f64) -> FutureAsset {
+        FutureAsset {
+            symbol: symbol.to_string(),
+            position_amt,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_exposure_nets_spot_against_futures() {
+        let holdings = vec![
+            VenueHoldings {
+                venue: "bithumb".to_string(),
+                spots: vec![spot("BTC", 1.0), spot("KRW", 1_300_000.0)],
+                ..Default::default()
+            },
+            VenueHoldings {
+                venue: "binance_futures:main".to_string(),
+                spots: vec![spot("USDT", 500.0), spot("XYZ", 3.0)],
+                futures: vec![perp("BTCUSDT", -0.9), perp("ETHUSDT", 2.0)],
+                margin_balance_usdt: Some(50_000.0),
+                unrealized_pnl_usdt: None,
+                error: None,
+            },
+        ];
+        let prices = PriceBook {
+            usdt_prices: HashMap::from([
+                ("BTC".to_string(), 60_000.0),
+                ("ETH".to_string(), 3_000.0),
+            ]),
+            usdt_krw: Some(1_300.0),
+        };
+
+        let report = aggregate_exposure(&holdings, &prices, vec!["intra_basis:BTCUSDT".into()]);
+
+        let btc = report.assets.iter().find(|a| a.asset == "BTC").unwrap();
+        assert!((btc.net_qty - 0.1).abs() < 1e-9);
+        assert!((btc.net_delta_usdt - 6_000.0).abs() < 1e-6);
+        assert!((btc.gross_notional_usdt - 114_000.0).abs() < 1e-6);
+        assert!((report.net_delta_usdt - 12_000.0).abs() < 1e-6);
+        assert_eq!(report.unpriced_assets, vec!["XYZ".to_string()]);
+
+        assert!((report.venues[0].cash_usdt - 1_000.0).abs() < 1e-6);
+        assert!((report.cash_usdt - 1_500.0).abs() < 1e-6);
+        let futures = &report.venues[1];
+        assert!((futures.futures_gross_usdt - 60_000.0).abs() < 1e-6);
+        assert!((futures.futures_net_usdt + 48_000.0).abs() < 1e-6);
+        assert!((futures.margin_usage.unwrap() - 1.2).abs() < 1e-9);
+        assert_eq!(report.margin_usage, futures.margin_usage);
+    }
+}
@@ -0,0 +1,238 @@
+//! 거래소 실시간 포지션/잔고 미러 (`GET /positions/live`)
+//!
+//! 외부 위험 관리 도구가 거래소 자격 증명 없이 볼 수 있도록, 요청 시점에 각 거래소
+//! 비공개 API로 조회한 선물 포지션과 잔고를 같은 형태로 정규화해 돌려준다.
+
+use chrono::{DateTime, Utc};
+use exchanges::BithumbClient;
+use interface::SpotAsset;
+use serde::Serialize;
+use tracing::warn;
+
+use super::{PriceBook, fetch_price_book, holdings_from};
+use crate::trader::binance::{BinanceAccounts, BinanceFuturesApi, FuturesPositionRisk};
+
+/// 포지션 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+/// 정규화된 선물 포지션
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LivePosition {
+    /// 표시 이름 (예: "binance_futures:main")
+    pub venue: String,
+    pub symbol: String,
+    pub side: PositionSide,
+    /// 절대 수량
+    pub qty: f64,
+    pub entry_price: f64,
+    pub mark_price: f64,
+    /// 미실현 손익 (USDT)
+    pub unrealized_pnl: f64,
+    pub leverage: u32,
+    /// 청산 가격 (없으면 None)
+    pub liquidation_price: Option<f64>,
+    /// 수량 * 마크 가격
+    pub notional_usdt: f64,
+}
+
+/// 정규화된 잔고
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LiveBalance {
+    pub venue: String,
+    pub asset: String,
+    pub total: f64,
+    pub available: f64,
+    pub in_use: f64,
+    /// USDT 환산 가치 (가격을 모르면 None)
+    pub value_usdt: Option<f64>,
+}
+
+/// 거래소 조회 실패
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VenueError {
+    pub venue: String,
+    pub error: String,
+}
+
+/// `GET /positions/live` 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct LivePositionsReport {
+    pub positions: Vec<LivePosition>,
+    pub balances: Vec<LiveBalance>,
+    /// 조회에 실패한 거래소 (나머지 결과는 그대로 반환)
+    pub errors: Vec<VenueError>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// 바이낸스 포지션 위험 정보를 공통 형태로 변환 (수량 0이면 None)
+pub fn normalize_position(venue: &str, risk: &FuturesPositionRisk) -> Option<LivePosition> {
+    if risk.position_amt == 0.0 {
+        return None;
+    }
+    let qty = risk.position_amt.abs();
+    Some(LivePosition {
+        venue: venue.to_string(),
+        symbol: risk.symbol.clone(),
+        side: if risk.position_amt > 0.0 {
+            PositionSide::Long
+        } else {
+            PositionSide::Short
+        },
+        qty,
+        entry_price: risk.entry_price,
+        mark_price: risk.mark_price,
+        unrealized_pnl: risk.unrealized_pnl,
+        leverage: risk.leverage,
+        liquidation_price: (risk.liquidation_price > 0.0).then_some(risk.liquidation_price),
+        notional_usdt: qty * risk.mark_price,
+    })
+}
+
+/// 잔고를 공통 형태로 변환 (0 잔고 제외)
+pub fn normalize_balances(
+    venue: &str,
+    spots: &[SpotAsset],
+    prices: &PriceBook,
+) -> Vec<LiveBalance> {
+    spots
+        .iter()
+        .filter(|asset| asset.total != 0.0)
+        .map(|asset| LiveBalance {
+            venue: venue.to_string(),
+            asset: asset.currency.clone(),
+            total: asset.total,
+            available: asset.available,
+            in_use: asset.in_use,
+            value_usdt: prices.price_of(&asset.currency).map(|p| p * asset.total),
+        })
+        .collect()
+}
+
+/// 설정된 모든 계정의 포지션/잔고를 조회해 정규화 (자격 증명이 없는 거래소는 건너뜀)
+pub async fn fetch_live_positions() -> LivePositionsReport {
+    let prices = fetch_price_book().await;
+    let mut report = LivePositionsReport {
+        positions: Vec::new(),
+        balances: Vec::new(),
+        errors: Vec::new(),
+        generated_at: Utc::now(),
+    };
+    let mut venues = Vec::new();
+
+    match BinanceAccounts::from_env() {
+        Ok(accounts) => {
+            if accounts.is_split() {
+                venues.push(
+                    holdings_from(
+                        format!("binance_spot:{}", accounts.spot.label),
+                        &accounts.spot.client,
+                        true,
+                        false,
+                    )
+                    .await,
+                );
+            }
+            let venue = format!("binance_futures:{}", accounts.futures.label);
+            if !accounts.is_split() {
+                venues.push(
+                    holdings_from(venue.clone(), &accounts.futures.client, true, false).await,
+                );
+            }
+            let api = BinanceFuturesApi::new(accounts.futures.client.clone());
+            match api.get_open_positions().await {
+                Ok(risks) => report.positions.extend(
+                    risks
+                        .iter()
+                        .filter_map(|risk| normalize_position(&venue, risk)),
+                ),
+                Err(e) => report.errors.push(VenueError {
+                    venue,
+                    error: format!("positions: {}", e),
+                }),
+            }
+        }
+        Err(e) => warn!("바이낸스 계정 없음, 포지션 미러에서 제외: {}", e),
+    }
+
+    match BithumbClient::with_credentials() {
+        Ok(client) => venues.push(holdings_from("bithumb".to_string(), &client, true, false).await),
+        Err(e) => warn!("빗썸 계정 없음, 포지션 미러에서 제외: {}", e),
+    }
+
+    for holdings in venues {
+        report.balances.extend(normalize_balances(
+            &holdings.venue,
+            &holdings.spots,
+            &prices,
+        ));
+        if let Some(error) = holdings.error {
+            report.errors.push(VenueError {
+                venue: holdings.venue,
+                error,
+            });
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_positions_and_balances() {
+        let short = FuturesPositionRisk {
+            symbol: "BTCUSDT".to_string(),
+            position_amt: -0.5,
+            entry_price: 60000.0,
+            mark_price: 61000.0,
+            unrealized_pnl: -500.0,
+            liquidation_price: 0.0,
+            leverage: 3,
+        };
+        let position = normalize_position("binance_futures:main", &short).unwrap();
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.qty, 0.5);
+        assert_eq!(position.notional_usdt, 30500.0);
+        assert_eq!(position.liquidation_price, None);
+        assert!(
+            normalize_position(
+                "binance_futures:main",
+                &FuturesPositionRisk {
+                    position_amt: 0.0,
+                    ..short
+                }
+            )
+            .is_none()
+        );
+
+        let prices = PriceBook {
+            usdt_prices: [("BTC".to_string(), 60000.0)].into_iter().collect(),
+            usdt_krw: Some(1400.0),
+        };
+        let asset = |currency: &str, total: f64| SpotAsset {
+            currency: currency.to_string(),
+            total,
+            available: total,
+            in_use: 0.0,
+            updated_at: Utc::now(),
+        };
+        let balances = normalize_balances(
+            "bithumb",
+            &[
+                asset("KRW", 1_400_000.0),
+                asset("BTC", 0.0),
+                asset("XYZ", 3.0),
+            ],
+            &prices,
+        );
+        assert_eq!(balances.len(), 2);
+        assert!((balances[0].value_usdt.unwrap() - 1000.0).abs() < 1e-9);
+        assert_eq!(balances[1].value_usdt, None);
+    }
+}
@@ -0,0 +1,501 @@
+//! 펀딩비 정산 손익 귀속
+//!
+//! 포지션이 열려 있는 동안 선물 펀딩비가 정산될 때마다 (심볼, 거래소, 펀딩비율, 지급액, 포지션 수량)을
+//! 그 포지션의 OPEN 기록(`position_records.id`)에 연결해 `funding_settlement_records`에 저장한다.
+//! 청산된 포지션의 수익을 펀딩비 몫과 베이시스 수렴 몫으로 나눠 보기 위함이다.
+//!
+//! Binance USDⓈ-M 선물 정산 내역(`/fapi/v1/income`)만 대상이다. 포지션의 열림/닫힘은 봇별로
+//! 판단하고, 같은 심볼에 여러 봇의 포지션이 열려 있으면 선물 포지션이 합쳐져 정산되므로
+//! 가장 최근에 연 포지션에 귀속한다. 같은 정산은 (심볼, 정산 시각, 포지션)당 한 번만 저장된다.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Serialize;
+use tracing::{debug, info, warn};
+
+use crate::events::{PARTIAL_CLOSE_LABEL, PositionAction};
+use crate::record::{
+    FundingSettlementRecord, RecordError, StoredFundingSettlementRecord, StoredPositionRecord,
+    get_funding_settlement_repository, get_position_repository,
+    save_funding_settlement_record_safe,
+};
+use crate::trader::binance::{BinanceTrader, FundingIncome, FundingRateEntry};
+
+/// 정산 거래소 라벨 (포지션 기록의 buy/sell_exchange 값과 같음)
+pub const FUNDING_VENUE: &str = "binance_futures";
+
+const DEFAULT_LOOKBACK_HOURS: i64 = 24;
+/// 심볼 하나에서 OPEN 기록을 찾을 때 살펴볼 최근 기록 수
+const POSITION_SCAN_LIMIT: u64 = 200;
+
+/// 펀딩비 정산 기록 설정
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FundingAttributionParams {
+    /// 정산 내역 조회 간격
+    pub interval: Duration,
+    /// 저장된 정산 기록이 없을 때 처음 조회할 구간
+    pub lookback: chrono::Duration,
+}
+
+impl FundingAttributionParams {
+    /// `FUNDING_ATTRIBUTION_INTERVAL_SECS`가 설정되어 있을 때만 Some (기록은 선택 기능)
+    /// `FUNDING_ATTRIBUTION_LOOKBACK_HOURS`(기본 24)
+    pub fn from_env() -> Option<Self> {
+        let interval = std::env::var("FUNDING_ATTRIBUTION_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)?;
+        let lookback_hours = std::env::var("FUNDING_ATTRIBUTION_LOOKBACK_HOURS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|hours| *hours > 0)
+            .unwrap_or(DEFAULT_LOOKBACK_HOURS);
+        Some(Self {
+            interval: Duration::from_secs(interval),
+            lookback: chrono::Duration::hours(lookback_hours),
+        })
+    }
+}
+
+/// `at` 시점에 열려 있던 포지션의 OPEN 기록 (한 심볼의 기록, 순서 무관)
+/// 봇마다 열린 포지션을 찾고, 여러 봇이 열려 있으면 가장 최근에 연 포지션
+pub fn open_position_at(
+    records: &[StoredPositionRecord],
+    at: DateTime<Utc>,
+) -> Option<&StoredPositionRecord> {
+    let mut bots: Vec<&str> = records.iter().map(|r| r.record.bot_name.as_str()).collect();
+    bots.sort_unstable();
+    bots.dedup();
+    bots.into_iter()
+        .filter_map(|bot| bot_open_position_at(records, bot, at))
+        .max_by_key(|r| (r.record.executed_at, r.id))
+}
+
+/// 봇 하나의 `at` 시점 열린 포지션 (`closing_record`와 같은 봇 범위)
+/// 가장 최근 기록부터 거슬러 올라가 CLOSE를 먼저 만나면 닫힌 상태, OPEN을 만나면 열린 상태
+/// (부분 청산은 잔량이 남아 있으므로 건너뜀). 선물 레그가 Binance가 아닌 포지션은 제외
+fn bot_open_position_at<'a>(
+    records: &'a [StoredPositionRecord],
+    bot_name: &str,
+    at: DateTime<Utc>,
+) -> Option<&'a StoredPositionRecord> {
+    let mut before: Vec<&StoredPositionRecord> = records
+        .iter()
+        .filter(|r| r.record.bot_name == bot_name)
+        .filter(|r| r.record.executed_at <= at)
+        .filter(|r| {
+            r.record.buy_exchange == FUNDING_VENUE || r.record.sell_exchange == FUNDING_VENUE
+        })
+        .collect();
+    before.sort_by_key(|r| (r.record.executed_at, r.id));
+    for record in before.into_iter().rev() {
+        match record.record.action.as_str() {
+            a if a == PositionAction::Open.record_label() => return Some(record),
+            a if a == PARTIAL_CLOSE_LABEL => continue,
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// OPEN 기록 이후 처음 나온 CLOSE 기록 (아직 열려 있으면 None)
+pub fn closing_record<'a>(
+    records: &'a [StoredPositionRecord],
+    open: &StoredPositionRecord,
+) -> Option<&'a StoredPositionRecord> {
+    records
+        .iter()
+        .filter(|r| r.record.bot_name == open.record.bot_name)
+        .filter(|r| (r.record.executed_at, r.id) > (open.record.executed_at, open.id))
+        .filter(|r| r.record.action == PositionAction::Close.record_label())
+        .min_by_key(|r| (r.record.executed_at, r.id))
+}
+
+/// 정산 시점 포지션 수량 (Binance 펀딩비 = -수량 × 마크 가격 × 펀딩비율)
+pub fn implied_position_qty(payment: f64, funding_rate: f64, mark_price: f64) -> Option<f64> {
+    let denom = funding_rate * mark_price;
+    if denom == 0.0 || !denom.is_finite() {
+        return None;
+    }
+    Some(-payment / denom)
+}
+
+/// 정산 내역 하나를 포지션에 연결한 기록으로 변환
+/// 펀딩비율/마크 가격을 못 찾았거나 펀딩비율이 0이면 `fallback_qty`(현재 포지션 수량)를 쓴다
+pub fn settlement_record(
+    income: &FundingIncome,
+    rate: Option<&FundingRateEntry>,
+    position: &StoredPositionRecord,
+    fallback_qty: Option<f64>,
+) -> FundingSettlementRecord {
+    let funding_rate = rate.map(|r| r.funding_rate).unwrap_or(0.0);
+    let mark_price = rate.and_then(|r| r.mark_price);
+    let position_qty = mark_price
+        .and_then(|mark| implied_position_qty(income.income, funding_rate, mark))
+        .or(fallback_qty)
+        .unwrap_or(0.0);
+    FundingSettlementRecord {
+        settled_at: ms_to_datetime(income.time),
+        position_record_id: position.id,
+        bot_name: position.record.bot_name.clone(),
+        symbol: income.symbol.clone(),
+        venue: FUNDING_VENUE.to_string(),
+        funding_rate,
+        payment: income.income,
+        position_qty,
+        mark_price,
+    }
+}
+
+fn ms_to_datetime(ms: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(ms)
+        .single()
+        .unwrap_or_else(Utc::now)
+}
+
+/// 청산 포지션 수익 분해: 펀딩비 vs 베이시스 수렴
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingAttribution {
+    pub position_record_id: i64,
+    pub bot_name: String,
+    pub symbol: String,
+    pub carry: String,
+    pub opened_at: DateTime<Utc>,
+    /// 청산 시각 (아직 열려 있으면 None)
+    pub closed_at: Option<DateTime<Utc>>,
+    pub settlements: Vec<StoredFundingSettlementRecord>,
+    /// 받은 펀딩비 합계 (USDT)
+    pub funding_usdt: f64,
+    /// 진입 명목금액(최근 정산 수량 × 진입 현물가) 대비 펀딩비 (bps, 정산이 없으면 None)
+    pub funding_bps: Option<f64>,
+    /// 진입→청산 베이시스 수렴 손익 (bps, 수수료 제외, 청산 전이면 None)
+    /// carry는 베이시스가 줄어들수록, reverse는 늘어날수록 이득
+    pub basis_bps: Option<f64>,
+}
+
+fn record_basis_bps(record: &StoredPositionRecord) -> Option<f64> {
+    let spot = record.record.spot_price;
+    (spot > 0.0).then(|| (record.record.futures_mark - spot) / spot * 10_000.0)
+}
+
+/// OPEN/CLOSE 기록과 정산 기록으로 수익 분해
+pub fn attribute(
+    open: &StoredPositionRecord,
+    close: Option<&StoredPositionRecord>,
+    settlements: Vec<StoredFundingSettlementRecord>,
+) -> FundingAttribution {
+    let funding_usdt = settlements.iter().map(|s| s.record.payment).sum();
+    let notional = settlements
+        .last()
+        .map(|s| s.record.position_qty.abs() * open.record.spot_price)
+        .filter(|n| *n > 0.0);
+    let basis_bps = close.and_then(|close| {
+        let (open_bps, close_bps) = (record_basis_bps(open)?, record_basis_bps(close)?);
+        match open.record.carry.as_str() {
+            "CARRY" => Some(open_bps - close_bps),
+            "REVERSE" => Some(close_bps - open_bps),
+            _ => None,
+        }
+    });
+    FundingAttribution {
+        position_record_id: open.id,
+        bot_name: open.record.bot_name.clone(),
+        symbol: open.record.symbol.clone(),
+        carry: open.record.carry.clone(),
+        opened_at: open.record.executed_at,
+        closed_at: close.map(|c| c.record.executed_at),
+        funding_bps: notional.map(|n| funding_usdt / n * 10_000.0),
+        funding_usdt,
+        settlements,
+        basis_bps,
+    }
+}
+
+/// 포지션 OPEN 기록 ID로 수익 분해 조회 (기록이 없거나 OPEN 기록이 아니면 None)
+pub async fn funding_attribution(
+    position_record_id: i64,
+) -> Result<Option<FundingAttribution>, RecordError> {
+    let (Some(positions), Some(settlements)) = (
+        get_position_repository(),
+        get_funding_settlement_repository(),
+    ) else {
+        return Err(RecordError::Other("Repository not initialized".to_string()));
+    };
+    let Some(open) = positions.find_by_id(position_record_id).await? else {
+        return Ok(None);
+    };
+    if open.record.action != PositionAction::Open.record_label() {
+        return Ok(None);
+    }
+    let records = positions.find_by_symbol(&open.record.symbol, None).await?;
+    let close = closing_record(&records, &open);
+    let settled = settlements.find_by_position(open.id).await?;
+    Ok(Some(attribute(&open, close, settled)))
+}
+
+/// 펀딩비 정산 기록기
+pub struct FundingAttributor {
+    /// 다음 조회 시작 시각 (ms)
+    cursor: Mutex<Option<i64>>,
+    started: AtomicBool,
+}
+
+impl FundingAttributor {
+    fn new() -> Self {
+        Self {
+            cursor: Mutex::new(None),
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /// 저장된 마지막 정산 이후부터, 없으면 `lookback` 전부터 조회
+    async fn initial_cursor(&self, params: FundingAttributionParams) -> i64 {
+        let latest = match get_funding_settlement_repository() {
+            Some(repo) => repo.find_recent(Some(1)).await.unwrap_or_else(|e| {
+                warn!("저장된 펀딩비 정산 기록 조회 실패: {}", e);
+                Vec::new()
+            }),
+            None => Vec::new(),
+        };
+        match latest.first() {
+            Some(stored) => stored.record.settled_at.timestamp_millis() + 1,
+            None => (Utc::now() - params.lookback).timestamp_millis(),
+        }
+    }
+
+    /// 새 정산 내역을 포지션에 연결해 저장, 기록한 건수 반환
+    /// 중간에 실패하면 커서가 그대로라 다음 조회에서 같은 정산이 다시 오지만, 이미 저장된 정산은 무시된다
+    pub async fn poll(
+        &self,
+        trader: &BinanceTrader,
+        params: FundingAttributionParams,
+    ) -> Result<usize, String> {
+        let cursor = *self.cursor.lock().unwrap();
+        let start = match cursor {
+            Some(cursor) => cursor,
+            None => self.initial_cursor(params).await,
+        };
+        let incomes = trader
+            .futures
+            .get_funding_income(start)
+            .await
+            .map_err(|e| e.to_string())?;
+        let Some(positions) = get_position_repository() else {
+            return Err("Position repository not initialized".to_string());
+        };
+
+        let mut by_symbol: HashMap<&str, Vec<&FundingIncome>> = HashMap::new();
+        for income in &incomes {
+            by_symbol.entry(&income.symbol).or_default().push(income);
+        }
+
+        let mut saved = 0;
+        for (symbol, incomes) in by_symbol {
+            let records = positions
+                .find_by_symbol(symbol, Some(POSITION_SCAN_LIMIT))
+                .await
+                .map_err(|e| e.to_string())?;
+            let (first, last) = (incomes[0].time, incomes[incomes.len() - 1].time);
+            let rates = trader
+                .futures
+                .get_funding_rate_history(symbol, first - 60_000, last + 60_000)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("{} 펀딩비율 기록 조회 실패: {}", symbol, e);
+                    Vec::new()
+                });
+            let mut fallback_qty = None;
+            for income in incomes {
+                let Some(position) = open_position_at(&records, ms_to_datetime(income.time)) else {
+                    debug!(
+                        "{} 펀딩비 정산 {:.8} USDT: 열린 포지션 기록 없음",
+                        symbol, income.income
+                    );
+                    continue;
+                };
+                // 정산 시각과 1분 이내의 펀딩비율 (income 시각은 정산 시각과 조금 어긋날 수 있음)
+                let rate = rates
+                    .iter()
+                    .filter(|r| (r.funding_time - income.time).abs() <= 60_000)
+                    .min_by_key(|r| (r.funding_time - income.time).abs());
+                if rate.is_none_or(|r| r.funding_rate == 0.0 || r.mark_price.is_none())
+                    && fallback_qty.is_none()
+                {
+                    fallback_qty = trader
+                        .futures
+                        .get_position_risk(symbol)
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|p| p.position_amt);
+                }
+                let record = settlement_record(income, rate, position, fallback_qty);
+                save_funding_settlement_record_safe(&record).await;
+                saved += 1;
+            }
+        }
+
+        let next = incomes.iter().map(|i| i.time + 1).max().unwrap_or(start);
+        *self.cursor.lock().unwrap() = Some(next.max(start));
+        Ok(saved)
+    }
+
+    /// 주기적으로 정산 내역 조회 시작 (중복 호출은 무시)
+    pub fn start(&'static self, params: FundingAttributionParams) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        info!(
+            "펀딩비 정산 기록 시작 ({}초 간격, 최초 조회 {}시간 전부터)",
+            params.interval.as_secs(),
+            params.lookback.num_hours()
+        );
+        tokio::spawn(async move {
+            let trader = match BinanceTrader::new() {
+                Ok(trader) => trader,
+                Err(e) => {
+                    warn!("펀딩비 정산 기록 중단: Binance 클라이언트 생성 실패: {}", e);
+                    return;
+                }
+            };
+            loop {
+                match self.poll(&trader, params).await {
+                    Ok(0) => {}
+                    Ok(saved) => info!("펀딩비 정산 {}건 기록", saved),
+                    Err(e) => warn!("펀딩비 정산 내역 조회 실패: {}", e),
+                }
+                tokio::time::sleep(params.interval).await;
+            }
+        });
+    }
+}
+
+static FUNDING_ATTRIBUTOR: OnceLock<FundingAttributor> = OnceLock::new();
+
+/// 전역 펀딩비 정산 기록기
+pub fn funding_attributor() -> &'static FundingAttributor {
+    FUNDING_ATTRIBUTOR.get_or_init(FundingAttributor::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::PositionRecord;
+
+    fn position(id: i64, minutes: i64, action: &str, spot: f64, mark: f64) -> StoredPositionRecord {
+        bot_position("intra_basis", id, minutes, action, spot, mark)
+    }
+
+    fn bot_position(
+        bot: &str,
+        id: i64,
+        minutes: i64,
+        action: &str,
+        spot: f64,
+        mark: f64,
+    ) -> StoredPositionRecord {
+        StoredPositionRecord {
+            id,
+            record: PositionRecord {
+                executed_at: Utc.timestamp_opt(1_700_000_000 + minutes * 60, 0).unwrap(),
+                bot_name: bot.to_string(),
+                carry: "CARRY".to_string(),
+                action: action.to_string(),
+                symbol: "BTCUSDT".to_string(),
+                spot_price: spot,
+                futures_mark: mark,
+                buy_exchange: "binance_spot".to_string(),
+                sell_exchange: FUNDING_VENUE.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_settlement_linked_to_open_position_and_attributed() {
+        let records = vec![
+            position(1, 0, "OPEN", 100.0, 100.2),
+            position(2, 60, PARTIAL_CLOSE_LABEL, 100.0, 100.1),
+            position(3, 600, "CLOSE", 100.0, 100.05),
+        ];
+        let at = |minutes: i64| Utc.timestamp_opt(1_700_000_000 + minutes * 60, 0).unwrap();
+
+        // 부분 청산 뒤에도 열린 포지션, 청산 뒤와 진입 전에는 없음
+        assert_eq!(open_position_at(&records, at(120)).map(|r| r.id), Some(1));
+        assert!(open_position_at(&records, at(700)).is_none());
+        assert!(open_position_at(&records, at(-10)).is_none());
+
+        // 숏 2개, 펀딩비율 0.01%, 마크 100 → 0.02 USDT 수취
+        let income = FundingIncome {
+            symbol: "BTCUSDT".to_string(),
+            income: 0.02,
+            time: at(480).timestamp_millis(),
+        };
+        let rate = FundingRateEntry {
+            funding_time: income.time,
+            funding_rate: 0.0001,
+            mark_price: Some(100.0),
+        };
+        let record = settlement_record(&income, Some(&rate), &records[0], None);
+        assert_eq!(record.position_record_id, 1);
+        assert!((record.position_qty + 2.0).abs() < 1e-9);
+        // 펀딩비율을 모르면 현재 포지션 수량 사용
+        assert_eq!(
+            settlement_record(&income, None, &records[0], Some(-1.5)).position_qty,
+            -1.5
+        );
+
+        let open = &records[0];
+        let close = closing_record(&records, open);
+        assert_eq!(close.map(|r| r.id), Some(3));
+        let stored = StoredFundingSettlementRecord { id: 1, record };
+        let attribution = attribute(open, close, vec![stored]);
+        assert!((attribution.funding_usdt - 0.02).abs() < 1e-12);
+        // 0.02 / (2 × 100) = 1 bps
+        assert!((attribution.funding_bps.unwrap() - 1.0).abs() < 1e-9);
+        // carry: 20 bps → 5 bps 수렴 = 15 bps
+        assert!((attribution.basis_bps.unwrap() - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_open_position_scoped_per_bot() {
+        let records = vec![
+            bot_position("bot_a", 1, 0, "OPEN", 100.0, 100.2),
+            bot_position("bot_b", 2, 10, "OPEN", 100.0, 100.3),
+            bot_position("bot_b", 3, 20, "CLOSE", 100.0, 100.1),
+            bot_position("bot_a", 4, 40, "CLOSE", 100.0, 100.0),
+        ];
+        let at = |minutes: i64| Utc.timestamp_opt(1_700_000_000 + minutes * 60, 0).unwrap();
+
+        // 둘 다 열려 있으면 최근에 연 bot_b, bot_b의 CLOSE는 bot_a 포지션을 닫지 않음
+        assert_eq!(open_position_at(&records, at(15)).map(|r| r.id), Some(2));
+        assert_eq!(open_position_at(&records, at(30)).map(|r| r.id), Some(1));
+        assert!(open_position_at(&records, at(50)).is_none());
+        assert_eq!(closing_record(&records, &records[0]).map(|r| r.id), Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_settlement_saved_once() {
+        use crate::record::{
+            FundingSettlementRecordRepository, SqliteFundingSettlementRecordRepository,
+        };
+
+        let repo = SqliteFundingSettlementRecordRepository::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let open = position(1, 0, "OPEN", 100.0, 100.2);
+        let income = FundingIncome {
+            symbol: "BTCUSDT".to_string(),
+            income: 0.02,
+            time: 1_700_000_000_000 + 480 * 60_000,
+        };
+        let record = settlement_record(&income, None, &open, Some(-2.0));
+        // 커서가 전진하기 전에 같은 정산을 다시 조회한 경우
+        repo.save(&record).await.unwrap();
+        repo.save(&record).await.unwrap();
+        assert_eq!(repo.find_by_position(1).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,300 @@
+//! 대량 체결(고래) 감지
+//!
+//! 설정한 심볼의 Binance aggTrade 스트림을 구독해 명목가가 기준 이상인 체결을 기록하고
+//! 알림을 보낸다. 베이시스가 갑자기 움직였을 때 원인을 확인하는 참고 자료로 쓴다.
+//! `LARGE_TRADE_SYMBOLS`가 비어 있으면 수집기를 띄우지 않는다.
+
+use std::collections::VecDeque;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use exchanges::supervisor::{WS_STALE_AFTER, task_supervisor};
+use exchanges::ws::{Heartbeat, PingMessage, ReconnectConfig, ReconnectingClient, WsHandler};
+use interface::ExchangeId;
+
+use crate::notification::{AlertLevel, notification_center};
+use crate::trader::MarketKind;
+
+const SPOT_WS_URL: &str = "wss://stream.binance.com:9443/ws";
+const FUTURES_WS_URL: &str = "wss://fstream.binance.com/ws";
+
+/// 대량 체결 알림 종류
+pub const LARGE_TRADE_ALERT: &str = "large_trade";
+
+/// 메모리에 보관하는 최근 대량 체결 개수
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// 기본 명목가 기준 (USDT)
+const DEFAULT_MIN_NOTIONAL: f64 = 1_000_000.0;
+
+/// 대량 체결 수집 설정
+#[derive(Debug, Clone)]
+pub struct LargeTradeConfig {
+    pub symbols: Vec<String>,
+    pub markets: Vec<MarketKind>,
+    /// 이 명목가(견적 통화 단위) 이상인 체결만 기록
+    pub min_notional: f64,
+}
+
+impl LargeTradeConfig {
+    /// 환경 변수에서 설정 로드
+    /// - LARGE_TRADE_SYMBOLS: 쉼표로 구분한 심볼 (예: "BTCUSDT,ETHUSDT")
+    /// - LARGE_TRADE_MARKETS: "spot", "futures" 또는 둘 다 (기본 둘 다)
+    /// - LARGE_TRADE_MIN_NOTIONAL: 명목가 기준 (기본 1,000,000)
+    pub fn from_env() -> Self {
+        let list = |key: &str| -> Vec<String> {
+            std::env::var(key)
+                .unwrap_or_default()
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        };
+
+        let symbols = list("LARGE_TRADE_SYMBOLS")
+            .into_iter()
+            .map(|s| s.to_uppercase())
+            .collect();
+        let mut markets: Vec<MarketKind> = list("LARGE_TRADE_MARKETS")
+            .iter()
+            .filter_map(|m| match m.to_lowercase().as_str() {
+                "spot" => Some(MarketKind::Spot),
+                "futures" => Some(MarketKind::Futures),
+                other => {
+                    warn!("알 수 없는 LARGE_TRADE_MARKETS 값 무시: {}", other);
+                    None
+                }
+            })
+            .collect();
+        if markets.is_empty() {
+            markets = vec![MarketKind::Spot, MarketKind::Futures];
+        }
+        let min_notional = std::env::var("LARGE_TRADE_MIN_NOTIONAL")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MIN_NOTIONAL);
+
+        Self {
+            symbols,
+            markets,
+            min_notional,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+}
+
+/// 기준 이상으로 감지된 체결 한 건
+#[derive(Debug, Clone, Serialize)]
+pub struct LargeTrade {
+    pub symbol: String,
+    /// "spot" / "futures"
+    pub market: String,
+    pub price: f64,
+    pub qty: f64,
+    pub notional: f64,
+    /// 테이커 방향 ("BUY" = 시장가 매수)
+    pub taker_side: &'static str,
+    pub trade_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AggTrade {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "p")]
+    price: String,
+    #[serde(rename = "q")]
+    qty: String,
+    #[serde(rename = "T")]
+    trade_time: i64,
+    /// 매수자가 메이커인지 (true면 테이커는 매도)
+    #[serde(rename = "m")]
+    buyer_is_maker: bool,
+}
+
+/// aggTrade 메시지를 파싱해 기준 이상이면 반환
+pub fn detect_large_trade(text: &str, market: MarketKind, min_notional: f64) -> Option<LargeTrade> {
+    let trade: AggTrade = serde_json::from_str(text).ok()?;
+    let price: f64 = trade.price.parse().ok()?;
+    let qty: f64 = trade.qty.parse().ok()?;
+    let notional = price * qty;
+    if notional < min_notional {
+        return None;
+    }
+    Some(LargeTrade {
+        symbol: trade.symbol,
+        market: market.to_string(),
+        price,
+        qty,
+        notional,
+        taker_side: if trade.buyer_is_maker { "SELL" } else { "BUY" },
+        trade_time: Utc
+            .timestamp_millis_opt(trade.trade_time)
+            .single()
+            .unwrap_or_else(Utc::now),
+    })
+}
+
+/// 최근 대량 체결 보관소
+pub struct LargeTradeLog {
+    capacity: usize,
+    trades: RwLock<VecDeque<LargeTrade>>,
+}
+
+impl Default for LargeTradeLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl LargeTradeLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            trades: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    pub fn record(&self, trade: LargeTrade) {
+        let mut trades = self.trades.write().unwrap();
+        if trades.len() >= self.capacity {
+            trades.pop_front();
+        }
+        trades.push_back(trade);
+    }
+
+    /// 최근 대량 체결 (최신순, 심볼 필터 선택)
+    pub fn recent(&self, limit: usize, symbol: Option<&str>) -> Vec<LargeTrade> {
+        let trades = self.trades.read().unwrap();
+        trades
+            .iter()
+            .rev()
+            .filter(|t| symbol.is_none_or(|s| t.symbol.eq_ignore_ascii_case(s)))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+static GLOBAL_LARGE_TRADES: OnceLock<LargeTradeLog> = OnceLock::new();
+
+/// 전역 대량 체결 보관소 (`/large-trades`)
+pub fn large_trades() -> &'static LargeTradeLog {
+    GLOBAL_LARGE_TRADES.get_or_init(LargeTradeLog::default)
+}
+
+/// ReconnectingClient 용 aggTrade 핸들러
+struct AggTradeHandler {
+    market: MarketKind,
+    min_notional: f64,
+}
+
+#[async_trait]
+impl WsHandler for AggTradeHandler {
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+        let Some(trade) = detect_large_trade(text, self.market, self.min_notional) else {
+            return Ok(());
+        };
+
+        let title = format!(
+            "{} {} 대량 체결 {}",
+            trade.symbol, trade.market, trade.taker_side
+        );
+        let message = format!(
+            "{:.4} @ {:.4} (명목가 {:.0})",
+            trade.qty, trade.price, trade.notional
+        );
+        let data = serde_json::to_value(&trade)?;
+        large_trades().record(trade);
+        notification_center()
+            .notify(LARGE_TRADE_ALERT, AlertLevel::Info, title, message, data)
+            .await;
+        Ok(())
+    }
+}
+
+/// 설정된 심볼/시장마다 aggTrade 수집 태스크 시작
+pub fn start_large_trade_collector(config: &LargeTradeConfig) {
+    if !config.is_enabled() {
+        return;
+    }
+    info!(
+        "대량 체결 감지 시작: {:?} ({:?}, 기준 {})",
+        config.symbols, config.markets, config.min_notional
+    );
+
+    for symbol in &config.symbols {
+        for market in &config.markets {
+            let base_url = match market {
+                MarketKind::Spot => SPOT_WS_URL,
+                MarketKind::Futures => FUTURES_WS_URL,
+            };
+            let url = format!("{}/{}@aggTrade", base_url, symbol.to_lowercase());
+            let client = ReconnectingClient::new(&format!("{} aggTrade {}", market, symbol), &url)
+                .with_config(ReconnectConfig {
+                    heartbeat: Some(Heartbeat {
+                        interval: Duration::from_secs(30),
+                        timeout: Duration::from_secs(90),
+                        message: PingMessage::Frame,
+                    }),
+                    ..Default::default()
+                })
+                .with_status(
+                    ExchangeId::Binance,
+                    &format!("{}_agg_trade_ws:{}", market, symbol),
+                );
+            let (market, min_notional) = (*market, config.min_notional);
+            task_supervisor().spawn(
+                &format!("binance_{}_agg_trade_ws:{}", market, symbol),
+                Some(WS_STALE_AFTER),
+                move || {
+                    let client = client.clone();
+                    let mut handler = AggTradeHandler {
+                        market,
+                        min_notional,
+                    };
+                    async move {
+                        client.run(&mut handler).await;
+                    }
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_large_trade_and_log() {
+        let small = r#"{"e":"aggTrade","E":1,"s":"BTCUSDT","a":1,"p":"60000.0","q":"0.5","f":1,"l":1,"T":1700000000000,"m":false}"#;
+        assert!(detect_large_trade(small, MarketKind::Futures, 1_000_000.0).is_none());
+
+        let large = r#"{"e":"aggTrade","E":1,"s":"BTCUSDT","a":2,"p":"60000.0","q":"20","f":2,"l":3,"T":1700000000000,"m":true}"#;
+        let trade = detect_large_trade(large, MarketKind::Futures, 1_000_000.0).unwrap();
+        assert_eq!(trade.market, "futures");
+        assert_eq!(trade.taker_side, "SELL");
+        assert!((trade.notional - 1_200_000.0).abs() < 1e-6);
+        assert_eq!(trade.trade_time.timestamp_millis(), 1_700_000_000_000);
+
+        let log = LargeTradeLog::new(2);
+        for symbol in ["BTCUSDT", "ETHUSDT", "BTCUSDT"] {
+            log.record(LargeTrade {
+                symbol: symbol.to_string(),
+                ..trade.clone()
+            });
+        }
+        assert_eq!(log.recent(10, None).len(), 2);
+        assert_eq!(log.recent(10, Some("btcusdt")).len(), 1);
+        assert_eq!(log.recent(10, None)[0].symbol, "BTCUSDT");
+    }
+}
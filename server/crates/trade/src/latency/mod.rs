@@ -0,0 +1,274 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// 베뉴별로 유지하는 최근 샘플 개수
+const DEFAULT_WINDOW: usize = 1000;
+
+/// 측정 항목 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LatencyKind {
+    /// 주문 제출 → 거래소 응답(ack)까지 걸린 시간
+    OrderAck,
+    /// 가격 피드 메시지의 거래소 이벤트 시각 → 수신 시각 차이
+    FeedAge,
+}
+
+impl fmt::Display for LatencyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LatencyKind::OrderAck => write!(f, "order_ack"),
+            LatencyKind::FeedAge => write!(f, "feed_age"),
+        }
+    }
+}
+
+/// 최근 N개 샘플(ms)을 유지하는 롤링 윈도우
+#[derive(Debug, Clone)]
+pub struct RollingStats {
+    samples: VecDeque<f64>,
+    capacity: usize,
+    total_count: u64,
+}
+
+impl RollingStats {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            total_count: 0,
+        }
+    }
+
+    /// 샘플 추가 (윈도우가 가득 차면 가장 오래된 샘플 제거)
+    pub fn record(&mut self, value_ms: f64) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value_ms);
+        self.total_count += 1;
+    }
+
+    /// 윈도우 내 백분위수 (nearest-rank 방식, p는 0~100)
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        Some(percentile_of_sorted(&sorted, p))
+    }
+
+    pub fn summary(&self) -> LatencySummary {
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let (p50, p90, p99, max) = if sorted.is_empty() {
+            (0.0, 0.0, 0.0, 0.0)
+        } else {
+            (
+                percentile_of_sorted(&sorted, 50.0),
+                percentile_of_sorted(&sorted, 90.0),
+                percentile_of_sorted(&sorted, 99.0),
+                sorted[sorted.len() - 1],
+            )
+        };
+
+        LatencySummary {
+            total_count: self.total_count,
+            window_count: sorted.len(),
+            last_ms: self.samples.back().copied().unwrap_or(0.0),
+            p50_ms: p50,
+            p90_ms: p90,
+            p99_ms: p99,
+            max_ms: max,
+        }
+    }
+}
+
+fn percentile_of_sorted(sorted: &[f64], p: f64) -> f64 {
+    let p = p.clamp(0.0, 100.0);
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.saturating_sub(1).min(sorted.len() - 1)]
+}
+
+/// 롤링 윈도우 요약
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencySummary {
+    /// 누적 샘플 수
+    pub total_count: u64,
+    /// 현재 윈도우에 있는 샘플 수
+    pub window_count: usize,
+    pub last_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+/// 베뉴/항목별 지연 리포트 한 줄
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyReport {
+    /// 베뉴 이름 (예: "binance_spot", "binance_futures", "bithumb")
+    pub venue: String,
+    pub kind: LatencyKind,
+    #[serde(flatten)]
+    pub summary: LatencySummary,
+}
+
+/// 베뉴별 주문 왕복 시간 및 가격 피드 지연 추적기
+#[derive(Debug)]
+pub struct LatencyTracker {
+    window: usize,
+    stats: RwLock<HashMap<(String, LatencyKind), RollingStats>>,
+}
+
+impl Default for LatencyTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_WINDOW)
+    }
+}
+
+impl LatencyTracker {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            stats: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn record(&self, venue: &str, kind: LatencyKind, value_ms: f64) {
+        if !value_ms.is_finite() {
+            return;
+        }
+        let mut stats = self.stats.write().unwrap();
+        stats
+            .entry((venue.to_string(), kind))
+            .or_insert_with(|| RollingStats::new(self.window))
+            .record(value_ms.max(0.0));
+    }
+
+    /// 주문 제출 → ack 시간 기록
+    pub fn record_order_ack(&self, venue: &str, elapsed: Duration) {
+        self.record(venue, LatencyKind::OrderAck, elapsed.as_secs_f64() * 1000.0);
+    }
+
+    /// 가격 피드 메시지 지연 기록 (event_time_ms: 거래소 이벤트 시각, epoch ms)
+    pub fn record_feed_age(&self, venue: &str, event_time_ms: i64) {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        self.record(venue, LatencyKind::FeedAge, (now_ms - event_time_ms) as f64);
+    }
+
+    pub fn summary(&self, venue: &str, kind: LatencyKind) -> Option<LatencySummary> {
+        let stats = self.stats.read().unwrap();
+        stats.get(&(venue.to_string(), kind)).map(|s| s.summary())
+    }
+
+    /// 전체 리포트 (venue, kind 순으로 정렬)
+    pub fn report(&self) -> Vec<LatencyReport> {
+        let stats = self.stats.read().unwrap();
+        let mut reports: Vec<LatencyReport> = stats
+            .iter()
+            .map(|((venue, kind), s)| LatencyReport {
+                venue: venue.clone(),
+                kind: *kind,
+                summary: s.summary(),
+            })
+            .collect();
+        reports.sort_by(|a, b| (&a.venue, a.kind).cmp(&(&b.venue, b.kind)));
+        reports
+    }
+}
+
+/// 전역 지연 추적기
+static GLOBAL_LATENCY: OnceLock<LatencyTracker> = OnceLock::new();
+
+/// 전역 지연 추적기 가져오기 (최초 호출 시 생성)
+pub fn latency_tracker() -> &'static LatencyTracker {
+    GLOBAL_LATENCY.get_or_init(LatencyTracker::default)
+}
+
+/// 지연 리포트를 표 형태로 출력
+pub fn print_latency_report(reports: &[LatencyReport]) {
+    if reports.is_empty() {
+        println!("수집된 지연 데이터가 없습니다.");
+        return;
+    }
+
+    println!(
+        "{:<18} {:<10} {:>8} {:>10} {:>10} {:>10} {:>10} {:>10}",
+        "VENUE", "KIND", "COUNT", "LAST", "P50", "P90", "P99", "MAX"
+    );
+    for r in reports {
+        println!(
+            "{:<18} {:<10} {:>8} {:>10.2} {:>10.2} {:>10.2} {:>10.2} {:>10.2}",
+            r.venue,
+            r.kind.to_string(),
+            r.summary.window_count,
+            r.summary.last_ms,
+            r.summary.p50_ms,
+            r.summary.p90_ms,
+            r.summary.p99_ms,
+            r.summary.max_ms
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles() {
+        let mut stats = RollingStats::new(100);
+        for v in 1..=100 {
+            stats.record(v as f64);
+        }
+        assert_eq!(stats.percentile(50.0), Some(50.0));
+        assert_eq!(stats.percentile(99.0), Some(99.0));
+        assert_eq!(stats.percentile(100.0), Some(100.0));
+
+        let summary = stats.summary();
+        assert_eq!(summary.window_count, 100);
+        assert_eq!(summary.max_ms, 100.0);
+        assert_eq!(summary.last_ms, 100.0);
+    }
+
+    #[test]
+    fn test_rolling_window_drops_old_samples() {
+        let mut stats = RollingStats::new(3);
+        for v in [100.0, 1.0, 2.0, 3.0] {
+            stats.record(v);
+        }
+        let summary = stats.summary();
+        assert_eq!(summary.total_count, 4);
+        assert_eq!(summary.window_count, 3);
+        assert_eq!(summary.max_ms, 3.0);
+    }
+
+    #[test]
+    fn test_tracker_report_per_venue() {
+        let tracker = LatencyTracker::new(10);
+        tracker.record_order_ack("binance_spot", Duration::from_millis(12));
+        tracker.record_order_ack("bithumb", Duration::from_millis(40));
+        tracker.record("binance_spot", LatencyKind::FeedAge, 5.0);
+
+        let report = tracker.report();
+        assert_eq!(report.len(), 3);
+        assert_eq!(report[0].venue, "binance_spot");
+        assert_eq!(report[0].kind, LatencyKind::OrderAck);
+        assert!(
+            (tracker
+                .summary("bithumb", LatencyKind::OrderAck)
+                .unwrap()
+                .p50_ms
+                - 40.0)
+                .abs()
+                < 1e-6
+        );
+    }
+}
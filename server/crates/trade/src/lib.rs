@@ -16,10 +16,34 @@ fn setup() {
     init();
 }
 
+pub mod account_watch;
+pub mod accounting;
+pub mod address_book;
+pub mod allocation;
 pub mod arbitrage;
+pub mod backtest;
+pub mod bnb_fee;
+pub mod clock;
+pub mod console;
+pub mod credentials;
+pub mod dust;
 pub mod emergency;
+pub mod equity;
+pub mod events;
 pub mod explore;
+pub mod exposure;
+pub mod funding;
+pub mod large_trade;
+pub mod latency;
+pub mod listing;
 pub mod logger;
+pub mod notification;
+pub mod oracle_client;
+pub mod preflight;
 pub mod record;
 pub mod server;
+pub mod smoke_test;
+pub mod symbol_info;
 pub mod trader;
+pub mod transfer_status;
+pub mod volatility;
@@ -0,0 +1,262 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use exchanges::weight::TrackedSend;
+use interface::ExchangeError;
+
+use crate::notification::{AlertLevel, notification_center};
+
+const SPOT_BASE_URL: &str = "https://api.binance.com";
+const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
+
+/// Binance USDⓈ-M 선물 기본 펀딩 주기 (시간)
+const FUNDING_INTERVAL_HOURS: f64 = 8.0;
+
+/// 신규 상장 알림 종류
+pub const NEW_LISTING_ALERT: &str = "new_listing";
+
+/// 신규 선물 상장 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListingAlert {
+    pub symbol: String,
+    /// 상장 직후 펀딩비 (예: 0.001 = 0.1%)
+    pub funding_rate: f64,
+    pub mark_price: f64,
+    /// 다음 펀딩 시각 (epoch ms)
+    pub next_funding_time: i64,
+    /// 같은 심볼의 스팟 마켓 존재 여부
+    pub spot_available: bool,
+    /// 현재 펀딩비가 유지된다고 가정한 일일 캐리 (bps)
+    pub daily_carry_bps: f64,
+    /// 현재 펀딩비가 유지된다고 가정한 연환산 캐리
+    pub annualized_carry: f64,
+    /// 스팟 매수 + 선물 매도로 캐리를 받을 수 있는지 (스팟 존재 && 펀딩비 > 0)
+    pub carry_capturable: bool,
+}
+
+/// 펀딩비로부터 일일 캐리(bps)와 연환산 캐리 추정
+pub fn estimate_carry(funding_rate: f64, funding_interval_hours: f64) -> (f64, f64) {
+    let periods_per_day = 24.0 / funding_interval_hours.max(1.0);
+    let daily = funding_rate.abs() * periods_per_day;
+    (daily * 10_000.0, daily * 365.0)
+}
+
+/// 이전 심볼 집합에 없는 신규 심볼 (정렬됨)
+pub fn diff_new_listings(known: &HashSet<String>, current: &HashSet<String>) -> Vec<String> {
+    let mut listed: Vec<String> = current.difference(known).cloned().collect();
+    listed.sort();
+    listed
+}
+
+#[derive(Debug, Deserialize)]
+struct ExchangeInfo {
+    symbols: Vec<SymbolInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SymbolInfo {
+    symbol: String,
+    status: String,
+    #[serde(default)]
+    contract_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PremiumIndex {
+    mark_price: String,
+    last_funding_rate: String,
+    next_funding_time: i64,
+}
+
+/// Binance 선물 신규 상장 감시기
+///
+/// 주기적으로 선물 exchangeInfo의 심볼 집합을 이전 값과 비교하고,
+/// 새 무기한 선물이 생기면 펀딩비/스팟 존재 여부/예상 캐리를 담아 알림을 보낸다.
+/// 첫 조회 결과는 기준 집합으로만 사용한다.
+pub struct ListingWatcher {
+    http: reqwest::Client,
+    spot_base_url: String,
+    futures_base_url: String,
+    interval: Duration,
+    known: Option<HashSet<String>>,
+}
+
+impl ListingWatcher {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            http: exchanges::http::http_client(),
+            spot_base_url: SPOT_BASE_URL.to_string(),
+            futures_base_url: FUTURES_BASE_URL.to_string(),
+            interval,
+            known: None,
+        }
+    }
+
+    /// 무기한 선물 심볼 집합 (TRADING 상태만)
+    async fn fetch_perp_symbols(&self) -> Result<HashSet<String>, ExchangeError> {
+        let url = format!("{}/fapi/v1/exchangeInfo", self.futures_base_url);
+        let info: ExchangeInfo = self.http.get(&url).send_tracked().await?.json().await?;
+        Ok(info
+            .symbols
+            .into_iter()
+            .filter(|s| s.status == "TRADING" && s.contract_type.as_deref() == Some("PERPETUAL"))
+            .map(|s| s.symbol)
+            .collect())
+    }
+
+    /// 스팟 심볼 집합 (TRADING 상태만)
+    async fn fetch_spot_symbols(&self) -> Result<HashSet<String>, ExchangeError> {
+        let url = format!("{}/api/v3/exchangeInfo", self.spot_base_url);
+        let info: ExchangeInfo = self.http.get(&url).send_tracked().await?.json().await?;
+        Ok(info
+            .symbols
+            .into_iter()
+            .filter(|s| s.status == "TRADING")
+            .map(|s| s.symbol)
+            .collect())
+    }
+
+    async fn build_alert(
+        &self,
+        symbol: &str,
+        spot_symbols: &HashSet<String>,
+    ) -> Result<ListingAlert, ExchangeError> {
+        let url = format!(
+            "{}/fapi/v1/premiumIndex?symbol={}",
+            self.futures_base_url, symbol
+        );
+        let premium: PremiumIndex = self.http.get(&url).send_tracked().await?.json().await?;
+
+        let funding_rate = premium.last_funding_rate.parse::<f64>().unwrap_or(0.0);
+        let mark_price = premium.mark_price.parse::<f64>().unwrap_or(0.0);
+        let spot_available = spot_symbols.contains(symbol);
+        let (daily_carry_bps, annualized_carry) =
+            estimate_carry(funding_rate, FUNDING_INTERVAL_HOURS);
+
+        Ok(ListingAlert {
+            symbol: symbol.to_string(),
+            funding_rate,
+            mark_price,
+            next_funding_time: premium.next_funding_time,
+            spot_available,
+            daily_carry_bps,
+            annualized_carry,
+            carry_capturable: spot_available && funding_rate > 0.0,
+        })
+    }
+
+    /// 한 번 비교하고 신규 상장 목록을 반환 (알림 전송 포함)
+    pub async fn check_once(&mut self) -> Result<Vec<ListingAlert>, ExchangeError> {
+        let current = self.fetch_perp_symbols().await?;
+
+        let Some(known) = self.known.as_ref() else {
+            info!("선물 상장 감시 기준 심볼 {}개 로드", current.len());
+            self.known = Some(current);
+            return Ok(Vec::new());
+        };
+
+        let listed = diff_new_listings(known, &current);
+        self.known = Some(current);
+        if listed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let spot_symbols = self.fetch_spot_symbols().await.unwrap_or_else(|e| {
+            warn!("스팟 exchangeInfo 조회 실패: {}", e);
+            HashSet::new()
+        });
+
+        let mut alerts = Vec::new();
+        for symbol in listed {
+            let alert = match self.build_alert(&symbol, &spot_symbols).await {
+                Ok(alert) => alert,
+                Err(e) => {
+                    warn!("{} 신규 상장 정보 조회 실패: {}", symbol, e);
+                    continue;
+                }
+            };
+
+            let level = if alert.carry_capturable {
+                AlertLevel::Warning
+            } else {
+                AlertLevel::Info
+            };
+            notification_center()
+                .notify(
+                    NEW_LISTING_ALERT,
+                    level,
+                    format!("Binance 선물 신규 상장: {}", alert.symbol),
+                    format!(
+                        "펀딩비 {:.4}%, 스팟 {}, 예상 캐리 {:.1}bps/일 (연 {:.1}%)",
+                        alert.funding_rate * 100.0,
+                        if alert.spot_available {
+                            "있음"
+                        } else {
+                            "없음"
+                        },
+                        alert.daily_carry_bps,
+                        alert.annualized_carry * 100.0
+                    ),
+                    serde_json::to_value(&alert).unwrap_or_default(),
+                )
+                .await;
+            alerts.push(alert);
+        }
+
+        Ok(alerts)
+    }
+
+    /// 주기적으로 신규 상장을 감시 (영원히 실행)
+    pub async fn run(mut self) {
+        info!(
+            "선물 신규 상장 감시 시작: {}초 간격",
+            self.interval.as_secs()
+        );
+        loop {
+            if let Err(e) = self.check_once().await {
+                warn!("선물 상장 목록 조회 실패: {}", e);
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_new_listings() {
+        let known: HashSet<String> = ["BTCUSDT", "ETHUSDT"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let current: HashSet<String> = ["BTCUSDT", "ETHUSDT", "NEWUSDT", "ABCUSDT"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        assert_eq!(
+            diff_new_listings(&known, &current),
+            vec!["ABCUSDT".to_string(), "NEWUSDT".to_string()]
+        );
+        assert!(diff_new_listings(&current, &known).is_empty());
+    }
+
+    #[test]
+    fn test_estimate_carry() {
+        // 0.1% / 8h → 하루 0.3% = 30bps, 연 109.5%
+        let (daily_bps, annual) = estimate_carry(0.001, 8.0);
+        assert!((daily_bps - 30.0).abs() < 1e-9);
+        assert!((annual - 1.095).abs() < 1e-9);
+
+        // 음수 펀딩비도 크기로 계산
+        let (daily_bps, _) = estimate_carry(-0.002, 4.0);
+        assert!((daily_bps - 120.0).abs() < 1e-9);
+    }
+}
@@ -1,11 +1,17 @@
 use color_eyre::eyre;
 use exchanges::BinanceClient;
+use interface::{Bps, Notional};
 use structopt::StructOpt;
 use tracing::info;
 
-mod explore;
-
-use trade::arbitrage::{IntraBasisArbitrageStrategy, StrategyParams};
+use trade::arbitrage::strategy::StrategyMode;
+use trade::arbitrage::{
+    CashAndCarryParams, CashAndCarryStrategy, IntraBasisArbitrageStrategy, SpotSpreadParams,
+    SpotSpreadStrategy, StrategyParams, StrategyParamsBuilder,
+};
+use trade::explore;
+use trade::oracle_client::{self, OracleClient};
+use trade::smoke_test::SmokeTestParams;
 
 // lib.rs에서 자동으로 dotenv가 로드됨
 
@@ -17,9 +23,282 @@ enum Command {
     /// Oracle 서버 및 거래소 데이터 조회 테스트
     ExploreTest,
     /// 베이시스 아비트라지 전략 테스트 (dry-run 모드)
-    ArbitrageTest,
+    /// 인자를 생략하면 ARB_* 환경 변수, 그것도 없으면 기본값. 잘못된 조합은 시작 전에 거부
+    ArbitrageTest {
+        #[structopt(long)]
+        symbol: Option<String>,
+        /// carry | reverse | auto
+        #[structopt(long)]
+        mode: Option<StrategyMode>,
+        /// 진입 임계값 (bps, 양수)
+        #[structopt(long, allow_hyphen_values = true)]
+        entry_bps: Option<f64>,
+        /// 청산 임계값 (bps, 진입보다 작아야 함)
+        #[structopt(long, allow_hyphen_values = true)]
+        exit_bps: Option<f64>,
+        /// 명목가 (USDT)
+        #[structopt(long)]
+        notional: Option<f64>,
+        #[structopt(long)]
+        leverage: Option<u32>,
+        /// 운용 자금 한도 (USDT, 명목가 × (1 + 1.02/레버리지) 이상, 변동성 사이징이면 최대 배율 적용)
+        #[structopt(long)]
+        capital_budget: Option<f64>,
+    },
+    /// 분기물 캐시 앤 캐리 (현물 롱 + 분기물 숏, 만기 전 다음 분기물로 롤오버)
+    CashAndCarry {
+        /// 현물 심볼
+        #[structopt(long, default_value = "BTCUSDT")]
+        spot_symbol: String,
+        /// 분기물 페어 (USDⓈ-M: BTCUSDT, COIN-M: BTCUSD)
+        #[structopt(long, default_value = "BTCUSDT")]
+        pair: String,
+        /// linear (USDⓈ-M) | inverse (COIN-M)
+        #[structopt(long, default_value = "linear")]
+        contract: String,
+        /// 진입 연율 베이시스 (bps)
+        #[structopt(long, default_value = "800")]
+        entry_annualized_bps: f64,
+        /// 롤오버 최소 연율 베이시스 (bps, 미만이면 청산)
+        #[structopt(long, default_value = "400")]
+        min_roll_annualized_bps: f64,
+        /// 만기 며칠 전에 롤오버할지
+        #[structopt(long, default_value = "3")]
+        roll_days: f64,
+        /// 명목가 (현물 호가 통화)
+        #[structopt(long, default_value = "100")]
+        notional: f64,
+        /// 실제 주문 실행 (기본은 dry-run)
+        #[structopt(long)]
+        live: bool,
+    },
+    /// 현물-현물 거래소 간 스프레드 (싼 거래소 매수 + 비싼 거래소 재고 매도, 선물 없음)
+    SpotSpread {
+        /// 거래소 A (binance | bybit | okx | bithumb)
+        #[structopt(long, default_value = "binance")]
+        venue_a: String,
+        #[structopt(long, default_value = "BTCUSDT")]
+        symbol_a: String,
+        /// 거래소 B
+        #[structopt(long, default_value = "bithumb")]
+        venue_b: String,
+        #[structopt(long, default_value = "BTCUSDT")]
+        symbol_b: String,
+        /// 베이스 자산
+        #[structopt(long, default_value = "BTC")]
+        base_asset: String,
+        /// 호가 자산
+        #[structopt(long, default_value = "USDT")]
+        quote_asset: String,
+        /// 거래소 B 가격을 A 통화로 환산하는 계수
+        #[structopt(long, default_value = "1.0")]
+        fx_b: f64,
+        /// 거래소 A taker 수수료율
+        #[structopt(long, default_value = "0.001")]
+        fee_a: f64,
+        /// 거래소 B taker 수수료율
+        #[structopt(long, default_value = "0.0025")]
+        fee_b: f64,
+        /// 수수료 차감 후 진입 스프레드 (bps)
+        #[structopt(long, default_value = "10")]
+        entry_bps: f64,
+        /// 1회 매매 명목가 (A 통화)
+        #[structopt(long, default_value = "100")]
+        notional: f64,
+        /// 거래소별 최소 베이스 재고 비율 (0.0 ~ 0.5)
+        #[structopt(long, default_value = "0.2")]
+        min_inventory_ratio: f64,
+        /// 실제 주문 실행 (기본은 dry-run)
+        #[structopt(long)]
+        live: bool,
+    },
     /// 강제 청산 테스트 (모든 자산을 USDT/KRW로 변환)
     EmergencyTest,
+    /// 실행 중인 봇의 베뉴별 주문/가격 피드 지연 통계 출력
+    Latency,
+    /// 실행 중인 봇 제어 콘솔 (status, basis, balances, pause, resume, flatten)
+    Console,
+    /// 거래소 REST 연결 점검 및 클라이언트별 연결 상태 출력
+    Preflight,
+    /// Binance 선물 신규 상장 감시 (알림은 /alerts에서 조회)
+    ListingWatch {
+        /// 조회 간격 (초)
+        #[structopt(long, default_value = "60")]
+        interval: u64,
+    },
+    /// Binance 지갑 간 / 마스터 ↔ 서브 계정 이체
+    Transfer {
+        /// 출발 지갑 (spot | futures)
+        #[structopt(long)]
+        from: String,
+        /// 도착 지갑 (spot | futures)
+        #[structopt(long)]
+        to: String,
+        #[structopt(long, default_value = "USDT")]
+        asset: String,
+        #[structopt(long)]
+        amount: f64,
+        /// 출발 서브 계정 이메일 (이메일을 지정하면 마스터 계정 API로 서브 계정 이체)
+        #[structopt(long)]
+        from_email: Option<String>,
+        /// 도착 서브 계정 이메일
+        #[structopt(long)]
+        to_email: Option<String>,
+    },
+    /// 백테스트 파라미터 격자 탐색 (전체 결과는 CSV로 저장)
+    Optimize {
+        /// 가격 데이터 CSV (time,spot,futures) 또는 오라클 시계열 디렉터리 (ORACLE_TIMESERIES_DIR)
+        #[structopt(long)]
+        data: String,
+        #[structopt(long, default_value = "XPLUSDT")]
+        symbol: String,
+        /// 시계열 디렉터리에서 읽을 거래소
+        #[structopt(long, default_value = "binance")]
+        exchange: String,
+        /// carry | reverse | auto
+        #[structopt(long, default_value = "carry")]
+        mode: String,
+        /// 진입 bps 목록 ("2,4,6" 또는 "start:end:step")
+        #[structopt(long, default_value = "2:10:2")]
+        entry_bps: String,
+        /// 청산 bps 목록
+        #[structopt(long, default_value = "-6:2:2")]
+        exit_bps: String,
+        /// 명목가 목록 (USDT)
+        #[structopt(long, default_value = "100")]
+        notional: String,
+        /// 손절 bps 목록 ("none"은 손절 없음)
+        #[structopt(long, default_value = "none")]
+        stop_bps: String,
+        /// 레그별 수수료 (bps)
+        #[structopt(long, default_value = "4")]
+        fee_bps: f64,
+        /// 지정 시 전체 격자 대신 무작위로 N개 조합만 실행
+        #[structopt(long)]
+        samples: Option<usize>,
+        #[structopt(long, default_value = "42")]
+        seed: u64,
+        /// 순위 기준 (pnl | sharpe | calmar | hit_rate)
+        #[structopt(long, default_value = "pnl")]
+        objective: String,
+        /// 결과 CSV 경로 (워크포워드 모드에서는 구간별 결과)
+        #[structopt(long, default_value = "results/optimize.csv")]
+        output: String,
+        /// 워크포워드 검증 모드 (학습 구간에서 고른 파라미터를 다음 구간에서 평가)
+        #[structopt(long)]
+        walk_forward: bool,
+        /// 워크포워드 학습 구간 길이 (시간)
+        #[structopt(long, default_value = "72")]
+        train_hours: f64,
+        /// 워크포워드 검증 구간 길이 (시간)
+        #[structopt(long, default_value = "24")]
+        test_hours: f64,
+    },
+    /// 거래 기록 기반 과세 연도별 실현 손익 보고서 (매도 내역은 CSV로 저장)
+    TaxReport {
+        /// 과세 연도 (KST 기준)
+        #[structopt(long)]
+        year: i32,
+        /// 취득가액 산정 방식 (fifo | average)
+        #[structopt(long, default_value = "fifo")]
+        method: String,
+        /// 환산 USDT/KRW 환율 (미지정 시 현재 빗썸 시세)
+        #[structopt(long)]
+        usdt_krw: Option<f64>,
+        /// 매도 내역 CSV 경로 (기본 results/tax-{year}.csv)
+        #[structopt(long)]
+        output: Option<String>,
+    },
+    /// 보관 기간(RECORD_RETENTION_*_DAYS)이 지난 기록을 Parquet로 내보내고 DB에서 삭제
+    Archive {
+        /// 대상 행 수만 출력 (파일 쓰기/삭제 안 함)
+        #[structopt(long)]
+        dry_run: bool,
+    },
+    /// 소액 잔고 정리 (Binance 더스트 → BNB, Bithumb 소액 잔고 → KRW)
+    SweepDust {
+        /// 대상만 출력 (변환/매도 안 함)
+        #[structopt(long)]
+        dry_run: bool,
+    },
+    /// Oracle REST API 조회 (표 형태로 출력)
+    Oracle(OracleCommand),
+    /// 출금 주소 화이트리스트 관리 (ADDRESS_BOOK_KEY로 암호화 저장)
+    AddressBook(AddressBookCommand),
+    /// Binance 테스트넷 주문 경로 점검 (필터 로드, 지정가 주문/취소, 시장가 헤지/청산)
+    SmokeTest {
+        #[structopt(long, default_value = "BTCUSDT")]
+        symbol: String,
+        /// 현물 지정가 주문 명목가 (USDT)
+        #[structopt(long, default_value = "15")]
+        spot_notional: f64,
+        /// 선물 헤지 주문 명목가 (USDT, 최소 주문 금액 100 이상)
+        #[structopt(long, default_value = "110")]
+        futures_notional: f64,
+    },
+}
+
+#[derive(Debug, StructOpt)]
+enum AddressBookCommand {
+    /// 주소 추가
+    Add {
+        /// 거래소 (binance | bybit | okx | bitget | bithumb)
+        #[structopt(long)]
+        exchange: String,
+        /// 자산 (예: USDT)
+        #[structopt(long)]
+        asset: String,
+        /// 출금 네트워크 (예: TRX)
+        #[structopt(long)]
+        network: String,
+        #[structopt(long)]
+        address: String,
+        /// 메모/태그
+        #[structopt(long)]
+        memo: Option<String>,
+        /// 표시용 이름
+        #[structopt(long, default_value = "")]
+        label: String,
+    },
+    /// 주소 삭제
+    Remove {
+        #[structopt(long)]
+        exchange: String,
+        #[structopt(long)]
+        asset: String,
+        /// 출금 네트워크 (예: TRX)
+        #[structopt(long)]
+        network: String,
+        #[structopt(long)]
+        address: String,
+        /// 메모/태그
+        #[structopt(long)]
+        memo: Option<String>,
+    },
+    /// 등록된 주소 목록 (마스킹)
+    List,
+    /// 거래소 화이트리스트와 대조
+    Verify,
+}
+
+#[derive(Debug, StructOpt)]
+enum OracleCommand {
+    /// 심볼/거래소별 통합 스냅샷
+    Snapshots {
+        /// 심볼 또는 베이스 자산 (예: BTCUSDT, BTC)
+        #[structopt(long)]
+        symbol: Option<String>,
+        /// 거래소 (binance | bybit | okx | bitget | bithumb)
+        #[structopt(long)]
+        exchange: Option<String>,
+    },
+    /// 펀딩비 절댓값 상위 N개
+    FundingTop {
+        #[structopt(default_value = "20")]
+        n: usize,
+    },
+    /// 자산의 거래소별 가격 프리미엄 (기준: Binance 현물)
+    Premium { asset: String },
 }
 
 #[tokio::main]
@@ -35,6 +314,15 @@ async fn main() -> eyre::Result<()> {
         .await
         .map_err(|e| eyre::eyre!("거래 기록 저장소 초기화 실패: {}", e))?;
 
+    // 전략 이벤트 구독자 (기록 저장, 알림, 지표, 감사 로그)
+    trade::events::install_default_subscribers();
+
+    // 이메일 알림 채널 (NOTIFY_EMAIL_SMTP_HOST 설정 시)
+    if let Some(config) = trade::notification::email::EmailConfig::from_env() {
+        trade::notification::email::install_email_sink(config)
+            .map_err(|e| eyre::eyre!("이메일 알림 설정 오류: {}", e))?;
+    }
+
     // dotenv는 lib.rs에서 자동으로 로드됨
 
     // API 서버를 백그라운드로 시작
@@ -51,14 +339,204 @@ async fn main() -> eyre::Result<()> {
 
     info!("API 서버가 포트 {}에서 시작되었습니다", server_port);
 
+    // 대량 체결 감지 (LARGE_TRADE_SYMBOLS 설정 시)
+    trade::large_trade::start_large_trade_collector(
+        &trade::large_trade::LargeTradeConfig::from_env(),
+    );
+
     let cmd = Command::from_args();
 
     // 커맨드 실행 (서버는 백그라운드에서 계속 실행됨)
     let result = match cmd {
-        Command::Run => run_bot().await,
+        Command::Run => {
+            start_strategy_jobs();
+            run_bot().await
+        }
         Command::ExploreTest => run_explore_test().await,
-        Command::ArbitrageTest => run_arbitrage_test().await,
+        Command::ArbitrageTest {
+            symbol,
+            mode,
+            entry_bps,
+            exit_bps,
+            notional,
+            leverage,
+            capital_budget,
+        } => {
+            let mut builder = StrategyParamsBuilder::from_env()?.dry_run(false);
+            if let Some(symbol) = symbol {
+                builder = builder.symbol(symbol);
+            }
+            if let Some(mode) = mode {
+                builder = builder.mode(mode);
+            }
+            if let Some(bps) = entry_bps {
+                builder = builder.entry_bps(bps);
+            }
+            if let Some(bps) = exit_bps {
+                builder = builder.exit_bps(bps);
+            }
+            if let Some(notional) = notional {
+                builder = builder.notional(notional);
+            }
+            if let Some(leverage) = leverage {
+                builder = builder.leverage(leverage);
+            }
+            if let Some(budget) = capital_budget {
+                builder = builder.capital_budget(budget);
+            }
+            let params = builder
+                .with(|params| {
+                    params.liquidity_floors =
+                        trade::arbitrage::liquidity::LiquidityFloors::from_env();
+                    params.entry_recheck = trade::arbitrage::recheck::EntryRecheck::from_env();
+                    params.margin_top_up = trade::arbitrage::margin::MarginTopUp::from_env();
+                    params.dynamic_leverage =
+                        trade::arbitrage::leverage::DynamicLeverage::from_env();
+                    params.shadows = trade::arbitrage::shadow::ShadowParams::from_env();
+                })
+                .build()
+                .map_err(|e| eyre::eyre!("전략 설정 오류: {}", e))?;
+            start_strategy_jobs();
+            run_arbitrage_test(params).await
+        }
+        Command::CashAndCarry {
+            spot_symbol,
+            pair,
+            contract,
+            entry_annualized_bps,
+            min_roll_annualized_bps,
+            roll_days,
+            notional,
+            live,
+        } => {
+            let params = CashAndCarryParams {
+                spot_symbol,
+                pair,
+                contract: contract.parse().map_err(|e: String| eyre::eyre!(e))?,
+                entry_annualized_bps: Bps::try_new(entry_annualized_bps)?,
+                min_roll_annualized_bps: Bps::try_new(min_roll_annualized_bps)?,
+                roll_days,
+                notional: Notional::try_new(notional)?,
+                dry_run: !live,
+                ..Default::default()
+            };
+            start_strategy_jobs();
+            run_cash_and_carry(params).await
+        }
+        Command::SpotSpread {
+            venue_a,
+            symbol_a,
+            venue_b,
+            symbol_b,
+            base_asset,
+            quote_asset,
+            fx_b,
+            fee_a,
+            fee_b,
+            entry_bps,
+            notional,
+            min_inventory_ratio,
+            live,
+        } => {
+            let params = SpotSpreadParams {
+                venue_a: trade::trader::parse_exchange_id(&venue_a)?,
+                symbol_a,
+                venue_b: trade::trader::parse_exchange_id(&venue_b)?,
+                symbol_b,
+                base_asset,
+                quote_asset,
+                fx_b,
+                fee_a,
+                fee_b,
+                entry_bps: Bps::try_new(entry_bps)?,
+                notional: Notional::try_new(notional)?,
+                min_inventory_ratio: min_inventory_ratio.clamp(0.0, 0.5),
+                dry_run: !live,
+                ..Default::default()
+            };
+            start_strategy_jobs();
+            run_spot_spread(params).await
+        }
         Command::EmergencyTest => run_emergency_test().await,
+        Command::Latency => run_latency_report().await,
+        Command::Console => run_console().await,
+        Command::Preflight => run_preflight().await,
+        Command::ListingWatch { interval } => run_listing_watch(interval).await,
+        Command::Transfer {
+            from,
+            to,
+            asset,
+            amount,
+            from_email,
+            to_email,
+        } => run_transfer(&from, &to, &asset, amount, from_email, to_email).await,
+        Command::Optimize {
+            data,
+            symbol,
+            exchange,
+            mode,
+            entry_bps,
+            exit_bps,
+            notional,
+            stop_bps,
+            fee_bps,
+            samples,
+            seed,
+            objective,
+            output,
+            walk_forward,
+            train_hours,
+            test_hours,
+        } => {
+            let grid = trade::backtest::optimize::ParamGrid {
+                entry_bps: parse_values(&entry_bps)?,
+                exit_bps: parse_values(&exit_bps)?,
+                notional: parse_values(&notional)?,
+                stop_bps: trade::backtest::optimize::parse_stop_values(&stop_bps)
+                    .map_err(|e| eyre::eyre!(e))?,
+            };
+            let base = trade::backtest::BacktestConfig {
+                symbol,
+                mode: mode.parse().map_err(|e: String| eyre::eyre!(e))?,
+                fee_bps,
+                ..Default::default()
+            };
+            let walk_forward =
+                walk_forward.then_some(trade::backtest::walk_forward::WalkForwardConfig {
+                    train_ms: (train_hours * 3_600_000.0) as i64,
+                    test_ms: (test_hours * 3_600_000.0) as i64,
+                });
+            let sweep = OptimizeOptions {
+                samples,
+                seed,
+                objective,
+                output,
+                walk_forward,
+            };
+            run_optimize(&data, &exchange, base, grid, sweep).await
+        }
+        Command::TaxReport {
+            year,
+            method,
+            usdt_krw,
+            output,
+        } => run_tax_report(year, &method, usdt_krw, output).await,
+        Command::Archive { dry_run } => run_archive(dry_run).await,
+        Command::SweepDust { dry_run } => run_sweep_dust(dry_run).await,
+        Command::Oracle(oracle) => run_oracle(oracle).await,
+        Command::AddressBook(command) => run_address_book(command).await,
+        Command::SmokeTest {
+            symbol,
+            spot_notional,
+            futures_notional,
+        } => {
+            run_smoke_test(SmokeTestParams {
+                symbol,
+                spot_notional,
+                futures_notional,
+            })
+            .await
+        }
     };
 
     // 커맨드가 완료되어도 서버는 계속 실행되도록 대기
@@ -70,13 +548,44 @@ async fn main() -> eyre::Result<()> {
     result
 }
 
+/// 전략을 실제로 돌리는 커맨드(run, arbitrage-test, cash-and-carry, spot-spread)에서 도는 주기 작업
+/// (일회성 CLI 커맨드에서는 시작하지 않음)
+fn start_strategy_jobs() {
+    // 에쿼티 곡선/드로다운 수집 (EQUITY_SAMPLE_INTERVAL_SECS 설정 시)
+    if let Some(params) = trade::equity::EquityParams::from_env() {
+        trade::equity::equity_tracker().start(params);
+    }
+
+    // 계정 잔고 이상 변동 감시 (ACCOUNT_WATCH 설정 시)
+    if let Some(params) = trade::account_watch::AccountWatchParams::from_env() {
+        trade::account_watch::account_watcher().start(params);
+    }
+
+    // 펀딩비 정산 기록 (FUNDING_ATTRIBUTION_INTERVAL_SECS 설정 시)
+    if let Some(params) = trade::funding::FundingAttributionParams::from_env() {
+        trade::funding::funding_attributor().start(params);
+    }
+
+    // BNB 수수료 잔고 관리 (BNB_FEE_MIN 설정 시)
+    if let Some(params) = trade::bnb_fee::BnbFeeParams::from_env() {
+        trade::bnb_fee::bnb_fee_manager().start(params);
+    }
+
+    // 기록 보관 기간 아카이브 (RECORD_ARCHIVE_INTERVAL_HOURS 설정 시)
+    trade::record::start_archive_job(trade::record::RetentionPolicy::from_env());
+
+    // 소액 잔고 정리 (DUST_SWEEP_INTERVAL_HOURS 설정 시)
+    trade::dust::start_dust_sweep_job(trade::dust::DustSweepParams::from_env());
+}
+
 async fn run_bot() -> eyre::Result<()> {
     info!("거래 봇 시작...");
 
     info!("Oracle에서 unified-snapshots 데이터 가져오는 중...");
 
-    let snapshots = explore::fetch_unified_snapshots().await?;
-    explore::print_unified_snapshots(&snapshots);
+    let snapshots = OracleClient::from_env().fetch_unified_snapshots().await?;
+    let snapshots: Vec<_> = snapshots.iter().collect();
+    println!("{}", oracle_client::snapshots_table(&snapshots));
 
     todo!()
 }
@@ -89,33 +598,69 @@ async fn run_explore_test() -> eyre::Result<()> {
 
     info!("\n=== Bithumb 자산 정보 조회 중... ===");
     let assets = explore::fetch_bithumb_assets().await?;
-    explore::print_assets(&assets);
+    println!("{}", oracle_client::assets_table(&assets));
 
     info!("\n=== Binance 자산 정보 조회 중... ===");
     let assets = explore::fetch_binance_assets().await?;
-    explore::print_assets(&assets);
+    println!("{}", oracle_client::assets_table(&assets));
 
     info!("완료!");
 
     Ok(())
 }
 
+/// Oracle REST API 조회 결과를 표로 출력
+async fn run_oracle(command: OracleCommand) -> eyre::Result<()> {
+    let snapshots = OracleClient::from_env().fetch_unified_snapshots().await?;
+    let table = match command {
+        OracleCommand::Snapshots { symbol, exchange } => {
+            let exchange = exchange
+                .map(|e| trade::trader::parse_exchange_id(&e))
+                .transpose()
+                .map_err(|e| eyre::eyre!("{}", e))?;
+            oracle_client::snapshots_table(&oracle_client::filter_snapshots(
+                &snapshots,
+                symbol.as_deref(),
+                exchange,
+            ))
+        }
+        OracleCommand::FundingTop { n } => {
+            oracle_client::funding_table(&oracle_client::funding_top(&snapshots, n))
+        }
+        OracleCommand::Premium { asset } => {
+            oracle_client::premium_table(&oracle_client::premium_rows(&snapshots, &asset))
+        }
+    };
+    println!("{}", table);
+    Ok(())
+}
+
 /// 베이시스 아비트라지 전략 테스트 (dry-run 모드)
-async fn run_arbitrage_test() -> eyre::Result<()> {
+async fn run_arbitrage_test(params: StrategyParams) -> eyre::Result<()> {
     info!("베이시스 아비트라지 전략 테스트 시작 (dry-run 모드)...");
 
-    let mut params = StrategyParams::default();
-    params.dry_run = false;
-
     info!("테스트 파라미터:");
     info!("  Symbol: {}", params.symbol);
+    info!("  Spot Symbol: {}", params.spot_symbol());
     info!("  Mode: {}", params.mode);
     info!("  Entry BPS: {}", params.entry_bps);
     info!("  Exit BPS: {}", params.exit_bps);
+    info!("  Exit Ladder: {:?}", params.exit_ladder);
     info!("  Notional: {} USDT", params.notional);
     info!("  Leverage: {}x", params.leverage);
+    info!("  Dynamic Leverage: {:?}", params.dynamic_leverage);
     info!("  Isolated: {}", params.isolated);
     info!("  Dry Run: {}", params.dry_run);
+    info!("  Capital Budget: {} USDT", params.capital_budget);
+    info!("  Volatility Sizing: {:?}", params.vol_sizing);
+    info!("  Imbalance Threshold: {:?}", params.imbalance_threshold);
+    info!("  Liquidity Floors: {:?}", params.liquidity_floors);
+    info!("  Entry Recheck: {:?}", params.entry_recheck);
+    info!(
+        "  Leg Order: {:?} (second leg retries: {})",
+        params.leg_order, params.second_leg_retries
+    );
+    info!("  Shadows: {:?}", params.shadows);
 
     let strategy = IntraBasisArbitrageStrategy::new(params)
         .map_err(|e| eyre::eyre!("전략 초기화 실패: {}", e))?;
@@ -130,6 +675,35 @@ async fn run_arbitrage_test() -> eyre::Result<()> {
     Ok(())
 }
 
+/// 분기물 캐시 앤 캐리 전략 실행
+async fn run_cash_and_carry(params: CashAndCarryParams) -> eyre::Result<()> {
+    info!(
+        "캐시 앤 캐리 시작: 현물 {}, 분기물 페어 {} ({:?}), 롤오버 {}일 전, dry-run {}",
+        params.spot_symbol, params.pair, params.contract, params.roll_days, params.dry_run
+    );
+    let strategy =
+        CashAndCarryStrategy::new(params).map_err(|e| eyre::eyre!("전략 초기화 실패: {}", e))?;
+    strategy.run_loop().await?;
+    Ok(())
+}
+
+/// 현물-현물 스프레드 전략 실행
+async fn run_spot_spread(params: SpotSpreadParams) -> eyre::Result<()> {
+    info!(
+        "현물 스프레드 시작: {:?} {} / {:?} {}, 진입 {} bps, dry-run {}",
+        params.venue_a,
+        params.symbol_a,
+        params.venue_b,
+        params.symbol_b,
+        params.entry_bps,
+        params.dry_run
+    );
+    let strategy =
+        SpotSpreadStrategy::new(params).map_err(|e| eyre::eyre!("전략 초기화 실패: {}", e))?;
+    strategy.run_loop().await?;
+    Ok(())
+}
+
 /// 강제 청산 테스트
 async fn run_emergency_test() -> eyre::Result<()> {
     info!("강제 청산 테스트 시작...");
@@ -141,3 +715,425 @@ async fn run_emergency_test() -> eyre::Result<()> {
 
     Ok(())
 }
+
+/// 실행 중인 봇의 API 서버에서 지연 통계를 가져와 출력
+/// 대상 서버는 TRADE_API_URL 환경 변수로 지정 (기본값: http://localhost:12091)
+async fn run_latency_report() -> eyre::Result<()> {
+    let base_url =
+        std::env::var("TRADE_API_URL").unwrap_or_else(|_| "http://localhost:12091".to_string());
+    let url = format!("{}/metrics/latency", base_url);
+
+    let response = exchanges::http::http_client().get(&url).send().await?;
+    if !response.status().is_success() {
+        return Err(eyre::eyre!("서버 응답 오류: {}", response.status()));
+    }
+
+    let reports: Vec<trade::latency::LatencyReport> = response.json().await?;
+    trade::latency::print_latency_report(&reports);
+
+    Ok(())
+}
+
+/// 실행 중인 봇의 제어 API에 붙는 대화형 콘솔
+/// 대상 서버는 TRADE_API_URL 환경 변수로 지정 (기본값: http://localhost:12091)
+async fn run_console() -> eyre::Result<()> {
+    let client = trade::console::ConsoleClient::from_env();
+    trade::console::run_console(&client).await
+}
+
+/// 거래소 연결 점검. 비정상 거래소가 있으면 에러 반환
+async fn run_preflight() -> eyre::Result<()> {
+    info!("거래소 연결 점검 시작...");
+
+    let statuses = trade::preflight::run_checks().await?;
+    trade::preflight::print_status(&statuses);
+
+    let unhealthy: Vec<String> = statuses
+        .iter()
+        .filter(|s| !s.healthy)
+        .map(|s| format!("{:?}", s.exchange))
+        .collect();
+    if !unhealthy.is_empty() {
+        return Err(eyre::eyre!("연결 점검 실패: {}", unhealthy.join(", ")));
+    }
+
+    info!("모든 거래소 연결 정상");
+
+    Ok(())
+}
+
+/// 선물 신규 상장 감시 (종료하지 않음)
+async fn run_listing_watch(interval: u64) -> eyre::Result<()> {
+    let watcher = trade::listing::ListingWatcher::new(std::time::Duration::from_secs(interval));
+    watcher.run().await;
+
+    Ok(())
+}
+
+/// Binance 이체 실행
+async fn run_transfer(
+    from: &str,
+    to: &str,
+    asset: &str,
+    amount: f64,
+    from_email: Option<String>,
+    to_email: Option<String>,
+) -> eyre::Result<()> {
+    use trade::trader::binance::{SubAccountTransfer, Wallet, transfer};
+
+    let from_wallet: Wallet = from.parse()?;
+    let to_wallet: Wallet = to.parse()?;
+    if amount <= 0.0 {
+        return Err(eyre::eyre!("이체 금액은 0보다 커야 합니다: {}", amount));
+    }
+
+    let client = BinanceClient::with_credentials()?;
+    let response = if from_email.is_some() || to_email.is_some() {
+        let request = SubAccountTransfer {
+            from_email,
+            to_email,
+            from_wallet,
+            to_wallet,
+            asset: asset.to_string(),
+            amount,
+        };
+        info!("서브 계정 이체: {:?}", request);
+        transfer::sub_account_transfer(&client, &request).await?
+    } else {
+        info!(
+            "지갑 이체: {} {} ({} → {})",
+            amount, asset, from_wallet, to_wallet
+        );
+        transfer::universal_transfer(&client, from_wallet, to_wallet, asset, amount).await?
+    };
+
+    info!("이체 완료: tranId={}", response.tran_id);
+
+    Ok(())
+}
+
+fn parse_values(s: &str) -> eyre::Result<Vec<f64>> {
+    trade::backtest::optimize::parse_values(s).map_err(|e| eyre::eyre!(e))
+}
+
+/// optimize 커맨드 실행 옵션
+struct OptimizeOptions {
+    samples: Option<usize>,
+    seed: u64,
+    objective: String,
+    output: String,
+    walk_forward: Option<trade::backtest::walk_forward::WalkForwardConfig>,
+}
+
+/// 백테스트 파라미터 격자 탐색
+async fn run_optimize(
+    data: &str,
+    exchange: &str,
+    base: trade::backtest::BacktestConfig,
+    grid: trade::backtest::optimize::ParamGrid,
+    options: OptimizeOptions,
+) -> eyre::Result<()> {
+    use trade::backtest::optimize::{Objective, run_sweep, write_csv};
+
+    let objective: Objective = options
+        .objective
+        .parse()
+        .map_err(|e: String| eyre::eyre!(e))?;
+    let output = options.output.as_str();
+    let prices = trade::backtest::load_samples(data, exchange, &base.symbol)?;
+    if prices.is_empty() {
+        return Err(eyre::eyre!("가격 데이터가 비어 있습니다: {}", data));
+    }
+
+    let params = match options.samples {
+        Some(n) => grid.random_sample(n, options.seed),
+        None => grid.combinations(),
+    };
+
+    if let Some(config) = options.walk_forward {
+        return run_walk_forward(prices, &base, params, objective, config, output).await;
+    }
+    info!(
+        "파라미터 탐색 시작: 샘플 {}개, 조합 {}개, 기준 {}",
+        prices.len(),
+        params.len(),
+        objective
+    );
+
+    let runs = run_sweep(std::sync::Arc::new(prices), &base, params, objective).await?;
+    write_csv(&runs, output)?;
+
+    for (i, run) in runs.iter().take(10).enumerate() {
+        info!(
+            "#{} entry={} exit={} notional={} stop={:?} → score {:.6} (pnl {:.6}, trades {}, sharpe {:.3}, mdd {:.3}%)",
+            i + 1,
+            run.params.entry_bps,
+            run.params.exit_bps,
+            run.params.notional,
+            run.params.stop_bps,
+            run.score,
+            run.summary.total_pnl,
+            run.summary.total_trades,
+            run.summary.sharpe,
+            run.summary.max_drawdown_pct
+        );
+    }
+    info!("전체 결과 저장: {}", output);
+
+    Ok(())
+}
+
+/// 보관 기간이 지난 기록 아카이브 (Parquet 내보내기 후 삭제)
+async fn run_archive(dry_run: bool) -> eyre::Result<()> {
+    use trade::record::{RecordArchiver, RetentionPolicy};
+
+    let policy = RetentionPolicy::from_env();
+    if !policy.is_active() {
+        info!("보관 기간이 설정된 테이블이 없습니다 (RECORD_RETENTION_*_DAYS)");
+        return Ok(());
+    }
+    let archiver = RecordArchiver::new()
+        .await
+        .map_err(|e| eyre::eyre!("기록 DB 연결 실패: {}", e))?;
+    let results = archiver
+        .run(&policy, chrono::Utc::now(), dry_run)
+        .await
+        .map_err(|e| eyre::eyre!("기록 아카이브 실패: {}", e))?;
+    let total: usize = results.iter().map(|r| r.rows).sum();
+    info!(
+        "아카이브 {}: {}개 테이블, {}행",
+        if dry_run {
+            "대상 (dry-run)"
+        } else {
+            "완료"
+        },
+        results.len(),
+        total
+    );
+    Ok(())
+}
+
+/// 소액 잔고 정리 후 자산별 결과 출력
+async fn run_sweep_dust(dry_run: bool) -> eyre::Result<()> {
+    let params = trade::dust::DustSweepParams::from_env();
+    let records = trade::dust::sweep_all(&params, dry_run).await;
+    if records.is_empty() {
+        info!("정리할 소액 잔고가 없습니다");
+    }
+    for record in &records {
+        info!(
+            "{} {} {:.8} → {:.8} {} [{}]{}",
+            record.exchange,
+            record.asset,
+            record.amount,
+            record.received_amount,
+            record.target_asset,
+            record.status.as_str(),
+            record
+                .detail
+                .as_deref()
+                .map(|d| format!(" {}", d))
+                .unwrap_or_default()
+        );
+    }
+    Ok(())
+}
+
+/// 테스트넷 스모크 테스트 (한 단계라도 실패하면 에러)
+async fn run_smoke_test(params: SmokeTestParams) -> eyre::Result<()> {
+    let report = trade::smoke_test::run_smoke_test(&params).await;
+    for step in &report.steps {
+        println!(
+            "{} {:<22} {:>6}ms  {}",
+            if step.ok { "PASS" } else { "FAIL" },
+            step.name,
+            step.elapsed_ms,
+            step.detail
+        );
+    }
+    if !report.passed() {
+        return Err(eyre::eyre!("smoke test failed"));
+    }
+    info!("스모크 테스트 통과");
+    Ok(())
+}
+
+/// 출금 주소 화이트리스트 관리
+async fn run_address_book(command: AddressBookCommand) -> eyre::Result<()> {
+    use trade::address_book::{AddressEntry, address_book};
+
+    let parse = |e: &str| trade::trader::parse_exchange_id(e).map_err(|e| eyre::eyre!("{}", e));
+    let book = address_book();
+    match command {
+        AddressBookCommand::Add {
+            exchange,
+            asset,
+            network,
+            address,
+            memo,
+            label,
+        } => {
+            let entry = AddressEntry {
+                exchange: parse(&exchange)?,
+                asset: asset.to_uppercase(),
+                network: network.to_uppercase(),
+                address,
+                memo,
+                label,
+                added_at: chrono::Utc::now(),
+            };
+            info!("주소 추가: {}", entry.key());
+            book.add(entry)?;
+        }
+        AddressBookCommand::Remove {
+            exchange,
+            asset,
+            network,
+            address,
+            memo,
+        } => {
+            let removed = book.remove(
+                parse(&exchange)?,
+                &asset,
+                &network,
+                &address,
+                memo.as_deref(),
+            )?;
+            info!("주소 {}개 삭제", removed);
+        }
+        AddressBookCommand::List => {
+            for entry in book.view() {
+                println!(
+                    "{:?} {} {} {} {} {:?}",
+                    entry.exchange,
+                    entry.asset,
+                    entry.network,
+                    entry.address,
+                    entry.label,
+                    entry.status
+                );
+            }
+        }
+        AddressBookCommand::Verify => {
+            let report = book.verify().await;
+            for entry in &report.entries {
+                println!(
+                    "{:?} {} {} {} {:?}",
+                    entry.exchange, entry.asset, entry.network, entry.address, entry.status
+                );
+            }
+            for unknown in &report.unknown_on_exchange {
+                tracing::warn!("주소록에 없는 거래소 화이트리스트 주소: {}", unknown);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn run_tax_report(
+    year: i32,
+    method: &str,
+    usdt_krw: Option<f64>,
+    output: Option<String>,
+) -> eyre::Result<()> {
+    use trade::accounting::{CostBasisMethod, build_tax_report, write_disposals_csv};
+
+    let method: CostBasisMethod = method.parse().map_err(|e: String| eyre::eyre!(e))?;
+    let usdt_krw = match usdt_krw {
+        Some(rate) => rate,
+        None => exchanges::exchange_rate::fetch_usdt_krw_rate().await?,
+    };
+    let repo = trade::record::get_repository()
+        .ok_or_else(|| eyre::eyre!("거래 기록 저장소가 초기화되지 않았습니다"))?;
+    let records = repo
+        .find_all(None)
+        .await
+        .map_err(|e| eyre::eyre!("거래 기록 조회 실패: {}", e))?;
+
+    let report = build_tax_report(&records, year, method, usdt_krw);
+    let output = output.unwrap_or_else(|| format!("results/tax-{}.csv", year));
+    write_disposals_csv(&report, &output)?;
+
+    info!(
+        "{}년 실현 손익 ({}, USDT/KRW {}): {:.0} KRW / {:.2} USDT, 매도 {}건",
+        year,
+        method,
+        usdt_krw,
+        report.total_gain_krw,
+        report.total_gain_usdt,
+        report.disposals.len()
+    );
+    for asset in &report.by_asset {
+        info!(
+            "  {}: 매도 {:.8}, 손익 {:.0} KRW / {:.2} USDT, 잔여 {:.8}",
+            asset.asset, asset.disposed_qty, asset.gain_krw, asset.gain_usdt, asset.remaining_qty
+        );
+    }
+    if report.skipped_non_spot > 0 || report.skipped_unpriced > 0 {
+        info!(
+            "  제외: 선물 {}건, 체결가 없음 {}건",
+            report.skipped_non_spot, report.skipped_unpriced
+        );
+    }
+    info!("매도 내역 저장: {}", output);
+
+    Ok(())
+}
+
+/// 워크포워드 검증 실행 및 구간별 결과 저장
+async fn run_walk_forward(
+    prices: Vec<trade::backtest::PriceSample>,
+    base: &trade::backtest::BacktestConfig,
+    params: Vec<trade::backtest::optimize::SweepParams>,
+    objective: trade::backtest::optimize::Objective,
+    config: trade::backtest::walk_forward::WalkForwardConfig,
+    output: &str,
+) -> eyre::Result<()> {
+    use trade::backtest::walk_forward;
+
+    info!(
+        "워크포워드 검증 시작: 샘플 {}개, 조합 {}개, 학습 {}ms / 검증 {}ms",
+        prices.len(),
+        params.len(),
+        config.train_ms,
+        config.test_ms
+    );
+
+    let report = walk_forward::run_walk_forward(
+        std::sync::Arc::new(prices),
+        base,
+        params,
+        objective,
+        config,
+    )
+    .await?;
+    if report.windows.is_empty() {
+        return Err(eyre::eyre!("데이터가 학습 + 검증 구간보다 짧습니다"));
+    }
+    walk_forward::write_csv(&report, output)?;
+
+    for (i, w) in report.windows.iter().enumerate() {
+        info!(
+            "구간 {}: entry={} exit={} notional={} stop={:?} → 학습 {:.6} / 검증 {:.6} (pnl {:.6}, trades {})",
+            i + 1,
+            w.params.entry_bps,
+            w.params.exit_bps,
+            w.params.notional,
+            w.params.stop_bps,
+            w.train_score,
+            w.test_score,
+            w.test_summary.total_pnl,
+            w.test_summary.total_trades
+        );
+    }
+    info!(
+        "Out-of-sample 합계: pnl {:.6}, trades {}, 수익 구간 {:.1}%, efficiency {:.3}",
+        report.oos_total_pnl,
+        report.oos_total_trades,
+        report.oos_profitable_ratio * 100.0,
+        report.efficiency
+    );
+    info!("구간별 결과 저장: {}", output);
+
+    Ok(())
+}
@@ -0,0 +1,394 @@
+//! SMTP 이메일 알림 채널
+//!
+//! 중요도별로 즉시 발송 / 다이제스트 / 끔을 고른다. 기본값은 Critical(킬 스위치, 레그 되돌림 실패 등)만
+//! 즉시 보내고 Info/Warning은 모아 두었다가 `NOTIFY_EMAIL_DIGEST_INTERVAL_SECS`(기본 하루)마다
+//! 한 통으로 보낸다.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use tracing::{info, warn};
+
+use super::{Alert, AlertLevel, NotificationSink, notification_center};
+
+/// 다이제스트에 쌓아 둘 최대 알림 수 (넘치면 오래된 것부터 버림)
+const DIGEST_CAPACITY: usize = 1000;
+
+/// 중요도별 전달 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailDelivery {
+    /// 알림마다 바로 발송
+    Immediate,
+    /// 모았다가 주기마다 한 통으로 발송
+    Digest,
+    /// 보내지 않음
+    Off,
+}
+
+impl std::str::FromStr for EmailDelivery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "immediate" => Ok(Self::Immediate),
+            "digest" => Ok(Self::Digest),
+            "off" => Ok(Self::Off),
+            other => Err(format!("Unknown email delivery mode: {}", other)),
+        }
+    }
+}
+
+/// 이메일 채널 설정
+#[derive(Debug, Clone)]
+pub struct EmailConfig {
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+    pub info: EmailDelivery,
+    pub warning: EmailDelivery,
+    pub critical: EmailDelivery,
+    pub digest_interval: Duration,
+}
+
+impl EmailConfig {
+    /// NOTIFY_EMAIL_SMTP_HOST / NOTIFY_EMAIL_TO가 있으면 사용
+    /// NOTIFY_EMAIL_SMTP_PORT(587), NOTIFY_EMAIL_USERNAME, NOTIFY_EMAIL_PASSWORD,
+    /// NOTIFY_EMAIL_FROM(없으면 USERNAME), NOTIFY_EMAIL_INFO / _WARNING / _CRITICAL
+    /// (immediate / digest / off, 기본 digest / digest / immediate), NOTIFY_EMAIL_DIGEST_INTERVAL_SECS(86400)
+    pub fn from_env() -> Option<Self> {
+        let smtp_host = std::env::var("NOTIFY_EMAIL_SMTP_HOST").ok()?;
+        let to: Vec<String> = std::env::var("NOTIFY_EMAIL_TO")
+            .ok()?
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        let username = std::env::var("NOTIFY_EMAIL_USERNAME").ok();
+        let Some(from) = std::env::var("NOTIFY_EMAIL_FROM")
+            .ok()
+            .or_else(|| username.clone())
+        else {
+            warn!("NOTIFY_EMAIL_FROM 또는 NOTIFY_EMAIL_USERNAME이 없어 이메일 알림을 끕니다");
+            return None;
+        };
+        if to.is_empty() {
+            return None;
+        }
+
+        let delivery = |key: &str, default: EmailDelivery| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().map_err(|e| warn!("{} 무시: {}", key, e)).ok())
+                .unwrap_or(default)
+        };
+        Some(Self {
+            smtp_host,
+            smtp_port: std::env::var("NOTIFY_EMAIL_SMTP_PORT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(587),
+            username,
+            password: std::env::var("NOTIFY_EMAIL_PASSWORD").ok(),
+            from,
+            to,
+            info: delivery("NOTIFY_EMAIL_INFO", EmailDelivery::Digest),
+            warning: delivery("NOTIFY_EMAIL_WARNING", EmailDelivery::Digest),
+            critical: delivery("NOTIFY_EMAIL_CRITICAL", EmailDelivery::Immediate),
+            digest_interval: Duration::from_secs(
+                std::env::var("NOTIFY_EMAIL_DIGEST_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(86_400)
+                    .max(60),
+            ),
+        })
+    }
+
+    pub fn delivery(&self, level: AlertLevel) -> EmailDelivery {
+        match level {
+            AlertLevel::Info => self.info,
+            AlertLevel::Warning => self.warning,
+            AlertLevel::Critical => self.critical,
+        }
+    }
+}
+
+/// 메일 발송 수단 (SMTP, 테스트용 기록기 등)
+#[async_trait]
+pub trait MailTransport: Send + Sync {
+    async fn send_mail(&self, subject: &str, body: &str) -> eyre::Result<()>;
+}
+
+/// lettre SMTP(STARTTLS) 발송
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &EmailConfig) -> eyre::Result<Self> {
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)?
+            .port(config.smtp_port);
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+        Ok(Self {
+            transport: builder.build(),
+            from: config.from.parse()?,
+            to: config
+                .to
+                .iter()
+                .map(|addr| addr.parse())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[async_trait]
+impl MailTransport for SmtpMailer {
+    async fn send_mail(&self, subject: &str, body: &str) -> eyre::Result<()> {
+        let mut message = Message::builder().from(self.from.clone()).subject(subject);
+        for to in &self.to {
+            message = message.to(to.clone());
+        }
+        self.transport.send(message.body(body.to_string())?).await?;
+        Ok(())
+    }
+}
+
+/// 다이제스트 버퍼
+#[derive(Debug, Default)]
+struct DigestBuffer {
+    alerts: VecDeque<Alert>,
+    /// 용량을 넘어 버린 알림 수
+    dropped: u64,
+}
+
+/// 이메일 알림 채널
+pub struct EmailSink {
+    config: EmailConfig,
+    transport: Arc<dyn MailTransport>,
+    digest: Mutex<DigestBuffer>,
+}
+
+impl EmailSink {
+    pub fn new(config: EmailConfig, transport: Arc<dyn MailTransport>) -> Self {
+        Self {
+            config,
+            transport,
+            digest: Mutex::new(DigestBuffer::default()),
+        }
+    }
+
+    /// 다이제스트에 쌓인 알림 수
+    pub fn pending(&self) -> usize {
+        self.digest.lock().unwrap().alerts.len()
+    }
+
+    /// 쌓인 알림을 한 통으로 발송 (없으면 아무것도 안 함, 실패하면 다음 주기에 다시 시도)
+    pub async fn flush_digest(&self) -> eyre::Result<usize> {
+        let (alerts, dropped) = {
+            let mut digest = self.digest.lock().unwrap();
+            (
+                std::mem::take(&mut digest.alerts),
+                std::mem::take(&mut digest.dropped),
+            )
+        };
+        if alerts.is_empty() {
+            return Ok(0);
+        }
+
+        let count = alerts.len();
+        let subject = format!("[trade] 알림 다이제스트 ({}건)", count);
+        let body = digest_body(alerts.iter(), dropped);
+        if let Err(e) = self.transport.send_mail(&subject, &body).await {
+            let mut digest = self.digest.lock().unwrap();
+            for alert in alerts.into_iter().rev() {
+                digest.alerts.push_front(alert);
+            }
+            digest.dropped += dropped;
+            while digest.alerts.len() > DIGEST_CAPACITY {
+                digest.alerts.pop_front();
+                digest.dropped += 1;
+            }
+            return Err(e);
+        }
+        Ok(count)
+    }
+}
+
+#[async_trait]
+impl NotificationSink for EmailSink {
+    fn name(&self) -> &str {
+        "email"
+    }
+
+    async fn send(&self, alert: &Alert) -> eyre::Result<()> {
+        match self.config.delivery(alert.level) {
+            EmailDelivery::Immediate => {
+                let subject = format!("[trade][{:?}] {}", alert.level, alert.title);
+                self.transport.send_mail(&subject, &alert_line(alert)).await
+            }
+            EmailDelivery::Digest => {
+                let mut digest = self.digest.lock().unwrap();
+                if digest.alerts.len() == DIGEST_CAPACITY {
+                    digest.alerts.pop_front();
+                    digest.dropped += 1;
+                }
+                digest.alerts.push_back(alert.clone());
+                Ok(())
+            }
+            EmailDelivery::Off => Ok(()),
+        }
+    }
+}
+
+fn alert_line(alert: &Alert) -> String {
+    format!(
+        "{} [{:?}] {} ({})\n{}\n",
+        alert.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        alert.level,
+        alert.title,
+        alert.kind,
+        alert.message
+    )
+}
+
+/// 다이제스트 본문 (중요도 높은 것부터, 같은 중요도는 시간순)
+fn digest_body<'a>(alerts: impl Iterator<Item = &'a Alert>, dropped: u64) -> String {
+    let mut alerts: Vec<&Alert> = alerts.collect();
+    alerts.sort_by(|a, b| b.level.cmp(&a.level).then(a.created_at.cmp(&b.created_at)));
+    let mut body = String::new();
+    if dropped > 0 {
+        body.push_str(&format!("(버퍼 초과로 오래된 알림 {}건 생략)\n\n", dropped));
+    }
+    for alert in alerts {
+        body.push_str(&alert_line(alert));
+        body.push('\n');
+    }
+    body
+}
+
+/// 이메일 채널을 전역 알림 센터에 등록하고 다이제스트 발송 작업 시작
+pub fn install_email_sink(config: EmailConfig) -> eyre::Result<Arc<EmailSink>> {
+    let transport = Arc::new(SmtpMailer::new(&config)?);
+    let interval = config.digest_interval;
+    info!(
+        "이메일 알림 활성화: {} → {:?} (info={:?}, warning={:?}, critical={:?}, 다이제스트 {}초)",
+        config.smtp_host,
+        config.to,
+        config.info,
+        config.warning,
+        config.critical,
+        interval.as_secs()
+    );
+    let sink = Arc::new(EmailSink::new(config, transport));
+    notification_center().add_sink(sink.clone());
+
+    let digest_sink = sink.clone();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            match digest_sink.flush_digest().await {
+                Ok(0) => {}
+                Ok(count) => info!("이메일 다이제스트 발송: {}건", count),
+                Err(e) => warn!("이메일 다이제스트 발송 실패: {}", e),
+            }
+        }
+    });
+    Ok(sink)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[derive(Default)]
+    struct RecordingMailer {
+        sent: Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait]
+    impl MailTransport for RecordingMailer {
+        async fn send_mail(&self, subject: &str, body: &str) -> eyre::Result<()> {
+            self.sent
+                .lock()
+                .unwrap()
+                .push((subject.to_string(), body.to_string()));
+            Ok(())
+        }
+    }
+
+    fn alert(id: u64, level: AlertLevel, title: &str) -> Alert {
+        Alert {
+            id,
+            kind: "test".to_string(),
+            level,
+            title: title.to_string(),
+            message: "message".to_string(),
+            data: serde_json::Value::Null,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_email_sink_immediate_and_digest() {
+        let config = EmailConfig {
+            smtp_host: "smtp.example.com".to_string(),
+            smtp_port: 587,
+            username: None,
+            password: None,
+            from: "bot@example.com".to_string(),
+            to: vec!["ops@example.com".to_string()],
+            info: EmailDelivery::Digest,
+            warning: EmailDelivery::Off,
+            critical: EmailDelivery::Immediate,
+            digest_interval: Duration::from_secs(3600),
+        };
+        let mailer = Arc::new(RecordingMailer::default());
+        let sink = EmailSink::new(config, mailer.clone());
+
+        sink.send(&alert(1, AlertLevel::Info, "opened"))
+            .await
+            .unwrap();
+        sink.send(&alert(2, AlertLevel::Warning, "ignored"))
+            .await
+            .unwrap();
+        sink.send(&alert(3, AlertLevel::Critical, "kill switch"))
+            .await
+            .unwrap();
+        sink.send(&alert(4, AlertLevel::Info, "closed"))
+            .await
+            .unwrap();
+
+        // Critical은 바로 한 통, Info 두 건은 다이제스트 대기, Warning은 끔
+        {
+            let sent = mailer.sent.lock().unwrap();
+            assert_eq!(sent.len(), 1);
+            assert!(sent[0].0.contains("Critical"));
+            assert!(sent[0].0.contains("kill switch"));
+        }
+        assert_eq!(sink.pending(), 2);
+
+        assert_eq!(sink.flush_digest().await.unwrap(), 2);
+        assert_eq!(sink.pending(), 0);
+        assert_eq!(sink.flush_digest().await.unwrap(), 0);
+        let sent = mailer.sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        assert!(sent[1].0.contains("2건"));
+        assert!(sent[1].1.contains("opened") && sent[1].1.contains("closed"));
+        assert!(!sent[1].1.contains("ignored"));
+    }
+}
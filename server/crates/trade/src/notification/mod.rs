@@ -0,0 +1,228 @@
+pub mod email;
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::{info, warn};
+
+/// 메모리에 보관하는 최근 알림 개수
+const DEFAULT_CAPACITY: usize = 500;
+
+/// 알림 중요도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertLevel {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// 알림 한 건
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub id: u64,
+    /// 알림 종류 (예: "new_listing")
+    pub kind: String,
+    pub level: AlertLevel,
+    pub title: String,
+    pub message: String,
+    /// 알림 종류별 상세 데이터
+    pub data: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 알림 전달 채널 (로그, 이메일 등)
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn send(&self, alert: &Alert) -> eyre::Result<()>;
+}
+
+/// tracing 로그로 알림을 남기는 기본 채널
+pub struct LogSink;
+
+#[async_trait]
+impl NotificationSink for LogSink {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    async fn send(&self, alert: &Alert) -> eyre::Result<()> {
+        match alert.level {
+            AlertLevel::Info => info!("[알림:{}] {} - {}", alert.kind, alert.title, alert.message),
+            AlertLevel::Warning | AlertLevel::Critical => {
+                warn!("[알림:{}] {} - {}", alert.kind, alert.title, alert.message)
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 알림 센터
+///
+/// 알림을 최근 N개까지 보관하고(`/alerts`에서 조회) 등록된 채널로 전달한다.
+/// 채널 전달 실패는 로그만 남기고 다른 채널 전달을 막지 않는다.
+pub struct NotificationCenter {
+    capacity: usize,
+    next_id: AtomicU64,
+    alerts: RwLock<VecDeque<Alert>>,
+    sinks: RwLock<Vec<Arc<dyn NotificationSink>>>,
+}
+
+impl Default for NotificationCenter {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl NotificationCenter {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            next_id: AtomicU64::new(1),
+            alerts: RwLock::new(VecDeque::new()),
+            sinks: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// 알림 전달 채널 추가
+    pub fn add_sink(&self, sink: Arc<dyn NotificationSink>) {
+        self.sinks.write().unwrap().push(sink);
+    }
+
+    /// 알림 생성, 보관 및 전달
+    pub async fn notify(
+        &self,
+        kind: &str,
+        level: AlertLevel,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        data: serde_json::Value,
+    ) -> Alert {
+        let alert = Alert {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            kind: kind.to_string(),
+            level,
+            title: title.into(),
+            message: message.into(),
+            data,
+            created_at: Utc::now(),
+        };
+
+        {
+            let mut alerts = self.alerts.write().unwrap();
+            if alerts.len() == self.capacity {
+                alerts.pop_front();
+            }
+            alerts.push_back(alert.clone());
+        }
+
+        let sinks: Vec<Arc<dyn NotificationSink>> = self.sinks.read().unwrap().clone();
+        for sink in sinks {
+            if let Err(e) = sink.send(&alert).await {
+                warn!("알림 전달 실패 ({}): {}", sink.name(), e);
+            }
+        }
+
+        alert
+    }
+
+    /// 최근 알림 (최신순, kind가 주어지면 해당 종류만)
+    pub fn recent(&self, limit: usize, kind: Option<&str>) -> Vec<Alert> {
+        let alerts = self.alerts.read().unwrap();
+        alerts
+            .iter()
+            .rev()
+            .filter(|a| kind.is_none_or(|k| a.kind == k))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+/// 전역 알림 센터
+static GLOBAL_NOTIFICATIONS: OnceLock<NotificationCenter> = OnceLock::new();
+
+/// 전역 알림 센터 가져오기 (최초 호출 시 로그 채널과 함께 생성)
+pub fn notification_center() -> &'static NotificationCenter {
+    GLOBAL_NOTIFICATIONS.get_or_init(|| {
+        let center = NotificationCenter::default();
+        center.add_sink(Arc::new(LogSink));
+        center
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct RecordingSink {
+        sent: Mutex<Vec<u64>>,
+    }
+
+    #[async_trait]
+    impl NotificationSink for RecordingSink {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        async fn send(&self, alert: &Alert) -> eyre::Result<()> {
+            self.sent.lock().unwrap().push(alert.id);
+            Ok(())
+        }
+    }
+
+    struct FailingSink;
+
+    #[async_trait]
+    impl NotificationSink for FailingSink {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        async fn send(&self, _alert: &Alert) -> eyre::Result<()> {
+            Err(eyre::eyre!("unreachable"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notify_dispatches_to_all_sinks() {
+        let center = NotificationCenter::new(10);
+        let recording = Arc::new(RecordingSink {
+            sent: Mutex::new(Vec::new()),
+        });
+        center.add_sink(Arc::new(FailingSink));
+        center.add_sink(recording.clone());
+
+        let alert = center
+            .notify("test", AlertLevel::Info, "t", "m", serde_json::Value::Null)
+            .await;
+
+        assert_eq!(*recording.sent.lock().unwrap(), vec![alert.id]);
+    }
+
+    #[tokio::test]
+    async fn test_recent_is_bounded_and_newest_first() {
+        let center = NotificationCenter::new(2);
+        for kind in ["a", "b", "a"] {
+            center
+                .notify(kind, AlertLevel::Info, "t", "m", serde_json::Value::Null)
+                .await;
+        }
+
+        let recent = center.recent(10, None);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].id, 3);
+        assert_eq!(recent[1].id, 2);
+
+        let only_a = center.recent(10, Some("a"));
+        assert_eq!(only_a.len(), 1);
+        assert_eq!(only_a[0].id, 3);
+    }
+}
@@ -0,0 +1,447 @@
+//! Oracle REST API 조회용 클라이언트 (`trade oracle ...`)
+//!
+//! `/unified-snapshots`를 받아 심볼/거래소 필터, 펀딩비 상위, 거래소별 프리미엄을
+//! 표 형태로 출력한다. Oracle 주소는 `ORACLE_URL`(기본 http://localhost:12090).
+//! 전략이 시그널로 쓰는 스냅샷은 `ORACLE_MIN_QUALITY`(0.0~1.0) 미만 품질 점수를 걸러낸다.
+
+use interface::{Currency, ExchangeId, SpotAsset, UnifiedSnapshot};
+use tracing::debug;
+
+const DEFAULT_ORACLE_URL: &str = "http://localhost:12090";
+
+/// Oracle REST 클라이언트
+pub struct OracleClient {
+    base_url: String,
+    http: reqwest::Client,
+    /// 시그널용 스냅샷의 최소 품질 점수 (None이면 거르지 않음)
+    min_quality: Option<f64>,
+}
+
+impl OracleClient {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            http: exchanges::http::http_client(),
+            min_quality: None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let min_quality = std::env::var("ORACLE_MIN_QUALITY")
+            .ok()
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|q| (0.0..=1.0).contains(q));
+        Self::new(&std::env::var("ORACLE_URL").unwrap_or_else(|_| DEFAULT_ORACLE_URL.to_string()))
+            .with_min_quality(min_quality)
+    }
+
+    pub fn with_min_quality(mut self, min_quality: Option<f64>) -> Self {
+        self.min_quality = min_quality;
+        self
+    }
+
+    pub async fn fetch_unified_snapshots(&self) -> eyre::Result<Vec<UnifiedSnapshot>> {
+        let url = format!("{}/unified-snapshots", self.base_url);
+        let response = self.http.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(eyre::eyre!("서버 응답 오류: {}", response.status()));
+        }
+        Ok(response.json().await?)
+    }
+
+    /// 전략 시그널 판단용 스냅샷 (최소 품질 점수 미달 스냅샷 제외)
+    pub async fn fetch_signal_snapshots(&self) -> eyre::Result<Vec<UnifiedSnapshot>> {
+        let snapshots = self.fetch_unified_snapshots().await?;
+        Ok(retain_quality(snapshots, self.min_quality))
+    }
+}
+
+/// 최소 품질 점수를 넘는 스냅샷만 남김 (품질 정보가 없는 스냅샷도 제외)
+pub fn retain_quality(
+    mut snapshots: Vec<UnifiedSnapshot>,
+    min_quality: Option<f64>,
+) -> Vec<UnifiedSnapshot> {
+    if let Some(min_quality) = min_quality {
+        let before = snapshots.len();
+        snapshots.retain(|s| s.meets_quality(min_quality));
+        if snapshots.len() < before {
+            debug!(
+                "Dropped {} oracle snapshots below quality {:.2}",
+                before - snapshots.len(),
+                min_quality
+            );
+        }
+    }
+    snapshots
+}
+
+/// 심볼(정확히 일치 또는 베이스 자산)과 거래소로 스냅샷 필터
+pub fn filter_snapshots<'a>(
+    snapshots: &'a [UnifiedSnapshot],
+    symbol: Option<&str>,
+    exchange: Option<ExchangeId>,
+) -> Vec<&'a UnifiedSnapshot> {
+    let symbol = symbol.map(|s| s.to_uppercase());
+    snapshots
+        .iter()
+        .filter(|s| exchange.is_none_or(|e| s.exchange == e))
+        .filter(|s| {
+            symbol
+                .as_deref()
+                .is_none_or(|want| s.symbol == want || s.symbol.strip_suffix("USDT") == Some(want))
+        })
+        .collect()
+}
+
+/// 펀딩비 절댓값 상위 N개
+pub fn funding_top(snapshots: &[UnifiedSnapshot], n: usize) -> Vec<&UnifiedSnapshot> {
+    let mut with_perp: Vec<&UnifiedSnapshot> =
+        snapshots.iter().filter(|s| s.perp.is_some()).collect();
+    with_perp.sort_by(|a, b| {
+        let rate = |s: &UnifiedSnapshot| s.perp.as_ref().map_or(0.0, |p| p.funding_rate.abs());
+        rate(b).total_cmp(&rate(a))
+    });
+    with_perp.truncate(n);
+    with_perp
+}
+
+/// 거래소/시장별 USD 환산 가격과 기준 가격 대비 프리미엄
+#[derive(Debug, Clone, PartialEq)]
+pub struct PremiumRow {
+    pub exchange: ExchangeId,
+    /// "spot" / "perp"
+    pub market: &'static str,
+    pub currency: Currency,
+    /// 거래소 호가 통화 기준 가격
+    pub price: f64,
+    pub price_usd: f64,
+    /// 기준 가격 대비 (%)
+    pub premium_pct: f64,
+}
+
+/// 호가 통화 가격 → USD
+fn to_usd(price: f64, currency: &Currency, snapshot: &UnifiedSnapshot) -> f64 {
+    let rates = &snapshot.exchange_rates;
+    match currency {
+        Currency::USD => price,
+        Currency::USDT => price * rates.usdt_usd,
+        Currency::KRW if rates.usd_krw > 0.0 => price / rates.usd_krw,
+        Currency::KRW => 0.0,
+    }
+}
+
+/// 자산(예: "BTC")의 거래소별 프리미엄
+/// 기준 가격은 Binance 현물, 없으면 원화가 아닌 현물 가격의 중앙값
+pub fn premium_rows(snapshots: &[UnifiedSnapshot], asset: &str) -> Vec<PremiumRow> {
+    let mut rows = Vec::new();
+    for snapshot in filter_snapshots(snapshots, Some(asset), None) {
+        if let Some(spot) = &snapshot.spot {
+            rows.push(PremiumRow {
+                exchange: snapshot.exchange,
+                market: "spot",
+                currency: spot.currency,
+                price: spot.price.value(),
+                price_usd: to_usd(spot.price.value(), &spot.currency, snapshot),
+                premium_pct: 0.0,
+            });
+        }
+        if let Some(perp) = &snapshot.perp {
+            rows.push(PremiumRow {
+                exchange: snapshot.exchange,
+                market: "perp",
+                currency: perp.currency,
+                price: perp.mark_price.value(),
+                price_usd: to_usd(perp.mark_price.value(), &perp.currency, snapshot),
+                premium_pct: 0.0,
+            });
+        }
+    }
+
+    let reference = rows
+        .iter()
+        .find(|r| r.exchange == ExchangeId::Binance && r.market == "spot")
+        .map(|r| r.price_usd)
+        .or_else(|| {
+            let mut prices: Vec<f64> = rows
+                .iter()
+                .filter(|r| r.market == "spot" && !matches!(r.currency, Currency::KRW))
+                .map(|r| r.price_usd)
+                .collect();
+            prices.sort_by(f64::total_cmp);
+            prices.get(prices.len() / 2).copied()
+        });
+
+    if let Some(reference) = reference.filter(|p| *p > 0.0) {
+        for row in &mut rows {
+            row.premium_pct = (row.price_usd / reference - 1.0) * 100.0;
+        }
+    }
+    rows.sort_by(|a, b| b.premium_pct.total_cmp(&a.premium_pct));
+    rows
+}
+
+/// 열 너비를 맞춘 텍스트 표
+pub fn render_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            if let Some(width) = widths.get_mut(i) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+    }
+
+    let line = |cells: Vec<&str>| -> String {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut out = line(headers.to_vec());
+    out.push('\n');
+    out.push_str(&line(
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .iter()
+            .map(String::as_str)
+            .collect(),
+    ));
+    out.push('\n');
+    for row in rows {
+        out.push_str(&line(row.iter().map(String::as_str).collect()));
+        out.push('\n');
+    }
+    out
+}
+
+fn fmt_opt(value: Option<f64>, precision: usize) -> String {
+    value
+        .map(|v| format!("{:.precision$}", v, precision = precision))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// 스냅샷 표
+pub fn snapshots_table(snapshots: &[&UnifiedSnapshot]) -> String {
+    let rows: Vec<Vec<String>> = snapshots
+        .iter()
+        .map(|s| {
+            vec![
+                format!("{:?}", s.exchange),
+                s.symbol.clone(),
+                fmt_opt(s.spot.as_ref().map(|p| p.price.value()), 6),
+                fmt_opt(s.perp.as_ref().map(|p| p.mark_price.value()), 6),
+                fmt_opt(s.perp.as_ref().map(|p| p.funding_rate * 100.0), 4),
+                fmt_opt(s.perp.as_ref().map(|p| p.oi_usd), 0),
+                fmt_opt(
+                    s.perp
+                        .as_ref()
+                        .map(|p| p.vol_24h_usd)
+                        .or(s.spot.as_ref().map(|p| p.vol_24h_usd)),
+                    0,
+                ),
+                fmt_opt(s.quality_score(), 2),
+                s.updated_at.format("%H:%M:%S").to_string(),
+            ]
+        })
+        .collect();
+    render_table(
+        &[
+            "EXCHANGE",
+            "SYMBOL",
+            "SPOT",
+            "MARK",
+            "FUNDING%",
+            "OI_USD",
+            "VOL24H_USD",
+            "QUALITY",
+            "UPDATED",
+        ],
+        &rows,
+    )
+}
+
+/// 펀딩비 상위 표
+pub fn funding_table(snapshots: &[&UnifiedSnapshot]) -> String {
+    let rows: Vec<Vec<String>> = snapshots
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| {
+            let perp = s.perp.as_ref()?;
+            Some(vec![
+                (i + 1).to_string(),
+                format!("{:?}", s.exchange),
+                s.symbol.clone(),
+                format!("{:.4}", perp.funding_rate * 100.0),
+                format!("{:.6}", perp.mark_price),
+                format!("{:.0}", perp.oi_usd),
+                perp.next_funding_time
+                    .map(|t| t.format("%m-%d %H:%M").to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            ])
+        })
+        .collect();
+    render_table(
+        &[
+            "#",
+            "EXCHANGE",
+            "SYMBOL",
+            "FUNDING%",
+            "MARK",
+            "OI_USD",
+            "NEXT_FUNDING",
+        ],
+        &rows,
+    )
+}
+
+/// 프리미엄 표
+pub fn premium_table(rows: &[PremiumRow]) -> String {
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|r| {
+            vec![
+                format!("{:?}", r.exchange),
+                r.market.to_string(),
+                format!("{:?}", r.currency),
+                format!("{:.6}", r.price),
+                format!("{:.6}", r.price_usd),
+                format!("{:+.3}", r.premium_pct),
+            ]
+        })
+        .collect();
+    render_table(
+        &[
+            "EXCHANGE",
+            "MARKET",
+            "CCY",
+            "PRICE",
+            "PRICE_USD",
+            "PREMIUM%",
+        ],
+        &rows,
+    )
+}
+
+/// 보유 자산 표
+pub fn assets_table(assets: &[SpotAsset]) -> String {
+    let rows: Vec<Vec<String>> = assets
+        .iter()
+        .map(|a| {
+            vec![
+                a.currency.clone(),
+                format!("{:.8}", a.total),
+                format!("{:.8}", a.available),
+                format!("{:.8}", a.in_use),
+                a.updated_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ]
+        })
+        .collect();
+    render_table(&["ASSET", "TOTAL", "AVAILABLE", "IN_USE", "UPDATED"], &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use interface::{ExchangeRates, PerpData, Price, SnapshotQuality, SpotData};
+
+    fn snapshot(
+        exchange: ExchangeId,
+        symbol: &str,
+        spot: Option<(Currency, f64)>,
+        funding: Option<f64>,
+    ) -> UnifiedSnapshot {
+        UnifiedSnapshot {
+            schema_version: 2,
+            exchange,
+            symbol: symbol.to_string(),
+            currency: Currency::USDT,
+            perp: funding.map(|rate| PerpData {
+                currency: Currency::USDT,
+                mark_price: Price::new(100.0),
+                index_price: None,
+                oi_usd: 1_000.0,
+                vol_24h_usd: 10_000.0,
+                funding_rate: rate,
+                next_funding_time: None,
+                predicted_funding_rate: None,
+                funding_interval_hours: None,
+            }),
+            spot: spot.map(|(currency, price)| SpotData {
+                currency,
+                price: Price::new(price),
+                vol_24h_usd: 5_000.0,
+            }),
+            basis_bps: None,
+            cross_basis_bps: None,
+            exchange_rates: ExchangeRates {
+                usd_krw: 1_400.0,
+                usdt_usd: 1.0,
+                usdt_krw: 1_400.0,
+                updated_at: Utc::now(),
+            },
+            quality: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_funding_top_premium_and_table() {
+        let snapshots = vec![
+            snapshot(
+                ExchangeId::Binance,
+                "BTCUSDT",
+                Some((Currency::USDT, 100.0)),
+                Some(0.0001),
+            ),
+            snapshot(ExchangeId::Bybit, "ETHUSDT", None, Some(-0.0005)),
+            snapshot(
+                ExchangeId::Bithumb,
+                "BTCUSDT",
+                Some((Currency::KRW, 147_000.0)),
+                None,
+            ),
+        ];
+
+        let top = funding_top(&snapshots, 1);
+        assert_eq!(top[0].symbol, "ETHUSDT");
+        assert_eq!(filter_snapshots(&snapshots, Some("btc"), None).len(), 2);
+        assert_eq!(
+            filter_snapshots(&snapshots, Some("BTCUSDT"), Some(ExchangeId::Bithumb)).len(),
+            1
+        );
+
+        let rows = premium_rows(&snapshots, "BTC");
+        // 빗썸 147,000 KRW / 1,400 = 105 USD → 바이낸스 현물 100 대비 +5%
+        assert_eq!(rows[0].exchange, ExchangeId::Bithumb);
+        assert!((rows[0].premium_pct - 5.0).abs() < 1e-9);
+
+        let table = render_table(&["A", "LONG"], &[vec!["xyz".into(), "1".into()]]);
+        assert_eq!(table, "A    LONG\n---  ----\nxyz  1\n");
+
+        // 품질 점수가 낮거나 없는 스냅샷은 시그널에서 제외
+        let mut scored = snapshots.clone();
+        for (snapshot, score) in scored.iter_mut().zip([0.9, 0.5]) {
+            snapshot.quality = Some(SnapshotQuality {
+                score,
+                perp_age_secs: None,
+                spot_age_secs: None,
+                exchange_rate_age_secs: 0.0,
+                perp_source: None,
+                spot_source: None,
+                flags: Vec::new(),
+            });
+        }
+        let kept = retain_quality(scored.clone(), Some(0.8));
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].exchange, ExchangeId::Binance);
+        assert_eq!(retain_quality(scored, None).len(), 3);
+    }
+}
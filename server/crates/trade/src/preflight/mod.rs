@@ -0,0 +1,65 @@
+use exchanges::status::{ExchangeStatus, LinkState};
+use exchanges::{AssetExchange, BinanceClient, BithumbClient, PerpExchange, SpotExchange};
+use tracing::{info, warn};
+
+/// 거래 시작 전 사용하는 거래소 클라이언트의 REST 연결을 점검하고 상태를 반환
+///
+/// - Binance: 선물/현물 시세, 현물/선물 자산 (인증 필요)
+/// - Bithumb: 현물 시세, 현물 자산 (인증 필요)
+///
+/// 각 호출 결과는 전역 상태 레지스트리에 기록되며,
+/// 반환값은 클라이언트의 `status()`를 거래소별로 모은 것이다.
+pub async fn run_checks() -> eyre::Result<Vec<ExchangeStatus>> {
+    let binance = BinanceClient::with_credentials()?;
+    let bithumb = BithumbClient::with_credentials()?;
+
+    check("Binance 선물 시세", PerpExchange::fetch_all(&binance).await);
+    check("Binance 현물 시세", SpotExchange::fetch_all(&binance).await);
+    check("Binance 현물 자산", binance.fetch_spots().await);
+    check("Binance 선물 자산", binance.fetch_futures().await);
+
+    check("Bithumb 현물 시세", SpotExchange::fetch_all(&bithumb).await);
+    check("Bithumb 현물 자산", bithumb.fetch_spots().await);
+
+    Ok(vec![
+        AssetExchange::status(&binance),
+        AssetExchange::status(&bithumb),
+    ])
+}
+
+fn check<T>(name: &str, result: Result<Vec<T>, interface::ExchangeError>) {
+    match result {
+        Ok(items) => info!("{} 확인 완료 ({}개)", name, items.len()),
+        Err(e) => warn!("{} 실패: {}", name, e),
+    }
+}
+
+/// 연결 상태를 표 형태로 출력
+pub fn print_status(statuses: &[ExchangeStatus]) {
+    println!(
+        "{:<10} {:<28} {:<12} {:<8} {:<25} LAST ERROR",
+        "EXCHANGE", "CHANNEL", "STATE", "HEALTHY", "LAST SUCCESS"
+    );
+    for status in statuses {
+        for channel in &status.channels {
+            let state = match channel.state {
+                LinkState::Unknown => "unknown",
+                LinkState::Connecting => "connecting",
+                LinkState::Connected => "connected",
+                LinkState::Disconnected => "disconnected",
+            };
+            println!(
+                "{:<10} {:<28} {:<12} {:<8} {:<25} {}",
+                format!("{:?}", status.exchange),
+                channel.channel,
+                state,
+                channel.is_healthy(),
+                channel
+                    .last_success
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                channel.last_error.as_deref().unwrap_or("-")
+            );
+        }
+    }
+}
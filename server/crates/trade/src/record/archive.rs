@@ -0,0 +1,574 @@
+//! 기록 보관 기간과 아카이브
+//!
+//! SQLite 기록 테이블은 계속 커지므로 테이블별 보관 기간을 두고, 기간이 지난 행을 zstd 압축
+//! Parquet 파일(`{RECORD_ARCHIVE_DIR}/{table}/{table}-{cutoff}.parquet`)로 내보낸 뒤 삭제한다.
+//! 파일을 끝까지 쓰고 이름을 바꾼 다음에만 삭제하므로 중간에 실패하면 DB 행은 그대로 남는다.
+//! 컬럼 스키마는 엔티티 정의에서 읽는다 (정수 → INT64, 실수 → DOUBLE, 불리언 → BOOLEAN, 나머지 → UTF8).
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use parquet::basic::{Compression, LogicalType, Repetition, Type as PhysicalType, ZstdLevel};
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use sea_orm::sea_query::ColumnType;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, Database, DatabaseConnection, EntityTrait, IdenStatic, Iterable,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Schema,
+};
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{info, warn};
+
+use super::RecordError;
+use super::entities::{equity_point, position_record, shadow_trade_record, trade_record};
+
+/// 한 번에 읽어 Parquet 행 그룹 하나로 쓰는 행 수
+const BATCH_ROWS: u64 = 10_000;
+
+/// 테이블별 보관 기간 (None이면 보관 기간 없음)
+#[derive(Debug, Clone)]
+pub struct RetentionPolicy {
+    pub trade_records: Option<Duration>,
+    pub position_records: Option<Duration>,
+    pub shadow_trade_records: Option<Duration>,
+    pub equity_points: Option<Duration>,
+    pub archive_dir: PathBuf,
+    /// 백그라운드 주기 실행 간격 (None이면 `trade archive`로 수동 실행만)
+    pub interval: Option<std::time::Duration>,
+}
+
+impl RetentionPolicy {
+    /// `RECORD_RETENTION_{TRADE,POSITION,SHADOW,EQUITY}_DAYS`(미설정이면 보관 기간 없음),
+    /// `RECORD_ARCHIVE_DIR`(기본 `archive`), `RECORD_ARCHIVE_INTERVAL_HOURS`(미설정이면 주기 실행 안 함)
+    pub fn from_env() -> Self {
+        let days = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<i64>().ok())
+                .filter(|days| *days > 0)
+                .map(Duration::days)
+        };
+        Self {
+            trade_records: days("RECORD_RETENTION_TRADE_DAYS"),
+            position_records: days("RECORD_RETENTION_POSITION_DAYS"),
+            shadow_trade_records: days("RECORD_RETENTION_SHADOW_DAYS"),
+            equity_points: days("RECORD_RETENTION_EQUITY_DAYS"),
+            archive_dir: std::env::var("RECORD_ARCHIVE_DIR")
+                .unwrap_or_else(|_| "archive".to_string())
+                .into(),
+            interval: std::env::var("RECORD_ARCHIVE_INTERVAL_HOURS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|hours| *hours > 0)
+                .map(|hours| std::time::Duration::from_secs(hours * 3600)),
+        }
+    }
+
+    /// 보관 기간이 설정된 (테이블, 보관 기간)
+    pub fn tables(&self) -> Vec<(&'static str, Duration)> {
+        [
+            ("trade_records", self.trade_records),
+            ("position_records", self.position_records),
+            ("shadow_trade_records", self.shadow_trade_records),
+            ("equity_points", self.equity_points),
+        ]
+        .into_iter()
+        .filter_map(|(table, retention)| Some((table, retention?)))
+        .collect()
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.tables().is_empty()
+    }
+}
+
+/// 테이블 하나의 아카이브 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct TableArchive {
+    pub table: String,
+    /// 이 시각보다 오래된 행이 대상
+    pub cutoff: DateTime<Utc>,
+    /// 내보낸(dry-run이면 대상) 행 수
+    pub rows: usize,
+    /// 쓴 Parquet 파일 (대상이 없거나 dry-run이면 None)
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ColumnKind {
+    Int64,
+    Double,
+    Bool,
+    Utf8,
+}
+
+struct ArchiveColumn {
+    name: String,
+    kind: ColumnKind,
+    nullable: bool,
+}
+
+fn archive_columns<E: EntityTrait>() -> Vec<ArchiveColumn> {
+    E::Column::iter()
+        .map(|column| {
+            let def = column.def();
+            let kind = match def.get_column_type() {
+                ColumnType::TinyInteger
+                | ColumnType::SmallInteger
+                | ColumnType::Integer
+                | ColumnType::BigInteger => ColumnKind::Int64,
+                ColumnType::Float | ColumnType::Double => ColumnKind::Double,
+                ColumnType::Boolean => ColumnKind::Bool,
+                _ => ColumnKind::Utf8,
+            };
+            ArchiveColumn {
+                name: column.as_str().to_string(),
+                kind,
+                nullable: def.is_null(),
+            }
+        })
+        .collect()
+}
+
+fn parquet_error(e: parquet::errors::ParquetError) -> RecordError {
+    RecordError::Other(format!("Parquet write failed: {}", e))
+}
+
+fn io_error(e: std::io::Error) -> RecordError {
+    RecordError::Other(format!("Archive file error: {}", e))
+}
+
+/// 엔티티 컬럼으로 만든 Parquet 파일 쓰기 (행 그룹 단위)
+struct ParquetArchive {
+    columns: Vec<ArchiveColumn>,
+    writer: SerializedFileWriter<File>,
+}
+
+impl ParquetArchive {
+    fn create(path: &Path, table: &str, columns: Vec<ArchiveColumn>) -> Result<Self, RecordError> {
+        let fields = columns
+            .iter()
+            .map(|column| {
+                let (physical, logical) = match column.kind {
+                    ColumnKind::Int64 => (PhysicalType::INT64, None),
+                    ColumnKind::Double => (PhysicalType::DOUBLE, None),
+                    ColumnKind::Bool => (PhysicalType::BOOLEAN, None),
+                    ColumnKind::Utf8 => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+                };
+                let repetition = if column.nullable {
+                    Repetition::OPTIONAL
+                } else {
+                    Repetition::REQUIRED
+                };
+                Type::primitive_type_builder(&column.name, physical)
+                    .with_repetition(repetition)
+                    .with_logical_type(logical)
+                    .build()
+                    .map(Arc::new)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(parquet_error)?;
+        let schema = Type::group_type_builder(table)
+            .with_fields(fields)
+            .build()
+            .map_err(parquet_error)?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let file = File::create(path).map_err(io_error)?;
+        let writer = SerializedFileWriter::new(file, Arc::new(schema), Arc::new(props))
+            .map_err(parquet_error)?;
+        Ok(Self { columns, writer })
+    }
+
+    /// JSON 객체 행들을 행 그룹 하나로 기록
+    fn write_rows(&mut self, rows: &[Value]) -> Result<(), RecordError> {
+        let mut row_group = self.writer.next_row_group().map_err(parquet_error)?;
+        for column in &self.columns {
+            let Some(mut writer) = row_group.next_column().map_err(parquet_error)? else {
+                break;
+            };
+            let cells: Vec<&Value> = rows
+                .iter()
+                .map(|row| row.get(&column.name).unwrap_or(&Value::Null))
+                .collect();
+            // OPTIONAL 컬럼은 값이 있으면 1, null이면 0, REQUIRED 컬럼은 정의 레벨 없음
+            let levels: Vec<i16> = cells.iter().map(|v| i16::from(!v.is_null())).collect();
+            let def_levels = column.nullable.then_some(levels.as_slice());
+            let present = cells.iter().filter(|v| !column.nullable || !v.is_null());
+            let written = match column.kind {
+                ColumnKind::Int64 => {
+                    let values: Vec<i64> = present.map(|v| v.as_i64().unwrap_or(0)).collect();
+                    writer
+                        .typed::<Int64Type>()
+                        .write_batch(&values, def_levels, None)
+                }
+                ColumnKind::Double => {
+                    let values: Vec<f64> = present.map(|v| v.as_f64().unwrap_or(0.0)).collect();
+                    writer
+                        .typed::<DoubleType>()
+                        .write_batch(&values, def_levels, None)
+                }
+                ColumnKind::Bool => {
+                    let values: Vec<bool> = present
+                        .map(|v| v.as_bool().or(v.as_i64().map(|i| i != 0)).unwrap_or(false))
+                        .collect();
+                    writer
+                        .typed::<BoolType>()
+                        .write_batch(&values, def_levels, None)
+                }
+                ColumnKind::Utf8 => {
+                    let values: Vec<ByteArray> = present
+                        .map(|v| match v {
+                            Value::String(s) => ByteArray::from(s.as_str()),
+                            other => ByteArray::from(other.to_string().as_str()),
+                        })
+                        .collect();
+                    writer
+                        .typed::<ByteArrayType>()
+                        .write_batch(&values, def_levels, None)
+                }
+            };
+            written.map_err(parquet_error)?;
+            writer.close().map_err(parquet_error)?;
+        }
+        row_group.close().map_err(parquet_error)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), RecordError> {
+        self.writer.close().map_err(parquet_error)?;
+        Ok(())
+    }
+}
+
+/// 보관 기간이 지난 기록을 Parquet로 내보내고 삭제
+pub struct RecordArchiver {
+    db: DatabaseConnection,
+}
+
+impl RecordArchiver {
+    /// 기록 저장소와 같은 DB (`DB_PATH`, 기본 "trade_records.db")
+    pub async fn new() -> Result<Self, RecordError> {
+        let db_path = std::env::var("DB_PATH").unwrap_or_else(|_| "trade_records.db".to_string());
+
+        let mut path = PathBuf::from(&db_path);
+        if !path.is_absolute()
+            && let Ok(current_dir) = std::env::current_dir()
+        {
+            path = current_dir.join(&db_path);
+        }
+
+        Self::connect(&format!("sqlite://{}?mode=rwc", path.to_string_lossy())).await
+    }
+
+    /// DB URL로 연결 (대상 테이블이 없으면 생성)
+    pub async fn connect(db_url: &str) -> Result<Self, RecordError> {
+        let db = Database::connect(db_url)
+            .await
+            .map_err(RecordError::Database)?;
+
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        let mut statements = [
+            schema.create_table_from_entity(trade_record::Entity),
+            schema.create_table_from_entity(position_record::Entity),
+            schema.create_table_from_entity(shadow_trade_record::Entity),
+            schema.create_table_from_entity(equity_point::Entity),
+        ];
+        for stmt in statements.iter_mut() {
+            stmt.if_not_exists();
+            db.execute(backend.build(&*stmt))
+                .await
+                .map_err(RecordError::Database)?;
+        }
+
+        Ok(Self { db })
+    }
+
+    /// 보관 기간이 설정된 테이블마다 `now - 보관 기간`보다 오래된 행을 아카이브
+    /// dry-run이면 대상 행 수만 센다
+    pub async fn run(
+        &self,
+        policy: &RetentionPolicy,
+        now: DateTime<Utc>,
+        dry_run: bool,
+    ) -> Result<Vec<TableArchive>, RecordError> {
+        let mut results = Vec::new();
+        for (table, retention) in policy.tables() {
+            let cutoff = now - retention;
+            let dir = policy.archive_dir.join(table);
+            let archive = match table {
+                "trade_records" => {
+                    self.archive_table::<trade_record::Entity>(
+                        trade_record::Column::Id,
+                        trade_record::Column::ExecutedAt,
+                        table,
+                        cutoff,
+                        &dir,
+                        dry_run,
+                    )
+                    .await?
+                }
+                "position_records" => {
+                    self.archive_table::<position_record::Entity>(
+                        position_record::Column::Id,
+                        position_record::Column::ExecutedAt,
+                        table,
+                        cutoff,
+                        &dir,
+                        dry_run,
+                    )
+                    .await?
+                }
+                "shadow_trade_records" => {
+                    self.archive_table::<shadow_trade_record::Entity>(
+                        shadow_trade_record::Column::Id,
+                        shadow_trade_record::Column::ExecutedAt,
+                        table,
+                        cutoff,
+                        &dir,
+                        dry_run,
+                    )
+                    .await?
+                }
+                _ => {
+                    self.archive_table::<equity_point::Entity>(
+                        equity_point::Column::Id,
+                        equity_point::Column::SampledAt,
+                        table,
+                        cutoff,
+                        &dir,
+                        dry_run,
+                    )
+                    .await?
+                }
+            };
+            info!(
+                "{}: {} 이전 {}행 {}",
+                table,
+                cutoff.to_rfc3339(),
+                archive.rows,
+                match (&archive.file, dry_run) {
+                    (_, true) => "아카이브 대상 (dry-run)".to_string(),
+                    (Some(file), false) => format!("→ {}", file.display()),
+                    (None, false) => "아카이브 대상 없음".to_string(),
+                }
+            );
+            results.push(archive);
+        }
+        Ok(results)
+    }
+
+    /// 시각 컬럼(RFC 3339 문자열)이 cutoff보다 앞선 행을 id 순으로 읽어 파일에 쓰고,
+    /// 파일을 닫은 뒤 내보낸 id 범위만 삭제
+    async fn archive_table<E>(
+        &self,
+        id_column: E::Column,
+        time_column: E::Column,
+        table: &str,
+        cutoff: DateTime<Utc>,
+        dir: &Path,
+        dry_run: bool,
+    ) -> Result<TableArchive, RecordError>
+    where
+        E: EntityTrait,
+        E::Model: Serialize + Sync,
+    {
+        let cutoff_str = cutoff.to_rfc3339();
+        let mut result = TableArchive {
+            table: table.to_string(),
+            cutoff,
+            rows: 0,
+            file: None,
+        };
+
+        if dry_run {
+            result.rows = E::find()
+                .filter(time_column.lt(cutoff_str))
+                .count(&self.db)
+                .await
+                .map_err(RecordError::Database)? as usize;
+            return Ok(result);
+        }
+
+        let path = dir.join(format!(
+            "{}-{}.parquet",
+            table,
+            cutoff.format("%Y%m%dT%H%M%SZ")
+        ));
+        let tmp_path = path.with_extension("parquet.tmp");
+        let mut archive: Option<ParquetArchive> = None;
+        let mut last_id = i64::MIN;
+        loop {
+            let models = E::find()
+                .filter(time_column.lt(cutoff_str.as_str()))
+                .filter(id_column.gt(last_id))
+                .order_by_asc(id_column)
+                .limit(BATCH_ROWS)
+                .all(&self.db)
+                .await
+                .map_err(RecordError::Database)?;
+            if models.is_empty() {
+                break;
+            }
+            let rows = models
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| RecordError::Other(format!("Failed to serialize record: {}", e)))?;
+            last_id = rows
+                .iter()
+                .filter_map(|row| row.get(id_column.as_str())?.as_i64())
+                .max()
+                .unwrap_or(last_id);
+
+            let writer = match archive.as_mut() {
+                Some(writer) => writer,
+                None => {
+                    std::fs::create_dir_all(dir).map_err(io_error)?;
+                    archive.insert(ParquetArchive::create(
+                        &tmp_path,
+                        table,
+                        archive_columns::<E>(),
+                    )?)
+                }
+            };
+            writer.write_rows(&rows)?;
+            result.rows += rows.len();
+        }
+
+        let Some(archive) = archive else {
+            return Ok(result);
+        };
+        archive.finish()?;
+        std::fs::rename(&tmp_path, &path).map_err(io_error)?;
+
+        E::delete_many()
+            .filter(time_column.lt(cutoff_str.as_str()))
+            .filter(id_column.lte(last_id))
+            .exec(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+        result.file = Some(path);
+        Ok(result)
+    }
+}
+
+/// 보관 기간과 주기가 설정되어 있으면 주기적으로 아카이브하는 태스크 시작 (첫 실행은 한 주기 뒤)
+/// 행을 삭제하므로 봇 실행(`run`)에서만 호출한다
+pub fn start_archive_job(policy: RetentionPolicy) {
+    let Some(interval) = policy.interval.filter(|_| policy.is_active()) else {
+        return;
+    };
+    info!(
+        "기록 아카이브: {}시간마다 {:?} → {}",
+        interval.as_secs() / 3600,
+        policy
+            .tables()
+            .iter()
+            .map(|(table, _)| *table)
+            .collect::<Vec<_>>(),
+        policy.archive_dir.display()
+    );
+    tokio::spawn(async move {
+        let archiver = match RecordArchiver::new().await {
+            Ok(archiver) => archiver,
+            Err(e) => {
+                warn!("기록 아카이브 DB 연결 실패: {}", e);
+                return;
+            }
+        };
+        // 첫 실행은 한 주기 뒤 (시작하자마자 행을 지우지 않도록)
+        let mut ticker =
+            tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = archiver.run(&policy, Utc::now(), false).await {
+                warn!("기록 아카이브 실패: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{EquityRecord, EquityRecordRepository, SqliteEquityRecordRepository};
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    fn point(sampled_at: DateTime<Utc>, equity_krw: Option<f64>) -> EquityRecord {
+        EquityRecord {
+            sampled_at,
+            equity_usdt: 1_000.0,
+            equity_krw,
+            cash_usdt: 1_000.0,
+            spot_usdt: 0.0,
+            futures_wallet_usdt: 0.0,
+            unrealized_pnl_usdt: 0.0,
+            drawdown_pct: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_archive_exports_then_deletes_expired_rows() {
+        let dir = std::env::temp_dir().join(format!("record_archive_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_url = format!("sqlite://{}?mode=rwc", dir.join("records.db").display());
+
+        let now = Utc::now();
+        let repo = SqliteEquityRecordRepository::connect(&db_url)
+            .await
+            .unwrap();
+        repo.save(&point(now - Duration::days(40), Some(1_300_000.0)))
+            .await
+            .unwrap();
+        repo.save(&point(now - Duration::days(31), None))
+            .await
+            .unwrap();
+        repo.save(&point(now - Duration::days(1), None))
+            .await
+            .unwrap();
+
+        let policy = RetentionPolicy {
+            trade_records: None,
+            position_records: None,
+            shadow_trade_records: None,
+            equity_points: Some(Duration::days(30)),
+            archive_dir: dir.join("archive"),
+            interval: None,
+        };
+        let archiver = RecordArchiver::connect(&db_url).await.unwrap();
+
+        let planned = archiver.run(&policy, now, true).await.unwrap();
+        assert_eq!((planned[0].rows, planned[0].file.is_none()), (2, true));
+        assert_eq!(repo.find_recent(None).await.unwrap().len(), 3);
+
+        let done = archiver.run(&policy, now, false).await.unwrap();
+        assert_eq!(done.len(), 1);
+        assert_eq!(done[0].rows, 2);
+        let file = done[0].file.clone().unwrap();
+        let reader = SerializedFileReader::new(File::open(&file).unwrap()).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 2);
+        assert_eq!(
+            reader
+                .metadata()
+                .file_metadata()
+                .schema_descr()
+                .num_columns(),
+            archive_columns::<equity_point::Entity>().len()
+        );
+        assert_eq!(repo.find_recent(None).await.unwrap().len(), 1);
+
+        // 다시 돌리면 대상 없음
+        let again = archiver.run(&policy, now, false).await.unwrap();
+        assert_eq!((again[0].rows, again[0].file.is_none()), (0, true));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,99 @@
+//! 거래/포지션 기록 CSV 변환 (스프레드시트로 바로 가져갈 수 있는 형식)
+
+use super::{StoredPositionRecord, StoredTradeRecord};
+
+/// 거래 기록 CSV 헤더 (줄바꿈 포함)
+pub const TRADE_RECORD_CSV_HEADER: &str = "id,executed_at,exchange,symbol,market_type,side,trade_type,executed_price,quantity,is_liquidation,request_query_string,api_response,metadata\r\n";
+
+/// 포지션 기록 CSV 헤더 (줄바꿈 포함)
+pub const POSITION_RECORD_CSV_HEADER: &str = "id,executed_at,bot_name,carry,action,symbol,spot_price,futures_mark,buy_exchange,sell_exchange\r\n";
+
+/// RFC 4180 규칙으로 필드 이스케이프 (쉼표/따옴표/줄바꿈이 있으면 따옴표로 감쌈)
+pub fn escape_csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn join_row(fields: &[String]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|f| escape_csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// 거래 기록 한 줄 (줄바꿈 포함)
+pub fn trade_record_csv_row(stored: &StoredTradeRecord) -> String {
+    let r = &stored.record;
+    join_row(&[
+        stored.id.to_string(),
+        r.executed_at.to_rfc3339(),
+        r.exchange.clone(),
+        r.symbol.clone(),
+        r.market_type.to_string(),
+        r.side.to_string(),
+        r.trade_type.to_string(),
+        r.executed_price.map(|p| p.to_string()).unwrap_or_default(),
+        r.quantity.to_string(),
+        r.is_liquidation.to_string(),
+        r.request_query_string.clone().unwrap_or_default(),
+        r.api_response.clone().unwrap_or_default(),
+        r.metadata.clone().unwrap_or_default(),
+    ])
+}
+
+/// 포지션 기록 한 줄 (줄바꿈 포함)
+pub fn position_record_csv_row(stored: &StoredPositionRecord) -> String {
+    let r = &stored.record;
+    join_row(&[
+        stored.id.to_string(),
+        r.executed_at.to_rfc3339(),
+        r.bot_name.clone(),
+        r.carry.clone(),
+        r.action.clone(),
+        r.symbol.clone(),
+        r.spot_price.to_string(),
+        r.futures_mark.to_string(),
+        r.buy_exchange.clone(),
+        r.sell_exchange.clone(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{MarketType, TradeRecord, TradeSide, TradeType};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_trade_record_csv_row_escapes_fields() {
+        let stored = StoredTradeRecord {
+            id: 7,
+            record: TradeRecord {
+                executed_at: Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap(),
+                exchange: "binance".to_string(),
+                symbol: "BTCUSDT".to_string(),
+                market_type: MarketType::Futures,
+                side: TradeSide::Sell,
+                trade_type: TradeType::Market,
+                executed_price: None,
+                quantity: 0.5,
+                request_query_string: Some("symbol=BTCUSDT&side=SELL".to_string()),
+                api_response: Some(r#"{"a":1,"b":"x"}"#.to_string()),
+                metadata: None,
+                is_liquidation: false,
+            },
+        };
+
+        let row = trade_record_csv_row(&stored);
+        assert_eq!(
+            row,
+            "7,2024-01-02T03:04:05+00:00,binance,BTCUSDT,FUTURES,SELL,MARKET,,0.5,false,symbol=BTCUSDT&side=SELL,\"{\"\"a\"\":1,\"\"b\"\":\"\"x\"\"}\",\r\n"
+        );
+    }
+}
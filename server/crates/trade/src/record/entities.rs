@@ -117,3 +117,234 @@ pub mod position_record {
 
     impl ActiveModelBehavior for ActiveModel {}
 }
+
+/// 섀도(페이퍼) 거래 기록 엔티티 모듈
+pub mod shadow_trade_record {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "shadow_trade_records")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = true)]
+        pub id: i64,
+
+        /// 가상 체결 UTC 시간 (ISO 8601 형식)
+        #[sea_orm(column_type = "Text")]
+        pub executed_at: String,
+
+        /// 섀도를 돌린 실전 전략 ID (예: "intra_basis:BTCUSDT")
+        #[sea_orm(column_type = "Text")]
+        pub strategy_id: String,
+
+        /// 후보 파라미터 이름 (예: "tight")
+        #[sea_orm(column_type = "Text")]
+        pub variant: String,
+
+        /// 코인 심볼
+        #[sea_orm(column_type = "Text")]
+        pub symbol: String,
+
+        /// 포지션 방향 (CARRY, REVERSE)
+        #[sea_orm(column_type = "Text")]
+        pub carry: String,
+
+        /// 포지션 액션 (OPEN, CLOSE)
+        #[sea_orm(column_type = "Text")]
+        pub action: String,
+
+        /// 후보 진입 임계값 (bps)
+        #[sea_orm(column_type = "Double")]
+        pub entry_bps: f64,
+
+        /// 후보 청산 임계값 (bps)
+        #[sea_orm(column_type = "Double")]
+        pub exit_bps: f64,
+
+        /// 가상 체결 시점 베이시스 (bps)
+        #[sea_orm(column_type = "Double")]
+        pub basis_bps: f64,
+
+        /// 스팟 가격
+        #[sea_orm(column_type = "Double")]
+        pub spot_price: f64,
+
+        /// 선물 마크 가격
+        #[sea_orm(column_type = "Double")]
+        pub futures_mark: f64,
+
+        /// 가상 수량
+        #[sea_orm(column_type = "Double")]
+        pub quantity: f64,
+
+        /// 청산 시 수수료 차감 손익 (bps, 진입 기록은 NULL)
+        #[sea_orm(column_type = "Double", nullable)]
+        pub pnl_bps: Option<f64>,
+
+        /// 청산 시 수수료 차감 손익 (USDT, 진입 기록은 NULL)
+        #[sea_orm(column_type = "Double", nullable)]
+        pub pnl_usdt: Option<f64>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// 계좌 평가액(에쿼티) 기록 엔티티 모듈
+pub mod equity_point {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "equity_points")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = true)]
+        pub id: i64,
+
+        /// 평가 UTC 시간 (ISO 8601 형식)
+        #[sea_orm(column_type = "Text")]
+        pub sampled_at: String,
+
+        /// 총 평가액 (USDT)
+        #[sea_orm(column_type = "Double")]
+        pub equity_usdt: f64,
+
+        /// 총 평가액 (KRW, 환율 조회 실패 시 NULL)
+        #[sea_orm(column_type = "Double", nullable)]
+        pub equity_krw: Option<f64>,
+
+        /// 현금성 자산 (USDT 환산)
+        #[sea_orm(column_type = "Double")]
+        pub cash_usdt: f64,
+
+        /// 현금 외 현물 보유 평가액 (USDT)
+        #[sea_orm(column_type = "Double")]
+        pub spot_usdt: f64,
+
+        /// 선물 지갑 잔고 (USDT)
+        #[sea_orm(column_type = "Double")]
+        pub futures_wallet_usdt: f64,
+
+        /// 선물 미실현 손익 (USDT)
+        #[sea_orm(column_type = "Double")]
+        pub unrealized_pnl_usdt: f64,
+
+        /// 롤링 고점 대비 하락률 (%)
+        #[sea_orm(column_type = "Double")]
+        pub drawdown_pct: f64,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// 소액 잔고 정리(더스트 변환/매도) 기록 엔티티 모듈
+pub mod dust_sweep_record {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "dust_sweep_records")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = true)]
+        pub id: i64,
+
+        /// 정리 UTC 시간 (ISO 8601 형식)
+        #[sea_orm(column_type = "Text")]
+        pub swept_at: String,
+
+        /// 거래소 (binance, bithumb)
+        #[sea_orm(column_type = "Text")]
+        pub exchange: String,
+
+        /// 정리한 자산
+        #[sea_orm(column_type = "Text")]
+        pub asset: String,
+
+        /// 정리 대상 수량
+        #[sea_orm(column_type = "Double")]
+        pub amount: f64,
+
+        /// 받은 자산 (BNB, KRW)
+        #[sea_orm(column_type = "Text")]
+        pub target_asset: String,
+
+        /// 받은 수량 (수수료 차감 후, 건너뛰었거나 실패하면 0)
+        #[sea_orm(column_type = "Double")]
+        pub received_amount: f64,
+
+        /// 수수료 (받은 자산 기준)
+        #[sea_orm(column_type = "Double")]
+        pub fee: f64,
+
+        /// converted | sold | skipped | failed
+        #[sea_orm(column_type = "Text")]
+        pub status: String,
+
+        /// 거래소 이체/주문 ID 또는 건너뛴/실패 사유
+        #[sea_orm(column_type = "Text", nullable)]
+        pub detail: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// 펀딩비 정산 기록 엔티티 모듈
+pub mod funding_settlement_record {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "funding_settlement_records")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = true)]
+        pub id: i64,
+
+        /// 정산 UTC 시간 (ISO 8601 형식)
+        #[sea_orm(column_type = "Text")]
+        pub settled_at: String,
+
+        /// 정산 시점에 열려 있던 포지션의 OPEN 기록 ID (position_records.id)
+        pub position_record_id: i64,
+
+        /// 봇 이름
+        #[sea_orm(column_type = "Text")]
+        pub bot_name: String,
+
+        /// 코인 심볼
+        #[sea_orm(column_type = "Text")]
+        pub symbol: String,
+
+        /// 정산 거래소 (예: binance_futures)
+        #[sea_orm(column_type = "Text")]
+        pub venue: String,
+
+        /// 정산된 펀딩비율 (0.0001 == 0.01%)
+        #[sea_orm(column_type = "Double")]
+        pub funding_rate: f64,
+
+        /// 받은 펀딩비 (USDT, 지급했으면 음수)
+        #[sea_orm(column_type = "Double")]
+        pub payment: f64,
+
+        /// 정산 시점 선물 포지션 수량 (롱 양수, 숏 음수)
+        #[sea_orm(column_type = "Double")]
+        pub position_qty: f64,
+
+        /// 정산 마크 가격 (NULL 가능)
+        #[sea_orm(column_type = "Double", nullable)]
+        pub mark_price: Option<f64>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
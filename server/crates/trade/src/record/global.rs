@@ -2,7 +2,9 @@ use std::sync::Arc;
 use std::sync::OnceLock;
 
 use super::{
-    PositionRecordRepository, SqlitePositionRecordRepository, SqliteTradeRecordRepository,
+    DustSweepRecord, DustSweepRecordRepository, SqliteDustSweepRecordRepository, EquityRecord, EquityRecordRepository, FundingSettlementRecord, FundingSettlementRecordRepository, SqliteFundingSettlementRecordRepository, PositionRecordRepository, ShadowTradeRecord, ShadowTradeRecordRepository,
+    SqliteEquityRecordRepository, SqlitePositionRecordRepository,
+    SqliteShadowTradeRecordRepository, SqliteTradeRecordRepository,
     TradeRecordRepository,
 };
 
@@ -14,6 +16,23 @@ static GLOBAL_REPOSITORY: OnceLock<Arc<dyn TradeRecordRepository + Send + Sync>>
 static GLOBAL_POSITION_REPOSITORY: OnceLock<Arc<dyn PositionRecordRepository + Send + Sync>> =
     OnceLock::new();
 
+/// 전역 섀도 거래 기록 저장소
+static GLOBAL_SHADOW_REPOSITORY: OnceLock<Arc<dyn ShadowTradeRecordRepository + Send + Sync>> =
+    OnceLock::new();
+
+/// 전역 에쿼티 표본 저장소
+static GLOBAL_EQUITY_REPOSITORY: OnceLock<Arc<dyn EquityRecordRepository + Send + Sync>> =
+    OnceLock::new();
+
+/// 전역 소액 잔고 정리 기록 저장소
+static GLOBAL_DUST_SWEEP_REPOSITORY: OnceLock<Arc<dyn DustSweepRecordRepository + Send + Sync>> =
+    OnceLock::new();
+
+/// 전역 펀딩비 정산 기록 저장소
+static GLOBAL_FUNDING_SETTLEMENT_REPOSITORY: OnceLock<
+    Arc<dyn FundingSettlementRecordRepository + Send + Sync>,
+> = OnceLock::new();
+
 /// 전역 Repository 초기화
 pub async fn init_global_repository() -> Result<(), super::RecordError> {
     let repo = SqliteTradeRecordRepository::new().await?;
@@ -28,6 +47,36 @@ pub async fn init_global_repository() -> Result<(), super::RecordError> {
             super::RecordError::Other("Position repository already initialized".to_string())
         })?;
 
+    let shadow_repo = SqliteShadowTradeRecordRepository::new().await?;
+    GLOBAL_SHADOW_REPOSITORY
+        .set(Arc::new(shadow_repo))
+        .map_err(|_| {
+            super::RecordError::Other("Shadow repository already initialized".to_string())
+        })?;
+
+    let equity_repo = SqliteEquityRecordRepository::new().await?;
+    GLOBAL_EQUITY_REPOSITORY
+        .set(Arc::new(equity_repo))
+        .map_err(|_| {
+            super::RecordError::Other("Equity repository already initialized".to_string())
+        })?;
+
+    let dust_sweep_repo = SqliteDustSweepRecordRepository::new().await?;
+    GLOBAL_DUST_SWEEP_REPOSITORY
+        .set(Arc::new(dust_sweep_repo))
+        .map_err(|_| {
+            super::RecordError::Other("Dust sweep repository already initialized".to_string())
+        })?;
+
+    let funding_repo = SqliteFundingSettlementRecordRepository::new().await?;
+    GLOBAL_FUNDING_SETTLEMENT_REPOSITORY
+        .set(Arc::new(funding_repo))
+        .map_err(|_| {
+            super::RecordError::Other(
+                "Funding settlement repository already initialized".to_string(),
+            )
+        })?;
+
     Ok(())
 }
 
@@ -41,6 +90,27 @@ pub fn get_position_repository() -> Option<Arc<dyn PositionRecordRepository + Se
     GLOBAL_POSITION_REPOSITORY.get().cloned()
 }
 
+/// 전역 섀도 거래 기록 Repository 가져오기
+pub fn get_shadow_repository() -> Option<Arc<dyn ShadowTradeRecordRepository + Send + Sync>> {
+    GLOBAL_SHADOW_REPOSITORY.get().cloned()
+}
+
+/// 전역 에쿼티 표본 Repository 가져오기
+pub fn get_equity_repository() -> Option<Arc<dyn EquityRecordRepository + Send + Sync>> {
+    GLOBAL_EQUITY_REPOSITORY.get().cloned()
+}
+
+/// 전역 소액 잔고 정리 기록 Repository 가져오기
+pub fn get_dust_sweep_repository() -> Option<Arc<dyn DustSweepRecordRepository + Send + Sync>> {
+    GLOBAL_DUST_SWEEP_REPOSITORY.get().cloned()
+}
+
+/// 전역 펀딩비 정산 기록 Repository 가져오기
+pub fn get_funding_settlement_repository()
+-> Option<Arc<dyn FundingSettlementRecordRepository + Send + Sync>> {
+    GLOBAL_FUNDING_SETTLEMENT_REPOSITORY.get().cloned()
+}
+
 /// 거래 기록 저장 (전역 Repository 사용)
 /// Repository가 초기화되지 않았으면 에러 없이 무시
 pub async fn save_trade_record_safe(record: &super::TradeRecord) {
@@ -81,3 +151,43 @@ pub async fn save_position_record_safe(
         }
     }
 }
+
+/// 섀도 거래 기록 저장 (전역 Repository 사용)
+/// Repository가 초기화되지 않았으면 에러 없이 무시
+pub async fn save_shadow_trade_record_safe(record: &ShadowTradeRecord) {
+    if let Some(repo) = get_shadow_repository()
+        && let Err(e) = repo.save(record).await
+    {
+        tracing::warn!("Failed to save shadow trade record: {}", e);
+    }
+}
+
+/// 에쿼티 표본 저장 (전역 Repository 사용)
+/// Repository가 초기화되지 않았으면 에러 없이 무시
+pub async fn save_equity_record_safe(record: &EquityRecord) {
+    if let Some(repo) = get_equity_repository()
+        && let Err(e) = repo.save(record).await
+    {
+        tracing::warn!("Failed to save equity record: {}", e);
+    }
+}
+
+/// 소액 잔고 정리 기록 저장 (전역 Repository 사용)
+/// Repository가 초기화되지 않았으면 에러 없이 무시
+pub async fn save_dust_sweep_record_safe(record: &DustSweepRecord) {
+    if let Some(repo) = get_dust_sweep_repository()
+        && let Err(e) = repo.save(record).await
+    {
+        tracing::warn!("Failed to save dust sweep record: {}", e);
+    }
+}
+
+/// 펀딩비 정산 기록 저장 (전역 Repository 사용)
+/// Repository가 초기화되지 않았으면 에러 없이 무시
+pub async fn save_funding_settlement_record_safe(record: &FundingSettlementRecord) {
+    if let Some(repo) = get_funding_settlement_repository()
+        && let Err(e) = repo.save(record).await
+    {
+        tracing::warn!("Failed to save funding settlement record: {}", e);
+    }
+}
@@ -41,8 +41,11 @@ pub fn create_trade_record_from_order(
 }
 
 /// Spot 주문 기록 저장 (편의 함수)
+/// account: 주문을 낸 계정 라벨 (metadata.account 로 저장)
+#[allow(clippy::too_many_arguments)]
 pub async fn save_trade_record_spot_order(
     exchange: &str,
+    account: &str,
     symbol: &str,
     side: &str,
     quantity: f64,
@@ -57,7 +60,7 @@ pub async fn save_trade_record_spot_order(
         Err(_) => return, // 잘못된 side는 무시
     };
 
-    let record = create_trade_record_from_order(
+    let mut record = create_trade_record_from_order(
         exchange.to_string(),
         symbol.to_string(),
         MarketType::Spot,
@@ -68,13 +71,17 @@ pub async fn save_trade_record_spot_order(
         order_response,
         is_liquidation,
     );
+    add_metadata(&mut record, serde_json::json!({ "account": account }));
 
     save_trade_record_safe(&record).await;
 }
 
 /// Futures 주문 기록 저장 (편의 함수)
+/// account: 주문을 낸 계정 라벨 (metadata.account 로 저장)
+#[allow(clippy::too_many_arguments)]
 pub async fn save_trade_record_futures_order(
     exchange: &str,
+    account: &str,
     symbol: &str,
     side: &str,
     quantity: f64,
@@ -90,7 +97,7 @@ pub async fn save_trade_record_futures_order(
         Err(_) => return, // 잘못된 side는 무시
     };
 
-    let record = create_trade_record_from_order(
+    let mut record = create_trade_record_from_order(
         exchange.to_string(),
         symbol.to_string(),
         MarketType::Futures,
@@ -101,6 +108,7 @@ pub async fn save_trade_record_futures_order(
         order_response,
         is_liquidation,
     );
+    add_metadata(&mut record, serde_json::json!({ "account": account }));
 
     save_trade_record_safe(&record).await;
 }
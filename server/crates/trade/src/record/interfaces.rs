@@ -296,6 +296,364 @@ pub trait PositionRecordRepository: Send + Sync {
 
     /// 모든 포지션 기록 조회
     async fn find_all(&self, limit: Option<u64>) -> Result<Vec<StoredPositionRecord>, RecordError>;
+
+    /// ID로 포지션 기록 조회
+    async fn find_by_id(&self, id: i64) -> Result<Option<StoredPositionRecord>, RecordError>;
+
+    /// 심볼로 포지션 기록 조회 (최신순)
+    async fn find_by_symbol(
+        &self,
+        symbol: &str,
+        limit: Option<u64>,
+    ) -> Result<Vec<StoredPositionRecord>, RecordError>;
+}
+
+/// 섀도(페이퍼) 거래 기록
+/// 실전 전략과 같은 시세로 후보 파라미터를 돌렸을 때의 가상 진입/청산
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowTradeRecord {
+    /// 가상 체결 UTC 시간
+    pub executed_at: DateTime<Utc>,
+    /// 섀도를 돌린 실전 전략 ID
+    pub strategy_id: String,
+    /// 후보 파라미터 이름
+    pub variant: String,
+    /// 코인 심볼
+    pub symbol: String,
+    /// 포지션 방향 (CARRY, REVERSE)
+    pub carry: String,
+    /// 포지션 액션 (OPEN, CLOSE)
+    pub action: String,
+    /// 후보 진입 임계값 (bps)
+    pub entry_bps: f64,
+    /// 후보 청산 임계값 (bps)
+    pub exit_bps: f64,
+    /// 가상 체결 시점 베이시스 (bps)
+    pub basis_bps: f64,
+    /// 스팟 가격
+    pub spot_price: f64,
+    /// 선물 마크 가격
+    pub futures_mark: f64,
+    /// 가상 수량
+    pub quantity: f64,
+    /// 청산 시 수수료 차감 손익 (bps)
+    pub pnl_bps: Option<f64>,
+    /// 청산 시 수수료 차감 손익 (USDT)
+    pub pnl_usdt: Option<f64>,
+}
+
+/// 저장소에 저장된 섀도 거래 기록 (ID 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredShadowTradeRecord {
+    /// 데이터베이스 ID
+    pub id: i64,
+    /// 섀도 거래 기록 데이터
+    #[serde(flatten)]
+    pub record: ShadowTradeRecord,
+}
+
+/// SeaORM shadow_trade_record::Model을 StoredShadowTradeRecord로 변환
+impl TryFrom<super::entities::shadow_trade_record::Model> for StoredShadowTradeRecord {
+    type Error = RecordError;
+
+    fn try_from(model: super::entities::shadow_trade_record::Model) -> Result<Self, Self::Error> {
+        let executed_at = DateTime::parse_from_rfc3339(&model.executed_at)
+            .map_err(|e| RecordError::Other(format!("Failed to parse executed_at: {}", e)))?
+            .with_timezone(&Utc);
+
+        let record = ShadowTradeRecord {
+            executed_at,
+            strategy_id: model.strategy_id,
+            variant: model.variant,
+            symbol: model.symbol,
+            carry: model.carry,
+            action: model.action,
+            entry_bps: model.entry_bps,
+            exit_bps: model.exit_bps,
+            basis_bps: model.basis_bps,
+            spot_price: model.spot_price,
+            futures_mark: model.futures_mark,
+            quantity: model.quantity,
+            pnl_bps: model.pnl_bps,
+            pnl_usdt: model.pnl_usdt,
+        };
+
+        Ok(StoredShadowTradeRecord {
+            id: model.id,
+            record,
+        })
+    }
+}
+
+/// 섀도 거래 기록 저장소 인터페이스 (실전 거래 기록과 별도 테이블)
+#[async_trait]
+pub trait ShadowTradeRecordRepository: Send + Sync {
+    /// 섀도 거래 기록 저장
+    async fn save(&self, record: &ShadowTradeRecord) -> Result<(), RecordError>;
+
+    /// 섀도 거래 기록 조회 (전략 ID 필터, 최신순)
+    async fn find_all(
+        &self,
+        strategy_id: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<Vec<StoredShadowTradeRecord>, RecordError>;
+}
+
+/// 계좌 평가액(에쿼티) 표본
+/// 모든 거래소 잔고와 선물 미실현 손익을 USDT(및 KRW)로 평가한 값
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquityRecord {
+    /// 평가 UTC 시간
+    pub sampled_at: DateTime<Utc>,
+    /// 총 평가액 (USDT)
+    pub equity_usdt: f64,
+    /// 총 평가액 (KRW)
+    pub equity_krw: Option<f64>,
+    /// 현금성 자산 (USDT 환산)
+    pub cash_usdt: f64,
+    /// 현금 외 현물 보유 평가액 (USDT)
+    pub spot_usdt: f64,
+    /// 선물 지갑 잔고 (USDT)
+    pub futures_wallet_usdt: f64,
+    /// 선물 미실현 손익 (USDT)
+    pub unrealized_pnl_usdt: f64,
+    /// 롤링 고점 대비 하락률 (%)
+    pub drawdown_pct: f64,
+}
+
+/// 저장소에 저장된 에쿼티 표본 (ID 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredEquityRecord {
+    /// 데이터베이스 ID
+    pub id: i64,
+    /// 에쿼티 표본 데이터
+    #[serde(flatten)]
+    pub record: EquityRecord,
+}
+
+/// SeaORM equity_point::Model을 StoredEquityRecord로 변환
+impl TryFrom<super::entities::equity_point::Model> for StoredEquityRecord {
+    type Error = RecordError;
+
+    fn try_from(model: super::entities::equity_point::Model) -> Result<Self, Self::Error> {
+        let sampled_at = DateTime::parse_from_rfc3339(&model.sampled_at)
+            .map_err(|e| RecordError::Other(format!("Failed to parse sampled_at: {}", e)))?
+            .with_timezone(&Utc);
+
+        let record = EquityRecord {
+            sampled_at,
+            equity_usdt: model.equity_usdt,
+            equity_krw: model.equity_krw,
+            cash_usdt: model.cash_usdt,
+            spot_usdt: model.spot_usdt,
+            futures_wallet_usdt: model.futures_wallet_usdt,
+            unrealized_pnl_usdt: model.unrealized_pnl_usdt,
+            drawdown_pct: model.drawdown_pct,
+        };
+
+        Ok(StoredEquityRecord {
+            id: model.id,
+            record,
+        })
+    }
+}
+
+/// 에쿼티 표본 저장소 인터페이스
+#[async_trait]
+pub trait EquityRecordRepository: Send + Sync {
+    /// 에쿼티 표본 저장
+    async fn save(&self, record: &EquityRecord) -> Result<(), RecordError>;
+
+    /// 에쿼티 표본 조회 (최신순)
+    async fn find_recent(&self, limit: Option<u64>) -> Result<Vec<StoredEquityRecord>, RecordError>;
+}
+
+/// 소액 잔고 정리 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DustSweepStatus {
+    /// 거래소 더스트 변환으로 BNB 전환
+    Converted,
+    /// 시장가 매도로 KRW 전환
+    Sold,
+    /// 최소 주문 금액 미만 등으로 건너뜀
+    Skipped,
+    Failed,
+}
+
+impl DustSweepStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DustSweepStatus::Converted => "converted",
+            DustSweepStatus::Sold => "sold",
+            DustSweepStatus::Skipped => "skipped",
+            DustSweepStatus::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for DustSweepStatus {
+    type Err = RecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "converted" => Ok(DustSweepStatus::Converted),
+            "sold" => Ok(DustSweepStatus::Sold),
+            "skipped" => Ok(DustSweepStatus::Skipped),
+            "failed" => Ok(DustSweepStatus::Failed),
+            other => Err(RecordError::Other(format!("Unknown dust sweep status: {}", other))),
+        }
+    }
+}
+
+/// 소액 잔고 정리 기록 (자산 하나당 한 건)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DustSweepRecord {
+    /// 정리 UTC 시간
+    pub swept_at: DateTime<Utc>,
+    /// 거래소 (binance, bithumb)
+    pub exchange: String,
+    pub asset: String,
+    /// 정리 대상 수량
+    pub amount: f64,
+    /// 받은 자산 (BNB, KRW)
+    pub target_asset: String,
+    /// 받은 수량 (수수료 차감 후)
+    pub received_amount: f64,
+    /// 수수료 (받은 자산 기준)
+    pub fee: f64,
+    pub status: DustSweepStatus,
+    /// 거래소 이체/주문 ID 또는 건너뛴/실패 사유
+    pub detail: Option<String>,
+}
+
+/// 저장소에 저장된 소액 잔고 정리 기록 (ID 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredDustSweepRecord {
+    /// 데이터베이스 ID
+    pub id: i64,
+    /// 정리 기록 데이터
+    #[serde(flatten)]
+    pub record: DustSweepRecord,
+}
+
+/// SeaORM dust_sweep_record::Model을 StoredDustSweepRecord로 변환
+impl TryFrom<super::entities::dust_sweep_record::Model> for StoredDustSweepRecord {
+    type Error = RecordError;
+
+    fn try_from(model: super::entities::dust_sweep_record::Model) -> Result<Self, Self::Error> {
+        let swept_at = DateTime::parse_from_rfc3339(&model.swept_at)
+            .map_err(|e| RecordError::Other(format!("Failed to parse swept_at: {}", e)))?
+            .with_timezone(&Utc);
+
+        let record = DustSweepRecord {
+            swept_at,
+            exchange: model.exchange,
+            asset: model.asset,
+            amount: model.amount,
+            target_asset: model.target_asset,
+            received_amount: model.received_amount,
+            fee: model.fee,
+            status: model.status.parse()?,
+            detail: model.detail,
+        };
+
+        Ok(StoredDustSweepRecord {
+            id: model.id,
+            record,
+        })
+    }
+}
+
+/// 소액 잔고 정리 기록 저장소 인터페이스
+#[async_trait]
+pub trait DustSweepRecordRepository: Send + Sync {
+    /// 정리 기록 저장
+    async fn save(&self, record: &DustSweepRecord) -> Result<(), RecordError>;
+
+    /// 정리 기록 조회 (최신순)
+    async fn find_recent(&self, limit: Option<u64>)
+        -> Result<Vec<StoredDustSweepRecord>, RecordError>;
+}
+
+/// 펀딩비 정산 기록 (포지션이 열려 있는 동안 정산 한 번당 한 건)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundingSettlementRecord {
+    /// 정산 UTC 시간
+    pub settled_at: DateTime<Utc>,
+    /// 정산 시점에 열려 있던 포지션의 OPEN 기록 ID
+    pub position_record_id: i64,
+    pub bot_name: String,
+    pub symbol: String,
+    /// 정산 거래소 (예: binance_futures)
+    pub venue: String,
+    /// 정산된 펀딩비율 (0.0001 == 0.01%)
+    pub funding_rate: f64,
+    /// 받은 펀딩비 (USDT, 지급했으면 음수)
+    pub payment: f64,
+    /// 정산 시점 선물 포지션 수량 (롱 양수, 숏 음수)
+    pub position_qty: f64,
+    /// 정산 마크 가격
+    pub mark_price: Option<f64>,
+}
+
+/// 저장소에 저장된 펀딩비 정산 기록 (ID 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredFundingSettlementRecord {
+    /// 데이터베이스 ID
+    pub id: i64,
+    /// 정산 기록 데이터
+    #[serde(flatten)]
+    pub record: FundingSettlementRecord,
+}
+
+/// SeaORM funding_settlement_record::Model을 StoredFundingSettlementRecord로 변환
+impl TryFrom<super::entities::funding_settlement_record::Model> for StoredFundingSettlementRecord {
+    type Error = RecordError;
+
+    fn try_from(
+        model: super::entities::funding_settlement_record::Model,
+    ) -> Result<Self, Self::Error> {
+        let settled_at = DateTime::parse_from_rfc3339(&model.settled_at)
+            .map_err(|e| RecordError::Other(format!("Failed to parse settled_at: {}", e)))?
+            .with_timezone(&Utc);
+
+        let record = FundingSettlementRecord {
+            settled_at,
+            position_record_id: model.position_record_id,
+            bot_name: model.bot_name,
+            symbol: model.symbol,
+            venue: model.venue,
+            funding_rate: model.funding_rate,
+            payment: model.payment,
+            position_qty: model.position_qty,
+            mark_price: model.mark_price,
+        };
+
+        Ok(StoredFundingSettlementRecord {
+            id: model.id,
+            record,
+        })
+    }
+}
+
+/// 펀딩비 정산 기록 저장소 인터페이스
+#[async_trait]
+pub trait FundingSettlementRecordRepository: Send + Sync {
+    /// 정산 기록 저장 (같은 심볼·정산 시각·포지션의 기록이 이미 있으면 무시)
+    async fn save(&self, record: &FundingSettlementRecord) -> Result<(), RecordError>;
+
+    /// 정산 기록 조회 (최신순)
+    async fn find_recent(
+        &self,
+        limit: Option<u64>,
+    ) -> Result<Vec<StoredFundingSettlementRecord>, RecordError>;
+
+    /// 포지션 OPEN 기록에 연결된 정산 기록 (정산 시각순)
+    async fn find_by_position(
+        &self,
+        position_record_id: i64,
+    ) -> Result<Vec<StoredFundingSettlementRecord>, RecordError>;
 }
 
 /// 기록 저장소 에러 타입
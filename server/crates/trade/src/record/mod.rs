@@ -1,13 +1,19 @@
+pub mod archive;
+pub mod csv;
 pub mod entities;
 pub mod global;
 pub mod helpers;
 pub mod interfaces;
 pub mod sqlite;
 
+pub use archive::{RecordArchiver, RetentionPolicy, TableArchive, start_archive_job};
 pub use global::*;
 pub use helpers::*;
 pub use interfaces::{
-    MarketType, PositionRecord, PositionRecordRepository, RecordError, StoredPositionRecord,
-    StoredTradeRecord, TradeRecord, TradeRecordRepository, TradeSide, TradeType,
+    DustSweepRecord, DustSweepRecordRepository, DustSweepStatus, EquityRecord, EquityRecordRepository, FundingSettlementRecord, FundingSettlementRecordRepository, MarketType, PositionRecord, PositionRecordRepository, RecordError, ShadowTradeRecord,
+    ShadowTradeRecordRepository, StoredDustSweepRecord, StoredEquityRecord, StoredFundingSettlementRecord, StoredPositionRecord, StoredShadowTradeRecord, StoredTradeRecord,
+    TradeRecord, TradeRecordRepository, TradeSide, TradeType,
+};
+pub use sqlite::{
+    SqliteDustSweepRecordRepository, SqliteEquityRecordRepository, SqliteFundingSettlementRecordRepository, SqlitePositionRecordRepository, SqliteShadowTradeRecordRepository, SqliteTradeRecordRepository,
 };
-pub use sqlite::{SqlitePositionRecordRepository, SqliteTradeRecordRepository};
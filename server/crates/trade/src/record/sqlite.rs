@@ -9,10 +9,15 @@ use std::env;
 use std::path::PathBuf;
 use tracing::info;
 
+use super::entities::dust_sweep_record;
+use super::entities::equity_point;
+use super::entities::funding_settlement_record;
 use super::entities::position_record;
+use super::entities::shadow_trade_record;
 use super::entities::trade_record;
 use super::{
-    PositionRecordRepository, RecordError, StoredPositionRecord, StoredTradeRecord, TradeRecord,
+    DustSweepRecord, DustSweepRecordRepository, StoredDustSweepRecord, EquityRecord, EquityRecordRepository, FundingSettlementRecord, FundingSettlementRecordRepository, StoredFundingSettlementRecord, PositionRecordRepository, RecordError, ShadowTradeRecord, ShadowTradeRecordRepository,
+    StoredEquityRecord, StoredPositionRecord, StoredShadowTradeRecord, StoredTradeRecord, TradeRecord,
     TradeRecordRepository,
 };
 
@@ -366,4 +371,468 @@ impl PositionRecordRepository for SqlitePositionRecordRepository {
 
         models.into_iter().map(|m| m.try_into()).collect()
     }
+
+    async fn find_by_id(&self, id: i64) -> Result<Option<StoredPositionRecord>, RecordError> {
+        let model = position_record::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+
+        model.map(|m| m.try_into()).transpose()
+    }
+
+    async fn find_by_symbol(
+        &self,
+        symbol: &str,
+        limit: Option<u64>,
+    ) -> Result<Vec<StoredPositionRecord>, RecordError> {
+        let mut query = position_record::Entity::find()
+            .filter(position_record::Column::Symbol.eq(symbol))
+            .order_by_desc(position_record::Column::ExecutedAt);
+
+        if let Some(limit_val) = limit {
+            query = query.limit(limit_val);
+        }
+
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+}
+
+// ============================================================================
+// 섀도 거래 기록 저장소
+// ============================================================================
+
+/// SQLite 기반 섀도(페이퍼) 거래 기록 저장소
+pub struct SqliteShadowTradeRecordRepository {
+    db: DatabaseConnection,
+}
+
+impl SqliteShadowTradeRecordRepository {
+    /// 새로운 SQLite 저장소 인스턴스 생성
+    /// DB 파일 경로는 환경 변수 DB_PATH로 지정 가능 (기본값: "trade_records.db")
+    pub async fn new() -> Result<Self, RecordError> {
+        let db_path = env::var("DB_PATH").unwrap_or_else(|_| "trade_records.db".to_string());
+
+        let mut path = PathBuf::from(&db_path);
+        if !path.is_absolute()
+            && let Ok(current_dir) = env::current_dir()
+        {
+            path = current_dir.join(&db_path);
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| RecordError::Other(format!("Failed to create DB directory: {}", e)))?;
+        }
+
+        let db_url = format!("sqlite://{}?mode=rwc", path.to_string_lossy());
+        Self::connect(&db_url).await
+    }
+
+    /// DB URL로 연결하고 테이블 생성 (테스트는 sqlite::memory: 사용)
+    pub async fn connect(db_url: &str) -> Result<Self, RecordError> {
+        info!(
+            "Connecting to SQLite database for shadow trade records: {}",
+            db_url
+        );
+
+        let db = Database::connect(db_url)
+            .await
+            .map_err(RecordError::Database)?;
+
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_table_stmt = schema.create_table_from_entity(shadow_trade_record::Entity);
+        create_table_stmt.if_not_exists();
+
+        db.execute(backend.build(&create_table_stmt))
+            .await
+            .map_err(RecordError::Database)?;
+
+        info!("Shadow trade records table initialized");
+
+        Ok(Self { db })
+    }
+}
+
+#[async_trait]
+impl ShadowTradeRecordRepository for SqliteShadowTradeRecordRepository {
+    async fn save(&self, record: &ShadowTradeRecord) -> Result<(), RecordError> {
+        let model = shadow_trade_record::ActiveModel {
+            executed_at: Set(record.executed_at.to_rfc3339()),
+            strategy_id: Set(record.strategy_id.clone()),
+            variant: Set(record.variant.clone()),
+            symbol: Set(record.symbol.clone()),
+            carry: Set(record.carry.clone()),
+            action: Set(record.action.clone()),
+            entry_bps: Set(record.entry_bps),
+            exit_bps: Set(record.exit_bps),
+            basis_bps: Set(record.basis_bps),
+            spot_price: Set(record.spot_price),
+            futures_mark: Set(record.futures_mark),
+            quantity: Set(record.quantity),
+            pnl_bps: Set(record.pnl_bps),
+            pnl_usdt: Set(record.pnl_usdt),
+            ..Default::default()
+        };
+
+        shadow_trade_record::Entity::insert(model)
+            .exec(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        strategy_id: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<Vec<StoredShadowTradeRecord>, RecordError> {
+        let mut query = shadow_trade_record::Entity::find()
+            .order_by_desc(shadow_trade_record::Column::ExecutedAt);
+
+        if let Some(strategy_id) = strategy_id {
+            query = query.filter(shadow_trade_record::Column::StrategyId.eq(strategy_id));
+        }
+        if let Some(limit_val) = limit {
+            query = query.limit(limit_val);
+        }
+
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+}
+
+// ============================================================================
+// 에쿼티 표본 저장소
+// ============================================================================
+
+/// SQLite 기반 에쿼티 표본 저장소
+pub struct SqliteEquityRecordRepository {
+    db: DatabaseConnection,
+}
+
+impl SqliteEquityRecordRepository {
+    /// 새로운 SQLite 저장소 인스턴스 생성
+    /// DB 파일 경로는 환경 변수 DB_PATH로 지정 가능 (기본값: "trade_records.db")
+    pub async fn new() -> Result<Self, RecordError> {
+        let db_path = env::var("DB_PATH").unwrap_or_else(|_| "trade_records.db".to_string());
+
+        let mut path = PathBuf::from(&db_path);
+        if !path.is_absolute()
+            && let Ok(current_dir) = env::current_dir()
+        {
+            path = current_dir.join(&db_path);
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| RecordError::Other(format!("Failed to create DB directory: {}", e)))?;
+        }
+
+        let db_url = format!("sqlite://{}?mode=rwc", path.to_string_lossy());
+        Self::connect(&db_url).await
+    }
+
+    /// DB URL로 연결하고 테이블 생성 (테스트는 sqlite::memory: 사용)
+    pub async fn connect(db_url: &str) -> Result<Self, RecordError> {
+        info!("Connecting to SQLite database for equity points: {}", db_url);
+
+        let db = Database::connect(db_url)
+            .await
+            .map_err(RecordError::Database)?;
+
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_table_stmt = schema.create_table_from_entity(equity_point::Entity);
+        create_table_stmt.if_not_exists();
+
+        db.execute(backend.build(&create_table_stmt))
+            .await
+            .map_err(RecordError::Database)?;
+
+        info!("Equity points table initialized");
+
+        Ok(Self { db })
+    }
+}
+
+#[async_trait]
+impl EquityRecordRepository for SqliteEquityRecordRepository {
+    async fn save(&self, record: &EquityRecord) -> Result<(), RecordError> {
+        let model = equity_point::ActiveModel {
+            sampled_at: Set(record.sampled_at.to_rfc3339()),
+            equity_usdt: Set(record.equity_usdt),
+            equity_krw: Set(record.equity_krw),
+            cash_usdt: Set(record.cash_usdt),
+            spot_usdt: Set(record.spot_usdt),
+            futures_wallet_usdt: Set(record.futures_wallet_usdt),
+            unrealized_pnl_usdt: Set(record.unrealized_pnl_usdt),
+            drawdown_pct: Set(record.drawdown_pct),
+            ..Default::default()
+        };
+
+        equity_point::Entity::insert(model)
+            .exec(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+
+        Ok(())
+    }
+
+    async fn find_recent(&self, limit: Option<u64>) -> Result<Vec<StoredEquityRecord>, RecordError> {
+        let mut query =
+            equity_point::Entity::find().order_by_desc(equity_point::Column::SampledAt);
+
+        if let Some(limit_val) = limit {
+            query = query.limit(limit_val);
+        }
+
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+}
+
+// ============================================================================
+// 소액 잔고 정리 기록 저장소
+// ============================================================================
+
+/// SQLite 기반 소액 잔고 정리 기록 저장소
+pub struct SqliteDustSweepRecordRepository {
+    db: DatabaseConnection,
+}
+
+impl SqliteDustSweepRecordRepository {
+    /// 새로운 SQLite 저장소 인스턴스 생성
+    /// DB 파일 경로는 환경 변수 DB_PATH로 지정 가능 (기본값: "trade_records.db")
+    pub async fn new() -> Result<Self, RecordError> {
+        let db_path = env::var("DB_PATH").unwrap_or_else(|_| "trade_records.db".to_string());
+
+        let mut path = PathBuf::from(&db_path);
+        if !path.is_absolute()
+            && let Ok(current_dir) = env::current_dir()
+        {
+            path = current_dir.join(&db_path);
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| RecordError::Other(format!("Failed to create DB directory: {}", e)))?;
+        }
+
+        let db_url = format!("sqlite://{}?mode=rwc", path.to_string_lossy());
+        Self::connect(&db_url).await
+    }
+
+    /// DB URL로 연결하고 테이블 생성 (테스트는 sqlite::memory: 사용)
+    pub async fn connect(db_url: &str) -> Result<Self, RecordError> {
+        info!("Connecting to SQLite database for dust sweep records: {}", db_url);
+
+        let db = Database::connect(db_url)
+            .await
+            .map_err(RecordError::Database)?;
+
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_table_stmt = schema.create_table_from_entity(dust_sweep_record::Entity);
+        create_table_stmt.if_not_exists();
+
+        db.execute(backend.build(&create_table_stmt))
+            .await
+            .map_err(RecordError::Database)?;
+
+        info!("Dust sweep records table initialized");
+
+        Ok(Self { db })
+    }
+}
+
+#[async_trait]
+impl DustSweepRecordRepository for SqliteDustSweepRecordRepository {
+    async fn save(&self, record: &DustSweepRecord) -> Result<(), RecordError> {
+        let model = dust_sweep_record::ActiveModel {
+            swept_at: Set(record.swept_at.to_rfc3339()),
+            exchange: Set(record.exchange.clone()),
+            asset: Set(record.asset.clone()),
+            amount: Set(record.amount),
+            target_asset: Set(record.target_asset.clone()),
+            received_amount: Set(record.received_amount),
+            fee: Set(record.fee),
+            status: Set(record.status.as_str().to_string()),
+            detail: Set(record.detail.clone()),
+            ..Default::default()
+        };
+
+        dust_sweep_record::Entity::insert(model)
+            .exec(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+
+        Ok(())
+    }
+
+    async fn find_recent(
+        &self,
+        limit: Option<u64>,
+    ) -> Result<Vec<StoredDustSweepRecord>, RecordError> {
+        let mut query =
+            dust_sweep_record::Entity::find().order_by_desc(dust_sweep_record::Column::SweptAt);
+
+        if let Some(limit_val) = limit {
+            query = query.limit(limit_val);
+        }
+
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+}
+
+// ============================================================================
+// 펀딩비 정산 기록 저장소
+// ============================================================================
+
+/// SQLite 기반 펀딩비 정산 기록 저장소
+pub struct SqliteFundingSettlementRecordRepository {
+    db: DatabaseConnection,
+}
+
+impl SqliteFundingSettlementRecordRepository {
+    /// 새로운 SQLite 저장소 인스턴스 생성
+    /// DB 파일 경로는 환경 변수 DB_PATH로 지정 가능 (기본값: "trade_records.db")
+    pub async fn new() -> Result<Self, RecordError> {
+        let db_path = env::var("DB_PATH").unwrap_or_else(|_| "trade_records.db".to_string());
+
+        let mut path = PathBuf::from(&db_path);
+        if !path.is_absolute()
+            && let Ok(current_dir) = env::current_dir()
+        {
+            path = current_dir.join(&db_path);
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| RecordError::Other(format!("Failed to create DB directory: {}", e)))?;
+        }
+
+        let db_url = format!("sqlite://{}?mode=rwc", path.to_string_lossy());
+        Self::connect(&db_url).await
+    }
+
+    /// DB URL로 연결하고 테이블 생성 (테스트는 sqlite::memory: 사용)
+    pub async fn connect(db_url: &str) -> Result<Self, RecordError> {
+        info!(
+            "Connecting to SQLite database for funding settlement records: {}",
+            db_url
+        );
+
+        let db = Database::connect(db_url)
+            .await
+            .map_err(RecordError::Database)?;
+
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_table_stmt =
+            schema.create_table_from_entity(funding_settlement_record::Entity);
+        create_table_stmt.if_not_exists();
+
+        db.execute(backend.build(&create_table_stmt))
+            .await
+            .map_err(RecordError::Database)?;
+
+        // 같은 정산을 두 번 저장하지 않도록 (심볼, 정산 시각, 포지션) 유일 인덱스
+        let mut unique_idx = sea_orm::sea_query::Index::create()
+            .name("idx_funding_settlement_records_unique")
+            .table(funding_settlement_record::Entity)
+            .col(funding_settlement_record::Column::Symbol)
+            .col(funding_settlement_record::Column::SettledAt)
+            .col(funding_settlement_record::Column::PositionRecordId)
+            .unique()
+            .to_owned();
+        unique_idx.if_not_exists();
+        if let Err(e) = db.execute(backend.build(&unique_idx)).await {
+            tracing::warn!(
+                "Index idx_funding_settlement_records_unique creation failed (duplicate rows?): {}",
+                e
+            );
+        }
+
+        info!("Funding settlement records table initialized");
+
+        Ok(Self { db })
+    }
+}
+
+#[async_trait]
+impl FundingSettlementRecordRepository for SqliteFundingSettlementRecordRepository {
+    async fn save(&self, record: &FundingSettlementRecord) -> Result<(), RecordError> {
+        let model = funding_settlement_record::ActiveModel {
+            settled_at: Set(record.settled_at.to_rfc3339()),
+            position_record_id: Set(record.position_record_id),
+            bot_name: Set(record.bot_name.clone()),
+            symbol: Set(record.symbol.clone()),
+            venue: Set(record.venue.clone()),
+            funding_rate: Set(record.funding_rate),
+            payment: Set(record.payment),
+            position_qty: Set(record.position_qty),
+            mark_price: Set(record.mark_price),
+            ..Default::default()
+        };
+
+        // 이미 저장된 정산이면 무시 (조회 커서가 전진하기 전에 다시 조회된 경우)
+        funding_settlement_record::Entity::insert(model)
+            .on_conflict(
+                sea_orm::sea_query::OnConflict::columns([
+                    funding_settlement_record::Column::Symbol,
+                    funding_settlement_record::Column::SettledAt,
+                    funding_settlement_record::Column::PositionRecordId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+
+        Ok(())
+    }
+
+    async fn find_recent(
+        &self,
+        limit: Option<u64>,
+    ) -> Result<Vec<StoredFundingSettlementRecord>, RecordError> {
+        let mut query = funding_settlement_record::Entity::find()
+            .order_by_desc(funding_settlement_record::Column::SettledAt);
+
+        if let Some(limit_val) = limit {
+            query = query.limit(limit_val);
+        }
+
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+
+    async fn find_by_position(
+        &self,
+        position_record_id: i64,
+    ) -> Result<Vec<StoredFundingSettlementRecord>, RecordError> {
+        let models = funding_settlement_record::Entity::find()
+            .filter(funding_settlement_record::Column::PositionRecordId.eq(position_record_id))
+            .order_by_asc(funding_settlement_record::Column::SettledAt)
+            .all(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
 }
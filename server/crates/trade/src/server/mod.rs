@@ -1,19 +1,171 @@
-use std::net::SocketAddr;
+use std::{convert::Infallible, net::SocketAddr, sync::Arc, time::Duration};
 
-use axum::{Json, Router, response::IntoResponse, routing::get};
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::{
+        Path, Query, Request, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{HeaderValue, StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use exchanges::supervisor::task_supervisor;
+use exchanges::weight::weight_tracker;
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
 use tower_http::cors::CorsLayer;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+use utoipa::{IntoParams, OpenApi};
+use utoipa_swagger_ui::SwaggerUi;
 
-use crate::record::{get_position_repository, get_repository};
+use crate::account_watch::account_watcher;
+use crate::address_book::address_book;
+use crate::allocation::global_allocator;
+use crate::arbitrage::control::operator_control;
+use crate::arbitrage::hedge_venue::hedge_venue_choices;
+use crate::arbitrage::inflight::inflight_orders;
+use crate::arbitrage::kill_switch::kill_switches;
+use crate::arbitrage::live::{StrategyStateRegistry, strategy_states};
+use crate::bnb_fee::bnb_fee_manager;
+use crate::credentials::check_credentials;
+use crate::equity::equity_tracker;
+use crate::events::{event_bus, event_metrics};
+use crate::exposure::compute_exposure;
+use crate::exposure::positions::fetch_live_positions;
+use crate::funding::funding_attribution;
+use crate::large_trade::large_trades;
+use crate::latency::latency_tracker;
+use crate::notification::notification_center;
+use crate::record::csv::{
+    POSITION_RECORD_CSV_HEADER, TRADE_RECORD_CSV_HEADER, position_record_csv_row,
+    trade_record_csv_row,
+};
+use crate::record::{
+    get_dust_sweep_repository, get_funding_settlement_repository, get_position_repository,
+    get_repository, get_shadow_repository,
+};
+use crate::symbol_info::symbol_info_cache;
+use crate::trader::{MarketKind, parse_exchange_id};
+
+/// OpenAPI 문서 (`/openapi.json`, Swagger UI는 `/swagger-ui`)
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Trade API", description = "거래/포지션 기록, 자금 배분, 지연 지표, 알림 조회 API"),
+    paths(
+        health_handler,
+        healthz_handler,
+        trade_records_handler,
+        position_records_handler,
+        shadow_trade_records_handler,
+        dust_sweep_records_handler,
+        funding_settlement_records_handler,
+        position_funding_handler,
+        trade_records_csv_handler,
+        position_records_csv_handler,
+        allocations_handler,
+        exposure_handler,
+        live_positions_handler,
+        equity_handler,
+        rearm_equity_breaker_handler,
+        bnb_fee_handler,
+        account_anomalies_handler,
+        credentials_status_handler,
+        address_book_handler,
+        symbol_info_handler,
+        latency_metrics_handler,
+        event_metrics_handler,
+        weight_metrics_handler,
+        task_metrics_handler,
+        alerts_handler,
+        large_trades_handler,
+        strategy_state_handler,
+        inflight_orders_handler,
+        kill_switches_handler,
+        hedge_venues_handler,
+        rearm_kill_switch_handler,
+        control_handler,
+        pause_handler,
+        resume_handler,
+        flatten_handler
+    ),
+    tags(
+        (name = "status", description = "서버 상태"),
+        (name = "records", description = "거래/포지션 기록"),
+        (name = "market", description = "거래소 심볼 정보"),
+        (name = "metrics", description = "운용 지표 및 알림"),
+        (name = "strategy", description = "실행 중인 전략 상태"),
+        (name = "control", description = "운영자 제어 (일시 정지/전량 청산)")
+    ),
+    modifiers(&ControlTokenAuth)
+)]
+pub struct ApiDoc;
+
+/// 제어 라우트의 Bearer 토큰 인증 스킴 (`control_token`)
+struct ControlTokenAuth;
+
+impl utoipa::Modify for ControlTokenAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+
+        openapi
+            .components
+            .get_or_insert_with(Default::default)
+            .add_security_scheme(
+                "control_token",
+                SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+            );
+    }
+}
 
 /// API 서버 시작
 /// 백그라운드에서 실행되며 거래 기록과 포지션 기록을 조회하는 API를 제공합니다
 pub async fn start_server(port: u16) -> eyre::Result<()> {
     let app = Router::new()
         .route("/health", get(health_handler))
+        .route("/healthz", get(healthz_handler))
         .route("/trade-records", get(trade_records_handler))
         .route("/position-records", get(position_records_handler))
-        .layer(CorsLayer::permissive());
+        .route("/shadow-trade-records", get(shadow_trade_records_handler))
+        .route("/dust-sweep-records", get(dust_sweep_records_handler))
+        .route(
+            "/funding-settlement-records",
+            get(funding_settlement_records_handler),
+        )
+        .route(
+            "/position-records/:id/funding",
+            get(position_funding_handler),
+        )
+        .route("/trade-records.csv", get(trade_records_csv_handler))
+        .route("/position-records.csv", get(position_records_csv_handler))
+        .route("/allocations", get(allocations_handler))
+        .route("/exposure", get(exposure_handler))
+        .route("/positions/live", get(live_positions_handler))
+        .route("/equity", get(equity_handler))
+        .route("/fees/bnb", get(bnb_fee_handler))
+        .route("/account/anomalies", get(account_anomalies_handler))
+        .route("/credentials/status", get(credentials_status_handler))
+        .route("/address-book", get(address_book_handler))
+        .route("/symbol-info", get(symbol_info_handler))
+        .route("/metrics/latency", get(latency_metrics_handler))
+        .route("/metrics/events", get(event_metrics_handler))
+        .route("/metrics/weight", get(weight_metrics_handler))
+        .route("/metrics/tasks", get(task_metrics_handler))
+        .route("/alerts", get(alerts_handler))
+        .route("/large-trades", get(large_trades_handler))
+        .route("/strategy/:id/state", get(strategy_state_handler))
+        .route("/strategy/inflight", get(inflight_orders_handler))
+        .route("/strategy/kill-switches", get(kill_switches_handler))
+        .route("/strategy/hedge-venues", get(hedge_venues_handler))
+        .route("/control", get(control_handler))
+        .route("/ws", get(events_ws_handler))
+        .merge(SwaggerUi::new("/swagger-ui").url("/openapi.json", ApiDoc::openapi()))
+        .layer(CorsLayer::permissive())
+        // 상태를 바꾸는 라우트는 CORS 없이 제어 토큰으로만 허용
+        .merge(control_routes(control_token_from_env()));
 
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     info!("Trade API server listening on http://{}", addr);
@@ -23,12 +175,131 @@ pub async fn start_server(port: u16) -> eyre::Result<()> {
     Ok(())
 }
 
+/// 제어 토큰 환경 변수 (`Authorization: Bearer <토큰>`)
+pub const CONTROL_TOKEN_ENV: &str = "TRADE_CONTROL_TOKEN";
+
+/// TRADE_CONTROL_TOKEN (비어 있으면 None)
+pub fn control_token_from_env() -> Option<String> {
+    std::env::var(CONTROL_TOKEN_ENV)
+        .ok()
+        .filter(|token| !token.is_empty())
+}
+
+/// 상태를 바꾸는 제어 라우트 (일시 정지/재개/전량 청산, 킬 스위치·드로다운 브레이커 재가동)
+/// 토큰이 없으면 모든 요청을 거부한다
+fn control_routes(token: Option<String>) -> Router {
+    Router::new()
+        .route("/equity/breaker/rearm", post(rearm_equity_breaker_handler))
+        .route(
+            "/strategy/:id/kill-switch/rearm",
+            post(rearm_kill_switch_handler),
+        )
+        .route("/control/pause", post(pause_handler))
+        .route("/control/resume", post(resume_handler))
+        .route("/control/flatten", post(flatten_handler))
+        .route_layer(middleware::from_fn_with_state(
+            token.map(Arc::<str>::from),
+            require_control_token,
+        ))
+}
+
+async fn require_control_token(
+    State(token): State<Option<Arc<str>>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match check_control_token(
+        token.as_deref(),
+        request.headers().get(header::AUTHORIZATION),
+    ) {
+        Ok(()) => next.run(request).await,
+        Err((status, message)) => {
+            warn!(
+                "제어 요청 거부 ({} {}): {}",
+                request.method(),
+                request.uri(),
+                message
+            );
+            (status, Json(serde_json::json!({ "error": message }))).into_response()
+        }
+    }
+}
+
+/// `Authorization: Bearer <토큰>` 검사
+fn check_control_token(
+    expected: Option<&str>,
+    authorization: Option<&HeaderValue>,
+) -> Result<(), (StatusCode, &'static str)> {
+    let Some(expected) = expected else {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "control API disabled (TRADE_CONTROL_TOKEN not set)",
+        ));
+    };
+    let provided = authorization
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match provided {
+        Some(provided) if constant_time_eq(provided.as_bytes(), expected.as_bytes()) => Ok(()),
+        _ => Err((StatusCode::UNAUTHORIZED, "invalid control token")),
+    }
+}
+
+/// 토큰 비교 시간으로 일치 길이가 드러나지 않도록 전체 바이트를 비교
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// Health check 핸들러
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "status",
+    responses(
+        (status = 200, description = "서버 동작 여부")
+    )
+)]
 async fn health_handler() -> impl IntoResponse {
     Json(serde_json::json!({ "status": "ok" }))
 }
 
+/// 백그라운드 태스크(가격 피드, User Data Stream 등) 상태. 하나라도 멈췄거나 종료되었으면 503
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    tag = "status",
+    responses(
+        (status = 200, description = "모든 백그라운드 태스크 정상"),
+        (status = 503, description = "하나 이상의 태스크가 종료되었거나 하트비트가 끊김 (감시기가 재시작 중)")
+    )
+)]
+async fn healthz_handler() -> impl IntoResponse {
+    let tasks = task_supervisor().report();
+    let code = if tasks.healthy {
+        axum::http::StatusCode::OK
+    } else {
+        axum::http::StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        code,
+        Json(serde_json::json!({
+            "status": if tasks.healthy { "ok" } else { "degraded" },
+            "tasks": tasks.tasks,
+        })),
+    )
+}
+
 /// 모든 거래 기록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/trade-records",
+    tag = "records",
+    responses(
+        (status = 200, description = "전체 거래 기록"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
 async fn trade_records_handler() -> impl IntoResponse {
     let repo = match get_repository() {
         Some(repo) => repo,
@@ -63,6 +334,15 @@ async fn trade_records_handler() -> impl IntoResponse {
 }
 
 /// 모든 포지션 기록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/position-records",
+    tag = "records",
+    responses(
+        (status = 200, description = "전체 포지션 기록"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
 async fn position_records_handler() -> impl IntoResponse {
     let repo = match get_position_repository() {
         Some(repo) => repo,
@@ -95,3 +375,1033 @@ async fn position_records_handler() -> impl IntoResponse {
         }
     }
 }
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct ShadowTradeRecordsQuery {
+    /// 실전 전략 ID 필터 (예: intra_basis:BTCUSDT)
+    strategy_id: Option<String>,
+    /// 최대 개수
+    limit: Option<u64>,
+}
+
+/// 섀도(페이퍼) 거래 기록 조회 핸들러 (최신순)
+#[utoipa::path(
+    get,
+    path = "/shadow-trade-records",
+    tag = "records",
+    params(ShadowTradeRecordsQuery),
+    responses(
+        (status = 200, description = "후보 파라미터로 돌린 가상 진입/청산 기록 (청산 기록은 수수료 차감 손익 포함)"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
+async fn shadow_trade_records_handler(
+    Query(query): Query<ShadowTradeRecordsQuery>,
+) -> impl IntoResponse {
+    let repo = match get_shadow_repository() {
+        Some(repo) => repo,
+        None => {
+            error!("Shadow trade record repository is not initialized");
+            return (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "Repository not initialized"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    match repo
+        .find_all(query.strategy_id.as_deref(), query.limit)
+        .await
+    {
+        Ok(records) => Json(serde_json::json!(records)).into_response(),
+        Err(e) => {
+            error!("Failed to fetch shadow trade records: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch shadow trade records: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct DustSweepRecordsQuery {
+    /// 최대 개수
+    limit: Option<u64>,
+}
+
+/// 소액 잔고 정리 기록 조회 핸들러 (최신순)
+#[utoipa::path(
+    get,
+    path = "/dust-sweep-records",
+    tag = "records",
+    params(DustSweepRecordsQuery),
+    responses(
+        (status = 200, description = "자산별 더스트 변환(BNB)/소액 매도(KRW) 결과"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
+async fn dust_sweep_records_handler(
+    Query(query): Query<DustSweepRecordsQuery>,
+) -> impl IntoResponse {
+    let Some(repo) = get_dust_sweep_repository() else {
+        error!("Dust sweep record repository is not initialized");
+        return (
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": "Repository not initialized"
+            })),
+        )
+            .into_response();
+    };
+
+    match repo.find_recent(query.limit).await {
+        Ok(records) => Json(serde_json::json!(records)).into_response(),
+        Err(e) => {
+            error!("Failed to fetch dust sweep records: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch dust sweep records: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct FundingSettlementRecordsQuery {
+    /// 최대 개수
+    limit: Option<u64>,
+}
+
+/// 펀딩비 정산 기록 조회 핸들러 (최신순)
+#[utoipa::path(
+    get,
+    path = "/funding-settlement-records",
+    tag = "records",
+    params(FundingSettlementRecordsQuery),
+    responses(
+        (status = 200, description = "포지션 OPEN 기록에 연결된 펀딩비 정산 (심볼, 거래소, 펀딩비율, 지급액, 포지션 수량)"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
+async fn funding_settlement_records_handler(
+    Query(query): Query<FundingSettlementRecordsQuery>,
+) -> impl IntoResponse {
+    let Some(repo) = get_funding_settlement_repository() else {
+        error!("Funding settlement record repository is not initialized");
+        return (
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": "Repository not initialized"
+            })),
+        )
+            .into_response();
+    };
+
+    match repo.find_recent(query.limit).await {
+        Ok(records) => Json(serde_json::json!(records)).into_response(),
+        Err(e) => {
+            error!("Failed to fetch funding settlement records: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch funding settlement records: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 포지션 수익 분해 핸들러 (펀딩비 vs 베이시스 수렴)
+#[utoipa::path(
+    get,
+    path = "/position-records/{id}/funding",
+    tag = "records",
+    params(("id" = i64, Path, description = "포지션 OPEN 기록 ID")),
+    responses(
+        (status = 200, description = "정산 목록, 펀딩비 합계(USDT/bps), 진입→청산 베이시스 수렴 손익(bps)"),
+        (status = 404, description = "없는 기록 또는 OPEN 기록이 아님"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
+async fn position_funding_handler(Path(id): Path<i64>) -> impl IntoResponse {
+    match funding_attribution(id).await {
+        Ok(Some(attribution)) => Json(serde_json::json!(attribution)).into_response(),
+        Ok(None) => (
+            axum::http::StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!("No OPEN position record: {}", id)
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            error!("Failed to build funding attribution: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to build funding attribution: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 헤더 뒤에 행을 한 줄씩 흘려보내는 CSV 다운로드 응답
+fn csv_response(filename: &str, header: &'static str, rows: Vec<String>) -> Response {
+    let chunks = std::iter::once(header.to_string()).chain(rows);
+    let body = Body::from_stream(stream::iter(chunks.map(Ok::<_, Infallible>)));
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// 모든 거래 기록 CSV 다운로드 핸들러
+#[utoipa::path(
+    get,
+    path = "/trade-records.csv",
+    tag = "records",
+    responses(
+        (status = 200, description = "전체 거래 기록 (CSV)", content_type = "text/csv"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
+async fn trade_records_csv_handler() -> impl IntoResponse {
+    let Some(repo) = get_repository() else {
+        error!("Trade record repository is not initialized");
+        return (
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": "Repository not initialized"
+            })),
+        )
+            .into_response();
+    };
+
+    match repo.find_all(None).await {
+        Ok(records) => {
+            info!("Returning {} trade records as CSV", records.len());
+            let rows = records.iter().map(trade_record_csv_row).collect();
+            csv_response("trade-records.csv", TRADE_RECORD_CSV_HEADER, rows)
+        }
+        Err(e) => {
+            error!("Failed to fetch trade records: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch trade records: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 모든 포지션 기록 CSV 다운로드 핸들러
+#[utoipa::path(
+    get,
+    path = "/position-records.csv",
+    tag = "records",
+    responses(
+        (status = 200, description = "전체 포지션 기록 (CSV)", content_type = "text/csv"),
+        (status = 500, description = "저장소 미초기화 또는 조회 실패")
+    )
+)]
+async fn position_records_csv_handler() -> impl IntoResponse {
+    let Some(repo) = get_position_repository() else {
+        error!("Position record repository is not initialized");
+        return (
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": "Repository not initialized"
+            })),
+        )
+            .into_response();
+    };
+
+    match repo.find_all(None).await {
+        Ok(records) => {
+            info!("Returning {} position records as CSV", records.len());
+            let rows = records.iter().map(position_record_csv_row).collect();
+            csv_response("position-records.csv", POSITION_RECORD_CSV_HEADER, rows)
+        }
+        Err(e) => {
+            error!("Failed to fetch position records: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": format!("Failed to fetch position records: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 전략별 운용 자금 배분 및 사용률 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/allocations",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "전략별 자금 배분 및 사용률")
+    )
+)]
+async fn allocations_handler() -> impl IntoResponse {
+    let reports = global_allocator().report();
+    info!("Returning {} allocations", reports.len());
+    Json(serde_json::json!(reports))
+}
+
+/// 포트폴리오 노출 조회 핸들러
+/// 저장된 기록이 아니라 거래소 잔고/선물 포지션을 직접 조회해 계산한다
+#[utoipa::path(
+    get,
+    path = "/exposure",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "베이스 자산별 순 델타, 총 명목가, 증거금 사용률, 거래소별 내역")
+    )
+)]
+async fn exposure_handler() -> impl IntoResponse {
+    let report = compute_exposure().await;
+    info!(
+        "Returning exposure: {} assets, {} venues, net delta {:.2} USDT",
+        report.assets.len(),
+        report.venues.len(),
+        report.net_delta_usdt
+    );
+    Json(serde_json::json!(report))
+}
+
+/// 실시간 포지션/잔고 미러 조회 핸들러
+/// 외부 위험 관리 도구용으로 거래소별 포지션과 잔고를 정규화해 반환한다
+#[utoipa::path(
+    get,
+    path = "/positions/live",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "거래소별 선물 포지션(방향, 수량, 진입가, 마크 가격, 미실현 손익)과 잔고")
+    )
+)]
+async fn live_positions_handler() -> impl IntoResponse {
+    let report = fetch_live_positions().await;
+    info!(
+        "Returning live positions: {} positions, {} balances, {} errors",
+        report.positions.len(),
+        report.balances.len(),
+        report.errors.len()
+    );
+    Json(serde_json::json!(report))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct EquityQuery {
+    /// 최근 표본 최대 개수 (기본 288)
+    limit: Option<usize>,
+}
+
+/// 에쿼티 곡선 조회 핸들러 (최근 표본, 롤링 고점/드로다운, 서킷 브레이커 상태)
+#[utoipa::path(
+    get,
+    path = "/equity",
+    tag = "metrics",
+    params(EquityQuery),
+    responses(
+        (status = 200, description = "USDT/KRW 평가액 표본(최신순), 롤링 드로다운, 최대 드로다운, 드로다운 서킷 브레이커")
+    )
+)]
+async fn equity_handler(Query(query): Query<EquityQuery>) -> impl IntoResponse {
+    Json(serde_json::json!(
+        equity_tracker().report(query.limit.unwrap_or(288))
+    ))
+}
+
+/// 드로다운 서킷 브레이커 재가동 핸들러 (손실 원인을 확인한 뒤 호출)
+#[utoipa::path(
+    post,
+    path = "/equity/breaker/rearm",
+    tag = "metrics",
+    security(("control_token" = [])),
+    responses(
+        (status = 200, description = "재가동됨 (해제된 작동 기록)"),
+        (status = 401, description = "제어 토큰 불일치"),
+        (status = 403, description = "TRADE_CONTROL_TOKEN 미설정"),
+        (status = 404, description = "서킷 브레이커가 작동 중이 아님")
+    )
+)]
+async fn rearm_equity_breaker_handler() -> impl IntoResponse {
+    match equity_tracker().rearm() {
+        Some(trip) => {
+            info!(
+                "드로다운 서킷 브레이커 재가동 (작동 시 드로다운 {:.2}%)",
+                trip.drawdown_pct
+            );
+            Json(serde_json::json!({ "rearmed": trip })).into_response()
+        }
+        None => (
+            axum::http::StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "Drawdown breaker not tripped" })),
+        )
+            .into_response(),
+    }
+}
+
+/// BNB 수수료 장부 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/fees/bnb",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "BNB 차감 설정, 잔고, 수수료로 쓴 BNB(USDT 환산), 보충 매수 누적, 현재 현물 수수료 할인율")
+    )
+)]
+async fn bnb_fee_handler() -> impl IntoResponse {
+    Json(serde_json::json!({
+        "ledger": bnb_fee_manager().report(),
+        "spot_fee_discount": bnb_fee_manager().spot_fee_discount(),
+    }))
+}
+
+/// 계정 잔고 이상 변동 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/account/anomalies",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "감시 설정, 아직 설명되지 않은 자산별 잔차, 최근 감지된 이상 변동 (설명되지 않은 수량, 추정 원인)")
+    )
+)]
+async fn account_anomalies_handler() -> impl IntoResponse {
+    Json(account_watcher().report())
+}
+
+/// 출금 주소 화이트리스트 조회 핸들러 (주소는 마스킹)
+#[utoipa::path(
+    get,
+    path = "/address-book",
+    tag = "status",
+    responses(
+        (status = 200, description = "주소록 항목별 거래소 화이트리스트 대조 상태와 마지막 대조 보고서")
+    )
+)]
+async fn address_book_handler() -> impl IntoResponse {
+    let book = address_book();
+    Json(serde_json::json!({
+        "entries": book.view(),
+        "last_report": book.last_report(),
+    }))
+}
+
+/// 거래소 API 키 상태 점검 핸들러
+/// 호출할 때마다 거래소별 인증 API를 한 번씩 호출한다 (시크릿은 응답에 포함하지 않음)
+#[utoipa::path(
+    get,
+    path = "/credentials/status",
+    tag = "status",
+    responses(
+        (status = 200, description = "거래소/계정/범위별 키 상태 (valid, invalid, permission_missing, not_configured, unknown)")
+    )
+)]
+async fn credentials_status_handler() -> impl IntoResponse {
+    let report = check_credentials().await;
+    info!(
+        "Returning credential status: {} checks, all valid: {}",
+        report.checks.len(),
+        report.all_valid
+    );
+    Json(serde_json::json!(report))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct SymbolInfoQuery {
+    /// binance | bybit | okx
+    venue: String,
+    /// 거래소 공통 심볼 (예: BTCUSDT)
+    symbol: String,
+    /// spot | futures (생략하면 둘 다)
+    market: Option<String>,
+}
+
+/// 심볼 주문 단위 조회 핸들러 (호가/수량 단위, 최소·최대 수량, 최소 주문 금액)
+/// 거래소 심볼 목록은 1시간 동안 캐시한다
+#[utoipa::path(
+    get,
+    path = "/symbol-info",
+    tag = "market",
+    params(SymbolInfoQuery),
+    responses(
+        (status = 200, description = "시장별 심볼 주문 단위 (거래소 공통 형식)"),
+        (status = 400, description = "지원하지 않는 venue 또는 market"),
+        (status = 404, description = "심볼 없음"),
+        (status = 502, description = "거래소 심볼 목록 조회 실패")
+    )
+)]
+async fn symbol_info_handler(Query(query): Query<SymbolInfoQuery>) -> impl IntoResponse {
+    let bad_request = |error: String| {
+        (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": error })),
+        )
+            .into_response()
+    };
+    let venue = match parse_exchange_id(&query.venue) {
+        Ok(venue) => venue,
+        Err(e) => return bad_request(e.to_string()),
+    };
+    let markets = match query.market.as_deref().map(str::to_lowercase).as_deref() {
+        None => vec![MarketKind::Spot, MarketKind::Futures],
+        Some("spot") => vec![MarketKind::Spot],
+        Some("futures") => vec![MarketKind::Futures],
+        Some(other) => return bad_request(format!("Unknown market: {}", other)),
+    };
+
+    let mut infos = Vec::new();
+    for market in markets {
+        match symbol_info_cache().get(venue, market, &query.symbol).await {
+            Ok(Some(info)) => infos.push(info),
+            Ok(None) => {}
+            Err(e) => {
+                warn!("Failed to load {:?} {} symbol info: {}", venue, market, e);
+                return (
+                    axum::http::StatusCode::BAD_GATEWAY,
+                    Json(serde_json::json!({ "error": e.to_string() })),
+                )
+                    .into_response();
+            }
+        }
+    }
+    if infos.is_empty() {
+        return (
+            axum::http::StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!("Symbol not found: {:?} {}", venue, query.symbol)
+            })),
+        )
+            .into_response();
+    }
+    Json(serde_json::json!(infos)).into_response()
+}
+
+/// 베뉴별 주문 ack 지연 및 가격 피드 지연 통계 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/metrics/latency",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "베뉴별 주문 ack / 가격 피드 지연 통계")
+    )
+)]
+async fn latency_metrics_handler() -> impl IntoResponse {
+    Json(serde_json::json!(latency_tracker().report()))
+}
+
+/// 전략별 이벤트 발생 횟수 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/metrics/events",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "전략별 이벤트 종류별 횟수 및 마지막 이벤트 시각")
+    )
+)]
+async fn event_metrics_handler() -> impl IntoResponse {
+    Json(serde_json::json!(event_metrics().report()))
+}
+
+/// 베뉴별 요청 가중치 사용량 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/metrics/weight",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "베뉴별 분당 사용 가중치, 한도, 현재 감속 대기 시간")
+    )
+)]
+async fn weight_metrics_handler() -> impl IntoResponse {
+    Json(serde_json::json!(weight_tracker().report()))
+}
+
+/// 백그라운드 태스크 상태 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/metrics/tasks",
+    tag = "metrics",
+    responses(
+        (status = 200, description = "태스크별 실행 여부, 마지막 하트비트, 재시작 횟수와 마지막 재시작 사유")
+    )
+)]
+async fn task_metrics_handler() -> impl IntoResponse {
+    Json(serde_json::json!(task_supervisor().report()))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct AlertsQuery {
+    /// 최대 개수 (기본 100)
+    limit: Option<usize>,
+    /// 알림 종류 필터 (예: "new_listing")
+    kind: Option<String>,
+}
+
+/// 최근 알림 조회 핸들러 (최신순)
+#[utoipa::path(
+    get,
+    path = "/alerts",
+    tag = "metrics",
+    params(AlertsQuery),
+    responses(
+        (status = 200, description = "최근 알림 목록 (최신순)")
+    )
+)]
+async fn alerts_handler(Query(query): Query<AlertsQuery>) -> impl IntoResponse {
+    let alerts = notification_center().recent(query.limit.unwrap_or(100), query.kind.as_deref());
+    Json(serde_json::json!(alerts))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct LargeTradesQuery {
+    /// 최대 개수 (기본 100)
+    limit: Option<usize>,
+    /// 심볼 필터 (예: "BTCUSDT")
+    symbol: Option<String>,
+}
+
+/// 최근 대량 체결 조회 핸들러 (최신순)
+#[utoipa::path(
+    get,
+    path = "/large-trades",
+    tag = "metrics",
+    params(LargeTradesQuery),
+    responses(
+        (status = 200, description = "aggTrade 스트림에서 감지한 기준 이상 체결 (최신순)")
+    )
+)]
+async fn large_trades_handler(Query(query): Query<LargeTradesQuery>) -> impl IntoResponse {
+    let trades = large_trades().recent(query.limit.unwrap_or(100), query.symbol.as_deref());
+    Json(serde_json::json!(trades))
+}
+
+/// 전략 실시간 상태 조회 핸들러 (현재 베이시스, 진입/청산 기준, 포지션, 마지막 주문 응답, 루프 상태)
+#[utoipa::path(
+    get,
+    path = "/strategy/{id}/state",
+    tag = "strategy",
+    params(("id" = String, Path, description = "전략 ID (예: intra_basis:BTCUSDT)")),
+    responses(
+        (status = 200, description = "전략 상태"),
+        (status = 404, description = "실행 중이 아닌 전략")
+    )
+)]
+async fn strategy_state_handler(Path(id): Path<String>) -> impl IntoResponse {
+    match strategy_states().get(&id) {
+        Some(report) => Json(serde_json::json!(report)).into_response(),
+        None => (
+            axum::http::StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!("Unknown strategy: {}", id),
+                "strategies": strategy_states().ids(),
+            })),
+        )
+            .into_response(),
+    }
+}
+
+/// 진행 중/미해결 진입 주문 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/strategy/inflight",
+    tag = "strategy",
+    responses(
+        (status = 200, description = "심볼별 진행 중 또는 결과 미확정(unresolved) 진입 주문")
+    )
+)]
+async fn inflight_orders_handler() -> impl IntoResponse {
+    Json(serde_json::json!(inflight_orders().report()))
+}
+
+/// 작동 중인 킬 스위치 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/strategy/kill-switches",
+    tag = "strategy",
+    responses(
+        (status = 200, description = "가격 이상이 지속되어 주문을 멈춘 전략 (작동 사유, 시각)")
+    )
+)]
+async fn kill_switches_handler() -> impl IntoResponse {
+    Json(serde_json::json!(kill_switches().report()))
+}
+
+/// 진입 시점 헤지 거래소 선택 기록 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/strategy/hedge-venues",
+    tag = "strategy",
+    responses(
+        (status = 200, description = "전략별 마지막 헤지 거래소 선택 (후보 점수, 제외 사유, 선택 이유)")
+    )
+)]
+async fn hedge_venues_handler() -> impl IntoResponse {
+    Json(serde_json::json!(hedge_venue_choices().report()))
+}
+
+/// 킬 스위치 재가동 핸들러 (가격 데이터를 직접 확인한 뒤 호출)
+#[utoipa::path(
+    post,
+    path = "/strategy/{id}/kill-switch/rearm",
+    tag = "strategy",
+    params(("id" = String, Path, description = "전략 ID (예: intra_basis:BTCUSDT)")),
+    security(("control_token" = [])),
+    responses(
+        (status = 200, description = "재가동됨 (해제된 작동 기록)"),
+        (status = 401, description = "제어 토큰 불일치"),
+        (status = 403, description = "TRADE_CONTROL_TOKEN 미설정"),
+        (status = 404, description = "킬 스위치가 작동 중이 아닌 전략")
+    )
+)]
+async fn rearm_kill_switch_handler(Path(id): Path<String>) -> impl IntoResponse {
+    match kill_switches().rearm(&id) {
+        Some(trip) => {
+            info!("킬 스위치 재가동: {} (사유: {})", id, trip.reason);
+            Json(serde_json::json!({ "rearmed": trip })).into_response()
+        }
+        None => (
+            axum::http::StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!("Kill switch not tripped: {}", id),
+                "tripped": kill_switches().report(),
+            })),
+        )
+            .into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct ControlQuery {
+    /// 사유 (기본 "operator")
+    reason: Option<String>,
+}
+
+impl ControlQuery {
+    fn reason(&self) -> &str {
+        self.reason.as_deref().unwrap_or("operator")
+    }
+}
+
+/// 운영자 제어 상태 조회 핸들러
+#[utoipa::path(
+    get,
+    path = "/control",
+    tag = "control",
+    responses(
+        (status = 200, description = "일시 정지 여부, 전량 청산 요청 수, 실행 중인 전략 ID")
+    )
+)]
+async fn control_handler() -> impl IntoResponse {
+    Json(serde_json::json!({
+        "control": operator_control().report(),
+        "strategies": strategy_states().ids(),
+    }))
+}
+
+/// 새 진입 일시 정지 핸들러 (보유 포지션 청산은 평소 조건대로 진행)
+#[utoipa::path(
+    post,
+    path = "/control/pause",
+    tag = "control",
+    params(ControlQuery),
+    security(("control_token" = [])),
+    responses(
+        (status = 200, description = "일시 정지 상태 (이미 정지 중이면 기존 상태)"),
+        (status = 401, description = "제어 토큰 불일치"),
+        (status = 403, description = "TRADE_CONTROL_TOKEN 미설정")
+    )
+)]
+async fn pause_handler(Query(query): Query<ControlQuery>) -> impl IntoResponse {
+    let paused = operator_control().pause(query.reason());
+    warn!("진입 일시 정지: {}", paused.reason);
+    Json(serde_json::json!({ "paused": paused }))
+}
+
+/// 진입 재개 핸들러
+#[utoipa::path(
+    post,
+    path = "/control/resume",
+    tag = "control",
+    security(("control_token" = [])),
+    responses(
+        (status = 200, description = "재개됨 (해제된 정지 상태, 정지 중이 아니었으면 null)"),
+        (status = 401, description = "제어 토큰 불일치"),
+        (status = 403, description = "TRADE_CONTROL_TOKEN 미설정")
+    )
+)]
+async fn resume_handler() -> impl IntoResponse {
+    let resumed = operator_control().resume();
+    if let Some(paused) = &resumed {
+        info!("진입 재개 (정지 사유: {})", paused.reason);
+    }
+    Json(serde_json::json!({ "resumed": resumed }))
+}
+
+/// 전량 청산 요청 핸들러 (진입도 일시 정지, 각 전략이 다음 반복에서 보유 포지션 청산)
+#[utoipa::path(
+    post,
+    path = "/control/flatten",
+    tag = "control",
+    params(ControlQuery),
+    security(("control_token" = [])),
+    responses(
+        (status = 200, description = "청산 요청 번호와 일시 정지 상태"),
+        (status = 401, description = "제어 토큰 불일치"),
+        (status = 403, description = "TRADE_CONTROL_TOKEN 미설정")
+    )
+)]
+async fn flatten_handler(Query(query): Query<ControlQuery>) -> impl IntoResponse {
+    let request = operator_control().request_flatten(query.reason());
+    warn!("전량 청산 요청 #{}: {}", request, query.reason());
+    Json(serde_json::json!({
+        "flatten_request": request,
+        "paused": operator_control().paused(),
+    }))
+}
+
+/// `/ws` 미실현 손익 프레임 전송 주기
+const PNL_PUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Deserialize)]
+struct EventsWsQuery {
+    /// 전략 ID 필터 (예: intra_basis:BTCUSDT, 없으면 전체)
+    strategy_id: Option<String>,
+}
+
+/// 열린 포지션의 미실현 손익 프레임 (이벤트 버스 이벤트와 같은 스트림으로 전달)
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename = "pnl_update")]
+struct PnlFrame {
+    strategy_id: String,
+    symbol: String,
+    at: chrono::DateTime<chrono::Utc>,
+    dir: Option<String>,
+    spot_price: f64,
+    futures_mark: f64,
+    basis_bps: f64,
+    open_basis_bps: Option<f64>,
+    unrealized_bps: f64,
+    unrealized_pnl_usdt: f64,
+}
+
+/// 포지션이 열린 전략의 손익 프레임 (필터가 있으면 해당 전략만)
+fn pnl_frames(registry: &StrategyStateRegistry, strategy_id: Option<&str>) -> Vec<PnlFrame> {
+    registry
+        .reports()
+        .into_iter()
+        .filter(|r| strategy_id.is_none_or(|id| r.state.strategy_id == id))
+        .filter_map(|r| {
+            let state = r.state;
+            Some(PnlFrame {
+                unrealized_bps: state.unrealized_bps()?,
+                unrealized_pnl_usdt: state.unrealized_pnl_usdt()?,
+                at: state.last_tick,
+                dir: state.dir,
+                spot_price: state.spot_price,
+                futures_mark: state.futures_mark,
+                basis_bps: state.basis_bps,
+                open_basis_bps: state.last_open_basis_bps,
+                strategy_id: state.strategy_id,
+                symbol: state.symbol,
+            })
+        })
+        .collect()
+}
+
+/// 전략 이벤트 스트림 (진입 신호/주문/체결/청산/롤오버/에러, 1초마다 열린 포지션 손익)
+/// 이벤트는 버스 envelope JSON 그대로, 손익은 `"type": "pnl_update"` 프레임으로 보냅니다.
+async fn events_ws_handler(
+    ws: WebSocketUpgrade,
+    Query(query): Query<EventsWsQuery>,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| events_ws_session(socket, query.strategy_id))
+}
+
+async fn events_ws_session(mut socket: WebSocket, strategy_id: Option<String>) {
+    let mut rx = event_bus().receiver();
+    let mut pnl_interval = tokio::time::interval(PNL_PUSH_INTERVAL);
+
+    loop {
+        tokio::select! {
+            envelope = rx.recv() => match envelope {
+                Ok(envelope) => {
+                    if strategy_id.as_ref().is_some_and(|id| *id != envelope.strategy_id) {
+                        continue;
+                    }
+                    if send_json(&mut socket, envelope.as_ref()).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!("이벤트 ws 구독자가 {}개 이벤트를 놓침", n);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            _ = pnl_interval.tick() => {
+                let frames = pnl_frames(strategy_states(), strategy_id.as_deref());
+                let mut failed = false;
+                for frame in &frames {
+                    if send_json(&mut socket, frame).await.is_err() {
+                        failed = true;
+                        break;
+                    }
+                }
+                if failed {
+                    break;
+                }
+            },
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                _ => {}
+            },
+        }
+    }
+}
+
+async fn send_json<T: Serialize>(socket: &mut WebSocket, frame: &T) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(frame).unwrap_or_default();
+    socket.send(Message::Text(text)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_openapi_lists_routes() {
+        let doc = ApiDoc::openapi();
+        for path in [
+            "/health",
+            "/healthz",
+            "/trade-records",
+            "/position-records",
+            "/shadow-trade-records",
+            "/dust-sweep-records",
+            "/funding-settlement-records",
+            "/position-records/{id}/funding",
+            "/trade-records.csv",
+            "/position-records.csv",
+            "/allocations",
+            "/exposure",
+            "/positions/live",
+            "/fees/bnb",
+            "/account/anomalies",
+            "/credentials/status",
+            "/address-book",
+            "/metrics/latency",
+            "/metrics/events",
+            "/metrics/weight",
+            "/metrics/tasks",
+            "/alerts",
+            "/large-trades",
+            "/strategy/{id}/state",
+            "/strategy/inflight",
+            "/strategy/kill-switches",
+            "/strategy/hedge-venues",
+            "/strategy/{id}/kill-switch/rearm",
+            "/control",
+            "/control/pause",
+            "/control/resume",
+            "/control/flatten",
+        ] {
+            assert!(doc.paths.paths.contains_key(path), "missing {}", path);
+        }
+        assert!(doc.to_json().unwrap().contains("\"limit\""));
+        assert!(
+            doc.components
+                .unwrap()
+                .security_schemes
+                .contains_key("control_token")
+        );
+    }
+
+    #[test]
+    fn test_control_token_check() {
+        let bearer = |token: &str| HeaderValue::from_str(&format!("Bearer {}", token)).unwrap();
+
+        // 토큰을 설정하지 않으면 제어 라우트 비활성화
+        assert_eq!(
+            check_control_token(None, Some(&bearer("secret")))
+                .unwrap_err()
+                .0,
+            StatusCode::FORBIDDEN
+        );
+        assert!(check_control_token(Some("secret"), Some(&bearer("secret"))).is_ok());
+        for header in [
+            None,
+            Some(bearer("secre")),
+            Some(bearer("secret2")),
+            Some(HeaderValue::from_static("secret")),
+        ] {
+            assert_eq!(
+                check_control_token(Some("secret"), header.as_ref())
+                    .unwrap_err()
+                    .0,
+                StatusCode::UNAUTHORIZED
+            );
+        }
+    }
+
+    #[test]
+    fn test_pnl_frames_only_open_positions() {
+        use crate::arbitrage::live::StrategyLiveState;
+        use crate::arbitrage::state::ArbitrageState;
+        use crate::arbitrage::strategy::StrategyMode;
+        use crate::trader::binance::HedgedPair;
+        use interface::Bps;
+
+        let registry = StrategyStateRegistry::default();
+        for (symbol, open) in [("BTCUSDT", true), ("ETHUSDT", false)] {
+            let mut live = StrategyLiveState::new(
+                format!("intra_basis:{}", symbol),
+                symbol.to_string(),
+                StrategyMode::Carry,
+                Bps::new(6.0),
+                Bps::new(-6.0),
+            );
+            live.tick(100.0, 100.04, 4.0);
+            let mut state = ArbitrageState::new(symbol.to_string());
+            let pair = HedgedPair {
+                spot_order_qty: 2.0,
+                ..HedgedPair::default()
+            };
+            state.update_position(open, Some("carry".to_string()), pair, Some(10.0), None);
+            live.sync_position(&state);
+            registry.update(&live);
+        }
+
+        let frames = pnl_frames(&registry, None);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].strategy_id, "intra_basis:BTCUSDT");
+        assert!((frames[0].unrealized_bps - 6.0).abs() < 1e-9);
+        assert!((frames[0].unrealized_pnl_usdt - 0.12).abs() < 1e-9);
+        let json = serde_json::to_value(&frames[0]).unwrap();
+        assert_eq!(json["type"], "pnl_update");
+
+        assert!(pnl_frames(&registry, Some("intra_basis:ETHUSDT")).is_empty());
+    }
+}
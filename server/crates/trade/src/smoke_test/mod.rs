@@ -0,0 +1,385 @@
+//! Binance 테스트넷 스모크 테스트 (`trade smoke-test`)
+//!
+//! 거래소 연동 코드를 바꾼 뒤 실제 주문 경로가 동작하는지 확인하는 실행형 인수 테스트.
+//! 테스트넷에서 정해진 순서로 실행하고, 단계마다 성공 여부를 확인한다.
+//!
+//! 1. exchangeInfo 로드 (현물/선물 LOT_SIZE, 거래 상태)
+//! 2. 현물 소액 지정가 매수 (체결되지 않도록 최우선 매수호가 아래)
+//! 3. 지정가 주문 취소 (조회 결과 CANCELED)
+//! 4. 선물 소액 시장가 매도 (헤지 레그, 조회 결과 FILLED)
+//! 5. 선물 reduce-only 시장가 매수로 청산 (조회 결과 FILLED)
+//!
+//! 인증 정보는 `BINANCE_API_KEY_TESTNET` / `BINANCE_API_SECRET_TESTNET`을 읽는다.
+
+use std::time::{Duration, Instant};
+
+use exchanges::BinanceClient;
+use exchanges::weight::TrackedSend;
+use interface::ExchangeError;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::trader::binance::endpoint::{self, spot_base_url};
+use crate::trader::binance::{
+    BinanceFuturesApi, BinanceOrderClient, BinancePriceFeed, BinanceSpotApi,
+    HttpBinanceOrderClient, LotSizeFilter, OrderResponse, PlaceFuturesOrderOptions,
+    PlaceOrderOptions,
+};
+
+/// 거래 기록에 남길 계정 라벨
+pub const SMOKE_TEST_ACCOUNT: &str = "testnet";
+
+const TESTNET_ACCOUNT: &str = "TESTNET";
+/// 지정가 주문 가격 (최우선 매수호가 대비 비율, 체결 방지)
+const LIMIT_PRICE_RATIO: f64 = 0.9;
+const QUERY_RETRIES: usize = 10;
+const QUERY_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 스모크 테스트 설정
+#[derive(Debug, Clone)]
+pub struct SmokeTestParams {
+    pub symbol: String,
+    /// 현물 지정가 주문 명목가 (USDT, 최소 주문 금액 이상)
+    pub spot_notional: f64,
+    /// 선물 헤지 주문 명목가 (USDT, 최소 주문 금액 이상)
+    pub futures_notional: f64,
+}
+
+/// 단계 하나의 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct SmokeStep {
+    pub name: &'static str,
+    pub ok: bool,
+    pub detail: String,
+    pub elapsed_ms: u128,
+}
+
+/// 스모크 테스트 결과 (실패한 단계 이후는 실행하지 않음)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SmokeReport {
+    pub steps: Vec<SmokeStep>,
+}
+
+impl SmokeReport {
+    pub fn passed(&self) -> bool {
+        !self.steps.is_empty() && self.steps.iter().all(|s| s.ok)
+    }
+
+    fn record(
+        &mut self,
+        name: &'static str,
+        started: Instant,
+        result: Result<String, String>,
+    ) -> bool {
+        let ok = result.is_ok();
+        let detail = result.unwrap_or_else(|e| e);
+        if ok {
+            info!("[smoke] {} 성공: {}", name, detail);
+        } else {
+            warn!("[smoke] {} 실패: {}", name, detail);
+        }
+        self.steps.push(SmokeStep {
+            name,
+            ok,
+            detail,
+            elapsed_ms: started.elapsed().as_millis(),
+        });
+        ok
+    }
+}
+
+/// exchangeInfo에서 PRICE_FILTER tickSize 추출
+pub fn parse_tick_size(exchange_info: &serde_json::Value, symbol: &str) -> Option<f64> {
+    exchange_info
+        .get("symbols")?
+        .as_array()?
+        .iter()
+        .find(|s| s.get("symbol").and_then(|v| v.as_str()) == Some(symbol))?
+        .get("filters")?
+        .as_array()?
+        .iter()
+        .find(|f| f.get("filterType").and_then(|v| v.as_str()) == Some("PRICE_FILTER"))?
+        .get("tickSize")?
+        .as_str()?
+        .parse::<f64>()
+        .ok()
+        .filter(|tick| *tick > 0.0)
+}
+
+/// 가격을 tick 단위로 내림
+pub fn floor_to_tick(price: f64, tick: f64) -> f64 {
+    ((price / tick) + 1e-9).floor() * tick
+}
+
+/// 명목가 이상이 되는 최소 수량 (stepSize 올림, minQty 이상)
+pub fn qty_for_notional(filter: LotSizeFilter, notional: f64, price: f64) -> f64 {
+    if price <= 0.0 {
+        return 0.0;
+    }
+    let raw = notional / price;
+    let qty = if filter.step_size > 0.0 {
+        ((raw / filter.step_size) - 1e-9).ceil() * filter.step_size
+    } else {
+        raw
+    };
+    qty.max(filter.min_qty).min(filter.max_qty)
+}
+
+/// 주문이 기대 상태가 될 때까지 조회
+async fn wait_for_status<F, Fut>(query: F, expected: &str) -> Result<OrderResponse, String>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<OrderResponse, ExchangeError>>,
+{
+    let mut last = None;
+    for _ in 0..QUERY_RETRIES {
+        let order = query().await.map_err(|e| e.to_string())?;
+        if order.status.as_deref() == Some(expected) {
+            return Ok(order);
+        }
+        last = order.status;
+        tokio::time::sleep(QUERY_INTERVAL).await;
+    }
+    Err(format!("expected status {}, last {:?}", expected, last))
+}
+
+fn order_id(order: &OrderResponse) -> Result<String, String> {
+    order
+        .order_id
+        .map(|id| id.to_string())
+        .ok_or_else(|| "order response has no orderId".to_string())
+}
+
+async fn fetch_spot_tick_size(client: &BinanceClient, symbol: &str) -> Result<f64, String> {
+    let url = format!("{}/api/v3/exchangeInfo?symbol={}", spot_base_url(), symbol);
+    let info: serde_json::Value = client
+        .http
+        .get(&url)
+        .send_tracked()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+    parse_tick_size(&info, symbol).ok_or_else(|| format!("no PRICE_FILTER for {}", symbol))
+}
+
+/// 테스트넷 스모크 테스트 실행 (이후 이 프로세스의 Binance 요청은 모두 테스트넷으로 간다)
+pub async fn run_smoke_test(params: &SmokeTestParams) -> SmokeReport {
+    endpoint::use_testnet();
+    let mut report = SmokeReport::default();
+    let symbol = params.symbol.as_str();
+
+    let started = Instant::now();
+    let client = match BinanceClient::with_account_credentials(TESTNET_ACCOUNT) {
+        Ok(client) => client,
+        Err(e) => {
+            report.record("credentials", started, Err(e.to_string()));
+            return report;
+        }
+    };
+    let spot = BinanceSpotApi::new(client.clone());
+    let futures = BinanceFuturesApi::new(client.clone());
+    let feed = BinancePriceFeed::new(client.clone(), client.clone());
+    let orders = HttpBinanceOrderClient::new(client.clone(), client.clone())
+        .with_account_labels(SMOKE_TEST_ACCOUNT, SMOKE_TEST_ACCOUNT);
+
+    // 1. 필터 로드
+    let started = Instant::now();
+    let filters = async {
+        spot.load_exchange_info().await.map_err(|e| e.to_string())?;
+        futures
+            .load_exchange_info()
+            .await
+            .map_err(|e| e.to_string())?;
+        for (market, status) in [
+            ("spot", spot.symbol_status(symbol)),
+            ("futures", futures.symbol_status(symbol)),
+        ] {
+            match status {
+                Some(status) if status.is_trading() => {}
+                other => return Err(format!("{} {} not trading: {:?}", market, symbol, other)),
+            }
+        }
+        let spot_lot = spot
+            .get_lot_size(symbol)
+            .ok_or_else(|| format!("no spot LOT_SIZE for {}", symbol))?;
+        let futures_lot = futures
+            .get_lot_size(symbol)
+            .ok_or_else(|| format!("no futures LOT_SIZE for {}", symbol))?;
+        let tick = fetch_spot_tick_size(&client, symbol).await?;
+        Ok((spot_lot, futures_lot, tick))
+    }
+    .await;
+    let (spot_lot, futures_lot, tick) = match filters {
+        Ok(filters) => {
+            report.record(
+                "load_filters",
+                started,
+                Ok(format!(
+                    "spot step {}, futures step {}, tick {}",
+                    filters.0.step_size, filters.1.step_size, filters.2
+                )),
+            );
+            filters
+        }
+        Err(e) => {
+            report.record("load_filters", started, Err(e));
+            return report;
+        }
+    };
+
+    // 2. 현물 지정가 매수 (체결 방지 가격)
+    let started = Instant::now();
+    let placed = async {
+        let top = feed
+            .get_spot_book_top(symbol)
+            .await
+            .map_err(|e| e.to_string())?;
+        let price = floor_to_tick(top.bid_price * LIMIT_PRICE_RATIO, tick);
+        let qty = qty_for_notional(spot_lot, params.spot_notional, price);
+        let order = orders
+            .place_spot_order(
+                symbol,
+                "BUY",
+                qty,
+                Some(price),
+                PlaceOrderOptions::default(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok::<_, String>((order_id(&order)?, qty, price))
+    }
+    .await;
+    let spot_order_id = match placed {
+        Ok((id, qty, price)) => {
+            report.record(
+                "spot_limit_order",
+                started,
+                Ok(format!("order {} BUY {} @ {}", id, qty, price)),
+            );
+            id
+        }
+        Err(e) => {
+            report.record("spot_limit_order", started, Err(e));
+            return report;
+        }
+    };
+
+    // 3. 취소
+    let started = Instant::now();
+    let cancelled = async {
+        orders
+            .cancel_spot_order(symbol, &spot_order_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        wait_for_status(
+            || orders.query_spot_order(symbol, &spot_order_id),
+            "CANCELED",
+        )
+        .await?;
+        Ok(format!("order {} CANCELED", spot_order_id))
+    }
+    .await;
+    if !report.record("cancel_spot_order", started, cancelled) {
+        return report;
+    }
+
+    // 4. 선물 시장가 매도 (헤지)
+    let started = Instant::now();
+    let hedged = async {
+        let top = feed
+            .get_futures_book_top(symbol)
+            .await
+            .map_err(|e| e.to_string())?;
+        let qty = qty_for_notional(futures_lot, params.futures_notional, top.bid_price);
+        let order = orders
+            .place_futures_order(
+                symbol,
+                "SELL",
+                qty,
+                None,
+                PlaceFuturesOrderOptions::default(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        let id = order_id(&order)?;
+        wait_for_status(|| orders.query_futures_order(symbol, &id), "FILLED").await?;
+        Ok::<_, String>((id, qty))
+    }
+    .await;
+    let hedge_qty = match hedged {
+        Ok((id, qty)) => {
+            report.record(
+                "futures_market_hedge",
+                started,
+                Ok(format!("order {} SELL {} FILLED", id, qty)),
+            );
+            qty
+        }
+        Err(e) => {
+            report.record("futures_market_hedge", started, Err(e));
+            return report;
+        }
+    };
+
+    // 5. 헤지 청산
+    let started = Instant::now();
+    let closed = async {
+        let order = orders
+            .place_futures_order(
+                symbol,
+                "BUY",
+                hedge_qty,
+                None,
+                PlaceFuturesOrderOptions {
+                    reduce_only: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        let id = order_id(&order)?;
+        wait_for_status(|| orders.query_futures_order(symbol, &id), "FILLED").await?;
+        Ok(format!("order {} BUY {} reduce-only FILLED", id, hedge_qty))
+    }
+    .await;
+    report.record("close_futures_hedge", started, closed);
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smoke_order_sizing() {
+        let info = serde_json::json!({
+            "symbols": [{
+                "symbol": "BTCUSDT",
+                "filters": [
+                    {"filterType": "PRICE_FILTER", "tickSize": "0.01000000"},
+                    {"filterType": "LOT_SIZE", "stepSize": "0.00001000"}
+                ]
+            }]
+        });
+        assert_eq!(parse_tick_size(&info, "BTCUSDT"), Some(0.01));
+        assert_eq!(parse_tick_size(&info, "ETHUSDT"), None);
+
+        assert!((floor_to_tick(54_321.987, 0.01) - 54_321.98).abs() < 1e-6);
+        assert!((floor_to_tick(0.1, 0.1) - 0.1).abs() < 1e-12);
+
+        // 명목가 이상이 되도록 올림
+        let lot = LotSizeFilter {
+            min_qty: 0.001,
+            max_qty: 1000.0,
+            step_size: 0.001,
+        };
+        let qty = qty_for_notional(lot, 110.0, 50_000.0);
+        assert!((qty - 0.003).abs() < 1e-12);
+        assert!(qty * 50_000.0 >= 110.0);
+        // 최소 수량 이상
+        assert!((qty_for_notional(lot, 1.0, 50_000.0) - 0.001).abs() < 1e-12);
+    }
+}
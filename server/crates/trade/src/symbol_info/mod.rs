@@ -0,0 +1,372 @@
+//! 거래소 심볼 주문 단위 조회 (프론트엔드 주문 폼용)
+//!
+//! 거래소마다 다른 심볼 규격 API를 공개 엔드포인트로 읽어 호가 단위, 수량 단위, 최소/최대 수량,
+//! 최소 주문 금액을 거래소 공통 형식으로 정리한다. 목록 전체를 (거래소, 시장)별로 캐시하고
+//! `CACHE_TTL`이 지나면 다음 조회 때 다시 읽는다.
+//!
+//! - Binance: `/api/v3/exchangeInfo`, `/fapi/v1/exchangeInfo`
+//! - Bybit: `/v5/market/instruments-info` (spot / linear)
+//! - OKX: `/api/v5/public/instruments` (SPOT / SWAP, 수량은 계약 크기를 곱해 기초 자산 단위)
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use interface::{ExchangeError, ExchangeId};
+use serde::Serialize;
+use serde_json::Value;
+use tracing::info;
+
+use crate::trader::MarketKind;
+
+/// 심볼 목록 재조회 간격
+pub const CACHE_TTL: Duration = Duration::from_secs(3600);
+
+const BINANCE_SPOT_URL: &str = "https://api.binance.com";
+const BINANCE_FUTURES_URL: &str = "https://fapi.binance.com";
+const BYBIT_URL: &str = "https://api.bybit.com";
+const OKX_URL: &str = "https://www.okx.com";
+
+/// 거래소 공통 심볼 주문 단위
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SymbolInfo {
+    pub venue: ExchangeId,
+    pub market: MarketKind,
+    /// 거래소 공통 심볼 (예: "BTCUSDT")
+    pub symbol: String,
+    /// 거래소 원래 상태 문자열 (TRADING, Trading, live 등)
+    pub status: Option<String>,
+    /// 가격 단위
+    pub tick_size: f64,
+    /// 수량 단위 (기초 자산)
+    pub step_size: f64,
+    pub min_qty: f64,
+    pub max_qty: Option<f64>,
+    /// 최소 주문 금액 (견적 자산, 제공하지 않는 거래소는 None)
+    pub min_notional: Option<f64>,
+}
+
+fn num(value: Option<&Value>) -> Option<f64> {
+    match value? {
+        Value::String(s) => s.parse::<f64>().ok(),
+        Value::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
+/// Binance exchangeInfo 응답 파싱 (PRICE_FILTER, LOT_SIZE, NOTIONAL/MIN_NOTIONAL)
+pub fn parse_binance(market: MarketKind, resp: &Value) -> Vec<SymbolInfo> {
+    let mut infos = Vec::new();
+    for item in resp
+        .get("symbols")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let Some(symbol) = item.get("symbol").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let filters: HashMap<&str, &Value> = item
+            .get("filters")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|f| Some((f.get("filterType")?.as_str()?, f)))
+            .collect();
+        let (Some(price), Some(lot)) = (filters.get("PRICE_FILTER"), filters.get("LOT_SIZE"))
+        else {
+            continue;
+        };
+        // 스팟은 NOTIONAL.minNotional (구 MIN_NOTIONAL), 선물은 MIN_NOTIONAL.notional
+        let min_notional = filters
+            .get("NOTIONAL")
+            .or(filters.get("MIN_NOTIONAL"))
+            .and_then(|f| num(f.get("minNotional")).or(num(f.get("notional"))));
+        infos.push(SymbolInfo {
+            venue: ExchangeId::Binance,
+            market,
+            symbol: symbol.to_string(),
+            status: item
+                .get("status")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            tick_size: num(price.get("tickSize")).unwrap_or(0.0),
+            step_size: num(lot.get("stepSize")).unwrap_or(0.0),
+            min_qty: num(lot.get("minQty")).unwrap_or(0.0),
+            max_qty: num(lot.get("maxQty")),
+            min_notional,
+        });
+    }
+    infos
+}
+
+/// Bybit instruments-info `result.list` 파싱
+pub fn parse_bybit(market: MarketKind, list: &[Value]) -> Vec<SymbolInfo> {
+    list.iter()
+        .filter_map(|item| {
+            let lot = item.get("lotSizeFilter");
+            let field = |name: &str| num(lot.and_then(|l| l.get(name)));
+            let (step, min_notional) = match market {
+                MarketKind::Spot => (field("basePrecision"), field("minOrderAmt")),
+                MarketKind::Futures => (field("qtyStep"), field("minNotionalValue")),
+            };
+            Some(SymbolInfo {
+                venue: ExchangeId::Bybit,
+                market,
+                symbol: item.get("symbol")?.as_str()?.to_string(),
+                status: item
+                    .get("status")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                tick_size: num(item.get("priceFilter").and_then(|p| p.get("tickSize")))?,
+                step_size: step?,
+                min_qty: field("minOrderQty").unwrap_or(0.0),
+                max_qty: field("maxOrderQty"),
+                min_notional,
+            })
+        })
+        .collect()
+}
+
+/// OKX instruments `data` 파싱 (USDT 견적/정산만, 계약 수량은 기초 자산 단위로 환산)
+pub fn parse_okx(market: MarketKind, data: &[Value]) -> Vec<SymbolInfo> {
+    data.iter()
+        .filter_map(|item| {
+            let inst_id = item.get("instId")?.as_str()?;
+            let quote = match market {
+                MarketKind::Spot => item.get("quoteCcy"),
+                MarketKind::Futures => item.get("settleCcy"),
+            };
+            if quote.and_then(|v| v.as_str()) != Some("USDT") {
+                return None;
+            }
+            let ct_val = match market {
+                MarketKind::Spot => 1.0,
+                MarketKind::Futures => num(item.get("ctVal")).unwrap_or(1.0),
+            };
+            Some(SymbolInfo {
+                venue: ExchangeId::Okx,
+                market,
+                symbol: inst_id.trim_end_matches("-SWAP").replace('-', ""),
+                status: item
+                    .get("state")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                tick_size: num(item.get("tickSz"))?,
+                step_size: num(item.get("lotSz"))? * ct_val,
+                min_qty: num(item.get("minSz")).unwrap_or(0.0) * ct_val,
+                max_qty: num(item.get("maxLmtSz")).map(|sz| sz * ct_val),
+                min_notional: None,
+            })
+        })
+        .collect()
+}
+
+async fn get_json(http: &reqwest::Client, url: &str) -> Result<Value, ExchangeError> {
+    let response = http
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+    let status = response.status();
+    let text = response.text().await?;
+    if !status.is_success() {
+        return Err(ExchangeError::Other(format!(
+            "{} returned {}: {}",
+            url,
+            status,
+            text.chars().take(200).collect::<String>()
+        )));
+    }
+    serde_json::from_str(&text)
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse {}: {}", url, e)))
+}
+
+async fn fetch_bybit(
+    http: &reqwest::Client,
+    market: MarketKind,
+) -> Result<Vec<SymbolInfo>, ExchangeError> {
+    let category = match market {
+        MarketKind::Spot => "spot",
+        MarketKind::Futures => "linear",
+    };
+    let mut infos = Vec::new();
+    let mut cursor = String::new();
+    loop {
+        let mut url = format!(
+            "{}/v5/market/instruments-info?category={}&limit=1000",
+            BYBIT_URL, category
+        );
+        if !cursor.is_empty() {
+            url.push_str(&format!("&cursor={}", cursor));
+        }
+        let resp = get_json(http, &url).await?;
+        let result = resp.get("result").cloned().unwrap_or(Value::Null);
+        let list = result
+            .get("list")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+        infos.extend(parse_bybit(market, &list));
+        cursor = result
+            .get("nextPageCursor")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if cursor.is_empty() {
+            return Ok(infos);
+        }
+    }
+}
+
+/// 거래소/시장 하나의 심볼 목록 전체 조회
+pub async fn fetch_symbol_infos(
+    http: &reqwest::Client,
+    venue: ExchangeId,
+    market: MarketKind,
+) -> Result<Vec<SymbolInfo>, ExchangeError> {
+    match venue {
+        ExchangeId::Binance => {
+            let url = match market {
+                MarketKind::Spot => format!("{}/api/v3/exchangeInfo", BINANCE_SPOT_URL),
+                MarketKind::Futures => format!("{}/fapi/v1/exchangeInfo", BINANCE_FUTURES_URL),
+            };
+            Ok(parse_binance(market, &get_json(http, &url).await?))
+        }
+        ExchangeId::Bybit => fetch_bybit(http, market).await,
+        ExchangeId::Okx => {
+            let inst_type = match market {
+                MarketKind::Spot => "SPOT",
+                MarketKind::Futures => "SWAP",
+            };
+            let url = format!(
+                "{}/api/v5/public/instruments?instType={}",
+                OKX_URL, inst_type
+            );
+            let resp = get_json(http, &url).await?;
+            let data = resp
+                .get("data")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default();
+            Ok(parse_okx(market, &data))
+        }
+        other => Err(ExchangeError::Other(format!(
+            "{:?} does not provide symbol info",
+            other
+        ))),
+    }
+}
+
+struct CachedVenue {
+    fetched_at: Instant,
+    symbols: HashMap<String, SymbolInfo>,
+}
+
+/// (거래소, 시장)별 심볼 목록 캐시
+pub struct SymbolInfoCache {
+    http: reqwest::Client,
+    venues: RwLock<HashMap<(ExchangeId, MarketKind), CachedVenue>>,
+}
+
+impl Default for SymbolInfoCache {
+    fn default() -> Self {
+        Self {
+            http: exchanges::http::http_client_with_timeout(Duration::from_secs(10)),
+            venues: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl SymbolInfoCache {
+    fn cached(
+        &self,
+        venue: ExchangeId,
+        market: MarketKind,
+        symbol: &str,
+    ) -> Option<Option<SymbolInfo>> {
+        let venues = self.venues.read().unwrap();
+        let cached = venues.get(&(venue, market))?;
+        if cached.fetched_at.elapsed() >= CACHE_TTL {
+            return None;
+        }
+        Some(cached.symbols.get(symbol).cloned())
+    }
+
+    /// 심볼 주문 단위 (캐시가 없거나 오래됐으면 목록 전체를 다시 읽음, 없는 심볼이면 None)
+    pub async fn get(
+        &self,
+        venue: ExchangeId,
+        market: MarketKind,
+        symbol: &str,
+    ) -> Result<Option<SymbolInfo>, ExchangeError> {
+        let symbol = symbol.to_uppercase();
+        if let Some(found) = self.cached(venue, market, &symbol) {
+            return Ok(found);
+        }
+
+        let infos = fetch_symbol_infos(&self.http, venue, market).await?;
+        info!("{:?} {} symbol info loaded: {}", venue, market, infos.len());
+        let symbols: HashMap<String, SymbolInfo> = infos
+            .into_iter()
+            .map(|info| (info.symbol.clone(), info))
+            .collect();
+        let found = symbols.get(&symbol).cloned();
+        self.venues.write().unwrap().insert(
+            (venue, market),
+            CachedVenue {
+                fetched_at: Instant::now(),
+                symbols,
+            },
+        );
+        Ok(found)
+    }
+}
+
+static SYMBOL_INFO_CACHE: OnceLock<SymbolInfoCache> = OnceLock::new();
+
+pub fn symbol_info_cache() -> &'static SymbolInfoCache {
+    SYMBOL_INFO_CACHE.get_or_init(SymbolInfoCache::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_venue_filters() {
+        let binance = parse_binance(
+            MarketKind::Futures,
+            &json!({"symbols": [{
+                "symbol": "BTCUSDT",
+                "status": "TRADING",
+                "filters": [
+                    {"filterType": "PRICE_FILTER", "tickSize": "0.10"},
+                    {"filterType": "LOT_SIZE", "stepSize": "0.001", "minQty": "0.001", "maxQty": "1000"},
+                    {"filterType": "MIN_NOTIONAL", "notional": "100"}
+                ]
+            }, {"symbol": "NOFILTERS", "filters": []}]}),
+        );
+        assert_eq!(binance.len(), 1);
+        assert_eq!(binance[0].tick_size, 0.1);
+        assert_eq!(binance[0].step_size, 0.001);
+        assert_eq!(binance[0].max_qty, Some(1000.0));
+        assert_eq!(binance[0].min_notional, Some(100.0));
+
+        let okx = parse_okx(
+            MarketKind::Futures,
+            &[
+                json!({"instId": "BTC-USDT-SWAP", "settleCcy": "USDT", "state": "live",
+                       "ctVal": "0.01", "tickSz": "0.1", "lotSz": "1", "minSz": "1", "maxLmtSz": "100"}),
+                json!({"instId": "BTC-USD-SWAP", "settleCcy": "BTC", "tickSz": "0.1", "lotSz": "1"}),
+            ],
+        );
+        assert_eq!(okx.len(), 1);
+        assert_eq!(okx[0].symbol, "BTCUSDT");
+        // 계약 1개 = 0.01 BTC
+        assert_eq!(okx[0].step_size, 0.01);
+        assert_eq!(okx[0].max_qty, Some(1.0));
+        assert_eq!(okx[0].min_notional, None);
+    }
+}
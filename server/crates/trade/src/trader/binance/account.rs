@@ -0,0 +1,81 @@
+use exchanges::BinanceClient;
+use interface::ExchangeError;
+
+/// 계정 이름을 지정하지 않았을 때 사용하는 라벨
+pub const DEFAULT_ACCOUNT_LABEL: &str = "main";
+
+/// 스팟 레그에 사용할 계정 이름 환경 변수
+pub const SPOT_ACCOUNT_ENV: &str = "BINANCE_SPOT_ACCOUNT";
+/// 선물 레그에 사용할 계정 이름 환경 변수
+pub const FUTURES_ACCOUNT_ENV: &str = "BINANCE_FUTURES_ACCOUNT";
+
+/// 라벨이 붙은 Binance 계정
+#[derive(Clone)]
+pub struct BinanceAccount {
+    /// 로그와 거래 기록에 남길 계정 라벨
+    pub label: String,
+    pub client: BinanceClient,
+    /// 서브 계정 이메일 (마스터 ↔ 서브 계정 이체에 사용, 기본 계정은 None)
+    pub email: Option<String>,
+}
+
+impl BinanceAccount {
+    /// 기본 인증 정보(BINANCE_API_KEY / BINANCE_API_SECRET)를 사용하는 계정
+    pub fn main() -> Result<Self, ExchangeError> {
+        Ok(Self {
+            label: DEFAULT_ACCOUNT_LABEL.to_string(),
+            client: BinanceClient::with_credentials()?,
+            email: None,
+        })
+    }
+
+    /// 이름이 붙은 계정 (BINANCE_API_KEY_{NAME} / BINANCE_API_SECRET_{NAME}, 이메일은 BINANCE_ACCOUNT_EMAIL_{NAME})
+    pub fn named(name: &str) -> Result<Self, ExchangeError> {
+        Ok(Self {
+            label: name.to_lowercase(),
+            client: BinanceClient::with_account_credentials(name)?,
+            email: std::env::var(format!("BINANCE_ACCOUNT_EMAIL_{}", name.to_uppercase())).ok(),
+        })
+    }
+
+    /// 환경 변수에 계정 이름이 있으면 해당 계정, 없으면 기본 계정
+    fn from_env_var(var: &str) -> Result<Self, ExchangeError> {
+        match std::env::var(var) {
+            Ok(name) if !name.trim().is_empty() => Self::named(name.trim()),
+            _ => Self::main(),
+        }
+    }
+}
+
+/// 스팟/선물 레그별 계정 구성
+///
+/// 스팟과 선물을 서로 다른 서브 계정에서 운용하는 경우
+/// BINANCE_SPOT_ACCOUNT / BINANCE_FUTURES_ACCOUNT 에 계정 이름을 지정한다.
+/// 지정하지 않은 레그는 기본 계정을 사용한다.
+#[derive(Clone)]
+pub struct BinanceAccounts {
+    pub spot: BinanceAccount,
+    pub futures: BinanceAccount,
+}
+
+impl BinanceAccounts {
+    /// 두 레그 모두 같은 계정 사용
+    pub fn single(account: BinanceAccount) -> Self {
+        Self {
+            spot: account.clone(),
+            futures: account,
+        }
+    }
+
+    pub fn from_env() -> Result<Self, ExchangeError> {
+        Ok(Self {
+            spot: BinanceAccount::from_env_var(SPOT_ACCOUNT_ENV)?,
+            futures: BinanceAccount::from_env_var(FUTURES_ACCOUNT_ENV)?,
+        })
+    }
+
+    /// 스팟과 선물이 서로 다른 계정인지
+    pub fn is_split(&self) -> bool {
+        self.spot.label != self.futures.label
+    }
+}
@@ -0,0 +1,204 @@
+//! Binance 분기물(delivery) 선물 계약 목록
+//!
+//! USDⓈ-M(`fapi`)과 COIN-M(`dapi`) 모두 exchangeInfo에 `CURRENT_QUARTER`/`NEXT_QUARTER`
+//! 계약을 함께 내려준다 (예: `BTCUSDT_251226`, `BTCUSD_251226`). 주문/마크 가격 조회는
+//! 무기한 계약과 같은 엔드포인트를 쓰므로, 여기서는 만기일과 계약 선택(롤오버 시점 판단)만 다룬다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use exchanges::BinanceClient;
+use exchanges::weight::TrackedSend;
+use interface::{Bps, ExchangeError};
+
+use crate::trader::ContractKind;
+
+const USDM_EXCHANGE_INFO_URL: &str = "https://fapi.binance.com/fapi/v1/exchangeInfo";
+const COINM_EXCHANGE_INFO_URL: &str = "https://dapi.binance.com/dapi/v1/exchangeInfo";
+
+/// 분기물 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryContractType {
+    CurrentQuarter,
+    NextQuarter,
+}
+
+impl DeliveryContractType {
+    /// exchangeInfo `contractType` 값 (무기한 계약은 None)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "CURRENT_QUARTER" => Some(Self::CurrentQuarter),
+            "NEXT_QUARTER" => Some(Self::NextQuarter),
+            _ => None,
+        }
+    }
+}
+
+/// 거래 가능한 분기물 계약
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryContract {
+    /// 주문 심볼 (예: "BTCUSDT_251226")
+    pub symbol: String,
+    /// 기초 페어 (USDⓈ-M "BTCUSDT", COIN-M "BTCUSD")
+    pub pair: String,
+    pub contract_type: DeliveryContractType,
+    pub kind: ContractKind,
+    pub delivery_date: DateTime<Utc>,
+}
+
+impl DeliveryContract {
+    /// 만기까지 남은 일수
+    pub fn days_to_expiry(&self, now: DateTime<Utc>) -> f64 {
+        (self.delivery_date - now).num_seconds() as f64 / 86_400.0
+    }
+
+    /// 롤오버 시점 도달 여부 (만기 roll_days일 전부터)
+    pub fn roll_due(&self, now: DateTime<Utc>, roll_days: f64) -> bool {
+        self.days_to_expiry(now) <= roll_days
+    }
+}
+
+/// 만기까지 고정되는 베이시스를 연율로 환산 (365일 기준)
+pub fn annualized_basis(basis: Bps, days_to_expiry: f64) -> Bps {
+    if days_to_expiry <= 0.0 {
+        return Bps::ZERO;
+    }
+    basis * (365.0 / days_to_expiry)
+}
+
+/// exchangeInfo 응답에서 거래 중인 분기물 계약 추출
+pub fn parse_delivery_contracts(
+    resp: &serde_json::Value,
+    kind: ContractKind,
+) -> Vec<DeliveryContract> {
+    let mut contracts: Vec<DeliveryContract> = resp
+        .get("symbols")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|info| {
+            let contract_type = DeliveryContractType::parse(info.get("contractType")?.as_str()?)?;
+            // USDⓈ-M은 status, COIN-M은 contractStatus
+            let status = info
+                .get("status")
+                .or_else(|| info.get("contractStatus"))
+                .and_then(|v| v.as_str());
+            if status != Some("TRADING") {
+                return None;
+            }
+            let delivery_date =
+                DateTime::from_timestamp_millis(info.get("deliveryDate")?.as_i64()?)?;
+            Some(DeliveryContract {
+                symbol: info.get("symbol")?.as_str()?.to_string(),
+                pair: info.get("pair")?.as_str()?.to_string(),
+                contract_type,
+                kind,
+                delivery_date,
+            })
+        })
+        .collect();
+    contracts.sort_by_key(|c| c.delivery_date);
+    contracts
+}
+
+/// 페어의 분기물 중 롤오버 구간 밖에서 만기가 가장 가까운 계약
+pub fn select_contract<'a>(
+    contracts: &'a [DeliveryContract],
+    pair: &str,
+    now: DateTime<Utc>,
+    roll_days: f64,
+) -> Option<&'a DeliveryContract> {
+    contracts
+        .iter()
+        .filter(|c| c.pair == pair && !c.roll_due(now, roll_days))
+        .min_by_key(|c| c.delivery_date)
+}
+
+/// 분기물 계약 목록 조회 (전략은 진입/롤오버 시점에 다시 조회)
+#[async_trait]
+pub trait DeliveryContractSource: Send + Sync {
+    async fn delivery_contracts(&self) -> Result<Vec<DeliveryContract>, ExchangeError>;
+}
+
+/// 고정 목록 (테스트, 수동 지정용)
+#[async_trait]
+impl DeliveryContractSource for Vec<DeliveryContract> {
+    async fn delivery_contracts(&self) -> Result<Vec<DeliveryContract>, ExchangeError> {
+        Ok(self.clone())
+    }
+}
+
+/// Binance exchangeInfo에서 분기물 목록 조회
+pub struct BinanceDeliveryContracts {
+    client: BinanceClient,
+    kind: ContractKind,
+}
+
+impl BinanceDeliveryContracts {
+    pub fn new(client: BinanceClient, kind: ContractKind) -> Self {
+        Self { client, kind }
+    }
+}
+
+#[async_trait]
+impl DeliveryContractSource for BinanceDeliveryContracts {
+    async fn delivery_contracts(&self) -> Result<Vec<DeliveryContract>, ExchangeError> {
+        fetch_delivery_contracts(&self.client, self.kind).await
+    }
+}
+
+/// USDⓈ-M(Linear) 또는 COIN-M(Inverse) 분기물 목록 조회
+pub async fn fetch_delivery_contracts(
+    client: &BinanceClient,
+    kind: ContractKind,
+) -> Result<Vec<DeliveryContract>, ExchangeError> {
+    let url = match kind {
+        ContractKind::Linear => USDM_EXCHANGE_INFO_URL,
+        ContractKind::Inverse => COINM_EXCHANGE_INFO_URL,
+    };
+    let resp: serde_json::Value = client
+        .http
+        .get(url)
+        .send_tracked()
+        .await
+        .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
+        .json()
+        .await
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse exchangeInfo: {}", e)))?;
+    Ok(parse_delivery_contracts(&resp, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_parse_and_select_with_roll_window() {
+        let now = Utc::now();
+        let ms = |days: i64| (now + Duration::days(days)).timestamp_millis();
+        let resp = serde_json::json!({
+            "symbols": [
+                {"symbol": "BTCUSD_PERP", "pair": "BTCUSD", "contractType": "PERPETUAL",
+                 "contractStatus": "TRADING", "deliveryDate": 4133404800000i64},
+                {"symbol": "BTCUSD_NEXT", "pair": "BTCUSD", "contractType": "NEXT_QUARTER",
+                 "contractStatus": "TRADING", "deliveryDate": ms(95)},
+                {"symbol": "BTCUSD_CUR", "pair": "BTCUSD", "contractType": "CURRENT_QUARTER",
+                 "contractStatus": "TRADING", "deliveryDate": ms(4)},
+                {"symbol": "ETHUSD_CUR", "pair": "ETHUSD", "contractType": "CURRENT_QUARTER",
+                 "contractStatus": "PENDING_TRADING", "deliveryDate": ms(4)},
+            ]
+        });
+        let contracts = parse_delivery_contracts(&resp, ContractKind::Inverse);
+        let symbols: Vec<&str> = contracts.iter().map(|c| c.symbol.as_str()).collect();
+        assert_eq!(symbols, vec!["BTCUSD_CUR", "BTCUSD_NEXT"]);
+
+        // 만기 4일 전: 롤오버 기준 3일이면 현재 분기물, 7일이면 다음 분기물
+        let pick = |roll_days| select_contract(&contracts, "BTCUSD", now, roll_days);
+        assert_eq!(pick(3.0).unwrap().symbol, "BTCUSD_CUR");
+        assert_eq!(pick(7.0).unwrap().symbol, "BTCUSD_NEXT");
+        assert!(pick(120.0).is_none());
+
+        let annualized = annualized_basis(Bps::new(50.0), 73.0);
+        assert!((annualized.value() - 250.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use exchanges::BinanceClient;
+use interface::ExchangeError;
+
+use super::transfer::signed_post;
+
+/// BNB로 변환할 수 있는 소액 자산 (POST /sapi/v1/asset/dust-btc)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DustCandidate {
+    pub asset: String,
+    pub amount_free: f64,
+    pub to_btc: f64,
+    /// 수수료 차감 후 받을 BNB
+    pub to_bnb: f64,
+}
+
+/// 자산별 더스트 변환 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DustTransferResult {
+    pub from_asset: String,
+    pub amount: f64,
+    /// 받은 BNB (수수료 차감 후)
+    pub transfered_amount: f64,
+    /// 수수료 (BNB)
+    pub service_charge_amount: f64,
+    pub tran_id: i64,
+}
+
+/// 더스트 변환 응답 (POST /sapi/v1/asset/dust)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DustTransferResponse {
+    pub total_service_charge: f64,
+    pub total_transfered: f64,
+    pub results: Vec<DustTransferResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DustBtcRaw {
+    #[serde(default)]
+    details: Vec<DustDetailRaw>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DustDetailRaw {
+    asset: String,
+    amount_free: String,
+    #[serde(rename = "toBTC")]
+    to_btc: String,
+    #[serde(rename = "toBNB")]
+    to_bnb: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DustTransferRaw {
+    total_service_charge: String,
+    total_transfered: String,
+    #[serde(default)]
+    transfer_result: Vec<DustTransferResultRaw>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DustTransferResultRaw {
+    amount: String,
+    from_asset: String,
+    service_charge_amount: String,
+    tran_id: i64,
+    transfered_amount: String,
+}
+
+fn parse(v: &str) -> f64 {
+    v.parse().unwrap_or(0.0)
+}
+
+/// BNB로 변환할 수 있는 소액 자산 목록
+pub async fn dust_candidates(client: &BinanceClient) -> Result<Vec<DustCandidate>, ExchangeError> {
+    let raw: DustBtcRaw = signed_post(client, "/sapi/v1/asset/dust-btc", "").await?;
+    Ok(raw
+        .details
+        .into_iter()
+        .map(|d| DustCandidate {
+            amount_free: parse(&d.amount_free),
+            to_btc: parse(&d.to_btc),
+            to_bnb: parse(&d.to_bnb),
+            asset: d.asset,
+        })
+        .collect())
+}
+
+/// 소액 자산을 BNB로 변환 (거래소 정책상 같은 자산은 6시간에 한 번만 변환된다)
+pub async fn dust_transfer(
+    client: &BinanceClient,
+    assets: &[String],
+) -> Result<DustTransferResponse, ExchangeError> {
+    if assets.is_empty() {
+        return Err(ExchangeError::Other(
+            "No dust assets to convert".to_string(),
+        ));
+    }
+    let params = assets
+        .iter()
+        .map(|asset| format!("asset={}", asset))
+        .collect::<Vec<_>>()
+        .join("&");
+    let raw: DustTransferRaw = signed_post(client, "/sapi/v1/asset/dust", &params).await?;
+    Ok(DustTransferResponse {
+        total_service_charge: parse(&raw.total_service_charge),
+        total_transfered: parse(&raw.total_transfered),
+        results: raw
+            .transfer_result
+            .into_iter()
+            .map(|r| DustTransferResult {
+                amount: parse(&r.amount),
+                transfered_amount: parse(&r.transfered_amount),
+                service_charge_amount: parse(&r.service_charge_amount),
+                tran_id: r.tran_id,
+                from_asset: r.from_asset,
+            })
+            .collect(),
+    })
+}
+
+/// BNB 수수료 차감 설정 (POST /sapi/v1/bnbBurn 응답)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BnbBurnStatus {
+    /// 현물 거래 수수료를 BNB로 차감
+    #[serde(rename = "spotBNBBurn")]
+    pub spot_bnb_burn: bool,
+    /// 마진 이자를 BNB로 차감
+    #[serde(rename = "interestBNBBurn", default)]
+    pub interest_bnb_burn: bool,
+}
+
+/// 현물 거래 수수료 BNB 차감 켜기/끄기
+pub async fn set_spot_bnb_burn(
+    client: &BinanceClient,
+    enabled: bool,
+) -> Result<BnbBurnStatus, ExchangeError> {
+    signed_post(
+        client,
+        "/sapi/v1/bnbBurn",
+        &format!("spotBNBBurn={}", enabled),
+    )
+    .await
+}
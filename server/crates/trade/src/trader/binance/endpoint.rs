@@ -0,0 +1,68 @@
+//! Binance REST/WebSocket 엔드포인트 (메인넷 / 테스트넷)
+//!
+//! `BINANCE_TESTNET=true`이거나 `use_testnet()`을 호출하면 주문·exchangeInfo·시세 요청이
+//! 테스트넷(현물 `testnet.binance.vision`, 선물 `testnet.binancefuture.com`)으로 간다.
+//! 이체/SAPI처럼 테스트넷이 없는 API는 바꾸지 않는다.
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const SPOT_BASE_URL: &str = "https://api.binance.com";
+const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
+const SPOT_WS_URL: &str = "wss://stream.binance.com:9443/ws";
+const FUTURES_WS_URL: &str = "wss://fstream.binance.com/ws";
+
+const TESTNET_SPOT_BASE_URL: &str = "https://testnet.binance.vision";
+const TESTNET_FUTURES_BASE_URL: &str = "https://testnet.binancefuture.com";
+const TESTNET_SPOT_WS_URL: &str = "wss://stream.testnet.binance.vision/ws";
+const TESTNET_FUTURES_WS_URL: &str = "wss://stream.binancefuture.com/ws";
+
+static TESTNET: AtomicBool = AtomicBool::new(false);
+static TESTNET_ENV: OnceLock<bool> = OnceLock::new();
+
+/// 이후 요청을 테스트넷으로 보냄 (프로세스 전체)
+pub fn use_testnet() {
+    TESTNET.store(true, Ordering::Relaxed);
+}
+
+/// 테스트넷 사용 여부 (`use_testnet()` 또는 `BINANCE_TESTNET`)
+pub fn is_testnet() -> bool {
+    TESTNET.load(Ordering::Relaxed)
+        || *TESTNET_ENV.get_or_init(|| {
+            std::env::var("BINANCE_TESTNET")
+                .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+                .unwrap_or(false)
+        })
+}
+
+pub fn spot_base_url() -> &'static str {
+    if is_testnet() {
+        TESTNET_SPOT_BASE_URL
+    } else {
+        SPOT_BASE_URL
+    }
+}
+
+pub fn futures_base_url() -> &'static str {
+    if is_testnet() {
+        TESTNET_FUTURES_BASE_URL
+    } else {
+        FUTURES_BASE_URL
+    }
+}
+
+pub fn spot_ws_url() -> &'static str {
+    if is_testnet() {
+        TESTNET_SPOT_WS_URL
+    } else {
+        SPOT_WS_URL
+    }
+}
+
+pub fn futures_ws_url() -> &'static str {
+    if is_testnet() {
+        TESTNET_FUTURES_WS_URL
+    } else {
+        FUTURES_WS_URL
+    }
+}
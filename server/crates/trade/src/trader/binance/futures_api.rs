@@ -3,16 +3,77 @@ use std::sync::RwLock;
 
 use exchanges::binance::{generate_signature, get_timestamp};
 use exchanges::BinanceClient;
+use exchanges::weight::TrackedSend;
 use interface::ExchangeError;
 
-use super::types::{clamp_quantity_with_filter, LotSizeFilter};
+use crate::trader::ContractKind;
 
-const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
+use super::delivery::{fetch_delivery_contracts, DeliveryContract};
+use super::endpoint::futures_base_url;
+use super::transfer::{self, TransferResponse, Wallet};
+use super::types::{clamp_quantity_with_filter, LotSizeFilter, SymbolStatus};
+
+/// 선물 USDT 잔고 (`/fapi/v2/balance`의 USDT 항목)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FuturesUsdtBalance {
+    /// 지갑 잔고 (미실현 손익 제외)
+    pub balance: f64,
+    /// 교차 마진 포지션 미실현 손익 (격리 마진 포지션은 포함되지 않음)
+    pub cross_unrealized_pnl: f64,
+}
+
+/// 선물 포지션 위험 정보 (`/fapi/v2/positionRisk`)
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct FuturesPositionRisk {
+    pub symbol: String,
+    /// 포지션 수량 (롱 양수, 숏 음수)
+    pub position_amt: f64,
+    /// 평균 진입 가격
+    pub entry_price: f64,
+    pub mark_price: f64,
+    /// 미실현 손익 (USDT)
+    pub unrealized_pnl: f64,
+    /// 강제 청산 가격 (포지션이 없거나 청산 위험이 없으면 0)
+    pub liquidation_price: f64,
+    /// 현재 설정된 레버리지
+    pub leverage: u32,
+}
+
+impl FuturesPositionRisk {
+    /// 마크 가격에서 청산 가격까지 거리 (bps, 청산 가격이 없으면 None)
+    pub fn liquidation_distance_bps(&self) -> Option<f64> {
+        if self.liquidation_price <= 0.0 || self.mark_price <= 0.0 {
+            return None;
+        }
+        Some((self.mark_price - self.liquidation_price).abs() / self.mark_price * 10_000.0)
+    }
+}
+
+/// 선물 펀딩비 정산 내역 (`/fapi/v1/income`, incomeType=FUNDING_FEE)
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct FundingIncome {
+    pub symbol: String,
+    /// 받은 펀딩비 (USDT, 지급했으면 음수)
+    pub income: f64,
+    /// 정산 시각 (ms)
+    pub time: i64,
+}
+
+/// 정산된 펀딩비율 (`/fapi/v1/fundingRate`)
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct FundingRateEntry {
+    /// 정산 시각 (ms)
+    pub funding_time: i64,
+    pub funding_rate: f64,
+    /// 정산 마크 가격 (오래된 기록은 없을 수 있음)
+    pub mark_price: Option<f64>,
+}
 
 /// Binance Futures API: Futures 주문, exchangeInfo, LOT_SIZE 캐시 관리
 pub struct BinanceFuturesApi {
     client: BinanceClient,
     lot_size_cache: RwLock<HashMap<String, LotSizeFilter>>,
+    status_cache: RwLock<HashMap<String, SymbolStatus>>,
 }
 
 impl BinanceFuturesApi {
@@ -20,18 +81,19 @@ impl BinanceFuturesApi {
         Self {
             client,
             lot_size_cache: RwLock::new(HashMap::new()),
+            status_cache: RwLock::new(HashMap::new()),
         }
     }
 
-    /// 선물 exchangeInfo를 로드하여 LOT_SIZE 필터를 캐시에 저장
+    /// 선물 exchangeInfo를 로드하여 LOT_SIZE 필터와 심볼 거래 상태를 캐시에 저장
     pub async fn load_exchange_info(&self) -> Result<(), ExchangeError> {
-        let url = format!("{}/fapi/v1/exchangeInfo", FUTURES_BASE_URL);
+        let url = format!("{}/fapi/v1/exchangeInfo", futures_base_url());
 
         let response = self
             .client
             .http
             .get(&url)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
 
@@ -51,6 +113,8 @@ impl BinanceFuturesApi {
 
         let mut cache = self.lot_size_cache.write().unwrap();
         cache.clear();
+        let mut statuses = self.status_cache.write().unwrap();
+        statuses.clear();
 
         if let Some(symbols) = resp.get("symbols").and_then(|v| v.as_array()) {
             for symbol_info in symbols {
@@ -59,6 +123,10 @@ impl BinanceFuturesApi {
                     None => continue,
                 };
 
+                if let Some(status) = symbol_info.get("status").and_then(|v| v.as_str()) {
+                    statuses.insert(symbol.clone(), SymbolStatus::new(status));
+                }
+
                 if let Some(filters) = symbol_info.get("filters").and_then(|v| v.as_array()) {
                     for filter in filters {
                         let filter_type = filter.get("filterType").and_then(|v| v.as_str());
@@ -105,6 +173,11 @@ impl BinanceFuturesApi {
         self.lot_size_cache.read().unwrap().get(symbol).copied()
     }
 
+    /// 선물 심볼의 거래 상태 (exchangeInfo에 없으면 None)
+    pub fn symbol_status(&self, symbol: &str) -> Option<SymbolStatus> {
+        self.status_cache.read().unwrap().get(symbol).cloned()
+    }
+
     /// 선물 수량을 거래소 규칙에 맞게 조정 (LOT_SIZE)
     pub fn clamp_quantity(&self, symbol: &str, qty: f64) -> f64 {
         if let Some(filter) = self.get_lot_size(symbol) {
@@ -148,7 +221,7 @@ impl BinanceFuturesApi {
 
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            futures_base_url(), endpoint, query_string, signature
         );
 
         let response = self
@@ -156,7 +229,7 @@ impl BinanceFuturesApi {
             .http
             .post(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await;
 
         // 마진 타입이 이미 설정되어 있으면 에러가 날 수 있음 (무시)
@@ -181,7 +254,7 @@ impl BinanceFuturesApi {
 
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            futures_base_url(), endpoint, query_string, signature
         );
 
         let response = self
@@ -189,7 +262,7 @@ impl BinanceFuturesApi {
             .http
             .post(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
 
@@ -206,8 +279,29 @@ impl BinanceFuturesApi {
         Ok(())
     }
 
+    /// USDⓈ-M 선물 지갑 → 현물 지갑 이체
+    pub async fn transfer_to_spot(
+        &self,
+        asset: &str,
+        amount: f64,
+    ) -> Result<TransferResponse, ExchangeError> {
+        transfer::universal_transfer(
+            &self.client,
+            Wallet::UsdtFutures,
+            Wallet::Spot,
+            asset,
+            amount,
+        )
+        .await
+    }
+
     /// 선물 잔고 조회 (USDT 마진)
     pub async fn get_balance(&self) -> Result<f64, ExchangeError> {
+        Ok(self.get_usdt_balance().await?.balance)
+    }
+
+    /// 선물 USDT 지갑 잔고 + 교차 마진 미실현 손익 (`/fapi/v2/balance`)
+    pub async fn get_usdt_balance(&self) -> Result<FuturesUsdtBalance, ExchangeError> {
         let api_key = self
             .client
             .api_key
@@ -226,7 +320,7 @@ impl BinanceFuturesApi {
 
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            futures_base_url(), endpoint, query_string, signature
         );
 
         let response = self
@@ -234,7 +328,7 @@ impl BinanceFuturesApi {
             .http
             .get(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
 
@@ -254,18 +348,268 @@ impl BinanceFuturesApi {
         struct FuturesBalance {
             asset: String,
             balance: String,
+            #[serde(default)]
+            cross_un_pnl: Option<String>,
         }
 
         let balances: Vec<FuturesBalance> = serde_json::from_str(&response_text)
             .map_err(|e| ExchangeError::Other(format!("Failed to parse balance: {}", e)))?;
 
-        let usdt_balance = balances
-            .iter()
-            .find(|b| b.asset == "USDT")
-            .and_then(|b| b.balance.parse::<f64>().ok())
-            .unwrap_or(0.0);
+        let parse = |v: Option<&String>| v.and_then(|v| v.parse::<f64>().ok()).unwrap_or(0.0);
+        let usdt = balances.iter().find(|b| b.asset == "USDT");
+        Ok(FuturesUsdtBalance {
+            balance: parse(usdt.map(|b| &b.balance)),
+            cross_unrealized_pnl: parse(usdt.and_then(|b| b.cross_un_pnl.as_ref())),
+        })
+    }
+
+    /// 심볼의 선물 포지션 위험 정보 (단방향 모드 기준, 응답에 없으면 None)
+    pub async fn get_position_risk(
+        &self,
+        symbol: &str,
+    ) -> Result<Option<FuturesPositionRisk>, ExchangeError> {
+        Ok(self
+            .position_risks(Some(symbol))
+            .await?
+            .into_iter()
+            .find(|p| p.symbol == symbol))
+    }
+
+    /// 수량이 0이 아닌 모든 선물 포지션
+    pub async fn get_open_positions(&self) -> Result<Vec<FuturesPositionRisk>, ExchangeError> {
+        Ok(self
+            .position_risks(None)
+            .await?
+            .into_iter()
+            .filter(|p| p.position_amt != 0.0)
+            .collect())
+    }
+
+    async fn position_risks(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<FuturesPositionRisk>, ExchangeError> {
+        let api_key = self
+            .client
+            .api_key
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+        let api_secret = self
+            .client
+            .api_secret
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+
+        let endpoint = "/fapi/v2/positionRisk";
+        let timestamp = get_timestamp();
+        let mut query_string = format!("timestamp={}&recvWindow=50000", timestamp);
+        if let Some(symbol) = symbol {
+            query_string = format!("symbol={}&{}", symbol, query_string);
+        }
+        let signature = generate_signature(&query_string, api_secret);
+
+        let url = format!(
+            "{}{}?{}&signature={}",
+            futures_base_url(), endpoint, query_string, signature
+        );
+
+        let response = self
+            .client
+            .http
+            .get(&url)
+            .header("X-MBX-APIKEY", api_key.as_str())
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(ExchangeError::Other(format!(
+                "Futures position risk API error: status {}, response: {}",
+                status,
+                response_text.chars().take(200).collect::<String>()
+            )));
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PositionRisk {
+            symbol: String,
+            position_amt: String,
+            entry_price: String,
+            mark_price: String,
+            un_realized_profit: String,
+            liquidation_price: String,
+            leverage: String,
+        }
+
+        let positions: Vec<PositionRisk> = serde_json::from_str(&response_text)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse position risk: {}", e)))?;
+
+        let parse = |v: &str| v.parse::<f64>().unwrap_or(0.0);
+        Ok(positions
+            .into_iter()
+            .map(|p| FuturesPositionRisk {
+                position_amt: parse(&p.position_amt),
+                entry_price: parse(&p.entry_price),
+                mark_price: parse(&p.mark_price),
+                unrealized_pnl: parse(&p.un_realized_profit),
+                liquidation_price: parse(&p.liquidation_price),
+                leverage: p.leverage.parse().unwrap_or(0),
+                symbol: p.symbol,
+            })
+            .collect())
+    }
+
+    /// 현재 펀딩비 조회 (premiumIndex lastFundingRate, 0.0001 == 0.01%)
+    pub async fn get_funding_rate(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        let url = format!(
+            "{}/fapi/v1/premiumIndex?symbol={}",
+            futures_base_url(), symbol
+        );
+
+        #[derive(Debug, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PremiumIndexResponse {
+            last_funding_rate: String,
+        }
+
+        let response: PremiumIndexResponse = self
+            .client
+            .http
+            .get(&url)
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse premium index: {}", e)))?;
+
+        response.last_funding_rate.parse::<f64>().map_err(|e| {
+            ExchangeError::Other(format!("Failed to parse funding rate as f64: {}", e))
+        })
+    }
+
+    /// `start_time`(ms) 이후 펀딩비 정산 내역 (오래된 순, 최대 1000건)
+    pub async fn get_funding_income(
+        &self,
+        start_time: i64,
+    ) -> Result<Vec<FundingIncome>, ExchangeError> {
+        let api_key = self
+            .client
+            .api_key
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+        let api_secret = self
+            .client
+            .api_secret
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+
+        let endpoint = "/fapi/v1/income";
+        let timestamp = get_timestamp();
+        let query_string = format!(
+            "incomeType=FUNDING_FEE&startTime={}&limit=1000&timestamp={}&recvWindow=50000",
+            start_time, timestamp
+        );
+        let signature = generate_signature(&query_string, api_secret);
+
+        let url = format!(
+            "{}{}?{}&signature={}",
+            futures_base_url(), endpoint, query_string, signature
+        );
+
+        let response = self
+            .client
+            .http
+            .get(&url)
+            .header("X-MBX-APIKEY", api_key.as_str())
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(ExchangeError::Other(format!(
+                "Futures income API error: status {}, response: {}",
+                status,
+                response_text.chars().take(200).collect::<String>()
+            )));
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        struct Income {
+            symbol: String,
+            income: String,
+            time: i64,
+        }
+
+        let incomes: Vec<Income> = serde_json::from_str(&response_text)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse income: {}", e)))?;
+
+        Ok(incomes
+            .into_iter()
+            .filter_map(|i| {
+                Some(FundingIncome {
+                    income: i.income.parse().ok()?,
+                    time: i.time,
+                    symbol: i.symbol,
+                })
+            })
+            .collect())
+    }
+
+    /// 심볼의 정산 펀딩비율 기록 (`start_time`~`end_time` ms, 오래된 순)
+    pub async fn get_funding_rate_history(
+        &self,
+        symbol: &str,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<FundingRateEntry>, ExchangeError> {
+        let url = format!(
+            "{}/fapi/v1/fundingRate?symbol={}&startTime={}&endTime={}&limit=1000",
+            futures_base_url(), symbol, start_time, end_time
+        );
+
+        #[derive(Debug, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct FundingRate {
+            funding_time: i64,
+            funding_rate: String,
+            #[serde(default)]
+            mark_price: String,
+        }
+
+        let rates: Vec<FundingRate> = self
+            .client
+            .http
+            .get(&url)
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse funding rate history: {}", e)))?;
+
+        Ok(rates
+            .into_iter()
+            .filter_map(|r| {
+                Some(FundingRateEntry {
+                    funding_time: r.funding_time,
+                    funding_rate: r.funding_rate.parse().ok()?,
+                    mark_price: r.mark_price.parse().ok().filter(|p: &f64| *p > 0.0),
+                })
+            })
+            .collect())
+    }
 
-        Ok(usdt_balance)
+    /// USDⓈ-M 분기물 계약 목록 (예: BTCUSDT_251226, 만기 오름차순)
+    pub async fn load_delivery_contracts(&self) -> Result<Vec<DeliveryContract>, ExchangeError> {
+        fetch_delivery_contracts(&self.client, ContractKind::Linear).await
     }
 
     pub fn client(&self) -> &BinanceClient {
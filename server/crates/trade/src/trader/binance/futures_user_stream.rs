@@ -0,0 +1,400 @@
+//! USDⓈ-M 선물 User Data Stream (listenKey)
+//!
+//! 현물 WebSocket API(`userDataStream.subscribe.signature`)로는 선물 주문/잔고 이벤트가 오지 않으므로
+//! 선물 계정은 `/fapi/v1/listenKey`로 발급받은 listenKey를 fstream에 구독한다.
+//! - 연결(재연결 포함)할 때마다 listenKey를 발급받아 SUBSCRIBE (유효한 키가 있으면 같은 키가 연장됨)
+//! - 연결 중에는 30분마다 PUT으로 연장 (60분 동안 연장하지 않으면 만료)
+//! - `listenKeyExpired`를 받으면 연결을 끊고 새 키로 다시 구독
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use exchanges::BinanceClient;
+use exchanges::weight::TrackedSend;
+use exchanges::ws::{
+    ConnectionState, Heartbeat, PingMessage, ReconnectConfig, ReconnectingClient, WsHandler,
+};
+use interface::{ExchangeError, ExchangeId};
+
+use super::endpoint::{futures_base_url, futures_ws_url};
+
+const LISTEN_KEY_ENDPOINT: &str = "/fapi/v1/listenKey";
+/// listenKey 연장 간격 (유효 시간 60분)
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Binance 선물 User Data Stream
+pub struct BinanceFuturesUserStream {
+    futures_client: BinanceClient,
+    /// 로그와 상태 레지스트리에 표시할 계정 라벨
+    label: String,
+}
+
+impl BinanceFuturesUserStream {
+    pub fn new(futures_client: BinanceClient) -> Self {
+        Self {
+            futures_client,
+            label: super::account::DEFAULT_ACCOUNT_LABEL.to_string(),
+        }
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// listenKey 발급에 필요한 API 키가 있는지
+    pub fn has_credentials(&self) -> bool {
+        self.futures_client.api_key.is_some()
+    }
+
+    /// 선물 User Data Stream 시작 및 이벤트 수신
+    /// 연결이 끊기면 지수 백오프로 재연결하고, 재연결마다 listenKey를 다시 받아 구독한다
+    pub async fn start<F>(&self, event_handler: F) -> Result<(), ExchangeError>
+    where
+        F: FnMut(FuturesUserDataEvent) + Send + 'static,
+    {
+        if !self.has_credentials() {
+            return Err(ExchangeError::Other("API key not set".to_string()));
+        }
+
+        let client = ReconnectingClient::new(
+            &format!("Futures User Data Stream ({})", self.label),
+            futures_ws_url(),
+        )
+        .with_config(ReconnectConfig {
+            heartbeat: Some(Heartbeat {
+                interval: Duration::from_secs(30),
+                timeout: Duration::from_secs(90),
+                message: PingMessage::Frame,
+            }),
+            resubscribe_interval: Some(KEEPALIVE_INTERVAL),
+            ..Default::default()
+        })
+        .with_status(
+            ExchangeId::Binance,
+            &format!("futures_user_stream_ws:{}", self.label),
+        );
+
+        let mut handler = FuturesUserStreamHandler {
+            client: self.futures_client.clone(),
+            event_handler,
+        };
+        client.run(&mut handler).await;
+
+        Ok(())
+    }
+
+    /// listenKey 발급 (이미 유효한 키가 있으면 같은 키를 돌려주고 유효 시간을 연장)
+    async fn create_listen_key(client: &BinanceClient) -> Result<String, ExchangeError> {
+        let text = Self::send_listen_key_request(client, reqwest::Method::POST).await?;
+        let response: ListenKeyResponse = serde_json::from_str(&text)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse listenKey: {}", e)))?;
+        Ok(response.listen_key)
+    }
+
+    /// listenKey 유효 시간 연장
+    async fn keepalive_listen_key(client: &BinanceClient) -> Result<(), ExchangeError> {
+        Self::send_listen_key_request(client, reqwest::Method::PUT).await?;
+        Ok(())
+    }
+
+    /// listenKey 요청 (서명 없이 API 키 헤더만 필요)
+    async fn send_listen_key_request(
+        client: &BinanceClient,
+        method: reqwest::Method,
+    ) -> Result<String, ExchangeError> {
+        let api_key = client
+            .api_key
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+        let url = format!("{}{}", futures_base_url(), LISTEN_KEY_ENDPOINT);
+
+        let response = client
+            .http
+            .request(method.clone(), &url)
+            .header("X-MBX-APIKEY", api_key.as_str())
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(ExchangeError::Other(format!(
+                "listenKey {} error: status {}, response: {}",
+                method,
+                status,
+                text.chars().take(200).collect::<String>()
+            )));
+        }
+        Ok(text)
+    }
+
+    /// 메시지에서 이벤트 파싱 (SUBSCRIBE 응답 등 이벤트가 아니면 None)
+    fn parse_event(text: &str) -> Option<FuturesUserDataEvent> {
+        let value = serde_json::from_str::<serde_json::Value>(text).ok()?;
+        let event_type = value.get("e").and_then(|v| v.as_str())?;
+        match event_type {
+            "ORDER_TRADE_UPDATE" => match serde_json::from_value(value.clone()) {
+                Ok(update) => return Some(FuturesUserDataEvent::OrderTradeUpdate(update)),
+                Err(e) => warn!("Failed to parse ORDER_TRADE_UPDATE: {} ({:?})", e, value),
+            },
+            "ACCOUNT_UPDATE" => match serde_json::from_value(value.clone()) {
+                Ok(update) => return Some(FuturesUserDataEvent::AccountUpdate(update)),
+                Err(e) => warn!("Failed to parse ACCOUNT_UPDATE: {} ({:?})", e, value),
+            },
+            "listenKeyExpired" => return Some(FuturesUserDataEvent::ListenKeyExpired),
+            _ => {}
+        }
+        Some(FuturesUserDataEvent::Unknown(value))
+    }
+}
+
+/// ReconnectingClient 용 선물 User Data Stream 핸들러
+struct FuturesUserStreamHandler<F> {
+    client: BinanceClient,
+    event_handler: F,
+}
+
+#[async_trait]
+impl<F> WsHandler for FuturesUserStreamHandler<F>
+where
+    F: FnMut(FuturesUserDataEvent) + Send + 'static,
+{
+    async fn subscriptions(&mut self) -> eyre::Result<Vec<String>> {
+        let listen_key = BinanceFuturesUserStream::create_listen_key(&self.client).await?;
+        let request = serde_json::json!({
+            "method": "SUBSCRIBE",
+            "params": [listen_key],
+            "id": 1,
+        });
+        Ok(vec![request.to_string()])
+    }
+
+    async fn resubscribe(&mut self) -> eyre::Result<Vec<String>> {
+        BinanceFuturesUserStream::keepalive_listen_key(&self.client).await?;
+        Ok(Vec::new())
+    }
+
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+        match BinanceFuturesUserStream::parse_event(text) {
+            Some(FuturesUserDataEvent::ListenKeyExpired) => {
+                Err(eyre::eyre!("listenKey expired, resubscribing"))
+            }
+            Some(event) => {
+                (self.event_handler)(event);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn on_state_change(&mut self, state: ConnectionState) {
+        if state == ConnectionState::Connected {
+            info!("Futures User Data Stream 이벤트 수신 대기 중...");
+        }
+    }
+}
+
+// ========== 선물 User Data Stream 관련 타입 정의 ==========
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListenKeyResponse {
+    listen_key: String,
+}
+
+/// 선물 User Data Stream 이벤트 타입
+#[derive(Debug, Clone)]
+pub enum FuturesUserDataEvent {
+    OrderTradeUpdate(Box<FuturesOrderTradeUpdate>),
+    AccountUpdate(FuturesAccountUpdate),
+    /// listenKey 만료 (핸들러가 재연결하므로 이벤트 핸들러로는 전달되지 않음)
+    ListenKeyExpired,
+    Unknown(serde_json::Value),
+}
+
+/// 주문 갱신 이벤트 (ORDER_TRADE_UPDATE)
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesOrderTradeUpdate {
+    /// 이벤트 시간
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// 체결 시간
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "o")]
+    pub order: FuturesOrderUpdate,
+}
+
+/// 주문 갱신 내용
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesOrderUpdate {
+    /// 심볼
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 클라이언트 주문 ID (강제 청산은 "autoclose-", ADL은 "adl_autoclose")
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// 주문 방향 (BUY/SELL)
+    #[serde(rename = "S")]
+    pub side: String,
+    /// 주문 타입 (MARKET, LIMIT, LIQUIDATION 등)
+    #[serde(rename = "o")]
+    pub order_type: String,
+    /// 주문 수량
+    #[serde(rename = "q")]
+    pub order_quantity: String,
+    /// 평균 체결 가격
+    #[serde(rename = "ap")]
+    pub average_price: String,
+    /// 실행 타입 (NEW, TRADE, CANCELED, EXPIRED, CALCULATED 등)
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    /// 현재 주문 상태
+    #[serde(rename = "X")]
+    pub order_status: String,
+    /// 주문 ID
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    /// 마지막 체결 수량
+    #[serde(rename = "l")]
+    pub last_filled_quantity: String,
+    /// 누적 체결 수량
+    #[serde(rename = "z")]
+    pub cumulative_filled_quantity: String,
+    /// 마지막 체결 가격
+    #[serde(rename = "L")]
+    pub last_filled_price: String,
+    /// 수수료
+    #[serde(rename = "n", default)]
+    pub commission_amount: Option<String>,
+    /// 수수료 자산
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<String>,
+    /// reduce-only 여부
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+    /// 이 체결의 실현 손익
+    #[serde(rename = "rp", default)]
+    pub realized_profit: Option<String>,
+}
+
+impl FuturesOrderUpdate {
+    /// 거래소가 낸 강제 청산/자동 디레버리징(ADL) 주문인지
+    pub fn is_forced_close(&self) -> bool {
+        self.order_type == "LIQUIDATION"
+            || self.execution_type == "CALCULATED"
+            || self.client_order_id.starts_with("autoclose-")
+            || self.client_order_id == "adl_autoclose"
+    }
+}
+
+/// 잔고/포지션 갱신 이벤트 (ACCOUNT_UPDATE)
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesAccountUpdate {
+    /// 이벤트 시간
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "a")]
+    pub update: FuturesAccountUpdateData,
+}
+
+/// 잔고/포지션 갱신 내용
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesAccountUpdateData {
+    /// 갱신 사유 (ORDER, FUNDING_FEE, DEPOSIT, WITHDRAW 등)
+    #[serde(rename = "m")]
+    pub reason: String,
+    #[serde(rename = "B", default)]
+    pub balances: Vec<FuturesBalanceUpdate>,
+    #[serde(rename = "P", default)]
+    pub positions: Vec<FuturesPositionUpdate>,
+}
+
+/// 자산별 지갑 잔고
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesBalanceUpdate {
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// 지갑 잔고
+    #[serde(rename = "wb")]
+    pub wallet_balance: String,
+    /// 교차 마진 지갑 잔고
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: String,
+    /// PnL/수수료를 제외한 잔고 변화
+    #[serde(rename = "bc", default)]
+    pub balance_change: Option<String>,
+}
+
+/// 심볼별 포지션
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesPositionUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 포지션 수량 (롱 양수, 숏 음수)
+    #[serde(rename = "pa")]
+    pub position_amount: String,
+    /// 진입 가격
+    #[serde(rename = "ep")]
+    pub entry_price: String,
+    /// 미실현 손익
+    #[serde(rename = "up")]
+    pub unrealized_pnl: String,
+    /// 마진 타입 (isolated/cross)
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+    /// 포지션 방향 (BOTH/LONG/SHORT)
+    #[serde(rename = "ps")]
+    pub position_side: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_futures_user_data_events() {
+        let liquidation = r#"{"e":"ORDER_TRADE_UPDATE","E":1700000000100,"T":1700000000099,"o":{
+            "s":"BTCUSDT","c":"autoclose-1700000000","S":"BUY","o":"LIQUIDATION","f":"IOC",
+            "q":"0.010","p":"43000","ap":"43010.5","sp":"0","x":"TRADE","X":"FILLED","i":123,
+            "l":"0.010","z":"0.010","L":"43010.5","N":"USDT","n":"0.21","T":1700000000099,"t":9,
+            "b":"0","a":"0","m":false,"R":true,"wt":"CONTRACT_PRICE","ot":"LIQUIDATION",
+            "ps":"BOTH","cp":false,"rp":"-12.5"}}"#;
+        let Some(FuturesUserDataEvent::OrderTradeUpdate(update)) =
+            BinanceFuturesUserStream::parse_event(liquidation)
+        else {
+            panic!("ORDER_TRADE_UPDATE not parsed");
+        };
+        assert_eq!(update.order.symbol, "BTCUSDT");
+        assert!(update.order.reduce_only);
+        assert!(update.order.is_forced_close());
+
+        let account = r#"{"e":"ACCOUNT_UPDATE","E":1700000000200,"T":1700000000199,"a":{
+            "m":"FUNDING_FEE","B":[{"a":"USDT","wb":"1000.5","cw":"1000.5","bc":"0"}],
+            "P":[{"s":"BTCUSDT","pa":"-0.010","ep":"43000","cr":"0","up":"-0.1",
+            "mt":"cross","iw":"0","ps":"BOTH"}]}}"#;
+        let Some(FuturesUserDataEvent::AccountUpdate(update)) =
+            BinanceFuturesUserStream::parse_event(account)
+        else {
+            panic!("ACCOUNT_UPDATE not parsed");
+        };
+        assert_eq!(update.update.reason, "FUNDING_FEE");
+        assert_eq!(update.update.balances[0].wallet_balance, "1000.5");
+        assert_eq!(update.update.positions[0].position_amount, "-0.010");
+
+        assert!(matches!(
+            BinanceFuturesUserStream::parse_event(r#"{"e":"listenKeyExpired","E":1}"#),
+            Some(FuturesUserDataEvent::ListenKeyExpired)
+        ));
+        // SUBSCRIBE 응답은 이벤트가 아님
+        assert!(BinanceFuturesUserStream::parse_event(r#"{"result":null,"id":1}"#).is_none());
+    }
+}
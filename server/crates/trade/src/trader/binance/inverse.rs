@@ -0,0 +1,533 @@
+//! Binance COIN-M(코인 마진, inverse) 무기한 선물
+//!
+//! inverse 계약은 1계약 = `contract_size` USD(BTCUSD_PERP는 100 USD, 그 외 10 USD)이고
+//! 증거금과 손익이 BTC 같은 기초 자산으로 정산된다. 스팟 롱 + inverse 숏으로 캐리 포지션을
+//! 만들면 USDT를 전혀 들고 있지 않아도 되므로 원화 기반 운용에서 USDT 노출을 피할 수 있다.
+//!
+//! `FuturesExchangeTrader` 트레이트는 기초 자산 수량(BTC) 기준으로 주고받으므로
+//! 이 모듈에서 마크 가격으로 수량 ↔ 계약 수를 변환한다.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use exchanges::BinanceClient;
+use exchanges::binance::{generate_signature, get_timestamp};
+use exchanges::weight::TrackedSend;
+use interface::{ExchangeError, Price, Qty};
+
+use crate::latency::latency_tracker;
+use crate::trader::FuturesExchangeTrader;
+use crate::trader::order_api::ContractKind;
+use crate::trader::quote::split_symbol;
+
+use super::account::BinanceAccounts;
+use super::delivery::{DeliveryContract, fetch_delivery_contracts};
+use super::types::{LotSizeFilter, OrderResponse, clamp_quantity_with_filter};
+
+const COIN_FUTURES_BASE_URL: &str = "https://dapi.binance.com";
+
+/// inverse 계약 규격
+#[derive(Debug, Clone, Copy)]
+pub struct InverseContractSpec {
+    /// 1계약의 USD 가치
+    pub contract_size: f64,
+    /// 계약 수 기준 LOT_SIZE
+    pub lot: LotSizeFilter,
+}
+
+/// 기초 자산 수량 → 계약 수 (반올림 전)
+pub fn contracts_for_base_qty(base_qty: f64, price: f64, contract_size: f64) -> f64 {
+    if price <= 0.0 || contract_size <= 0.0 {
+        return 0.0;
+    }
+    base_qty * price / contract_size
+}
+
+/// 계약 수 → 기초 자산 수량
+pub fn base_qty_for_contracts(contracts: f64, price: f64, contract_size: f64) -> f64 {
+    if price <= 0.0 {
+        return 0.0;
+    }
+    contracts * contract_size / price
+}
+
+/// inverse 포지션 손익 (기초 자산 단위)
+/// 롱: N * CS * (1/진입가 - 1/청산가), 숏은 부호 반대
+pub fn inverse_pnl_coin(
+    contracts: f64,
+    contract_size: f64,
+    entry_price: f64,
+    exit_price: f64,
+    short: bool,
+) -> f64 {
+    if entry_price <= 0.0 || exit_price <= 0.0 {
+        return 0.0;
+    }
+    let long_pnl = contracts * contract_size * (1.0 / entry_price - 1.0 / exit_price);
+    if short { -long_pnl } else { long_pnl }
+}
+
+/// inverse 포지션 필요 증거금 (기초 자산 단위)
+pub fn inverse_margin_coin(contracts: f64, contract_size: f64, price: f64, leverage: u32) -> f64 {
+    if price <= 0.0 {
+        return 0.0;
+    }
+    contracts * contract_size / price / leverage.max(1) as f64
+}
+
+/// Binance COIN-M 선물 API
+pub struct BinanceCoinFuturesApi {
+    client: BinanceClient,
+    label: String,
+    specs: RwLock<HashMap<String, InverseContractSpec>>,
+    /// 수량 변환에 쓰는 최근 마크 가격
+    last_mark: RwLock<HashMap<String, f64>>,
+}
+
+impl BinanceCoinFuturesApi {
+    pub fn new(client: BinanceClient, label: &str) -> Self {
+        Self {
+            client,
+            label: label.to_string(),
+            specs: RwLock::new(HashMap::new()),
+            last_mark: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn credentials(&self) -> Result<(&str, &str), ExchangeError> {
+        let api_key = self
+            .client
+            .api_key
+            .as_deref()
+            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+        let api_secret = self
+            .client
+            .api_secret
+            .as_deref()
+            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+        Ok((api_key, api_secret))
+    }
+
+    /// 서명 요청. 응답 본문 반환
+    async fn signed_request(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: &str,
+    ) -> Result<String, ExchangeError> {
+        let (api_key, api_secret) = self.credentials()?;
+        let query_string = format!("{}&timestamp={}&recvWindow=50000", params, get_timestamp());
+        let signature = generate_signature(&query_string, api_secret);
+        let url = format!(
+            "{}{}?{}&signature={}",
+            COIN_FUTURES_BASE_URL, endpoint, query_string, signature
+        );
+
+        let response = self
+            .client
+            .http
+            .request(method, &url)
+            .header("X-MBX-APIKEY", api_key)
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            return Err(ExchangeError::Other(format!(
+                "{} API error: status {}, response: {}",
+                endpoint,
+                status,
+                response_text.chars().take(200).collect::<String>()
+            )));
+        }
+        Ok(response_text)
+    }
+
+    /// exchangeInfo에서 계약 규격(contractSize, LOT_SIZE) 로드
+    pub async fn load_exchange_info(&self) -> Result<(), ExchangeError> {
+        let url = format!("{}/dapi/v1/exchangeInfo", COIN_FUTURES_BASE_URL);
+        let resp: serde_json::Value = self
+            .client
+            .http
+            .get(&url)
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse exchangeInfo: {}", e)))?;
+
+        let mut specs = HashMap::new();
+        for symbol_info in resp
+            .get("symbols")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let Some(symbol) = symbol_info.get("symbol").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let Some(contract_size) = symbol_info.get("contractSize").and_then(|v| v.as_f64())
+            else {
+                continue;
+            };
+            let lot = symbol_info
+                .get("filters")
+                .and_then(|v| v.as_array())
+                .and_then(|filters| {
+                    filters
+                        .iter()
+                        .find(|f| f.get("filterType").and_then(|v| v.as_str()) == Some("LOT_SIZE"))
+                })
+                .map(|f| {
+                    let num = |key: &str, default: f64| {
+                        f.get(key)
+                            .and_then(|v| v.as_str())
+                            .and_then(|s| s.parse::<f64>().ok())
+                            .unwrap_or(default)
+                    };
+                    LotSizeFilter {
+                        min_qty: num("minQty", 1.0),
+                        max_qty: num("maxQty", f64::MAX),
+                        step_size: num("stepSize", 1.0),
+                    }
+                })
+                .unwrap_or(LotSizeFilter {
+                    min_qty: 1.0,
+                    max_qty: f64::MAX,
+                    step_size: 1.0,
+                });
+            specs.insert(
+                symbol.to_string(),
+                InverseContractSpec { contract_size, lot },
+            );
+        }
+
+        info!(
+            "Loaded {} coin-margined futures contract specs",
+            specs.len()
+        );
+        *self.specs.write().unwrap() = specs;
+        Ok(())
+    }
+
+    /// COIN-M 분기물 계약 목록 (예: BTCUSD_251226, 만기 오름차순)
+    pub async fn load_delivery_contracts(&self) -> Result<Vec<DeliveryContract>, ExchangeError> {
+        fetch_delivery_contracts(&self.client, ContractKind::Inverse).await
+    }
+
+    pub fn get_spec(&self, symbol: &str) -> Option<InverseContractSpec> {
+        self.specs.read().unwrap().get(symbol).copied()
+    }
+
+    /// 마진 타입 및 레버리지 설정
+    pub async fn ensure_setup(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        let margin_type = if isolated { "ISOLATED" } else { "CROSSED" };
+        let params = format!("symbol={}&marginType={}", symbol, margin_type);
+        if let Err(e) = self
+            .signed_request(reqwest::Method::POST, "/dapi/v1/marginType", &params)
+            .await
+        {
+            // -4046: No need to change margin type
+            if !e.to_string().contains("-4046") {
+                warn!("Failed to set coin-margined margin type: {}", e);
+            }
+        }
+
+        let params = format!("symbol={}&leverage={}", symbol, leverage);
+        if let Err(e) = self
+            .signed_request(reqwest::Method::POST, "/dapi/v1/leverage", &params)
+            .await
+        {
+            warn!("Failed to set coin-margined leverage: {}", e);
+        }
+        Ok(())
+    }
+
+    /// 마크 가격 조회 (premiumIndex는 배열로 응답)
+    pub async fn get_mark_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        let index = self.premium_index(symbol).await?;
+        let mark = index
+            .get("markPrice")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<f64>().ok())
+            .ok_or_else(|| ExchangeError::Other(format!("No mark price for {}", symbol)))?;
+        self.last_mark
+            .write()
+            .unwrap()
+            .insert(symbol.to_string(), mark);
+        Ok(mark)
+    }
+
+    /// 현재 펀딩비 (lastFundingRate)
+    pub async fn get_funding_rate(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        let index = self.premium_index(symbol).await?;
+        index
+            .get("lastFundingRate")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<f64>().ok())
+            .ok_or_else(|| ExchangeError::Other(format!("No funding rate for {}", symbol)))
+    }
+
+    async fn premium_index(&self, symbol: &str) -> Result<serde_json::Value, ExchangeError> {
+        let url = format!(
+            "{}/dapi/v1/premiumIndex?symbol={}",
+            COIN_FUTURES_BASE_URL, symbol
+        );
+        let resp: serde_json::Value = self
+            .client
+            .http
+            .get(&url)
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse premium index: {}", e)))?;
+        resp.as_array()
+            .and_then(|items| items.iter().find(|i| i["symbol"] == symbol).cloned())
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unknown coin-margined symbol: {}", symbol))
+            })
+    }
+
+    /// 최근 조회한 마크 가격
+    pub fn last_mark_price(&self, symbol: &str) -> Option<f64> {
+        self.last_mark.read().unwrap().get(symbol).copied()
+    }
+
+    /// 증거금 자산 잔고 (예: "BTC")
+    pub async fn get_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
+        let response = self
+            .signed_request(reqwest::Method::GET, "/dapi/v1/balance", "")
+            .await?;
+        let balances: Vec<serde_json::Value> = serde_json::from_str(&response)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse balance: {}", e)))?;
+        Ok(balances
+            .iter()
+            .find(|b| b["asset"] == asset)
+            .and_then(|b| b["balance"].as_str())
+            .and_then(|s| s.parse::<f64>().ok())
+            .unwrap_or(0.0))
+    }
+
+    /// 계약 수를 LOT_SIZE에 맞게 조정
+    pub fn clamp_contracts(&self, symbol: &str, contracts: f64) -> f64 {
+        match self.get_spec(symbol) {
+            Some(spec) => clamp_quantity_with_filter(spec.lot, contracts),
+            None => contracts.floor(),
+        }
+    }
+
+    /// 계약 수 단위 시장가 주문
+    pub async fn place_order(
+        &self,
+        symbol: &str,
+        side: &str,
+        contracts: f64,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let mut params = format!(
+            "symbol={}&side={}&type=MARKET&quantity={}",
+            symbol, side, contracts
+        );
+        if reduce_only {
+            params.push_str("&reduceOnly=true");
+        }
+        info!(
+            "[{}] place_coin_futures_order params: {}",
+            self.label, params
+        );
+
+        let started = Instant::now();
+        let response = self
+            .signed_request(reqwest::Method::POST, "/dapi/v1/order", &params)
+            .await?;
+        latency_tracker().record_order_ack("binance_coin_futures", started.elapsed());
+        info!(
+            "[{}] place_coin_futures_order response: {}",
+            self.label, response
+        );
+
+        let order: OrderResponse = serde_json::from_str(&response)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse order response: {}", e)))?;
+
+        // 거래 기록의 수량은 계약 수
+        crate::record::save_trade_record_futures_order(
+            "binance_coinm",
+            &self.label,
+            symbol,
+            side,
+            contracts,
+            &params,
+            &order,
+            reduce_only,
+            false,
+        )
+        .await;
+
+        Ok(order)
+    }
+}
+
+/// COIN-M 선물을 헤지 레그로 쓰는 트레이더 (수량은 기초 자산 단위로 주고받음)
+pub struct BinanceInverseTrader {
+    pub api: BinanceCoinFuturesApi,
+}
+
+impl BinanceInverseTrader {
+    /// BINANCE_FUTURES_ACCOUNT 계정(없으면 기본 계정) 사용
+    pub fn new() -> Result<Self, ExchangeError> {
+        let accounts = BinanceAccounts::from_env()?;
+        Ok(Self {
+            api: BinanceCoinFuturesApi::new(accounts.futures.client, &accounts.futures.label),
+        })
+    }
+
+    /// 심볼의 증거금 자산 (예: "BTCUSD_PERP" -> "BTC")
+    pub fn margin_asset(symbol: &str) -> String {
+        let pair = symbol.split('_').next().unwrap_or(symbol);
+        split_symbol(pair)
+            .map(|(base, _)| base)
+            .unwrap_or(pair)
+            .to_string()
+    }
+
+    /// 증거금 잔고 (기초 자산 단위)
+    pub async fn get_margin_balance(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        self.api.get_balance(&Self::margin_asset(symbol)).await
+    }
+
+    /// 기초 자산 수량을 현재 마크 가격 기준 계약 수로 변환 (LOT_SIZE 반영)
+    fn contracts_for(&self, symbol: &str, qty: f64, price: f64) -> Result<f64, ExchangeError> {
+        let spec = self.api.get_spec(symbol).ok_or_else(|| {
+            ExchangeError::Other(format!("Contract spec not loaded for {}", symbol))
+        })?;
+        let contracts = self.api.clamp_contracts(
+            symbol,
+            contracts_for_base_qty(qty, price, spec.contract_size),
+        );
+        if contracts <= 0.0 {
+            return Err(ExchangeError::Other(format!(
+                "Quantity too small for coin-margined contract: {} {} (contract size {} USD)",
+                qty, symbol, spec.contract_size
+            )));
+        }
+        Ok(contracts)
+    }
+
+    async fn place(
+        &self,
+        symbol: &str,
+        side: &str,
+        qty: f64,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let price = self.api.get_mark_price(symbol).await?;
+        let contracts = self.contracts_for(symbol, qty, price)?;
+        self.api
+            .place_order(symbol, side, contracts, reduce_only)
+            .await
+    }
+}
+
+#[async_trait]
+impl FuturesExchangeTrader for BinanceInverseTrader {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        self.api.load_exchange_info().await
+    }
+
+    async fn ensure_account_setup(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        self.api.ensure_setup(symbol, leverage, isolated).await
+    }
+
+    async fn get_mark_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        self.api.get_mark_price(symbol).await.map(Price::new)
+    }
+
+    async fn get_funding_rate(&self, symbol: &str) -> Result<Option<f64>, ExchangeError> {
+        self.api.get_funding_rate(symbol).await.map(Some)
+    }
+
+    fn contract_kind(&self) -> ContractKind {
+        ContractKind::Inverse
+    }
+
+    /// 최근 마크 가격으로 계약 단위에 맞춘 기초 자산 수량 (마크 가격을 모르면 0)
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        let (Some(spec), Some(price)) =
+            (self.api.get_spec(symbol), self.api.last_mark_price(symbol))
+        else {
+            warn!(
+                "Contract spec or mark price missing for coin-margined symbol: {}",
+                symbol
+            );
+            return Qty::ZERO;
+        };
+        let contracts = self.api.clamp_contracts(
+            symbol,
+            contracts_for_base_qty(qty.value(), price, spec.contract_size),
+        );
+        Qty::new(base_qty_for_contracts(contracts, price, spec.contract_size))
+    }
+
+    async fn buy_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.place(symbol, "BUY", qty.value(), reduce_only).await
+    }
+
+    async fn sell_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.place(symbol, "SELL", qty.value(), reduce_only).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse_contract_math() {
+        // BTCUSD_PERP: 1계약 = 100 USD
+        let contracts = contracts_for_base_qty(0.5, 60_000.0, 100.0);
+        assert!((contracts - 300.0).abs() < 1e-9);
+        assert!((base_qty_for_contracts(300.0, 60_000.0, 100.0) - 0.5).abs() < 1e-12);
+
+        // 300계약 숏, 60k → 50k 하락: 숏 이익 = 300*100*(1/50000 - 1/60000) = 0.1 BTC
+        let pnl = inverse_pnl_coin(300.0, 100.0, 60_000.0, 50_000.0, true);
+        assert!((pnl - 0.1).abs() < 1e-9);
+        let long = inverse_pnl_coin(300.0, 100.0, 60_000.0, 50_000.0, false);
+        assert!((long + 0.1).abs() < 1e-9);
+
+        // 3배 레버리지 증거금 = 30000 USD / 60000 / 3 = 0.1666.. BTC
+        let margin = inverse_margin_coin(300.0, 100.0, 60_000.0, 3);
+        assert!((margin - 30_000.0 / 60_000.0 / 3.0).abs() < 1e-12);
+
+        assert_eq!(BinanceInverseTrader::margin_asset("BTCUSD_PERP"), "BTC");
+        assert_eq!(BinanceInverseTrader::margin_asset("ETHUSD_250627"), "ETH");
+    }
+}
@@ -0,0 +1,91 @@
+//! 테스트용 인프로세스 WebSocket 목 서버
+//!
+//! 연결마다 미리 정해둔 메시지 스크립트를 순서대로 보낸다.
+//! 마지막 스크립트가 아닌 연결은 메시지를 보낸 뒤 Close 프레임으로 끊어
+//! 클라이언트의 재연결 동작을 검증할 수 있게 한다.
+
+use std::sync::{Arc, Mutex};
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::accept_hdr_async;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+pub struct MockWsServer {
+    url: String,
+    paths: Arc<Mutex<Vec<String>>>,
+    handle: JoinHandle<()>,
+}
+
+impl MockWsServer {
+    /// 목 서버 시작
+    /// `scripts[i]`는 i번째 연결에 보낼 텍스트 메시지 목록
+    pub async fn start(scripts: Vec<Vec<String>>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind mock ws server");
+        let addr = listener.local_addr().unwrap();
+        let paths = Arc::new(Mutex::new(Vec::new()));
+
+        let accepted_paths = Arc::clone(&paths);
+        let handle = tokio::spawn(async move {
+            let mut index = 0;
+            while let Ok((stream, _)) = listener.accept().await {
+                let paths = Arc::clone(&accepted_paths);
+                // 콜백 시그니처는 tungstenite가 정한 것이라 에러 타입 크기를 줄일 수 없음
+                #[allow(clippy::result_large_err)]
+                let callback = move |req: &Request, resp: Response| {
+                    paths.lock().unwrap().push(req.uri().path().to_string());
+                    Ok(resp)
+                };
+                let Ok(ws) = accept_hdr_async(stream, callback).await else {
+                    continue;
+                };
+
+                let script = scripts.get(index).cloned().unwrap_or_default();
+                let is_last = index + 1 >= scripts.len();
+                index += 1;
+
+                tokio::spawn(async move {
+                    let (mut write, mut read) = ws.split();
+                    for text in script {
+                        if write.send(Message::Text(text)).await.is_err() {
+                            return;
+                        }
+                    }
+
+                    if is_last {
+                        // 마지막 연결은 클라이언트가 끊을 때까지 유지
+                        while let Some(Ok(_)) = read.next().await {}
+                    } else {
+                        let _ = write.send(Message::Close(None)).await;
+                    }
+                });
+            }
+        });
+
+        Self {
+            url: format!("ws://{}", addr),
+            paths,
+            handle,
+        }
+    }
+
+    /// ws://127.0.0.1:{port} 형태의 베이스 URL
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// 지금까지 수락한 연결의 요청 경로 목록
+    pub fn paths(&self) -> Vec<String> {
+        self.paths.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockWsServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
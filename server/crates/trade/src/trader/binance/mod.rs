@@ -3,30 +3,62 @@
 //! 이 모듈은 Binance 거래소와의 상호작용을 담당합니다.
 //! 기능별로 여러 하위 모듈로 분리되어 있습니다:
 //! - `types`: 공통 타입 정의
+//! - `account`: 스팟/선물 레그별 계정(서브 계정) 구성
 //! - `order_client`: 주문 클라이언트 트레이트 및 HTTP 구현
+//! - `order_limit`: 심볼별 주문 명목가 상한 (거절 또는 자식 주문 분할)
 //! - `spot_api`: Spot 거래 관련 API
 //! - `futures_api`: Futures 거래 관련 API
+//! - `endpoint`: 메인넷/테스트넷 REST·WebSocket 엔드포인트
+//! - `delivery`: USDⓈ-M/COIN-M 분기물 계약 목록 및 롤오버 계약 선택
+//! - `inverse`: COIN-M(코인 마진) 선물 API 및 헤지 트레이더
 //! - `price_feed`: 실시간 가격 피드 (WebSocket)
 //! - `user_stream`: User Data Stream (WebSocket)
+//! - `futures_user_stream`: 선물 User Data Stream (listenKey, fstream)
+//! - `transfer`: 지갑 간 / 마스터 ↔ 서브 계정 이체
+//! - `dust`: 소액 자산 BNB 변환, BNB 수수료 차감 설정
 //! - `trader`: BinanceTrader 메인 구조체 및 트레이트 구현
 
+pub mod account;
+pub mod delivery;
+pub mod dust;
+pub mod endpoint;
 pub mod futures_api;
+pub mod futures_user_stream;
+pub mod inverse;
+#[cfg(test)]
+mod mock_ws;
 pub mod order_client;
+pub mod order_limit;
 pub mod price_feed;
 pub mod spot_api;
 pub mod trader;
+pub mod transfer;
 pub mod types;
 pub mod user_stream;
 
 // 공개 API
-pub use futures_api::BinanceFuturesApi;
+pub use account::{BinanceAccount, BinanceAccounts};
+pub use delivery::{
+    BinanceDeliveryContracts, DeliveryContract, DeliveryContractSource, DeliveryContractType,
+};
+pub use dust::{BnbBurnStatus, DustCandidate, DustTransferResponse, DustTransferResult};
+pub use futures_api::{
+    BinanceFuturesApi, FundingIncome, FundingRateEntry, FuturesPositionRisk, FuturesUsdtBalance,
+};
+pub use futures_user_stream::{
+    BinanceFuturesUserStream, FuturesAccountUpdate, FuturesOrderTradeUpdate, FuturesOrderUpdate,
+    FuturesUserDataEvent,
+};
+pub use inverse::{BinanceCoinFuturesApi, BinanceInverseTrader, InverseContractSpec};
 pub use order_client::{BinanceOrderClient, HttpBinanceOrderClient};
-pub use price_feed::BinancePriceFeed;
+pub use order_limit::{NotionalLimitedOrderClient, OrderNotionalLimits, OversizeAction};
+pub use price_feed::{BinancePriceFeed, FeedReadiness, FeedReadySource};
 pub use spot_api::BinanceSpotApi;
 pub use trader::BinanceTrader;
+pub use transfer::{SubAccountTransfer, TransferResponse, Wallet, WithdrawAddress};
 pub use types::{
-    clamp_quantity_with_filter, HedgedPair, LotSizeFilter, OrderResponse,
-    PlaceFuturesOrderOptions, PlaceOrderOptions, PriceState,
+    clamp_quantity_with_filter, BookTop, HedgedPair, LotSizeFilter, OrderResponse,
+    PlaceFuturesOrderOptions, PlaceOrderOptions, PriceState, SymbolStatus,
 };
 pub use user_stream::{
     BalanceInfo, BalanceUpdate, ExecutionReport, OutboundAccountPosition, UserDataEvent,
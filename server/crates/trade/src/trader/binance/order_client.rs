@@ -1,14 +1,17 @@
 use async_trait::async_trait;
+use std::time::Instant;
 use tracing::info;
 
 use exchanges::BinanceClient;
 use exchanges::binance::{generate_signature, get_timestamp};
+use exchanges::weight::TrackedSend;
 use interface::ExchangeError;
 
-use super::types::{OrderResponse, PlaceFuturesOrderOptions, PlaceOrderOptions};
+use crate::latency::latency_tracker;
 
-const SPOT_BASE_URL: &str = "https://api.binance.com";
-const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
+use super::account::DEFAULT_ACCOUNT_LABEL;
+use super::endpoint::{futures_base_url, spot_base_url};
+use super::types::{OrderResponse, PlaceFuturesOrderOptions, PlaceOrderOptions};
 
 /// BinanceTrader가 의존하는 주문 클라이언트 트레이트. 나중에 WebSocket 기반 구현체를 추가할 수 있다.
 #[async_trait]
@@ -35,12 +38,55 @@ pub trait BinanceOrderClient: Send + Sync {
 
     async fn cancel_futures_order(&self, symbol: &str, order_id: &str)
     -> Result<(), ExchangeError>;
+
+    async fn query_spot_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError>;
+
+    async fn query_futures_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError>;
+
+    /// newClientOrderId로 스팟 주문 조회 (거래소에 없는 주문이면 None)
+    async fn find_spot_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError>;
+
+    /// newClientOrderId로 선물 주문 조회 (거래소에 없는 주문이면 None)
+    async fn find_futures_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError>;
+}
+
+/// 주문 없음 에러 코드 (Spot/Futures 공통, "Order does not exist.")
+const ORDER_NOT_FOUND_CODE: &str = "-2013";
+
+/// 시장가 / GTC 지정가 주문 파라미터
+fn order_type_params(qty: f64, price: Option<f64>) -> String {
+    match price {
+        Some(price) => format!(
+            "type=LIMIT&timeInForce=GTC&quantity={:.8}&price={:.8}",
+            qty, price
+        ),
+        None => format!("type=MARKET&quantity={:.8}", qty),
+    }
 }
 
 /// HTTP 기반으로 Binance Spot/Futures 주문을 보내는 구현체
 pub struct HttpBinanceOrderClient {
     spot_client: BinanceClient,
     futures_client: BinanceClient,
+    /// 로그와 거래 기록에 남길 스팟/선물 계정 라벨
+    spot_account: String,
+    futures_account: String,
 }
 
 impl HttpBinanceOrderClient {
@@ -48,8 +94,93 @@ impl HttpBinanceOrderClient {
         Self {
             spot_client,
             futures_client,
+            spot_account: DEFAULT_ACCOUNT_LABEL.to_string(),
+            futures_account: DEFAULT_ACCOUNT_LABEL.to_string(),
         }
     }
+
+    pub fn with_account_labels(mut self, spot_account: &str, futures_account: &str) -> Self {
+        self.spot_account = spot_account.to_string();
+        self.futures_account = futures_account.to_string();
+        self
+    }
+
+    /// client order id로 주문 조회, 주문 없음(-2013)은 None
+    async fn find_order(
+        &self,
+        futures: bool,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError> {
+        let endpoint = if futures {
+            "/fapi/v1/order"
+        } else {
+            "/api/v3/order"
+        };
+        let params = format!("symbol={}&origClientOrderId={}", symbol, client_order_id);
+        match self
+            .signed_request(futures, reqwest::Method::GET, endpoint, &params)
+            .await
+        {
+            Ok(response) => serde_json::from_str(&response).map(Some).map_err(|e| {
+                ExchangeError::Other(format!("Failed to parse order response: {}", e))
+            }),
+            Err(ExchangeError::Other(message)) if message.contains(ORDER_NOT_FOUND_CODE) => {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 주문 취소/조회용 서명 요청. 응답 본문 반환
+    async fn signed_request(
+        &self,
+        futures: bool,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: &str,
+    ) -> Result<String, ExchangeError> {
+        let (client, base_url) = if futures {
+            (&self.futures_client, futures_base_url())
+        } else {
+            (&self.spot_client, spot_base_url())
+        };
+        let api_key = client
+            .api_key
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+        let api_secret = client
+            .api_secret
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+
+        let query_string = format!("{}&timestamp={}&recvWindow=50000", params, get_timestamp());
+        let signature = generate_signature(&query_string, api_secret);
+        let url = format!(
+            "{}{}?{}&signature={}",
+            base_url, endpoint, query_string, signature
+        );
+
+        let response = client
+            .http
+            .request(method, &url)
+            .header("X-MBX-APIKEY", api_key.as_str())
+            .send_tracked()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            return Err(ExchangeError::Other(format!(
+                "{} API error: status {}, response: {}",
+                endpoint,
+                status,
+                response_text.chars().take(200).collect::<String>()
+            )));
+        }
+        Ok(response_text)
+    }
 }
 
 #[async_trait]
@@ -59,7 +190,7 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         symbol: &str,
         side: &str,
         qty: f64,
-        _price: Option<f64>,
+        price: Option<f64>,
         options: PlaceOrderOptions,
     ) -> Result<OrderResponse, ExchangeError> {
         let api_key = self
@@ -80,32 +211,48 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         };
 
         let timestamp = get_timestamp();
-        let qty_str = format!("{:.8}", qty);
-        let query_string = format!(
-            "symbol={}&side={}&type=MARKET&quantity={}&timestamp={}&recvWindow=50000",
-            symbol, side, qty_str, timestamp
+        let mut query_string = format!(
+            "symbol={}&side={}&{}&timestamp={}&recvWindow=50000",
+            symbol,
+            side,
+            order_type_params(qty, price),
+            timestamp
+        );
+        if let Some(client_order_id) = &options.client_order_id {
+            query_string.push_str(&format!("&newClientOrderId={}", client_order_id));
+        }
+        info!(
+            "[{}] place_spot_order query_string: {}",
+            self.spot_account, query_string
         );
-        info!("place_spot_order query_string: {}", query_string);
         let signature = generate_signature(&query_string, api_secret);
 
         let url = format!(
             "{}{}?{}&signature={}",
-            SPOT_BASE_URL, endpoint, query_string, signature
+            spot_base_url(),
+            endpoint,
+            query_string,
+            signature
         );
 
+        let started = Instant::now();
         let response = self
             .spot_client
             .http
             .post(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        latency_tracker().record_order_ack("binance_spot", started.elapsed());
 
         let status = response.status();
         let response_text = response.text().await?;
 
-        info!("place_spot_order response: {}", response_text);
+        info!(
+            "[{}] place_spot_order response: {}",
+            self.spot_account, response_text
+        );
 
         if !status.is_success() {
             return Err(ExchangeError::Other(format!(
@@ -122,6 +269,7 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         if !options.test {
             crate::record::save_trade_record_spot_order(
                 "binance",
+                &self.spot_account,
                 symbol,
                 side,
                 qty,
@@ -130,6 +278,13 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
                 false,
             )
             .await;
+            // 잔고 이상 변동 감시에 예상 변동 등록
+            crate::account_watch::account_watcher().expect_spot_fill(
+                &self.spot_account,
+                symbol,
+                side,
+                &order,
+            );
         }
 
         Ok(order)
@@ -140,7 +295,7 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         symbol: &str,
         side: &str,
         qty: f64,
-        _price: Option<f64>,
+        price: Option<f64>,
         options: PlaceFuturesOrderOptions,
     ) -> Result<OrderResponse, ExchangeError> {
         let api_key = self
@@ -157,38 +312,54 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         let endpoint = "/fapi/v1/order";
 
         let timestamp = get_timestamp();
-        let qty_str = format!("{:.8}", qty);
         let mut query_string = format!(
-            "symbol={}&side={}&type=MARKET&quantity={}&timestamp={}&recvWindow=50000",
-            symbol, side, qty_str, timestamp
+            "symbol={}&side={}&{}&timestamp={}&recvWindow=50000",
+            symbol,
+            side,
+            order_type_params(qty, price),
+            timestamp
         );
 
-        info!("place_futures_order query_string: {}", query_string);
+        info!(
+            "[{}] place_futures_order query_string: {}",
+            self.futures_account, query_string
+        );
 
         if options.reduce_only {
             query_string.push_str("&reduceOnly=true");
         }
+        if let Some(client_order_id) = &options.client_order_id {
+            query_string.push_str(&format!("&newClientOrderId={}", client_order_id));
+        }
 
         let signature = generate_signature(&query_string, api_secret);
 
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            futures_base_url(),
+            endpoint,
+            query_string,
+            signature
         );
 
+        let started = Instant::now();
         let response = self
             .futures_client
             .http
             .post(&url)
             .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        latency_tracker().record_order_ack("binance_futures", started.elapsed());
 
         let status = response.status();
         let response_text = response.text().await?;
 
-        info!("place_futures_order response: {}", response_text);
+        info!(
+            "[{}] place_futures_order response: {}",
+            self.futures_account, response_text
+        );
 
         if !status.is_success() {
             return Err(ExchangeError::Other(format!(
@@ -204,6 +375,7 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         // 거래 기록 저장
         crate::record::save_trade_record_futures_order(
             "binance",
+            &self.futures_account,
             symbol,
             side,
             qty,
@@ -217,17 +389,73 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         Ok(order)
     }
 
-    async fn cancel_spot_order(&self, _symbol: &str, _order_id: &str) -> Result<(), ExchangeError> {
-        // TODO: 구현 필요
-        Err(ExchangeError::Other("Not implemented".to_string()))
+    async fn cancel_spot_order(&self, symbol: &str, order_id: &str) -> Result<(), ExchangeError> {
+        let params = format!("symbol={}&orderId={}", symbol, order_id);
+        let response = self
+            .signed_request(false, reqwest::Method::DELETE, "/api/v3/order", &params)
+            .await?;
+        info!(
+            "[{}] cancel_spot_order response: {}",
+            self.spot_account, response
+        );
+        Ok(())
     }
 
     async fn cancel_futures_order(
         &self,
-        _symbol: &str,
-        _order_id: &str,
+        symbol: &str,
+        order_id: &str,
     ) -> Result<(), ExchangeError> {
-        // TODO: 구현 필요
-        Err(ExchangeError::Other("Not implemented".to_string()))
+        let params = format!("symbol={}&orderId={}", symbol, order_id);
+        let response = self
+            .signed_request(true, reqwest::Method::DELETE, "/fapi/v1/order", &params)
+            .await?;
+        info!(
+            "[{}] cancel_futures_order response: {}",
+            self.futures_account, response
+        );
+        Ok(())
+    }
+
+    async fn query_spot_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let params = format!("symbol={}&orderId={}", symbol, order_id);
+        let response = self
+            .signed_request(false, reqwest::Method::GET, "/api/v3/order", &params)
+            .await?;
+        serde_json::from_str(&response)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse order response: {}", e)))
+    }
+
+    async fn query_futures_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let params = format!("symbol={}&orderId={}", symbol, order_id);
+        let response = self
+            .signed_request(true, reqwest::Method::GET, "/fapi/v1/order", &params)
+            .await?;
+        serde_json::from_str(&response)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse order response: {}", e)))
+    }
+
+    async fn find_spot_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.find_order(false, symbol, client_order_id).await
+    }
+
+    async fn find_futures_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.find_order(true, symbol, client_order_id).await
     }
 }
@@ -0,0 +1,655 @@
+//! 심볼별 주문 명목가 상한 (fat-finger 방지)
+//!
+//! `NotionalLimitedOrderClient`는 다른 `BinanceOrderClient`를 감싸 주문 명목가(수량 × 기준가)가
+//! 상한을 넘으면 거절하거나, 상한 이하의 자식 주문으로 나눠 순서대로 보낸다.
+//! 기준가는 지정가면 주문 가격, 시장가면 현재 시세(스팟 체결가 / 선물 마크 가격)를 쓴다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use interface::ExchangeError;
+
+use crate::trader::order_api::MarketKind;
+
+use super::futures_api::BinanceFuturesApi;
+use super::order_client::BinanceOrderClient;
+use super::price_feed::BinancePriceFeed;
+use super::spot_api::BinanceSpotApi;
+use super::types::{OrderResponse, PlaceFuturesOrderOptions, PlaceOrderOptions};
+
+/// 상한을 넘는 주문 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OversizeAction {
+    /// 주문 거절
+    Reject,
+    /// 상한 이하 자식 주문으로 나눠 순차 실행
+    Split,
+}
+
+impl std::str::FromStr for OversizeAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "reject" => Ok(OversizeAction::Reject),
+            "split" => Ok(OversizeAction::Split),
+            other => Err(format!("Unknown oversize action: {}", other)),
+        }
+    }
+}
+
+/// 주문 명목가 상한 설정
+#[derive(Debug, Clone)]
+pub struct OrderNotionalLimits {
+    /// 심볼별 설정이 없을 때 쓰는 상한 (None이면 제한 없음)
+    pub default_max: Option<f64>,
+    /// 심볼별 상한 (예: "BTCUSDT" → 50,000)
+    pub per_symbol: HashMap<String, f64>,
+    pub action: OversizeAction,
+    /// 자식 주문 최대 개수 (넘으면 분할하지 않고 거절)
+    pub max_children: usize,
+}
+
+impl Default for OrderNotionalLimits {
+    fn default() -> Self {
+        Self {
+            default_max: None,
+            per_symbol: HashMap::new(),
+            action: OversizeAction::Reject,
+            max_children: 20,
+        }
+    }
+}
+
+impl OrderNotionalLimits {
+    /// 환경 변수에서 로드
+    /// - `BINANCE_MAX_ORDER_NOTIONAL`: 기본 상한 (quote 자산 기준)
+    /// - `BINANCE_MAX_ORDER_NOTIONAL_SYMBOLS`: 심볼별 상한 (예: "BTCUSDT:50000,ETHUSDT:20000")
+    /// - `BINANCE_ORDER_OVERSIZE_ACTION`: "reject"(기본) 또는 "split"
+    /// - `BINANCE_ORDER_MAX_CHILDREN`: 자식 주문 최대 개수 (기본 20)
+    pub fn from_env() -> Self {
+        let mut limits = Self {
+            default_max: std::env::var("BINANCE_MAX_ORDER_NOTIONAL")
+                .ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| *v > 0.0),
+            ..Self::default()
+        };
+        if let Ok(raw) = std::env::var("BINANCE_MAX_ORDER_NOTIONAL_SYMBOLS") {
+            limits.per_symbol = parse_symbol_limits(&raw);
+        }
+        if let Ok(raw) = std::env::var("BINANCE_ORDER_OVERSIZE_ACTION") {
+            match raw.parse() {
+                Ok(action) => limits.action = action,
+                Err(e) => warn!("{} (reject로 동작)", e),
+            }
+        }
+        if let Some(max_children) = std::env::var("BINANCE_ORDER_MAX_CHILDREN")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .filter(|v| *v > 0)
+        {
+            limits.max_children = max_children;
+        }
+        limits
+    }
+
+    /// 설정된 상한이 하나라도 있는지
+    pub fn is_enabled(&self) -> bool {
+        self.default_max.is_some() || !self.per_symbol.is_empty()
+    }
+
+    /// 심볼의 명목가 상한
+    pub fn limit_for(&self, symbol: &str) -> Option<f64> {
+        self.per_symbol
+            .get(&symbol.to_uppercase())
+            .copied()
+            .or(self.default_max)
+    }
+}
+
+/// "BTCUSDT:50000,ETHUSDT:20000" 형식 파싱 (잘못된 항목은 경고 후 무시)
+pub fn parse_symbol_limits(raw: &str) -> HashMap<String, f64> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let parsed = entry.split_once(':').and_then(|(symbol, max)| {
+                let max = max.trim().parse::<f64>().ok().filter(|v| *v > 0.0)?;
+                Some((symbol.trim().to_uppercase(), max))
+            });
+            if parsed.is_none() {
+                warn!("주문 명목가 상한 항목 무시: {}", entry);
+            }
+            parsed
+        })
+        .collect()
+}
+
+/// 자식 주문 수량 계획 (합은 LOT_SIZE에 맞춰 clamp한 qty, 각 수량은 max_qty 이하)
+/// 자투리를 마지막 자식에 몰면 단위나 상한을 벗어나 중간에 거절되므로, 모든 자식이 clamp 결과
+/// 그대로이면서 max_qty 이하가 되는 가장 적은 개수로 고르게 나눈다. 그렇게 나눌 수 없으면
+/// (최소 주문 수량 미만으로 쪼개야 하면) 빈 목록.
+pub fn plan_child_orders(qty: f64, max_qty: f64, clamp: impl Fn(f64) -> f64) -> Vec<f64> {
+    let total = clamp(qty);
+    let child_max = clamp(max_qty.min(total));
+    if total <= 0.0 || child_max <= 0.0 {
+        return Vec::new();
+    }
+    let mut count = ((total / child_max) * (1.0 - 1e-9)).ceil().max(1.0) as usize;
+    loop {
+        let each = clamp(total / count as f64);
+        if each <= 0.0 {
+            return Vec::new();
+        }
+        // 단위 내림으로 남은 몫은 마지막 자식이 가져간다 (total과 each가 단위 배수라 last도 단위 배수)
+        let last = total - each * (count - 1) as f64;
+        if last <= child_max * (1.0 + 1e-9) {
+            let mut children = vec![each; count - 1];
+            children.push(last);
+            return children;
+        }
+        count += 1;
+    }
+}
+
+/// 명목가 계산과 자식 주문 수량 맞춤에 쓰는 시세/수량 규칙
+#[async_trait]
+pub trait OrderSizing: Send + Sync {
+    /// 스팟은 최근 체결가, 선물은 마크 가격
+    async fn reference_price(&self, market: MarketKind, symbol: &str)
+    -> Result<f64, ExchangeError>;
+
+    /// 거래소 수량 단위(LOT_SIZE)에 맞춰 내림
+    fn clamp_quantity(&self, market: MarketKind, symbol: &str, qty: f64) -> f64;
+}
+
+/// BinanceTrader의 가격 피드와 LOT_SIZE 캐시를 쓰는 구현
+pub struct BinanceOrderSizing {
+    pub price_feed: Arc<BinancePriceFeed>,
+    pub spot: Arc<BinanceSpotApi>,
+    pub futures: Arc<BinanceFuturesApi>,
+}
+
+#[async_trait]
+impl OrderSizing for BinanceOrderSizing {
+    async fn reference_price(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+    ) -> Result<f64, ExchangeError> {
+        match market {
+            MarketKind::Spot => self.price_feed.get_spot_price(symbol).await,
+            MarketKind::Futures => self.price_feed.get_futures_mark_price(symbol).await,
+        }
+    }
+
+    fn clamp_quantity(&self, market: MarketKind, symbol: &str, qty: f64) -> f64 {
+        match market {
+            MarketKind::Spot => self.spot.clamp_quantity(symbol, qty),
+            MarketKind::Futures => self.futures.clamp_quantity(symbol, qty),
+        }
+    }
+}
+
+/// 명목가 상한을 적용하는 주문 클라이언트
+pub struct NotionalLimitedOrderClient {
+    inner: Arc<dyn BinanceOrderClient>,
+    sizing: Arc<dyn OrderSizing>,
+    limits: OrderNotionalLimits,
+}
+
+impl NotionalLimitedOrderClient {
+    pub fn new(
+        inner: Arc<dyn BinanceOrderClient>,
+        sizing: Arc<dyn OrderSizing>,
+        limits: OrderNotionalLimits,
+    ) -> Self {
+        Self {
+            inner,
+            sizing,
+            limits,
+        }
+    }
+
+    pub fn limits(&self) -> &OrderNotionalLimits {
+        &self.limits
+    }
+
+    /// 상한 확인 후 자식 주문 수량 목록 (상한 이하면 원래 수량 하나)
+    async fn child_quantities(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        qty: f64,
+        price: Option<f64>,
+    ) -> Result<Vec<f64>, ExchangeError> {
+        let Some(max_notional) = self.limits.limit_for(symbol) else {
+            return Ok(vec![qty]);
+        };
+        let reference = match price {
+            Some(price) => price,
+            None => self.sizing.reference_price(market, symbol).await?,
+        };
+        if reference <= 0.0 {
+            return Err(ExchangeError::Other(format!(
+                "No reference price to check order notional: {} {}",
+                market, symbol
+            )));
+        }
+        let notional = qty * reference;
+        if notional <= max_notional {
+            return Ok(vec![qty]);
+        }
+
+        let oversize = || {
+            ExchangeError::Other(format!(
+                "Order notional {:.2} exceeds max {:.2} ({} {} qty {})",
+                notional, max_notional, market, symbol, qty
+            ))
+        };
+        if self.limits.action == OversizeAction::Reject {
+            return Err(oversize());
+        }
+        let children = plan_child_orders(qty, max_notional / reference, |q| {
+            self.sizing.clamp_quantity(market, symbol, q)
+        });
+        if children.is_empty() || children.len() > self.limits.max_children {
+            return Err(oversize());
+        }
+        let planned: f64 = children.iter().sum();
+        if planned < qty * (1.0 - 1e-9) {
+            warn!(
+                "주문 분할: {} {} 수량 {}을 LOT_SIZE 단위 {}로 맞춤",
+                market, symbol, qty, planned
+            );
+        }
+        info!(
+            "주문 분할: {} {} 수량 {} (명목가 {:.2} > 상한 {:.2}) → 자식 주문 {}개",
+            market,
+            symbol,
+            qty,
+            notional,
+            max_notional,
+            children.len()
+        );
+        Ok(children)
+    }
+}
+
+/// 자식 주문 응답 합치기 (체결 수량 합산, 개별 응답은 extra.child_orders)
+/// 중간에 실패하면 그때까지의 체결만 담아 PARTIALLY_FILLED로 반환한다.
+fn merge_child_responses(
+    symbol: &str,
+    responses: Vec<OrderResponse>,
+    error: Option<ExchangeError>,
+) -> OrderResponse {
+    let executed: f64 = responses
+        .iter()
+        .filter_map(|r| r.executed_qty.as_deref()?.parse::<f64>().ok())
+        .sum();
+    let last = responses.last();
+    let status = match &error {
+        Some(_) => Some("PARTIALLY_FILLED".to_string()),
+        None => last.and_then(|r| r.status.clone()),
+    };
+    let mut extra = serde_json::json!({ "child_orders": responses });
+    if let Some(e) = error {
+        extra["split_error"] = serde_json::json!(e.to_string());
+    }
+    OrderResponse {
+        symbol: symbol.to_string(),
+        order_id: last.and_then(|r| r.order_id),
+        client_order_id: last.and_then(|r| r.client_order_id.clone()),
+        executed_qty: Some(executed.to_string()),
+        status,
+        extra,
+    }
+}
+
+/// i번째 자식 주문의 client order id
+/// 첫 자식은 원래 ID를 그대로 써서, 에러로 끝난 분할 주문(첫 자식 실패)도 원래 ID로 조회된다.
+fn child_client_order_id(client_order_id: Option<&str>, i: usize) -> Option<String> {
+    client_order_id.map(|id| match i {
+        0 => id.to_string(),
+        _ => format!("{}-{}", id, i + 1),
+    })
+}
+
+/// 자식 주문을 순서대로 실행 (첫 주문이 실패하면 에러, 이후 실패는 부분 체결 응답)
+/// `place`는 (자식 순번, 수량)을 받는다.
+async fn run_children<F, Fut>(
+    symbol: &str,
+    children: Vec<f64>,
+    mut place: F,
+) -> Result<OrderResponse, ExchangeError>
+where
+    F: FnMut(usize, f64) -> Fut,
+    Fut: std::future::Future<Output = Result<OrderResponse, ExchangeError>>,
+{
+    if children.len() == 1 {
+        return place(0, children[0]).await;
+    }
+    let total = children.len();
+    let mut responses = Vec::with_capacity(total);
+    for (i, qty) in children.into_iter().enumerate() {
+        match place(i, qty).await {
+            Ok(response) => responses.push(response),
+            Err(e) if responses.is_empty() => return Err(e),
+            Err(e) => {
+                warn!(
+                    "{} 자식 주문 {}/{} 실패, 부분 체결로 종료: {}",
+                    symbol,
+                    i + 1,
+                    total,
+                    e
+                );
+                return Ok(merge_child_responses(symbol, responses, Some(e)));
+            }
+        }
+    }
+    Ok(merge_child_responses(symbol, responses, None))
+}
+
+#[async_trait]
+impl BinanceOrderClient for NotionalLimitedOrderClient {
+    async fn place_spot_order(
+        &self,
+        symbol: &str,
+        side: &str,
+        qty: f64,
+        price: Option<f64>,
+        options: PlaceOrderOptions,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let children = self
+            .child_quantities(MarketKind::Spot, symbol, qty, price)
+            .await?;
+        run_children(symbol, children, |i, child_qty| {
+            let options = PlaceOrderOptions {
+                client_order_id: child_client_order_id(options.client_order_id.as_deref(), i),
+                ..options.clone()
+            };
+            self.inner
+                .place_spot_order(symbol, side, child_qty, price, options)
+        })
+        .await
+    }
+
+    async fn place_futures_order(
+        &self,
+        symbol: &str,
+        side: &str,
+        qty: f64,
+        price: Option<f64>,
+        options: PlaceFuturesOrderOptions,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let children = self
+            .child_quantities(MarketKind::Futures, symbol, qty, price)
+            .await?;
+        run_children(symbol, children, |i, child_qty| {
+            let options = PlaceFuturesOrderOptions {
+                client_order_id: child_client_order_id(options.client_order_id.as_deref(), i),
+                ..options.clone()
+            };
+            self.inner
+                .place_futures_order(symbol, side, child_qty, price, options)
+        })
+        .await
+    }
+
+    async fn cancel_spot_order(&self, symbol: &str, order_id: &str) -> Result<(), ExchangeError> {
+        self.inner.cancel_spot_order(symbol, order_id).await
+    }
+
+    async fn cancel_futures_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        self.inner.cancel_futures_order(symbol, order_id).await
+    }
+
+    async fn query_spot_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.inner.query_spot_order(symbol, order_id).await
+    }
+
+    async fn query_futures_order(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.inner.query_futures_order(symbol, order_id).await
+    }
+
+    async fn find_spot_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.inner.find_spot_order(symbol, client_order_id).await
+    }
+
+    async fn find_futures_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.inner.find_futures_order(symbol, client_order_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// 0.001 단위로 내림, 고정 기준가
+    struct FixedSizing(f64);
+
+    #[async_trait]
+    impl OrderSizing for FixedSizing {
+        async fn reference_price(&self, _: MarketKind, _: &str) -> Result<f64, ExchangeError> {
+            Ok(self.0)
+        }
+
+        fn clamp_quantity(&self, _: MarketKind, _: &str, qty: f64) -> f64 {
+            ((qty + 1e-9) / 0.001).floor() * 0.001
+        }
+    }
+
+    /// 받은 선물 주문 수량을 기록, fail_at번째 주문부터 실패
+    #[derive(Default)]
+    struct RecordingClient {
+        futures_qtys: Mutex<Vec<f64>>,
+        fail_at: Option<usize>,
+    }
+
+    #[async_trait]
+    impl BinanceOrderClient for RecordingClient {
+        async fn place_spot_order(
+            &self,
+            symbol: &str,
+            _: &str,
+            qty: f64,
+            _: Option<f64>,
+            _: PlaceOrderOptions,
+        ) -> Result<OrderResponse, ExchangeError> {
+            Ok(filled(symbol, qty))
+        }
+
+        async fn place_futures_order(
+            &self,
+            symbol: &str,
+            _: &str,
+            qty: f64,
+            _: Option<f64>,
+            _: PlaceFuturesOrderOptions,
+        ) -> Result<OrderResponse, ExchangeError> {
+            let mut qtys = self.futures_qtys.lock().unwrap();
+            if self.fail_at.is_some_and(|n| qtys.len() + 1 >= n) {
+                return Err(ExchangeError::Other("rejected".to_string()));
+            }
+            qtys.push(qty);
+            Ok(filled(symbol, qty))
+        }
+
+        async fn cancel_spot_order(&self, _: &str, _: &str) -> Result<(), ExchangeError> {
+            Ok(())
+        }
+
+        async fn cancel_futures_order(&self, _: &str, _: &str) -> Result<(), ExchangeError> {
+            Ok(())
+        }
+
+        async fn query_spot_order(
+            &self,
+            symbol: &str,
+            _: &str,
+        ) -> Result<OrderResponse, ExchangeError> {
+            Ok(filled(symbol, 0.0))
+        }
+
+        async fn query_futures_order(
+            &self,
+            symbol: &str,
+            _: &str,
+        ) -> Result<OrderResponse, ExchangeError> {
+            Ok(filled(symbol, 0.0))
+        }
+
+        async fn find_spot_order(
+            &self,
+            _: &str,
+            _: &str,
+        ) -> Result<Option<OrderResponse>, ExchangeError> {
+            Ok(None)
+        }
+
+        async fn find_futures_order(
+            &self,
+            _: &str,
+            _: &str,
+        ) -> Result<Option<OrderResponse>, ExchangeError> {
+            Ok(None)
+        }
+    }
+
+    fn filled(symbol: &str, qty: f64) -> OrderResponse {
+        OrderResponse {
+            symbol: symbol.to_string(),
+            order_id: Some(1),
+            client_order_id: None,
+            executed_qty: Some(qty.to_string()),
+            status: Some("FILLED".to_string()),
+            extra: serde_json::Value::Null,
+        }
+    }
+
+    fn client(inner: Arc<RecordingClient>, action: OversizeAction) -> NotionalLimitedOrderClient {
+        let limits = OrderNotionalLimits {
+            default_max: Some(1_000.0),
+            per_symbol: parse_symbol_limits("btcusdt:25000, bad, ETHUSDT:-1"),
+            action,
+            max_children: 20,
+        };
+        NotionalLimitedOrderClient::new(inner, Arc::new(FixedSizing(100_000.0)), limits)
+    }
+
+    #[test]
+    fn test_plan_child_orders_stays_on_lot_size() {
+        // 단위 0.001, 최소 수량 0.1
+        let clamp = |q: f64| {
+            let q = ((q + 1e-9) / 0.001).floor() * 0.001;
+            if q < 0.1 { 0.0 } else { q }
+        };
+        let on_step = |q: f64| (q / 0.001 - (q / 0.001).round()).abs() < 1e-6;
+
+        let children = plan_child_orders(0.75, 0.25, clamp);
+        assert_eq!(children.len(), 3);
+        // 단위를 벗어난 0.0005는 분할 전에 버린다
+        let children = plan_child_orders(0.5005, 0.25, clamp);
+        assert_eq!(children.len(), 2);
+        assert!((children.iter().sum::<f64>() - 0.5).abs() < 1e-9);
+
+        // 0.25씩 나누면 최소 수량 미만인 0.05가 남는다 → 마지막 자식에 합치면 상한 초과이므로 3개로 고르게
+        let children = plan_child_orders(0.55, 0.25, clamp);
+        assert_eq!(children.len(), 3);
+        assert!((children.iter().sum::<f64>() - 0.55).abs() < 1e-9);
+        for child in &children {
+            assert!(*child >= 0.1 && *child <= 0.25 + 1e-9, "child {}", child);
+            assert!(on_step(*child), "child {}", child);
+        }
+
+        // 최소 수량보다 작은 단위로 쪼개야 하면 분할 불가
+        assert!(plan_child_orders(1.0, 0.05, clamp).is_empty());
+        assert!(plan_child_orders(0.05, 0.25, clamp).is_empty());
+    }
+
+    #[test]
+    fn test_child_client_order_ids() {
+        assert_eq!(child_client_order_id(None, 1), None);
+        assert_eq!(
+            child_client_order_id(Some("tp1-f1"), 0).as_deref(),
+            Some("tp1-f1")
+        );
+        assert_eq!(
+            child_client_order_id(Some("tp1-f1"), 2).as_deref(),
+            Some("tp1-f1-3")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_split_reject_and_partial_fill() {
+        // BTCUSDT 상한 25,000 / 기준가 100,000 → 0.6 BTC는 0.2씩 세 번 (자투리 없이 고르게)
+        let inner = Arc::new(RecordingClient::default());
+        let split = client(inner.clone(), OversizeAction::Split);
+        let response = split
+            .place_futures_order("BTCUSDT", "SELL", 0.6, None, Default::default())
+            .await
+            .unwrap();
+        let qtys = inner.futures_qtys.lock().unwrap().clone();
+        assert_eq!(qtys.len(), 3);
+        assert!(qtys.iter().all(|q| (q - 0.2).abs() < 1e-9));
+        let executed: f64 = response.executed_qty.unwrap().parse().unwrap();
+        assert!((executed - 0.6).abs() < 1e-9);
+        assert_eq!(response.extra["child_orders"].as_array().unwrap().len(), 3);
+
+        // 상한 이하면 그대로, 잘못된 심볼 설정은 기본 상한(1,000) 적용
+        assert!(
+            split
+                .place_futures_order("BTCUSDT", "BUY", 0.2, None, Default::default())
+                .await
+                .is_ok()
+        );
+        assert_eq!(split.limits().limit_for("ETHUSDT"), Some(1_000.0));
+
+        let reject = client(Arc::new(RecordingClient::default()), OversizeAction::Reject);
+        assert!(
+            reject
+                .place_spot_order("BTCUSDT", "BUY", 0.6, None, Default::default())
+                .await
+                .is_err()
+        );
+
+        // 두 번째 자식 주문부터 실패 → 첫 체결만 담은 부분 체결 응답
+        let failing = Arc::new(RecordingClient {
+            fail_at: Some(2),
+            ..Default::default()
+        });
+        let response = client(failing, OversizeAction::Split)
+            .place_futures_order("BTCUSDT", "SELL", 0.6, None, Default::default())
+            .await
+            .unwrap();
+        assert_eq!(response.status.as_deref(), Some("PARTIALLY_FILLED"));
+        let executed: f64 = response.executed_qty.unwrap().parse().unwrap();
+        assert!((executed - 0.2).abs() < 1e-9);
+        assert!(response.extra["split_error"].is_string());
+    }
+}
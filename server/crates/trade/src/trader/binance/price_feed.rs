@@ -1,25 +1,53 @@
+use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock as TokioRwLock;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
-use futures_util::StreamExt;
 use tracing::{info, warn};
 
-use exchanges::BinanceClient;
-use interface::ExchangeError;
-
-use super::types::PriceState;
+use exchanges::supervisor::{WS_STALE_AFTER, task_supervisor};
+use exchanges::weight::TrackedSend;
+use exchanges::ws::{Heartbeat, PingMessage, ReconnectConfig, ReconnectingClient, WsHandler};
+use exchanges::{BinanceClient, OrderBookExchange};
+use interface::{ExchangeError, ExchangeId, OrderBook};
+
+use super::endpoint::{futures_base_url, futures_ws_url, spot_base_url, spot_ws_url};
+use super::types::{BookTop, PriceState};
+use crate::latency::latency_tracker;
+use crate::volatility::volatility_registry;
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// HTTP 시드 가격 검증: 스팟/선물 가격 차이 상한 (비율)
+const MAX_SEED_DIVERGENCE: f64 = 0.05;
+
+/// 가격 피드 준비 완료 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedReadySource {
+    /// 스팟/선물 WebSocket 가격을 모두 받음
+    WebSocket,
+    /// 제한 시간 안에 WebSocket 가격이 오지 않아 HTTP로 받은 가격을 검증해 채움
+    HttpSeed,
+}
 
-const SPOT_BASE_URL: &str = "https://api.binance.com";
-const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
-const SPOT_WS_URL: &str = "wss://stream.binance.com:9443/ws";
-const FUTURES_WS_URL: &str = "wss://fstream.binance.com/ws";
+/// `await_ready` 결과
+#[derive(Debug, Clone, Copy)]
+pub struct FeedReadiness {
+    pub source: FeedReadySource,
+    pub spot_price: f64,
+    pub futures_mark_price: f64,
+    pub waited: Duration,
+}
 
 /// Binance Price Feed: WebSocket 가격 스트림 관리
 pub struct BinancePriceFeed {
     price_state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
     spot_client: BinanceClient,
     futures_client: BinanceClient,
+    spot_ws_url: String,
+    futures_ws_url: String,
+    reconnect_delay: Duration,
 }
 
 impl BinancePriceFeed {
@@ -28,29 +56,126 @@ impl BinancePriceFeed {
             price_state: Arc::new(TokioRwLock::new(HashMap::new())),
             spot_client,
             futures_client,
+            spot_ws_url: spot_ws_url().to_string(),
+            futures_ws_url: futures_ws_url().to_string(),
+            reconnect_delay: RECONNECT_DELAY,
         }
     }
 
+    /// WebSocket 베이스 URL 변경 (테스트넷, 로컬 목 서버 등)
+    pub fn with_ws_urls(mut self, spot_ws_url: &str, futures_ws_url: &str) -> Self {
+        self.spot_ws_url = spot_ws_url.trim_end_matches('/').to_string();
+        self.futures_ws_url = futures_ws_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// 연결 종료 후 재연결까지 대기 시간 변경
+    pub fn with_reconnect_delay(mut self, delay: Duration) -> Self {
+        self.reconnect_delay = delay;
+        self
+    }
+
+    /// 메모리에 저장된 심볼의 가격 상태 조회 (HTTP 폴백 없음)
+    pub async fn price_state(&self, symbol: &str) -> Option<PriceState> {
+        self.price_state.read().await.get(symbol).cloned()
+    }
+
     /// 특정 심볼에 대한 WebSocket 리스너 시작
     /// 스팟 ticker와 선물 markPrice를 동시에 구독
     pub fn start_symbol(&self, symbol: &str) {
+        self.start_symbols(symbol, symbol);
+    }
+
+    /// 스팟/선물 심볼을 따로 지정해 구독 (예: BTCUSDC 스팟 + BTCUSDT 선물)
+    pub fn start_symbols(&self, spot_symbol: &str, futures_symbol: &str) {
+        if spot_symbol == futures_symbol {
+            info!("WebSocket 리스너 시작: {}", spot_symbol);
+        } else {
+            info!(
+                "WebSocket 리스너 시작: 스팟 {}, 선물 {}",
+                spot_symbol, futures_symbol
+            );
+        }
+
         let price_state = Arc::clone(&self.price_state);
+        let reconnect_delay = self.reconnect_delay;
 
-        // 스팟 ticker WebSocket
+        // 스팟 ticker WebSocket (종료되거나 멈추면 감시기가 재시작)
+        let spot_symbol = spot_symbol.to_string();
+        let spot_ws_url = self.spot_ws_url.clone();
         let spot_state = Arc::clone(&price_state);
-        let spot_symbol = symbol.to_string();
-        tokio::spawn(async move {
-            Self::start_spot_websocket(&spot_symbol, spot_state).await;
-        });
+        task_supervisor().spawn(
+            &format!("binance_spot_ticker_ws:{}", spot_symbol),
+            Some(WS_STALE_AFTER),
+            move || {
+                let (url, symbol, state) =
+                    (spot_ws_url.clone(), spot_symbol.clone(), Arc::clone(&spot_state));
+                async move {
+                    Self::start_spot_websocket(&url, &symbol, state, reconnect_delay).await;
+                }
+            },
+        );
 
         // 선물 markPrice WebSocket
+        let fut_symbol = futures_symbol.to_string();
+        let futures_ws_url = self.futures_ws_url.clone();
         let fut_state = Arc::clone(&price_state);
-        let fut_symbol = symbol.to_string();
-        tokio::spawn(async move {
-            Self::start_futures_websocket(&fut_symbol, fut_state).await;
-        });
+        task_supervisor().spawn(
+            &format!("binance_mark_price_ws:{}", fut_symbol),
+            Some(WS_STALE_AFTER),
+            move || {
+                let (url, symbol, state) =
+                    (futures_ws_url.clone(), fut_symbol.clone(), Arc::clone(&fut_state));
+                async move {
+                    Self::start_futures_websocket(&url, &symbol, state, reconnect_delay).await;
+                }
+            },
+        );
+    }
 
-        info!("WebSocket 리스너 시작: {}", symbol);
+    /// 스팟/선물 가격이 모두 들어올 때까지 대기
+    /// `timeout` 안에 WebSocket 가격이 오지 않으면 HTTP로 받은 가격을 검증(양수, 스팟/선물 차이
+    /// 5% 이내)해 시드로 채운다. 시드 조회나 검증에 실패하면 에러
+    pub async fn await_ready(
+        &self,
+        spot_symbol: &str,
+        futures_symbol: &str,
+        timeout: Duration,
+    ) -> Result<FeedReadiness, ExchangeError> {
+        let started = std::time::Instant::now();
+        while started.elapsed() < timeout {
+            let spot = self
+                .price_state(spot_symbol)
+                .await
+                .and_then(|s| s.spot_price);
+            let futures = self
+                .price_state(futures_symbol)
+                .await
+                .and_then(|s| s.futures_mark_price);
+            if let (Some(spot_price), Some(futures_mark_price)) = (spot, futures) {
+                return Ok(FeedReadiness {
+                    source: FeedReadySource::WebSocket,
+                    spot_price,
+                    futures_mark_price,
+                    waited: started.elapsed(),
+                });
+            }
+            tokio::time::sleep(READY_POLL_INTERVAL).await;
+        }
+
+        warn!(
+            "{:?} 안에 WebSocket 가격을 받지 못해 HTTP 가격으로 시드합니다 (스팟 {}, 선물 {})",
+            timeout, spot_symbol, futures_symbol
+        );
+        let spot_price = self.get_spot_price(spot_symbol).await?;
+        let futures_mark_price = self.get_futures_mark_price(futures_symbol).await?;
+        verify_seed(spot_price, futures_mark_price)?;
+        Ok(FeedReadiness {
+            source: FeedReadySource::HttpSeed,
+            spot_price,
+            futures_mark_price,
+            waited: started.elapsed(),
+        })
     }
 
     /// 스팟 현재가 조회 (메모리에서 읽기, 없으면 HTTP 폴백)
@@ -70,7 +195,7 @@ impl BinancePriceFeed {
             "WebSocket에서 스팟 가격을 찾을 수 없어 HTTP로 조회합니다 (symbol: {})",
             symbol
         );
-        let url = format!("{}/api/v3/ticker/price?symbol={}", SPOT_BASE_URL, symbol);
+        let url = format!("{}/api/v3/ticker/price?symbol={}", spot_base_url(), symbol);
 
         #[derive(Debug, serde::Deserialize)]
         struct PriceResponse {
@@ -81,7 +206,7 @@ impl BinancePriceFeed {
             .spot_client
             .http
             .get(&url)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
             .json()
@@ -125,7 +250,8 @@ impl BinancePriceFeed {
         );
         let url = format!(
             "{}/fapi/v1/premiumIndex?symbol={}",
-            FUTURES_BASE_URL, symbol
+            futures_base_url(),
+            symbol
         );
 
         #[derive(Debug, serde::Deserialize)]
@@ -138,7 +264,7 @@ impl BinancePriceFeed {
             .futures_client
             .http
             .get(&url)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
             .json()
@@ -162,142 +288,88 @@ impl BinancePriceFeed {
         Ok(price)
     }
 
+    /// 스팟 최우선 호가 조회 (HTTP bookTicker)
+    pub async fn get_spot_book_top(&self, symbol: &str) -> Result<BookTop, ExchangeError> {
+        let url = format!(
+            "{}/api/v3/ticker/bookTicker?symbol={}",
+            spot_base_url(),
+            symbol
+        );
+        fetch_book_top(&self.spot_client, &url).await
+    }
+
+    /// 스팟 호가창 조회 (HTTP depth, 100단계)
+    pub async fn get_spot_order_book(&self, symbol: &str) -> Result<OrderBook, ExchangeError> {
+        self.spot_client.fetch_orderbook(symbol).await
+    }
+
+    /// 선물 최우선 호가 조회 (HTTP bookTicker)
+    pub async fn get_futures_book_top(&self, symbol: &str) -> Result<BookTop, ExchangeError> {
+        let url = format!(
+            "{}/fapi/v1/ticker/bookTicker?symbol={}",
+            futures_base_url(),
+            symbol
+        );
+        fetch_book_top(&self.futures_client, &url).await
+    }
+
     /// 스팟 ticker WebSocket 연결 및 수신
     async fn start_spot_websocket(
+        ws_url: &str,
         symbol: &str,
         state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
+        reconnect_delay: Duration,
     ) {
         let symbol_lower = symbol.to_lowercase();
         let stream_name = format!("{}@ticker", symbol_lower);
-        let url = format!("{}/{}", SPOT_WS_URL, stream_name);
-
-        loop {
-            match Self::connect_spot_websocket(&url, symbol, state.clone()).await {
-                Ok(_) => {
-                    warn!(
-                        "스팟 WebSocket 연결이 종료되었습니다. 재연결 시도... (symbol: {})",
-                        symbol
-                    );
-                }
-                Err(e) => {
-                    warn!(
-                        "스팟 WebSocket 오류: {:?}. 재연결 시도... (symbol: {})",
-                        e, symbol
-                    );
-                }
-            }
-
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-        }
+        let url = format!("{}/{}", ws_url, stream_name);
+
+        let client = ReconnectingClient::new(&format!("스팟 ticker {}", symbol), &url)
+            .with_config(Self::reconnect_config(reconnect_delay))
+            .with_status(ExchangeId::Binance, &format!("spot_ticker_ws:{}", symbol));
+        let mut handler = PriceStreamHandler {
+            symbol: symbol.to_string(),
+            stream: PriceStream::SpotTicker,
+            state,
+        };
+        client.run(&mut handler).await;
     }
 
     /// 선물 markPrice WebSocket 연결 및 수신
     async fn start_futures_websocket(
+        ws_url: &str,
         symbol: &str,
         state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
+        reconnect_delay: Duration,
     ) {
         let symbol_lower = symbol.to_lowercase();
         let stream_name = format!("{}@markPrice", symbol_lower);
-        let url = format!("{}/{}", FUTURES_WS_URL, stream_name);
-
-        loop {
-            match Self::connect_futures_websocket(&url, symbol, state.clone()).await {
-                Ok(_) => {
-                    warn!(
-                        "선물 WebSocket 연결이 종료되었습니다. 재연결 시도... (symbol: {})",
-                        symbol
-                    );
-                }
-                Err(e) => {
-                    warn!(
-                        "선물 WebSocket 오류: {:?}. 재연결 시도... (symbol: {})",
-                        e, symbol
-                    );
-                }
-            }
-
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-        }
+        let url = format!("{}/{}", ws_url, stream_name);
+
+        let client = ReconnectingClient::new(&format!("선물 markPrice {}", symbol), &url)
+            .with_config(Self::reconnect_config(reconnect_delay))
+            .with_status(ExchangeId::Binance, &format!("mark_price_ws:{}", symbol));
+        let mut handler = PriceStreamHandler {
+            symbol: symbol.to_string(),
+            stream: PriceStream::FuturesMarkPrice,
+            state,
+        };
+        client.run(&mut handler).await;
     }
 
-    /// 스팟 ticker WebSocket 연결 및 메시지 처리
-    async fn connect_spot_websocket(
-        url: &str,
-        symbol: &str,
-        state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
-    ) -> Result<(), ExchangeError> {
-        let (ws_stream, _) = connect_async(url)
-            .await
-            .map_err(|e| ExchangeError::Other(format!("WebSocket 연결 실패: {}", e)))?;
-        let (_write, mut read) = ws_stream.split();
-
-        info!("스팟 WebSocket 연결 성공: {} (symbol: {})", url, symbol);
-
-        while let Some(msg) = read.next().await {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    if let Err(e) =
-                        Self::handle_spot_ticker_message(&text, symbol, state.clone()).await
-                    {
-                        warn!("스팟 ticker 메시지 처리 오류: {:?}", e);
-                    }
-                }
-                Ok(Message::Close(_)) => {
-                    warn!("스팟 WebSocket 연결이 닫혔습니다 (symbol: {})", symbol);
-                    break;
-                }
-                Err(e) => {
-                    warn!(
-                        "스팟 WebSocket 메시지 수신 오류: {:?} (symbol: {})",
-                        e, symbol
-                    );
-                    break;
-                }
-                _ => {}
-            }
-        }
-
-        Ok(())
-    }
-
-    /// 선물 markPrice WebSocket 연결 및 메시지 처리
-    async fn connect_futures_websocket(
-        url: &str,
-        symbol: &str,
-        state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
-    ) -> Result<(), ExchangeError> {
-        let (ws_stream, _) = connect_async(url)
-            .await
-            .map_err(|e| ExchangeError::Other(format!("WebSocket 연결 실패: {}", e)))?;
-        let (_write, mut read) = ws_stream.split();
-
-        info!("선물 WebSocket 연결 성공: {} (symbol: {})", url, symbol);
-
-        while let Some(msg) = read.next().await {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    if let Err(e) =
-                        Self::handle_futures_mark_price_message(&text, symbol, state.clone()).await
-                    {
-                        warn!("선물 markPrice 메시지 처리 오류: {:?}", e);
-                    }
-                }
-                Ok(Message::Close(_)) => {
-                    warn!("선물 WebSocket 연결이 닫혔습니다 (symbol: {})", symbol);
-                    break;
-                }
-                Err(e) => {
-                    warn!(
-                        "선물 WebSocket 메시지 수신 오류: {:?} (symbol: {})",
-                        e, symbol
-                    );
-                    break;
-                }
-                _ => {}
-            }
+    /// 가격 스트림 재연결 정책
+    /// reconnect_delay 에서 시작해 최대 1분까지 지수 백오프
+    fn reconnect_config(reconnect_delay: Duration) -> ReconnectConfig {
+        ReconnectConfig {
+            initial_backoff: reconnect_delay,
+            max_backoff: MAX_RECONNECT_DELAY.max(reconnect_delay),
+            heartbeat: Some(Heartbeat {
+                interval: Duration::from_secs(30),
+                timeout: Duration::from_secs(90),
+                message: PingMessage::Frame,
+            }),
+            ..Default::default()
         }
-
-        Ok(())
     }
 
     /// 스팟 ticker 메시지 처리
@@ -312,6 +384,8 @@ impl BinancePriceFeed {
             symbol: String,
             #[serde(rename = "c")]
             last_price: String,
+            #[serde(rename = "E")]
+            event_time: Option<i64>,
         }
 
         let ticker: SpotTicker = serde_json::from_str(text).map_err(|e| {
@@ -322,6 +396,10 @@ impl BinancePriceFeed {
             return Ok(());
         }
 
+        if let Some(event_time) = ticker.event_time {
+            latency_tracker().record_feed_age("binance_spot", event_time);
+        }
+
         let price: f64 = ticker.last_price.parse().map_err(|e| {
             ExchangeError::Other(format!(
                 "가격 파싱 실패: {} (price: {})",
@@ -329,6 +407,11 @@ impl BinancePriceFeed {
             ))
         })?;
 
+        let sample_ms = ticker
+            .event_time
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+        volatility_registry().record(symbol, sample_ms, price);
+
         let mut state_map = state.write().await;
         let price_state = state_map
             .entry(symbol.to_string())
@@ -351,6 +434,8 @@ impl BinancePriceFeed {
             symbol: String,
             #[serde(rename = "p")]
             mark_price: String,
+            #[serde(rename = "E")]
+            event_time: Option<i64>,
         }
 
         let mark_price_data: FuturesMarkPrice = serde_json::from_str(text).map_err(|e| {
@@ -361,6 +446,10 @@ impl BinancePriceFeed {
             return Ok(());
         }
 
+        if let Some(event_time) = mark_price_data.event_time {
+            latency_tracker().record_feed_age("binance_futures", event_time);
+        }
+
         let price: f64 = mark_price_data.mark_price.parse().map_err(|e| {
             ExchangeError::Other(format!(
                 "가격 파싱 실패: {} (price: {})",
@@ -379,3 +468,294 @@ impl BinancePriceFeed {
     }
 }
 
+/// 가격 스트림 종류
+#[derive(Debug, Clone, Copy)]
+enum PriceStream {
+    SpotTicker,
+    FuturesMarkPrice,
+}
+
+/// ReconnectingClient 용 가격 스트림 핸들러
+struct PriceStreamHandler {
+    symbol: String,
+    stream: PriceStream,
+    state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
+}
+
+#[async_trait]
+impl WsHandler for PriceStreamHandler {
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+        let result = match self.stream {
+            PriceStream::SpotTicker => {
+                BinancePriceFeed::handle_spot_ticker_message(text, &self.symbol, self.state.clone())
+                    .await
+            }
+            PriceStream::FuturesMarkPrice => {
+                BinancePriceFeed::handle_futures_mark_price_message(
+                    text,
+                    &self.symbol,
+                    self.state.clone(),
+                )
+                .await
+            }
+        };
+
+        if let Err(e) = result {
+            warn!("{:?} 메시지 처리 오류: {:?}", self.stream, e);
+        }
+        Ok(())
+    }
+}
+
+/// HTTP 시드 가격 검증 (양수이고 스팟/선물 차이가 상한 이내)
+fn verify_seed(spot_price: f64, futures_mark_price: f64) -> Result<(), ExchangeError> {
+    let valid = |price: f64| price.is_finite() && price > 0.0;
+    if !valid(spot_price) || !valid(futures_mark_price) {
+        return Err(ExchangeError::Other(format!(
+            "invalid seed prices: spot {}, futures {}",
+            spot_price, futures_mark_price
+        )));
+    }
+    let divergence = (futures_mark_price - spot_price).abs() / spot_price;
+    if divergence > MAX_SEED_DIVERGENCE {
+        return Err(ExchangeError::Other(format!(
+            "seed prices diverge by {:.2}%: spot {}, futures {}",
+            divergence * 100.0,
+            spot_price,
+            futures_mark_price
+        )));
+    }
+    Ok(())
+}
+
+async fn fetch_book_top(client: &BinanceClient, url: &str) -> Result<BookTop, ExchangeError> {
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct BookTickerResponse {
+        bid_price: String,
+        bid_qty: String,
+        ask_price: String,
+        ask_qty: String,
+    }
+
+    let response: BookTickerResponse = client
+        .http
+        .get(url)
+        .send_tracked()
+        .await
+        .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
+        .json()
+        .await
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse book ticker: {}", e)))?;
+
+    let parse = |v: &str| {
+        v.parse::<f64>()
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse book ticker field: {}", e)))
+    };
+    Ok(BookTop {
+        bid_price: parse(&response.bid_price)?,
+        bid_qty: parse(&response.bid_qty)?,
+        ask_price: parse(&response.ask_price)?,
+        ask_qty: parse(&response.ask_qty)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trader::binance::mock_ws::MockWsServer;
+
+    fn spot_ticker(symbol: &str, price: &str) -> String {
+        format!(
+            r#"{{"e":"24hrTicker","E":{},"s":"{}","c":"{}"}}"#,
+            chrono::Utc::now().timestamp_millis(),
+            symbol,
+            price
+        )
+    }
+
+    fn mark_price(symbol: &str, price: &str) -> String {
+        format!(
+            r#"{{"e":"markPriceUpdate","E":{},"s":"{}","p":"{}"}}"#,
+            chrono::Utc::now().timestamp_millis(),
+            symbol,
+            price
+        )
+    }
+
+    fn new_state() -> Arc<TokioRwLock<HashMap<String, PriceState>>> {
+        Arc::new(TokioRwLock::new(HashMap::new()))
+    }
+
+    fn new_feed(spot_url: &str, futures_url: &str) -> BinancePriceFeed {
+        BinancePriceFeed::new(BinanceClient::new(), BinanceClient::new())
+            .with_ws_urls(spot_url, futures_url)
+            .with_reconnect_delay(Duration::from_millis(50))
+    }
+
+    /// 조건을 만족할 때까지 최대 5초간 대기
+    async fn wait_for<F>(feed: &BinancePriceFeed, symbol: &str, cond: F) -> Option<PriceState>
+    where
+        F: Fn(&PriceState) -> bool,
+    {
+        for _ in 0..100 {
+            if let Some(state) = feed.price_state(symbol).await
+                && cond(&state)
+            {
+                return Some(state);
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        None
+    }
+
+    #[tokio::test]
+    async fn test_parse_spot_ticker() {
+        let state = new_state();
+        BinancePriceFeed::handle_spot_ticker_message(
+            &spot_ticker("BTCUSDT", "65000.5"),
+            "BTCUSDT",
+            state.clone(),
+        )
+        .await
+        .unwrap();
+
+        let map = state.read().await;
+        let price_state = map.get("BTCUSDT").unwrap();
+        assert_eq!(price_state.spot_price, Some(65000.5));
+        assert_eq!(price_state.futures_mark_price, None);
+        assert!(price_state.last_updated.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_parse_futures_mark_price() {
+        let state = new_state();
+        BinancePriceFeed::handle_futures_mark_price_message(
+            &mark_price("BTCUSDT", "65010.25"),
+            "BTCUSDT",
+            state.clone(),
+        )
+        .await
+        .unwrap();
+
+        let map = state.read().await;
+        assert_eq!(
+            map.get("BTCUSDT").unwrap().futures_mark_price,
+            Some(65010.25)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_messages_are_rejected() {
+        let state = new_state();
+        assert!(
+            BinancePriceFeed::handle_spot_ticker_message("not json", "BTCUSDT", state.clone())
+                .await
+                .is_err()
+        );
+        assert!(
+            BinancePriceFeed::handle_futures_mark_price_message(
+                &mark_price("BTCUSDT", "abc"),
+                "BTCUSDT",
+                state.clone(),
+            )
+            .await
+            .is_err()
+        );
+        assert!(state.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_other_symbols_are_ignored() {
+        let state = new_state();
+        BinancePriceFeed::handle_spot_ticker_message(
+            &spot_ticker("ETHUSDT", "3000"),
+            "BTCUSDT",
+            state.clone(),
+        )
+        .await
+        .unwrap();
+        BinancePriceFeed::handle_futures_mark_price_message(
+            &mark_price("ETHUSDT", "3001"),
+            "BTCUSDT",
+            state.clone(),
+        )
+        .await
+        .unwrap();
+
+        assert!(state.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stream_updates_prices_from_mock_server() {
+        let spot_server = MockWsServer::start(vec![vec![
+            spot_ticker("ETHUSDT", "3000"),
+            spot_ticker("BTCUSDT", "65000"),
+        ]])
+        .await;
+        let futures_server = MockWsServer::start(vec![vec![mark_price("BTCUSDT", "65020")]]).await;
+
+        let feed = new_feed(spot_server.url(), futures_server.url());
+        feed.start_symbol("BTCUSDT");
+
+        let state = wait_for(&feed, "BTCUSDT", |s| {
+            s.spot_price.is_some() && s.futures_mark_price.is_some()
+        })
+        .await
+        .expect("prices were not received from mock server");
+
+        assert_eq!(state.spot_price, Some(65000.0));
+        assert_eq!(state.futures_mark_price, Some(65020.0));
+        assert!(feed.price_state("ETHUSDT").await.is_none());
+        assert_eq!(spot_server.paths(), vec!["/btcusdt@ticker".to_string()]);
+        assert_eq!(
+            futures_server.paths(),
+            vec!["/btcusdt@markPrice".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_await_ready_waits_for_both_legs() {
+        let spot_server = MockWsServer::start(vec![vec![spot_ticker("BTCUSDC", "65000")]]).await;
+        let futures_server = MockWsServer::start(vec![vec![mark_price("BTCUSDT", "65020")]]).await;
+
+        let feed = new_feed(spot_server.url(), futures_server.url());
+        feed.start_symbols("BTCUSDC", "BTCUSDT");
+
+        let ready = feed
+            .await_ready("BTCUSDC", "BTCUSDT", Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(ready.source, FeedReadySource::WebSocket);
+        assert_eq!(ready.spot_price, 65000.0);
+        assert_eq!(ready.futures_mark_price, 65020.0);
+
+        assert!(verify_seed(100.0, 101.0).is_ok());
+        assert!(verify_seed(100.0, 120.0).is_err());
+        assert!(verify_seed(0.0, 100.0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_close_and_refreshes_staleness() {
+        let spot_server = MockWsServer::start(vec![
+            vec![spot_ticker("BTCUSDT", "100")],
+            vec![spot_ticker("BTCUSDT", "101")],
+        ])
+        .await;
+        let futures_server = MockWsServer::start(vec![vec![]]).await;
+
+        let feed = new_feed(spot_server.url(), futures_server.url());
+        feed.start_symbol("BTCUSDT");
+
+        let first = wait_for(&feed, "BTCUSDT", |s| s.spot_price.is_some())
+            .await
+            .expect("first price was not received");
+
+        let second = wait_for(&feed, "BTCUSDT", |s| s.spot_price == Some(101.0))
+            .await
+            .expect("price was not refreshed after reconnect");
+
+        assert!(spot_server.paths().len() >= 2);
+        assert!(second.last_updated.unwrap() >= first.last_updated.unwrap());
+    }
+}
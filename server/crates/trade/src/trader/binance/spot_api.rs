@@ -2,16 +2,19 @@ use std::collections::HashMap;
 use std::sync::RwLock;
 
 use exchanges::{AssetExchange, BinanceClient};
-use interface::ExchangeError;
+use exchanges::weight::TrackedSend;
+use interface::{ExchangeError, SpotAsset};
 
-use super::types::{clamp_quantity_with_filter, LotSizeFilter};
-
-const SPOT_BASE_URL: &str = "https://api.binance.com";
+use super::dust::{self, BnbBurnStatus, DustCandidate, DustTransferResponse};
+use super::endpoint::spot_base_url;
+use super::transfer::{self, SubAccountTransfer, TransferResponse, Wallet};
+use super::types::{clamp_quantity_with_filter, LotSizeFilter, SymbolStatus};
 
 /// Binance Spot API: Spot 주문, exchangeInfo, LOT_SIZE 캐시 관리
 pub struct BinanceSpotApi {
     client: BinanceClient,
     lot_size_cache: RwLock<HashMap<String, LotSizeFilter>>,
+    status_cache: RwLock<HashMap<String, SymbolStatus>>,
 }
 
 impl BinanceSpotApi {
@@ -19,18 +22,19 @@ impl BinanceSpotApi {
         Self {
             client,
             lot_size_cache: RwLock::new(HashMap::new()),
+            status_cache: RwLock::new(HashMap::new()),
         }
     }
 
-    /// 스팟 exchangeInfo를 로드하여 LOT_SIZE 필터를 캐시에 저장
+    /// 스팟 exchangeInfo를 로드하여 LOT_SIZE 필터와 심볼 거래 상태를 캐시에 저장
     pub async fn load_exchange_info(&self) -> Result<(), ExchangeError> {
-        let url = format!("{}/api/v3/exchangeInfo", SPOT_BASE_URL);
+        let url = format!("{}/api/v3/exchangeInfo", spot_base_url());
 
         let response = self
             .client
             .http
             .get(&url)
-            .send()
+            .send_tracked()
             .await
             .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
 
@@ -50,6 +54,8 @@ impl BinanceSpotApi {
 
         let mut cache = self.lot_size_cache.write().unwrap();
         cache.clear();
+        let mut statuses = self.status_cache.write().unwrap();
+        statuses.clear();
 
         if let Some(symbols) = resp.get("symbols").and_then(|v| v.as_array()) {
             for symbol_info in symbols {
@@ -58,6 +64,10 @@ impl BinanceSpotApi {
                     None => continue,
                 };
 
+                if let Some(status) = symbol_info.get("status").and_then(|v| v.as_str()) {
+                    statuses.insert(symbol.clone(), SymbolStatus::new(status));
+                }
+
                 if let Some(filters) = symbol_info.get("filters").and_then(|v| v.as_array()) {
                     for filter in filters {
                         let filter_type = filter.get("filterType").and_then(|v| v.as_str());
@@ -104,6 +114,11 @@ impl BinanceSpotApi {
         self.lot_size_cache.read().unwrap().get(symbol).copied()
     }
 
+    /// 스팟 심볼의 거래 상태 (exchangeInfo에 없으면 None)
+    pub fn symbol_status(&self, symbol: &str) -> Option<SymbolStatus> {
+        self.status_cache.read().unwrap().get(symbol).cloned()
+    }
+
     /// 스팟 수량을 거래소 규칙에 맞게 조정 (LOT_SIZE)
     pub fn clamp_quantity(&self, symbol: &str, qty: f64) -> f64 {
         if let Some(filter) = self.get_lot_size(symbol) {
@@ -134,6 +149,56 @@ impl BinanceSpotApi {
         Ok(balance)
     }
 
+    /// 스팟 지갑 전체 잔고 조회 (잔고가 있는 자산만)
+    pub async fn get_balances(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
+        self.client
+            .fetch_spots()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("Failed to fetch spot assets: {}", e)))
+    }
+
+    /// 현물 지갑 → USDⓈ-M 선물 지갑 이체
+    pub async fn transfer_to_futures(
+        &self,
+        asset: &str,
+        amount: f64,
+    ) -> Result<TransferResponse, ExchangeError> {
+        transfer::universal_transfer(
+            &self.client,
+            Wallet::Spot,
+            Wallet::UsdtFutures,
+            asset,
+            amount,
+        )
+        .await
+    }
+
+    /// BNB로 변환할 수 있는 소액 자산 목록
+    pub async fn dust_candidates(&self) -> Result<Vec<DustCandidate>, ExchangeError> {
+        dust::dust_candidates(&self.client).await
+    }
+
+    /// 소액 자산을 BNB로 변환
+    pub async fn convert_dust_to_bnb(
+        &self,
+        assets: &[String],
+    ) -> Result<DustTransferResponse, ExchangeError> {
+        dust::dust_transfer(&self.client, assets).await
+    }
+
+    /// 현물 거래 수수료 BNB 차감 켜기/끄기
+    pub async fn set_bnb_burn(&self, enabled: bool) -> Result<BnbBurnStatus, ExchangeError> {
+        dust::set_spot_bnb_burn(&self.client, enabled).await
+    }
+
+    /// 마스터 ↔ 서브 계정 이체 (이 클라이언트가 마스터 계정이어야 함)
+    pub async fn sub_account_transfer(
+        &self,
+        request: &SubAccountTransfer,
+    ) -> Result<TransferResponse, ExchangeError> {
+        transfer::sub_account_transfer(&self.client, request).await
+    }
+
     pub fn client(&self) -> &BinanceClient {
         &self.client
     }
@@ -1,17 +1,32 @@
 use async_trait::async_trait;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
 
-use interface::ExchangeError;
+use exchanges::fee_override::fee_overrides;
+use interface::{ExchangeError, ExchangeId, FeeInfo, OrderBook, Price, Qty};
 
+use crate::trader::order_api::{ExchangeOrderApi, MarketKind, OrderRequest};
+use crate::trader::quote::split_symbol;
 use crate::trader::{FuturesExchangeTrader, SpotExchangeTrader};
 
-use super::futures_api::BinanceFuturesApi;
+use super::account::BinanceAccounts;
+use super::dust::{BnbBurnStatus, DustCandidate, DustTransferResponse};
+use super::futures_api::{BinanceFuturesApi, FuturesPositionRisk};
+use super::futures_user_stream::{BinanceFuturesUserStream, FuturesUserDataEvent};
 use super::order_client::{BinanceOrderClient, HttpBinanceOrderClient};
-use super::price_feed::BinancePriceFeed;
+use super::order_limit::{BinanceOrderSizing, NotionalLimitedOrderClient, OrderNotionalLimits};
+use super::price_feed::{BinancePriceFeed, FeedReadiness};
 use super::spot_api::BinanceSpotApi;
-use super::types::{HedgedPair, OrderResponse, PlaceFuturesOrderOptions, PlaceOrderOptions};
+use super::transfer::{self, SubAccountTransfer, TransferResponse, Wallet};
+use super::types::{
+    BookTop, HedgedPair, OrderResponse, PlaceFuturesOrderOptions, PlaceOrderOptions, SymbolStatus,
+};
 use super::user_stream::{BinanceUserStream, UserDataEvent};
-use exchanges::BinanceClient;
+
+/// Binance USDⓈ-M 선물 기본 등급(VIP 0) 수수료
+const BINANCE_FUTURES_DEFAULT_MAKER: f64 = 0.0002;
+const BINANCE_FUTURES_DEFAULT_TAKER: f64 = 0.0005;
 
 pub struct BinanceTrader {
     pub order_client: Arc<dyn BinanceOrderClient>,
@@ -19,34 +34,79 @@ pub struct BinanceTrader {
     pub futures: Arc<BinanceFuturesApi>,
     pub price_feed: Arc<BinancePriceFeed>,
     pub user_stream: Option<Arc<BinanceUserStream>>,
+    /// 선물 계정 User Data Stream (listenKey, 스팟 스트림으로는 선물 이벤트가 오지 않음)
+    pub futures_user_stream: Option<Arc<BinanceFuturesUserStream>>,
+    pub accounts: BinanceAccounts,
 }
 
 impl BinanceTrader {
+    /// 환경 변수로 계정 구성 (BINANCE_SPOT_ACCOUNT / BINANCE_FUTURES_ACCOUNT, 없으면 기본 계정)
     pub fn new() -> Result<Self, ExchangeError> {
-        let spot_client = BinanceClient::with_credentials()
-            .map_err(|e| ExchangeError::Other(format!("Failed to create spot client: {}", e)))?;
-        let futures_client = BinanceClient::with_credentials()
-            .map_err(|e| ExchangeError::Other(format!("Failed to create futures client: {}", e)))?;
+        let accounts = BinanceAccounts::from_env().map_err(|e| {
+            ExchangeError::Other(format!("Failed to create Binance clients: {}", e))
+        })?;
+        Ok(Self::with_accounts(accounts))
+    }
 
-        let order_client = Arc::new(HttpBinanceOrderClient::new(
-            spot_client.clone(),
-            futures_client.clone(),
-        ));
+    /// 스팟/선물 레그에 서로 다른 계정을 사용하는 트레이더 생성
+    pub fn with_accounts(accounts: BinanceAccounts) -> Self {
+        let spot_client = accounts.spot.client.clone();
+        let futures_client = accounts.futures.client.clone();
+
+        let http_order_client: Arc<dyn BinanceOrderClient> = Arc::new(
+            HttpBinanceOrderClient::new(spot_client.clone(), futures_client.clone())
+                .with_account_labels(&accounts.spot.label, &accounts.futures.label),
+        );
         let spot = Arc::new(BinanceSpotApi::new(spot_client.clone()));
         let futures = Arc::new(BinanceFuturesApi::new(futures_client.clone()));
         let price_feed = Arc::new(BinancePriceFeed::new(
             spot_client.clone(),
             futures_client.clone(),
         ));
-        let user_stream = Some(Arc::new(BinanceUserStream::new(spot_client)));
 
-        Ok(Self {
+        // 주문 명목가 상한이 설정되어 있으면 거절/분할 클라이언트로 감쌈
+        let limits = OrderNotionalLimits::from_env();
+        let order_client: Arc<dyn BinanceOrderClient> = if limits.is_enabled() {
+            info!(
+                "주문 명목가 상한: 기본 {:?}, 심볼별 {:?}, 초과 시 {:?}",
+                limits.default_max, limits.per_symbol, limits.action
+            );
+            let sizing = Arc::new(BinanceOrderSizing {
+                price_feed: price_feed.clone(),
+                spot: spot.clone(),
+                futures: futures.clone(),
+            });
+            Arc::new(NotionalLimitedOrderClient::new(
+                http_order_client,
+                sizing,
+                limits,
+            ))
+        } else {
+            http_order_client
+        };
+        let user_stream = Some(Arc::new(
+            BinanceUserStream::new(spot_client).with_label(&accounts.spot.label),
+        ));
+        let futures_user_stream = Some(Arc::new(
+            BinanceFuturesUserStream::new(futures_client).with_label(&accounts.futures.label),
+        ));
+
+        if accounts.is_split() {
+            info!(
+                "Binance 계정 분리: 스팟={}, 선물={}",
+                accounts.spot.label, accounts.futures.label
+            );
+        }
+
+        Self {
             order_client,
             spot,
             futures,
             price_feed,
             user_stream,
-        })
+            futures_user_stream,
+            accounts,
+        }
     }
 
     /// 거래소 이름 반환
@@ -60,6 +120,23 @@ impl BinanceTrader {
         self.price_feed.start_symbol(symbol);
     }
 
+    /// 스팟/선물 심볼이 다를 때 (예: BTCUSDC 스팟 + BTCUSDT 선물)
+    pub fn start_websocket_listeners(&self, spot_symbol: &str, futures_symbol: &str) {
+        self.price_feed.start_symbols(spot_symbol, futures_symbol);
+    }
+
+    /// 스팟/선물 가격 수신 대기 (제한 시간이 지나면 검증된 HTTP 가격으로 시드)
+    pub async fn await_prices_ready(
+        &self,
+        spot_symbol: &str,
+        futures_symbol: &str,
+        timeout: Duration,
+    ) -> Result<FeedReadiness, ExchangeError> {
+        self.price_feed
+            .await_ready(spot_symbol, futures_symbol, timeout)
+            .await
+    }
+
     /// 스팟 현재가 조회 (메모리에서 읽기, 없으면 HTTP 폴백)
     pub async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
         self.price_feed.get_spot_price(symbol).await
@@ -70,33 +147,126 @@ impl BinanceTrader {
         self.price_feed.get_futures_mark_price(symbol).await
     }
 
+    /// 스팟 최우선 호가 조회
+    pub async fn get_spot_book_top(&self, symbol: &str) -> Result<BookTop, ExchangeError> {
+        self.price_feed.get_spot_book_top(symbol).await
+    }
+
+    /// 스팟 호가창 조회 (100단계)
+    pub async fn get_spot_order_book(&self, symbol: &str) -> Result<OrderBook, ExchangeError> {
+        self.price_feed.get_spot_order_book(symbol).await
+    }
+
+    /// 선물 최우선 호가 조회
+    pub async fn get_futures_book_top(&self, symbol: &str) -> Result<BookTop, ExchangeError> {
+        self.price_feed.get_futures_book_top(symbol).await
+    }
+
     /// 스팟 잔고 조회
     pub async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
         self.spot.get_balance(asset).await
     }
 
-    /// 특정 심볼의 거래 수수료 조회
-    pub async fn get_trade_fee_for_symbol(
-        &self,
-        symbol: &str,
-    ) -> Result<interface::FeeInfo, ExchangeError> {
+    /// 특정 심볼의 거래 수수료 조회 (`FEE_OVERRIDES` 설정이 있으면 API 대신 사용)
+    pub async fn get_trade_fee_for_symbol(&self, symbol: &str) -> Result<FeeInfo, ExchangeError> {
+        if let Some(fee) = fee_overrides().spot_symbol(ExchangeId::Binance, symbol) {
+            return Ok(fee);
+        }
         self.spot.client().get_trade_fee_for_symbol(symbol).await
     }
 
+    /// 선물 거래 수수료 (`FEE_OVERRIDES` 설정이 없으면 USDⓈ-M 기본 등급 요율)
+    pub fn futures_fee(&self) -> FeeInfo {
+        fee_overrides()
+            .futures(ExchangeId::Binance)
+            .unwrap_or(FeeInfo::new(
+                BINANCE_FUTURES_DEFAULT_MAKER,
+                BINANCE_FUTURES_DEFAULT_TAKER,
+            ))
+    }
+
     /// 선물 잔고 조회 (USDT 마진)
     pub async fn get_futures_balance(&self) -> Result<f64, ExchangeError> {
         self.futures.get_balance().await
     }
 
+    /// 현물 계정에서 BNB로 변환할 수 있는 소액 자산 목록
+    pub async fn get_dust_candidates(&self) -> Result<Vec<DustCandidate>, ExchangeError> {
+        self.spot.dust_candidates().await
+    }
+
+    /// 현물 계정의 소액 자산을 BNB로 변환
+    pub async fn convert_dust_to_bnb(
+        &self,
+        assets: &[String],
+    ) -> Result<DustTransferResponse, ExchangeError> {
+        self.spot.convert_dust_to_bnb(assets).await
+    }
+
+    /// 현물 계정의 거래 수수료 BNB 차감 켜기/끄기
+    pub async fn set_spot_bnb_burn(&self, enabled: bool) -> Result<BnbBurnStatus, ExchangeError> {
+        self.spot.set_bnb_burn(enabled).await
+    }
+
+    /// 선물 포지션 위험 정보 (청산 가격, 레버리지)
+    pub async fn get_futures_position_risk(
+        &self,
+        symbol: &str,
+    ) -> Result<Option<FuturesPositionRisk>, ExchangeError> {
+        self.futures.get_position_risk(symbol).await
+    }
+
+    /// 선물 증거금 보충 (현물 지갑 → 선물 지갑)
+    /// 스팟/선물 계정이 분리되어 있으면 마스터 계정(BINANCE_API_KEY)으로 서브 계정 간 이체한다
+    pub async fn top_up_futures_margin(
+        &self,
+        asset: &str,
+        amount: f64,
+    ) -> Result<TransferResponse, ExchangeError> {
+        info!(
+            "선물 증거금 보충: {} {} ({} → {})",
+            amount, asset, self.accounts.spot.label, self.accounts.futures.label
+        );
+
+        if !self.accounts.is_split() {
+            return self.spot.transfer_to_futures(asset, amount).await;
+        }
+
+        let master = exchanges::BinanceClient::with_credentials()?;
+        let request = SubAccountTransfer {
+            from_email: self.accounts.spot.email.clone(),
+            to_email: self.accounts.futures.email.clone(),
+            from_wallet: Wallet::Spot,
+            to_wallet: Wallet::UsdtFutures,
+            asset: asset.to_string(),
+            amount,
+        };
+        transfer::sub_account_transfer(&master, &request).await
+    }
+
+    /// 선물 USDT 잔고가 min_balance 미만이면 target_balance까지 보충
+    /// 보유 중 전략 루프에서 호출한다 (`arbitrage::margin`). 보충하지 않았으면 None
+    pub async fn ensure_futures_margin(
+        &self,
+        min_balance: f64,
+        target_balance: f64,
+    ) -> Result<Option<TransferResponse>, ExchangeError> {
+        let balance = self.get_futures_balance().await?;
+        if balance >= min_balance {
+            return Ok(None);
+        }
+
+        let amount = target_balance.max(min_balance) - balance;
+        self.top_up_futures_margin("USDT", amount).await.map(Some)
+    }
+
     /// 심볼에서 베이스 자산 추출 (예: "BTCUSDT" -> "BTC")
+    /// USDC/FDUSD 등 다른 견적 자산도 처리 (`quote::KNOWN_QUOTES`)
     pub fn base_asset_from_symbol(symbol: &str) -> String {
-        if symbol.ends_with("USDT") {
-            symbol[..symbol.len() - 4].to_string()
-        } else if symbol.ends_with("USD") {
-            symbol[..symbol.len() - 3].to_string()
-        } else {
-            symbol.to_string()
-        }
+        split_symbol(symbol)
+            .map(|(base, _)| base)
+            .unwrap_or(symbol)
+            .to_string()
     }
 
     /// 스팟 수량을 거래소 규칙에 맞게 조정 (LOT_SIZE)
@@ -118,13 +288,24 @@ impl BinanceTrader {
         symbol: &str,
         target_net_qty: f64,
         spot_fee_rate: f64,
+    ) -> Option<HedgedPair> {
+        self.find_hedged_pair_for(symbol, symbol, target_net_qty, spot_fee_rate)
+    }
+
+    /// 스팟/선물 심볼이 다른 경우의 `find_hedged_pair` (LOT_SIZE를 각자 심볼에서 조회)
+    pub fn find_hedged_pair_for(
+        &self,
+        spot_symbol: &str,
+        futures_symbol: &str,
+        target_net_qty: f64,
+        spot_fee_rate: f64,
     ) -> Option<HedgedPair> {
         if target_net_qty <= 0.0 {
             return None;
         }
 
         // 선물 LOT_SIZE filter에서 stepSize를 가져와서 "한 스텝씩 줄여가며 탐색"에 사용
-        let fut_lot = self.futures.get_lot_size(symbol)?;
+        let fut_lot = self.futures.get_lot_size(futures_symbol)?;
         let fut_step = if fut_lot.step_size > 0.0 {
             fut_lot.step_size
         } else {
@@ -134,7 +315,7 @@ impl BinanceTrader {
 
         // 1) 먼저 target_net_qty를 기준으로 "선물 수량 후보"를 만든다.
         //    (선물 LOT_SIZE에 맞게 클램프)
-        let mut fut_candidate = self.clamp_futures_quantity(symbol, target_net_qty);
+        let mut fut_candidate = self.clamp_futures_quantity(futures_symbol, target_net_qty);
         if fut_candidate <= 0.0 {
             return None;
         }
@@ -142,7 +323,7 @@ impl BinanceTrader {
         // 허용 오차: 스팟/선물 스텝 중 더 작은 값의 절반 정도
         let spot_step = self
             .spot
-            .get_lot_size(symbol)
+            .get_lot_size(spot_symbol)
             .map(|f| f.step_size)
             .unwrap_or(fut_step.max(1e-8)); // 그래도 0은 피하기
 
@@ -161,7 +342,7 @@ impl BinanceTrader {
             }
 
             // 스팟 LOT_SIZE에 맞게 주문 수량 클램프
-            let spot_order_qty = self.clamp_spot_quantity(symbol, ideal_spot_order);
+            let spot_order_qty = self.clamp_spot_quantity(spot_symbol, ideal_spot_order);
             if spot_order_qty <= 0.0 {
                 break;
             }
@@ -189,7 +370,7 @@ impl BinanceTrader {
             }
 
             let next_fut = fut_candidate - fut_step;
-            let next_fut = self.clamp_futures_quantity(symbol, next_fut);
+            let next_fut = self.clamp_futures_quantity(futures_symbol, next_fut);
             if next_fut <= 0.0 || (next_fut - fut_candidate).abs() < 1e-12 {
                 break;
             }
@@ -209,6 +390,16 @@ impl BinanceTrader {
         self.futures.load_exchange_info().await
     }
 
+    /// 스팟 심볼 거래 상태 (exchangeInfo 로드 시점 기준)
+    pub fn spot_symbol_status(&self, symbol: &str) -> Option<SymbolStatus> {
+        self.spot.symbol_status(symbol)
+    }
+
+    /// 선물 심볼 거래 상태 (exchangeInfo 로드 시점 기준)
+    pub fn futures_symbol_status(&self, symbol: &str) -> Option<SymbolStatus> {
+        self.futures.symbol_status(symbol)
+    }
+
     /// 레거시 호환성을 위한 정적 메서드 (deprecated)
     /// 실제로는 clamp_spot_quantity 또는 clamp_futures_quantity를 사용해야 함
     #[deprecated(note = "Use clamp_spot_quantity or clamp_futures_quantity instead")]
@@ -228,7 +419,16 @@ impl BinanceTrader {
         test: bool,
     ) -> Result<OrderResponse, ExchangeError> {
         self.order_client
-            .place_spot_order(symbol, side, quantity, None, PlaceOrderOptions { test })
+            .place_spot_order(
+                symbol,
+                side,
+                quantity,
+                None,
+                PlaceOrderOptions {
+                    test,
+                    ..Default::default()
+                },
+            )
             .await
     }
 
@@ -246,11 +446,103 @@ impl BinanceTrader {
                 side,
                 quantity,
                 None,
-                PlaceFuturesOrderOptions { reduce_only },
+                PlaceFuturesOrderOptions {
+                    reduce_only,
+                    ..Default::default()
+                },
             )
             .await
     }
 
+    /// client order id를 지정한 스팟 시장가 주문 (실패 응답 뒤 `find_spot_order`로 접수 여부 확인)
+    pub async fn place_spot_order_with_id(
+        &self,
+        symbol: &str,
+        side: &str,
+        quantity: f64,
+        client_order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.order_client
+            .place_spot_order(
+                symbol,
+                side,
+                quantity,
+                None,
+                PlaceOrderOptions {
+                    client_order_id: Some(client_order_id.to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+    }
+
+    /// client order id를 지정한 선물 시장가 주문
+    pub async fn place_futures_order_with_id(
+        &self,
+        symbol: &str,
+        side: &str,
+        quantity: f64,
+        reduce_only: bool,
+        client_order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        self.order_client
+            .place_futures_order(
+                symbol,
+                side,
+                quantity,
+                None,
+                PlaceFuturesOrderOptions {
+                    reduce_only,
+                    client_order_id: Some(client_order_id.to_string()),
+                },
+            )
+            .await
+    }
+
+    /// client order id로 스팟 주문 조회 (접수되지 않았으면 None)
+    pub async fn find_spot_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.order_client
+            .find_spot_order(symbol, client_order_id)
+            .await
+    }
+
+    /// client order id로 선물 주문 조회 (접수되지 않았으면 None)
+    pub async fn find_futures_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<OrderResponse>, ExchangeError> {
+        self.order_client
+            .find_futures_order(symbol, client_order_id)
+            .await
+    }
+
+    /// 거래소 order id로 스팟 주문 취소
+    pub async fn cancel_spot_order(
+        &self,
+        symbol: &str,
+        order_id: u64,
+    ) -> Result<(), ExchangeError> {
+        self.order_client
+            .cancel_spot_order(symbol, &order_id.to_string())
+            .await
+    }
+
+    /// 거래소 order id로 선물 주문 취소
+    pub async fn cancel_futures_order(
+        &self,
+        symbol: &str,
+        order_id: u64,
+    ) -> Result<(), ExchangeError> {
+        self.order_client
+            .cancel_futures_order(symbol, &order_id.to_string())
+            .await
+    }
+
     /// User Data Stream 시작 및 이벤트 수신
     pub async fn start_user_data_stream<F>(&self, event_handler: F) -> Result<(), ExchangeError>
     where
@@ -264,6 +556,23 @@ impl BinanceTrader {
             ))
         }
     }
+
+    /// 선물 계정 User Data Stream 시작 및 이벤트 수신 (ORDER_TRADE_UPDATE, ACCOUNT_UPDATE)
+    pub async fn start_futures_user_data_stream<F>(
+        &self,
+        event_handler: F,
+    ) -> Result<(), ExchangeError>
+    where
+        F: FnMut(FuturesUserDataEvent) + Send + 'static,
+    {
+        if let Some(user_stream) = &self.futures_user_stream {
+            user_stream.start(event_handler).await
+        } else {
+            Err(ExchangeError::Other(
+                "Futures user stream not initialized".to_string(),
+            ))
+        }
+    }
 }
 
 #[async_trait]
@@ -272,28 +581,40 @@ impl SpotExchangeTrader for BinanceTrader {
         self.load_spot_exchange_info().await
     }
 
-    async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
-        self.get_spot_price(symbol).await
+    async fn get_spot_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        self.get_spot_price(symbol).await.map(Price::new)
     }
 
-    fn clamp_spot_quantity(&self, symbol: &str, qty: f64) -> f64 {
-        self.clamp_spot_quantity(symbol, qty)
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        Qty::new(self.clamp_spot_quantity(symbol, qty.value()))
     }
 
-    async fn buy_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
+    async fn buy_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
         self.order_client
-            .place_spot_order(symbol, "BUY", qty, None, PlaceOrderOptions { test: false })
+            .place_spot_order(
+                symbol,
+                "BUY",
+                qty.value(),
+                None,
+                PlaceOrderOptions::default(),
+            )
             .await
     }
 
-    async fn sell_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
+    async fn sell_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
         self.order_client
-            .place_spot_order(symbol, "SELL", qty, None, PlaceOrderOptions { test: false })
+            .place_spot_order(
+                symbol,
+                "SELL",
+                qty.value(),
+                None,
+                PlaceOrderOptions::default(),
+            )
             .await
     }
 
-    async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
-        self.get_spot_balance(asset).await
+    async fn get_spot_balance(&self, asset: &str) -> Result<Qty, ExchangeError> {
+        self.get_spot_balance(asset).await.map(Qty::new)
     }
 }
 
@@ -312,27 +633,34 @@ impl FuturesExchangeTrader for BinanceTrader {
         self.futures.ensure_setup(symbol, leverage, isolated).await
     }
 
-    async fn get_mark_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
-        self.get_futures_mark_price(symbol).await
+    async fn get_mark_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        self.get_futures_mark_price(symbol).await.map(Price::new)
+    }
+
+    async fn get_funding_rate(&self, symbol: &str) -> Result<Option<f64>, ExchangeError> {
+        self.futures.get_funding_rate(symbol).await.map(Some)
     }
 
-    fn clamp_futures_quantity(&self, symbol: &str, qty: f64) -> f64 {
-        self.clamp_futures_quantity(symbol, qty)
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        Qty::new(self.clamp_futures_quantity(symbol, qty.value()))
     }
 
     async fn buy_futures(
         &self,
         symbol: &str,
-        qty: f64,
+        qty: Qty,
         reduce_only: bool,
     ) -> Result<OrderResponse, ExchangeError> {
         self.order_client
             .place_futures_order(
                 symbol,
                 "BUY",
-                qty,
+                qty.value(),
                 None,
-                PlaceFuturesOrderOptions { reduce_only },
+                PlaceFuturesOrderOptions {
+                    reduce_only,
+                    ..Default::default()
+                },
             )
             .await
     }
@@ -340,19 +668,127 @@ impl FuturesExchangeTrader for BinanceTrader {
     async fn sell_futures(
         &self,
         symbol: &str,
-        qty: f64,
+        qty: Qty,
         reduce_only: bool,
     ) -> Result<OrderResponse, ExchangeError> {
         self.order_client
             .place_futures_order(
                 symbol,
                 "SELL",
-                qty,
+                qty.value(),
                 None,
-                PlaceFuturesOrderOptions { reduce_only },
+                PlaceFuturesOrderOptions {
+                    reduce_only,
+                    ..Default::default()
+                },
             )
             .await
     }
-
 }
 
+#[async_trait]
+impl ExchangeOrderApi for BinanceTrader {
+    fn exchange(&self) -> ExchangeId {
+        ExchangeId::Binance
+    }
+
+    async fn load_instruments(&self, market: MarketKind) -> Result<(), ExchangeError> {
+        match market {
+            MarketKind::Spot => self.load_spot_exchange_info().await,
+            MarketKind::Futures => self.load_futures_exchange_info().await,
+        }
+    }
+
+    fn clamp_quantity(&self, market: MarketKind, symbol: &str, qty: f64) -> f64 {
+        match market {
+            MarketKind::Spot => self.clamp_spot_quantity(symbol, qty),
+            MarketKind::Futures => self.clamp_futures_quantity(symbol, qty),
+        }
+    }
+
+    async fn get_price(&self, market: MarketKind, symbol: &str) -> Result<f64, ExchangeError> {
+        match market {
+            MarketKind::Spot => self.get_spot_price(symbol).await,
+            MarketKind::Futures => self.get_futures_mark_price(symbol).await,
+        }
+    }
+
+    async fn set_leverage(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        self.futures.ensure_setup(symbol, leverage, isolated).await
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        match request.market {
+            MarketKind::Spot => {
+                self.order_client
+                    .place_spot_order(
+                        &request.symbol,
+                        request.side.as_str(),
+                        request.qty,
+                        request.price,
+                        PlaceOrderOptions::default(),
+                    )
+                    .await
+            }
+            MarketKind::Futures => {
+                self.order_client
+                    .place_futures_order(
+                        &request.symbol,
+                        request.side.as_str(),
+                        request.qty,
+                        request.price,
+                        PlaceFuturesOrderOptions {
+                            reduce_only: request.reduce_only,
+                            ..Default::default()
+                        },
+                    )
+                    .await
+            }
+        }
+    }
+
+    async fn cancel_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        match market {
+            MarketKind::Spot => self.order_client.cancel_spot_order(symbol, order_id).await,
+            MarketKind::Futures => {
+                self.order_client
+                    .cancel_futures_order(symbol, order_id)
+                    .await
+            }
+        }
+    }
+
+    async fn query_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        match market {
+            MarketKind::Spot => self.order_client.query_spot_order(symbol, order_id).await,
+            MarketKind::Futures => {
+                self.order_client
+                    .query_futures_order(symbol, order_id)
+                    .await
+            }
+        }
+    }
+
+    /// 선물 잔고는 USDT 마진 기준이므로 asset과 무관하게 USDT 잔고 반환
+    async fn get_balance(&self, market: MarketKind, asset: &str) -> Result<f64, ExchangeError> {
+        match market {
+            MarketKind::Spot => self.get_spot_balance(asset).await,
+            MarketKind::Futures => self.get_futures_balance().await,
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use exchanges::BinanceClient;
+use exchanges::binance::{generate_signature, get_timestamp};
+use exchanges::weight::TrackedSend;
+use interface::ExchangeError;
+
+const SAPI_BASE_URL: &str = "https://api.binance.com";
+
+/// 지갑 종류 (이체 출발지/도착지)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Wallet {
+    /// 현물 지갑
+    Spot,
+    /// USDⓈ-M 선물 지갑
+    UsdtFutures,
+}
+
+impl Wallet {
+    /// sub-account universalTransfer의 fromAccountType/toAccountType 값
+    fn account_type(&self) -> &'static str {
+        match self {
+            Wallet::Spot => "SPOT",
+            Wallet::UsdtFutures => "USDT_FUTURE",
+        }
+    }
+}
+
+impl FromStr for Wallet {
+    type Err = ExchangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "spot" => Ok(Wallet::Spot),
+            "futures" | "usdt_futures" | "um" => Ok(Wallet::UsdtFutures),
+            other => Err(ExchangeError::Other(format!("Unknown wallet: {}", other))),
+        }
+    }
+}
+
+impl fmt::Display for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Wallet::Spot => write!(f, "spot"),
+            Wallet::UsdtFutures => write!(f, "futures"),
+        }
+    }
+}
+
+/// 같은 계정 내 지갑 간 이체 종류 (POST /sapi/v1/asset/transfer 의 type)
+pub fn universal_transfer_type(from: Wallet, to: Wallet) -> Result<&'static str, ExchangeError> {
+    match (from, to) {
+        (Wallet::Spot, Wallet::UsdtFutures) => Ok("MAIN_UMFUTURE"),
+        (Wallet::UsdtFutures, Wallet::Spot) => Ok("UMFUTURE_MAIN"),
+        _ => Err(ExchangeError::Other(format!(
+            "Transfer from {} to {} is not supported",
+            from, to
+        ))),
+    }
+}
+
+/// 이체 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResponse {
+    pub tran_id: i64,
+}
+
+/// 마스터 ↔ 서브 계정 이체 요청 (마스터 계정 API 키로만 호출 가능)
+/// 이메일이 None이면 마스터 계정을 의미한다
+#[derive(Debug, Clone)]
+pub struct SubAccountTransfer {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_wallet: Wallet,
+    pub to_wallet: Wallet,
+    pub asset: String,
+    pub amount: f64,
+}
+
+/// 같은 계정 내 지갑 간 이체
+pub async fn universal_transfer(
+    client: &BinanceClient,
+    from: Wallet,
+    to: Wallet,
+    asset: &str,
+    amount: f64,
+) -> Result<TransferResponse, ExchangeError> {
+    let transfer_type = universal_transfer_type(from, to)?;
+    let params = format!(
+        "type={}&asset={}&amount={}",
+        transfer_type,
+        asset,
+        format_amount(amount)
+    );
+    signed_post(client, "/sapi/v1/asset/transfer", &params).await
+}
+
+/// 마스터 ↔ 서브 계정 이체 (POST /sapi/v1/sub-account/universalTransfer)
+pub async fn sub_account_transfer(
+    client: &BinanceClient,
+    request: &SubAccountTransfer,
+) -> Result<TransferResponse, ExchangeError> {
+    let mut params = String::new();
+    if let Some(email) = &request.from_email {
+        params.push_str(&format!("fromEmail={}&", encode_query_value(email)));
+    }
+    if let Some(email) = &request.to_email {
+        params.push_str(&format!("toEmail={}&", encode_query_value(email)));
+    }
+    params.push_str(&format!(
+        "fromAccountType={}&toAccountType={}&asset={}&amount={}",
+        request.from_wallet.account_type(),
+        request.to_wallet.account_type(),
+        request.asset,
+        format_amount(request.amount)
+    ));
+    signed_post(client, "/sapi/v1/sub-account/universalTransfer", &params).await
+}
+
+/// 쿼리 값 퍼센트 인코딩 (RFC 3986 비예약 문자만 그대로)
+/// 인코딩하지 않으면 이메일의 `+`가 공백으로 해석되어 서명 검증이 실패한다
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn format_amount(amount: f64) -> String {
+    let s = format!("{:.8}", amount);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// 출금 주소록(화이트리스트)에 등록된 주소
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawAddress {
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: String,
+    pub coin: String,
+    #[serde(default)]
+    pub name: String,
+    pub network: String,
+    /// 화이트리스트(출금 주소 제한) 등록 여부
+    #[serde(default)]
+    pub white_status: bool,
+}
+
+/// 출금 주소록 조회 (GET /sapi/v1/capital/withdraw/address/list)
+pub async fn withdraw_address_list(
+    client: &BinanceClient,
+) -> Result<Vec<WithdrawAddress>, ExchangeError> {
+    signed_request(
+        client,
+        reqwest::Method::GET,
+        "/sapi/v1/capital/withdraw/address/list",
+        "",
+    )
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+struct WithdrawApplyResponse {
+    id: String,
+}
+
+/// 출금 신청 (POST /sapi/v1/capital/withdraw/apply), 출금 ID 반환
+/// 주소 검증 없이 바로 호출하지 말고 `address_book::withdraw_whitelisted`를 거친다
+pub(crate) async fn withdraw_apply(
+    client: &BinanceClient,
+    coin: &str,
+    network: &str,
+    address: &str,
+    address_tag: Option<&str>,
+    amount: f64,
+) -> Result<String, ExchangeError> {
+    let mut params = format!(
+        "coin={}&network={}&address={}&amount={}",
+        coin, network, address, amount
+    );
+    if let Some(tag) = address_tag {
+        params.push_str(&format!("&addressTag={}", tag));
+    }
+    let response: WithdrawApplyResponse =
+        signed_post(client, "/sapi/v1/capital/withdraw/apply", &params).await?;
+    Ok(response.id)
+}
+
+/// 서명된 SAPI POST 요청 (이체/더스트 변환 공용)
+pub(super) async fn signed_post<T: DeserializeOwned>(
+    client: &BinanceClient,
+    endpoint: &str,
+    params: &str,
+) -> Result<T, ExchangeError> {
+    signed_request(client, reqwest::Method::POST, endpoint, params).await
+}
+
+/// 서명된 SAPI 요청
+async fn signed_request<T: DeserializeOwned>(
+    client: &BinanceClient,
+    method: reqwest::Method,
+    endpoint: &str,
+    params: &str,
+) -> Result<T, ExchangeError> {
+    let api_key = client
+        .api_key
+        .as_ref()
+        .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+    let api_secret = client
+        .api_secret
+        .as_ref()
+        .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+
+    let mut query_string = format!("timestamp={}&recvWindow=50000", get_timestamp());
+    if !params.is_empty() {
+        query_string = format!("{}&{}", params, query_string);
+    }
+    let signature = generate_signature(&query_string, api_secret);
+    let url = format!(
+        "{}{}?{}&signature={}",
+        SAPI_BASE_URL, endpoint, query_string, signature
+    );
+
+    let response = client
+        .http
+        .request(method, &url)
+        .header("X-MBX-APIKEY", api_key.as_str())
+        .send_tracked()
+        .await
+        .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+
+    let status = response.status();
+    let response_text = response.text().await?;
+
+    if !status.is_success() {
+        return Err(ExchangeError::Other(format!(
+            "Transfer API error: status {}, response: {}",
+            status,
+            response_text.chars().take(200).collect::<String>()
+        )));
+    }
+
+    serde_json::from_str(&response_text)
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse transfer response: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_universal_transfer_type() {
+        assert_eq!(
+            universal_transfer_type(Wallet::Spot, Wallet::UsdtFutures).unwrap(),
+            "MAIN_UMFUTURE"
+        );
+        assert_eq!(
+            universal_transfer_type(Wallet::UsdtFutures, Wallet::Spot).unwrap(),
+            "UMFUTURE_MAIN"
+        );
+        assert!(universal_transfer_type(Wallet::Spot, Wallet::Spot).is_err());
+    }
+
+    #[test]
+    fn test_wallet_parse_and_amount_format() {
+        assert_eq!("futures".parse::<Wallet>().unwrap(), Wallet::UsdtFutures);
+        assert_eq!("SPOT".parse::<Wallet>().unwrap(), Wallet::Spot);
+        assert!("margin".parse::<Wallet>().is_err());
+
+        assert_eq!(format_amount(10.0), "10");
+        assert_eq!(format_amount(0.12345678), "0.12345678");
+        assert_eq!(format_amount(1.5), "1.5");
+    }
+
+    #[test]
+    fn test_email_is_percent_encoded() {
+        assert_eq!(
+            encode_query_value("ops+sub1@example.com"),
+            "ops%2Bsub1%40example.com"
+        );
+        assert_eq!(encode_query_value("a.b_c-d~e"), "a.b_c-d~e");
+    }
+}
@@ -16,12 +16,16 @@ pub struct OrderResponse {
 #[derive(Debug, Clone, Default)]
 pub struct PlaceOrderOptions {
     pub test: bool,
+    /// newClientOrderId (None이면 거래소가 생성). 재시도 시 같은 주문인지 조회하는 데 쓴다
+    pub client_order_id: Option<String>,
 }
 
 /// 주문 옵션 (Futures 주문용)
 #[derive(Debug, Clone, Default)]
 pub struct PlaceFuturesOrderOptions {
     pub reduce_only: bool,
+    /// newClientOrderId (None이면 거래소가 생성)
+    pub client_order_id: Option<String>,
 }
 
 /// Binance LOT_SIZE 필터 정보
@@ -32,6 +36,31 @@ pub struct LotSizeFilter {
     pub step_size: f64,
 }
 
+/// exchangeInfo 심볼 거래 상태 (현물: TRADING/BREAK/HALT, 선물: TRADING/SETTLING/PENDING_TRADING/CLOSE 등)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolStatus(pub String);
+
+impl SymbolStatus {
+    pub fn new(status: impl Into<String>) -> Self {
+        Self(status.into())
+    }
+
+    /// 주문 가능한 상태인지
+    pub fn is_trading(&self) -> bool {
+        self.0 == "TRADING"
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for SymbolStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 /// 실시간 가격 상태 (WebSocket에서 업데이트)
 #[derive(Debug, Clone, Default)]
 pub struct PriceState {
@@ -40,6 +69,15 @@ pub struct PriceState {
     pub last_updated: Option<std::time::SystemTime>,
 }
 
+/// 최우선 호가 (bookTicker)
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct BookTop {
+    pub bid_price: f64,
+    pub bid_qty: f64,
+    pub ask_price: f64,
+    pub ask_qty: f64,
+}
+
 /// 헤지된 주문 쌍
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct HedgedPair {
@@ -53,6 +91,18 @@ pub struct HedgedPair {
     pub delta_est: f64,
 }
 
+impl HedgedPair {
+    /// 양쪽 레그가 같은 수량으로 체결된 쌍 (수수료 차감 추정 없음)
+    pub fn filled(qty: f64) -> Self {
+        Self {
+            spot_order_qty: qty,
+            fut_order_qty: qty,
+            spot_net_qty_est: qty,
+            delta_est: 0.0,
+        }
+    }
+}
+
 /// LOT_SIZE 필터를 사용하여 수량을 clamp하는 헬퍼 함수
 pub fn clamp_quantity_with_filter(filter: LotSizeFilter, qty: f64) -> f64 {
     const BASE_PRECISION: u32 = 8;
@@ -1,135 +1,86 @@
-use std::collections::BTreeMap;
-use futures_util::{SinkExt, StreamExt};
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
-use tracing::{error, info, warn};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::{info, warn};
 
-use exchanges::binance::{generate_signature, get_timestamp};
 use exchanges::BinanceClient;
-use interface::ExchangeError;
+use exchanges::binance::{generate_signature, get_timestamp};
+use exchanges::ws::{
+    ConnectionState, Heartbeat, PingMessage, ReconnectConfig, ReconnectingClient, WsHandler,
+};
+use interface::{ExchangeError, ExchangeId};
 
 const WS_API_URL: &str = "wss://ws-api.binance.com/ws-api/v3";
+const SUBSCRIBE_REQUEST_ID: &str = "user-stream-1";
 
 /// Binance User Stream: User Data Stream WebSocket 관리
 pub struct BinanceUserStream {
     spot_client: BinanceClient,
+    /// 로그와 상태 레지스트리에 표시할 계정 라벨
+    label: String,
 }
 
 impl BinanceUserStream {
     pub fn new(spot_client: BinanceClient) -> Self {
-        Self { spot_client }
+        Self {
+            spot_client,
+            label: super::account::DEFAULT_ACCOUNT_LABEL.to_string(),
+        }
     }
 
-    /// User Data Stream 시작 및 이벤트 수신
-    pub async fn start<F>(&self, mut event_handler: F) -> Result<(), ExchangeError>
-    where
-        F: FnMut(UserDataEvent) + Send + 'static,
-    {
-        loop {
-            match self.connect(&mut event_handler).await {
-                Ok(_) => {
-                    warn!("User Data Stream WebSocket 연결이 종료되었습니다. 재연결 시도...");
-                }
-                Err(e) => {
-                    error!("User Data Stream WebSocket 오류: {:?}. 재연결 시도...", e);
-                }
-            }
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
 
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-        }
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 구독 서명에 필요한 API 키/시크릿이 있는지
+    pub fn has_credentials(&self) -> bool {
+        self.spot_client.api_key.is_some() && self.spot_client.api_secret.is_some()
     }
 
-    /// WebSocket 연결 및 메시지 수신
-    async fn connect<F>(&self, event_handler: &mut F) -> Result<(), ExchangeError>
+    /// User Data Stream 시작 및 이벤트 수신
+    /// 연결이 끊기면 지수 백오프로 재연결하고, 재연결마다 새 서명으로 재구독한다
+    pub async fn start<F>(&self, event_handler: F) -> Result<(), ExchangeError>
     where
         F: FnMut(UserDataEvent) + Send + 'static,
     {
         let api_key = self
             .spot_client
             .api_key
-            .as_ref()
+            .clone()
             .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
         let api_secret = self
             .spot_client
             .api_secret
-            .as_ref()
+            .clone()
             .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
 
-        let (ws_stream, _) = connect_async(WS_API_URL)
-            .await
-            .map_err(|e| ExchangeError::Other(format!("WebSocket 연결 실패: {}", e)))?;
-
-        let (mut write, mut read) = ws_stream.split();
-
-        info!("User Data Stream WebSocket 연결 성공: {}", WS_API_URL);
-
-        // 구독 요청 전송
-        let _request_id = Self::subscribe_user_data_stream(&mut write, api_key, api_secret).await?;
-
-        // 구독 응답 대기
-        if let Some(msg) = read.next().await {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    info!("Subscribe response: {}", text);
-                    let response: WsResponse = serde_json::from_str(&text)
-                        .map_err(|e| ExchangeError::Other(format!("응답 파싱 실패: {}", e)))?;
-
-                    if let Some(error) = response.error {
-                        return Err(ExchangeError::Other(format!(
-                            "구독 실패: code={:?}, msg={:?}",
-                            error.code, error.msg
-                        )));
-                    }
-
-                    if let Some(result) = response.result {
-                        info!(
-                            "구독 성공: subscriptionId={:?}",
-                            result.get("subscriptionId")
-                        );
-                    }
-                }
-                Ok(Message::Close(_)) => {
-                    warn!("WebSocket 연결이 닫혔습니다");
-                    return Ok(());
-                }
-                Err(e) => {
-                    return Err(ExchangeError::Other(format!(
-                        "구독 응답 수신 오류: {:?}",
-                        e
-                    )));
-                }
-                _ => {}
-            }
-        }
-
-        info!("User Data Stream 이벤트 수신 대기 중...");
-
-        // 이벤트 수신 루프
-        while let Some(msg) = read.next().await {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    if let Err(e) = Self::handle_user_data_message(&text, event_handler) {
-                        warn!("메시지 처리 오류: {:?}", e);
-                    }
-                }
-                Ok(Message::Close(_)) => {
-                    warn!("WebSocket 연결이 닫혔습니다");
-                    break;
-                }
-                Ok(Message::Ping(data)) => {
-                    // Ping에 대한 Pong 응답
-                    if let Err(e) = write.send(Message::Pong(data)).await {
-                        error!("Pong 전송 실패: {:?}", e);
-                        break;
-                    }
-                }
-                Err(e) => {
-                    error!("WebSocket 메시지 수신 오류: {:?}", e);
-                    break;
-                }
-                _ => {}
-            }
-        }
+        let client =
+            ReconnectingClient::new(&format!("User Data Stream ({})", self.label), WS_API_URL)
+                .with_config(ReconnectConfig {
+                    heartbeat: Some(Heartbeat {
+                        interval: Duration::from_secs(30),
+                        timeout: Duration::from_secs(90),
+                        message: PingMessage::Frame,
+                    }),
+                    ..Default::default()
+                })
+                .with_status(
+                    ExchangeId::Binance,
+                    &format!("user_stream_ws:{}", self.label),
+                );
+
+        let mut handler = UserStreamHandler {
+            api_key,
+            api_secret,
+            event_handler,
+        };
+        client.run(&mut handler).await;
 
         Ok(())
     }
@@ -150,16 +101,8 @@ impl BinanceUserStream {
         generate_signature(&payload, secret)
     }
 
-    /// User Data Stream 구독 요청 전송
-    async fn subscribe_user_data_stream<S>(
-        write: &mut S,
-        api_key: &str,
-        api_secret: &str,
-    ) -> Result<String, ExchangeError>
-    where
-        S: SinkExt<Message> + Unpin,
-        <S as futures_util::Sink<Message>>::Error: std::fmt::Debug,
-    {
+    /// User Data Stream 구독 요청 메시지 생성
+    fn build_subscribe_request(api_key: &str, api_secret: &str) -> Result<String, ExchangeError> {
         let timestamp = get_timestamp().to_string();
 
         let mut params = BTreeMap::new();
@@ -172,7 +115,7 @@ impl BinanceUserStream {
         params.insert("signature".to_string(), signature);
 
         let request = WsRequest {
-            id: "user-stream-1".to_string(),
+            id: SUBSCRIBE_REQUEST_ID.to_string(),
             method: "userDataStream.subscribe.signature".to_string(),
             params,
         };
@@ -182,11 +125,27 @@ impl BinanceUserStream {
 
         info!("Sending subscribe request: {}", request_json);
 
-        write.send(Message::Text(request_json)).await.map_err(|e| {
-            ExchangeError::Other(format!("Failed to send subscribe request: {:?}", e))
-        })?;
+        Ok(request_json)
+    }
+
+    /// 구독 요청에 대한 응답 확인
+    /// 에러 응답이면 Err를 반환해 재연결하도록 한다
+    fn check_subscribe_response(response: &WsResponse) -> Result<(), ExchangeError> {
+        if let Some(error) = &response.error {
+            return Err(ExchangeError::Other(format!(
+                "구독 실패: code={:?}, msg={:?}",
+                error.code, error.msg
+            )));
+        }
+
+        if let Some(result) = &response.result {
+            info!(
+                "구독 성공: subscriptionId={:?}",
+                result.get("subscriptionId")
+            );
+        }
 
-        Ok("user-stream-1".to_string())
+        Ok(())
     }
 
     /// 메시지 처리 및 이벤트 파싱
@@ -255,6 +214,46 @@ impl BinanceUserStream {
     }
 }
 
+/// ReconnectingClient 용 User Data Stream 핸들러
+struct UserStreamHandler<F> {
+    api_key: String,
+    api_secret: String,
+    event_handler: F,
+}
+
+#[async_trait]
+impl<F> WsHandler for UserStreamHandler<F>
+where
+    F: FnMut(UserDataEvent) + Send + 'static,
+{
+    async fn subscriptions(&mut self) -> eyre::Result<Vec<String>> {
+        let request = BinanceUserStream::build_subscribe_request(&self.api_key, &self.api_secret)?;
+        Ok(vec![request])
+    }
+
+    async fn on_message(&mut self, text: &str) -> eyre::Result<()> {
+        // 구독 요청에 대한 응답
+        if let Ok(response) = serde_json::from_str::<WsResponse>(text)
+            && response.id.as_deref() == Some(SUBSCRIBE_REQUEST_ID)
+        {
+            info!("Subscribe response: {}", text);
+            BinanceUserStream::check_subscribe_response(&response)?;
+            return Ok(());
+        }
+
+        if let Err(e) = BinanceUserStream::handle_user_data_message(text, &mut self.event_handler) {
+            warn!("메시지 처리 오류: {:?}", e);
+        }
+        Ok(())
+    }
+
+    fn on_state_change(&mut self, state: ConnectionState) {
+        if state == ConnectionState::Connected {
+            info!("User Data Stream 이벤트 수신 대기 중...");
+        }
+    }
+}
+
 // ========== User Data Stream 관련 타입 정의 ==========
 
 /// WebSocket API 요청 메시지
@@ -425,4 +424,3 @@ pub struct BalanceUpdate {
     #[serde(rename = "w")]
     pub wallet_type: Option<String>,
 }
-
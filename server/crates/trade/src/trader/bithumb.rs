@@ -5,15 +5,17 @@ use hmac::{Hmac, Mac};
 use serde::Deserialize;
 use serde_json::Value;
 use sha2::Sha512;
+use std::time::Instant;
 use tracing::{info, warn};
 
 use exchanges::{
     AssetExchange,
     bithumb::{self, BASE_URL, BithumbClient},
 };
-use interface::ExchangeError;
+use interface::{ExchangeError, Price, Qty};
 
 use super::{OrderResponse, SpotExchangeTrader};
+use crate::latency::latency_tracker;
 
 type HmacSha512 = Hmac<Sha512>;
 
@@ -29,40 +31,40 @@ impl SpotExchangeTrader for BithumbTrader {
         Ok(())
     }
 
-    async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
-        self.fetch_price(symbol).await
+    async fn get_spot_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        self.fetch_price(symbol).await.map(Price::new)
     }
 
-    fn clamp_spot_quantity(&self, symbol: &str, qty: f64) -> f64 {
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Qty) -> Qty {
         let step = Self::step_size_for(&symbol.to_uppercase());
-        let clamped = Self::clamp_quantity(qty, step);
+        let clamped = Self::clamp_quantity(qty.value(), step);
         if clamped <= 0.0 {
             warn!(
                 "Quantity too small after clamp for {} (step {}). Requested: {}",
                 symbol, step, qty
             );
         }
-        clamped
+        Qty::new(clamped)
     }
 
-    async fn buy_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
-        self.place_market_order(symbol, qty, MARKET_BUY_ENDPOINT)
+    async fn buy_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        self.place_market_order(symbol, qty.value(), MARKET_BUY_ENDPOINT)
             .await
     }
 
-    async fn sell_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
-        self.place_market_order(symbol, qty, MARKET_SELL_ENDPOINT)
+    async fn sell_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        self.place_market_order(symbol, qty.value(), MARKET_SELL_ENDPOINT)
             .await
     }
 
-    async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
+    async fn get_spot_balance(&self, asset: &str) -> Result<Qty, ExchangeError> {
         let assets = self.client.fetch_spots().await?;
         let target = asset.to_uppercase();
         Ok(assets
             .iter()
             .find(|a| a.currency == target)
-            .map(|a| a.available)
-            .unwrap_or(0.0))
+            .map(|a| Qty::new(a.available))
+            .unwrap_or(Qty::ZERO))
     }
 }
 
@@ -83,7 +85,7 @@ impl BithumbTrader {
 
         Ok(Self {
             client,
-            http: reqwest::Client::new(),
+            http: exchanges::http::http_client(),
             api_key,
             api_secret,
         })
@@ -216,7 +218,9 @@ impl BithumbTrader {
             base, quote, qty
         );
 
+        let started = Instant::now();
         let data = self.post_private(endpoint, &params).await?;
+        latency_tracker().record_order_ack("bithumb", started.elapsed());
 
         let order_id = data
             .get("order_id")
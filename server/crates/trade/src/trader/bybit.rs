@@ -0,0 +1,521 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use exchanges::binance::get_timestamp;
+use hmac::{Hmac, Mac};
+use reqwest::Method;
+use serde_json::{Value, json};
+use sha2::Sha256;
+use tracing::{info, warn};
+
+use interface::{ExchangeError, ExchangeId, TransferStatus};
+
+use super::OrderResponse;
+use super::binance::{LotSizeFilter, clamp_quantity_with_filter};
+use super::order_api::{ExchangeOrderApi, MarketKind, OrderRequest, OrderSide};
+use crate::latency::latency_tracker;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const BASE_URL: &str = "https://api.bybit.com";
+const RECV_WINDOW: &str = "5000";
+/// 레버리지가 이미 같은 값이면 반환되는 코드
+const LEVERAGE_NOT_MODIFIED: i64 = 110043;
+/// 마진 모드가 이미 같은 값이면 반환되는 코드
+const MARGIN_MODE_NOT_MODIFIED: i64 = 110026;
+
+/// Bybit v5 통합 계정 주문 API (spot + USDT 무기한)
+pub struct BybitOrderApi {
+    http: reqwest::Client,
+    api_key: String,
+    api_secret: String,
+    lot_sizes: RwLock<HashMap<(MarketKind, String), LotSizeFilter>>,
+}
+
+impl BybitOrderApi {
+    pub fn new(api_key: String, api_secret: String) -> Self {
+        Self {
+            http: exchanges::http::http_client(),
+            api_key,
+            api_secret,
+            lot_sizes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// BYBIT_API_KEY / BYBIT_API_SECRET 환경 변수 사용
+    pub fn from_env() -> Result<Self, ExchangeError> {
+        let api_key = std::env::var("BYBIT_API_KEY")
+            .map_err(|e| ExchangeError::Other(format!("BYBIT_API_KEY not found: {}", e)))?;
+        let api_secret = std::env::var("BYBIT_API_SECRET")
+            .map_err(|e| ExchangeError::Other(format!("BYBIT_API_SECRET not found: {}", e)))?;
+        Ok(Self::new(api_key, api_secret))
+    }
+
+    fn category(market: MarketKind) -> &'static str {
+        match market {
+            MarketKind::Spot => "spot",
+            MarketKind::Futures => "linear",
+        }
+    }
+
+    /// v5 서명: HMAC_SHA256(timestamp + api_key + recv_window + (query | body))
+    fn sign(&self, timestamp: &str, payload: &str) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("{}{}{}{}", timestamp, self.api_key, RECV_WINDOW, payload).as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// 요청 전송 후 retCode 확인, `result` 반환
+    /// GET은 payload를 쿼리 문자열로, POST는 JSON 본문으로 보낸다.
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        payload: &str,
+        signed: bool,
+    ) -> Result<Value, ExchangeError> {
+        let is_get = method == Method::GET;
+        let url = if is_get && !payload.is_empty() {
+            format!("{}{}?{}", BASE_URL, path, payload)
+        } else {
+            format!("{}{}", BASE_URL, path)
+        };
+
+        let mut builder = self.http.request(method, &url);
+        if signed {
+            let timestamp = get_timestamp().to_string();
+            builder = builder
+                .header("X-BAPI-API-KEY", &self.api_key)
+                .header("X-BAPI-TIMESTAMP", &timestamp)
+                .header("X-BAPI-RECV-WINDOW", RECV_WINDOW)
+                .header("X-BAPI-SIGN", self.sign(&timestamp, payload));
+        }
+        if !is_get {
+            builder = builder
+                .header("Content-Type", "application/json")
+                .body(payload.to_string());
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        if !status.is_success() {
+            return Err(ExchangeError::Other(format!(
+                "Bybit API HTTP error: status {}, response: {}",
+                status,
+                body.chars().take(200).collect::<String>()
+            )));
+        }
+
+        let parsed: Value = serde_json::from_str(&body)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse Bybit response: {}", e)))?;
+        let ret_code = parsed.get("retCode").and_then(|v| v.as_i64()).unwrap_or(-1);
+        if ret_code != 0 {
+            return Err(ExchangeError::Other(format!(
+                "Bybit API error {}: {}",
+                ret_code,
+                parsed
+                    .get("retMsg")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+            )));
+        }
+
+        Ok(parsed.get("result").cloned().unwrap_or(Value::Null))
+    }
+
+    fn ret_code_of(err: &ExchangeError) -> Option<i64> {
+        let ExchangeError::Other(msg) = err else {
+            return None;
+        };
+        msg.strip_prefix("Bybit API error ")?
+            .split(':')
+            .next()?
+            .parse()
+            .ok()
+    }
+
+    fn parse_order(symbol: &str, data: &Value) -> OrderResponse {
+        let order_id = data
+            .get("orderId")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        OrderResponse {
+            symbol: symbol.to_string(),
+            order_id: order_id.as_deref().and_then(|s| s.parse::<u64>().ok()),
+            client_order_id: data
+                .get("orderLinkId")
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .or(order_id),
+            executed_qty: data
+                .get("cumExecQty")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            status: data
+                .get("orderStatus")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            extra: data.clone(),
+        }
+    }
+
+    /// 자산 입출금 가능 여부 (`/v5/asset/coin/query-info`, 체인 중 하나라도 열려 있으면 가능)
+    pub async fn transfer_status(&self, asset: &str) -> Result<TransferStatus, ExchangeError> {
+        let asset = asset.to_uppercase();
+        let query = format!("coin={}", asset);
+        let result = self
+            .request(Method::GET, "/v5/asset/coin/query-info", &query, true)
+            .await?;
+        Ok(parse_transfer_status(&asset, &result))
+    }
+}
+
+fn parse_transfer_status(asset: &str, result: &Value) -> TransferStatus {
+    let chains: Vec<&Value> = result
+        .get("rows")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter(|row| row.get("coin").and_then(|v| v.as_str()) == Some(asset))
+        .flat_map(|row| {
+            row.get("chains")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+        })
+        .collect();
+    let enabled = |field: &str| {
+        chains
+            .iter()
+            .any(|chain| chain.get(field).and_then(|v| v.as_str()) == Some("1"))
+    };
+    TransferStatus {
+        currency: asset.to_string(),
+        deposit_enabled: enabled("chainDeposit"),
+        withdraw_enabled: enabled("chainWithdraw"),
+    }
+}
+
+fn parse_f64(value: Option<&Value>) -> Option<f64> {
+    value?.as_str()?.parse::<f64>().ok()
+}
+
+#[async_trait]
+impl ExchangeOrderApi for BybitOrderApi {
+    fn exchange(&self) -> ExchangeId {
+        ExchangeId::Bybit
+    }
+
+    async fn load_instruments(&self, market: MarketKind) -> Result<(), ExchangeError> {
+        let category = Self::category(market);
+        let mut filters = Vec::new();
+        let mut cursor = String::new();
+
+        loop {
+            let mut query = format!("category={}&limit=1000", category);
+            if !cursor.is_empty() {
+                query.push_str(&format!("&cursor={}", cursor));
+            }
+            let result = self
+                .request(Method::GET, "/v5/market/instruments-info", &query, false)
+                .await?;
+
+            for item in result
+                .get("list")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+            {
+                let Some(symbol) = item.get("symbol").and_then(|v| v.as_str()) else {
+                    continue;
+                };
+                let lot = item.get("lotSizeFilter");
+                let step = match market {
+                    MarketKind::Spot => parse_f64(lot.and_then(|l| l.get("basePrecision"))),
+                    MarketKind::Futures => parse_f64(lot.and_then(|l| l.get("qtyStep"))),
+                };
+                let Some(step_size) = step else {
+                    continue;
+                };
+                filters.push((
+                    symbol.to_string(),
+                    LotSizeFilter {
+                        min_qty: parse_f64(lot.and_then(|l| l.get("minOrderQty"))).unwrap_or(0.0),
+                        max_qty: parse_f64(lot.and_then(|l| l.get("maxOrderQty")))
+                            .unwrap_or(f64::MAX),
+                        step_size,
+                    },
+                ));
+            }
+
+            cursor = result
+                .get("nextPageCursor")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            if cursor.is_empty() {
+                break;
+            }
+        }
+
+        info!("Bybit {} instruments loaded: {}", market, filters.len());
+        let mut cache = self.lot_sizes.write().unwrap();
+        cache.retain(|(m, _), _| *m != market);
+        cache.extend(filters.into_iter().map(|(s, f)| ((market, s), f)));
+        Ok(())
+    }
+
+    fn clamp_quantity(&self, market: MarketKind, symbol: &str, qty: f64) -> f64 {
+        let cache = self.lot_sizes.read().unwrap();
+        match cache.get(&(market, symbol.to_uppercase())) {
+            Some(filter) => clamp_quantity_with_filter(*filter, qty),
+            None => {
+                warn!(
+                    "Bybit {} lot size for {} not loaded, using raw quantity",
+                    market, symbol
+                );
+                qty
+            }
+        }
+    }
+
+    async fn get_price(&self, market: MarketKind, symbol: &str) -> Result<f64, ExchangeError> {
+        let query = format!(
+            "category={}&symbol={}",
+            Self::category(market),
+            symbol.to_uppercase()
+        );
+        let result = self
+            .request(Method::GET, "/v5/market/tickers", &query, false)
+            .await?;
+        let ticker = result
+            .get("list")
+            .and_then(|v| v.get(0))
+            .ok_or_else(|| ExchangeError::Other(format!("Bybit ticker not found: {}", symbol)))?;
+        let field = match market {
+            MarketKind::Spot => "lastPrice",
+            MarketKind::Futures => "markPrice",
+        };
+        parse_f64(ticker.get(field))
+            .ok_or_else(|| ExchangeError::Other(format!("Invalid Bybit {}: {}", field, ticker)))
+    }
+
+    async fn set_leverage(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        let symbol = symbol.to_uppercase();
+        let margin = json!({
+            "category": "linear",
+            "symbol": symbol,
+            "tradeMode": if isolated { 1 } else { 0 },
+            "buyLeverage": leverage.to_string(),
+            "sellLeverage": leverage.to_string(),
+        });
+        match self
+            .request(
+                Method::POST,
+                "/v5/position/switch-isolated",
+                &margin.to_string(),
+                true,
+            )
+            .await
+        {
+            Ok(_) => {}
+            Err(e) if Self::ret_code_of(&e) == Some(MARGIN_MODE_NOT_MODIFIED) => {}
+            // 통합 계정은 심볼 단위 마진 모드 변경을 지원하지 않으므로 레버리지만 설정
+            Err(e) => warn!("Bybit margin mode setup skipped for {}: {}", symbol, e),
+        }
+
+        let body = json!({
+            "category": "linear",
+            "symbol": symbol,
+            "buyLeverage": leverage.to_string(),
+            "sellLeverage": leverage.to_string(),
+        });
+        match self
+            .request(
+                Method::POST,
+                "/v5/position/set-leverage",
+                &body.to_string(),
+                true,
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) if Self::ret_code_of(&e) == Some(LEVERAGE_NOT_MODIFIED) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        if request.qty <= 0.0 {
+            return Err(ExchangeError::Other(
+                "Quantity must be positive".to_string(),
+            ));
+        }
+        let symbol = request.symbol.to_uppercase();
+        let mut body = json!({
+            "category": Self::category(request.market),
+            "symbol": symbol,
+            "side": match request.side {
+                OrderSide::Buy => "Buy",
+                OrderSide::Sell => "Sell",
+            },
+            "orderType": if request.price.is_some() { "Limit" } else { "Market" },
+            "qty": format!("{}", request.qty),
+        });
+        if let Some(price) = request.price {
+            body["price"] = json!(format!("{}", price));
+            body["timeInForce"] = json!("GTC");
+        }
+        match request.market {
+            // spot 시장가 매수는 기본이 quote 수량이므로 base 수량으로 지정
+            MarketKind::Spot => body["marketUnit"] = json!("baseCoin"),
+            MarketKind::Futures => body["reduceOnly"] = json!(request.reduce_only),
+        }
+        let payload = body.to_string();
+        info!("Bybit place_order: {}", payload);
+
+        let started = Instant::now();
+        let result = self
+            .request(Method::POST, "/v5/order/create", &payload, true)
+            .await?;
+        latency_tracker().record_order_ack(&format!("bybit_{}", request.market), started.elapsed());
+
+        let order = Self::parse_order(&symbol, &result);
+        match request.market {
+            MarketKind::Spot => {
+                crate::record::save_trade_record_spot_order(
+                    "bybit",
+                    "default",
+                    &symbol,
+                    request.side.as_str(),
+                    request.qty,
+                    &payload,
+                    &order,
+                    false,
+                )
+                .await
+            }
+            MarketKind::Futures => {
+                crate::record::save_trade_record_futures_order(
+                    "bybit",
+                    "default",
+                    &symbol,
+                    request.side.as_str(),
+                    request.qty,
+                    &payload,
+                    &order,
+                    request.reduce_only,
+                    false,
+                )
+                .await
+            }
+        }
+
+        Ok(order)
+    }
+
+    async fn cancel_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        let body = json!({
+            "category": Self::category(market),
+            "symbol": symbol.to_uppercase(),
+            "orderId": order_id,
+        });
+        self.request(Method::POST, "/v5/order/cancel", &body.to_string(), true)
+            .await?;
+        Ok(())
+    }
+
+    async fn query_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let symbol = symbol.to_uppercase();
+        let query = format!(
+            "category={}&symbol={}&orderId={}",
+            Self::category(market),
+            symbol,
+            order_id
+        );
+        let result = self
+            .request(Method::GET, "/v5/order/realtime", &query, true)
+            .await?;
+        let data = result
+            .get("list")
+            .and_then(|v| v.get(0))
+            .ok_or_else(|| ExchangeError::Other(format!("Bybit order not found: {}", order_id)))?;
+        Ok(Self::parse_order(&symbol, data))
+    }
+
+    async fn get_balance(&self, _market: MarketKind, asset: &str) -> Result<f64, ExchangeError> {
+        // 통합 계정은 spot/선물이 같은 지갑을 사용
+        let query = format!("accountType=UNIFIED&coin={}", asset.to_uppercase());
+        let result = self
+            .request(Method::GET, "/v5/account/wallet-balance", &query, true)
+            .await?;
+        let coin = result
+            .get("list")
+            .and_then(|v| v.get(0))
+            .and_then(|a| a.get("coin"))
+            .and_then(|c| c.get(0));
+        let Some(coin) = coin else {
+            return Ok(0.0);
+        };
+        let wallet = parse_f64(coin.get("walletBalance")).unwrap_or(0.0);
+        let locked = parse_f64(coin.get("locked")).unwrap_or(0.0);
+        Ok((wallet - locked).max(0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_transfer_status() {
+        let result = json!({"rows": [{"coin": "USDT", "chains": [
+            {"chain": "ETH", "chainDeposit": "1", "chainWithdraw": "0"},
+            {"chain": "TRX", "chainDeposit": "0", "chainWithdraw": "0"}
+        ]}]});
+        let status = parse_transfer_status("USDT", &result);
+        assert!(status.deposit_enabled);
+        assert!(!status.withdraw_enabled);
+        // 응답에 없는 자산은 중단으로 봄
+        assert!(parse_transfer_status("BTC", &result).is_suspended());
+    }
+
+    #[test]
+    fn test_sign_and_ret_code() {
+        let api = BybitOrderApi::new("key".to_string(), "secret".to_string());
+        let sig = api.sign("1700000000000", "category=spot");
+        assert_eq!(sig.len(), 64);
+        assert_eq!(sig, api.sign("1700000000000", "category=spot"));
+        assert_ne!(sig, api.sign("1700000000001", "category=spot"));
+
+        let err = ExchangeError::Other("Bybit API error 110043: leverage not modified".to_string());
+        assert_eq!(
+            BybitOrderApi::ret_code_of(&err),
+            Some(LEVERAGE_NOT_MODIFIED)
+        );
+    }
+}
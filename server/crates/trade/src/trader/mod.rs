@@ -1,21 +1,34 @@
 pub mod binance;
 pub mod bithumb;
+pub mod bybit;
+pub mod okx;
+pub mod order_api;
+pub mod quote;
 
 use async_trait::async_trait;
-use interface::ExchangeError;
+use interface::{ExchangeError, ExchangeId, Price, Qty};
+
+use crate::events::PositionDirection;
 
 pub use binance::{BinanceTrader, OrderResponse};
 pub use bithumb::BithumbTrader;
+pub use bybit::BybitOrderApi;
+pub use okx::OkxOrderApi;
+pub use order_api::{
+    ContractKind, ExchangeOrderApi, MarketKind, OrderApiTrader, OrderRequest, OrderSide,
+    futures_trader_for, order_api_for, parse_exchange_id, spot_trader_for,
+};
 
 /// 프리미엄 거래소(spot)를 제어하기 위한 공통 인터페이스.
+/// 가격/수량은 단위 타입(`Price`, `Qty`)으로 주고받는다.
 #[async_trait]
 pub trait SpotExchangeTrader: Send + Sync {
     async fn ensure_exchange_info(&self) -> Result<(), ExchangeError>;
-    async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError>;
-    fn clamp_spot_quantity(&self, symbol: &str, qty: f64) -> f64;
-    async fn buy_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError>;
-    async fn sell_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError>;
-    async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError>;
+    async fn get_spot_price(&self, symbol: &str) -> Result<Price, ExchangeError>;
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Qty) -> Qty;
+    async fn buy_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError>;
+    async fn sell_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError>;
+    async fn get_spot_balance(&self, asset: &str) -> Result<Qty, ExchangeError>;
 }
 
 /// 헤지 거래소(선물)를 제어하기 위한 공통 인터페이스.
@@ -28,18 +41,135 @@ pub trait FuturesExchangeTrader: Send + Sync {
         leverage: u32,
         isolated: bool,
     ) -> Result<(), ExchangeError>;
-    async fn get_mark_price(&self, symbol: &str) -> Result<f64, ExchangeError>;
-    fn clamp_futures_quantity(&self, symbol: &str, qty: f64) -> f64;
+    async fn get_mark_price(&self, symbol: &str) -> Result<Price, ExchangeError>;
+    /// 현재 펀딩비. 조회를 지원하지 않는 거래소는 None
+    async fn get_funding_rate(&self, _symbol: &str) -> Result<Option<f64>, ExchangeError> {
+        Ok(None)
+    }
+    /// 계약 종류 (기본: USDT 마진)
+    fn contract_kind(&self) -> ContractKind {
+        ContractKind::Linear
+    }
+    /// 주문이 실제로 나가는 거래소 (진입 시점에 거래소를 바꾸는 트레이더만 Some)
+    fn venue(&self) -> Option<ExchangeId> {
+        None
+    }
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Qty) -> Qty;
     async fn buy_futures(
         &self,
         symbol: &str,
-        qty: f64,
+        qty: Qty,
         reduce_only: bool,
     ) -> Result<OrderResponse, ExchangeError>;
     async fn sell_futures(
         &self,
         symbol: &str,
-        qty: f64,
+        qty: Qty,
         reduce_only: bool,
     ) -> Result<OrderResponse, ExchangeError>;
+    /// 포지션이 없을 때 진입 직전 호출. 헤지 거래소를 바꿨으면 true
+    /// (true면 호출자는 새 거래소 가격으로 진입 조건을 다시 확인해야 함)
+    async fn prepare_entry(
+        &self,
+        _symbol: &str,
+        _direction: PositionDirection,
+        _notional_usd: f64,
+    ) -> Result<bool, ExchangeError> {
+        Ok(false)
+    }
+}
+
+/// 설정에서 거래소를 고르는 경우를 위한 동적 디스패치 구현
+#[async_trait]
+impl SpotExchangeTrader for Box<dyn SpotExchangeTrader> {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        (**self).ensure_exchange_info().await
+    }
+
+    async fn get_spot_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        (**self).get_spot_price(symbol).await
+    }
+
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        (**self).clamp_spot_quantity(symbol, qty)
+    }
+
+    async fn buy_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        (**self).buy_spot(symbol, qty).await
+    }
+
+    async fn sell_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        (**self).sell_spot(symbol, qty).await
+    }
+
+    async fn get_spot_balance(&self, asset: &str) -> Result<Qty, ExchangeError> {
+        (**self).get_spot_balance(asset).await
+    }
+}
+
+#[async_trait]
+impl FuturesExchangeTrader for Box<dyn FuturesExchangeTrader> {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        (**self).ensure_exchange_info().await
+    }
+
+    async fn ensure_account_setup(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        (**self)
+            .ensure_account_setup(symbol, leverage, isolated)
+            .await
+    }
+
+    async fn get_mark_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        (**self).get_mark_price(symbol).await
+    }
+
+    async fn get_funding_rate(&self, symbol: &str) -> Result<Option<f64>, ExchangeError> {
+        (**self).get_funding_rate(symbol).await
+    }
+
+    fn contract_kind(&self) -> ContractKind {
+        (**self).contract_kind()
+    }
+
+    fn venue(&self) -> Option<ExchangeId> {
+        (**self).venue()
+    }
+
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        (**self).clamp_futures_quantity(symbol, qty)
+    }
+
+    async fn buy_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        (**self).buy_futures(symbol, qty, reduce_only).await
+    }
+
+    async fn sell_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        (**self).sell_futures(symbol, qty, reduce_only).await
+    }
+
+    async fn prepare_entry(
+        &self,
+        symbol: &str,
+        direction: PositionDirection,
+        notional_usd: f64,
+    ) -> Result<bool, ExchangeError> {
+        (**self)
+            .prepare_entry(symbol, direction, notional_usd)
+            .await
+    }
 }
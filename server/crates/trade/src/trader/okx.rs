@@ -0,0 +1,531 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::Method;
+use serde_json::{Value, json};
+use sha2::Sha256;
+use tracing::{info, warn};
+
+use interface::{ExchangeError, ExchangeId, TransferStatus};
+
+use super::OrderResponse;
+use super::binance::{LotSizeFilter, clamp_quantity_with_filter};
+use super::order_api::{ExchangeOrderApi, MarketKind, OrderRequest, OrderSide, split_usdt_symbol};
+use crate::latency::latency_tracker;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const BASE_URL: &str = "https://www.okx.com";
+
+/// 상품 정보 (수량은 모두 base 자산 단위로 환산)
+#[derive(Debug, Clone, Copy)]
+struct OkxInstrument {
+    lot: LotSizeFilter,
+    /// 계약 1개당 base 수량 (spot은 1)
+    ct_val: f64,
+}
+
+/// OKX v5 주문 API (spot + USDT 무기한 SWAP)
+pub struct OkxOrderApi {
+    http: reqwest::Client,
+    api_key: String,
+    api_secret: String,
+    passphrase: String,
+    instruments: RwLock<HashMap<(MarketKind, String), OkxInstrument>>,
+    /// set_leverage에서 지정한 심볼별 마진 모드 ("cross" / "isolated")
+    margin_modes: RwLock<HashMap<String, &'static str>>,
+}
+
+impl OkxOrderApi {
+    pub fn new(api_key: String, api_secret: String, passphrase: String) -> Self {
+        Self {
+            http: exchanges::http::http_client(),
+            api_key,
+            api_secret,
+            passphrase,
+            instruments: RwLock::new(HashMap::new()),
+            margin_modes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// OKX_API_KEY / OKX_API_SECRET / OKX_API_PASSPHRASE 환경 변수 사용
+    pub fn from_env() -> Result<Self, ExchangeError> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .map_err(|e| ExchangeError::Other(format!("{} not found: {}", name, e)))
+        };
+        Ok(Self::new(
+            var("OKX_API_KEY")?,
+            var("OKX_API_SECRET")?,
+            var("OKX_API_PASSPHRASE")?,
+        ))
+    }
+
+    fn inst_type(market: MarketKind) -> &'static str {
+        match market {
+            MarketKind::Spot => "SPOT",
+            MarketKind::Futures => "SWAP",
+        }
+    }
+
+    /// "BTCUSDT" → "BTC-USDT" (spot) / "BTC-USDT-SWAP" (swap)
+    fn inst_id(market: MarketKind, symbol: &str) -> Result<String, ExchangeError> {
+        let (base, quote) = split_usdt_symbol(symbol)?;
+        Ok(match market {
+            MarketKind::Spot => format!("{}-{}", base, quote),
+            MarketKind::Futures => format!("{}-{}-SWAP", base, quote),
+        })
+    }
+
+    /// "BTC-USDT-SWAP" → "BTCUSDT"
+    fn symbol_from_inst_id(inst_id: &str) -> String {
+        inst_id.trim_end_matches("-SWAP").replace('-', "")
+    }
+
+    /// v5 서명: Base64(HMAC_SHA256(timestamp + METHOD + requestPath + body))
+    fn sign(&self, timestamp: &str, method: &Method, request_path: &str, body: &str) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("{}{}{}{}", timestamp, method.as_str(), request_path, body).as_bytes());
+        BASE64.encode(mac.finalize().into_bytes())
+    }
+
+    /// 요청 전송 후 code 확인, `data` 배열 반환
+    /// GET은 query를 경로에 붙이고, POST는 body를 JSON 본문으로 보낸다.
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        query: &str,
+        body: &str,
+        signed: bool,
+    ) -> Result<Vec<Value>, ExchangeError> {
+        let request_path = if query.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}?{}", path, query)
+        };
+
+        let mut builder = self
+            .http
+            .request(method.clone(), format!("{}{}", BASE_URL, request_path));
+        if signed {
+            let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+            builder = builder
+                .header("OK-ACCESS-KEY", &self.api_key)
+                .header(
+                    "OK-ACCESS-SIGN",
+                    self.sign(&timestamp, &method, &request_path, body),
+                )
+                .header("OK-ACCESS-TIMESTAMP", &timestamp)
+                .header("OK-ACCESS-PASSPHRASE", &self.passphrase);
+        }
+        if !body.is_empty() {
+            builder = builder
+                .header("Content-Type", "application/json")
+                .body(body.to_string());
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        let parsed: Value = serde_json::from_str(&text).map_err(|e| {
+            ExchangeError::Other(format!(
+                "Failed to parse OKX response (status {}): {}",
+                status, e
+            ))
+        })?;
+        let code = parsed.get("code").and_then(|v| v.as_str()).unwrap_or("-1");
+        if !status.is_success() || code != "0" {
+            // 주문 API는 data[0].sMsg에 실제 사유가 들어있다
+            let detail = parsed
+                .get("data")
+                .and_then(|d| d.get(0))
+                .and_then(|d| d.get("sMsg"))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            return Err(ExchangeError::Other(format!(
+                "OKX API error {}: {} {}",
+                code,
+                parsed
+                    .get("msg")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default(),
+                detail
+            )));
+        }
+
+        Ok(parsed
+            .get("data")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn instrument(&self, market: MarketKind, symbol: &str) -> Option<OkxInstrument> {
+        self.instruments
+            .read()
+            .unwrap()
+            .get(&(market, symbol.to_uppercase()))
+            .copied()
+    }
+
+    /// 계약 단위 수량 → base 수량
+    fn ct_val(&self, market: MarketKind, symbol: &str) -> f64 {
+        match market {
+            MarketKind::Spot => 1.0,
+            MarketKind::Futures => self
+                .instrument(market, symbol)
+                .map(|i| i.ct_val)
+                .unwrap_or(1.0),
+        }
+    }
+
+    fn parse_order(symbol: &str, data: &Value, ct_val: f64) -> OrderResponse {
+        let order_id = data
+            .get("ordId")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        OrderResponse {
+            symbol: symbol.to_string(),
+            order_id: order_id.as_deref().and_then(|s| s.parse::<u64>().ok()),
+            client_order_id: data
+                .get("clOrdId")
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .or(order_id),
+            executed_qty: parse_f64(data.get("accFillSz")).map(|sz| format!("{}", sz * ct_val)),
+            status: data
+                .get("state")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            extra: data.clone(),
+        }
+    }
+
+    /// 자산 입출금 가능 여부 (`/api/v5/asset/currencies`, 체인 중 하나라도 열려 있으면 가능)
+    pub async fn transfer_status(&self, asset: &str) -> Result<TransferStatus, ExchangeError> {
+        let asset = asset.to_uppercase();
+        let query = format!("ccy={}", asset);
+        let data = self
+            .request(Method::GET, "/api/v5/asset/currencies", &query, "", true)
+            .await?;
+        let enabled = |field: &str| {
+            data.iter()
+                .any(|chain| chain.get(field).and_then(|v| v.as_bool()) == Some(true))
+        };
+        Ok(TransferStatus {
+            deposit_enabled: enabled("canDep"),
+            withdraw_enabled: enabled("canWd"),
+            currency: asset,
+        })
+    }
+}
+
+fn parse_f64(value: Option<&Value>) -> Option<f64> {
+    value?.as_str()?.parse::<f64>().ok()
+}
+
+/// base 수량을 계약 수로 환산 (부동소수 오차 보정)
+fn contracts_from_qty(qty: f64, ct_val: f64) -> f64 {
+    if ct_val <= 0.0 {
+        return qty;
+    }
+    (qty / ct_val * 1e8).round() / 1e8
+}
+
+#[async_trait]
+impl ExchangeOrderApi for OkxOrderApi {
+    fn exchange(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    async fn load_instruments(&self, market: MarketKind) -> Result<(), ExchangeError> {
+        let query = format!("instType={}", Self::inst_type(market));
+        let data = self
+            .request(Method::GET, "/api/v5/public/instruments", &query, "", false)
+            .await?;
+
+        let mut loaded = Vec::new();
+        for item in &data {
+            let Some(inst_id) = item.get("instId").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            if market == MarketKind::Futures
+                && item.get("settleCcy").and_then(|v| v.as_str()) != Some("USDT")
+            {
+                continue;
+            }
+            let ct_val = match market {
+                MarketKind::Spot => 1.0,
+                MarketKind::Futures => parse_f64(item.get("ctVal")).unwrap_or(1.0),
+            };
+            let Some(lot_sz) = parse_f64(item.get("lotSz")) else {
+                continue;
+            };
+            let min_sz = parse_f64(item.get("minSz")).unwrap_or(0.0);
+            let max_sz = parse_f64(item.get("maxMktSz")).unwrap_or(f64::MAX / ct_val);
+            loaded.push((
+                Self::symbol_from_inst_id(inst_id),
+                OkxInstrument {
+                    lot: LotSizeFilter {
+                        min_qty: min_sz * ct_val,
+                        max_qty: max_sz * ct_val,
+                        step_size: lot_sz * ct_val,
+                    },
+                    ct_val,
+                },
+            ));
+        }
+
+        info!("OKX {} instruments loaded: {}", market, loaded.len());
+        let mut cache = self.instruments.write().unwrap();
+        cache.retain(|(m, _), _| *m != market);
+        cache.extend(loaded.into_iter().map(|(s, i)| ((market, s), i)));
+        Ok(())
+    }
+
+    fn clamp_quantity(&self, market: MarketKind, symbol: &str, qty: f64) -> f64 {
+        match self.instrument(market, symbol) {
+            Some(instrument) => clamp_quantity_with_filter(instrument.lot, qty),
+            None => {
+                warn!(
+                    "OKX {} lot size for {} not loaded, using raw quantity",
+                    market, symbol
+                );
+                qty
+            }
+        }
+    }
+
+    async fn get_price(&self, market: MarketKind, symbol: &str) -> Result<f64, ExchangeError> {
+        let inst_id = Self::inst_id(market, symbol)?;
+        let (path, query, field) = match market {
+            MarketKind::Spot => (
+                "/api/v5/market/ticker",
+                format!("instId={}", inst_id),
+                "last",
+            ),
+            MarketKind::Futures => (
+                "/api/v5/public/mark-price",
+                format!("instType=SWAP&instId={}", inst_id),
+                "markPx",
+            ),
+        };
+        let data = self.request(Method::GET, path, &query, "", false).await?;
+        data.first()
+            .and_then(|d| parse_f64(d.get(field)))
+            .ok_or_else(|| ExchangeError::Other(format!("OKX {} not found: {}", field, inst_id)))
+    }
+
+    async fn set_leverage(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        let mgn_mode = if isolated { "isolated" } else { "cross" };
+        let body = json!({
+            "instId": Self::inst_id(MarketKind::Futures, symbol)?,
+            "lever": leverage.to_string(),
+            "mgnMode": mgn_mode,
+        });
+        self.request(
+            Method::POST,
+            "/api/v5/account/set-leverage",
+            "",
+            &body.to_string(),
+            true,
+        )
+        .await?;
+        self.margin_modes
+            .write()
+            .unwrap()
+            .insert(symbol.to_uppercase(), mgn_mode);
+        Ok(())
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ExchangeError> {
+        if request.qty <= 0.0 {
+            return Err(ExchangeError::Other(
+                "Quantity must be positive".to_string(),
+            ));
+        }
+        let symbol = request.symbol.to_uppercase();
+        let ct_val = self.ct_val(request.market, &symbol);
+        let td_mode = match request.market {
+            MarketKind::Spot => "cash",
+            MarketKind::Futures => self
+                .margin_modes
+                .read()
+                .unwrap()
+                .get(&symbol)
+                .copied()
+                .unwrap_or("cross"),
+        };
+
+        let mut body = json!({
+            "instId": Self::inst_id(request.market, &symbol)?,
+            "tdMode": td_mode,
+            "side": match request.side {
+                OrderSide::Buy => "buy",
+                OrderSide::Sell => "sell",
+            },
+            "ordType": if request.price.is_some() { "limit" } else { "market" },
+            "sz": format!("{}", contracts_from_qty(request.qty, ct_val)),
+        });
+        if let Some(price) = request.price {
+            body["px"] = json!(format!("{}", price));
+        }
+        match request.market {
+            // spot 시장가 매수는 기본이 quote 수량이므로 base 수량으로 지정
+            MarketKind::Spot => body["tgtCcy"] = json!("base_ccy"),
+            MarketKind::Futures => body["reduceOnly"] = json!(request.reduce_only),
+        }
+        let payload = body.to_string();
+        info!("OKX place_order: {}", payload);
+
+        let started = Instant::now();
+        let data = self
+            .request(Method::POST, "/api/v5/trade/order", "", &payload, true)
+            .await?;
+        latency_tracker().record_order_ack(&format!("okx_{}", request.market), started.elapsed());
+
+        let order = Self::parse_order(&symbol, data.first().unwrap_or(&Value::Null), ct_val);
+        match request.market {
+            MarketKind::Spot => {
+                crate::record::save_trade_record_spot_order(
+                    "okx",
+                    "default",
+                    &symbol,
+                    request.side.as_str(),
+                    request.qty,
+                    &payload,
+                    &order,
+                    false,
+                )
+                .await
+            }
+            MarketKind::Futures => {
+                crate::record::save_trade_record_futures_order(
+                    "okx",
+                    "default",
+                    &symbol,
+                    request.side.as_str(),
+                    request.qty,
+                    &payload,
+                    &order,
+                    request.reduce_only,
+                    false,
+                )
+                .await
+            }
+        }
+
+        Ok(order)
+    }
+
+    async fn cancel_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        let body = json!({
+            "instId": Self::inst_id(market, symbol)?,
+            "ordId": order_id,
+        });
+        self.request(
+            Method::POST,
+            "/api/v5/trade/cancel-order",
+            "",
+            &body.to_string(),
+            true,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn query_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let symbol = symbol.to_uppercase();
+        let query = format!(
+            "instId={}&ordId={}",
+            Self::inst_id(market, &symbol)?,
+            order_id
+        );
+        let data = self
+            .request(Method::GET, "/api/v5/trade/order", &query, "", true)
+            .await?;
+        let order = data
+            .first()
+            .ok_or_else(|| ExchangeError::Other(format!("OKX order not found: {}", order_id)))?;
+        Ok(Self::parse_order(
+            &symbol,
+            order,
+            self.ct_val(market, &symbol),
+        ))
+    }
+
+    async fn get_balance(&self, _market: MarketKind, asset: &str) -> Result<f64, ExchangeError> {
+        // 통합 trading 계정은 spot/선물이 같은 잔고를 사용
+        let query = format!("ccy={}", asset.to_uppercase());
+        let data = self
+            .request(Method::GET, "/api/v5/account/balance", &query, "", true)
+            .await?;
+        Ok(data
+            .first()
+            .and_then(|d| d.get("details"))
+            .and_then(|d| d.get(0))
+            .and_then(|d| parse_f64(d.get("availBal")))
+            .unwrap_or(0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inst_id_round_trip() {
+        assert_eq!(
+            OkxOrderApi::inst_id(MarketKind::Spot, "BTCUSDT").unwrap(),
+            "BTC-USDT"
+        );
+        assert_eq!(
+            OkxOrderApi::inst_id(MarketKind::Futures, "ethusdt").unwrap(),
+            "ETH-USDT-SWAP"
+        );
+        assert_eq!(OkxOrderApi::symbol_from_inst_id("ETH-USDT-SWAP"), "ETHUSDT");
+    }
+
+    #[test]
+    fn test_contracts_and_sign() {
+        // ETH-USDT-SWAP ctVal = 0.1
+        assert_eq!(contracts_from_qty(0.3, 0.1), 3.0);
+        assert_eq!(contracts_from_qty(0.5, 1.0), 0.5);
+
+        let api = OkxOrderApi::new("key".into(), "secret".into(), "pass".into());
+        let sig = api.sign(
+            "2024-01-01T00:00:00.000Z",
+            &Method::GET,
+            "/api/v5/account/balance?ccy=USDT",
+            "",
+        );
+        assert!(BASE64.decode(&sig).is_ok_and(|raw| raw.len() == 32));
+    }
+}
@@ -0,0 +1,334 @@
+//! 거래소 공통 주문 API (ccxt 스타일)
+//!
+//! 거래소마다 다른 REST 규격을 `ExchangeOrderApi` 하나로 감싸고,
+//! `OrderApiTrader` 어댑터로 기존 `SpotExchangeTrader` / `FuturesExchangeTrader`를 구현해
+//! CrossBasisArbitrageStrategy가 거래소 이름만으로 spot/선물 레그를 조합할 수 있게 한다.
+
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use interface::{ExchangeError, ExchangeId, Price, Qty};
+use serde::Serialize;
+
+use super::bybit::BybitOrderApi;
+use super::okx::OkxOrderApi;
+use super::{
+    BinanceTrader, BithumbTrader, FuturesExchangeTrader, OrderResponse, SpotExchangeTrader,
+};
+
+/// 주문 대상 시장
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketKind {
+    Spot,
+    /// USDT 무기한 선물
+    Futures,
+}
+
+impl fmt::Display for MarketKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarketKind::Spot => write!(f, "spot"),
+            MarketKind::Futures => write!(f, "futures"),
+        }
+    }
+}
+
+/// 선물 계약 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContractKind {
+    /// USDT 마진 (수량 = 기초 자산, 손익 = USDT)
+    Linear,
+    /// 코인 마진 (수량 = USD 계약, 증거금/손익 = 기초 자산)
+    Inverse,
+}
+
+impl std::str::FromStr for ContractKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "linear" | "usdm" => Ok(ContractKind::Linear),
+            "inverse" | "coinm" => Ok(ContractKind::Inverse),
+            other => Err(format!("Unknown contract kind: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+impl OrderSide {
+    /// "BUY" / "SELL" (거래 기록용)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderSide::Buy => "BUY",
+            OrderSide::Sell => "SELL",
+        }
+    }
+}
+
+/// 거래소 공통 주문 요청 (수량은 항상 base 자산 단위)
+#[derive(Debug, Clone)]
+pub struct OrderRequest {
+    pub market: MarketKind,
+    /// 거래소 공통 심볼 (예: "BTCUSDT")
+    pub symbol: String,
+    pub side: OrderSide,
+    pub qty: f64,
+    /// None이면 시장가, Some이면 GTC 지정가
+    pub price: Option<f64>,
+    /// 선물 전용 (포지션 축소만 허용)
+    pub reduce_only: bool,
+}
+
+impl OrderRequest {
+    pub fn market(market: MarketKind, symbol: &str, side: OrderSide, qty: f64) -> Self {
+        Self {
+            market,
+            symbol: symbol.to_string(),
+            side,
+            qty,
+            price: None,
+            reduce_only: false,
+        }
+    }
+
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+}
+
+/// 거래소 공통 주문/잔고 API
+#[async_trait]
+pub trait ExchangeOrderApi: Send + Sync {
+    fn exchange(&self) -> ExchangeId;
+
+    /// 수량 단위(LOT_SIZE 등) 정보 로드
+    async fn load_instruments(&self, market: MarketKind) -> Result<(), ExchangeError>;
+
+    /// 거래소 수량 단위에 맞춰 내림 (주문 불가 수량이면 0)
+    fn clamp_quantity(&self, market: MarketKind, symbol: &str, qty: f64) -> f64;
+
+    /// 현물은 최근 체결가, 선물은 마크 가격
+    async fn get_price(&self, market: MarketKind, symbol: &str) -> Result<f64, ExchangeError>;
+
+    /// 선물 레버리지 / 마진 모드 설정
+    async fn set_leverage(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError>;
+
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderResponse, ExchangeError>;
+
+    async fn cancel_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<(), ExchangeError>;
+
+    async fn query_order(
+        &self,
+        market: MarketKind,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderResponse, ExchangeError>;
+
+    /// 사용 가능한 잔고 (선물은 증거금 자산 기준)
+    async fn get_balance(&self, market: MarketKind, asset: &str) -> Result<f64, ExchangeError>;
+}
+
+/// 설정 파일의 거래소 이름 파싱 ("binance", "bybit", "okx", "bitget", "bithumb")
+pub fn parse_exchange_id(name: &str) -> Result<ExchangeId, ExchangeError> {
+    match name.trim().to_lowercase().as_str() {
+        "binance" => Ok(ExchangeId::Binance),
+        "bybit" => Ok(ExchangeId::Bybit),
+        "okx" => Ok(ExchangeId::Okx),
+        "bitget" => Ok(ExchangeId::Bitget),
+        "bithumb" => Ok(ExchangeId::Bithumb),
+        other => Err(ExchangeError::Other(format!("Unknown exchange: {}", other))),
+    }
+}
+
+/// 거래소 ID로 주문 API 생성 (인증 정보는 환경 변수에서 읽음)
+pub fn order_api_for(exchange: ExchangeId) -> Result<Arc<dyn ExchangeOrderApi>, ExchangeError> {
+    match exchange {
+        ExchangeId::Binance => Ok(Arc::new(BinanceTrader::new()?)),
+        ExchangeId::Bybit => Ok(Arc::new(BybitOrderApi::from_env()?)),
+        ExchangeId::Okx => Ok(Arc::new(OkxOrderApi::from_env()?)),
+        other => Err(ExchangeError::Other(format!(
+            "{:?} does not support ExchangeOrderApi",
+            other
+        ))),
+    }
+}
+
+/// 거래소 ID로 spot 레그 트레이더 생성 (빗썸은 기존 BithumbTrader 사용)
+pub fn spot_trader_for(exchange: ExchangeId) -> Result<Box<dyn SpotExchangeTrader>, ExchangeError> {
+    match exchange {
+        ExchangeId::Bithumb => Ok(Box::new(BithumbTrader::new()?)),
+        other => Ok(Box::new(OrderApiTrader::new(order_api_for(other)?))),
+    }
+}
+
+/// 거래소 ID로 선물 레그 트레이더 생성
+pub fn futures_trader_for(
+    exchange: ExchangeId,
+) -> Result<Box<dyn FuturesExchangeTrader>, ExchangeError> {
+    Ok(Box::new(OrderApiTrader::new(order_api_for(exchange)?)))
+}
+
+/// `ExchangeOrderApi`를 기존 spot/선물 트레이더 트레이트로 노출하는 어댑터
+#[derive(Clone)]
+pub struct OrderApiTrader {
+    api: Arc<dyn ExchangeOrderApi>,
+}
+
+impl OrderApiTrader {
+    pub fn new(api: Arc<dyn ExchangeOrderApi>) -> Self {
+        Self { api }
+    }
+
+    pub fn api(&self) -> &Arc<dyn ExchangeOrderApi> {
+        &self.api
+    }
+}
+
+#[async_trait]
+impl SpotExchangeTrader for OrderApiTrader {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        self.api.load_instruments(MarketKind::Spot).await
+    }
+
+    async fn get_spot_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        self.api
+            .get_price(MarketKind::Spot, symbol)
+            .await
+            .map(Price::new)
+    }
+
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        Qty::new(
+            self.api
+                .clamp_quantity(MarketKind::Spot, symbol, qty.value()),
+        )
+    }
+
+    async fn buy_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        let request = OrderRequest::market(MarketKind::Spot, symbol, OrderSide::Buy, qty.value());
+        self.api.place_order(&request).await
+    }
+
+    async fn sell_spot(&self, symbol: &str, qty: Qty) -> Result<OrderResponse, ExchangeError> {
+        let request = OrderRequest::market(MarketKind::Spot, symbol, OrderSide::Sell, qty.value());
+        self.api.place_order(&request).await
+    }
+
+    async fn get_spot_balance(&self, asset: &str) -> Result<Qty, ExchangeError> {
+        self.api
+            .get_balance(MarketKind::Spot, asset)
+            .await
+            .map(Qty::new)
+    }
+}
+
+#[async_trait]
+impl FuturesExchangeTrader for OrderApiTrader {
+    async fn ensure_exchange_info(&self) -> Result<(), ExchangeError> {
+        self.api.load_instruments(MarketKind::Futures).await
+    }
+
+    async fn ensure_account_setup(
+        &self,
+        symbol: &str,
+        leverage: u32,
+        isolated: bool,
+    ) -> Result<(), ExchangeError> {
+        self.api.set_leverage(symbol, leverage, isolated).await
+    }
+
+    async fn get_mark_price(&self, symbol: &str) -> Result<Price, ExchangeError> {
+        self.api
+            .get_price(MarketKind::Futures, symbol)
+            .await
+            .map(Price::new)
+    }
+
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Qty) -> Qty {
+        Qty::new(
+            self.api
+                .clamp_quantity(MarketKind::Futures, symbol, qty.value()),
+        )
+    }
+
+    async fn buy_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let request =
+            OrderRequest::market(MarketKind::Futures, symbol, OrderSide::Buy, qty.value())
+                .reduce_only(reduce_only);
+        self.api.place_order(&request).await
+    }
+
+    async fn sell_futures(
+        &self,
+        symbol: &str,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> Result<OrderResponse, ExchangeError> {
+        let request =
+            OrderRequest::market(MarketKind::Futures, symbol, OrderSide::Sell, qty.value())
+                .reduce_only(reduce_only);
+        self.api.place_order(&request).await
+    }
+}
+
+/// "BTCUSDT" → ("BTC", "USDT")
+pub fn split_usdt_symbol(symbol: &str) -> Result<(String, String), ExchangeError> {
+    let cleaned = symbol.replace(['-', '_', '/'], "").to_uppercase();
+    for quote in ["USDT", "USDC"] {
+        if let Some(base) = cleaned.strip_suffix(quote)
+            && !base.is_empty()
+        {
+            return Ok((base.to_string(), quote.to_string()));
+        }
+    }
+    Err(ExchangeError::Other(format!(
+        "Unsupported symbol: {}",
+        symbol
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_exchange_id() {
+        assert_eq!(parse_exchange_id("Bybit").unwrap(), ExchangeId::Bybit);
+        assert_eq!(parse_exchange_id(" okx ").unwrap(), ExchangeId::Okx);
+        assert!(parse_exchange_id("kraken").is_err());
+    }
+
+    #[test]
+    fn test_split_usdt_symbol() {
+        assert_eq!(
+            split_usdt_symbol("BTC-USDT").unwrap(),
+            ("BTC".to_string(), "USDT".to_string())
+        );
+        assert_eq!(split_usdt_symbol("ethusdc").unwrap().0, "ETH");
+        assert!(split_usdt_symbol("USDT").is_err());
+        assert!(split_usdt_symbol("BTCKRW").is_err());
+    }
+}
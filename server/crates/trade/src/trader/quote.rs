@@ -0,0 +1,136 @@
+//! 심볼의 견적(quote) 자산 처리
+//!
+//! 스팟 레그가 BTCUSDC / BTCFDUSD 처럼 USDT 외 스테이블코인으로 호가되는 경우,
+//! USDT 마진 선물과 비교하려면 스팟 가격을 USDT로 환산해야 한다.
+//! 환산율은 `{QUOTE}USDT` 스팟 시세(예: USDCUSDT)를 사용한다.
+
+use std::time::{Duration, Instant};
+
+/// 인식하는 견적 자산 (접미사 검사 순서: 긴 것/겹치는 것 먼저)
+pub const KNOWN_QUOTES: [&str; 6] = ["FDUSD", "USDT", "USDC", "TUSD", "KRW", "USD"];
+
+/// 선물 증거금 기준 통화
+pub const SETTLEMENT_QUOTE: &str = "USDT";
+
+/// 환산율 기본 갱신 주기
+const DEFAULT_REFRESH: Duration = Duration::from_secs(60);
+
+/// 심볼을 (베이스, 견적)으로 분리 (예: "BTCFDUSD" -> ("BTC", "FDUSD"))
+pub fn split_symbol(symbol: &str) -> Option<(&str, &str)> {
+    KNOWN_QUOTES.iter().find_map(|quote| {
+        symbol
+            .strip_suffix(quote)
+            .filter(|base| !base.is_empty())
+            .map(|base| (base, *quote))
+    })
+}
+
+/// 심볼의 견적 자산 (알 수 없으면 USDT로 간주)
+pub fn quote_asset_from_symbol(symbol: &str) -> &str {
+    split_symbol(symbol)
+        .map(|(_, quote)| quote)
+        .unwrap_or(SETTLEMENT_QUOTE)
+}
+
+/// 견적 자산 → USDT 환산에 쓰는 스팟 심볼 (USDT면 None)
+pub fn conversion_symbol(quote: &str) -> Option<String> {
+    (quote != SETTLEMENT_QUOTE).then(|| format!("{}{}", quote, SETTLEMENT_QUOTE))
+}
+
+/// 스팟 견적 자산 ↔ USDT 환산기
+///
+/// 스테이블코인 간 시세는 거의 움직이지 않으므로 매 루프 조회하지 않고
+/// `refresh_every` 주기로만 갱신한다.
+#[derive(Debug, Clone)]
+pub struct QuoteConverter {
+    quote: String,
+    /// 1 quote = rate USDT
+    rate: f64,
+    refreshed_at: Option<Instant>,
+    refresh_every: Duration,
+}
+
+impl QuoteConverter {
+    pub fn for_symbol(spot_symbol: &str) -> Self {
+        Self {
+            quote: quote_asset_from_symbol(spot_symbol).to_string(),
+            rate: 1.0,
+            refreshed_at: None,
+            refresh_every: DEFAULT_REFRESH,
+        }
+    }
+
+    pub fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// 환산이 필요 없는 USDT 견적인지
+    pub fn is_identity(&self) -> bool {
+        self.quote == SETTLEMENT_QUOTE
+    }
+
+    /// 환산율 조회 심볼 (갱신이 필요할 때만)
+    pub fn symbol_to_refresh(&self, now: Instant) -> Option<String> {
+        let stale = self
+            .refreshed_at
+            .is_none_or(|at| now.duration_since(at) >= self.refresh_every);
+        if stale {
+            conversion_symbol(&self.quote)
+        } else {
+            None
+        }
+    }
+
+    /// 환산율 갱신 (0 이하/비정상 값은 무시)
+    pub fn update(&mut self, rate: f64, now: Instant) {
+        if rate.is_finite() && rate > 0.0 {
+            self.rate = rate;
+            self.refreshed_at = Some(now);
+        }
+    }
+
+    /// 스팟 견적 가격 → USDT 가격
+    pub fn to_settlement(&self, price: f64) -> f64 {
+        price * self.rate
+    }
+
+    /// USDT 금액 → 스팟 견적 금액
+    pub fn from_settlement(&self, amount: f64) -> f64 {
+        amount / self.rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_symbol_and_converter() {
+        assert_eq!(split_symbol("BTCFDUSD"), Some(("BTC", "FDUSD")));
+        assert_eq!(split_symbol("BTCUSDC"), Some(("BTC", "USDC")));
+        assert_eq!(split_symbol("ETHUSDT"), Some(("ETH", "USDT")));
+        assert_eq!(split_symbol("BTCUSD"), Some(("BTC", "USD")));
+        assert_eq!(split_symbol("USDT"), None);
+        assert_eq!(conversion_symbol("USDC").as_deref(), Some("USDCUSDT"));
+        assert_eq!(conversion_symbol("USDT"), None);
+
+        let now = Instant::now();
+        let mut converter = QuoteConverter::for_symbol("BTCUSDC");
+        assert_eq!(
+            converter.symbol_to_refresh(now).as_deref(),
+            Some("USDCUSDT")
+        );
+        converter.update(0.9995, now);
+        assert_eq!(converter.symbol_to_refresh(now), None);
+        assert!((converter.to_settlement(100_000.0) - 99_950.0).abs() < 1e-6);
+        assert!((converter.from_settlement(99_950.0) - 100_000.0).abs() < 1e-6);
+
+        let usdt = QuoteConverter::for_symbol("BTCUSDT");
+        assert!(usdt.is_identity());
+        assert_eq!(usdt.symbol_to_refresh(now), None);
+    }
+}
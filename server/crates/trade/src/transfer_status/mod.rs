@@ -0,0 +1,357 @@
+//! 입출금 중단 감시 (크로스 거래소 포지션)
+//!
+//! 크로스 거래소 캐리는 양쪽 거래소 사이로 코인과 USDT를 옮겨 재고·증거금을 맞출 수 있어야
+//! 유지된다. 감시 대상(거래소, 자산)의 코인 설정 API를 주기적으로 조회해 입금 또는 출금이
+//! 막히면 알림을 보내고, 설정에 따라 중단이 풀릴 때까지 새 크로스 진입을 막는다.
+//!
+//! - Binance: `/sapi/v1/capital/config/getall` (서명 필요)
+//! - Bithumb: `/public/assetsstatus/ALL`
+//! - Bybit: `/v5/asset/coin/query-info` (서명 필요)
+//! - OKX: `/api/v5/asset/currencies` (서명 필요)
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use exchanges::BithumbClient;
+use interface::{ExchangeError, ExchangeId, TransferStatus};
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::notification::{AlertLevel, notification_center};
+use crate::trader::binance::BinanceAccounts;
+use crate::trader::{BybitOrderApi, OkxOrderApi};
+
+/// 입출금 중단/재개 알림 종류
+pub const TRANSFER_STATUS_ALERT: &str = "transfer_status";
+
+const DEFAULT_INTERVAL_SECS: u64 = 300;
+
+/// 입출금 감시 설정
+#[derive(Debug, Clone, Copy)]
+pub struct TransferMonitorParams {
+    /// 코인 설정 조회 간격
+    pub interval: Duration,
+    /// 감시 대상 중 하나라도 중단되면 새 진입을 막을지
+    pub block_entries: bool,
+}
+
+impl Default for TransferMonitorParams {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(DEFAULT_INTERVAL_SECS),
+            block_entries: false,
+        }
+    }
+}
+
+impl TransferMonitorParams {
+    /// `TRANSFER_MONITOR_INTERVAL_SECS`(기본 300), `TRANSFER_MONITOR_BLOCK_ENTRIES`(기본 false)
+    pub fn from_env() -> Self {
+        let interval = std::env::var("TRANSFER_MONITOR_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_INTERVAL_SECS);
+        let block_entries = std::env::var("TRANSFER_MONITOR_BLOCK_ENTRIES")
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        Self {
+            interval: Duration::from_secs(interval),
+            block_entries,
+        }
+    }
+}
+
+/// 감시 대상 하나의 최근 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchedTransfer {
+    pub exchange: ExchangeId,
+    pub asset: String,
+    /// 아직 조회 전이면 None
+    pub status: Option<TransferStatus>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+impl WatchedTransfer {
+    /// 중단 사유 (예: "Bithumb BTC 출금 중단")
+    pub fn suspension(&self) -> Option<String> {
+        let status = self.status.as_ref()?;
+        let what = match (status.deposit_enabled, status.withdraw_enabled) {
+            (true, true) => return None,
+            (false, false) => "입출금",
+            (false, true) => "입금",
+            (true, false) => "출금",
+        };
+        Some(format!("{:?} {} {} 중단", self.exchange, self.asset, what))
+    }
+}
+
+/// 중단 여부가 바뀐 감시 대상
+#[derive(Debug, Clone)]
+pub struct TransferChange {
+    pub before: Option<String>,
+    pub after: WatchedTransfer,
+}
+
+/// 감시 대상과 최근 상태 저장소
+#[derive(Debug, Default)]
+pub struct TransferMonitor {
+    watched: RwLock<BTreeMap<(String, String), WatchedTransfer>>,
+    started: AtomicBool,
+}
+
+fn watch_key(exchange: ExchangeId, asset: &str) -> (String, String) {
+    (format!("{:?}", exchange), asset.to_uppercase())
+}
+
+impl TransferMonitor {
+    /// 감시 대상 추가 (이미 있으면 무시)
+    pub fn watch(&self, exchange: ExchangeId, asset: &str) {
+        self.watched
+            .write()
+            .unwrap()
+            .entry(watch_key(exchange, asset))
+            .or_insert_with(|| WatchedTransfer {
+                exchange,
+                asset: asset.to_uppercase(),
+                status: None,
+                checked_at: None,
+                last_error: None,
+            });
+    }
+
+    /// 거래소별 감시 자산
+    fn targets(&self) -> BTreeMap<String, (ExchangeId, Vec<String>)> {
+        let mut targets: BTreeMap<String, (ExchangeId, Vec<String>)> = BTreeMap::new();
+        for watched in self.watched.read().unwrap().values() {
+            targets
+                .entry(format!("{:?}", watched.exchange))
+                .or_insert_with(|| (watched.exchange, Vec::new()))
+                .1
+                .push(watched.asset.clone());
+        }
+        targets
+    }
+
+    /// 조회 결과 반영, 중단 여부가 바뀌었으면 변경 내용 반환
+    /// 처음 조회한 자산은 이미 중단된 경우만 변경으로 본다
+    pub fn record(&self, exchange: ExchangeId, status: TransferStatus) -> Option<TransferChange> {
+        let mut watched = self.watched.write().unwrap();
+        let entry = watched.get_mut(&watch_key(exchange, &status.currency))?;
+        let before = entry.suspension();
+        entry.status = Some(status);
+        entry.checked_at = Some(Utc::now());
+        entry.last_error = None;
+        (entry.suspension() != before).then(|| TransferChange {
+            before,
+            after: entry.clone(),
+        })
+    }
+
+    /// 조회 실패 기록 (직전 상태는 유지)
+    pub fn record_error(&self, exchange: ExchangeId, asset: &str, error: &str) {
+        if let Some(entry) = self
+            .watched
+            .write()
+            .unwrap()
+            .get_mut(&watch_key(exchange, asset))
+        {
+            entry.last_error = Some(error.to_string());
+        }
+    }
+
+    /// 주어진 대상 중 입금 또는 출금이 중단된 것의 사유 (조회 전이거나 감시하지 않는 대상은 제외)
+    pub fn suspensions(&self, targets: &[(ExchangeId, &str)]) -> Vec<String> {
+        let watched = self.watched.read().unwrap();
+        targets
+            .iter()
+            .filter_map(|(exchange, asset)| watched.get(&watch_key(*exchange, asset)))
+            .filter_map(WatchedTransfer::suspension)
+            .collect()
+    }
+
+    /// 전체 감시 대상 (거래소, 자산 순)
+    pub fn snapshot(&self) -> Vec<WatchedTransfer> {
+        self.watched.read().unwrap().values().cloned().collect()
+    }
+
+    /// 주기 조회 작업 시작 (프로세스당 한 번, 이후 `watch`로 추가한 대상도 다음 주기부터 조회)
+    pub fn start(&'static self, interval: Duration) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        info!("입출금 상태 감시 시작 ({}초 간격)", interval.as_secs());
+        tokio::spawn(async move {
+            loop {
+                self.poll().await;
+                tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
+    /// 감시 대상 전체를 한 번 조회하고 변경 사항 알림
+    pub async fn poll(&self) {
+        for (_, (exchange, assets)) in self.targets() {
+            match fetch_statuses(exchange, &assets).await {
+                Ok(mut statuses) => {
+                    for asset in &assets {
+                        // 응답에 없는 자산은 상장 폐지 등으로 입출금 불가로 봄
+                        let status = statuses.remove(asset).unwrap_or_else(|| TransferStatus {
+                            currency: asset.clone(),
+                            deposit_enabled: false,
+                            withdraw_enabled: false,
+                        });
+                        if let Some(change) = self.record(exchange, status) {
+                            notify_change(&change).await;
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!("{:?} 입출금 상태 조회 실패: {}", exchange, e);
+                    for asset in &assets {
+                        self.record_error(exchange, asset, &e.to_string());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 거래소의 자산별 입출금 상태 조회
+async fn fetch_statuses(
+    exchange: ExchangeId,
+    assets: &[String],
+) -> Result<HashMap<String, TransferStatus>, ExchangeError> {
+    match exchange {
+        ExchangeId::Binance => {
+            let accounts = BinanceAccounts::from_env()?;
+            accounts.spot.client.fetch_transfer_statuses().await
+        }
+        ExchangeId::Bithumb => BithumbClient::new().fetch_transfer_statuses().await,
+        ExchangeId::Bybit => {
+            let api = BybitOrderApi::from_env()?;
+            let mut statuses = HashMap::new();
+            for asset in assets {
+                statuses.insert(asset.clone(), api.transfer_status(asset).await?);
+            }
+            Ok(statuses)
+        }
+        ExchangeId::Okx => {
+            let api = OkxOrderApi::from_env()?;
+            let mut statuses = HashMap::new();
+            for asset in assets {
+                statuses.insert(asset.clone(), api.transfer_status(asset).await?);
+            }
+            Ok(statuses)
+        }
+        other => Err(ExchangeError::Other(format!(
+            "{:?} transfer status is not supported",
+            other
+        ))),
+    }
+}
+
+async fn notify_change(change: &TransferChange) {
+    let watched = &change.after;
+    let (level, title) = match (&watched.suspension(), &change.before) {
+        (Some(reason), _) => {
+            warn!("입출금 중단 감지: {}", reason);
+            (AlertLevel::Warning, reason.clone())
+        }
+        (None, Some(before)) => {
+            info!("입출금 재개: {}", before);
+            (
+                AlertLevel::Info,
+                format!("{:?} {} 입출금 재개", watched.exchange, watched.asset),
+            )
+        }
+        (None, None) => return,
+    };
+    notification_center()
+        .notify(
+            TRANSFER_STATUS_ALERT,
+            level,
+            title,
+            "크로스 거래소 재고/증거금 이동에 영향 (TRANSFER_MONITOR_BLOCK_ENTRIES=true면 신규 진입 차단)",
+            serde_json::to_value(watched).unwrap_or_default(),
+        )
+        .await;
+}
+
+static GLOBAL_TRANSFER_MONITOR: OnceLock<TransferMonitor> = OnceLock::new();
+
+/// 전역 입출금 감시기
+pub fn transfer_monitor() -> &'static TransferMonitor {
+    GLOBAL_TRANSFER_MONITOR.get_or_init(TransferMonitor::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(asset: &str, deposit: bool, withdraw: bool) -> TransferStatus {
+        TransferStatus {
+            currency: asset.to_string(),
+            deposit_enabled: deposit,
+            withdraw_enabled: withdraw,
+        }
+    }
+
+    #[test]
+    fn test_record_reports_suspend_and_resume() {
+        let monitor = TransferMonitor::default();
+        monitor.watch(ExchangeId::Bithumb, "btc");
+        monitor.watch(ExchangeId::Binance, "USDT");
+        let targets = [(ExchangeId::Bithumb, "BTC"), (ExchangeId::Binance, "USDT")];
+
+        // 처음부터 정상이면 변경 아님, 감시하지 않는 자산은 무시
+        assert!(
+            monitor
+                .record(ExchangeId::Binance, status("USDT", true, true))
+                .is_none()
+        );
+        assert!(
+            monitor
+                .record(ExchangeId::Okx, status("USDT", false, false))
+                .is_none()
+        );
+        assert!(monitor.suspensions(&targets).is_empty());
+
+        let change = monitor
+            .record(ExchangeId::Bithumb, status("BTC", true, false))
+            .unwrap();
+        assert_eq!(change.before, None);
+        assert_eq!(monitor.suspensions(&targets), vec!["Bithumb BTC 출금 중단"]);
+
+        // 중단 종류가 바뀌면 다시 알림, 같은 상태 반복은 무시
+        assert!(
+            monitor
+                .record(ExchangeId::Bithumb, status("BTC", false, false))
+                .is_some()
+        );
+        assert!(
+            monitor
+                .record(ExchangeId::Bithumb, status("BTC", false, false))
+                .is_none()
+        );
+
+        // 조회 실패는 직전 상태 유지
+        monitor.record_error(ExchangeId::Bithumb, "BTC", "timeout");
+        assert_eq!(
+            monitor.suspensions(&targets),
+            vec!["Bithumb BTC 입출금 중단"]
+        );
+
+        let resumed = monitor
+            .record(ExchangeId::Bithumb, status("BTC", true, true))
+            .unwrap();
+        assert_eq!(resumed.before.as_deref(), Some("Bithumb BTC 입출금 중단"));
+        assert!(resumed.after.suspension().is_none());
+        assert!(monitor.suspensions(&targets).is_empty());
+        assert_eq!(monitor.snapshot().len(), 2);
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{OnceLock, RwLock};
+
+use serde::Serialize;
+
+/// 1분봉 길이 (ms)
+const BAR_MS: i64 = 60_000;
+/// 심볼별로 보관하는 완성된 1분봉 개수
+const DEFAULT_WINDOW: usize = 60;
+
+/// 1분봉 (OHLC)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    /// 봉 시작 시각 (epoch ms)
+    pub start_ms: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Bar {
+    fn new(start_ms: i64, price: f64) -> Self {
+        Self {
+            start_ms,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+        }
+    }
+
+    /// 이전 봉 종가 기준 True Range
+    fn true_range(&self, prev_close: f64) -> f64 {
+        (self.high - self.low)
+            .max((self.high - prev_close).abs())
+            .max((self.low - prev_close).abs())
+    }
+}
+
+/// 변동성 추정치
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct VolatilityEstimate {
+    /// 1분봉 ATR / 마지막 종가 (bps)
+    pub atr_bps: f64,
+    /// 1분 로그 수익률 표준편차 (bps)
+    pub return_std_bps: f64,
+    /// 추정에 사용된 완성 봉 개수
+    pub bars: usize,
+}
+
+impl VolatilityEstimate {
+    pub fn value(&self, measure: VolatilityMeasure) -> f64 {
+        match measure {
+            VolatilityMeasure::Atr => self.atr_bps,
+            VolatilityMeasure::ReturnStd => self.return_std_bps,
+        }
+    }
+}
+
+/// 가격 샘플을 1분봉으로 묶어 ATR / 수익률 σ를 계산하는 추정기
+///
+/// 진행 중인 봉은 계산에 포함하지 않고, 완성된 봉만 최근 `window`개까지 보관한다.
+/// 이전 봉보다 오래된 샘플은 무시한다.
+#[derive(Debug, Clone)]
+pub struct VolatilityEstimator {
+    window: usize,
+    current: Option<Bar>,
+    bars: VecDeque<Bar>,
+}
+
+impl Default for VolatilityEstimator {
+    fn default() -> Self {
+        Self::new(DEFAULT_WINDOW)
+    }
+}
+
+impl VolatilityEstimator {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(2),
+            current: None,
+            bars: VecDeque::new(),
+        }
+    }
+
+    /// 가격 샘플 추가
+    pub fn update(&mut self, ts_ms: i64, price: f64) {
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+        let start_ms = ts_ms - ts_ms.rem_euclid(BAR_MS);
+
+        match self.current.as_mut() {
+            Some(bar) if bar.start_ms == start_ms => {
+                bar.high = bar.high.max(price);
+                bar.low = bar.low.min(price);
+                bar.close = price;
+            }
+            Some(bar) if bar.start_ms > start_ms => {}
+            _ => {
+                if let Some(done) = self.current.take() {
+                    if self.bars.len() == self.window {
+                        self.bars.pop_front();
+                    }
+                    self.bars.push_back(done);
+                }
+                self.current = Some(Bar::new(start_ms, price));
+            }
+        }
+    }
+
+    /// 완성된 봉 개수
+    pub fn bar_count(&self) -> usize {
+        self.bars.len()
+    }
+
+    /// 완성된 봉 기준 ATR (가격 단위). 봉이 2개 미만이면 None
+    pub fn atr(&self) -> Option<f64> {
+        if self.bars.len() < 2 {
+            return None;
+        }
+        let sum: f64 = self
+            .bars
+            .iter()
+            .zip(self.bars.iter().skip(1))
+            .map(|(prev, bar)| bar.true_range(prev.close))
+            .sum();
+        Some(sum / (self.bars.len() - 1) as f64)
+    }
+
+    /// 1분 로그 수익률의 표본 표준편차. 수익률이 2개 미만이면 None
+    pub fn return_std(&self) -> Option<f64> {
+        let returns: Vec<f64> = self
+            .bars
+            .iter()
+            .zip(self.bars.iter().skip(1))
+            .map(|(prev, bar)| (bar.close / prev.close).ln())
+            .collect();
+        if returns.len() < 2 {
+            return None;
+        }
+        let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+        let var =
+            returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+        Some(var.sqrt())
+    }
+
+    /// ATR과 수익률 σ를 bps로 환산한 추정치
+    pub fn estimate(&self) -> Option<VolatilityEstimate> {
+        let last_close = self.bars.back()?.close;
+        Some(VolatilityEstimate {
+            atr_bps: self.atr()? / last_close * 10_000.0,
+            return_std_bps: self.return_std()? * 10_000.0,
+            bars: self.bars.len(),
+        })
+    }
+}
+
+/// 사이징에 사용할 변동성 지표
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolatilityMeasure {
+    /// 1분봉 ATR (가격 대비 bps)
+    Atr,
+    /// 1분 로그 수익률 표준편차 (bps)
+    ReturnStd,
+}
+
+impl std::str::FromStr for VolatilityMeasure {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "atr" => Ok(VolatilityMeasure::Atr),
+            "return_std" | "std" => Ok(VolatilityMeasure::ReturnStd),
+            other => Err(format!("Unknown volatility measure: {}", other)),
+        }
+    }
+}
+
+/// 변동성 기반 명목가 스케일링 설정
+///
+/// 명목가 = notional × clamp(target_bps / 현재 변동성, min_scale, max_scale)
+/// 변동성이 목표보다 크면 명목가를 줄이고, 작으면 늘린다.
+#[derive(Debug, Clone, Copy)]
+pub struct VolatilitySizing {
+    pub measure: VolatilityMeasure,
+    /// 기준 변동성 (bps). 이 변동성에서 notional 그대로 거래
+    pub target_bps: f64,
+    /// 최소 스케일 (예: 0.25 = notional의 25%까지 축소)
+    pub min_scale: f64,
+    /// 최대 스케일 (예: 2.0 = notional의 2배까지 확대)
+    pub max_scale: f64,
+    /// 스케일링에 필요한 최소 완성 봉 개수 (부족하면 스케일 1.0)
+    pub min_bars: usize,
+}
+
+impl Default for VolatilitySizing {
+    fn default() -> Self {
+        Self {
+            measure: VolatilityMeasure::Atr,
+            target_bps: 10.0,
+            min_scale: 0.25,
+            max_scale: 2.0,
+            min_bars: 15,
+        }
+    }
+}
+
+impl VolatilitySizing {
+    /// 추정치로부터 명목가 스케일 계산
+    pub fn scale(&self, estimate: Option<&VolatilityEstimate>) -> f64 {
+        let Some(estimate) = estimate else {
+            return 1.0;
+        };
+        let vol = estimate.value(self.measure);
+        if estimate.bars < self.min_bars || !vol.is_finite() || vol <= 0.0 {
+            return 1.0;
+        }
+        (self.target_bps / vol).clamp(self.min_scale, self.max_scale)
+    }
+}
+
+/// 심볼별 변동성 추정기 레지스트리 (가격 피드에서 갱신)
+#[derive(Debug, Default)]
+pub struct VolatilityRegistry {
+    estimators: RwLock<HashMap<String, VolatilityEstimator>>,
+}
+
+impl VolatilityRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 가격 샘플 기록
+    pub fn record(&self, symbol: &str, ts_ms: i64, price: f64) {
+        let mut estimators = self.estimators.write().unwrap();
+        estimators
+            .entry(symbol.to_string())
+            .or_default()
+            .update(ts_ms, price);
+    }
+
+    /// 심볼의 현재 변동성 추정치
+    pub fn estimate(&self, symbol: &str) -> Option<VolatilityEstimate> {
+        let estimators = self.estimators.read().unwrap();
+        estimators.get(symbol)?.estimate()
+    }
+}
+
+/// 전역 변동성 레지스트리
+static GLOBAL_VOLATILITY: OnceLock<VolatilityRegistry> = OnceLock::new();
+
+/// 전역 변동성 레지스트리 가져오기 (최초 호출 시 생성)
+pub fn volatility_registry() -> &'static VolatilityRegistry {
+    GLOBAL_VOLATILITY.get_or_init(VolatilityRegistry::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed_closes(estimator: &mut VolatilityEstimator, closes: &[f64]) {
+        for (i, close) in closes.iter().enumerate() {
+            estimator.update(i as i64 * BAR_MS, *close);
+        }
+    }
+
+    #[test]
+    fn test_bars_aggregate_samples_within_minute() {
+        let mut estimator = VolatilityEstimator::new(10);
+        estimator.update(0, 100.0);
+        estimator.update(10_000, 102.0);
+        estimator.update(20_000, 99.0);
+        estimator.update(30_000, 101.0);
+        // 과거 봉의 샘플은 무시
+        estimator.update(BAR_MS, 100.0);
+        estimator.update(-1, 500.0);
+        estimator.update(2 * BAR_MS, 100.0);
+
+        assert_eq!(estimator.bar_count(), 2);
+        assert_eq!(
+            estimator.bars[0],
+            Bar {
+                start_ms: 0,
+                open: 100.0,
+                high: 102.0,
+                low: 99.0,
+                close: 101.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_atr_and_return_std() {
+        let mut estimator = VolatilityEstimator::new(10);
+        // 완성 봉: 100, 101, 100, 101 (마지막 샘플은 진행 중인 봉)
+        feed_closes(&mut estimator, &[100.0, 101.0, 100.0, 101.0, 101.0]);
+
+        assert_eq!(estimator.bar_count(), 4);
+        assert!((estimator.atr().unwrap() - 1.0).abs() < 1e-9);
+
+        let estimate = estimator.estimate().unwrap();
+        assert!((estimate.atr_bps - 1.0 / 101.0 * 10_000.0).abs() < 1e-6);
+        assert!(estimate.return_std_bps > 0.0);
+    }
+
+    #[test]
+    fn test_window_is_bounded() {
+        let mut estimator = VolatilityEstimator::new(3);
+        feed_closes(&mut estimator, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(estimator.bar_count(), 3);
+        assert_eq!(estimator.bars[0].close, 3.0);
+    }
+
+    #[test]
+    fn test_sizing_scale() {
+        let sizing = VolatilitySizing {
+            target_bps: 10.0,
+            min_bars: 5,
+            ..Default::default()
+        };
+        let estimate = |atr_bps: f64, bars: usize| VolatilityEstimate {
+            atr_bps,
+            return_std_bps: 0.0,
+            bars,
+        };
+
+        // 변동성 20bps → 절반
+        assert!((sizing.scale(Some(&estimate(20.0, 10))) - 0.5).abs() < 1e-9);
+        // 변동성이 매우 작으면 max_scale로 제한
+        assert_eq!(sizing.scale(Some(&estimate(1.0, 10))), 2.0);
+        // 변동성이 매우 크면 min_scale로 제한
+        assert_eq!(sizing.scale(Some(&estimate(1_000.0, 10))), 0.25);
+        // 데이터 부족 시 스케일 없음
+        assert_eq!(sizing.scale(Some(&estimate(20.0, 3))), 1.0);
+        assert_eq!(sizing.scale(None), 1.0);
+    }
+}
@@ -2,7 +2,7 @@ pub mod order;
 pub mod trade;
 pub mod snapshot;
 
-pub use order::{Order, OrderSide, OrderType};
-pub use trade::Trade;
+pub use order::{Order, OrderSide, OrderType, SelfTradePrevention, TimeInForce};
+pub use trade::{Trade, TriggerEvent};
 pub use snapshot::MarketSnapshot;
 
@@ -8,10 +8,63 @@ pub enum OrderSide {
     Sell,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum OrderType {
     Limit,
     Market,
+    /// 마지막 체결가가 stop_price에 닿으면 Market 주문으로 전환
+    /// (Buy: 체결가 >= stop_price, Sell: 체결가 <= stop_price)
+    StopMarket { stop_price: f64 },
+    /// 마지막 체결가가 stop_price에 닿으면 price의 Limit 주문으로 전환
+    StopLimit { stop_price: f64 },
+    /// 오더북에는 display_qty만 노출되고, 노출분이 모두 체결되면 숨은 수량에서 다시 채움
+    Iceberg { display_qty: f64 },
+}
+
+impl OrderType {
+    /// 스탑 주문이면 stop_price 반환
+    pub fn stop_price(&self) -> Option<f64> {
+        match self {
+            OrderType::StopMarket { stop_price } | OrderType::StopLimit { stop_price } => {
+                Some(*stop_price)
+            }
+            _ => None,
+        }
+    }
+
+    /// 가격이 필요한 주문인지 (Limit, StopLimit, Iceberg)
+    pub fn requires_price(&self) -> bool {
+        matches!(
+            self,
+            OrderType::Limit | OrderType::StopLimit { .. } | OrderType::Iceberg { .. }
+        )
+    }
+}
+
+/// 주문 유효 조건
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TimeInForce {
+    /// 체결되지 않은 수량은 오더북에 남김
+    #[default]
+    Gtc,
+    /// 즉시 체결 가능한 만큼만 체결하고 남은 수량은 취소
+    Ioc,
+    /// 전량 즉시 체결되지 않으면 주문 전체 취소
+    Fok,
+}
+
+impl std::str::FromStr for TimeInForce {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "GTC" => Ok(TimeInForce::Gtc),
+            "IOC" => Ok(TimeInForce::Ioc),
+            "FOK" => Ok(TimeInForce::Fok),
+            other => Err(format!("unknown time in force: {}", other)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,5 +75,39 @@ pub struct Order {
     pub price: Option<f64>, // None for Market orders
     pub quantity: f64,
     pub timestamp: DateTime<Utc>,
+    /// 주문 소유 계정 (None이면 익명 플로우, self-trade prevention 대상 아님)
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// 유효 조건 (기록된 이전 세션은 GTC로 읽음)
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+}
+
+/// 같은 계정의 주문끼리 체결될 때의 처리 방식 (self-trade prevention)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SelfTradePrevention {
+    /// 자기 체결 허용
+    #[default]
+    None,
+    /// 들어온(taker) 주문의 남은 수량 취소
+    CancelNewest,
+    /// 오더북에 있던(maker) 주문 취소 후 매칭 계속
+    CancelOldest,
+    /// 겹치는 수량만큼 양쪽 수량을 체결 없이 차감
+    DecrementBoth,
+}
+
+impl std::str::FromStr for SelfTradePrevention {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "none" => Ok(SelfTradePrevention::None),
+            "cancel-newest" => Ok(SelfTradePrevention::CancelNewest),
+            "cancel-oldest" => Ok(SelfTradePrevention::CancelOldest),
+            "decrement-both" => Ok(SelfTradePrevention::DecrementBoth),
+            other => Err(format!("unknown self-trade prevention policy: {}", other)),
+        }
+    }
 }
 
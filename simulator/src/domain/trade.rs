@@ -1,12 +1,25 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 use crate::domain::order::OrderSide;
 
-#[derive(Debug, Clone, Serialize)]
+/// 스탑 주문 발동 정보
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TriggerEvent {
+    pub order_id: Uuid,
+    pub stop_price: f64,
+    /// 발동 시점의 마지막 체결가
+    pub trigger_price: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
     pub price: f64,
     pub quantity: f64,
     pub side: OrderSide, // 매수 주문인지 매도 주문인지
     pub timestamp: DateTime<Utc>,
+    /// 발동된 스탑 주문이 만든 체결이면 발동 정보 (스탑 연쇄 추적용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<TriggerEvent>,
 }
 
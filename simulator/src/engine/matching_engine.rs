@@ -1,7 +1,11 @@
-use crate::domain::{MarketSnapshot, Order, OrderSide, OrderType, Trade};
+use crate::domain::{
+    MarketSnapshot, Order, OrderSide, OrderType, SelfTradePrevention, TimeInForce, Trade,
+    TriggerEvent,
+};
 use chrono::Utc;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Debug, Error)]
 pub enum EngineError {
@@ -9,13 +13,26 @@ pub enum EngineError {
     PriceMissing,
     #[error("Order quantity must be positive")]
     InvalidQuantity,
+    #[error("Iceberg display quantity must be positive")]
+    InvalidDisplayQuantity,
+    #[error("Stop order would trigger immediately")]
+    WouldTriggerImmediately,
 }
 
+/// 수량 합산 시 부동소수점 오차 허용치
+const QTY_EPSILON: f64 = 1e-9;
+
 pub struct MatchingEngine {
     bids: Vec<Order>,         // sorted by price desc
     asks: Vec<Order>,         // sorted by price asc
     trades: VecDeque<Trade>,  // recent trades (queue for FIFO removal)
     max_trade_history: usize, // max number of stored trades
+    /// 발동 대기 중인 스탑 주문 (접수 순)
+    stop_orders: Vec<Order>,
+    /// 아이스버그 주문의 숨은 잔량 (order id -> 수량)
+    hidden: HashMap<Uuid, f64>,
+    /// 같은 owner 주문끼리 만났을 때의 처리 정책
+    stp: SelfTradePrevention,
 }
 
 impl MatchingEngine {
@@ -25,9 +42,17 @@ impl MatchingEngine {
             asks: Vec::new(),
             trades: VecDeque::new(),
             max_trade_history: 100, // keep up to 100 recent trades
+            stop_orders: Vec::new(),
+            hidden: HashMap::new(),
+            stp: SelfTradePrevention::None,
         }
     }
 
+    /// self-trade prevention 정책 설정 (owner가 같은 주문끼리만 적용)
+    pub fn set_self_trade_prevention(&mut self, policy: SelfTradePrevention) {
+        self.stp = policy;
+    }
+
     /// Returns a snapshot of current market (best bid/ask and last trade price).
     /// best_bid는 bids[0]의 가격, best_ask는 asks[0]의 가격을 사용합니다.
     pub fn get_snapshot(&self) -> MarketSnapshot {
@@ -62,50 +87,258 @@ impl MatchingEngine {
     }
 
     /// Submits an order and returns a list of trades that occurred.
-    pub fn submit_order(&mut self, mut order: Order) -> Result<Vec<Trade>, EngineError> {
+    /// 주문으로 인해 발동된 스탑 주문의 체결도 함께 반환합니다 (Trade.trigger로 구분).
+    pub fn submit_order(&mut self, order: Order) -> Result<Vec<Trade>, EngineError> {
         // Validate order
-        match order.order_type {
-            OrderType::Limit => {
-                if order.price.is_none() {
-                    return Err(EngineError::PriceMissing);
-                }
-            }
-            OrderType::Market => {
-                // Market orders don't need price validation
-            }
+        if order.order_type.requires_price() && order.price.is_none() {
+            return Err(EngineError::PriceMissing);
         }
 
         if order.quantity <= 0.0 {
             return Err(EngineError::InvalidQuantity);
         }
 
+        if let OrderType::Iceberg { display_qty } = order.order_type {
+            if display_qty <= 0.0 {
+                return Err(EngineError::InvalidDisplayQuantity);
+            }
+        }
+
         let mut trades = Vec::new();
+        if order.order_type.stop_price().is_some() {
+            // 접수 시점에 이미 발동 조건을 만족하면 거절 (실거래소와 동일)
+            if let Some(last) = self.trades.back().map(|t| t.price) {
+                if Self::stop_hit(&order, last) {
+                    return Err(EngineError::WouldTriggerImmediately);
+                }
+            }
+            self.stop_orders.push(order);
+        } else {
+            self.execute(order, None, &mut trades);
+        }
+
+        self.process_stop_triggers(&mut trades);
+
+        // Trim trade history if needed
+        while self.trades.len() > self.max_trade_history {
+            self.trades.pop_front();
+        }
+
+        Ok(trades)
+    }
+
+    /// 주문을 매칭하고 남은 수량을 오더북에 올림
+    /// IOC는 남은 수량을 버리고, FOK는 전량 체결되지 않으면 오더북과 체결 기록을 주문 전으로 되돌립니다.
+    /// FOK가 STP에 걸리면 전량 체결이 아니므로 주문 전체를 거절하고 STP로 차감된 오더북도 되돌립니다.
+    fn execute(
+        &mut self,
+        mut order: Order,
+        trigger: Option<TriggerEvent>,
+        trades: &mut Vec<Trade>,
+    ) {
+        let before = (order.time_in_force == TimeInForce::Fok).then(|| {
+            (
+                self.bids.clone(),
+                self.asks.clone(),
+                self.hidden.clone(),
+                self.trades.len(),
+                trades.len(),
+            )
+        });
+
         let remaining_qty = match order.side {
-            OrderSide::Buy => self.match_buy_order(&mut order, &mut trades)?,
-            OrderSide::Sell => self.match_sell_order(&mut order, &mut trades)?,
+            OrderSide::Buy => self.match_buy_order(&mut order, trigger, trades),
+            OrderSide::Sell => self.match_sell_order(&mut order, trigger, trades),
         };
 
+        if let Some((bids, asks, hidden, history_len, batch_len)) = before {
+            // STP로 취소/차감된 수량은 남은 수량이 0이어도 체결이 아니므로 실제 체결량으로 판단
+            let filled: f64 = trades[batch_len..].iter().map(|t| t.quantity).sum();
+            if remaining_qty <= 0.0 && filled + QTY_EPSILON >= order.quantity {
+                return;
+            }
+            self.bids = bids;
+            self.asks = asks;
+            self.hidden = hidden;
+            self.trades.truncate(history_len);
+            trades.truncate(batch_len);
+            return;
+        }
+
+        if remaining_qty <= 0.0 {
+            return;
+        }
+        if order.time_in_force == TimeInForce::Ioc {
+            return;
+        }
+
         // If there's remaining quantity and it's a limit order, add to book
-        if remaining_qty > 0.0 && matches!(order.order_type, OrderType::Limit) {
-            order.quantity = remaining_qty;
-            match order.side {
-                OrderSide::Buy => {
-                    self.insert_bid(order);
-                }
-                OrderSide::Sell => {
-                    self.insert_ask(order);
+        match order.order_type {
+            OrderType::Limit => {
+                order.quantity = remaining_qty;
+                self.insert_resting(order);
+            }
+            OrderType::Iceberg { display_qty } => {
+                // 노출 수량만 오더북에 올리고 나머지는 숨김
+                let visible = remaining_qty.min(display_qty);
+                if remaining_qty > visible {
+                    self.hidden.insert(order.id, remaining_qty - visible);
                 }
+                order.quantity = visible;
+                self.insert_resting(order);
             }
+            _ => {}
         }
+    }
 
-        Ok(trades)
+    /// 스탑 주문 발동 조건 (Buy: 체결가 >= stop, Sell: 체결가 <= stop)
+    fn stop_hit(order: &Order, last_price: f64) -> bool {
+        match (order.order_type.stop_price(), order.side) {
+            (Some(stop), OrderSide::Buy) => last_price >= stop,
+            (Some(stop), OrderSide::Sell) => last_price <= stop,
+            (None, _) => false,
+        }
+    }
+
+    /// 마지막 체결가로 스탑 주문을 발동 (발동된 주문의 체결이 다른 스탑을 연쇄 발동할 수 있음)
+    fn process_stop_triggers(&mut self, trades: &mut Vec<Trade>) {
+        loop {
+            let Some(last_price) = self.trades.back().map(|t| t.price) else {
+                return;
+            };
+            let Some(idx) = self
+                .stop_orders
+                .iter()
+                .position(|o| Self::stop_hit(o, last_price))
+            else {
+                return;
+            };
+
+            let mut order = self.stop_orders.remove(idx);
+            let trigger = TriggerEvent {
+                order_id: order.id,
+                stop_price: order.order_type.stop_price().unwrap_or(last_price),
+                trigger_price: last_price,
+            };
+            order.order_type = match order.order_type {
+                OrderType::StopLimit { .. } => OrderType::Limit,
+                _ => OrderType::Market,
+            };
+            if order.order_type == OrderType::Market {
+                order.price = None;
+            }
+            self.execute(order, Some(trigger), trades);
+        }
+    }
+
+    fn record_trade(
+        &mut self,
+        price: f64,
+        quantity: f64,
+        side: OrderSide,
+        trigger: Option<TriggerEvent>,
+        trades: &mut Vec<Trade>,
+    ) {
+        let trade = Trade {
+            price,
+            quantity,
+            side,
+            timestamp: Utc::now(),
+            trigger,
+        };
+        trades.push(trade.clone());
+        self.trades.push_back(trade);
+    }
+
+    /// 완전히 체결된 아이스버그 주문을 숨은 잔량으로 다시 채움
+    /// 다시 채워진 주문은 같은 가격 레벨의 맨 뒤로 들어가 시간 우선순위를 잃습니다.
+    fn replenish_iceberg(&mut self, mut filled: Order) {
+        let OrderType::Iceberg { display_qty } = filled.order_type else {
+            return;
+        };
+        let Some(hidden) = self.hidden.remove(&filled.id) else {
+            return;
+        };
+
+        let visible = hidden.min(display_qty);
+        if hidden > visible {
+            self.hidden.insert(filled.id, hidden - visible);
+        }
+        filled.quantity = visible;
+        filled.timestamp = Utc::now();
+
+        let price = filled.price.unwrap_or(0.0);
+        match filled.side {
+            OrderSide::Buy => {
+                let pos = self
+                    .bids
+                    .partition_point(|o| o.price.unwrap_or(0.0) >= price);
+                self.bids.insert(pos, filled);
+            }
+            OrderSide::Sell => {
+                let pos = self
+                    .asks
+                    .partition_point(|o| o.price.unwrap_or(0.0) <= price);
+                self.asks.insert(pos, filled);
+            }
+        }
+    }
+
+    fn insert_resting(&mut self, order: Order) {
+        match order.side {
+            OrderSide::Buy => {
+                self.insert_bid(order);
+            }
+            OrderSide::Sell => {
+                self.insert_ask(order);
+            }
+        }
+    }
+
+    /// 오더북 최우선 주문이 taker와 같은 owner면 STP 정책을 적용
+    /// 정책을 적용했으면 taker의 남은 수량을, 일반 체결을 진행해야 하면 None을 반환합니다.
+    fn prevent_self_trade(&mut self, taker: &Order, remaining_qty: f64) -> Option<f64> {
+        if self.stp == SelfTradePrevention::None {
+            return None;
+        }
+        let book = match taker.side {
+            OrderSide::Buy => &mut self.asks,
+            OrderSide::Sell => &mut self.bids,
+        };
+        let resting = book.first()?;
+        match (&taker.owner, &resting.owner) {
+            (Some(a), Some(b)) if a == b => {}
+            _ => return None,
+        }
+
+        match self.stp {
+            SelfTradePrevention::None => None,
+            SelfTradePrevention::CancelNewest => Some(0.0),
+            SelfTradePrevention::CancelOldest => {
+                let cancelled = book.remove(0);
+                self.hidden.remove(&cancelled.id);
+                Some(remaining_qty)
+            }
+            SelfTradePrevention::DecrementBoth => {
+                let qty = remaining_qty.min(resting.quantity);
+                if resting.quantity > qty {
+                    book[0].quantity -= qty;
+                } else {
+                    // 노출분이 모두 차감된 아이스버그는 숨은 잔량으로 다시 채움
+                    let decremented = book.remove(0);
+                    self.replenish_iceberg(decremented);
+                }
+                Some(remaining_qty - qty)
+            }
+        }
     }
 
     fn match_buy_order(
         &mut self,
         order: &mut Order,
+        trigger: Option<TriggerEvent>,
         trades: &mut Vec<Trade>,
-    ) -> Result<f64, EngineError> {
+    ) -> f64 {
         let mut remaining_qty = order.quantity;
         let buy_price = order.price;
 
@@ -123,18 +356,17 @@ impl MatchingEngine {
                 break;
             }
 
+            if let Some(rest) = self.prevent_self_trade(order, remaining_qty) {
+                remaining_qty = rest;
+                continue;
+            }
+
+            let ask = &self.asks[0];
             let trade_qty = remaining_qty.min(ask.quantity);
             let trade_price = ask_price; // Price-time priority: use resting order's price
 
             // Create trade (매수 주문이 체결됨)
-            let trade = Trade {
-                price: trade_price,
-                quantity: trade_qty,
-                side: OrderSide::Buy, // 매수 주문이 체결됨
-                timestamp: Utc::now(),
-            };
-            trades.push(trade.clone());
-            self.trades.push_back(trade);
+            self.record_trade(trade_price, trade_qty, OrderSide::Buy, trigger, trades);
 
             // Update quantities
             remaining_qty -= trade_qty;
@@ -144,22 +376,21 @@ impl MatchingEngine {
                 // Partial fill of resting order
                 matched_ask.quantity -= trade_qty;
                 self.insert_ask(matched_ask);
+            } else {
+                // 노출분이 모두 체결된 아이스버그는 숨은 잔량으로 다시 채움
+                self.replenish_iceberg(matched_ask);
             }
         }
 
-        // Trim trade history if needed
-        while self.trades.len() > self.max_trade_history {
-            self.trades.pop_front();
-        }
-
-        Ok(remaining_qty)
+        remaining_qty
     }
 
     fn match_sell_order(
         &mut self,
         order: &mut Order,
+        trigger: Option<TriggerEvent>,
         trades: &mut Vec<Trade>,
-    ) -> Result<f64, EngineError> {
+    ) -> f64 {
         let mut remaining_qty = order.quantity;
         let sell_price = order.price;
 
@@ -177,18 +408,17 @@ impl MatchingEngine {
                 break;
             }
 
+            if let Some(rest) = self.prevent_self_trade(order, remaining_qty) {
+                remaining_qty = rest;
+                continue;
+            }
+
+            let bid = &self.bids[0];
             let trade_qty = remaining_qty.min(bid.quantity);
             let trade_price = bid_price; // Price-time priority: use resting order's price
 
             // Create trade (매도 주문이 체결됨)
-            let trade = Trade {
-                price: trade_price,
-                quantity: trade_qty,
-                side: OrderSide::Sell, // 매도 주문이 체결됨
-                timestamp: Utc::now(),
-            };
-            trades.push(trade.clone());
-            self.trades.push_back(trade);
+            self.record_trade(trade_price, trade_qty, OrderSide::Sell, trigger, trades);
 
             // Update quantities
             remaining_qty -= trade_qty;
@@ -198,15 +428,13 @@ impl MatchingEngine {
                 // Partial fill of resting order
                 matched_bid.quantity -= trade_qty;
                 self.insert_bid(matched_bid);
+            } else {
+                // 노출분이 모두 체결된 아이스버그는 숨은 잔량으로 다시 채움
+                self.replenish_iceberg(matched_bid);
             }
         }
 
-        // Trim trade history if needed
-        while self.trades.len() > self.max_trade_history {
-            self.trades.pop_front();
-        }
-
-        Ok(remaining_qty)
+        remaining_qty
     }
 
     fn insert_bid(&mut self, order: Order) {
@@ -254,4 +482,393 @@ impl MatchingEngine {
     pub fn get_trades(&self) -> Vec<&Trade> {
         self.trades.iter().collect()
     }
+
+    /// 발동 대기 중인 스탑 주문
+    pub fn get_stop_orders(&self) -> Vec<&Order> {
+        self.stop_orders.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(
+        side: OrderSide,
+        order_type: OrderType,
+        price: Option<f64>,
+        quantity: f64,
+        owner: Option<&str>,
+        time_in_force: TimeInForce,
+    ) -> Order {
+        Order {
+            id: Uuid::new_v4(),
+            side,
+            order_type,
+            price,
+            quantity,
+            timestamp: Utc::now(),
+            owner: owner.map(str::to_string),
+            time_in_force,
+        }
+    }
+
+    fn limit(side: OrderSide, price: f64, quantity: f64) -> Order {
+        order(
+            side,
+            OrderType::Limit,
+            Some(price),
+            quantity,
+            None,
+            TimeInForce::Gtc,
+        )
+    }
+
+    fn owned(side: OrderSide, price: f64, quantity: f64, owner: &str, tif: TimeInForce) -> Order {
+        order(
+            side,
+            OrderType::Limit,
+            Some(price),
+            quantity,
+            Some(owner),
+            tif,
+        )
+    }
+
+    fn market(side: OrderSide, quantity: f64) -> Order {
+        order(
+            side,
+            OrderType::Market,
+            None,
+            quantity,
+            None,
+            TimeInForce::Gtc,
+        )
+    }
+
+    fn book_qty(orders: &[&Order]) -> Vec<(f64, f64)> {
+        orders
+            .iter()
+            .map(|o| (o.price.unwrap_or(0.0), o.quantity))
+            .collect()
+    }
+
+    #[test]
+    fn test_validation_rejects_invalid_orders() {
+        let mut engine = MatchingEngine::new();
+
+        let no_price = order(
+            OrderSide::Buy,
+            OrderType::Limit,
+            None,
+            1.0,
+            None,
+            TimeInForce::Gtc,
+        );
+        assert!(matches!(
+            engine.submit_order(no_price),
+            Err(EngineError::PriceMissing)
+        ));
+        assert!(matches!(
+            engine.submit_order(limit(OrderSide::Buy, 100.0, 0.0)),
+            Err(EngineError::InvalidQuantity)
+        ));
+        let iceberg = order(
+            OrderSide::Sell,
+            OrderType::Iceberg { display_qty: 0.0 },
+            Some(100.0),
+            5.0,
+            None,
+            TimeInForce::Gtc,
+        );
+        assert!(matches!(
+            engine.submit_order(iceberg),
+            Err(EngineError::InvalidDisplayQuantity)
+        ));
+
+        // 마지막 체결가 100에서 stop 100 매도는 즉시 발동 조건이므로 거절
+        engine
+            .submit_order(limit(OrderSide::Sell, 100.0, 1.0))
+            .unwrap();
+        engine.submit_order(market(OrderSide::Buy, 1.0)).unwrap();
+        let stop = order(
+            OrderSide::Sell,
+            OrderType::StopMarket { stop_price: 100.0 },
+            None,
+            1.0,
+            None,
+            TimeInForce::Gtc,
+        );
+        assert!(matches!(
+            engine.submit_order(stop),
+            Err(EngineError::WouldTriggerImmediately)
+        ));
+        assert!(engine.get_stop_orders().is_empty());
+        let (bids, asks) = engine.get_orderbook();
+        assert!(bids.is_empty() && asks.is_empty());
+    }
+
+    #[test]
+    fn test_stop_market_triggers_on_last_trade() {
+        let mut engine = MatchingEngine::new();
+        engine
+            .submit_order(limit(OrderSide::Sell, 100.0, 1.0))
+            .unwrap();
+        engine
+            .submit_order(limit(OrderSide::Sell, 101.0, 1.0))
+            .unwrap();
+        engine
+            .submit_order(limit(OrderSide::Sell, 105.0, 5.0))
+            .unwrap();
+
+        let stop = order(
+            OrderSide::Buy,
+            OrderType::StopMarket { stop_price: 101.0 },
+            None,
+            2.0,
+            None,
+            TimeInForce::Gtc,
+        );
+        let stop_id = stop.id;
+        assert!(engine.submit_order(stop).unwrap().is_empty());
+        assert_eq!(engine.get_stop_orders().len(), 1);
+
+        let trades = engine.submit_order(market(OrderSide::Buy, 2.0)).unwrap();
+        assert_eq!(trades.len(), 3);
+        assert!(trades[..2].iter().all(|t| t.trigger.is_none()));
+        let triggered = &trades[2];
+        assert_eq!(triggered.price, 105.0);
+        assert_eq!(triggered.quantity, 2.0);
+        let event = triggered.trigger.expect("trigger event");
+        assert_eq!(event.order_id, stop_id);
+        assert_eq!(event.stop_price, 101.0);
+        assert_eq!(event.trigger_price, 101.0);
+
+        assert!(engine.get_stop_orders().is_empty());
+        let (_, asks) = engine.get_orderbook();
+        assert_eq!(book_qty(&asks), vec![(105.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_stop_limit_rests_at_limit_price_when_triggered() {
+        let mut engine = MatchingEngine::new();
+        engine
+            .submit_order(limit(OrderSide::Buy, 100.0, 1.0))
+            .unwrap();
+        engine
+            .submit_order(limit(OrderSide::Buy, 95.0, 1.0))
+            .unwrap();
+
+        let stop = order(
+            OrderSide::Sell,
+            OrderType::StopLimit { stop_price: 100.0 },
+            Some(98.0),
+            2.0,
+            None,
+            TimeInForce::Gtc,
+        );
+        engine.submit_order(stop).unwrap();
+
+        let trades = engine.submit_order(market(OrderSide::Sell, 1.0)).unwrap();
+        // 발동된 StopLimit은 98 위로만 체결되므로 95 매수와는 만나지 않고 오더북에 남음
+        assert_eq!(trades.len(), 1);
+        assert!(engine.get_stop_orders().is_empty());
+        let (bids, asks) = engine.get_orderbook();
+        assert_eq!(book_qty(&bids), vec![(95.0, 1.0)]);
+        assert_eq!(book_qty(&asks), vec![(98.0, 2.0)]);
+        assert_eq!(asks[0].order_type, OrderType::Limit);
+    }
+
+    #[test]
+    fn test_iceberg_replenishes_from_hidden_quantity() {
+        let mut engine = MatchingEngine::new();
+        let iceberg = order(
+            OrderSide::Sell,
+            OrderType::Iceberg { display_qty: 2.0 },
+            Some(100.0),
+            5.0,
+            None,
+            TimeInForce::Gtc,
+        );
+        engine.submit_order(iceberg).unwrap();
+        let (_, asks) = engine.get_orderbook();
+        assert_eq!(book_qty(&asks), vec![(100.0, 2.0)]);
+
+        let trades = engine.submit_order(market(OrderSide::Buy, 2.0)).unwrap();
+        assert_eq!(trades.len(), 1);
+        let (_, asks) = engine.get_orderbook();
+        assert_eq!(book_qty(&asks), vec![(100.0, 2.0)]);
+
+        // 노출분 2를 체결한 뒤 마지막 숨은 잔량 1로 다시 채워서 계속 체결
+        let trades = engine.submit_order(market(OrderSide::Buy, 3.0)).unwrap();
+        let filled: Vec<f64> = trades.iter().map(|t| t.quantity).collect();
+        assert_eq!(filled, vec![2.0, 1.0]);
+        let (_, asks) = engine.get_orderbook();
+        assert!(asks.is_empty());
+    }
+
+    #[test]
+    fn test_stp_cancel_newest_drops_taker() {
+        let mut engine = MatchingEngine::new();
+        engine.set_self_trade_prevention(SelfTradePrevention::CancelNewest);
+        engine
+            .submit_order(owned(OrderSide::Sell, 100.0, 1.0, "a", TimeInForce::Gtc))
+            .unwrap();
+
+        let trades = engine
+            .submit_order(owned(OrderSide::Buy, 100.0, 1.0, "a", TimeInForce::Gtc))
+            .unwrap();
+        assert!(trades.is_empty());
+        let (bids, asks) = engine.get_orderbook();
+        assert!(bids.is_empty());
+        assert_eq!(book_qty(&asks), vec![(100.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_stp_cancel_oldest_drops_maker_and_keeps_matching() {
+        let mut engine = MatchingEngine::new();
+        engine.set_self_trade_prevention(SelfTradePrevention::CancelOldest);
+        engine
+            .submit_order(owned(OrderSide::Sell, 100.0, 1.0, "a", TimeInForce::Gtc))
+            .unwrap();
+        engine
+            .submit_order(owned(OrderSide::Sell, 101.0, 1.0, "b", TimeInForce::Gtc))
+            .unwrap();
+
+        let trades = engine
+            .submit_order(owned(OrderSide::Buy, 101.0, 2.0, "a", TimeInForce::Gtc))
+            .unwrap();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].price, 101.0);
+        let (bids, asks) = engine.get_orderbook();
+        assert!(asks.is_empty());
+        assert_eq!(book_qty(&bids), vec![(101.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_stp_decrement_both_reduces_without_trade() {
+        let mut engine = MatchingEngine::new();
+        engine.set_self_trade_prevention(SelfTradePrevention::DecrementBoth);
+        engine
+            .submit_order(owned(OrderSide::Sell, 100.0, 3.0, "a", TimeInForce::Gtc))
+            .unwrap();
+
+        let trades = engine
+            .submit_order(owned(OrderSide::Buy, 100.0, 1.0, "a", TimeInForce::Gtc))
+            .unwrap();
+        assert!(trades.is_empty());
+        assert!(engine.get_trades().is_empty());
+        let (bids, asks) = engine.get_orderbook();
+        assert!(bids.is_empty());
+        assert_eq!(book_qty(&asks), vec![(100.0, 2.0)]);
+    }
+
+    #[test]
+    fn test_stp_ignores_different_or_anonymous_owners() {
+        let mut engine = MatchingEngine::new();
+        engine.set_self_trade_prevention(SelfTradePrevention::CancelNewest);
+        engine
+            .submit_order(owned(OrderSide::Sell, 100.0, 1.0, "a", TimeInForce::Gtc))
+            .unwrap();
+        engine
+            .submit_order(limit(OrderSide::Sell, 100.0, 1.0))
+            .unwrap();
+
+        let trades = engine
+            .submit_order(owned(OrderSide::Buy, 100.0, 1.0, "b", TimeInForce::Gtc))
+            .unwrap();
+        assert_eq!(trades.len(), 1);
+        let trades = engine.submit_order(market(OrderSide::Buy, 1.0)).unwrap();
+        assert_eq!(trades.len(), 1);
+    }
+
+    #[test]
+    fn test_ioc_discards_unfilled_rest() {
+        let mut engine = MatchingEngine::new();
+        engine
+            .submit_order(limit(OrderSide::Sell, 100.0, 1.0))
+            .unwrap();
+
+        let ioc = order(
+            OrderSide::Buy,
+            OrderType::Limit,
+            Some(100.0),
+            3.0,
+            None,
+            TimeInForce::Ioc,
+        );
+        let trades = engine.submit_order(ioc).unwrap();
+        assert_eq!(trades.len(), 1);
+        let (bids, asks) = engine.get_orderbook();
+        assert!(bids.is_empty() && asks.is_empty());
+    }
+
+    #[test]
+    fn test_fok_fills_fully_or_restores_book() {
+        let mut engine = MatchingEngine::new();
+        engine
+            .submit_order(limit(OrderSide::Sell, 100.0, 1.0))
+            .unwrap();
+        engine
+            .submit_order(limit(OrderSide::Sell, 101.0, 1.0))
+            .unwrap();
+
+        let short = order(
+            OrderSide::Buy,
+            OrderType::Limit,
+            Some(101.0),
+            3.0,
+            None,
+            TimeInForce::Fok,
+        );
+        assert!(engine.submit_order(short).unwrap().is_empty());
+        assert!(engine.get_trades().is_empty());
+        let (bids, asks) = engine.get_orderbook();
+        assert!(bids.is_empty());
+        assert_eq!(book_qty(&asks), vec![(100.0, 1.0), (101.0, 1.0)]);
+
+        let full = order(
+            OrderSide::Buy,
+            OrderType::Limit,
+            Some(101.0),
+            2.0,
+            None,
+            TimeInForce::Fok,
+        );
+        assert_eq!(engine.submit_order(full).unwrap().len(), 2);
+        let (_, asks) = engine.get_orderbook();
+        assert!(asks.is_empty());
+    }
+
+    #[test]
+    fn test_fok_rejected_by_stp_restores_book() {
+        for policy in [
+            SelfTradePrevention::CancelNewest,
+            SelfTradePrevention::DecrementBoth,
+        ] {
+            let mut engine = MatchingEngine::new();
+            engine.set_self_trade_prevention(policy);
+            engine
+                .submit_order(owned(OrderSide::Sell, 99.0, 1.0, "b", TimeInForce::Gtc))
+                .unwrap();
+            engine
+                .submit_order(owned(OrderSide::Sell, 100.0, 2.0, "a", TimeInForce::Gtc))
+                .unwrap();
+
+            // 99에서 1 체결 후 자기 주문에 걸리므로 전량 체결이 아님 -> 주문 전체 거절
+            let trades = engine
+                .submit_order(owned(OrderSide::Buy, 100.0, 2.0, "a", TimeInForce::Fok))
+                .unwrap();
+            assert!(trades.is_empty(), "{:?}", policy);
+            assert!(engine.get_trades().is_empty(), "{:?}", policy);
+            let (bids, asks) = engine.get_orderbook();
+            assert!(bids.is_empty(), "{:?}", policy);
+            assert_eq!(
+                book_qty(&asks),
+                vec![(99.0, 1.0), (100.0, 2.0)],
+                "{:?}",
+                policy
+            );
+        }
+    }
 }
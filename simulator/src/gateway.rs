@@ -1,6 +1,5 @@
 use axum::{
     extract::Extension,
-    http::StatusCode,
     response::Json,
 };
 use std::sync::{Arc, RwLock};
@@ -8,8 +7,10 @@ use uuid::Uuid;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::{Order, OrderSide, OrderType};
+use crate::domain::{Order, OrderSide, OrderType, TimeInForce};
 use crate::engine::MatchingEngine;
+use crate::recording::SharedRecorder;
+use crate::validation::{reject, ApiError, OrderRules, Rejection};
 use crate::websocket::{BroadcastTx, WebSocketMessage};
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +19,21 @@ pub struct OrderRequest {
     pub order_type: String,
     pub price: Option<f64>,
     pub quantity: f64,
+    /// StopMarket / StopLimit 발동 가격
+    #[serde(default)]
+    pub stop_price: Option<f64>,
+    /// Iceberg 노출 수량
+    #[serde(default)]
+    pub display_qty: Option<f64>,
+    /// 주문 소유 계정 (같은 계정 주문끼리는 self-trade prevention 정책 적용)
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// "GTC" | "IOC" | "FOK" (생략하면 GTC)
+    #[serde(default)]
+    pub time_in_force: Option<String>,
+    /// 주문 필터를 적용할 심볼 (생략하면 SIM_SYMBOL)
+    #[serde(default)]
+    pub symbol: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -27,13 +43,13 @@ pub struct OrderResponse {
     pub trades: Vec<crate::domain::Trade>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderBookResponse {
     pub bids: Vec<OrderJson>,
     pub asks: Vec<OrderJson>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderJson {
     pub id: Uuid,
     pub side: String,
@@ -43,6 +59,23 @@ pub struct OrderJson {
     pub timestamp: String,
 }
 
+/// 엔진 오더북을 응답 형식으로 변환 (내부 벡터 순서 그대로)
+pub fn orderbook_response(engine: &MatchingEngine) -> OrderBookResponse {
+    let (bids, asks) = engine.get_orderbook();
+    let to_json = |o: &Order| OrderJson {
+        id: o.id,
+        side: format!("{:?}", o.side),
+        order_type: format!("{:?}", o.order_type),
+        price: o.price,
+        quantity: o.quantity,
+        timestamp: o.timestamp.to_rfc3339(),
+    };
+    OrderBookResponse {
+        bids: bids.into_iter().map(to_json).collect(),
+        asks: asks.into_iter().map(to_json).collect(),
+    }
+}
+
 pub async fn get_orderbook(
     Extension(engine): Extension<Arc<RwLock<MatchingEngine>>>,
 ) -> Json<OrderBookResponse> {
@@ -82,6 +115,26 @@ pub async fn get_orderbook(
     })
 }
 
+/// 발동 대기 중인 스탑 주문 목록 (접수 순)
+pub async fn get_stop_orders(
+    Extension(engine): Extension<Arc<RwLock<MatchingEngine>>>,
+) -> Json<Vec<OrderJson>> {
+    let engine = engine.read().unwrap();
+    let orders: Vec<OrderJson> = engine
+        .get_stop_orders()
+        .iter()
+        .map(|o| OrderJson {
+            id: o.id,
+            side: format!("{:?}", o.side),
+            order_type: format!("{:?}", o.order_type),
+            price: o.price,
+            quantity: o.quantity,
+            timestamp: o.timestamp.to_rfc3339(),
+        })
+        .collect();
+    Json(orders)
+}
+
 pub async fn get_trades(
     Extension(engine): Extension<Arc<RwLock<MatchingEngine>>>,
 ) -> Json<Vec<crate::domain::Trade>> {
@@ -93,30 +146,58 @@ pub async fn get_trades(
 pub async fn post_order(
     Extension(engine): Extension<Arc<RwLock<MatchingEngine>>>,
     Extension(broadcast_tx): Extension<BroadcastTx>,
+    Extension(rules): Extension<Arc<OrderRules>>,
+    Extension(recorder): Extension<SharedRecorder>,
     Json(req): Json<OrderRequest>,
-) -> Result<Json<OrderResponse>, StatusCode> {
+) -> Result<Json<OrderResponse>, Rejection> {
+    let symbol_rules = rules.get(req.symbol.as_deref()).map_err(reject)?;
+
     // Parse side
     let side = match req.side.as_str() {
         "Buy" => OrderSide::Buy,
         "Sell" => OrderSide::Sell,
-        _ => return Err(StatusCode::BAD_REQUEST),
+        _ => return Err(reject(ApiError::new(-1117, "Invalid side."))),
     };
 
     // Parse order type
+    let stop_price = || req.stop_price.ok_or_else(|| reject(ApiError::missing_param("stop_price")));
     let order_type = match req.order_type.as_str() {
         "Limit" => OrderType::Limit,
         "Market" => OrderType::Market,
-        _ => return Err(StatusCode::BAD_REQUEST),
+        "StopMarket" => OrderType::StopMarket {
+            stop_price: stop_price()?,
+        },
+        "StopLimit" => OrderType::StopLimit {
+            stop_price: stop_price()?,
+        },
+        "Iceberg" => OrderType::Iceberg {
+            display_qty: req
+                .display_qty
+                .ok_or_else(|| reject(ApiError::missing_param("display_qty")))?,
+        },
+        _ => return Err(reject(ApiError::new(-1116, "Invalid orderType."))),
+    };
+
+    let time_in_force = match req.time_in_force.as_deref() {
+        Some(tif) => tif
+            .parse::<TimeInForce>()
+            .map_err(|_| reject(ApiError::new(-1115, "Invalid timeInForce.")))?,
+        None => TimeInForce::Gtc,
     };
 
     // Validate price for limit orders
-    let price = match order_type {
-        OrderType::Limit => {
-            Some(req.price.ok_or(StatusCode::BAD_REQUEST)?)
-        }
-        OrderType::Market => None,
+    let price = if order_type.requires_price() {
+        Some(req.price.ok_or_else(|| reject(ApiError::missing_param("price")))?)
+    } else {
+        None
     };
 
+    // 틱/수량 단위, 최소 주문 금액, 가격 밴드 검증
+    let snapshot = engine.read().unwrap().get_snapshot();
+    symbol_rules
+        .validate(&order_type, price, req.quantity, &snapshot)
+        .map_err(reject)?;
+
     // Create order
     let new_order = Order {
         id: Uuid::new_v4(),
@@ -125,26 +206,33 @@ pub async fn post_order(
         price,
         quantity: req.quantity,
         timestamp: Utc::now(),
+        owner: req.owner.clone(),
+        time_in_force,
     };
 
     // Submit to engine
     let mut engine = engine.write().unwrap();
     match engine.submit_order(new_order.clone()) {
         Ok(trades) => {
-            let status = if trades.is_empty() {
+            // 이 주문 자체의 체결 (발동된 다른 스탑 주문의 체결은 제외)
+            let own_trades: Vec<&crate::domain::Trade> =
+                trades.iter().filter(|t| t.trigger.is_none()).collect();
+            let total_filled: f64 = own_trades.iter().map(|t| t.quantity).sum();
+            let status = if order_type.stop_price().is_some() {
+                "Untriggered"
+            } else if total_filled >= new_order.quantity {
+                "Filled"
+            } else if time_in_force != TimeInForce::Gtc {
+                // IOC 잔량 취소 / FOK 전량 취소
+                "Expired"
+            } else if own_trades.is_empty() {
                 if matches!(order_type, OrderType::Market) {
                     "NotFilled"
                 } else {
                     "Open"
                 }
             } else {
-                // Check if order is fully filled
-                let total_filled: f64 = trades.iter().map(|t| t.quantity).sum();
-                if total_filled >= new_order.quantity {
-                    "Filled"
-                } else {
-                    "PartiallyFilled"
-                }
+                "PartiallyFilled"
             };
 
             // Get order ID from book if still open, otherwise use new order ID
@@ -188,13 +276,17 @@ pub async fn post_order(
                 bids: bids_json,
                 asks: asks_json,
             };
+
+            if let Some(recorder) = &recorder {
+                recorder.record_batch(std::slice::from_ref(&new_order), &trades, &orderbook);
+            }
             
-            let _ = broadcast_tx.send(WebSocketMessage::OrderBook(orderbook));
+            broadcast_tx.send(WebSocketMessage::OrderBook(orderbook));
             
             // 새로운 trades만 브로드캐스트 (있는 경우에만)
             if !trades.is_empty() {
                 let new_trades: Vec<crate::domain::Trade> = trades.iter().cloned().collect();
-                let _ = broadcast_tx.send(WebSocketMessage::Trades(new_trades));
+                broadcast_tx.send(WebSocketMessage::Trades(new_trades));
             }
 
             Ok(Json(OrderResponse {
@@ -203,7 +295,13 @@ pub async fn post_order(
                 trades,
             }))
         }
-        Err(_) => Err(StatusCode::BAD_REQUEST),
+        Err(e) => {
+            // 엔진이 거부한 주문도 재생 시 같은 거부가 재현되도록 기록
+            if let Some(recorder) = &recorder {
+                recorder.record_batch(&[new_order], &[], &orderbook_response(&engine));
+            }
+            Err(reject(ApiError::from_engine(e)))
+        }
     }
 }
 
@@ -1,6 +1,6 @@
 use std::net::SocketAddr;
 use std::sync::{Arc, RwLock};
-use tokio::time::{interval, Duration};
+use tokio::time::{interval, Duration, Instant};
 use axum::{Router, routing::get, routing::post, Extension};
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
@@ -9,32 +9,62 @@ mod domain;
 mod engine;
 mod market;
 mod gateway;
+mod metrics;
+mod recording;
+mod validation;
 mod websocket;
 
+use crate::domain::SelfTradePrevention;
 use crate::engine::MatchingEngine;
-use crate::market::{CompositeFlow, NoiseTrader, PassiveMM, SpikeGenerator, WhaleAgent, OrderFlowSource, RegimeState, Regime};
-use crate::gateway::{get_orderbook, get_trades, post_order, OrderBookResponse, OrderJson};
+use crate::market::{FlowConfig, MomentumTrader, SourceRegistry, WhaleAgent, OrderFlowSource, RegimeState, Regime};
+use crate::gateway::{get_orderbook, get_stop_orders, get_trades, post_order, OrderBookResponse, OrderJson};
+use crate::recording::{replay, ReplayConfig, SessionRecorder};
+use crate::metrics::{get_metrics, BookDepth, SharedMetrics, TickSample};
+use crate::validation::OrderRules;
 use crate::websocket::{websocket_handler, create_broadcast, WebSocketMessage};
 
 #[tokio::main]
 async fn main() {
     // Initialize shared state
-    let engine = Arc::new(RwLock::new(MatchingEngine::new()));
-
-    // Set up market simulation sources
-    let noise_trader = NoiseTrader;
-    let passive_mm = PassiveMM::new(0.005); // e.g., 0.5% spread offset
-    let spike_gen = SpikeGenerator::new(0.02, 50.0); // 기본 확률 2%, up to 50 quantity
-
-    let sources: Vec<Box<dyn market::OrderFlowSource + Send>> = vec![
-        Box::new(noise_trader),
-        Box::new(passive_mm),
-        Box::new(spike_gen),
-    ];
-    let mut composite_flow = CompositeFlow::new(sources);
+    let mut matching_engine = MatchingEngine::new();
+    // 자기체결 방지 정책 (none / cancel-newest / cancel-oldest / decrement-both)
+    if let Ok(policy) = std::env::var("SIM_STP_POLICY") {
+        match policy.parse::<SelfTradePrevention>() {
+            Ok(stp) => {
+                println!("Self-trade prevention: {:?}", stp);
+                matching_engine.set_self_trade_prevention(stp);
+            }
+            Err(e) => eprintln!("SIM_STP_POLICY 무시: {}", e),
+        }
+    }
+    let engine = Arc::new(RwLock::new(matching_engine));
+
+    // REST 주문 필터 (SIM_SYMBOL, SIM_SYMBOL_RULES)
+    let order_rules = Arc::new(OrderRules::from_env());
+    println!("Order filters for default symbol {}", order_rules.default_symbol());
+
+    // 세션 기록 (SIM_RECORD) / 재생 (SIM_REPLAY, SIM_REPLAY_SPEED)
+    let recorder = SessionRecorder::from_env();
+    let recorder_clone = recorder.clone();
+    let replay_config = ReplayConfig::from_env();
+    let replaying = replay_config.is_some();
+
+    // Set up market simulation sources (SIM_FLOW_CONFIG, 없으면 noise / passive_mm / spike)
+    // 구성이 잘못되면(알 수 없는 소스, 잘못된 파라미터) 경고하고 기본 구성으로 실행
+    let registry = SourceRegistry::with_builtin();
+    let flow_config = FlowConfig::from_env();
+    let mut composite_flow = registry.build(&flow_config).unwrap_or_else(|e| {
+        eprintln!("SIM_FLOW_CONFIG 무시, 기본 구성 사용: {}", e);
+        registry
+            .build(&FlowConfig::default())
+            .expect("default flow config must build")
+    });
     
     // WhaleAgent는 별도로 관리 (레짐 변경 시 리셋하기 위해)
     let mut whale_agent = WhaleAgent::new(crate::domain::OrderSide::Buy, 0.0); // 초기값, 나중에 리셋됨
+
+    // MomentumTrader도 별도로 관리 (체결 결과를 관찰해야 하므로)
+    let mut momentum_trader = MomentumTrader::new(40, 0.002, 2.0, 30.0); // 최근 40체결, 0.2% 이상 drift에 반응
     
     // RegimeState 초기화
     let mut regime = RegimeState::new();
@@ -44,13 +74,32 @@ async fn main() {
     let broadcast_tx = create_broadcast();
     let broadcast_tx_clone = broadcast_tx.clone();
 
+    // 시뮬레이션 루프 지표 (/metrics)
+    let metrics: SharedMetrics = Default::default();
+    let metrics_clone = metrics.clone();
+
+    if let Some(config) = replay_config {
+        let engine = engine.clone();
+        let tx = broadcast_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = replay(config, engine, tx).await {
+                eprintln!("[REPLAY] 재생 실패: {}", e);
+            }
+        });
+    }
+
     // Spawn the simulation loop in a background task
     let engine_clone = engine.clone();
     tokio::spawn(async move {
+        // 재생 중에는 기록된 주문만 엔진에 들어가도록 시뮬레이션 플로우를 돌리지 않음
+        if replaying {
+            return;
+        }
         let mut ticker = interval(Duration::from_millis(50)); // 500ms로 변경 (요구사항에 따라)
         let mut rng = StdRng::from_entropy();
         loop {
             ticker.tick().await;
+            let tick_started = Instant::now();
             
             // 1) 레짐 업데이트
             regime.step(&mut rng);
@@ -84,26 +133,46 @@ async fn main() {
             };
             
             // 3) 모든 플로우에서 주문 생성 (레짐 전달)
-            let mut orders: Vec<domain::Order> = composite_flow.generate(&snapshot, regime.current);
+            let mut orders: Vec<domain::Order> = Vec::new();
+            let mut order_counts: Vec<(&'static str, usize)> = Vec::new();
+            for (source, source_orders) in composite_flow.generate_by_source(&snapshot, regime.current) {
+                order_counts.push((source, source_orders.len()));
+                orders.extend(source_orders);
+            }
             
             // WhaleAgent 주문도 추가
             let whale_orders = whale_agent.generate(&snapshot, regime.current);
+            order_counts.push((whale_agent.name(), whale_orders.len()));
             orders.extend(whale_orders);
+
+            // MomentumTrader 주문도 추가
+            let momentum_orders = momentum_trader.generate(&snapshot, regime.current);
+            order_counts.push((momentum_trader.name(), momentum_orders.len()));
+            orders.extend(momentum_orders);
             
             if orders.is_empty() {
+                metrics_clone.write().unwrap().record_tick(TickSample {
+                    duration: tick_started.elapsed(),
+                    orders: &order_counts,
+                    trades: 0,
+                    book: None,
+                });
                 continue; // skip if no orders generated this tick
             }
             
             // 4) 매칭 엔진에 주문 제출
             let mut eng = engine_clone.write().unwrap();
             let mut new_trades = Vec::new();
-            for order in orders {
+            for order in orders.iter().cloned() {
                 // We ignore errors from engine here because our generators produce valid orders.
                 // In a real scenario, we might log or handle EngineError.
                 if let Ok(trades) = eng.submit_order(order) {
                     new_trades.extend(trades);
                 }
             }
+
+            // 이번 tick 체결을 MomentumTrader가 관찰
+            momentum_trader.observe_trades(&new_trades);
             
             // Broadcast updated orderbook via WebSocket
             // get_orderbook()은 내부 벡터 순서를 그대로 반환 (추가 정렬 없음)
@@ -136,12 +205,26 @@ async fn main() {
                 asks: asks_json,
             };
             
-            let _ = broadcast_tx_clone.send(WebSocketMessage::OrderBook(orderbook));
+            if let Some(recorder) = &recorder_clone {
+                recorder.record_batch(&orders, &new_trades, &orderbook);
+            }
+            
+            broadcast_tx_clone.send(WebSocketMessage::OrderBook(orderbook));
             
             // 새로운 trades만 브로드캐스트 (있는 경우에만)
+            let trade_count = new_trades.len();
             if !new_trades.is_empty() {
-                let _ = broadcast_tx_clone.send(WebSocketMessage::Trades(new_trades));
+                broadcast_tx_clone.send(WebSocketMessage::Trades(new_trades));
             }
+
+            let book = BookDepth::from_engine(&eng);
+            drop(eng);
+            metrics_clone.write().unwrap().record_tick(TickSample {
+                duration: tick_started.elapsed(),
+                orders: &order_counts,
+                trades: trade_count,
+                book: Some(book),
+            });
         }
     });
 
@@ -149,10 +232,15 @@ async fn main() {
     let app = Router::new()
         .route("/orderbook", get(get_orderbook))
         .route("/trades", get(get_trades))
+        .route("/stop-orders", get(get_stop_orders))
         .route("/order", post(post_order))
         .route("/ws", get(websocket_handler))
+        .route("/metrics", get(get_metrics))
         .layer(Extension(engine.clone())) // provide engine state to handlers
-        .layer(Extension(broadcast_tx.clone())); // provide broadcast channel to handlers
+        .layer(Extension(broadcast_tx.clone())) // provide broadcast channel to handlers
+        .layer(Extension(order_rules))
+        .layer(Extension(recorder))
+        .layer(Extension(metrics));
 
     // Start HTTP server
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
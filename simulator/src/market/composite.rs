@@ -9,13 +9,28 @@ impl CompositeFlow {
     pub fn new(sources: Vec<Box<dyn OrderFlowSource + Send>>) -> Self {
         Self { sources }
     }
+
+    /// 소스별로 주문 생성 (소스 이름, 생성된 주문)
+    pub fn generate_by_source(
+        &mut self,
+        snapshot: &MarketSnapshot,
+        regime: Regime,
+    ) -> Vec<(&'static str, Vec<Order>)> {
+        self.sources
+            .iter_mut()
+            .map(|source| (source.name(), source.generate(snapshot, regime)))
+            .collect()
+    }
 }
 
 impl OrderFlowSource for CompositeFlow {
+    fn name(&self) -> &'static str {
+        "composite"
+    }
+
     fn generate(&mut self, snapshot: &MarketSnapshot, regime: Regime) -> Vec<Order> {
         let mut all_orders = Vec::new();
-        for source in &mut self.sources {
-            let mut orders = source.generate(snapshot, regime);
+        for (_, mut orders) in self.generate_by_source(snapshot, regime) {
             all_orders.append(&mut orders);
         }
         all_orders
@@ -1,19 +1,25 @@
 pub mod composite;
+pub mod momentum_trader;
 pub mod noise_trader;
 pub mod passive_mm;
 pub mod regime;
+pub mod registry;
 pub mod spike_generator;
 pub mod whale_agent;
 
 pub use composite::CompositeFlow;
+pub use momentum_trader::MomentumTrader;
 pub use noise_trader::NoiseTrader;
 pub use passive_mm::PassiveMM;
 pub use regime::{Regime, RegimeState};
+pub use registry::{FlowConfig, SourceRegistry};
 pub use spike_generator::SpikeGenerator;
 pub use whale_agent::WhaleAgent;
 
 use crate::domain::{MarketSnapshot, Order};
 
 pub trait OrderFlowSource {
+    /// 지표(/metrics)에 표시되는 소스 이름
+    fn name(&self) -> &'static str;
     fn generate(&mut self, snapshot: &MarketSnapshot, regime: Regime) -> Vec<Order>;
 }
@@ -0,0 +1,106 @@
+use crate::domain::{MarketSnapshot, Order, OrderSide, OrderType, TimeInForce, Trade};
+use crate::market::{OrderFlowSource, Regime};
+use chrono::Utc;
+use rand::Rng;
+use std::collections::VecDeque;
+use uuid::Uuid;
+
+/// 최근 체결가의 단기 추세(drift)를 따라가는 추세추종 트레이더
+///
+/// 최근 체결 window를 앞/뒤 절반으로 나눠 평균가 변화율을 drift로 보고,
+/// drift가 threshold를 넘으면 그 방향으로 drift 크기에 비례하는 Market 주문을 낸다.
+/// 추세가 추세를 부르도록 만들어 레짐 전환 시 자기상관이 있는 가격 흐름을 만든다.
+pub struct MomentumTrader {
+    window: usize,     // drift 추정에 사용하는 최근 체결 개수
+    threshold: f64,    // 반응하는 최소 drift (예: 0.002 = 0.2%)
+    qty_per_unit: f64, // drift가 threshold 1배일 때 주문 수량
+    max_qty: f64,      // 한 번에 내는 최대 수량
+    prices: VecDeque<f64>,
+}
+
+impl MomentumTrader {
+    pub fn new(window: usize, threshold: f64, qty_per_unit: f64, max_qty: f64) -> Self {
+        Self {
+            window: window.max(4),
+            threshold,
+            qty_per_unit,
+            max_qty,
+            prices: VecDeque::new(),
+        }
+    }
+
+    /// 새로 발생한 체결을 관찰 (매 tick 체결 후 호출)
+    pub fn observe_trades(&mut self, trades: &[Trade]) {
+        for trade in trades {
+            self.prices.push_back(trade.price);
+            if self.prices.len() > self.window {
+                self.prices.pop_front();
+            }
+        }
+    }
+
+    /// 단기 drift = (최근 절반 평균가 - 이전 절반 평균가) / 이전 절반 평균가
+    /// window가 절반 이상 차지 않았으면 None
+    pub fn drift(&self) -> Option<f64> {
+        if self.prices.len() < self.window / 2 {
+            return None;
+        }
+        let half = self.prices.len() / 2;
+        let older: f64 = self.prices.iter().take(half).sum::<f64>() / half as f64;
+        let newer: f64 =
+            self.prices.iter().skip(half).sum::<f64>() / (self.prices.len() - half) as f64;
+        if older <= 0.0 {
+            return None;
+        }
+        Some((newer - older) / older)
+    }
+}
+
+impl OrderFlowSource for MomentumTrader {
+    fn name(&self) -> &'static str {
+        "momentum"
+    }
+
+    fn generate(&mut self, _snapshot: &MarketSnapshot, regime: Regime) -> Vec<Order> {
+        let mut rng = rand::thread_rng();
+        let mut orders = Vec::new();
+
+        let drift = match self.drift() {
+            Some(d) if d.abs() >= self.threshold => d,
+            _ => return orders,
+        };
+
+        // 레짐에 따라 추세에 올라타는 빈도 조정 (변동성이 클수록 적극적)
+        let participation = match regime {
+            Regime::Calm => 0.1,
+            Regime::Normal => 0.2,
+            Regime::HighVol | Regime::WhaleAccum | Regime::WhaleDump => 0.4,
+            Regime::FlashCrash | Regime::FlashPump => 0.6,
+        };
+        if !rng.gen_bool(participation) {
+            return orders;
+        }
+
+        let side = if drift > 0.0 {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        // drift 크기에 비례한 수량 (약간의 랜덤성 추가)
+        let strength = drift.abs() / self.threshold;
+        let quantity = (strength * self.qty_per_unit * rng.gen_range(0.5..=1.5)).min(self.max_qty);
+
+        orders.push(Order {
+            id: Uuid::new_v4(),
+            side,
+            order_type: OrderType::Market,
+            price: None,
+            quantity,
+            timestamp: Utc::now(),
+            owner: None,
+            time_in_force: TimeInForce::Gtc,
+        });
+
+        orders
+    }
+}
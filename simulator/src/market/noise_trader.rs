@@ -1,4 +1,4 @@
-use crate::domain::{MarketSnapshot, Order, OrderSide, OrderType};
+use crate::domain::{MarketSnapshot, Order, OrderSide, OrderType, TimeInForce};
 use crate::market::{OrderFlowSource, Regime};
 use chrono::Utc;
 use rand::Rng;
@@ -7,6 +7,10 @@ use uuid::Uuid;
 pub struct NoiseTrader;
 
 impl OrderFlowSource for NoiseTrader {
+    fn name(&self) -> &'static str {
+        "noise"
+    }
+
     fn generate(&mut self, snapshot: &MarketSnapshot, regime: Regime) -> Vec<Order> {
         let mut rng = rand::thread_rng();
         let mut orders = Vec::new();
@@ -67,7 +71,7 @@ impl OrderFlowSource for NoiseTrader {
                     let offset = rng.gen_range(offset_range);
                     Some(base_price * (1.0 + offset))
                 }
-                OrderType::Market => None,
+                _ => None,
             };
 
             let quantity = rng.gen_range(qty_range.clone());
@@ -79,6 +83,8 @@ impl OrderFlowSource for NoiseTrader {
                 price,
                 quantity,
                 timestamp: Utc::now(),
+                owner: None,
+                time_in_force: TimeInForce::Gtc,
             });
         }
 
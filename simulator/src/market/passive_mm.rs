@@ -1,7 +1,7 @@
 use chrono::Utc;
 use uuid::Uuid;
 use rand::Rng;
-use crate::domain::{Order, OrderSide, OrderType, MarketSnapshot};
+use crate::domain::{Order, OrderSide, OrderType, TimeInForce, MarketSnapshot};
 use crate::market::{OrderFlowSource, Regime};
 
 pub struct PassiveMM {
@@ -15,6 +15,10 @@ impl PassiveMM {
 }
 
 impl OrderFlowSource for PassiveMM {
+    fn name(&self) -> &'static str {
+        "passive_mm"
+    }
+
     fn generate(&mut self, snapshot: &MarketSnapshot, regime: Regime) -> Vec<Order> {
         let mut orders = Vec::new();
         let mut rng = rand::thread_rng();
@@ -42,6 +46,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(bid_price),
                         quantity: rng.gen_range(5.0..=15.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
                 if rng.gen_bool(0.6) {
@@ -53,6 +59,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(ask_price),
                         quantity: rng.gen_range(5.0..=15.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
             }
@@ -87,6 +95,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(bid_price),
                         quantity: qty,
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
                 if rng.gen_bool(0.5) {
@@ -103,6 +113,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(ask_price),
                         quantity: qty,
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
             }
@@ -117,6 +129,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(bid_price),
                         quantity: rng.gen_range(3.0..=10.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
                 if rng.gen_bool(0.1) {
@@ -129,6 +143,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(ask_price),
                         quantity: rng.gen_range(1.0..=3.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
             }
@@ -144,6 +160,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(bid_price),
                         quantity: rng.gen_range(1.0..=3.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
                 if rng.gen_bool(0.8) {
@@ -155,6 +173,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(ask_price),
                         quantity: rng.gen_range(3.0..=10.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
             }
@@ -169,6 +189,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(bid_price),
                         quantity: rng.gen_range(5.0..=15.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
                 if rng.gen_bool(0.5) {
@@ -180,6 +202,8 @@ impl OrderFlowSource for PassiveMM {
                         price: Some(ask_price),
                         quantity: rng.gen_range(5.0..=15.0),
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
             }
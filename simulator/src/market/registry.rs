@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::market::{CompositeFlow, NoiseTrader, OrderFlowSource, PassiveMM, SpikeGenerator};
+
+pub type BoxedSource = Box<dyn OrderFlowSource + Send>;
+
+/// params JSON으로 소스를 만드는 함수
+pub type SourceFactory = fn(&Value) -> Result<BoxedSource, String>;
+
+/// 소스 하나의 설정
+#[derive(Debug, Clone, Deserialize)]
+pub struct SourceConfig {
+    /// 레지스트리에 등록된 소스 종류 (예: "noise", "passive_mm", "spike")
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 소스별 파라미터 (생략한 항목은 기본값)
+    #[serde(default)]
+    pub params: Value,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// CompositeFlow 구성 (SIM_FLOW_CONFIG JSON 파일)
+#[derive(Debug, Clone, Deserialize)]
+pub struct FlowConfig {
+    pub sources: Vec<SourceConfig>,
+}
+
+impl Default for FlowConfig {
+    /// 설정 파일이 없을 때의 기본 구성 (noise, passive_mm, spike)
+    fn default() -> Self {
+        let source = |kind: &str| SourceConfig {
+            kind: kind.to_string(),
+            enabled: true,
+            params: Value::Null,
+        };
+        Self {
+            sources: vec![source("noise"), source("passive_mm"), source("spike")],
+        }
+    }
+}
+
+impl FlowConfig {
+    /// SIM_FLOW_CONFIG 경로가 있으면 읽고, 없거나 읽지 못하면 기본 구성
+    pub fn from_env() -> Self {
+        let Ok(path) = std::env::var("SIM_FLOW_CONFIG") else {
+            return Self::default();
+        };
+        let loaded = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|raw| serde_json::from_str::<Self>(&raw).map_err(|e| e.to_string()));
+        match loaded {
+            Ok(config) => {
+                println!("Order flow sources from {}", path);
+                config
+            }
+            Err(e) => {
+                eprintln!("SIM_FLOW_CONFIG 무시 ({}): {}", path, e);
+                Self::default()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct PassiveMMParams {
+    spread_offset: f64,
+}
+
+impl Default for PassiveMMParams {
+    fn default() -> Self {
+        Self {
+            spread_offset: 0.005,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct SpikeParams {
+    probability: f64,
+    max_quantity: f64,
+}
+
+impl Default for SpikeParams {
+    fn default() -> Self {
+        Self {
+            probability: 0.02,
+            max_quantity: 50.0,
+        }
+    }
+}
+
+/// params가 없으면 기본값, 있으면 기본값 위에 덮어씀
+fn parse_params<P: DeserializeOwned + Default>(params: &Value) -> Result<P, String> {
+    if params.is_null() {
+        return Ok(P::default());
+    }
+    serde_json::from_value(params.clone()).map_err(|e| e.to_string())
+}
+
+/// 소스 종류 이름 -> 생성 함수
+/// WhaleAgent, MomentumTrader는 레짐 리셋 / 체결 관찰 때문에 main에서 따로 관리한다
+pub struct SourceRegistry {
+    factories: HashMap<String, SourceFactory>,
+}
+
+impl SourceRegistry {
+    pub fn empty() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// 기본 소스(noise, passive_mm, spike)가 등록된 레지스트리
+    pub fn with_builtin() -> Self {
+        let mut registry = Self::empty();
+        registry.register("noise", |_| Ok(Box::new(NoiseTrader)));
+        registry.register("passive_mm", |params| {
+            let p: PassiveMMParams = parse_params(params)?;
+            Ok(Box::new(PassiveMM::new(p.spread_offset)))
+        });
+        registry.register("spike", |params| {
+            let p: SpikeParams = parse_params(params)?;
+            Ok(Box::new(SpikeGenerator::new(p.probability, p.max_quantity)))
+        });
+        registry
+    }
+
+    /// 새 소스 종류 등록 (같은 이름이면 교체)
+    pub fn register(&mut self, kind: &str, factory: SourceFactory) {
+        self.factories.insert(kind.to_string(), factory);
+    }
+
+    /// 등록된 소스 종류 (정렬)
+    pub fn kinds(&self) -> Vec<&str> {
+        let mut kinds: Vec<&str> = self.factories.keys().map(String::as_str).collect();
+        kinds.sort_unstable();
+        kinds
+    }
+
+    /// 설정대로 소스를 만들어 CompositeFlow 구성 (꺼진 소스는 건너뜀)
+    pub fn build(&self, config: &FlowConfig) -> Result<CompositeFlow, String> {
+        let mut sources = Vec::new();
+        for source in config.sources.iter().filter(|s| s.enabled) {
+            let factory = self.factories.get(&source.kind).ok_or_else(|| {
+                format!(
+                    "unknown source type: {} (available: {})",
+                    source.kind,
+                    self.kinds().join(", ")
+                )
+            })?;
+            let built = factory(&source.params).map_err(|e| format!("{}: {}", source.kind, e))?;
+            sources.push(built);
+        }
+        Ok(CompositeFlow::new(sources))
+    }
+}
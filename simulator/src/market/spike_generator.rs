@@ -1,7 +1,7 @@
 use chrono::Utc;
 use uuid::Uuid;
 use rand::Rng;
-use crate::domain::{Order, OrderSide, OrderType, MarketSnapshot};
+use crate::domain::{Order, OrderSide, OrderType, TimeInForce, MarketSnapshot};
 use crate::market::{OrderFlowSource, Regime};
 
 pub struct SpikeGenerator {
@@ -19,6 +19,10 @@ impl SpikeGenerator {
 }
 
 impl OrderFlowSource for SpikeGenerator {
+    fn name(&self) -> &'static str {
+        "spike"
+    }
+
     fn generate(&mut self, _snapshot: &MarketSnapshot, regime: Regime) -> Vec<Order> {
         let mut rng = rand::thread_rng();
         let mut orders = Vec::new();
@@ -74,6 +78,8 @@ impl OrderFlowSource for SpikeGenerator {
                 price: None,
                 quantity: rng.gen_range(self.max_quantity * 0.5 * qty_multiplier..=self.max_quantity * qty_multiplier),
                 timestamp: Utc::now(),
+                owner: None,
+                time_in_force: TimeInForce::Gtc,
             });
         }
 
@@ -1,7 +1,7 @@
 use chrono::Utc;
 use uuid::Uuid;
 use rand::Rng;
-use crate::domain::{Order, OrderSide, OrderType, MarketSnapshot};
+use crate::domain::{Order, OrderSide, OrderType, TimeInForce, MarketSnapshot};
 use crate::market::{OrderFlowSource, Regime};
 
 pub struct WhaleAgent {
@@ -35,6 +35,10 @@ impl WhaleAgent {
 }
 
 impl OrderFlowSource for WhaleAgent {
+    fn name(&self) -> &'static str {
+        "whale"
+    }
+
     fn generate(&mut self, snapshot: &MarketSnapshot, regime: Regime) -> Vec<Order> {
         let mut orders = Vec::new();
         let mut rng = rand::thread_rng();
@@ -91,7 +95,7 @@ impl OrderFlowSource for WhaleAgent {
                         };
                         Some(base_price * (1.0 + offset))
                     }
-                    OrderType::Market => None,
+                    _ => None,
                 };
 
                 orders.push(Order {
@@ -101,6 +105,8 @@ impl OrderFlowSource for WhaleAgent {
                     price,
                     quantity: order_qty,
                     timestamp: Utc::now(),
+                    owner: None,
+                    time_in_force: TimeInForce::Gtc,
                 });
 
                 // filled 업데이트는 실제로 체결된 후에 해야 하지만,
@@ -121,6 +127,8 @@ impl OrderFlowSource for WhaleAgent {
                         price: None,
                         quantity: order_qty,
                         timestamp: Utc::now(),
+                        owner: None,
+                        time_in_force: TimeInForce::Gtc,
                     });
                 }
             }
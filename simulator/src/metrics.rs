@@ -0,0 +1,159 @@
+use axum::{extract::Extension, response::Json};
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::engine::MatchingEngine;
+
+/// tick 처리 시간 통계에 사용하는 최근 tick 개수 (50ms tick 기준 약 1분)
+const TICK_WINDOW: usize = 1200;
+
+pub type SharedMetrics = Arc<RwLock<SimMetrics>>;
+
+/// 시뮬레이션 루프 성능 지표
+#[derive(Debug, Default)]
+pub struct SimMetrics {
+    ticks: u64,
+    recent_tick_ms: VecDeque<f64>,
+    max_tick_ms: f64,
+    orders_last_tick: BTreeMap<&'static str, usize>,
+    orders_total: BTreeMap<&'static str, u64>,
+    trades_last_tick: usize,
+    trades_total: u64,
+    book: BookDepth,
+}
+
+/// 오더북 깊이 (잔량이 남아 있는 주문 기준)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BookDepth {
+    pub bid_orders: usize,
+    pub ask_orders: usize,
+    pub bid_qty: f64,
+    pub ask_qty: f64,
+}
+
+impl BookDepth {
+    pub fn from_engine(engine: &MatchingEngine) -> Self {
+        let (bids, asks) = engine.get_orderbook();
+        Self {
+            bid_orders: bids.len(),
+            ask_orders: asks.len(),
+            bid_qty: bids.iter().map(|o| o.quantity).sum(),
+            ask_qty: asks.iter().map(|o| o.quantity).sum(),
+        }
+    }
+}
+
+/// 한 tick의 측정값
+pub struct TickSample<'a> {
+    pub duration: Duration,
+    /// 소스 이름별 생성 주문 수
+    pub orders: &'a [(&'static str, usize)],
+    pub trades: usize,
+    /// 주문이 없어 매칭을 건너뛴 tick이면 None (직전 값 유지)
+    pub book: Option<BookDepth>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TickStats {
+    pub last_ms: f64,
+    pub avg_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    /// avg/p99 계산에 사용된 최근 tick 수
+    pub window: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SourceOrders {
+    pub last_tick: usize,
+    pub total: u64,
+    pub per_tick: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MetricsResponse {
+    pub ticks: u64,
+    pub tick: TickStats,
+    pub orders: BTreeMap<&'static str, SourceOrders>,
+    pub trades_last_tick: usize,
+    pub trades_total: u64,
+    pub book: BookDepth,
+}
+
+impl SimMetrics {
+    pub fn record_tick(&mut self, sample: TickSample<'_>) {
+        let ms = sample.duration.as_secs_f64() * 1000.0;
+        self.ticks += 1;
+        if self.recent_tick_ms.len() >= TICK_WINDOW {
+            self.recent_tick_ms.pop_front();
+        }
+        self.recent_tick_ms.push_back(ms);
+        self.max_tick_ms = self.max_tick_ms.max(ms);
+
+        self.orders_last_tick.clear();
+        for &(source, count) in sample.orders {
+            *self.orders_last_tick.entry(source).or_default() += count;
+            *self.orders_total.entry(source).or_default() += count as u64;
+        }
+
+        self.trades_last_tick = sample.trades;
+        self.trades_total += sample.trades as u64;
+        if let Some(book) = sample.book {
+            self.book = book;
+        }
+    }
+
+    pub fn report(&self) -> MetricsResponse {
+        let mut sorted: Vec<f64> = self.recent_tick_ms.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let avg_ms = if sorted.is_empty() {
+            0.0
+        } else {
+            sorted.iter().sum::<f64>() / sorted.len() as f64
+        };
+        let p99_ms = sorted
+            .get((sorted.len() * 99 / 100).min(sorted.len().saturating_sub(1)))
+            .copied()
+            .unwrap_or(0.0);
+
+        let ticks = self.ticks.max(1) as f64;
+        let orders = self
+            .orders_total
+            .iter()
+            .map(|(&source, &total)| {
+                (
+                    source,
+                    SourceOrders {
+                        last_tick: self.orders_last_tick.get(source).copied().unwrap_or(0),
+                        total,
+                        per_tick: total as f64 / ticks,
+                    },
+                )
+            })
+            .collect();
+
+        MetricsResponse {
+            ticks: self.ticks,
+            tick: TickStats {
+                last_ms: self.recent_tick_ms.back().copied().unwrap_or(0.0),
+                avg_ms,
+                p99_ms,
+                max_ms: self.max_tick_ms,
+                window: sorted.len(),
+            },
+            orders,
+            trades_last_tick: self.trades_last_tick,
+            trades_total: self.trades_total,
+            book: self.book.clone(),
+        }
+    }
+}
+
+/// 시뮬레이션 루프 지표 (tick 처리 시간, 소스별 주문 수, 체결 수, 오더북 깊이)
+pub async fn get_metrics(
+    Extension(metrics): Extension<SharedMetrics>,
+) -> Json<MetricsResponse> {
+    Json(metrics.read().unwrap().report())
+}
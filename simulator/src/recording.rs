@@ -0,0 +1,188 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::sync::{Arc, Mutex, RwLock};
+
+use serde::{Deserialize, Serialize};
+use tokio::time::{sleep_until, Duration, Instant};
+
+use crate::domain::{Order, Trade};
+use crate::engine::MatchingEngine;
+use crate::gateway::{orderbook_response, OrderBookResponse};
+use crate::websocket::{BroadcastTx, WebSocketMessage};
+
+/// 세션 기록 한 줄 (JSONL)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// 세션 시작 후 경과 시간 (ms)
+    pub t_ms: u64,
+    pub event: SessionEvent,
+}
+
+/// 기록 대상 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SessionEvent {
+    /// 엔진에 제출된 주문 (시뮬레이션 플로우 + REST)
+    Order(Order),
+    /// 직전 주문들로 생긴 체결
+    Trades(Vec<Trade>),
+    /// 체결 반영 후 오더북 (tick / REST 주문 단위 경계)
+    OrderBook(OrderBookResponse),
+}
+
+/// 세션 기록기: 모든 주문, 체결, 오더북 스냅샷을 파일에 한 줄씩 추가
+pub struct SessionRecorder {
+    started: Instant,
+    writer: Mutex<BufWriter<File>>,
+}
+
+/// 기록이 꺼져 있으면 None
+pub type SharedRecorder = Option<Arc<SessionRecorder>>;
+
+impl SessionRecorder {
+    pub fn create(path: &str) -> std::io::Result<Self> {
+        Ok(Self {
+            started: Instant::now(),
+            writer: Mutex::new(BufWriter::new(File::create(path)?)),
+        })
+    }
+
+    /// SIM_RECORD 경로가 있으면 기록 시작
+    pub fn from_env() -> SharedRecorder {
+        let path = std::env::var("SIM_RECORD").ok()?;
+        match Self::create(&path) {
+            Ok(recorder) => {
+                println!("Recording session to {}", path);
+                Some(Arc::new(recorder))
+            }
+            Err(e) => {
+                eprintln!("SIM_RECORD 무시 ({}): {}", path, e);
+                None
+            }
+        }
+    }
+
+    pub fn record(&self, event: SessionEvent) {
+        let line = RecordedEvent {
+            t_ms: self.started.elapsed().as_millis() as u64,
+            event,
+        };
+        let mut writer = self.writer.lock().unwrap();
+        if let Ok(json) = serde_json::to_string(&line) {
+            let _ = writeln!(writer, "{}", json);
+        }
+    }
+
+    /// tick / REST 주문 하나를 기록: 주문들, 체결(있으면), 결과 오더북 순서
+    /// 경계마다 flush 하므로 프로세스가 죽어도 마지막 경계까지는 남는다
+    pub fn record_batch(&self, orders: &[Order], trades: &[Trade], book: &OrderBookResponse) {
+        for order in orders {
+            self.record(SessionEvent::Order(order.clone()));
+        }
+        if !trades.is_empty() {
+            self.record(SessionEvent::Trades(trades.to_vec()));
+        }
+        self.record(SessionEvent::OrderBook(book.clone()));
+        let _ = self.writer.lock().unwrap().flush();
+    }
+}
+
+/// 재생 설정 (SIM_REPLAY=파일, SIM_REPLAY_SPEED=배속, 0 이하면 대기 없이 최대 속도)
+pub struct ReplayConfig {
+    pub path: String,
+    pub speed: f64,
+}
+
+impl ReplayConfig {
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var("SIM_REPLAY").ok()?;
+        let speed = std::env::var("SIM_REPLAY_SPEED")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(1.0);
+        Some(Self { path, speed })
+    }
+}
+
+/// 기록된 주문을 원래 간격(배속 적용)대로 새 엔진에 다시 제출하고, 결과를 WebSocket으로 브로드캐스트
+///
+/// 오더북 경계마다 재생 결과의 체결 수와 최우선 호가를 기록과 비교해 어긋나면 로그를 남긴다
+/// (STP 정책 등 엔진 설정은 기록 당시와 같아야 함)
+pub async fn replay(
+    config: ReplayConfig,
+    engine: Arc<RwLock<MatchingEngine>>,
+    tx: BroadcastTx,
+) -> std::io::Result<()> {
+    let reader = BufReader::new(File::open(&config.path)?);
+    println!("Replaying {} at {}x", config.path, config.speed);
+
+    let started = Instant::now();
+    let mut pending_trades: Vec<Trade> = Vec::new();
+    let mut recorded_trades = 0usize;
+    let mut boundaries = 0usize;
+    let mut divergences = 0usize;
+
+    for (line_no, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let recorded: RecordedEvent = match serde_json::from_str(&line) {
+            Ok(recorded) => recorded,
+            Err(e) => {
+                eprintln!("[REPLAY] {}번째 줄 무시: {}", line_no + 1, e);
+                continue;
+            }
+        };
+
+        if config.speed > 0.0 {
+            let due = started + Duration::from_secs_f64(recorded.t_ms as f64 / 1000.0 / config.speed);
+            sleep_until(due).await;
+        }
+
+        match recorded.event {
+            SessionEvent::Order(order) => {
+                let mut eng = engine.write().unwrap();
+                if let Ok(trades) = eng.submit_order(order) {
+                    pending_trades.extend(trades);
+                }
+            }
+            SessionEvent::Trades(trades) => recorded_trades += trades.len(),
+            SessionEvent::OrderBook(recorded_book) => {
+                boundaries += 1;
+                let book = orderbook_response(&engine.read().unwrap());
+                if pending_trades.len() != recorded_trades
+                    || top_of_book(&book) != top_of_book(&recorded_book)
+                {
+                    divergences += 1;
+                    eprintln!(
+                        "[REPLAY] t={}ms 기록과 다름: 체결 {} (기록 {}), 최우선 호가 {:?} (기록 {:?})",
+                        recorded.t_ms,
+                        pending_trades.len(),
+                        recorded_trades,
+                        top_of_book(&book),
+                        top_of_book(&recorded_book)
+                    );
+                }
+                tx.send(WebSocketMessage::OrderBook(book));
+                if !pending_trades.is_empty() {
+                    tx.send(WebSocketMessage::Trades(std::mem::take(&mut pending_trades)));
+                }
+                recorded_trades = 0;
+            }
+        }
+    }
+
+    println!(
+        "Replay finished: {} boundaries, {} divergences",
+        boundaries, divergences
+    );
+    Ok(())
+}
+
+/// (최우선 매수가, 최우선 매도가)
+fn top_of_book(book: &OrderBookResponse) -> (Option<f64>, Option<f64>) {
+    (
+        book.bids.first().and_then(|o| o.price),
+        book.asks.first().and_then(|o| o.price),
+    )
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use axum::{http::StatusCode, response::Json};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{MarketSnapshot, OrderType};
+use crate::engine::matching_engine::EngineError;
+
+/// 심볼별 주문 필터 (Binance exchangeInfo filters와 같은 의미)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SymbolRules {
+    /// PRICE_FILTER tickSize: 가격은 이 값의 배수
+    pub tick_size: f64,
+    /// LOT_SIZE stepSize: 수량은 이 값의 배수
+    pub step_size: f64,
+    /// LOT_SIZE minQty
+    pub min_qty: f64,
+    /// LOT_SIZE maxQty
+    pub max_qty: f64,
+    /// NOTIONAL minNotional (가격 * 수량)
+    pub min_notional: f64,
+    /// PERCENT_PRICE multiplierUp: 기준가 * 이 값보다 높은 가격 거부
+    pub multiplier_up: f64,
+    /// PERCENT_PRICE multiplierDown: 기준가 * 이 값보다 낮은 가격 거부
+    pub multiplier_down: f64,
+}
+
+impl Default for SymbolRules {
+    fn default() -> Self {
+        Self {
+            tick_size: 0.01,
+            step_size: 0.0001,
+            min_qty: 0.0001,
+            max_qty: 9000.0,
+            min_notional: 5.0,
+            multiplier_up: 5.0,
+            multiplier_down: 0.2,
+        }
+    }
+}
+
+/// 주문 거부 응답 (Binance 에러 형식: `{"code": -1013, "msg": "..."}`)
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiError {
+    pub code: i32,
+    pub msg: String,
+}
+
+impl ApiError {
+    pub fn new(code: i32, msg: impl Into<String>) -> Self {
+        Self { code, msg: msg.into() }
+    }
+
+    /// -1013 필터 위반
+    pub fn filter_failure(filter: &str) -> Self {
+        Self::new(-1013, format!("Filter failure: {}", filter))
+    }
+
+    /// -1102 필수 파라미터 누락
+    pub fn missing_param(name: &str) -> Self {
+        Self::new(
+            -1102,
+            format!("Mandatory parameter '{}' was not sent, was empty/null, or malformed.", name),
+        )
+    }
+
+    /// 매칭 엔진 거부 (-2010 NEW_ORDER_REJECTED)
+    pub fn from_engine(err: EngineError) -> Self {
+        match err {
+            EngineError::PriceMissing => Self::missing_param("price"),
+            EngineError::InvalidQuantity => Self::new(-1013, "Invalid quantity."),
+            EngineError::InvalidDisplayQuantity => Self::new(-1013, "Invalid icebergQty."),
+            EngineError::WouldTriggerImmediately => {
+                Self::new(-2010, "Stop price would trigger immediately.")
+            }
+        }
+    }
+}
+
+/// REST 핸들러 에러 타입 (HTTP 400 + Binance 형식 본문)
+pub type Rejection = (StatusCode, Json<ApiError>);
+
+/// 400 Bad Request로 거부
+pub fn reject(err: ApiError) -> Rejection {
+    (StatusCode::BAD_REQUEST, Json(err))
+}
+
+/// 시뮬레이션 심볼별 주문 필터 모음
+#[derive(Debug, Clone)]
+pub struct OrderRules {
+    default_symbol: String,
+    symbols: HashMap<String, SymbolRules>,
+}
+
+impl OrderRules {
+    pub fn new(default_symbol: impl Into<String>, rules: SymbolRules) -> Self {
+        let default_symbol = default_symbol.into();
+        let mut symbols = HashMap::new();
+        symbols.insert(default_symbol.clone(), rules);
+        Self { default_symbol, symbols }
+    }
+
+    /// SIM_SYMBOL (기본 BTCUSDT) 과 SIM_SYMBOL_RULES (심볼 → 필터 JSON 파일) 로 구성
+    /// 파일에 기본 심볼이 없으면 기본 필터를 사용
+    pub fn from_env() -> Self {
+        let default_symbol = std::env::var("SIM_SYMBOL").unwrap_or_else(|_| "BTCUSDT".to_string());
+        let mut rules = Self::new(default_symbol, SymbolRules::default());
+        if let Ok(path) = std::env::var("SIM_SYMBOL_RULES") {
+            let loaded = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|raw| {
+                    serde_json::from_str::<HashMap<String, SymbolRules>>(&raw).map_err(|e| e.to_string())
+                });
+            match loaded {
+                Ok(symbols) => rules.symbols.extend(symbols),
+                Err(e) => eprintln!("SIM_SYMBOL_RULES 무시 ({}): {}", path, e),
+            }
+        }
+        rules
+    }
+
+    pub fn default_symbol(&self) -> &str {
+        &self.default_symbol
+    }
+
+    /// 요청 심볼의 필터 (생략하면 기본 심볼, 모르는 심볼은 -1121)
+    /// 오더북은 하나뿐이므로 다른 심볼은 필터 검증 용도로만 쓰인다
+    pub fn get(&self, symbol: Option<&str>) -> Result<&SymbolRules, ApiError> {
+        let symbol = symbol.unwrap_or(&self.default_symbol);
+        self.symbols
+            .get(symbol)
+            .ok_or_else(|| ApiError::new(-1121, "Invalid symbol."))
+    }
+}
+
+impl SymbolRules {
+    /// 엔진 제출 전 필터 검증
+    /// 기준가(최근 체결가, 없으면 호가 중간값)가 없으면 NOTIONAL(시장가)과 PERCENT_PRICE 검사는 건너뜀
+    pub fn validate(
+        &self,
+        order_type: &OrderType,
+        price: Option<f64>,
+        quantity: f64,
+        snapshot: &MarketSnapshot,
+    ) -> Result<(), ApiError> {
+        let reference = reference_price(snapshot);
+        let prices = price.into_iter().chain(order_type.stop_price());
+
+        for p in prices.clone() {
+            if p <= 0.0 || !is_multiple(p, self.tick_size) {
+                return Err(ApiError::filter_failure("PRICE_FILTER"));
+            }
+        }
+
+        if quantity < self.min_qty || quantity > self.max_qty || !is_multiple(quantity, self.step_size) {
+            return Err(ApiError::filter_failure("LOT_SIZE"));
+        }
+        if let OrderType::Iceberg { display_qty } = order_type {
+            if !is_multiple(*display_qty, self.step_size) {
+                return Err(ApiError::filter_failure("LOT_SIZE"));
+            }
+        }
+
+        if let Some(notional_price) = price.or(order_type.stop_price()).or(reference) {
+            if notional_price * quantity < self.min_notional {
+                return Err(ApiError::filter_failure("NOTIONAL"));
+            }
+        }
+
+        if let Some(reference) = reference {
+            let upper = reference * self.multiplier_up;
+            let lower = reference * self.multiplier_down;
+            if prices.into_iter().any(|p| p > upper || p < lower) {
+                return Err(ApiError::filter_failure("PERCENT_PRICE"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 가격 밴드 기준가: 최근 체결가, 없으면 최우선 호가 중간값
+fn reference_price(snapshot: &MarketSnapshot) -> Option<f64> {
+    snapshot.last_trade_price.or(match (snapshot.best_bid, snapshot.best_ask) {
+        (Some(bid), Some(ask)) => Some((bid + ask) / 2.0),
+        _ => None,
+    })
+}
+
+/// 부동소수 오차를 감안한 배수 판정 (step <= 0이면 검사하지 않음)
+fn is_multiple(value: f64, step: f64) -> bool {
+    if step <= 0.0 {
+        return true;
+    }
+    let ratio = value / step;
+    (ratio - ratio.round()).abs() < 1e-6
+}
@@ -7,19 +7,209 @@ use axum::{
 };
 use futures_util::{SinkExt, StreamExt};
 use serde_json;
-use std::sync::{Arc, RwLock};
-use tokio::sync::broadcast;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::sync::{broadcast, Notify};
+use tokio::time::{interval, Duration};
 
 use crate::domain::Trade;
 use crate::engine::MatchingEngine;
 use crate::gateway::{OrderBookResponse, OrderJson};
 
-pub type BroadcastTx = broadcast::Sender<WebSocketMessage>;
+/// 기본 배치 주기 (시뮬레이션 tick과 같음)
+const DEFAULT_BATCH_MS: u64 = 50;
+/// 클라이언트별 기본 전송 대기열 길이
+const DEFAULT_CLIENT_QUEUE: usize = 256;
+
+pub type BroadcastTx = Broadcaster;
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub enum WebSocketMessage {
     OrderBook(OrderBookResponse),
     Trades(Vec<Trade>), // 새로운 trades만 포함 (전체가 아님)
+    /// 클라이언트가 따라오지 못해 버려진 메시지 수 (다음 OrderBook으로 다시 맞추면 된다)
+    Gap { dropped: u64 },
+    /// 구독 요청 확인 (현재 구독 중인 채널)
+    Subscribed { channels: Vec<Channel> },
+    /// 잘못된 클라이언트 요청
+    Error { message: String },
+}
+
+/// 구독 채널 (연결 직후에는 모두 구독)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    OrderBook,
+    Trades,
+}
+
+impl Channel {
+    const ALL: [Channel; 2] = [Channel::OrderBook, Channel::Trades];
+}
+
+/// 클라이언트 구독 요청: `{"subscribe": ["orderbook", "trades"]}` (보낸 목록으로 교체)
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SubscribeRequest {
+    subscribe: Vec<Channel>,
+}
+
+impl WebSocketMessage {
+    /// 메시지가 속한 채널 (Gap 등 제어 메시지는 None이라 항상 전송)
+    fn channel(&self) -> Option<Channel> {
+        match self {
+            WebSocketMessage::OrderBook(_) => Some(Channel::OrderBook),
+            WebSocketMessage::Trades(_) => Some(Channel::Trades),
+            _ => None,
+        }
+    }
+}
+
+/// 배치 주기 동안 모인 메시지 (오더북은 최신 것만, 체결은 누적)
+#[derive(Debug, Default)]
+struct PendingBroadcast {
+    orderbook: Option<OrderBookResponse>,
+    trades: Vec<Trade>,
+}
+
+/// WebSocket 브로드캐스트 배치기
+/// 주문/체결마다 바로 보내지 않고 `SIM_WS_BATCH_MS`(기본 50ms)마다 오더북 1개 + 체결 1개로 묶어 보낸다
+#[derive(Clone)]
+pub struct Broadcaster {
+    tx: broadcast::Sender<WebSocketMessage>,
+    pending: Arc<Mutex<PendingBroadcast>>,
+    client_queue: usize,
+}
+
+impl Broadcaster {
+    /// 배치에 추가 (Gap 등 제어 메시지는 클라이언트별로만 생기므로 무시)
+    pub fn send(&self, message: WebSocketMessage) {
+        let mut pending = self.pending.lock().unwrap();
+        match message {
+            WebSocketMessage::OrderBook(book) => pending.orderbook = Some(book),
+            WebSocketMessage::Trades(trades) => pending.trades.extend(trades),
+            _ => {}
+        }
+    }
+
+    /// 모인 메시지를 구독자에게 전송
+    pub fn flush(&self) {
+        let (orderbook, trades) = {
+            let mut pending = self.pending.lock().unwrap();
+            (pending.orderbook.take(), std::mem::take(&mut pending.trades))
+        };
+        if let Some(book) = orderbook {
+            let _ = self.tx.send(WebSocketMessage::OrderBook(book));
+        }
+        if !trades.is_empty() {
+            let _ = self.tx.send(WebSocketMessage::Trades(trades));
+        }
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<WebSocketMessage> {
+        self.tx.subscribe()
+    }
+}
+
+/// 클라이언트 하나의 전송 대기열
+/// 가득 차면 가장 오래된 메시지를 버리고, 버린 수는 다음 전송 앞에 Gap으로 알린다
+/// 구독하지 않은 채널의 메시지는 대기열에 넣지 않는다
+struct ClientQueue {
+    state: Mutex<ClientQueueState>,
+    notify: Notify,
+    capacity: usize,
+}
+
+#[derive(Default)]
+struct ClientQueueState {
+    messages: VecDeque<WebSocketMessage>,
+    dropped: u64,
+    closed: bool,
+    /// 구독하지 않은 채널
+    muted: Vec<Channel>,
+}
+
+impl ClientQueue {
+    fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(ClientQueueState::default()),
+            notify: Notify::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    fn push(&self, message: WebSocketMessage) {
+        {
+            let mut state = self.state.lock().unwrap();
+            if message
+                .channel()
+                .is_some_and(|channel| state.muted.contains(&channel))
+            {
+                return;
+            }
+            if state.messages.len() >= self.capacity {
+                state.messages.pop_front();
+                state.dropped += 1;
+            }
+            state.messages.push_back(message);
+        }
+        self.notify.notify_one();
+    }
+
+    /// 브로드캐스트 채널에서 밀려 받지 못한 메시지
+    fn lagged(&self, count: u64) {
+        self.state.lock().unwrap().dropped += count;
+        self.notify.notify_one();
+    }
+
+    /// 구독 채널 교체, 현재 구독 목록 반환
+    /// 구독을 끊은 채널의 대기 중 메시지도 버린다 (버린 수는 Gap으로 세지 않음)
+    fn subscribe(&self, channels: &[Channel]) -> Vec<Channel> {
+        let mut state = self.state.lock().unwrap();
+        state.muted = Channel::ALL
+            .into_iter()
+            .filter(|channel| !channels.contains(channel))
+            .collect();
+        let muted = state.muted.clone();
+        state
+            .messages
+            .retain(|message| message.channel().is_none_or(|c| !muted.contains(&c)));
+        Channel::ALL
+            .into_iter()
+            .filter(|channel| channels.contains(channel))
+            .collect()
+    }
+
+    /// 구독 필터를 거치지 않는 응답 메시지
+    fn reply(&self, message: WebSocketMessage) {
+        self.state.lock().unwrap().messages.push_back(message);
+        self.notify.notify_one();
+    }
+
+    fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.notify.notify_one();
+    }
+
+    /// 다음에 보낼 메시지 (닫혔고 비었으면 None)
+    async fn pop(&self) -> Option<WebSocketMessage> {
+        loop {
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.dropped > 0 {
+                    let dropped = std::mem::take(&mut state.dropped);
+                    return Some(WebSocketMessage::Gap { dropped });
+                }
+                if let Some(message) = state.messages.pop_front() {
+                    return Some(message);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            self.notify.notified().await;
+        }
+    }
 }
 
 pub async fn websocket_handler(
@@ -66,8 +256,8 @@ pub async fn websocket_handler(
     };
 
     // Send initial messages
-    let _ = tx.send(WebSocketMessage::OrderBook(initial_orderbook));
-    let _ = tx.send(WebSocketMessage::Trades(initial_trades));
+    tx.send(WebSocketMessage::OrderBook(initial_orderbook));
+    tx.send(WebSocketMessage::Trades(initial_trades));
 
     ws.on_upgrade(|socket| handle_socket(socket, tx))
 }
@@ -75,10 +265,25 @@ pub async fn websocket_handler(
 async fn handle_socket(socket: WebSocket, tx: BroadcastTx) {
     let (mut sender, mut receiver) = socket.split();
     let mut rx = tx.subscribe();
+    let queue = Arc::new(ClientQueue::new(tx.client_queue));
+
+    // 브로드캐스트를 클라이언트 대기열로 옮김 (느린 클라이언트가 다른 클라이언트를 막지 않도록)
+    let queue_clone = queue.clone();
+    let mut forward_task = tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(msg) => queue_clone.push(msg),
+                Err(broadcast::error::RecvError::Lagged(count)) => queue_clone.lagged(count),
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+        queue_clone.close();
+    });
 
-    // Spawn task to forward broadcast messages to websocket
+    // Spawn task to forward queued messages to websocket
+    let reply_queue = queue.clone();
     let mut send_task = tokio::spawn(async move {
-        while let Ok(msg) = rx.recv().await {
+        while let Some(msg) = queue.pop().await {
             if let Ok(json) = serde_json::to_string(&msg) {
                 if sender.send(Message::Text(json)).await.is_err() {
                     break;
@@ -87,11 +292,23 @@ async fn handle_socket(socket: WebSocket, tx: BroadcastTx) {
         }
     });
 
-    // Spawn task to receive messages from websocket (for ping/pong)
+    // 클라이언트 메시지 수신 (구독 요청, ping/pong)
     let mut recv_task = tokio::spawn(async move {
         while let Some(Ok(msg)) = receiver.next().await {
-            if let Message::Close(_) = msg {
-                break;
+            match msg {
+                Message::Text(text) => {
+                    let reply = match serde_json::from_str::<SubscribeRequest>(&text) {
+                        Ok(request) => WebSocketMessage::Subscribed {
+                            channels: reply_queue.subscribe(&request.subscribe),
+                        },
+                        Err(e) => WebSocketMessage::Error {
+                            message: format!("invalid subscribe request: {}", e),
+                        },
+                    };
+                    reply_queue.reply(reply);
+                }
+                Message::Close(_) => break,
+                _ => {}
             }
         }
     });
@@ -100,9 +317,35 @@ async fn handle_socket(socket: WebSocket, tx: BroadcastTx) {
         _ = (&mut send_task) => recv_task.abort(),
         _ = (&mut recv_task) => send_task.abort(),
     };
+    forward_task.abort();
+    let _ = (&mut forward_task).await;
 }
 
+/// 브로드캐스트 배치기 생성 후 배치 전송 태스크 시작
+/// SIM_WS_BATCH_MS: 배치 주기 (기본 50ms), SIM_WS_CLIENT_QUEUE: 클라이언트별 대기열 길이 (기본 256)
 pub fn create_broadcast() -> BroadcastTx {
-    broadcast::channel(100).0
+    let batch_ms = env_or("SIM_WS_BATCH_MS", DEFAULT_BATCH_MS).max(1);
+    let client_queue = env_or("SIM_WS_CLIENT_QUEUE", DEFAULT_CLIENT_QUEUE);
+    let broadcaster = Broadcaster {
+        tx: broadcast::channel(client_queue.max(1)).0,
+        pending: Default::default(),
+        client_queue,
+    };
+    let flusher = broadcaster.clone();
+    tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_millis(batch_ms));
+        loop {
+            ticker.tick().await;
+            flusher.flush();
+        }
+    });
+    broadcaster
+}
+
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
 }
 